@@ -0,0 +1,322 @@
+//! `voxtype bench` — run one recording through every installed engine and
+//! model and compare load time, inference time, real-time factor and, with
+//! a reference transcript, word error rate.
+//!
+//! Each model is loaded, run once and dropped before the next, so only one
+//! model is in memory at a time. Engines this binary was not built with
+//! are listed with their error rather than skipped silently.
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+use voxtype::config::{Config, TranscriptionEngine, WhisperMode};
+use voxtype::setup::model_disk::{self, ModelKind};
+use voxtype::{audio, transcribe};
+
+use super::transcribe_file::read_wav_16k_mono;
+
+/// An installed model to benchmark
+#[derive(Debug, Clone, PartialEq)]
+struct Candidate {
+    engine: TranscriptionEngine,
+    /// Value for the engine's `model` setting
+    model: String,
+    /// Name shown in the table
+    label: String,
+}
+
+/// Outcome of one benchmark run
+struct BenchResult {
+    candidate: Candidate,
+    outcome: Result<Timing, String>,
+}
+
+struct Timing {
+    load: Duration,
+    inference: Duration,
+    text: String,
+}
+
+/// Run the benchmark
+pub(crate) async fn run_bench(
+    config: &Config,
+    file: Option<&Path>,
+    duration_secs: u64,
+    reference: Option<&Path>,
+    engines: &[String],
+    models: &[String],
+) -> anyhow::Result<()> {
+    let engines = engines
+        .iter()
+        .map(|name| {
+            name.parse::<TranscriptionEngine>().map_err(|_| {
+                anyhow::anyhow!(
+                    "Invalid engine '{}'. Valid options: {}",
+                    name,
+                    voxtype::cli::ENGINE_NAMES_CSV
+                )
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let reference = match reference {
+        Some(path) => Some(
+            std::fs::read_to_string(path)
+                .map_err(|e| anyhow::anyhow!("Cannot read reference {:?}: {}", path, e))?,
+        ),
+        None => None,
+    };
+
+    let candidates: Vec<Candidate> = installed_candidates(&Config::models_dir())
+        .into_iter()
+        .filter(|c| engines.is_empty() || engines.contains(&c.engine))
+        .filter(|c| models.is_empty() || models.contains(&c.label))
+        .collect();
+    if candidates.is_empty() {
+        println!("No installed models to benchmark. Download some with: voxtype setup model");
+        return Ok(());
+    }
+
+    let samples = match file {
+        Some(path) => read_wav_16k_mono(path)?.1,
+        None => record_sample(config, duration_secs).await?,
+    };
+    let audio_secs = samples.len() as f32 / 16000.0;
+    if samples.is_empty() {
+        anyhow::bail!("No audio to benchmark");
+    }
+    println!(
+        "Benchmarking {} model(s) on {:.1}s of audio\n",
+        candidates.len(),
+        audio_secs
+    );
+
+    let mut results = Vec::new();
+    for candidate in candidates {
+        eprintln!("Running {} {}...", candidate.engine.name(), candidate.label);
+        let outcome = bench_one(config, &candidate, &samples);
+        results.push(BenchResult { candidate, outcome });
+    }
+
+    println!();
+    print_table(&results, audio_secs, reference.as_deref());
+    Ok(())
+}
+
+/// Every model in `models_dir` that an engine can load
+fn installed_candidates(models_dir: &Path) -> Vec<Candidate> {
+    let mut candidates: Vec<Candidate> = model_disk::scan(models_dir)
+        .into_iter()
+        .filter_map(|entry| match entry.kind {
+            ModelKind::Whisper => Some(Candidate {
+                engine: TranscriptionEngine::Whisper,
+                model: entry.name.clone(),
+                label: entry.name,
+            }),
+            // Engines resolve absolute paths as-is, which sidesteps each
+            // engine's own name lookup rules
+            ModelKind::Directory(Some(prefix)) => Some(Candidate {
+                engine: prefix.parse().ok()?,
+                model: entry.path.to_string_lossy().into_owned(),
+                label: entry.name,
+            }),
+            _ => None,
+        })
+        .collect();
+    candidates.sort_by(|a, b| {
+        a.engine
+            .name()
+            .cmp(b.engine.name())
+            .then_with(|| a.label.cmp(&b.label))
+    });
+    candidates
+}
+
+/// Record `secs` seconds from the configured microphone
+pub(super) async fn record_sample(config: &Config, secs: u64) -> anyhow::Result<Vec<f32>> {
+    let mut capture = audio::create_capture(&config.audio)?;
+    println!("Recording {}s from the microphone, speak now...", secs);
+    // Chunks are also collected internally and returned by stop()
+    let _chunks = capture.start().await?;
+    tokio::time::sleep(Duration::from_secs(secs)).await;
+    let samples = capture.stop().await?;
+    println!("Recorded {:.1}s\n", samples.len() as f32 / 16000.0);
+    Ok(samples)
+}
+
+/// Load one model and transcribe `samples` with it
+fn bench_one(config: &Config, candidate: &Candidate, samples: &[f32]) -> Result<Timing, String> {
+    let mut config = config.clone();
+    config.select_model(candidate.engine, &candidate.model);
+    // Benchmark the local model, in this process
+    if config.whisper.effective_mode() == WhisperMode::Remote {
+        config.whisper.mode = Some(WhisperMode::Local);
+        config.whisper.backend = None;
+    }
+    config.whisper.gpu_isolation = false;
+
+    let start = Instant::now();
+    let transcriber = transcribe::create_transcriber(&config).map_err(|e| e.to_string())?;
+    let load = start.elapsed();
+
+    let start = Instant::now();
+    let text = transcriber.transcribe(samples).map_err(|e| e.to_string())?;
+    let inference = start.elapsed();
+
+    Ok(Timing {
+        load,
+        inference,
+        text: text.trim().to_string(),
+    })
+}
+
+fn print_table(results: &[BenchResult], audio_secs: f32, reference: Option<&str>) {
+    let label_width = results
+        .iter()
+        .map(|r| r.candidate.label.len())
+        .max()
+        .unwrap_or(0)
+        .max("MODEL".len());
+
+    print!(
+        "{:<11}  {:<width$}  {:>8}  {:>9}  {:>6}",
+        "ENGINE",
+        "MODEL",
+        "LOAD",
+        "INFERENCE",
+        "RTF",
+        width = label_width
+    );
+    if reference.is_some() {
+        print!("  {:>6}", "WER");
+    }
+    println!();
+
+    for result in results {
+        print!(
+            "{:<11}  {:<width$}",
+            result.candidate.engine.name(),
+            result.candidate.label,
+            width = label_width
+        );
+        match &result.outcome {
+            Ok(timing) => {
+                print!(
+                    "  {:>7.2}s  {:>8.2}s  {:>6.3}",
+                    timing.load.as_secs_f32(),
+                    timing.inference.as_secs_f32(),
+                    timing.inference.as_secs_f32() / audio_secs
+                );
+                if let Some(wer) = reference.and_then(|r| word_error_rate(r, &timing.text)) {
+                    print!("  {:>5.1}%", wer * 100.0);
+                }
+                println!();
+            }
+            Err(_) => println!("  failed"),
+        }
+    }
+
+    println!("\nTranscripts:");
+    for result in results {
+        let text = match &result.outcome {
+            Ok(timing) if timing.text.is_empty() => "(empty)",
+            Ok(timing) => &timing.text,
+            Err(e) => e,
+        };
+        println!(
+            "  {} {}: {}",
+            result.candidate.engine.name(),
+            result.candidate.label,
+            text
+        );
+    }
+}
+
+/// Lowercased words with punctuation removed
+fn normalize_words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric() || *c == '\'')
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Word error rate of `hypothesis` against `reference`: word-level edit
+/// distance divided by the number of reference words. Case and
+/// punctuation are ignored. None if the reference has no words.
+fn word_error_rate(reference: &str, hypothesis: &str) -> Option<f32> {
+    let reference = normalize_words(reference);
+    let hypothesis = normalize_words(hypothesis);
+    if reference.is_empty() {
+        return None;
+    }
+
+    // Single-row Levenshtein distance over words
+    let mut row: Vec<usize> = (0..=hypothesis.len()).collect();
+    for (i, ref_word) in reference.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, hyp_word) in hypothesis.iter().enumerate() {
+            let substitution = diagonal + usize::from(ref_word != hyp_word);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    Some(row[hypothesis.len()] as f32 / reference.len() as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_error_rate() {
+        assert_eq!(word_error_rate("Hello, world.", "hello world"), Some(0.0));
+        // One substitution out of four words
+        assert_eq!(
+            word_error_rate("the quick brown fox", "the quick brown box"),
+            Some(0.25)
+        );
+        // One deletion and one insertion
+        assert_eq!(
+            word_error_rate("the quick brown fox", "quick brown fox jumps"),
+            Some(0.5)
+        );
+        assert_eq!(word_error_rate("one two", ""), Some(1.0));
+        assert_eq!(word_error_rate("", "anything"), None);
+    }
+
+    #[test]
+    fn test_normalize_words_keeps_apostrophes() {
+        assert_eq!(
+            normalize_words("Don't STOP -- now!"),
+            vec!["don't", "stop", "now"]
+        );
+    }
+
+    #[test]
+    fn test_installed_candidates() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("ggml-base.en.bin"), b"x").unwrap();
+        std::fs::write(dir.path().join("ggml-base.en.bin.part"), b"x").unwrap();
+        std::fs::create_dir(dir.path().join("parakeet-tdt-0.6b-v3")).unwrap();
+        std::fs::create_dir(dir.path().join("unrelated")).unwrap();
+
+        let candidates = installed_candidates(dir.path());
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].engine, TranscriptionEngine::Parakeet);
+        assert_eq!(candidates[0].label, "parakeet-tdt-0.6b-v3");
+        assert!(Path::new(&candidates[0].model).is_absolute());
+        assert_eq!(
+            candidates[1],
+            Candidate {
+                engine: TranscriptionEngine::Whisper,
+                model: "base.en".to_string(),
+                label: "base.en".to_string(),
+            }
+        );
+    }
+}
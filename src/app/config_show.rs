@@ -23,7 +23,14 @@ pub(crate) fn format_meeting_config_section(meeting: &config::MeetingConfig) ->
 
     let _ = writeln!(s, "\n[meeting.audio]");
     let _ = writeln!(s, "  mic_device = {:?}", meeting.audio.mic_device);
-    let _ = writeln!(s, "  loopback_device = {:?}", meeting.audio.loopback_device);
+    match meeting.audio.loopback_device {
+        config::LoopbackDevice::Single(ref device) => {
+            let _ = writeln!(s, "  loopback_device = {:?}", device);
+        }
+        config::LoopbackDevice::Multiple(ref devices) => {
+            let _ = writeln!(s, "  loopback_device = {:?}", devices);
+        }
+    }
     let _ = writeln!(s, "  echo_cancel = {:?}", meeting.audio.echo_cancel);
 
     let _ = writeln!(s, "\n[meeting.diarization]");
@@ -229,6 +236,7 @@ pub(crate) async fn show_config(config: &config::Config) -> anyhow::Result<()> {
     } else {
         println!("  driver_order = (default: wtype -> dotool -> ydotool -> clipboard)");
     }
+    println!("  auto_driver = {}", config.output.auto_driver);
     println!("  type_delay_ms = {}", config.output.type_delay_ms);
     println!("  pre_type_delay_ms = {}", config.output.pre_type_delay_ms);
     println!("  restore_clipboard = {}", config.output.restore_clipboard);
@@ -236,6 +244,9 @@ pub(crate) async fn show_config(config: &config::Config) -> anyhow::Result<()> {
         "  restore_clipboard_delay_ms = {}",
         config.output.restore_clipboard_delay_ms
     );
+    if let Some(secs) = config.output.restore_clipboard_after_secs {
+        println!("  restore_clipboard_after_secs = {}", secs);
+    }
     println!(
         "  wait_for_modifier_release = {}",
         config.output.wait_for_modifier_release
@@ -9,13 +9,15 @@ use voxtype::{
     config, daemon, setup, transcribe, Cli, Commands, ConfigAction, ConfigSetKey, SetupAction,
 };
 
+use super::bench::run_bench;
 use super::config_set_engine::run_config_set_engine;
 use super::config_show::show_config;
 use super::info::run_info_command;
 use super::meeting::run_meeting_command;
-use super::record::send_record_command;
+use super::record::{send_record_command, send_translate_command, send_undo_command};
 use super::status::run_status;
-use super::transcribe_file::transcribe_file;
+use super::test_output::run_test_output;
+use super::transcribe_file::{transcribe_batch, transcribe_file};
 use super::updates::check_for_updates;
 
 /// Check if running as root and warn for commands that don't need elevated privileges.
@@ -119,7 +121,14 @@ pub(crate) async fn dispatch(
             menubar::run(state_file);
         }
 
-        Commands::Transcribe { file, engine } => {
+        Commands::Transcribe {
+            file,
+            batch,
+            glob,
+            format,
+            out_dir,
+            engine,
+        } => {
             if let Some(engine_name) = engine {
                 match engine_name.parse::<config::TranscriptionEngine>() {
                     Ok(e) => config.engine = e,
@@ -133,7 +142,37 @@ pub(crate) async fn dispatch(
                     }
                 }
             }
-            transcribe_file(&config, &file)?;
+            match (batch, file) {
+                (Some(dir), _) => {
+                    transcribe_batch(&config, &dir, &glob, format, out_dir.as_deref())?
+                }
+                (None, Some(file)) => {
+                    if out_dir.is_some() {
+                        eprintln!("Error: --out-dir can only be used with --batch");
+                        std::process::exit(1);
+                    }
+                    transcribe_file(&config, &file)?
+                }
+                (None, None) => unreachable!("clap requires a file or --batch"),
+            }
+        }
+
+        Commands::Bench {
+            file,
+            duration,
+            reference,
+            engines,
+            models,
+        } => {
+            run_bench(
+                &config,
+                file.as_deref(),
+                duration,
+                reference.as_deref(),
+                &engines,
+                &models,
+            )
+            .await?;
         }
 
         Commands::TranscribeWorker {
@@ -252,9 +291,20 @@ pub(crate) async fn dispatch(
                         setup::dms::print_config();
                     }
                 }
-                Some(SetupAction::Model { list, set, restart }) => {
+                Some(SetupAction::Model {
+                    list,
+                    set,
+                    restart,
+                    disk_usage,
+                    remove,
+                    force,
+                }) => {
                     warn_if_root("model");
-                    if list {
+                    if !remove.is_empty() {
+                        setup::model_disk::remove(&config, &remove, force)?;
+                    } else if disk_usage {
+                        setup::model_disk::show_disk_usage(&config);
+                    } else if list {
                         setup::model::list_installed();
                     } else if let Some(model_name) = set {
                         setup::model::set_model(&model_name, restart).await?;
@@ -320,12 +370,20 @@ pub(crate) async fn dispatch(
                     warn_if_root("compositor");
                     setup::compositor::run(&compositor_type).await?;
                 }
-                Some(SetupAction::Vad { status }) => {
+                Some(SetupAction::Vad { status, backend }) => {
                     warn_if_root("vad");
                     if status {
                         setup::vad::show_status();
                     } else {
-                        setup::vad::download_model()?;
+                        setup::vad::download_model(&config, backend.as_deref())?;
+                    }
+                }
+                Some(SetupAction::WakeWord { status, model }) => {
+                    warn_if_root("wake-word");
+                    if status {
+                        setup::wake_word::show_status(&config);
+                    } else {
+                        setup::wake_word::download_models(&config, model.as_deref())?;
                     }
                 }
                 Some(SetupAction::Quickshell {
@@ -387,10 +445,22 @@ pub(crate) async fn dispatch(
             send_record_command(&config, action, top_level_model.as_deref())?;
         }
 
+        Commands::Translate { state } => {
+            send_translate_command(state)?;
+        }
+
+        Commands::Undo => {
+            send_undo_command()?;
+        }
+
         Commands::Meeting { action } => {
             run_meeting_command(&config, action).await?;
         }
 
+        Commands::TestOutput { type_sample, delay } => {
+            run_test_output(&config, type_sample, delay).await?;
+        }
+
         Commands::CheckUpdate => {
             check_for_updates().await?;
         }
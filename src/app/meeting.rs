@@ -1,14 +1,19 @@
-//! `voxtype meeting <action>` — start/stop/pause/resume/status/list/export/show/delete/label/summarize.
+//! `voxtype meeting <action>` — start/stop/pause/resume/status/calendar/watch/list/search/
+//! export/show/correct/stats/chapters/prune/sync/delete/label/speakers/summarize.
 
-use std::path::PathBuf;
-use voxtype::{config, daemon_status::check_daemon_running, meeting, setup, MeetingAction};
+use std::path::{Path, PathBuf};
+use voxtype::{
+    config, daemon_status::check_daemon_running, meeting, setup, MeetingAction, SpeakersAction,
+};
 
 /// Run a meeting command
 pub(crate) async fn run_meeting_command(
     config: &config::Config,
     action: MeetingAction,
 ) -> anyhow::Result<()> {
-    use meeting::{export_meeting, ExportFormat, ExportOptions, MeetingConfig, StorageConfig};
+    use meeting::{
+        export_meeting, ExportFormat, ExportOptions, MeetingConfig, StorageConfig, TranscriptText,
+    };
 
     // Convert config to meeting config
     let storage_path = if config.meeting.storage_path == "auto" {
@@ -105,6 +110,8 @@ pub(crate) async fn run_meeting_command(
                 let _ = std::fs::remove_file(&diarization_file);
             }
 
+            let _ = std::fs::remove_file(runtime_dir.join("meeting_start_resume"));
+
             // Write start trigger file (with optional title)
             let start_file = runtime_dir.join("meeting_start");
             let content = title.unwrap_or_default();
@@ -167,7 +174,49 @@ pub(crate) async fn run_meeting_command(
             println!("Meeting pause requested.");
         }
 
-        MeetingAction::Resume => {
+        MeetingAction::Resume {
+            meeting_id: Some(meeting_id),
+        } => {
+            if !config.meeting.enabled {
+                eprintln!("Error: Meeting mode is disabled in config.");
+                std::process::exit(1);
+            }
+            check_daemon_running()?;
+
+            let meeting_state_file = config::Config::runtime_dir().join("meeting_state");
+            let state = std::fs::read_to_string(&meeting_state_file).unwrap_or_default();
+            if state.starts_with("recording") || state.starts_with("paused") {
+                eprintln!("Error: A meeting is already in progress.");
+                eprintln!("Use 'voxtype meeting stop' to end it first.");
+                std::process::exit(1);
+            }
+
+            // Fail here rather than in the daemon log if the ID is wrong
+            let meeting_data = match meeting::get_meeting(&meeting_config, &meeting_id) {
+                Ok(m) => m,
+                Err(e) => {
+                    eprintln!("Error loading meeting: {}", e);
+                    std::process::exit(1);
+                }
+            };
+
+            // The daemon reads which meeting to reopen before the start trigger
+            let runtime_dir = config::Config::runtime_dir();
+            let _ = std::fs::remove_file(runtime_dir.join("meeting_start_diarization"));
+            std::fs::write(
+                runtime_dir.join("meeting_start_resume"),
+                meeting_data.metadata.id.to_string(),
+            )?;
+            std::fs::write(runtime_dir.join("meeting_start"), "")?;
+
+            println!(
+                "Resuming {} ({} segments so far). Check status with 'voxtype meeting status'.",
+                meeting_data.metadata.display_title(),
+                meeting_data.transcript.segments.len()
+            );
+        }
+
+        MeetingAction::Resume { meeting_id: None } => {
             check_daemon_running()?;
 
             // Check if meeting is paused
@@ -190,6 +239,23 @@ pub(crate) async fn run_meeting_command(
             println!("Meeting resume requested.");
         }
 
+        MeetingAction::Mark { note } => {
+            check_daemon_running()?;
+
+            let meeting_state_file = config::Config::runtime_dir().join("meeting_state");
+            let state = std::fs::read_to_string(&meeting_state_file).unwrap_or_default();
+            if !state.starts_with("recording") && !state.starts_with("paused") {
+                eprintln!("Error: No meeting in progress.");
+                std::process::exit(1);
+            }
+
+            // Write mark trigger file (with optional note)
+            let mark_file = config::Config::runtime_dir().join("meeting_mark");
+            std::fs::write(&mark_file, note.unwrap_or_default())?;
+
+            println!("Meeting bookmark requested.");
+        }
+
         MeetingAction::Status => {
             // Read meeting state file
             let meeting_state_file = config::Config::runtime_dir().join("meeting_state");
@@ -218,11 +284,91 @@ pub(crate) async fn run_meeting_command(
             }
         }
 
-        MeetingAction::List { limit } => {
-            match meeting::list_meetings(&meeting_config, Some(limit)) {
+        MeetingAction::Calendar => {
+            let calendar = &config.meeting.calendar;
+            if calendar.source == "disabled" {
+                println!("Calendar lookup is disabled.");
+                println!();
+                println!("Set source = \"ics\" or \"khal\" in [meeting.calendar] to title");
+                println!("meetings after the current calendar event.");
+                return Ok(());
+            }
+
+            match meeting::calendar::current_event(calendar).await {
+                Ok(Some(event)) => {
+                    println!("Title:     {}", event.title);
+                    if let Some(time) = meeting::calendar::format_event_time(&event) {
+                        println!("Time:      {}", time);
+                    }
+                    if !event.attendees.is_empty() {
+                        println!("Attendees: {}", event.attendees.join(", "));
+                    }
+                }
+                Ok(None) => {
+                    println!(
+                        "No event now or in the next {} minutes ({}).",
+                        calendar.early_mins, calendar.source
+                    );
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        MeetingAction::Watch => {
+            if !config.meeting.enabled {
+                eprintln!("Error: Meeting mode is disabled in config.");
+                eprintln!();
+                eprintln!("Enable it by adding to config.toml:");
+                eprintln!("  [meeting]");
+                eprintln!("  enabled = true");
+                std::process::exit(1);
+            }
+            check_daemon_running()?;
+            voxtype::tui::run_meeting_watch(&meeting::live::default_socket_path())?;
+        }
+
+        MeetingAction::Captions {
+            format,
+            output,
+            lines,
+            no_speakers,
+            text,
+        } => {
+            if !config.meeting.enabled {
+                eprintln!("Error: Meeting mode is disabled in config.");
+                eprintln!();
+                eprintln!("Enable it by adding to config.toml:");
+                eprintln!("  [meeting]");
+                eprintln!("  enabled = true");
+                std::process::exit(1);
+            }
+            check_daemon_running()?;
+
+            let options = meeting::captions::CaptionOptions {
+                format: meeting::captions::CaptionFormat::parse(&format)
+                    .unwrap_or(meeting::captions::CaptionFormat::Text),
+                lines,
+                speakers: !no_speakers,
+                text: TranscriptText::parse(&text).unwrap_or_default(),
+            };
+            let socket_path = meeting::live::default_socket_path();
+            if let Err(e) = meeting::captions::run(&socket_path, options, output.as_deref()) {
+                eprintln!("Error: Captions stopped: {}", e);
+                std::process::exit(1);
+            }
+        }
+
+        MeetingAction::List { limit, tag } => {
+            match meeting::list_meetings(&meeting_config, Some(limit), tag.as_deref()) {
                 Ok(meetings) => {
                     if meetings.is_empty() {
-                        println!("No meetings found.");
+                        match tag {
+                            Some(tag) => println!("No meetings tagged \"{}\".", tag),
+                            None => println!("No meetings found."),
+                        }
                         return Ok(());
                     }
 
@@ -244,6 +390,9 @@ pub(crate) async fn run_meeting_command(
                         println!("  Date: {}", m.started_at.format("%Y-%m-%d %H:%M"));
                         println!("  Duration: {}", duration);
                         println!("  Status: {:?}", m.status);
+                        if !m.tags.is_empty() {
+                            println!("  Tags: {}", m.tags.join(", "));
+                        }
                         println!();
                     }
                 }
@@ -254,46 +403,124 @@ pub(crate) async fn run_meeting_command(
             }
         }
 
+        MeetingAction::Search { query, limit, tag } => {
+            let query = query.join(" ");
+            let hits =
+                match meeting::search_meetings(&meeting_config, &query, limit, tag.as_deref()) {
+                    Ok(hits) => hits,
+                    Err(e) => {
+                        eprintln!("Error searching meetings: {}", e);
+                        std::process::exit(1);
+                    }
+                };
+            if hits.is_empty() {
+                println!("No matches for \"{}\".", query);
+                return Ok(());
+            }
+
+            // Group by meeting, best-matching meeting first, segments in
+            // transcript order
+            let mut groups: Vec<Vec<meeting::SearchHit>> = Vec::new();
+            let total = hits.len();
+            for hit in hits {
+                match groups
+                    .iter_mut()
+                    .find(|group| group[0].meeting_id == hit.meeting_id)
+                {
+                    Some(group) => group.push(hit),
+                    None => groups.push(vec![hit]),
+                }
+            }
+
+            println!(
+                "{} match{} for \"{}\" in {} meeting{}\n",
+                total,
+                if total == 1 { "" } else { "es" },
+                query,
+                groups.len(),
+                if groups.len() == 1 { "" } else { "s" }
+            );
+            for mut group in groups {
+                group.sort_by_key(|hit| hit.start_ms);
+                let first = &group[0];
+                println!(
+                    "{} ({})",
+                    first.display_title(),
+                    first.meeting_started_at.format("%Y-%m-%d %H:%M")
+                );
+                println!("  ID: {}", first.meeting_id);
+                for hit in &group {
+                    println!(
+                        "  [{}] {}: {}",
+                        hit.format_timestamp(),
+                        hit.speaker,
+                        hit.snippet
+                    );
+                }
+                println!();
+            }
+        }
+
         MeetingAction::Export {
             meeting_id,
             format,
             output,
+            out_dir,
+            template,
             timestamps,
             speakers,
             metadata,
+            text,
         } => {
             let export_format = ExportFormat::parse(&format).ok_or_else(|| {
                 anyhow::anyhow!(
-                    "Unknown export format '{}'. Valid formats: text, markdown, json",
+                    "Unknown export format '{}'. Valid formats: text, markdown, json, srt, vtt, html, template",
                     format
                 )
             })?;
 
-            let options = ExportOptions {
+            let export_config = &config.meeting.export;
+            let mut options = ExportOptions {
                 include_timestamps: timestamps,
                 include_speakers: speakers,
                 include_metadata: metadata,
                 line_width: 0,
+                audio_url: None,
+                template: None,
+                tags: export_config.tags.clone(),
+                text: TranscriptText::parse(&text).unwrap_or_default(),
             };
+            if export_format == ExportFormat::Template {
+                let name = template.as_deref().unwrap_or(&export_config.template);
+                options.template = Some(meeting::export::template::load_template(name)?);
+            }
 
-            let meeting_data = match meeting::get_meeting(&meeting_config, &meeting_id) {
-                Ok(m) => m,
-                Err(e) => {
-                    eprintln!("Error loading meeting: {}", e);
-                    std::process::exit(1);
+            // Without --output, the template format goes to the configured
+            // notes directory (an Obsidian vault, a Logseq pages folder)
+            let out_dir = out_dir.or_else(|| match export_format {
+                ExportFormat::Template => {
+                    export_config.out_dir.as_deref().map(meeting::expand_home)
+                }
+                _ => None,
+            });
+            let output = match (output, out_dir) {
+                (None, Some(dir)) => {
+                    std::fs::create_dir_all(&dir)?;
+                    Some(dir)
                 }
+                (output, _) => output,
             };
 
-            let content = match export_meeting(&meeting_data, export_format, &options) {
-                Ok(c) => c,
+            let meeting_data = match meeting::get_meeting(&meeting_config, &meeting_id) {
+                Ok(m) => m,
                 Err(e) => {
-                    eprintln!("Error exporting meeting: {}", e);
+                    eprintln!("Error loading meeting: {}", e);
                     std::process::exit(1);
                 }
             };
 
-            if let Some(path) = output {
-                let file_path = if path.is_dir() {
+            let output = output.map(|path| {
+                if path.is_dir() {
                     let title = meeting_data.metadata.display_title();
                     let safe_title =
                         title.replace(['/', '\\', ':', '*', '?', '"', '<', '>', '|'], "-");
@@ -305,7 +532,32 @@ pub(crate) async fn run_meeting_command(
                     path.join(format!("{}.{}", basename, export_format.extension()))
                 } else {
                     path
-                };
+                }
+            });
+
+            // An HTML page written to a file gets a copy of the audio next to
+            // it, so the page and its player can be shared together
+            if let (ExportFormat::Html, Some(file_path)) = (export_format, &output) {
+                let audio = meeting_data.metadata.audio_path().filter(|p| p.exists());
+                if let Some(audio) = audio {
+                    let audio_copy = file_path.with_extension("wav");
+                    std::fs::copy(&audio, &audio_copy)?;
+                    println!("Copied audio to {}", audio_copy.display());
+                    options.audio_url = audio_copy
+                        .file_name()
+                        .map(|name| name.to_string_lossy().into_owned());
+                }
+            }
+
+            let content = match export_meeting(&meeting_data, export_format, &options) {
+                Ok(c) => c,
+                Err(e) => {
+                    eprintln!("Error exporting meeting: {}", e);
+                    std::process::exit(1);
+                }
+            };
+
+            if let Some(file_path) = output {
                 std::fs::write(&file_path, &content)?;
                 println!("Exported to {}", file_path.display());
             } else {
@@ -313,6 +565,77 @@ pub(crate) async fn run_meeting_command(
             }
         }
 
+        MeetingAction::Play {
+            meeting_id,
+            from,
+            segment,
+            bookmark,
+            list,
+        } => {
+            let meeting = meeting::get_meeting(&meeting_config, &meeting_id)
+                .map_err(|e| anyhow::anyhow!("Failed to load meeting: {}", e))?;
+            let segments = meeting::playback::numbered_segments(&meeting);
+
+            if list {
+                for (i, segment) in segments.iter().enumerate() {
+                    println!("{}", meeting::playback::format_numbered(i + 1, segment));
+                }
+                let bookmarks = &meeting.transcript.bookmarks;
+                if !bookmarks.is_empty() {
+                    println!();
+                    for (i, bookmark) in bookmarks.iter().enumerate() {
+                        println!("{}", meeting::playback::format_bookmark(i + 1, bookmark));
+                    }
+                }
+                return Ok(());
+            }
+
+            let audio = meeting
+                .metadata
+                .audio_path()
+                .filter(|path| path.exists())
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "No audio was kept for this meeting. Set [meeting] retain_audio = true \
+                         to keep the audio of future meetings."
+                    )
+                })?;
+            let start_ms =
+                meeting::playback::start_position(&meeting, from.as_deref(), segment, bookmark)
+                    .map_err(|e| anyhow::anyhow!(e))?;
+
+            if let Some(number) = segment {
+                println!(
+                    "{}",
+                    meeting::playback::format_numbered(number, segments[number - 1])
+                );
+            }
+            if let Some(number) = bookmark {
+                println!(
+                    "{}",
+                    meeting::playback::format_bookmark(
+                        number,
+                        &meeting.transcript.bookmarks[number - 1]
+                    )
+                );
+            }
+            eprintln!(
+                "Playing {} from {} (Ctrl+C to stop)",
+                meeting.metadata.display_title(),
+                meeting::data::format_offset(start_ms)
+            );
+
+            let player = config.meeting.audio.player.clone();
+            tokio::task::spawn_blocking(move || match player {
+                Some(ref command) if !command.trim().is_empty() => {
+                    meeting::playback::play_external(command, &audio, start_ms)
+                }
+                _ => meeting::playback::play(&audio, start_ms),
+            })
+            .await?
+            .map_err(|e| anyhow::anyhow!(e))?;
+        }
+
         MeetingAction::Show { meeting_id } => {
             match meeting::get_meeting(&meeting_config, &meeting_id) {
                 Ok(meeting) => {
@@ -327,6 +650,12 @@ pub(crate) async fn run_meeting_command(
                     if let Some(ended) = meeting.metadata.ended_at {
                         println!("Ended:    {}", ended.format("%Y-%m-%d %H:%M UTC"));
                     }
+                    if !meeting.metadata.attendees.is_empty() {
+                        println!("Attendees: {}", meeting.metadata.attendees.join(", "));
+                    }
+                    if !meeting.metadata.tags.is_empty() {
+                        println!("Tags:     {}", meeting.metadata.tags.join(", "));
+                    }
                     if let Some(duration) = meeting.metadata.duration_secs {
                         let hours = duration / 3600;
                         let mins = (duration % 3600) / 60;
@@ -345,11 +674,37 @@ pub(crate) async fn run_meeting_command(
                     println!("Segments: {}", meeting.transcript.segments.len());
                     println!("Words:    {}", meeting.transcript.word_count());
                     println!("Speakers: {}", meeting.transcript.speakers().join(", "));
+                    let corrected = meeting
+                        .transcript
+                        .segments
+                        .iter()
+                        .filter(|s| s.original_text.is_some())
+                        .count();
+                    if let Some(revision) = meeting.transcript.revisions.last() {
+                        println!(
+                            "Corrected: {} segment(s), revision {}",
+                            corrected, revision.number
+                        );
+                    }
                     println!();
+                    if !meeting.transcript.bookmarks.is_empty() {
+                        println!("Bookmarks:");
+                        println!("----------");
+                        for bookmark in &meeting.transcript.bookmarks {
+                            println!("[{}] {}", bookmark.format_timestamp(), bookmark.label());
+                        }
+                        println!();
+                    }
                     println!(
                         "Use 'voxtype meeting export {}' to export the transcript.",
                         meeting_id
                     );
+                    if meeting.metadata.audio_retained {
+                        println!(
+                            "Use 'voxtype meeting play {} --list' to play the audio of a segment or bookmark.",
+                            meeting_id
+                        );
+                    }
                 }
                 Err(e) => {
                     eprintln!("Error loading meeting: {}", e);
@@ -358,6 +713,325 @@ pub(crate) async fn run_meeting_command(
             }
         }
 
+        MeetingAction::Edit {
+            meeting_id,
+            title,
+            tags,
+            participants,
+        } => {
+            if title.is_none() && tags.is_none() && participants.is_none() {
+                anyhow::bail!("Nothing to change. Use --title, --tags, or --participants.");
+            }
+
+            let storage = meeting::MeetingStorage::open(meeting_config.storage.clone())
+                .map_err(|e| anyhow::anyhow!("Failed to open storage: {}", e))?;
+            let id = storage
+                .resolve_meeting_id(&meeting_id)
+                .map_err(|e| anyhow::anyhow!("Meeting not found: {}", e))?;
+            let mut metadata = storage
+                .get_meeting(&id)
+                .map_err(|e| anyhow::anyhow!("Failed to load meeting: {}", e))?
+                .ok_or_else(|| anyhow::anyhow!("Meeting not found: {}", meeting_id))?;
+
+            // The daemon rewrites the metadata of the meeting it's recording
+            if matches!(
+                metadata.status,
+                meeting::MeetingStatus::Active | meeting::MeetingStatus::Paused
+            ) {
+                anyhow::bail!(
+                    "Meeting {} is still being recorded. Edit it after 'voxtype meeting stop'.",
+                    meeting_id
+                );
+            }
+
+            if let Some(title) = title {
+                let title = title.trim();
+                metadata.title = (!title.is_empty()).then(|| title.to_string());
+            }
+            if let Some(tags) = tags {
+                metadata.set_tags(&tags);
+            }
+            if let Some(participants) = participants {
+                metadata.attendees = participants
+                    .iter()
+                    .map(|p| p.trim())
+                    .filter(|p| !p.is_empty())
+                    .map(String::from)
+                    .collect();
+            }
+            // Changed, so sync uploads it again
+            metadata.synced_at = None;
+            storage
+                .update_meeting(&metadata)
+                .map_err(|e| anyhow::anyhow!("Failed to update meeting: {}", e))?;
+
+            println!("Updated {}", metadata.display_title());
+            if !metadata.tags.is_empty() {
+                println!("  Tags: {}", metadata.tags.join(", "));
+            }
+            if !metadata.attendees.is_empty() {
+                println!("  Participants: {}", metadata.attendees.join(", "));
+            }
+        }
+
+        MeetingAction::Correct {
+            meeting_id,
+            file,
+            revert,
+        } => {
+            let storage = meeting::MeetingStorage::open(meeting_config.storage.clone())
+                .map_err(|e| anyhow::anyhow!("Failed to open storage: {}", e))?;
+            let id = storage
+                .resolve_meeting_id(&meeting_id)
+                .map_err(|e| anyhow::anyhow!("Meeting not found: {}", e))?;
+            let mut data = storage
+                .load_meeting_data(&id)
+                .map_err(|e| anyhow::anyhow!("Failed to load meeting: {}", e))?;
+
+            // The daemon is still appending to the transcript
+            if matches!(
+                data.metadata.status,
+                meeting::MeetingStatus::Active | meeting::MeetingStatus::Paused
+            ) {
+                anyhow::bail!(
+                    "Meeting {} is still being recorded. Correct it after 'voxtype meeting stop'.",
+                    meeting_id
+                );
+            }
+
+            let changes = if revert {
+                None
+            } else {
+                let (edited, temp_path) = match file {
+                    Some(path) => (
+                        std::fs::read_to_string(&path)
+                            .map_err(|e| anyhow::anyhow!("{}: {}", path.display(), e))?,
+                        None,
+                    ),
+                    None => {
+                        let (edited, path) =
+                            edit_in_editor(&meeting::corrections::document(&data), &id)?;
+                        (edited, Some(path))
+                    }
+                };
+                let changes = meeting::corrections::parse(&edited)
+                    .and_then(|edits| meeting::corrections::changes(&data, &edits));
+                match (changes, temp_path) {
+                    (Ok(changes), temp_path) => {
+                        if let Some(path) = temp_path {
+                            let _ = std::fs::remove_file(path);
+                        }
+                        Some(changes)
+                    }
+                    // Keep the edits, so they can be fixed and applied
+                    (Err(e), Some(path)) => anyhow::bail!(
+                        "Invalid corrections: {}. Your edits are in {}; fix them and run \
+                         'voxtype meeting correct {} --file {}'.",
+                        e,
+                        path.display(),
+                        meeting_id,
+                        path.display()
+                    ),
+                    (Err(e), None) => anyhow::bail!("Invalid corrections: {}", e),
+                }
+            };
+            let revision = match changes {
+                Some(changes) => data.transcript.correct(&changes),
+                None => data.transcript.revert_corrections(),
+            };
+            let Some(revision) = revision else {
+                println!("No corrections.");
+                return Ok(());
+            };
+            println!(
+                "Saved revision {}: {} segment(s) {}.",
+                revision.number,
+                revision.corrections.len(),
+                if revert { "reverted" } else { "corrected" }
+            );
+
+            storage
+                .save_transcript(&id, &data.transcript)
+                .map_err(|e| anyhow::anyhow!("Failed to save transcript: {}", e))?;
+            mark_unsynced(&storage, &id)?;
+            if data.metadata.summary.is_some() {
+                println!(
+                    "The summary is of the previous text; run 'voxtype meeting summarize {}' to update it.",
+                    meeting_id
+                );
+            }
+        }
+
+        MeetingAction::Stats { meeting_id, json } => {
+            let meeting = meeting::get_meeting(&meeting_config, &meeting_id)
+                .map_err(|e| anyhow::anyhow!("Failed to load meeting: {}", e))?;
+            let stats = meeting::stats::speaker_stats(&meeting);
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&stats)?);
+            } else if stats.is_empty() {
+                println!("No speech in {}.", meeting.metadata.display_title());
+            } else {
+                println!("{}", meeting.metadata.display_title());
+                println!();
+                print!("{}", meeting::stats::format_table(&stats));
+            }
+        }
+
+        MeetingAction::Chapters {
+            meeting_id,
+            regenerate,
+        } => {
+            let mut meeting = meeting::get_meeting(&meeting_config, &meeting_id)
+                .map_err(|e| anyhow::anyhow!("Failed to load meeting: {}", e))?;
+
+            if meeting.transcript.chapters.is_empty() || regenerate {
+                if matches!(
+                    meeting.metadata.status,
+                    meeting::MeetingStatus::Active | meeting::MeetingStatus::Paused
+                ) {
+                    anyhow::bail!(
+                        "Meeting {} is still being recorded. Its chapters are found when it stops.",
+                        meeting_id
+                    );
+                }
+                eprintln!("Finding chapters...");
+                save_chapters(config, &meeting_config.storage, &mut meeting)?;
+            }
+
+            if meeting.transcript.chapters.is_empty() {
+                println!("No chapters: the meeting is short or stays on one topic.");
+            }
+            for chapter in &meeting.transcript.chapters {
+                println!("[{}] {}", chapter.format_timestamp(), chapter.title);
+            }
+        }
+
+        MeetingAction::Prune {
+            dry_run,
+            max_age_days,
+            max_total_size_mb,
+            keep_summaries,
+        } => {
+            use meeting::retention::{format_size, PruneAction, PruneReason};
+
+            let mut retention = config.meeting.retention.clone();
+            if let Some(days) = max_age_days {
+                retention.max_age_days = days;
+            }
+            if let Some(mb) = max_total_size_mb {
+                retention.max_total_size_mb = mb;
+            }
+            retention.keep_summaries |= keep_summaries;
+            if !retention.is_enabled() {
+                anyhow::bail!(
+                    "No limit to prune by. Set max_age_days or max_total_size_mb in \
+                     [meeting.retention], or pass --max-age-days or --max-total-size-mb."
+                );
+            }
+
+            let storage = meeting::MeetingStorage::open(meeting_config.storage.clone())
+                .map_err(|e| anyhow::anyhow!("Failed to open storage: {}", e))?;
+            let pruned = meeting::retention::prune(&storage, &retention, dry_run)
+                .map_err(|e| anyhow::anyhow!("Failed to prune meetings: {}", e))?;
+
+            if pruned.is_empty() {
+                println!("Nothing to prune.");
+                return Ok(());
+            }
+            for p in &pruned {
+                let action = match p.action {
+                    PruneAction::Delete => "meeting",
+                    PruneAction::DeleteAudio => "audio",
+                };
+                let reason = match p.reason {
+                    PruneReason::Age => format!("older than {} days", retention.max_age_days),
+                    PruneReason::Size => "over the size limit".to_string(),
+                };
+                println!(
+                    "{}  {:<8} {:>9}  {} ({})",
+                    p.metadata.started_at.format("%Y-%m-%d"),
+                    action,
+                    format_size(p.freed),
+                    p.metadata.display_title(),
+                    reason
+                );
+            }
+            let freed: u64 = pruned.iter().map(|p| p.freed).sum();
+            println!();
+            println!(
+                "{} {} meeting(s), {} {}.",
+                if dry_run { "Would prune" } else { "Pruned" },
+                pruned.len(),
+                if dry_run { "freeing" } else { "freed" },
+                format_size(freed)
+            );
+        }
+
+        MeetingAction::Sync { meeting_id } => {
+            let client = meeting::sync::SyncClient::new(&config.meeting.sync).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Sync not configured. Set [meeting.sync] endpoint in config.toml:\n\n\
+                    [meeting.sync]\n\
+                    endpoint = \"https://meetings.example.com/api/meetings\""
+                )
+            })?;
+            let storage = meeting::MeetingStorage::open(meeting_config.storage.clone())
+                .map_err(|e| anyhow::anyhow!("Failed to open storage: {}", e))?;
+
+            if let Some(meeting_id) = meeting_id {
+                let id = storage
+                    .resolve_meeting_id(&meeting_id)
+                    .map_err(|e| anyhow::anyhow!("Meeting not found: {}", e))?;
+                let metadata = storage
+                    .get_meeting(&id)
+                    .map_err(|e| anyhow::anyhow!("Failed to load meeting: {}", e))?
+                    .ok_or_else(|| anyhow::anyhow!("Meeting not found: {}", meeting_id))?;
+                if matches!(
+                    metadata.status,
+                    meeting::MeetingStatus::Active | meeting::MeetingStatus::Paused
+                ) {
+                    anyhow::bail!(
+                        "Meeting {} is still being recorded. Sync it after 'voxtype meeting stop'.",
+                        meeting_id
+                    );
+                }
+
+                eprintln!("Uploading {}...", metadata.display_title());
+                let metadata = meeting::sync::sync_meeting(&storage, &client, &id)
+                    .map_err(|e| anyhow::anyhow!("Sync failed: {}", e))?;
+                println!(
+                    "Synced {} to {}",
+                    metadata.display_title(),
+                    client.meeting_url(&id)
+                );
+                return Ok(());
+            }
+
+            let synced = meeting::sync::sync_pending(&storage, &client)
+                .map_err(|e| anyhow::anyhow!("Failed to list meetings: {}", e))?;
+            if synced.is_empty() {
+                println!("All meetings are synced.");
+                return Ok(());
+            }
+            for s in &synced {
+                match s.result {
+                    Ok(()) => println!("Synced  {}", s.metadata.display_title()),
+                    Err(ref e) => println!("Failed  {}: {}", s.metadata.display_title(), e),
+                }
+            }
+            let failed = synced.iter().filter(|s| s.result.is_err()).count();
+            println!();
+            if failed > 0 {
+                anyhow::bail!(
+                    "{} of {} meeting(s) failed to sync. Run 'voxtype meeting sync' to retry.",
+                    failed,
+                    synced.len()
+                );
+            }
+            println!("Synced {} meeting(s).", synced.len());
+        }
+
         MeetingAction::Delete { meeting_id, force } => {
             if !force {
                 eprintln!("This will permanently delete the meeting and all associated files.");
@@ -409,6 +1083,7 @@ pub(crate) async fn run_meeting_command(
             storage
                 .set_speaker_label(&id, speaker_num, &label)
                 .map_err(|e| anyhow::anyhow!("Failed to set speaker label: {}", e))?;
+            mark_unsynced(&storage, &id)?;
 
             println!(
                 "Labeled SPEAKER_{:02} as '{}' in meeting {}",
@@ -416,24 +1091,38 @@ pub(crate) async fn run_meeting_command(
             );
         }
 
+        MeetingAction::Speakers { action } => {
+            let storage = meeting::MeetingStorage::open(meeting_config.storage.clone())
+                .map_err(|e| anyhow::anyhow!("Failed to open storage: {}", e))?;
+            run_speakers_command(config, &storage, action).await?;
+        }
+
         MeetingAction::Summarize {
             meeting_id,
             format,
             output,
         } => {
             // Load meeting
-            let meeting = meeting::get_meeting(&meeting_config, &meeting_id)
+            let mut meeting = meeting::get_meeting(&meeting_config, &meeting_id)
                 .map_err(|e| anyhow::anyhow!("Failed to load meeting: {}", e))?;
 
+            // Meetings that ended before chapters were enabled get them now
+            let finished = !matches!(
+                meeting.metadata.status,
+                meeting::MeetingStatus::Active | meeting::MeetingStatus::Paused
+            );
+            if config.meeting.chapters.is_enabled()
+                && finished
+                && meeting.transcript.chapters.is_empty()
+            {
+                if let Err(e) = save_chapters(config, &meeting_config.storage, &mut meeting) {
+                    eprintln!("Warning: {}", e);
+                }
+            }
+
             // Create summary config from meeting config
-            let summary_config = meeting::summary::SummaryConfig {
-                backend: config.meeting.summary.backend.clone(),
-                ollama_url: config.meeting.summary.ollama_url.clone(),
-                ollama_model: config.meeting.summary.ollama_model.clone(),
-                remote_endpoint: config.meeting.summary.remote_endpoint.clone(),
-                remote_api_key: config.meeting.summary.remote_api_key.clone(),
-                timeout_secs: config.meeting.summary.timeout_secs,
-            };
+            let summary_config =
+                meeting::summary::SummaryConfig::from_config(&config.meeting.summary);
 
             // Create summarizer
             let summarizer = meeting::summary::create_summarizer(&summary_config)
@@ -457,10 +1146,42 @@ pub(crate) async fn run_meeting_command(
 
             eprintln!("Generating summary using {}...", summarizer.name());
 
-            // Generate summary
-            let summary = summarizer
-                .summarize(&meeting)
-                .map_err(|e| anyhow::anyhow!("Summarization failed: {}", e))?;
+            // Generate summary, reusing the sections summarized while the
+            // meeting was running
+            let rolling = meeting
+                .metadata
+                .storage_path
+                .as_deref()
+                .and_then(meeting::summary::rolling::RollingSummary::load);
+            let mut summary = match rolling {
+                Some(mut rolling) => rolling.update(summarizer.as_ref(), &meeting).and_then(|_| {
+                    rolling
+                        .summary
+                        .ok_or(meeting::summary::SummaryError::EmptyTranscript)
+                }),
+                None => summarizer.summarize(&meeting),
+            }
+            .map_err(|e| anyhow::anyhow!("Summarization failed: {}", e))?;
+            summary.chapters = meeting.transcript.chapters.clone();
+
+            // Keep the summary with the meeting, for sync to upload
+            if let Some(dir) = meeting
+                .metadata
+                .storage_path
+                .as_deref()
+                .filter(|_| finished)
+            {
+                let saved = meeting::summary::save_summary(dir, &summary)
+                    .map_err(|e| anyhow::anyhow!("Failed to save summary: {}", e))
+                    .and_then(|_| {
+                        let storage = meeting::MeetingStorage::open(meeting_config.storage.clone())
+                            .map_err(|e| anyhow::anyhow!("Failed to open storage: {}", e))?;
+                        mark_unsynced(&storage, &meeting.metadata.id)
+                    });
+                if let Err(e) = saved {
+                    eprintln!("Warning: {}", e);
+                }
+            }
 
             // Format output
             let content = match format.as_str() {
@@ -496,6 +1217,18 @@ pub(crate) async fn run_meeting_command(
                         for decision in &summary.decisions {
                             text.push_str(&format!("  - {}\n", decision));
                         }
+                        text.push('\n');
+                    }
+
+                    if !summary.chapters.is_empty() {
+                        text.push_str("Chapters:\n");
+                        for chapter in &summary.chapters {
+                            text.push_str(&format!(
+                                "  [{}] {}\n",
+                                chapter.format_timestamp(),
+                                chapter.title
+                            ));
+                        }
                     }
 
                     text
@@ -510,8 +1243,203 @@ pub(crate) async fn run_meeting_command(
             } else {
                 println!("{}", content);
             }
+
+            let hooks = &config.meeting.hooks;
+            let event = meeting::hooks::HookEvent::Summarized;
+            if let Err(e) = meeting::hooks::run_hooks(hooks, event, &meeting, Some(&summary)).await
+            {
+                eprintln!("Warning: meeting hooks failed: {}", e);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Split a finished meeting into chapters with `[meeting.chapters]`, or
+/// with the similarity backend when chapters are disabled, and save them
+fn save_chapters(
+    config: &config::Config,
+    storage_config: &meeting::StorageConfig,
+    meeting: &mut meeting::MeetingData,
+) -> anyhow::Result<()> {
+    let mut chapters_config = config.meeting.chapters.clone();
+    if !chapters_config.is_enabled() {
+        chapters_config.backend = "similarity".to_string();
+    }
+    meeting.transcript.chapters =
+        meeting::chapters::generate(&chapters_config, &config.meeting.summary, meeting);
+
+    let storage = meeting::MeetingStorage::open(storage_config.clone())
+        .map_err(|e| anyhow::anyhow!("Failed to open storage: {}", e))?;
+    storage
+        .save_transcript(&meeting.metadata.id, &meeting.transcript)
+        .map_err(|e| anyhow::anyhow!("Failed to save chapters: {}", e))?;
+    mark_unsynced(&storage, &meeting.metadata.id)
+}
+
+/// Clear the `synced_at` of a meeting that changed, so sync uploads it again
+/// Open `text` in $VISUAL or $EDITOR (default vi). Returns what was saved
+/// and the file it was saved in.
+fn edit_in_editor(text: &str, id: &meeting::MeetingId) -> anyhow::Result<(String, PathBuf)> {
+    let path = std::env::temp_dir().join(format!("voxtype-correct-{}.txt", id));
+    std::fs::write(&path, text)
+        .map_err(|e| anyhow::anyhow!("Failed to write {}: {}", path.display(), e))?;
+
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .ok()
+        .filter(|e| !e.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+    // Through the shell, so editors with arguments ("code --wait") work
+    let status = std::process::Command::new("sh")
+        .arg("-c")
+        .arg(format!("{} \"$1\"", editor))
+        .arg("sh")
+        .arg(&path)
+        .status()
+        .map_err(|e| anyhow::anyhow!("Failed to run {}: {}", editor, e))?;
+    if !status.success() {
+        anyhow::bail!(
+            "{} exited with {}; no corrections saved (edits are in {})",
+            editor,
+            status,
+            path.display()
+        );
+    }
+
+    let edited = std::fs::read_to_string(&path)
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path.display(), e))?;
+    Ok((edited, path))
+}
+
+fn mark_unsynced(storage: &meeting::MeetingStorage, id: &meeting::MeetingId) -> anyhow::Result<()> {
+    let metadata = storage
+        .get_meeting(id)
+        .map_err(|e| anyhow::anyhow!("Failed to load meeting: {}", e))?;
+    if let Some(mut metadata) = metadata.filter(|m| m.synced_at.is_some()) {
+        metadata.synced_at = None;
+        storage
+            .update_meeting(&metadata)
+            .map_err(|e| anyhow::anyhow!("Failed to update meeting: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Run a `voxtype meeting speakers` action
+async fn run_speakers_command(
+    config: &config::Config,
+    storage: &meeting::MeetingStorage,
+    action: SpeakersAction,
+) -> anyhow::Result<()> {
+    use meeting::diarization::enrolled::{validate_name, EnrolledSpeaker};
+
+    match action {
+        SpeakersAction::Enroll {
+            name,
+            file,
+            duration,
+        } => {
+            let name = name.trim().to_string();
+            validate_name(&name).map_err(anyhow::Error::msg)?;
+
+            let (embedding, windows) = voice_print(config, file.as_deref(), duration).await?;
+            storage
+                .enroll_speaker(&EnrolledSpeaker {
+                    name: name.clone(),
+                    embedding,
+                    windows,
+                })
+                .map_err(|e| anyhow::anyhow!("Failed to save voice print: {}", e))?;
+
+            println!("Enrolled '{}' ({} voice windows).", name, windows);
+            if config.meeting.diarization.backend != "ml" {
+                println!();
+                println!("Enrolled speakers are recognized by the ml diarization backend. Set");
+                println!("  [meeting.diarization] backend = \"ml\"");
+                println!("or start meetings with 'voxtype meeting start --diarization ml'.");
+            }
+        }
+
+        SpeakersAction::List => {
+            let enrolled = storage
+                .list_enrolled_speakers()
+                .map_err(|e| anyhow::anyhow!("Failed to list enrolled speakers: {}", e))?;
+            if enrolled.is_empty() {
+                println!("No enrolled speakers.");
+                println!("Enroll one with 'voxtype meeting speakers enroll <name>'.");
+                return Ok(());
+            }
+
+            println!("Enrolled Speakers");
+            println!("=================\n");
+            for (speaker, enrolled_at) in enrolled {
+                println!(
+                    "{:<24} enrolled {} ({} voice windows)",
+                    speaker.name,
+                    enrolled_at
+                        .with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M"),
+                    speaker.windows
+                );
+            }
+        }
+
+        SpeakersAction::Remove { name } => {
+            let removed = storage
+                .remove_enrolled_speaker(&name)
+                .map_err(|e| anyhow::anyhow!("Failed to remove enrolled speaker: {}", e))?;
+            if !removed {
+                anyhow::bail!("No enrolled speaker named '{}'", name);
+            }
+            println!("Removed enrolled speaker '{}'", name);
         }
     }
 
     Ok(())
 }
+
+/// Voice print of a WAV file, or of `duration` seconds recorded from the
+/// microphone
+#[cfg(feature = "ml-diarization")]
+async fn voice_print(
+    config: &config::Config,
+    file: Option<&Path>,
+    duration: u64,
+) -> anyhow::Result<(Vec<f32>, u32)> {
+    use meeting::diarization::{ml::MlDiarizer, DiarizationConfig};
+
+    let diarization = &config.meeting.diarization;
+    let mut diarizer = MlDiarizer::new(&DiarizationConfig {
+        model_path: diarization.model_path.clone(),
+        vad_window_secs: diarization.vad_window_secs,
+        vad_hop_secs: diarization.vad_hop_secs,
+        vad_rms_floor: diarization.vad_rms_floor,
+        ..Default::default()
+    });
+    if !MlDiarizer::default_model_path().exists() {
+        setup::model::ensure_ecapa_model();
+    }
+    diarizer.load_model().map_err(anyhow::Error::msg)?;
+
+    let samples = match file {
+        Some(path) => super::transcribe_file::read_wav_16k_mono(path)?.1,
+        None => super::bench::record_sample(config, duration).await?,
+    };
+    diarizer.voice_print(&samples).map_err(anyhow::Error::msg)
+}
+
+/// Voice print of a WAV file, or of `duration` seconds recorded from the
+/// microphone
+#[cfg(not(feature = "ml-diarization"))]
+async fn voice_print(
+    _config: &config::Config,
+    _file: Option<&Path>,
+    _duration: u64,
+) -> anyhow::Result<(Vec<f32>, u32)> {
+    anyhow::bail!(
+        "Speaker enrollment needs the `ml-diarization` feature. ECAPA-TDNN voice prints \
+         are built into the ONNX binaries (voxtype-onnx-avx2, voxtype-onnx-avx512, \
+         voxtype-onnx-cuda-*, voxtype-onnx-migraphx)."
+    )
+}
@@ -4,8 +4,8 @@
 //!
 //! The rest of this module is organised by subcommand — each long handler
 //! lives in its own file (`record.rs`, `status.rs`, `meeting.rs`,
-//! `transcribe_file.rs`, `info.rs`, `config_show.rs`, `config_set_engine.rs`,
-//! `updates.rs`, `macos.rs`). Shared binary-side plumbing lives in
+//! `transcribe_file.rs`, `test_output.rs`, `info.rs`, `config_show.rs`,
+//! `config_set_engine.rs`, `updates.rs`, `macos.rs`). Shared binary-side plumbing lives in
 //! `dispatch.rs` (the top-level subcommand router), `overrides.rs` (CLI →
 //! Config layering), and `sigpipe.rs`. Cross-binary helpers like daemon
 //! liveness sit in the library at `voxtype::daemon_status`, so the TUI and
@@ -15,6 +15,7 @@
 use std::path::PathBuf;
 use voxtype::{config, Cli};
 
+mod bench;
 mod config_set_engine;
 mod config_show;
 mod dispatch;
@@ -26,6 +27,7 @@ mod overrides;
 mod record;
 pub(crate) mod sigpipe;
 mod status;
+mod test_output;
 mod transcribe_file;
 mod updates;
 
@@ -41,5 +43,8 @@ pub(crate) async fn run(
     mut config: config::Config,
 ) -> anyhow::Result<()> {
     let top_level_model = overrides::apply_cli_overrides(&mut config, &cli);
+    voxtype::transcribe::model_file::set_mmap_models(config.transcribe.mmap_models);
+    voxtype::setup::download::configure(&config.models);
+    voxtype::network::configure(&config.network);
     dispatch::dispatch(cli, config_path, config, top_level_model).await
 }
@@ -43,6 +43,15 @@ pub(crate) fn apply_cli_overrides(config: &mut config::Config, cli: &Cli) -> Opt
     if cli.paste {
         config.output.mode = config::OutputMode::Paste;
     }
+    if cli.primary {
+        config.output.mode = config::OutputMode::Primary;
+    }
+    if cli.tmux {
+        config.output.mode = config::OutputMode::Tmux;
+    }
+    if cli.webhook {
+        config.output.mode = config::OutputMode::Webhook;
+    }
     if cli.restore_clipboard {
         config.output.restore_clipboard = true;
     }
@@ -50,7 +59,9 @@ pub(crate) fn apply_cli_overrides(config: &mut config::Config, cli: &Cli) -> Opt
         config.output.restore_clipboard_delay_ms = delay;
     }
     if let Some(ref model) = cli.model {
-        if setup::model::is_valid_model(model) {
+        if config.select_custom_model(model) {
+            tracing::debug!("Using custom model '{}' ({})", model, config.engine.name());
+        } else if setup::model::is_known_whisper_model(model, &config.whisper) {
             config.whisper.model = model.clone();
         } else {
             let default_model = &config.whisper.model;
@@ -123,6 +134,12 @@ pub(crate) fn apply_cli_overrides(config: &mut config::Config, cli: &Cli) -> Opt
     if cli.gpu_isolation {
         config.whisper.gpu_isolation = true;
     }
+    if let Some(ttl) = cli.worker_idle_ttl {
+        config.whisper.worker_idle_ttl_secs = ttl;
+    }
+    if let Some(min_confidence) = cli.min_confidence {
+        config.transcribe.min_confidence = Some(min_confidence);
+    }
     if let Some(gpu_device) = cli.gpu_device {
         config.whisper.gpu_device = Some(gpu_device);
     }
@@ -137,9 +154,10 @@ pub(crate) fn apply_cli_overrides(config: &mut config::Config, cli: &Cli) -> Opt
             "local" => config.whisper.mode = Some(config::WhisperMode::Local),
             "remote" => config.whisper.mode = Some(config::WhisperMode::Remote),
             "cli" => config.whisper.mode = Some(config::WhisperMode::Cli),
+            "server" => config.whisper.mode = Some(config::WhisperMode::Server),
             _ => {
                 eprintln!(
-                    "Error: Invalid whisper mode '{}'. Valid options: local, remote, cli",
+                    "Error: Invalid whisper mode '{}'. Valid options: local, remote, cli, server",
                     mode
                 );
                 std::process::exit(1);
@@ -153,7 +171,8 @@ pub(crate) fn apply_cli_overrides(config: &mut config::Config, cli: &Cli) -> Opt
         config.whisper.eager_processing = true;
     }
     if let Some(ref endpoint) = cli.remote_endpoint {
-        config.whisper.remote_endpoint = Some(endpoint.clone());
+        config.whisper.remote_endpoint =
+            Some(config::RemoteEndpointConfig::from_comma_separated(endpoint));
     }
     if let Some(ref model) = cli.remote_model {
         config.whisper.remote_model = Some(model.clone());
@@ -230,6 +249,41 @@ pub(crate) fn apply_cli_overrides(config: &mut config::Config, cli: &Cli) -> Opt
     if cli.spoken_punctuation {
         config.text.spoken_punctuation = true;
     }
+    if cli.spoken_numbers {
+        config.text.spoken_numbers = true;
+    }
+    if cli.auto_capitalize {
+        config.text.auto_capitalize = true;
+    }
+    if cli.emoji {
+        config.text.emoji = true;
+    }
+    if let Some(ref mode) = cli.profanity {
+        match mode.to_lowercase().as_str() {
+            "off" => config.text.profanity = config::ProfanityMode::Off,
+            "mask" => config.text.profanity = config::ProfanityMode::Mask,
+            "remove" => config.text.profanity = config::ProfanityMode::Remove,
+            _ => {
+                eprintln!(
+                    "Error: Invalid profanity mode '{}'. Valid options: off, mask, remove",
+                    mode
+                );
+                std::process::exit(1);
+            }
+        }
+    }
+    if cli.spelling {
+        config.text.spelling = true;
+    }
+    if cli.code_modes {
+        config.text.code_modes = true;
+    }
+    if cli.markdown_commands {
+        config.text.markdown_commands = true;
+    }
+    if let Some(ref path) = cli.replacements_file {
+        config.text.replacements_file = Some(path.clone());
+    }
     apply_bool_override(
         &mut config.text.filter_filler_words,
         cli.filter_fillers,
@@ -296,9 +350,10 @@ pub(crate) fn apply_cli_overrides(config: &mut config::Config, cli: &Cli) -> Opt
             "auto" => config::VadBackend::Auto,
             "energy" => config::VadBackend::Energy,
             "whisper" => config::VadBackend::Whisper,
+            "silero" => config::VadBackend::Silero,
             _ => {
                 eprintln!(
-                    "Unknown VAD backend '{}'. Valid options: auto, energy, whisper",
+                    "Unknown VAD backend '{}'. Valid options: auto, energy, whisper, silero",
                     backend
                 );
                 std::process::exit(1);
@@ -1,11 +1,14 @@
-//! `voxtype record start|stop|toggle|cancel` — write override files for the
+//! `voxtype record start|stop|toggle|cancel`, `voxtype translate` and
+//! `voxtype undo` —
+//! write override files for the
 //! daemon and send the appropriate signal. The override files (model,
-//! output_mode, profile, smart_auto_submit, auto_submit, shift_enter_newlines)
+//! output_mode, profile, target, smart_auto_submit, auto_submit,
+//! shift_enter_newlines, refocus)
 //! are intentionally separate sentinels under `runtime_dir/`; merging them
 //! would invent write-race surface that doesn't exist today (see
 //! `docs/REFACTORING.md`).
 
-use voxtype::{config, daemon_status, RecordAction};
+use voxtype::{config, daemon_status, RecordAction, TranslateState};
 
 /// Send a record command to the running daemon via Unix signals or file triggers
 pub(crate) fn send_record_command(
@@ -37,6 +40,9 @@ pub(crate) fn send_record_command(
             OutputModeOverride::Type => "type".to_string(),
             OutputModeOverride::Clipboard => "clipboard".to_string(),
             OutputModeOverride::Paste => "paste".to_string(),
+            OutputModeOverride::Primary => "primary".to_string(),
+            OutputModeOverride::Tmux => "tmux".to_string(),
+            OutputModeOverride::Webhook => "webhook".to_string(),
             OutputModeOverride::File => {
                 // Check if explicit path was provided with --file=path
                 match action.file_path() {
@@ -96,6 +102,22 @@ pub(crate) fn send_record_command(
             .map_err(|e| anyhow::anyhow!("Failed to write profile override: {}", e))?;
     }
 
+    // Write target window override file if specified
+    if let Some(target) = action.target() {
+        if let Err(e) = voxtype::focus::WindowTarget::parse(target) {
+            eprintln!("Error: Invalid --target pattern '{}': {}", target, e);
+            std::process::exit(1);
+        }
+        let target_file = config::Config::runtime_dir().join("target_override");
+        std::fs::write(&target_file, target)
+            .map_err(|e| anyhow::anyhow!("Failed to write target override: {}", e))?;
+        if action.refocus() {
+            let override_file = config::Config::runtime_dir().join("refocus_override");
+            std::fs::write(&override_file, "true")
+                .map_err(|e| anyhow::anyhow!("Failed to write refocus override: {}", e))?;
+        }
+    }
+
     // Write auto_submit override file if specified
     if let Some(value) = action.auto_submit_override() {
         let override_file = config::Config::runtime_dir().join("auto_submit_override");
@@ -146,6 +168,14 @@ pub(crate) fn send_record_command(
             if active {
                 libc::SIGUSR2 // Stop
             } else {
+                // Toggled recordings are hands-free, so [vad]
+                // auto_stop_silence_ms applies as with the toggle hotkey
+                if config.vad.auto_stop_silence_ms > 0 {
+                    let override_file = config::Config::runtime_dir().join("auto_stop_override");
+                    std::fs::write(&override_file, "true").map_err(|e| {
+                        anyhow::anyhow!("Failed to write auto-stop override: {}", e)
+                    })?;
+                }
                 libc::SIGUSR1 // Start
             }
         }
@@ -162,3 +192,30 @@ pub(crate) fn send_record_command(
 
     Ok(())
 }
+
+/// Ask the running daemon to switch translate-to-English (file trigger,
+/// picked up the next time the daemon is idle)
+pub(crate) fn send_translate_command(state: Option<TranslateState>) -> anyhow::Result<()> {
+    daemon_status::check_daemon_running()?;
+
+    let request = match state.unwrap_or(TranslateState::Toggle) {
+        TranslateState::On => "on",
+        TranslateState::Off => "off",
+        TranslateState::Toggle => "toggle",
+    };
+    let trigger_file = config::Config::runtime_dir().join("translate");
+    std::fs::write(&trigger_file, request)
+        .map_err(|e| anyhow::anyhow!("Failed to write translate trigger: {}", e))?;
+    Ok(())
+}
+
+/// Ask the running daemon to remove the last typed dictation (file
+/// trigger, picked up the next time the daemon is idle)
+pub(crate) fn send_undo_command() -> anyhow::Result<()> {
+    daemon_status::check_daemon_running()?;
+
+    let trigger_file = config::Config::runtime_dir().join("undo");
+    std::fs::write(&trigger_file, "undo")
+        .map_err(|e| anyhow::anyhow!("Failed to write undo trigger: {}", e))?;
+    Ok(())
+}
@@ -0,0 +1,111 @@
+//! `voxtype test-output` — walk the type mode driver order, report which
+//! drivers are installed and available, print fixes for the rest and,
+//! with `--type`, type a sample through each available driver.
+//!
+//! The checks themselves live in `voxtype::output::diagnose` so they use
+//! the same driver construction as the daemon's output chain.
+
+use voxtype::config::Config;
+
+/// Run the output diagnostics
+#[cfg(not(target_os = "macos"))]
+pub(crate) async fn run_test_output(
+    config: &Config,
+    type_sample: bool,
+    delay_secs: u64,
+) -> anyhow::Result<()> {
+    use std::time::Duration;
+    use voxtype::config::OutputMode;
+    use voxtype::output::diagnose;
+    use voxtype::output::session::{self, DisplaySession};
+    use voxtype::setup::{print_failure, print_info, print_success, print_warning};
+
+    let output = &config.output;
+    let session = match session::detect() {
+        DisplaySession::Wayland => "Wayland",
+        DisplaySession::X11 => "X11",
+    };
+    println!("Output drivers ({} session):\n", session);
+    if output.mode != OutputMode::Type {
+        let mode = format!("{:?}", output.mode).to_lowercase();
+        print_info(&format!(
+            "Output mode is {}; these drivers are only used in type mode",
+            mode
+        ));
+        println!();
+    }
+
+    let mut checks = Vec::new();
+    for driver in diagnose::driver_order(output) {
+        let check = diagnose::check_driver(driver, output).await;
+        let label = match (check.tool, check.installed) {
+            (Some(tool), true) => format!("{:<13} ({})", driver.to_string(), tool),
+            (Some(tool), false) => format!("{:<13} ({} not installed)", driver.to_string(), tool),
+            (None, _) => driver.to_string(),
+        };
+        if check.available {
+            print_success(&label);
+        } else if check.installed {
+            print_warning(&format!("{}: not available", label));
+        } else {
+            print_failure(&label);
+        }
+        for fix in &check.fixes {
+            println!("      → {}", fix);
+        }
+        checks.push(check);
+    }
+
+    println!();
+    let available: Vec<_> = checks.iter().filter(|c| c.available).collect();
+    match available.first() {
+        Some(first) => println!("  \x1b[32m→\x1b[0m Text will be typed via {}", first.driver),
+        None => {
+            println!("  \x1b[31m→\x1b[0m No output driver is available!");
+            println!("    Apply one of the fixes above, or set driver_order in [output]");
+        }
+    }
+
+    if !type_sample || available.is_empty() {
+        if !type_sample && !available.is_empty() {
+            println!("\nRun `voxtype test-output --type` to type a sample with each driver.");
+        }
+        return Ok(());
+    }
+
+    println!(
+        "\nFocus a text field. Typing a sample with {} driver(s) in {} seconds...",
+        available.len(),
+        delay_secs
+    );
+    tokio::time::sleep(Duration::from_secs(delay_secs)).await;
+
+    println!();
+    for check in available {
+        let sample = format!("voxtype {} test. ", check.driver);
+        match diagnose::driver_output(check.driver, output)
+            .output(&sample)
+            .await
+        {
+            Ok(()) => print_success(&format!("{}: sent \"{}\"", check.driver, sample.trim())),
+            Err(e) => print_failure(&format!("{}: {}", check.driver, e)),
+        }
+        // Let each sample land before the next driver starts
+        tokio::time::sleep(Duration::from_millis(300)).await;
+    }
+    println!("\nA driver that reports success but typed nothing can't reach this window;");
+    println!("put a driver whose sample appeared first in driver_order.");
+
+    Ok(())
+}
+
+/// Run the output diagnostics
+#[cfg(target_os = "macos")]
+pub(crate) async fn run_test_output(
+    _config: &Config,
+    _type_sample: bool,
+    _delay_secs: u64,
+) -> anyhow::Result<()> {
+    println!("test-output checks the Linux driver chain; on macOS use `voxtype setup check`.");
+    Ok(())
+}
@@ -1,59 +1,28 @@
-//! `voxtype transcribe <file>` — one-shot transcription of an audio file.
+//! `voxtype transcribe <file>` — one-shot transcription of an audio file,
+//! and `voxtype transcribe --batch <dir>` for a directory of them.
 //!
 //! `resample` lives here rather than in `src/audio/` because it has exactly
 //! one call site (this command). Per the refactoring policy: don't extract
 //! an abstraction from a single use site.
 
-use std::path::PathBuf;
-use voxtype::{config, transcribe, vad};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use voxtype::transcribe::TimedSegment;
+use voxtype::{config, transcribe, vad, BatchFormat};
 
 /// Transcribe an audio file
 pub(crate) fn transcribe_file(config: &config::Config, path: &PathBuf) -> anyhow::Result<()> {
-    use hound::WavReader;
-
     println!("Loading audio file: {:?}", path);
 
-    let reader = WavReader::open(path)?;
-    let spec = reader.spec();
+    let (spec, final_samples) = read_wav_16k_mono(path)?;
 
     println!(
         "Audio format: {} Hz, {} channel(s), {:?}",
         spec.sample_rate, spec.channels, spec.sample_format
     );
-
-    // Convert samples to f32 mono at 16kHz
-    let samples: Vec<f32> = match spec.sample_format {
-        hound::SampleFormat::Int => {
-            let max_val = (1 << (spec.bits_per_sample - 1)) as f32;
-            reader
-                .into_samples::<i32>()
-                .filter_map(|s| s.ok())
-                .map(|s| s as f32 / max_val)
-                .collect()
-        }
-        hound::SampleFormat::Float => reader
-            .into_samples::<f32>()
-            .filter_map(|s| s.ok())
-            .collect(),
-    };
-
-    // Mix to mono if stereo
-    let mono_samples: Vec<f32> = if spec.channels > 1 {
-        samples
-            .chunks(spec.channels as usize)
-            .map(|chunk| chunk.iter().sum::<f32>() / chunk.len() as f32)
-            .collect()
-    } else {
-        samples
-    };
-
-    // Resample to 16kHz if needed
-    let final_samples = if spec.sample_rate != 16000 {
-        println!("Resampling from {} Hz to 16000 Hz...", spec.sample_rate);
-        resample(&mono_samples, spec.sample_rate, 16000)
-    } else {
-        mono_samples
-    };
+    if spec.sample_rate != 16000 {
+        println!("Resampled from {} Hz to 16000 Hz", spec.sample_rate);
+    }
 
     println!(
         "Processing {} samples ({:.2}s)...",
@@ -90,6 +59,250 @@ pub(crate) fn transcribe_file(config: &config::Config, path: &PathBuf) -> anyhow
     Ok(())
 }
 
+/// Transcribe every file in `dir` whose name matches `pattern`, writing one
+/// output file per input. The transcriber is created once and reused, so
+/// the model loads a single time for the whole batch.
+pub(crate) fn transcribe_batch(
+    config: &config::Config,
+    dir: &Path,
+    pattern: &str,
+    format: BatchFormat,
+    out_dir: Option<&Path>,
+) -> anyhow::Result<()> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map_err(|e| anyhow::anyhow!("Cannot read directory {:?}: {}", dir, e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|name| glob_match(pattern, name))
+        })
+        .collect();
+    files.sort();
+
+    if files.is_empty() {
+        println!("No files matching '{}' in {:?}", pattern, dir);
+        return Ok(());
+    }
+
+    if let Some(out_dir) = out_dir {
+        std::fs::create_dir_all(out_dir)?;
+    }
+
+    println!("Loading {} model...", config.engine.name());
+    let transcriber = transcribe::create_transcriber(config)?;
+    let vad = vad::create_vad(config).unwrap_or_else(|e| {
+        eprintln!("VAD warning: {}", e);
+        None
+    });
+
+    let total = files.len();
+    let mut failures = Vec::new();
+    for (index, path) in files.iter().enumerate() {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        print_progress(index, total, &name);
+
+        let output_path = out_dir
+            .unwrap_or_else(|| path.parent().unwrap_or(dir))
+            .join(
+                path.with_extension(format.extension())
+                    .file_name()
+                    .unwrap_or_default(),
+            );
+
+        let result = read_wav_16k_mono(path).and_then(|(_, samples)| {
+            let has_speech = match vad.as_ref().map(|v| v.detect(&samples)) {
+                Some(Ok(result)) => result.has_speech,
+                _ => true,
+            };
+            let segments = if has_speech {
+                transcriber.transcribe_timed(&samples)?
+            } else {
+                Vec::new()
+            };
+            let duration_secs = samples.len() as f32 / 16000.0;
+            std::fs::write(
+                &output_path,
+                render_batch_output(format, path, duration_secs, &segments),
+            )?;
+            Ok(())
+        });
+
+        if let Err(e) = result {
+            failures.push((path.clone(), e));
+        }
+    }
+    print_progress(total, total, "");
+    eprintln!();
+
+    println!(
+        "Transcribed {} of {} file(s)",
+        total - failures.len(),
+        total
+    );
+    for (path, e) in &failures {
+        eprintln!("  {:?}: {}", path, e);
+    }
+    if !failures.is_empty() {
+        anyhow::bail!("{} file(s) failed to transcribe", failures.len());
+    }
+    Ok(())
+}
+
+/// Draw a single-line progress bar on stderr
+fn print_progress(done: usize, total: usize, current: &str) {
+    const WIDTH: usize = 30;
+    let filled = WIDTH * done / total.max(1);
+    eprint!(
+        "\r\x1b[K[{}{}] {}/{} {}",
+        "#".repeat(filled),
+        "-".repeat(WIDTH - filled),
+        done,
+        total,
+        current
+    );
+    let _ = std::io::stderr().flush();
+}
+
+/// Render one file's transcript in the requested batch output format
+fn render_batch_output(
+    format: BatchFormat,
+    path: &Path,
+    duration_secs: f32,
+    segments: &[TimedSegment],
+) -> String {
+    let text = segments
+        .iter()
+        .map(|s| s.text.trim())
+        .filter(|t| !t.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    match format {
+        BatchFormat::Txt => format!("{}\n", text),
+        BatchFormat::Srt => {
+            let mut output = String::new();
+            for (index, segment) in (1..).zip(segments.iter().filter(|s| !s.text.trim().is_empty()))
+            {
+                output.push_str(&format!(
+                    "{}\n{} --> {}\n{}\n\n",
+                    index,
+                    format_srt_time(segment.start_secs),
+                    format_srt_time(segment.end_secs),
+                    segment.text.trim()
+                ));
+            }
+            output
+        }
+        BatchFormat::Json => {
+            let segments: Vec<_> = segments
+                .iter()
+                .map(|s| {
+                    serde_json::json!({
+                        "start": s.start_secs,
+                        "end": s.end_secs,
+                        "text": s.text.trim(),
+                    })
+                })
+                .collect();
+            let value = serde_json::json!({
+                "file": path.to_string_lossy(),
+                "duration_secs": duration_secs,
+                "text": text,
+                "segments": segments,
+            });
+            format!("{:#}\n", value)
+        }
+    }
+}
+
+/// Format seconds as an SRT timestamp (HH:MM:SS,mmm)
+fn format_srt_time(secs: f32) -> String {
+    let ms = (secs.max(0.0) * 1000.0).round() as u64;
+    let total_secs = ms / 1000;
+    format!(
+        "{:02}:{:02}:{:02},{:03}",
+        total_secs / 3600,
+        (total_secs % 3600) / 60,
+        total_secs % 60,
+        ms % 1000
+    )
+}
+
+/// Match a file name against a shell-style pattern with `*` and `?`
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    backtrack = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Read a WAV file and convert it to f32 mono samples at 16kHz
+pub(super) fn read_wav_16k_mono(path: &Path) -> anyhow::Result<(hound::WavSpec, Vec<f32>)> {
+    use hound::WavReader;
+
+    let reader = WavReader::open(path)?;
+    let spec = reader.spec();
+
+    // Convert samples to f32
+    let samples: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Int => {
+            let max_val = (1 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .into_samples::<i32>()
+                .filter_map(|s| s.ok())
+                .map(|s| s as f32 / max_val)
+                .collect()
+        }
+        hound::SampleFormat::Float => reader
+            .into_samples::<f32>()
+            .filter_map(|s| s.ok())
+            .collect(),
+    };
+
+    // Mix to mono if stereo
+    let mono_samples: Vec<f32> = if spec.channels > 1 {
+        samples
+            .chunks(spec.channels as usize)
+            .map(|chunk| chunk.iter().sum::<f32>() / chunk.len() as f32)
+            .collect()
+    } else {
+        samples
+    };
+
+    // Resample to 16kHz if needed
+    let final_samples = if spec.sample_rate != 16000 {
+        resample(&mono_samples, spec.sample_rate, 16000)
+    } else {
+        mono_samples
+    };
+
+    Ok((spec, final_samples))
+}
+
 /// Simple linear resampling
 fn resample(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
     if from_rate == to_rate {
@@ -116,3 +329,66 @@ fn resample(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
 
     output
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*.wav", "memo.wav"));
+        assert!(glob_match("*.wav", ".wav"));
+        assert!(!glob_match("*.wav", "memo.mp3"));
+        assert!(!glob_match("*.wav", "memo.wav.bak"));
+        assert!(glob_match("memo-??.wav", "memo-01.wav"));
+        assert!(!glob_match("memo-??.wav", "memo-1.wav"));
+        assert!(glob_match("*-*.wav", "2024-01-02.wav"));
+        assert!(glob_match("*", "anything"));
+    }
+
+    #[test]
+    fn test_format_srt_time() {
+        assert_eq!(format_srt_time(0.0), "00:00:00,000");
+        assert_eq!(format_srt_time(1.5), "00:00:01,500");
+        assert_eq!(format_srt_time(3661.25), "01:01:01,250");
+    }
+
+    #[test]
+    fn test_render_batch_output() {
+        let segments = vec![
+            TimedSegment {
+                text: " Hello there.".to_string(),
+                start_secs: 0.0,
+                end_secs: 1.2,
+            },
+            TimedSegment {
+                text: " General Kenobi.".to_string(),
+                start_secs: 1.2,
+                end_secs: 2.5,
+            },
+        ];
+        let path = Path::new("memo.wav");
+
+        assert_eq!(
+            render_batch_output(BatchFormat::Txt, path, 2.5, &segments),
+            "Hello there. General Kenobi.\n"
+        );
+        assert_eq!(
+            render_batch_output(BatchFormat::Srt, path, 2.5, &segments),
+            "1\n00:00:00,000 --> 00:00:01,200\nHello there.\n\n\
+             2\n00:00:01,200 --> 00:00:02,500\nGeneral Kenobi.\n\n"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&render_batch_output(
+            BatchFormat::Json,
+            path,
+            2.5,
+            &segments,
+        ))
+        .unwrap();
+        assert_eq!(json["file"], "memo.wav");
+        assert_eq!(json["text"], "Hello there. General Kenobi.");
+        assert_eq!(json["segments"].as_array().unwrap().len(), 2);
+        assert_eq!(json["segments"][1]["text"], "General Kenobi.");
+    }
+}
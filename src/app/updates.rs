@@ -15,7 +15,10 @@ pub(crate) async fn check_for_updates() -> anyhow::Result<()> {
     // allocation just to satisfy clippy on a one-shot startup-time call.
     #[allow(clippy::result_large_err)]
     let result = tokio::task::spawn_blocking(|| {
-        ureq::get("https://api.github.com/repos/peteonrails/voxtype/releases/latest")
+        let url = "https://api.github.com/repos/peteonrails/voxtype/releases/latest";
+        voxtype::network::agent_builder(url)
+            .build()
+            .get(url)
             .set("User-Agent", "voxtype-update-checker")
             .call()
     })
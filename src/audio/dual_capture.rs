@@ -6,6 +6,9 @@
 //! Mic capture uses cpal (ALSA). Loopback capture uses `parec` (PulseAudio
 //! recording client) which works with PipeWire's PulseAudio compatibility
 //! layer and can access monitor sources that aren't visible to ALSA.
+//!
+//! Several loopback sources (a browser on a headset and a native app on the
+//! speakers, say) each get a `parec` and are mixed into one remote channel.
 
 use super::cpal_capture::CpalCapture;
 use super::AudioCapture;
@@ -143,49 +146,141 @@ impl Drop for ParecLoopback {
     }
 }
 
+/// How far one loopback source may run ahead of the others before the
+/// others are taken to be silent (1 second at 16kHz)
+const MAX_LOOPBACK_LAG: usize = 16000;
+
+/// Mixes several loopback sources into one channel
+///
+/// The sources deliver samples at the same rate but in bursts of different
+/// sizes, so samples wait until every source has reached the same position.
+/// A source that falls more than [`MAX_LOOPBACK_LAG`] behind (a stalled
+/// `parec`) counts as silence rather than holding up the rest.
+#[derive(Debug, Default)]
+pub struct LoopbackMixer {
+    pending: Vec<Vec<f32>>,
+}
+
+impl LoopbackMixer {
+    pub fn new(sources: usize) -> Self {
+        Self {
+            pending: vec![Vec::new(); sources],
+        }
+    }
+
+    /// Queue samples from source `index`
+    pub fn push(&mut self, index: usize, samples: Vec<f32>) {
+        if let Some(pending) = self.pending.get_mut(index) {
+            pending.extend(samples);
+        }
+    }
+
+    /// Mix the samples every source has delivered. With `flush`, mix
+    /// everything queued, padding the shorter sources with silence.
+    pub fn take(&mut self, flush: bool) -> Vec<f32> {
+        if let [only] = self.pending.as_mut_slice() {
+            return std::mem::take(only);
+        }
+        let shortest = self.pending.iter().map(Vec::len).min().unwrap_or(0);
+        let longest = self.pending.iter().map(Vec::len).max().unwrap_or(0);
+        let n = if flush {
+            longest
+        } else {
+            shortest.max(longest.saturating_sub(MAX_LOOPBACK_LAG))
+        };
+
+        // Sum rather than average, so a source playing alone keeps its level
+        let mut mixed = vec![0.0f32; n];
+        for pending in &mut self.pending {
+            let take = n.min(pending.len());
+            for (out, sample) in mixed.iter_mut().zip(pending.drain(..take)) {
+                *out += sample;
+            }
+        }
+        for sample in &mut mixed {
+            *sample = sample.clamp(-1.0, 1.0);
+        }
+        mixed
+    }
+}
+
 /// Dual audio capture for mic + loopback
 pub struct DualCapture {
     /// Microphone capture (via cpal/ALSA)
     mic_capture: CpalCapture,
-    /// Loopback capture (via parec subprocess)
-    loopback: Option<ParecLoopback>,
+    /// Loopback captures (via parec subprocesses), mixed into one channel
+    loopbacks: Vec<ParecLoopback>,
+    mixer: LoopbackMixer,
     /// Sample counter for timestamps
     sample_counter: u64,
 }
 
 impl DualCapture {
-    /// Create a new dual capture instance
-    pub fn new(
-        mic_config: &AudioConfig,
-        loopback_device: Option<&str>,
-    ) -> Result<Self, AudioError> {
+    /// Create a new dual capture instance. Each loopback device is a source
+    /// name, "auto" (the monitor of the output in use), or "all" (the
+    /// monitors of every output); an empty list captures the mic only.
+    pub fn new(mic_config: &AudioConfig, loopback_devices: &[&str]) -> Result<Self, AudioError> {
         let mic_capture = CpalCapture::new(mic_config)?;
 
-        let loopback = match loopback_device {
-            Some("disabled") | Some("") | None => None,
-            Some("auto") => match Self::find_monitor_source() {
-                Some(source) => {
-                    tracing::info!("Auto-detected loopback source: {}", source);
-                    Some(ParecLoopback::new(source))
+        let mut sources: Vec<String> = Vec::new();
+        for device in loopback_devices {
+            let found = match *device {
+                "auto" => match Self::find_monitor_source() {
+                    Some(source) => {
+                        tracing::info!("Auto-detected loopback source: {}", source);
+                        vec![source]
+                    }
+                    None => {
+                        tracing::warn!("No monitor source found for loopback \"auto\"");
+                        Vec::new()
+                    }
+                },
+                "all" => {
+                    let monitors = Self::monitor_sources();
+                    if monitors.is_empty() {
+                        tracing::warn!("No monitor sources found for loopback \"all\"");
+                    }
+                    monitors
+                }
+                device => {
+                    tracing::info!("Using configured loopback source: {}", device);
+                    vec![device.to_string()]
                 }
-                None => {
-                    tracing::warn!("No monitor source found, using mic only");
-                    None
+            };
+            for source in found {
+                if !sources.contains(&source) {
+                    sources.push(source);
                 }
-            },
-            Some(device) => {
-                tracing::info!("Using configured loopback source: {}", device);
-                Some(ParecLoopback::new(device.to_string()))
             }
-        };
+        }
+        if sources.is_empty() && !loopback_devices.is_empty() {
+            tracing::warn!("No loopback source available, using mic only");
+        }
 
         Ok(Self {
             mic_capture,
-            loopback,
+            mixer: LoopbackMixer::new(sources.len()),
+            loopbacks: sources.into_iter().map(ParecLoopback::new).collect(),
             sample_counter: 0,
         })
     }
 
+    /// Every PipeWire/PulseAudio monitor source, one per output device
+    fn monitor_sources() -> Vec<String> {
+        let Ok(output) = std::process::Command::new("pactl")
+            .args(["list", "short", "sources"])
+            .output()
+        else {
+            return Vec::new();
+        };
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.split('\t').nth(1))
+            .filter(|name| name.contains(".monitor"))
+            .map(str::to_string)
+            .collect()
+    }
+
     /// Find a PipeWire/PulseAudio monitor source via pactl
     fn find_monitor_source() -> Option<String> {
         // pactl list short sources output format:
@@ -225,19 +320,35 @@ impl DualCapture {
 
     /// Check if loopback capture is active
     pub fn has_loopback(&self) -> bool {
-        self.loopback.is_some()
+        !self.loopbacks.is_empty()
+    }
+
+    /// Queue what every loopback source captured since the last call and
+    /// mix what lines up
+    fn mix_loopback(&mut self, flush: bool) -> Vec<f32> {
+        for (index, loopback) in self.loopbacks.iter().enumerate() {
+            self.mixer.push(index, loopback.get_samples());
+        }
+        self.mixer.take(flush)
     }
 
     /// Start both captures
     pub async fn start(&mut self) -> Result<(), AudioError> {
         let _mic_rx = self.mic_capture.start().await?;
 
-        if let Some(ref mut loopback) = self.loopback {
-            if let Err(e) = loopback.start() {
-                tracing::warn!("Loopback capture failed, continuing with mic only: {}", e);
-                self.loopback = None;
-            }
+        let configured = self.loopbacks.len();
+        self.loopbacks
+            .retain_mut(|loopback| match loopback.start() {
+                Ok(()) => true,
+                Err(e) => {
+                    tracing::warn!("Loopback capture of {} failed: {}", loopback.source, e);
+                    false
+                }
+            });
+        if configured > 0 && self.loopbacks.is_empty() {
+            tracing::warn!("Loopback capture failed, continuing with mic only");
         }
+        self.mixer = LoopbackMixer::new(self.loopbacks.len());
 
         Ok(())
     }
@@ -246,13 +357,10 @@ impl DualCapture {
     pub async fn stop(&mut self) -> Result<DualSamples, AudioError> {
         let mic_samples = self.mic_capture.stop().await?;
 
-        let loopback_samples = if let Some(ref mut loopback) = self.loopback {
-            let samples = loopback.get_samples();
+        for loopback in &mut self.loopbacks {
             loopback.stop();
-            samples
-        } else {
-            Vec::new()
-        };
+        }
+        let loopback_samples = self.mix_loopback(true);
 
         Ok(DualSamples {
             mic: mic_samples,
@@ -264,11 +372,7 @@ impl DualCapture {
     pub async fn get_samples(&mut self) -> DualSamples {
         let mic = self.mic_capture.get_samples().await;
 
-        let loopback = if let Some(ref loopback) = self.loopback {
-            loopback.get_samples()
-        } else {
-            Vec::new()
-        };
+        let loopback = self.mix_loopback(false);
 
         DualSamples { mic, loopback }
     }
@@ -378,6 +482,39 @@ mod tests {
         assert!((merged[0] - 1.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_loopback_mixer_single_source() {
+        let mut mixer = LoopbackMixer::new(1);
+        mixer.push(0, vec![0.1, 0.2, 0.3]);
+        assert_eq!(mixer.take(false), vec![0.1, 0.2, 0.3]);
+        assert!(mixer.take(false).is_empty());
+    }
+
+    #[test]
+    fn test_loopback_mixer_waits_for_every_source() {
+        let mut mixer = LoopbackMixer::new(2);
+        mixer.push(0, vec![0.5, 0.5, 0.5]);
+        mixer.push(1, vec![0.25]);
+        assert_eq!(mixer.take(false), vec![0.75]);
+
+        mixer.push(1, vec![0.25, 0.25, 0.9]);
+        assert_eq!(mixer.take(false), vec![0.75, 0.75]);
+
+        // What's left of the faster source comes out on flush, clamped
+        mixer.push(0, vec![0.5]);
+        assert_eq!(mixer.take(true), vec![1.0]);
+    }
+
+    #[test]
+    fn test_loopback_mixer_stalled_source() {
+        let mut mixer = LoopbackMixer::new(2);
+        mixer.push(0, vec![0.1; MAX_LOOPBACK_LAG + 100]);
+        // The second source delivered nothing for over a second
+        let mixed = mixer.take(false);
+        assert_eq!(mixed.len(), 100);
+        assert!((mixed[0] - 0.1).abs() < f32::EPSILON);
+    }
+
     #[test]
     fn test_dual_samples_is_empty() {
         let empty = DualSamples::default();
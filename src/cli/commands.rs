@@ -21,7 +21,29 @@ pub enum Commands {
     /// Transcribe an audio file (WAV, 16kHz, mono)
     Transcribe {
         /// Path to audio file
-        file: std::path::PathBuf,
+        #[arg(required_unless_present = "batch", conflicts_with = "batch")]
+        file: Option<std::path::PathBuf>,
+
+        /// Transcribe every matching file in a directory, loading the model once
+        #[arg(long, value_name = "DIR")]
+        batch: Option<std::path::PathBuf>,
+
+        /// File name pattern for --batch (`*` and `?` wildcards)
+        #[arg(
+            long,
+            value_name = "PATTERN",
+            default_value = "*.wav",
+            requires = "batch"
+        )]
+        glob: String,
+
+        /// Output format for --batch
+        #[arg(long, value_enum, default_value_t = BatchFormat::Txt)]
+        format: BatchFormat,
+
+        /// Directory for --batch output files (default: next to each input)
+        #[arg(long, value_name = "DIR")]
+        out_dir: Option<std::path::PathBuf>,
 
         /// Override transcription engine
         #[arg(
@@ -32,6 +54,33 @@ pub enum Commands {
         engine: Option<String>,
     },
 
+    /// Benchmark installed engines and models on the same audio
+    ///
+    /// Records a short sample from the microphone (or uses FILE), runs it
+    /// through every installed model, and prints load time, inference time,
+    /// real-time factor (inference time / audio length) and the transcript
+    /// for each. With --reference, also prints the word error rate.
+    Bench {
+        /// WAV file to use instead of recording
+        file: Option<std::path::PathBuf>,
+
+        /// Seconds to record when no file is given
+        #[arg(long, value_name = "SECS", default_value_t = 10)]
+        duration: u64,
+
+        /// Text file with the expected transcript, for word error rate
+        #[arg(long, value_name = "FILE")]
+        reference: Option<std::path::PathBuf>,
+
+        /// Only benchmark this engine (repeatable)
+        #[arg(long = "engine", value_name = "ENGINE")]
+        engines: Vec<String>,
+
+        /// Only benchmark this model (repeatable)
+        #[arg(long = "model", value_name = "NAME")]
+        models: Vec<String>,
+    },
+
     /// Internal: Worker process for GPU-isolated transcription
     /// Reads audio from stdin, writes transcription result to stdout
     #[command(hide = true)]
@@ -125,6 +174,24 @@ pub enum Commands {
         action: RecordAction,
     },
 
+    /// Switch Whisper's translate-to-English in the running daemon
+    ///
+    /// Without an argument the current setting is flipped. The daemon
+    /// confirms the new mode with a notification. The change lasts until
+    /// the daemon restarts; set `translate` in config.toml to persist it.
+    Translate {
+        /// Turn translation on or off instead of flipping it
+        #[arg(value_enum)]
+        state: Option<TranslateState>,
+    },
+
+    /// Remove the last dictation typed at the cursor
+    ///
+    /// Backspaces over exactly the text the daemon typed last, or presses
+    /// Ctrl+Z in applications listed in `[output] undo_ctrl_z_apps`. Does
+    /// nothing when the last output went to the clipboard or a file.
+    Undo,
+
     /// Meeting transcription mode
     ///
     /// Continuous meeting transcription with chunked processing,
@@ -134,6 +201,61 @@ pub enum Commands {
         action: MeetingAction,
     },
 
+    /// Check the output drivers and suggest fixes
+    ///
+    /// Walks the type mode driver order (`[output] driver_order`, or the
+    /// global --driver flag), reports which drivers are installed and
+    /// available, and prints fixes for the ones that aren't. With --type,
+    /// each available driver then types a short sample after a countdown,
+    /// so you can see which ones reach the focused text field.
+    TestOutput {
+        /// Type a sample with each available driver
+        #[arg(long = "type")]
+        type_sample: bool,
+
+        /// Seconds to wait before typing, to focus a text field
+        #[arg(
+            long,
+            value_name = "SECS",
+            default_value_t = 3,
+            requires = "type_sample"
+        )]
+        delay: u64,
+    },
+
     /// Check for updates
     CheckUpdate,
 }
+
+/// Output format for `voxtype transcribe --batch`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum BatchFormat {
+    /// Plain text transcript
+    Txt,
+    /// SubRip subtitles with segment timestamps
+    Srt,
+    /// JSON with text and timed segments
+    Json,
+}
+
+impl BatchFormat {
+    /// File extension for output files in this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            BatchFormat::Txt => "txt",
+            BatchFormat::Srt => "srt",
+            BatchFormat::Json => "json",
+        }
+    }
+}
+
+/// Target state for `voxtype translate`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TranslateState {
+    /// Translate speech to English
+    On,
+    /// Transcribe in the spoken language
+    Off,
+    /// Flip the current setting
+    Toggle,
+}
@@ -10,7 +10,8 @@ use super::DIARIZATION_BACKENDS;
 pub enum MeetingAction {
     /// Start a new meeting transcription
     Start {
-        /// Meeting title (optional)
+        /// Meeting title (optional). Without one, the title and attendees
+        /// come from the current calendar event if `[meeting.calendar]` is set
         #[arg(long, short)]
         title: Option<String>,
 
@@ -32,29 +33,129 @@ pub enum MeetingAction {
     Stop,
     /// Pause the current meeting
     Pause,
-    /// Resume a paused meeting
-    Resume,
+    /// Resume a paused meeting, or continue a stored one
+    ///
+    /// With a meeting ID (or "latest"), reopens that meeting and appends to
+    /// its transcript, for example after the daemon crashed mid-meeting.
+    Resume {
+        /// Meeting to continue (default: the paused meeting)
+        meeting_id: Option<String>,
+    },
+    /// Bookmark the current meeting at this moment
+    ///
+    /// Bookmarks appear in exports, in `meeting watch`, and in
+    /// `meeting play --list`; `meeting play --bookmark N` plays from one.
+    /// The [hotkey] meeting_mark_key adds one without a note.
+    Mark {
+        /// What the moment is about, e.g. "decision on pricing"
+        note: Option<String>,
+    },
     /// Show meeting status
     Status,
+    /// Show the calendar event a meeting started now would be named after
+    Calendar,
+    /// Watch the current meeting's transcript live
+    ///
+    /// Opens a terminal view of the running meeting: transcript segments
+    /// with speaker labels as each chunk is transcribed, the elapsed time,
+    /// and what the chunk processor is doing. Connecting mid-meeting shows
+    /// the transcript so far.
+    Watch,
+    /// Write live captions of the current meeting
+    ///
+    /// Each transcript segment becomes a caption as soon as its chunk is
+    /// transcribed, and meetings started later are captioned too. With
+    /// --output and the text format, the file always holds the latest
+    /// captions, for an OBS text source reading from a file. srt and vtt
+    /// append timed cues. Runs until the daemon stops; press Ctrl+C to quit.
+    Captions {
+        /// Caption format: text, srt, or vtt
+        #[arg(
+            long,
+            short,
+            value_parser = PossibleValuesParser::new(["text", "srt", "vtt"]),
+            default_value = "text"
+        )]
+        format: String,
+
+        /// Write captions to this file instead of stdout
+        #[arg(long, short)]
+        output: Option<std::path::PathBuf>,
+
+        /// Captions the text format keeps in the file
+        #[arg(long, default_value = "2")]
+        lines: usize,
+
+        /// Leave out speaker names
+        #[arg(long)]
+        no_speakers: bool,
+
+        /// For translated meetings: original, translation, or both
+        #[arg(
+            long,
+            value_parser = PossibleValuesParser::new(["original", "translation", "both"]),
+            default_value = "original"
+        )]
+        text: String,
+    },
     /// List past meetings
     List {
         /// Maximum number of meetings to show
         #[arg(long, short, default_value = "10")]
         limit: u32,
+
+        /// Only show meetings with this tag
+        #[arg(long)]
+        tag: Option<String>,
+    },
+    /// Search the transcripts of past meetings
+    ///
+    /// Finds segments containing all the given words (or their variants:
+    /// "deploy" also finds "deployed"). Quote a phrase to match it exactly
+    /// and end a word with * to match a prefix.
+    Search {
+        /// Words to search for
+        #[arg(required = true)]
+        query: Vec<String>,
+
+        /// Maximum number of matching segments to show
+        #[arg(long, short, default_value = "20")]
+        limit: u32,
+
+        /// Only search meetings with this tag
+        #[arg(long)]
+        tag: Option<String>,
     },
     /// Export a meeting transcript
     Export {
         /// Meeting ID (or "latest" for most recent)
         meeting_id: String,
 
-        /// Output format: text, markdown, json
+        /// Output format: text, markdown, json, srt, vtt, html, template
+        ///
+        /// html writes a page with speaker colors and clickable timestamps.
+        /// With retained audio it embeds a player, and --output copies the
+        /// audio next to the page so the two can be shared together.
+        ///
+        /// template renders a markdown note with front matter for Obsidian
+        /// or Logseq (see --template and [meeting.export]).
         #[arg(long, short, default_value = "markdown")]
         format: String,
 
-        /// Output file path (default: stdout)
+        /// Output file path (default: stdout, or --out-dir)
         #[arg(long, short)]
         output: Option<std::path::PathBuf>,
 
+        /// Directory to write the export into, named after the meeting
+        /// (default: [meeting.export] out_dir for the template format)
+        #[arg(long, value_name = "DIR")]
+        out_dir: Option<std::path::PathBuf>,
+
+        /// Template for the template format: obsidian, logseq, or a file path
+        /// (default: [meeting.export] template)
+        #[arg(long, value_name = "NAME|PATH")]
+        template: Option<String>,
+
         /// Include timestamps in output
         #[arg(long)]
         timestamps: bool,
@@ -66,12 +167,143 @@ pub enum MeetingAction {
         /// Include metadata header in output
         #[arg(long)]
         metadata: bool,
+
+        /// Segment text to export when segments were translated
+        /// ([meeting.translation]): the original, the translation, or both
+        #[arg(
+            long,
+            value_parser = PossibleValuesParser::new(["original", "translation", "both"]),
+            default_value = "original"
+        )]
+        text: String,
+    },
+    /// Play a meeting's retained audio from a point in the transcript
+    ///
+    /// Needs audio kept with [meeting] retain_audio = true. Plays to the
+    /// end of the meeting; press Ctrl+C to stop.
+    Play {
+        /// Meeting ID (or "latest" for most recent)
+        meeting_id: String,
+
+        /// Start at a meeting time: HH:MM:SS, MM:SS or seconds
+        #[arg(long, value_name = "TIME", conflicts_with = "segment")]
+        from: Option<String>,
+
+        /// Start at transcript segment N, as numbered by --list
+        #[arg(long, short, value_name = "N", conflicts_with = "bookmark")]
+        segment: Option<usize>,
+
+        /// Start at bookmark N, as numbered by --list
+        #[arg(long, short, value_name = "N", conflicts_with = "from")]
+        bookmark: Option<usize>,
+
+        /// List the numbered transcript segments and bookmarks instead of
+        /// playing
+        #[arg(long, short, conflicts_with_all = ["from", "segment", "bookmark"])]
+        list: bool,
     },
     /// Show meeting details
     Show {
         /// Meeting ID (or "latest" for most recent)
         meeting_id: String,
     },
+    /// Edit a meeting's title, tags, or participants
+    ///
+    /// Only the given fields change. Lists are comma-separated and replace
+    /// the current ones; pass an empty value (--tags "") to clear a field.
+    Edit {
+        /// Meeting ID (or "latest" for most recent)
+        meeting_id: String,
+
+        /// New title
+        #[arg(long)]
+        title: Option<String>,
+
+        /// Tags, e.g. --tags planning,q3
+        #[arg(long, value_delimiter = ',')]
+        tags: Option<Vec<String>>,
+
+        /// Participants, e.g. --participants "Alice,Bob"
+        #[arg(long, value_delimiter = ',')]
+        participants: Option<Vec<String>>,
+    },
+    /// Correct a meeting's transcript
+    ///
+    /// Opens the transcript in $VISUAL or $EDITOR, one block per segment
+    /// numbered as in `meeting play --list`. Fix the text and save. With
+    /// --file, applies a file in the same format that lists only the
+    /// segments to change. Corrections are kept as revisions next to the
+    /// transcribed text, and exports and search use the corrected text.
+    Correct {
+        /// Meeting ID (or "latest" for most recent)
+        meeting_id: String,
+
+        /// Apply corrections from this file instead of opening an editor
+        #[arg(long, short, value_name = "PATH", conflicts_with = "revert")]
+        file: Option<std::path::PathBuf>,
+
+        /// Restore the transcribed text of every corrected segment
+        #[arg(long)]
+        revert: bool,
+    },
+    /// Show how long each speaker talked
+    ///
+    /// Talk time, share, words, turns, and how often each speaker cut in
+    /// on someone else or was cut off. Speakers are told apart by
+    /// diarization, or by microphone and system audio without it.
+    Stats {
+        /// Meeting ID (or "latest" for most recent)
+        meeting_id: String,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show a meeting's chapters
+    ///
+    /// Chapters are found when a meeting ends with [meeting.chapters]
+    /// enabled. For other meetings they are found now, with keyword titles
+    /// unless backend = "llm".
+    Chapters {
+        /// Meeting ID (or "latest" for most recent)
+        meeting_id: String,
+
+        /// Find the chapters again, replacing the stored ones
+        #[arg(long)]
+        regenerate: bool,
+    },
+    /// Prune old meetings under [meeting.retention]
+    ///
+    /// Deletes meetings older than max_age_days, then the oldest meetings
+    /// until all fit in max_total_size_mb. With keep_summaries, only their
+    /// audio is deleted. The daemon also prunes on its own while limits are
+    /// set. Flags override the config for this run.
+    Prune {
+        /// Show what would be pruned without deleting anything
+        #[arg(long, short = 'n')]
+        dry_run: bool,
+
+        /// Prune meetings older than this many days
+        #[arg(long, value_name = "DAYS")]
+        max_age_days: Option<u32>,
+
+        /// Prune the oldest meetings while all take more than this many MB
+        #[arg(long, value_name = "MB")]
+        max_total_size_mb: Option<u64>,
+
+        /// Delete only audio, keeping transcripts and summaries
+        #[arg(long)]
+        keep_summaries: bool,
+    },
+    /// Upload meetings to the [meeting.sync] endpoint
+    ///
+    /// Without a meeting ID, uploads every finished meeting that hasn't
+    /// synced since it last changed. With one, uploads that meeting even
+    /// if it has. The daemon also uploads meetings as they end.
+    Sync {
+        /// Meeting ID (or "latest" for most recent)
+        meeting_id: Option<String>,
+    },
     /// Delete a meeting
     Delete {
         /// Meeting ID
@@ -95,6 +327,15 @@ pub enum MeetingAction {
         /// Human-readable label to assign
         label: String,
     },
+    /// Manage enrolled speakers
+    ///
+    /// Enrolled speakers are recognized by the ml diarization backend in
+    /// every meeting, so transcripts show their names instead of
+    /// SPEAKER_00, SPEAKER_01, ...
+    Speakers {
+        #[command(subcommand)]
+        action: SpeakersAction,
+    },
     /// Generate an AI summary of a meeting
     ///
     /// Uses Ollama or a remote API to generate a summary with
@@ -113,6 +354,40 @@ pub enum MeetingAction {
     },
 }
 
+/// Enrolled speaker actions
+#[derive(Subcommand)]
+pub enum SpeakersAction {
+    /// Record a voice sample and enroll it under a name
+    ///
+    /// Speak naturally and continuously while recording; 20-30 seconds of
+    /// speech gives a reliable voice print. Enrolling an existing name
+    /// replaces its voice print. Requires the ml-diarization feature.
+    Enroll {
+        /// Name to show in transcripts
+        name: String,
+
+        /// Use a WAV file of the speaker instead of recording
+        #[arg(long, value_name = "FILE")]
+        file: Option<std::path::PathBuf>,
+
+        /// Seconds to record from the microphone
+        #[arg(
+            long,
+            value_name = "SECS",
+            default_value_t = 20,
+            conflicts_with = "file"
+        )]
+        duration: u64,
+    },
+    /// List enrolled speakers
+    List,
+    /// Remove an enrolled speaker
+    Remove {
+        /// Name of the enrolled speaker
+        name: String,
+    },
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,4 +472,356 @@ mod tests {
             _ => panic!("Expected Meeting Start command"),
         }
     }
+
+    #[test]
+    fn test_meeting_search() {
+        let cli = Cli::parse_from([
+            "voxtype",
+            "meeting",
+            "search",
+            "billing",
+            "\"next week\"",
+            "-l",
+            "5",
+        ]);
+        match cli.command {
+            Some(Commands::Meeting {
+                action: MeetingAction::Search { query, limit, tag },
+            }) => {
+                assert_eq!(query, ["billing", "\"next week\""]);
+                assert_eq!(limit, 5);
+                assert_eq!(tag, None);
+            }
+            _ => panic!("Expected Meeting Search command"),
+        }
+
+        assert!(Cli::try_parse_from(["voxtype", "meeting", "search"]).is_err());
+    }
+
+    #[test]
+    fn test_meeting_edit() {
+        let cli = Cli::parse_from([
+            "voxtype",
+            "meeting",
+            "edit",
+            "latest",
+            "--title",
+            "Roadmap",
+            "--tags",
+            "planning,q3",
+        ]);
+        match cli.command {
+            Some(Commands::Meeting {
+                action:
+                    MeetingAction::Edit {
+                        meeting_id,
+                        title,
+                        tags,
+                        participants,
+                    },
+            }) => {
+                assert_eq!(meeting_id, "latest");
+                assert_eq!(title.as_deref(), Some("Roadmap"));
+                assert_eq!(tags, Some(vec!["planning".to_string(), "q3".to_string()]));
+                assert_eq!(participants, None);
+            }
+            _ => panic!("Expected Meeting Edit command"),
+        }
+
+        let cli = Cli::parse_from(["voxtype", "meeting", "list", "--tag", "q3"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Meeting {
+                action: MeetingAction::List { tag: Some(ref tag), .. },
+            }) if tag == "q3"
+        ));
+    }
+
+    #[test]
+    fn test_meeting_stats() {
+        let cli = Cli::parse_from(["voxtype", "meeting", "stats", "latest", "--json"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Meeting {
+                action: MeetingAction::Stats { ref meeting_id, json: true },
+            }) if meeting_id == "latest"
+        ));
+    }
+
+    #[test]
+    fn test_meeting_prune() {
+        let cli = Cli::parse_from([
+            "voxtype",
+            "meeting",
+            "prune",
+            "--dry-run",
+            "--max-age-days",
+            "90",
+            "--keep-summaries",
+        ]);
+        match cli.command {
+            Some(Commands::Meeting {
+                action:
+                    MeetingAction::Prune {
+                        dry_run,
+                        max_age_days,
+                        max_total_size_mb,
+                        keep_summaries,
+                    },
+            }) => {
+                assert!(dry_run);
+                assert_eq!(max_age_days, Some(90));
+                assert_eq!(max_total_size_mb, None);
+                assert!(keep_summaries);
+            }
+            _ => panic!("Expected Meeting Prune command"),
+        }
+    }
+
+    #[test]
+    fn test_meeting_sync() {
+        let cli = Cli::parse_from(["voxtype", "meeting", "sync"]);
+        match cli.command {
+            Some(Commands::Meeting {
+                action: MeetingAction::Sync { meeting_id },
+            }) => assert_eq!(meeting_id, None),
+            _ => panic!("Expected Meeting Sync command"),
+        }
+
+        let cli = Cli::parse_from(["voxtype", "meeting", "sync", "latest"]);
+        match cli.command {
+            Some(Commands::Meeting {
+                action: MeetingAction::Sync { meeting_id },
+            }) => assert_eq!(meeting_id.as_deref(), Some("latest")),
+            _ => panic!("Expected Meeting Sync command"),
+        }
+    }
+
+    #[test]
+    fn test_meeting_chapters() {
+        let cli = Cli::parse_from(["voxtype", "meeting", "chapters", "latest", "--regenerate"]);
+        match cli.command {
+            Some(Commands::Meeting {
+                action:
+                    MeetingAction::Chapters {
+                        meeting_id,
+                        regenerate,
+                    },
+            }) => {
+                assert_eq!(meeting_id, "latest");
+                assert!(regenerate);
+            }
+            _ => panic!("Expected Meeting Chapters command"),
+        }
+    }
+
+    #[test]
+    fn test_meeting_play() {
+        let cli = Cli::parse_from(["voxtype", "meeting", "play", "latest", "--from", "12:30"]);
+        match cli.command {
+            Some(Commands::Meeting {
+                action:
+                    MeetingAction::Play {
+                        meeting_id,
+                        from,
+                        segment,
+                        bookmark,
+                        list,
+                    },
+            }) => {
+                assert_eq!(meeting_id, "latest");
+                assert_eq!(from.as_deref(), Some("12:30"));
+                assert_eq!(segment, None);
+                assert_eq!(bookmark, None);
+                assert!(!list);
+            }
+            _ => panic!("Expected Meeting Play command"),
+        }
+
+        let cli = Cli::parse_from(["voxtype", "meeting", "play", "latest", "-s", "4"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Meeting {
+                action: MeetingAction::Play {
+                    segment: Some(4),
+                    ..
+                },
+            })
+        ));
+
+        assert!(Cli::try_parse_from([
+            "voxtype",
+            "meeting",
+            "play",
+            "latest",
+            "--from",
+            "1:00",
+            "--segment",
+            "2"
+        ])
+        .is_err());
+
+        let cli = Cli::parse_from(["voxtype", "meeting", "play", "latest", "-b", "2"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Meeting {
+                action: MeetingAction::Play {
+                    bookmark: Some(2),
+                    ..
+                },
+            })
+        ));
+        assert!(Cli::try_parse_from([
+            "voxtype",
+            "meeting",
+            "play",
+            "latest",
+            "--segment",
+            "1",
+            "--bookmark",
+            "2"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_meeting_mark() {
+        let cli = Cli::parse_from(["voxtype", "meeting", "mark", "decision on pricing"]);
+        match cli.command {
+            Some(Commands::Meeting {
+                action: MeetingAction::Mark { note },
+            }) => assert_eq!(note.as_deref(), Some("decision on pricing")),
+            _ => panic!("Expected Meeting Mark command"),
+        }
+
+        let cli = Cli::parse_from(["voxtype", "meeting", "mark"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Meeting {
+                action: MeetingAction::Mark { note: None },
+            })
+        ));
+    }
+
+    #[test]
+    fn test_meeting_captions() {
+        let cli = Cli::parse_from([
+            "voxtype",
+            "meeting",
+            "captions",
+            "--format",
+            "srt",
+            "-o",
+            "/tmp/live.srt",
+            "--no-speakers",
+        ]);
+        match cli.command {
+            Some(Commands::Meeting {
+                action:
+                    MeetingAction::Captions {
+                        format,
+                        output,
+                        lines,
+                        no_speakers,
+                        text,
+                    },
+            }) => {
+                assert_eq!(format, "srt");
+                assert_eq!(output, Some(std::path::PathBuf::from("/tmp/live.srt")));
+                assert_eq!(lines, 2);
+                assert!(no_speakers);
+                assert_eq!(text, "original");
+            }
+            _ => panic!("Expected Meeting Captions command"),
+        }
+
+        assert!(Cli::try_parse_from(["voxtype", "meeting", "captions", "-f", "html"]).is_err());
+    }
+
+    #[test]
+    fn test_meeting_correct() {
+        let cli = Cli::parse_from([
+            "voxtype",
+            "meeting",
+            "correct",
+            "latest",
+            "--file",
+            "fixes.txt",
+        ]);
+        match cli.command {
+            Some(Commands::Meeting {
+                action:
+                    MeetingAction::Correct {
+                        meeting_id,
+                        file,
+                        revert,
+                    },
+            }) => {
+                assert_eq!(meeting_id, "latest");
+                assert_eq!(file, Some(std::path::PathBuf::from("fixes.txt")));
+                assert!(!revert);
+            }
+            _ => panic!("Expected Meeting Correct command"),
+        }
+
+        assert!(Cli::try_parse_from([
+            "voxtype",
+            "meeting",
+            "correct",
+            "latest",
+            "-f",
+            "fixes.txt",
+            "--revert"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_meeting_speakers_enroll() {
+        let cli = Cli::parse_from(["voxtype", "meeting", "speakers", "enroll", "Alice"]);
+        match cli.command {
+            Some(Commands::Meeting {
+                action:
+                    MeetingAction::Speakers {
+                        action:
+                            SpeakersAction::Enroll {
+                                name,
+                                file,
+                                duration,
+                            },
+                    },
+            }) => {
+                assert_eq!(name, "Alice");
+                assert_eq!(file, None);
+                assert_eq!(duration, 20);
+            }
+            _ => panic!("Expected Meeting Speakers Enroll command"),
+        }
+
+        let cli = Cli::parse_from([
+            "voxtype", "meeting", "speakers", "enroll", "Bob", "--file", "bob.wav",
+        ]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Meeting {
+                action: MeetingAction::Speakers {
+                    action: SpeakersAction::Enroll { file: Some(_), .. }
+                }
+            })
+        ));
+
+        // Recording length makes no sense with a file
+        assert!(Cli::try_parse_from([
+            "voxtype",
+            "meeting",
+            "speakers",
+            "enroll",
+            "Bob",
+            "--file",
+            "bob.wav",
+            "--duration",
+            "10",
+        ])
+        .is_err());
+    }
 }
@@ -13,10 +13,10 @@ mod record;
 mod root;
 mod setup;
 
-pub use commands::Commands;
+pub use commands::{BatchFormat, Commands, TranslateState};
 pub use config::{ConfigAction, ConfigSetKey};
 pub use info::InfoAction;
-pub use meeting::MeetingAction;
+pub use meeting::{MeetingAction, SpeakersAction};
 pub use record::{OutputModeOverride, RecordAction};
 pub use root::Cli;
 pub use setup::{CompositorType, SetupAction};
@@ -32,7 +32,7 @@ pub use setup::{CompositorType, SetupAction};
 /// `src/config/engines/mod.rs` so a new engine variant forces this string
 /// to update or the build breaks.
 pub const ENGINE_NAMES_CSV: &str =
-    "whisper, parakeet, moonshine, sensevoice, paraformer, dolphin, omnilingual, cohere, soniox";
+    "whisper, parakeet, moonshine, sensevoice, paraformer, dolphin, omnilingual, cohere, soniox, vosk, zipformer";
 
 /// Diarization backends the daemon dispatches on. Used by the CLI's
 /// `value_parser` for `--diarization` so unknown values are rejected at
@@ -42,3 +42,10 @@ pub const ENGINE_NAMES_CSV: &str =
 /// `match backend.as_str()` block; a test in `src/config/meeting.rs` pins
 /// this list against those arms.
 pub(crate) const DIARIZATION_BACKENDS: &[&str] = &["simple", "ml"];
+
+/// Pretrained wake words `voxtype setup wake-word --model` downloads.
+///
+/// Must match `crate::wake_word::PRETRAINED_MODELS`; this module is also
+/// compiled into build.rs, so it can't import it. A test in
+/// `src/wake_word/mod.rs` pins the two lists together.
+pub(crate) const WAKE_WORD_MODELS: &[&str] = &["alexa", "hey_jarvis", "hey_mycroft", "hey_rhasspy"];
@@ -9,6 +9,9 @@ pub enum OutputModeOverride {
     Clipboard,
     Paste,
     File,
+    Primary,
+    Tmux,
+    Webhook,
 }
 
 #[derive(Subcommand)]
@@ -27,6 +30,18 @@ pub enum RecordAction {
         #[arg(long, group = "output_mode")]
         paste: bool,
 
+        /// Override output mode to the primary selection (middle-click paste)
+        #[arg(long, group = "output_mode")]
+        primary: bool,
+
+        /// Override output mode to a tmux paste buffer
+        #[arg(long, group = "output_mode")]
+        tmux: bool,
+
+        /// Override output mode to the configured webhook
+        #[arg(long, group = "output_mode")]
+        webhook: bool,
+
         /// Write transcription to a file
         /// Use --file alone to use file_path from config, or --file=path.txt for explicit path
         #[arg(long, value_name = "FILE", group = "output_mode", num_args = 0..=1, default_missing_value = "")]
@@ -41,6 +56,15 @@ pub enum RecordAction {
         #[arg(long, value_name = "NAME")]
         profile: Option<String>,
 
+        /// Focus this window before typing, e.g. "class:obsidian" or
+        /// "title:Inbox" (case-insensitive regex; Hyprland and Sway)
+        #[arg(long, value_name = "PATTERN")]
+        target: Option<String>,
+
+        /// Return focus to the previous window after typing into --target
+        #[arg(long, requires = "target")]
+        refocus: bool,
+
         /// Auto-submit (press Enter) after this transcription
         #[arg(long)]
         auto_submit: bool,
@@ -78,6 +102,18 @@ pub enum RecordAction {
         /// Override output mode to paste (clipboard + Ctrl+V)
         #[arg(long, group = "output_mode")]
         paste: bool,
+
+        /// Override output mode to the primary selection (middle-click paste)
+        #[arg(long, group = "output_mode")]
+        primary: bool,
+
+        /// Override output mode to a tmux paste buffer
+        #[arg(long, group = "output_mode")]
+        tmux: bool,
+
+        /// Override output mode to the configured webhook
+        #[arg(long, group = "output_mode")]
+        webhook: bool,
     },
     /// Toggle recording state
     Toggle {
@@ -93,6 +129,18 @@ pub enum RecordAction {
         #[arg(long, group = "output_mode")]
         paste: bool,
 
+        /// Override output mode to the primary selection (middle-click paste)
+        #[arg(long, group = "output_mode")]
+        primary: bool,
+
+        /// Override output mode to a tmux paste buffer
+        #[arg(long, group = "output_mode")]
+        tmux: bool,
+
+        /// Override output mode to the configured webhook
+        #[arg(long, group = "output_mode")]
+        webhook: bool,
+
         /// Write transcription to a file
         /// Use --file alone to use file_path from config, or --file=path.txt for explicit path
         #[arg(long, value_name = "FILE", group = "output_mode", num_args = 0..=1, default_missing_value = "")]
@@ -107,6 +155,15 @@ pub enum RecordAction {
         #[arg(long, value_name = "NAME")]
         profile: Option<String>,
 
+        /// Focus this window before typing, e.g. "class:obsidian" or
+        /// "title:Inbox" (case-insensitive regex; Hyprland and Sway)
+        #[arg(long, value_name = "PATTERN")]
+        target: Option<String>,
+
+        /// Return focus to the previous window after typing into --target
+        #[arg(long, requires = "target")]
+        refocus: bool,
+
         /// Auto-submit (press Enter) after this transcription
         #[arg(long)]
         auto_submit: bool,
@@ -152,11 +209,14 @@ impl RecordAction {
     /// Extract the output mode override from the action flags
     /// Returns (mode_override, optional_file_path)
     pub fn output_mode_override(&self) -> Option<OutputModeOverride> {
-        let (type_mode, clipboard, paste, file) = match self {
+        let (type_mode, clipboard, paste, primary, tmux, webhook, file) = match self {
             RecordAction::Start {
                 type_mode,
                 clipboard,
                 paste,
+                primary,
+                tmux,
+                webhook,
                 file,
                 ..
             }
@@ -164,14 +224,30 @@ impl RecordAction {
                 type_mode,
                 clipboard,
                 paste,
+                primary,
+                tmux,
+                webhook,
                 file,
                 ..
-            } => (*type_mode, *clipboard, *paste, file.as_ref()),
+            } => (
+                *type_mode,
+                *clipboard,
+                *paste,
+                *primary,
+                *tmux,
+                *webhook,
+                file.as_ref(),
+            ),
             RecordAction::Stop {
                 type_mode,
                 clipboard,
                 paste,
-            } => (*type_mode, *clipboard, *paste, None),
+                primary,
+                tmux,
+                webhook,
+            } => (
+                *type_mode, *clipboard, *paste, *primary, *tmux, *webhook, None,
+            ),
             RecordAction::Cancel => return None,
         };
 
@@ -181,6 +257,12 @@ impl RecordAction {
             Some(OutputModeOverride::Clipboard)
         } else if paste {
             Some(OutputModeOverride::Paste)
+        } else if primary {
+            Some(OutputModeOverride::Primary)
+        } else if tmux {
+            Some(OutputModeOverride::Tmux)
+        } else if webhook {
+            Some(OutputModeOverride::Webhook)
         } else if file.is_some() {
             Some(OutputModeOverride::File)
         } else {
@@ -221,6 +303,24 @@ impl RecordAction {
         }
     }
 
+    /// Get the window pattern from --target flag
+    pub fn target(&self) -> Option<&str> {
+        match self {
+            RecordAction::Start { target, .. } | RecordAction::Toggle { target, .. } => {
+                target.as_deref()
+            }
+            RecordAction::Stop { .. } | RecordAction::Cancel => None,
+        }
+    }
+
+    /// Whether --refocus was given
+    pub fn refocus(&self) -> bool {
+        match self {
+            RecordAction::Start { refocus, .. } | RecordAction::Toggle { refocus, .. } => *refocus,
+            RecordAction::Stop { .. } | RecordAction::Cancel => false,
+        }
+    }
+
     /// Get the auto_submit override from --auto-submit / --no-auto-submit flags
     /// Returns Some(true) for --auto-submit, Some(false) for --no-auto-submit, None if unset
     pub fn auto_submit_override(&self) -> Option<bool> {
@@ -334,6 +434,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_record_toggle_primary_override() {
+        let cli = Cli::parse_from(["voxtype", "record", "toggle", "--primary"]);
+        match cli.command {
+            Some(Commands::Record { action }) => {
+                assert_eq!(
+                    action.output_mode_override(),
+                    Some(OutputModeOverride::Primary)
+                );
+            }
+            _ => panic!("Expected Record command"),
+        }
+        assert!(
+            Cli::try_parse_from(["voxtype", "record", "stop", "--primary", "--paste"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_record_start_tmux_override() {
+        let cli = Cli::parse_from(["voxtype", "record", "start", "--tmux"]);
+        match cli.command {
+            Some(Commands::Record { action }) => {
+                assert_eq!(
+                    action.output_mode_override(),
+                    Some(OutputModeOverride::Tmux)
+                );
+            }
+            _ => panic!("Expected Record command"),
+        }
+    }
+
+    #[test]
+    fn test_record_toggle_webhook_override() {
+        let cli = Cli::parse_from(["voxtype", "record", "toggle", "--webhook"]);
+        match cli.command {
+            Some(Commands::Record { action }) => {
+                assert_eq!(
+                    action.output_mode_override(),
+                    Some(OutputModeOverride::Webhook)
+                );
+            }
+            _ => panic!("Expected Record command"),
+        }
+    }
+
     #[test]
     fn test_record_start_type_override() {
         let cli = Cli::parse_from(["voxtype", "record", "start", "--type"]);
@@ -634,6 +779,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_record_toggle_with_target() {
+        let cli = Cli::parse_from([
+            "voxtype",
+            "record",
+            "toggle",
+            "--target",
+            "class:obsidian",
+            "--refocus",
+        ]);
+        match cli.command {
+            Some(Commands::Record { action }) => {
+                assert_eq!(action.target(), Some("class:obsidian"));
+                assert!(action.refocus());
+            }
+            _ => panic!("Expected Record command"),
+        }
+    }
+
+    #[test]
+    fn test_record_refocus_requires_target() {
+        let result = Cli::try_parse_from(["voxtype", "record", "start", "--refocus"]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_record_start_auto_submit() {
         let cli = Cli::parse_from(["voxtype", "record", "start", "--auto-submit"]);
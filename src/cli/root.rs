@@ -33,6 +33,7 @@ QUICK START:
   voxtype record start        Start recording
   voxtype record stop         Stop recording and transcribe
   voxtype record cancel       Cancel current recording
+  voxtype undo                Remove the last typed dictation
   voxtype status              Show daemon status
   voxtype setup               Check dependencies and download models
   voxtype config              Show current configuration
@@ -96,6 +97,24 @@ pub struct Cli {
     #[arg(long, help_heading = "Transcription", hide_short_help = true)]
     pub gpu_isolation: bool,
 
+    /// Keep a warm gpu_isolation worker for N seconds after each recording (0 = off)
+    #[arg(
+        long,
+        value_name = "SECS",
+        help_heading = "Transcription",
+        hide_short_help = true
+    )]
+    pub worker_idle_ttl: Option<u64>,
+
+    /// Treat transcriptions below this confidence (0.0-1.0) as unreliable
+    #[arg(
+        long,
+        value_name = "SCORE",
+        help_heading = "Transcription",
+        hide_short_help = true
+    )]
+    pub min_confidence: Option<f32>,
+
     /// GPU device index for multi-GPU systems (e.g., 1 for discrete GPU)
     #[arg(
         long,
@@ -142,7 +161,7 @@ pub struct Cli {
     #[arg(long, help_heading = "Whisper", hide_short_help = true)]
     pub flash_attention: bool,
 
-    /// Whisper execution mode: local, remote, or cli
+    /// Whisper execution mode: local, remote, cli, or server
     #[arg(
         long,
         value_name = "MODE",
@@ -151,7 +170,8 @@ pub struct Cli {
     )]
     pub whisper_mode: Option<String>,
 
-    /// Remote server endpoint URL (for remote whisper mode)
+    /// Remote server endpoint URL (for remote whisper mode); separate several
+    /// with commas to fail over between them
     #[arg(
         long,
         value_name = "URL",
@@ -249,6 +269,18 @@ pub struct Cli {
     #[arg(long, help_heading = "Output")]
     pub paste: bool,
 
+    /// Force primary selection mode (paste with middle-click)
+    #[arg(long, help_heading = "Output")]
+    pub primary: bool,
+
+    /// Force tmux mode (load a tmux paste buffer)
+    #[arg(long, help_heading = "Output")]
+    pub tmux: bool,
+
+    /// Force webhook mode (post to [output.webhook] url)
+    #[arg(long, help_heading = "Output")]
+    pub webhook: bool,
+
     /// Restore clipboard after paste mode
     #[arg(
         long,
@@ -273,7 +305,7 @@ pub struct Cli {
         value_name = "DRIVERS",
         help_heading = "Output",
         long_help = "Output driver order for type mode (comma-separated).\n\
-        Available: wtype, dotool, ydotool, clipboard.\n\
+        Available: wtype, dotool, ydotool, clipboard, osc52.\n\
         Example: --driver=ydotool,wtype,clipboard"
     )]
     pub driver: Option<String>,
@@ -457,6 +489,48 @@ pub struct Cli {
     #[arg(long, help_heading = "Text Processing")]
     pub spoken_punctuation: bool,
 
+    /// Convert spelled-out numbers to digits (e.g., "twenty three" to "23")
+    #[arg(long, help_heading = "Text Processing")]
+    pub spoken_numbers: bool,
+
+    /// Capitalize sentence starts and "I", and fix spacing after . ! ?
+    #[arg(long, help_heading = "Text Processing")]
+    pub auto_capitalize: bool,
+
+    /// Enable emoji and symbol names (e.g., "thumbs up emoji" to get 👍)
+    #[arg(long, help_heading = "Text Processing")]
+    pub emoji: bool,
+
+    /// Profanity filter: off, mask, or remove
+    #[arg(
+        long,
+        value_name = "MODE",
+        help_heading = "Text Processing",
+        hide_short_help = true
+    )]
+    pub profanity: Option<String>,
+
+    /// Enable spelling mode (e.g., "spell alpha bravo seven" to get "ab7")
+    #[arg(long, help_heading = "Text Processing")]
+    pub spelling: bool,
+
+    /// Enable code dictation modes (e.g., "camel case user id" to get "userId")
+    #[arg(long, help_heading = "Text Processing")]
+    pub code_modes: bool,
+
+    /// Enable markdown dictation commands (e.g., "bullet point", "heading two")
+    #[arg(long, help_heading = "Text Processing")]
+    pub markdown_commands: bool,
+
+    /// TOML file of extra word replacements, re-read when it changes
+    #[arg(
+        long,
+        value_name = "FILE",
+        help_heading = "Text Processing",
+        hide_short_help = true
+    )]
+    pub replacements_file: Option<String>,
+
     /// Convert newlines to Shift+Enter instead of regular Enter
     #[arg(long, help_heading = "Text Processing")]
     pub shift_enter_newlines: bool,
@@ -522,7 +596,7 @@ pub struct Cli {
     )]
     pub vad_threshold: Option<f32>,
 
-    /// VAD backend: auto, energy, whisper
+    /// VAD backend: auto, energy, whisper, silero
     #[arg(
         long,
         value_name = "BACKEND",
@@ -618,8 +692,8 @@ mod tests {
     fn test_transcribe_engine_flag() {
         let cli = Cli::parse_from(["voxtype", "transcribe", "test.wav", "--engine", "moonshine"]);
         match cli.command {
-            Some(Commands::Transcribe { file, engine }) => {
-                assert_eq!(file, std::path::PathBuf::from("test.wav"));
+            Some(Commands::Transcribe { file, engine, .. }) => {
+                assert_eq!(file, Some(std::path::PathBuf::from("test.wav")));
                 assert_eq!(engine, Some("moonshine".to_string()));
             }
             _ => panic!("Expected Transcribe command"),
@@ -647,4 +721,140 @@ mod tests {
             _ => panic!("Expected Transcribe command"),
         }
     }
+
+    #[test]
+    fn test_transcribe_batch_flags() {
+        let cli = Cli::parse_from([
+            "voxtype",
+            "transcribe",
+            "--batch",
+            "memos",
+            "--glob",
+            "*.WAV",
+            "--format",
+            "srt",
+            "--out-dir",
+            "out",
+        ]);
+        match cli.command {
+            Some(Commands::Transcribe {
+                file,
+                batch,
+                glob,
+                format,
+                out_dir,
+                ..
+            }) => {
+                assert!(file.is_none());
+                assert_eq!(batch, Some(std::path::PathBuf::from("memos")));
+                assert_eq!(glob, "*.WAV");
+                assert_eq!(format, BatchFormat::Srt);
+                assert_eq!(out_dir, Some(std::path::PathBuf::from("out")));
+            }
+            _ => panic!("Expected Transcribe command"),
+        }
+    }
+
+    #[test]
+    fn test_bench_flags() {
+        let cli = Cli::parse_from([
+            "voxtype",
+            "bench",
+            "sample.wav",
+            "--reference",
+            "sample.txt",
+            "--engine",
+            "whisper",
+            "--engine",
+            "parakeet",
+            "--model",
+            "base.en",
+        ]);
+        match cli.command {
+            Some(Commands::Bench {
+                file,
+                duration,
+                reference,
+                engines,
+                models,
+            }) => {
+                assert_eq!(file, Some(std::path::PathBuf::from("sample.wav")));
+                assert_eq!(duration, 10);
+                assert_eq!(reference, Some(std::path::PathBuf::from("sample.txt")));
+                assert_eq!(engines, vec!["whisper", "parakeet"]);
+                assert_eq!(models, vec!["base.en"]);
+            }
+            _ => panic!("Expected Bench command"),
+        }
+    }
+
+    #[test]
+    fn test_transcribe_batch_defaults() {
+        let cli = Cli::parse_from(["voxtype", "transcribe", "--batch", "memos"]);
+        match cli.command {
+            Some(Commands::Transcribe {
+                glob,
+                format,
+                out_dir,
+                ..
+            }) => {
+                assert_eq!(glob, "*.wav");
+                assert_eq!(format, BatchFormat::Txt);
+                assert!(out_dir.is_none());
+            }
+            _ => panic!("Expected Transcribe command"),
+        }
+    }
+
+    #[test]
+    fn test_transcribe_requires_file_or_batch() {
+        assert!(Cli::try_parse_from(["voxtype", "transcribe"]).is_err());
+        assert!(Cli::try_parse_from(["voxtype", "transcribe", "a.wav", "--batch", "dir"]).is_err());
+    }
+
+    #[test]
+    fn test_translate_command() {
+        let cli = Cli::parse_from(["voxtype", "translate"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Translate { state: None })
+        ));
+
+        let cli = Cli::parse_from(["voxtype", "translate", "off"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Translate {
+                state: Some(TranslateState::Off)
+            })
+        ));
+    }
+
+    #[test]
+    fn test_undo_command() {
+        let cli = Cli::parse_from(["voxtype", "undo"]);
+        assert!(matches!(cli.command, Some(Commands::Undo)));
+    }
+
+    #[test]
+    fn test_test_output_command() {
+        let cli = Cli::parse_from(["voxtype", "test-output"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::TestOutput {
+                type_sample: false,
+                delay: 3
+            })
+        ));
+
+        let cli = Cli::parse_from(["voxtype", "test-output", "--type", "--delay", "5"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::TestOutput {
+                type_sample: true,
+                delay: 5
+            })
+        ));
+        // --delay only applies to --type
+        assert!(Cli::try_parse_from(["voxtype", "test-output", "--delay", "5"]).is_err());
+    }
 }
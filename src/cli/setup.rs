@@ -1,10 +1,15 @@
 //! `voxtype setup` subcommand actions and compositor variants.
 
+use clap::builder::PossibleValuesParser;
 use clap::Subcommand;
 
+use super::WAKE_WORD_MODELS;
+
 #[derive(Subcommand)]
 pub enum SetupAction {
-    /// Check system configuration and dependencies
+    /// Check system configuration and dependencies, and show detected
+    /// hardware with a recommended model
+    #[command(alias = "doctor")]
     Check,
 
     /// Interactive macOS setup wizard
@@ -118,6 +123,18 @@ pub enum SetupAction {
         /// Restart the daemon after changing model (use with --set)
         #[arg(long)]
         restart: bool,
+
+        /// Show installed models and how much disk space each uses
+        #[arg(long)]
+        disk_usage: bool,
+
+        /// Delete an installed model (repeatable; names from --disk-usage)
+        #[arg(long, value_name = "NAME")]
+        remove: Vec<String>,
+
+        /// Remove models even if the current config uses them (use with --remove)
+        #[arg(long, requires = "remove")]
+        force: bool,
     },
 
     /// Manage GPU acceleration (Vulkan for Whisper, CUDA/MIGraphX for Parakeet)
@@ -182,6 +199,23 @@ pub enum SetupAction {
         /// Show VAD model status
         #[arg(long)]
         status: bool,
+
+        /// Model to download: whisper (GGML) or silero (ONNX). Defaults to
+        /// the one [vad] backend uses.
+        #[arg(long, value_parser = PossibleValuesParser::new(["whisper", "silero"]))]
+        backend: Option<String>,
+    },
+
+    /// Download the openWakeWord models for activation = "wake_word"
+    #[command(name = "wake-word")]
+    WakeWord {
+        /// Show wake word model status
+        #[arg(long)]
+        status: bool,
+
+        /// Pretrained wake word to download. Defaults to [wake_word] model.
+        #[arg(long, value_parser = PossibleValuesParser::new(WAKE_WORD_MODELS))]
+        model: Option<String>,
     },
 
     /// Install the Quickshell QML tree for the voxtype-osd-quickshell launcher
@@ -435,6 +469,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_model_remove_flags() {
+        let cli = Cli::parse_from([
+            "voxtype", "setup", "model", "--remove", "large-v3", "--remove", "tiny", "--force",
+        ]);
+        match cli.command {
+            Some(Commands::Setup {
+                action:
+                    Some(SetupAction::Model {
+                        remove,
+                        force,
+                        disk_usage,
+                        ..
+                    }),
+                ..
+            }) => {
+                assert_eq!(remove, vec!["large-v3".to_string(), "tiny".to_string()]);
+                assert!(force);
+                assert!(!disk_usage);
+            }
+            _ => panic!("Expected Setup Model command"),
+        }
+
+        // --force only makes sense alongside --remove
+        assert!(Cli::try_parse_from(["voxtype", "setup", "model", "--force"]).is_err());
+    }
+
     #[test]
     fn test_setup_download_with_model() {
         let cli = Cli::parse_from([
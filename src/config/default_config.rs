@@ -13,6 +13,10 @@ pub const DEFAULT_CONFIG: &str = r#"# Voxtype Configuration
 # Required for `voxtype record toggle` and `voxtype status` commands.
 state_file = "auto"
 
+# What starts recordings: "hotkey" (default) or "wake_word"
+# wake_word also listens for a spoken wake word; see [wake_word] below
+# activation = "hotkey"
+
 [hotkey]
 # Key to hold for push-to-talk
 # Common choices: SCROLLLOCK, PAUSE, RIGHTALT, F13-F24
@@ -38,6 +42,18 @@ modifiers = []
 # Example: model_modifier = "LEFTSHIFT"  # Shift+hotkey uses secondary model
 # model_modifier = "LEFTSHIFT"
 
+# Key that toggles Whisper's translate-to-English at runtime (evdev input mode only)
+# Same as running `voxtype translate`
+# translate_key = "F10"
+
+# Key that removes the last typed dictation (evdev input mode only)
+# Same as running `voxtype undo`
+# undo_key = "F9"
+
+# Key that bookmarks the meeting being recorded (evdev input mode only)
+# Same as running `voxtype meeting mark`
+# meeting_mark_key = "F8"
+
 [audio]
 # Audio input device ("default" uses system default)
 # List devices with: pactl list sources short
@@ -71,9 +87,12 @@ max_duration_secs = 60
 # volume = 0.7
 
 [whisper]
-# Transcription backend: "local" or "remote"
+# Transcription backend: "local", "remote", "cli" or "server"
 # - local: Use whisper.cpp locally (default)
 # - remote: Send audio to a remote whisper.cpp server or OpenAI-compatible API
+# - cli: Run whisper-cli for each recording
+# - server: Start and supervise a local whisper-server with the model kept loaded
+#   (optional: whisper_server_path = "/usr/local/bin/whisper-server")
 # backend = "local"
 
 # Model to use for transcription (local backend)
@@ -110,19 +129,43 @@ translate = false
 # Reduces memory usage (~75%) and improves speed (~10%) on CUDA/Vulkan.
 # flash_attention = false
 
+# Keep the decoding state (KV cache, scratch buffers) allocated between
+# recordings to cut per-utterance setup time, at the cost of its memory.
+# Only applies when gpu_isolation = false and on_demand_loading = false.
+# keep_context_warm = false
+
 # Initial prompt to provide context for transcription
 # Use this to hint at terminology, proper nouns, or formatting conventions.
 # Example: "Technical discussion about Rust, TypeScript, and Kubernetes."
 # initial_prompt = ""
 
+# Drop known hallucinations (matched per sentence, ignoring case/punctuation)
+# suppress_phrases = ["Thanks for watching!", "Subscribe"]
+#
+# Discard the whole output when the no-speech probability reaches this value
+# no_speech_threshold = 0.7
+
+# Decoder tuning, useful against repetition loops. Keys: beam_size, best_of,
+# temperature, temperature_increment, entropy_threshold, logprob_threshold.
+# Unset keys keep whisper.cpp's defaults.
+# decoding = { beam_size = 5, entropy_threshold = 2.8 }
+
 # --- Multi-model settings ---
 #
 # Secondary model for difficult audio (used with hotkey.model_modifier or CLI --model)
 # secondary_model = "large-v3-turbo"
 #
+# Automatically retry with secondary_model when confidence is below this value
+# (0.0-1.0), for clips no longer than secondary_retry_max_secs (default: 10)
+# secondary_retry_confidence = 0.6
+# secondary_retry_max_secs = 10
+#
 # List of available models that can be requested via CLI --model flag
 # available_models = ["large-v3-turbo", "medium.en"]
 #
+# Extra named models (download URL or local path), usable like built-in names
+# custom_models = { my-finetune = "https://example.com/ggml-model.bin" }
+#
 # Maximum models to keep loaded in memory (LRU eviction when exceeded)
 # Default: 2 (primary + one secondary). Only applies when gpu_isolation = false.
 # max_loaded_models = 2
@@ -130,6 +173,17 @@ translate = false
 # Seconds before unloading idle secondary models (0 = never auto-unload)
 # Default: 300 (5 minutes). Only applies when gpu_isolation = false.
 # cold_model_timeout_secs = 300
+#
+# Two-pass mode: output the primary model's draft immediately, then
+# re-transcribe the same audio with this model in the background
+# redo_model = "large-v3-turbo"
+#
+# What to do when the redo differs: "notify" (offer it to copy) or "replace"
+# (backspace the typed draft and type the correction)
+# redo_action = "notify"
+#
+# Fraction of words that must change before the redo is offered (default: 0.1)
+# redo_min_change = 0.1
 
 # --- Eager processing settings ---
 #
@@ -149,6 +203,12 @@ translate = false
 # Examples:
 #   - whisper.cpp server: "http://192.168.1.100:8080"
 #   - OpenAI API: "https://api.openai.com"
+#   - WebSocket streaming server: "wss://asr.example.com/stream" (--features remote-ws)
+#   - Failover list, tried in order on timeouts and 5xx errors:
+#     ["http://gpu-box:8080", "https://api.openai.com"]
+#   - Per-endpoint credentials, so a cloud key is never sent to the LAN box:
+#     [{ url = "http://gpu-box:8080", model = "large-v3-turbo" },
+#      { url = "https://api.openai.com", api_key = "sk-...", model = "whisper-1" }]
 # remote_endpoint = "http://192.168.1.100:8080"
 #
 # Model name to send to remote server (default: "whisper-1")
@@ -160,25 +220,85 @@ translate = false
 # Timeout for remote requests in seconds (default: 30)
 # remote_timeout_secs = 30
 
+# [transcribe]
+# Type text while still speaking by re-decoding the recording as it grows.
+# Applies to local Whisper (gpu_isolation = false) and the batch ONNX engines.
+# Parakeet, Soniox and Zipformer use their own streaming options.
+#
+# streaming = false
+# streaming_interval_ms = 1000      # Re-decode cadence
+# streaming_max_window_secs = 20.0  # Commit and start a new window after this
+#
+# Warn about transcriptions below this confidence (0.0-1.0, unset = off).
+# Reported by local Whisper, SenseVoice, Dolphin, Omnilingual and Vosk.
+# min_confidence = 0.4
+# low_confidence_action = "notify"  # "notify", "clipboard_only" or "discard"
+#
+# Favor project names and jargon while decoding (Zipformer, and Parakeet TDT
+# in builds with the parakeet-hotwords feature)
+# hotwords = { "Kubernetes" = 2.0, "voxtype" = 3.0 }
+#
+# Memory-map model files (Whisper and ONNX engines). Speeds up repeated
+# loads with on_demand_loading or gpu_isolation via the page cache.
+# mmap_models = false
+
 [output]
-# Primary output mode: "type" or "clipboard"
+# Primary output mode: "type", "clipboard", "paste", "primary", "tmux", "webhook" or "file"
 # - type: Simulates keyboard input at cursor position (requires ydotool)
 # - clipboard: Copies text to clipboard (requires wl-copy)
+# - primary: Copies text to the primary selection for middle-click paste
+# - tmux: Loads a tmux paste buffer (prefix + ] pastes it)
+# - webhook: POSTs the text as JSON to [output.webhook] url
 mode = "type"
 
+# Also send each transcription to other outputs, each tried once after the
+# primary one. Without mode, the first target is the primary output.
+# targets = ["type", "clipboard", "file"]
+
+# In tmux mode, paste the buffer into the active pane right away
+# tmux_paste = false
+
+# File mode: path may use ~/ and {date}, {time}, {year}, {month}, {day},
+# {weekday}. Example voice journal with one note per day:
+# file_path = "~/notes/{date}.md"
+# file_mode = "append"
+# file_entry_template = "- {time} {text}\n"
+
 # Fall back to clipboard if typing fails
 fallback_to_clipboard = true
 
 # Custom driver order for type mode (optional)
-# Default order: wtype -> dotool -> ydotool -> clipboard
+# Default order: input-method -> wtype -> eitype -> xdotool -> dotool -> ydotool -> uinput -> clipboard -> xclip
 # Customize to prefer a specific driver or change the fallback order.
-# Available drivers: wtype, dotool, ydotool, clipboard
+# Available drivers: input-method, wtype, eitype, xdotool, dotool, ydotool, uinput, atspi, ime, clipboard, xclip
+# (input-method needs a build with the wayland-im feature; uinput is built
+# in and needs only write access to /dev/uinput; atspi inserts into the
+# focused text field via accessibility and ime commits through IBus; neither
+# is in the default order)
 # Example: prefer ydotool over dotool:
 #   driver_order = ["wtype", "ydotool", "dotool", "clipboard"]
 # Example: use only ydotool, no fallback:
 #   driver_order = ["ydotool"]
+# Example: insert via accessibility, type where that isn't possible:
+#   driver_order = ["atspi", "wtype", "dotool", "clipboard"]
 # driver_order = ["wtype", "dotool", "ydotool", "clipboard"]
 
+# Move the drivers suited to the focused app to the front of the order
+# before typing: xdotool and the kernel-level drivers for XWayland apps
+# (Hyprland/Sway) and X11, dotool/ydotool/uinput on a text console
+# auto_driver = false
+
+# Keep one dotool process and one ydotoold connection open between outputs
+# instead of spawning dotool/ydotool for every dictation (default: true)
+# persistent_drivers = true
+
+# Desktop keyboard layout for the built-in uinput driver: us, gb, de, fr
+# uinput_xkb_layout = "us"
+
+# Terminal for the osc52 driver, which copies to the terminal's clipboard
+# over SSH (default: the terminal voxtype was started from)
+# osc52_tty = "/dev/pts/3"
+
 # Per-language XKB layout variants for multilingual dictation.
 # Use this with language arrays such as `language = ["en", "ru"]` when a
 # language needs a variant that should not apply to other languages.
@@ -203,6 +323,10 @@ type_delay_ms = 0
 # Workaround for apps (e.g., Discord) that drop the first CJK character
 # wtype_shift_prefix = false
 
+# Press Ctrl+Z instead of backspacing when undoing in these applications
+# (case-insensitive regexes on the focused window's class)
+# undo_ctrl_z_apps = ["libreoffice"]
+
 # Restore clipboard content after paste mode (default: false)
 # Saves clipboard before transcription, restores it after paste keystroke
 # Only applies to mode = "paste". Useful when you want to preserve your
@@ -213,6 +337,10 @@ type_delay_ms = 0
 # Allows time for the paste operation to complete (default: 200)
 # restore_clipboard_delay_ms = 200
 
+# Restore the previous clipboard this many seconds after mode = "clipboard"
+# copies a transcription, unless something else was copied in the meantime
+# restore_clipboard_after_secs = 30
+
 # Wait for modifier keys to be released before typing (default: true)
 # Prevents transcribed letters from combining with held modifiers (Ctrl/Alt/
 # Shift/Super) and triggering compositor or application keybindings. Reads
@@ -246,6 +374,72 @@ type_delay_ms = 0
 # timeout_ms = 30000  # 30 second timeout (generous for LLM)
 # trim = true         # Strip leading/trailing whitespace from output (default: true)
 # fallback_on_empty = true  # Use original text if command returns empty (default: true)
+#
+# Or chain several stages instead of one command. Each gets the previous
+# stage's output; on_error = "skip" ignores a failing stage, "abort" (default)
+# outputs the original transcription.
+#
+# [[output.post_process.stages]]
+# type = "command"          # "command", "regex" or "llm"
+# command = "~/.config/voxtype/remove-fillers.sh"
+# timeout_ms = 2000
+# on_error = "skip"
+#
+# [[output.post_process.stages]]
+# type = "llm"              # takes the [output.llm] options below
+# model = "llama3.2:1b"
+
+# LLM post-processing without a shell command (optional, replaces
+# [output.post_process]). The model stays loaded between dictations.
+#
+# [output.llm]
+# backend = "ollama"        # or "llama-cpp" (requires the llm-postprocess feature)
+# model = "llama3.2:1b"     # Ollama model, or GGUF file for llama-cpp
+# ollama_url = "http://localhost:11434"
+# prompt = "Fix punctuation and remove filler words. Reply with only the text."
+# max_tokens = 512
+# timeout_ms = 30000
+
+# Preview-and-confirm before output (optional)
+# Shows the text in a chooser with Accept / Edit / Discard; only Accept types it.
+# Chooser defaults to fuzzel, wofi, rofi, bemenu or dmenu; Edit uses zenity,
+# kdialog or yad.
+#
+# [output.confirm]
+# enabled = true
+# command = "fuzzel --dmenu --prompt 'voxtype: '"
+# timeout_secs = 120
+
+# Human-like typing pace (optional)
+# Types one character at a time with randomized delays and pauses between
+# words, for web apps that drop characters when text arrives at once.
+#
+# [output.pacing]
+# enabled = true
+# char_delay_ms = 20   # 0 types each word in one burst
+# word_pause_ms = 80
+# jitter = 0.5         # Vary each delay by up to 50%
+
+# Chunked typing (on by default)
+# Long output is typed in chunks with a short pause in between, so apps
+# don't drop input. Each driver has its own defaults (e.g. ydotool: 200
+# characters, 100ms); these override them for all drivers.
+#
+# [output.chunking]
+# enabled = true
+# chunk_chars = 200
+# pause_ms = 150
+
+# Webhook output (optional)
+# POSTs {"text", "profile", "timestamp"} as JSON. Used by mode = "webhook",
+# or for every dictation with always = true. The token can also come from
+# VOXTYPE_WEBHOOK_TOKEN.
+#
+# [output.webhook]
+# url = "https://n8n.example.com/webhook/voice"
+# bearer_token = "..."
+# always = false
+# timeout_ms = 5000
 
 [output.notification]
 # Show notification when recording starts (hotkey pressed)
@@ -268,18 +462,91 @@ on_transcription = true
 # Enable spoken punctuation conversion (e.g., say "period" to get ".")
 # spoken_punctuation = false
 #
+# Spoken punctuation language and spacing: "de", "fr" and "es" have their
+# own phrases ("Komma", "virgule", "coma"); "fr" also spaces "quoi ?" and
+# uses « » quotes, "de" uses „…“ quotes. Defaults to the whisper language.
+# locale = "fr"
+#
+# Add, override or (with "") remove spoken punctuation phrases
+# punctuation_map = { "arrow" = "->", "hash" = "" }
+#
+# Capitalize sentence starts and "i", and leave one space after . ! ?
+# Helpful with engines that output all-lowercase, unpunctuated text.
+# auto_capitalize = false
+#
+# Convert spelled-out numbers to digits ("twenty three point five" -> "23.5").
+# Spoken dates with a year ("march third twenty twenty five") use date_format,
+# a chrono/strftime string.
+# spoken_numbers = false
+# date_format = "%Y-%m-%d"
+#
 # Custom word replacements (case-insensitive)
 # replacements = { "vox type" = "voxtype" }
 #
+# Extra replacements from a separate TOML file (same "from" = "to" form),
+# re-read automatically when it changes. Inline replacements win on conflict.
+# replacements_file = "~/.config/voxtype/dictionary.toml"
+#
 # Smart auto-submit: say "submit" at the end of dictation to press Enter.
 # The word "submit" is stripped from the output text and Enter is pressed.
 # smart_auto_submit = false
 #
+# Voice editing commands: end a dictation with "scratch that" or "delete last
+# word" to drop what you just said, or say "undo" on its own to backspace over
+# the previous typed output.
+# editing_commands = false
+#
+# Case commands: end a dictation with "all caps", "lowercase that" or
+# "title case that" to change the case of what you said before it.
+# case_commands = false
+#
+# Emoji and symbols: "thumbs up emoji" -> 👍, "degree symbol" -> °.
+# emoji_map adds your own names (said before "emoji").
+# emoji = false
+# emoji_map = { "ship it" = "🚢" }
+#
+# Spelling: "spell alpha bravo seven charlie" -> "ab7c" (NATO alphabet,
+# digits, "capital" for uppercase, "dash", "underscore", "dot").
+# spelling = false
+#
+# Code dictation modes: "camel case user profile id" -> "userProfileId".
+# Also "pascal case", "snake case", "kebab case" and "constant case". The
+# identifier ends at punctuation or when you say "end case".
+# code_modes = false
+#
+# Markdown commands: "bullet point", "numbered item", "heading two",
+# "block quote", "code block" and "end code block" become markdown.
+# markdown_commands = false
+#
 # Remove filler words like "uh" and "um" from transcribed text.
 # Enabled by default. Set filter_filler_words = false to disable, or override
 # the word list via filler_words.
 # filter_filler_words = true
 # filler_words = ["uh", "um", "er", "ah", "eh", "hmm", "hm", "mm", "mhm"]
+#
+# Profanity filter: "off", "mask" ("shit" -> "s***") or "remove".
+# profanity_words extends the bundled English list.
+# profanity = "off"
+# profanity_words = []
+#
+# Spoken macros, expanded each time they're dictated. Placeholders: {date},
+# {time}, {date:%B %-d, %Y} (strftime formats) and {clipboard}.
+# [text.macros]
+# "insert today's date" = "{date}"
+# "insert time" = "{time}"
+# "paste clipboard" = "{clipboard}"
+#
+# Snippets: a dictation that is exactly the phrase is replaced by the text
+# (multi-line values and the macro placeholders work here too).
+# [text.snippets]
+# "my signature" = "Best regards,\nYour Name"
+#
+# Regex rewrite rules, applied in order after replacements. Use $1 or ${name}
+# for capture groups. Case-insensitive unless case_sensitive = true.
+# Keep [[text.rules]] entries after the other [text] settings.
+# [[text.rules]]
+# pattern = 'ticket (\d+)'
+# replacement = "JIRA-$1"
 
 # [vad]
 # Voice Activity Detection - filters silence-only recordings
@@ -288,6 +555,16 @@ on_transcription = true
 # enabled = false      # Enable VAD (off by default)
 # threshold = 0.5      # 0.0 = sensitive, 1.0 = aggressive
 # min_speech_duration_ms = 100  # Minimum speech required
+# auto_stop_silence_ms = 0      # End toggle recordings after this much silence (0 = off)
+
+# [wake_word]
+# Start recording by saying a wake word (requires an ONNX build and
+# `voxtype setup wake-word`). Enable with activation = "wake_word" at the
+# top of this file.
+#
+# model = "hey_jarvis"  # alexa, hey_jarvis, hey_mycroft, hey_rhasspy, or a path
+# threshold = 0.5       # Detection score needed to start recording
+# silence_ms = 1500     # Trailing silence that stops the recording
 
 # [status]
 # Status display icons for Waybar/tray integrations
@@ -324,6 +601,68 @@ on_transcription = true
 # [profiles.code]
 # post_process_command = "ollama run llama3.2:1b 'Format as code comment...'"
 # output_mode = "clipboard"
+# spoken_punctuation = true  # Per-profile [text] overrides
+# auto_capitalize = false
+# replacements = { "arrow" = "->" }
+#
+# [profiles.git]
+# post_process_template = "commit_message"  # Built-in LLM prompt: email_tone,
+#                                           # bullet_summary, code_comment, commit_message
+#
+# [profiles.digits]
+# grammar_file = "/path/to/digits.gbnf"  # GBNF grammar, requires backend = "cli"
+#
+# [profiles.spell]
+# spelling = true  # Spell the whole dictation with the NATO alphabet
+#
+# [profiles.markdown]
+# markdown_commands = true  # "bullet point", "heading two", ... become markdown
+#
+# [profiles.ident]
+# identifier_style = "snake"  # Whole dictation as one identifier: camel, pascal, snake, kebab, constant
+#
+# [profiles.notes]
+# engine = "parakeet"      # Per-profile engine, model, language and VAD overrides
+# model = "parakeet-tdt-0.6b-v3"
+# vad_enabled = true
+#
+# Pick a profile from the focused window (Hyprland, Sway, KDE, GNOME).
+# match is a case-insensitive regex on "class:" (default) or "title:"
+# [[profile_rules]]
+# match = "class:Slack"
+# profile = "slack"
+
+# [network]
+# Outbound proxy for downloads and remote services
+# (defaults to the HTTPS_PROXY/HTTP_PROXY env vars; localhost is never proxied)
+# proxy = "http://proxy.corp.example:3128"
+# no_proxy = ["internal.example"]
+
+# [mqtt]
+# Publish state (retained) and transcriptions to an MQTT broker, e.g. for
+# Home Assistant. Requires a build with --features mqtt.
+# enabled = true
+# host = "homeassistant.local"
+# port = 1883              # 8883 with tls = true
+# username = "voxtype"
+# password = "..."         # Or VOXTYPE_MQTT_PASSWORD env var
+# tls = false
+# ca_file = "/etc/ssl/my-ca.pem"
+# topic_prefix = "voxtype" # voxtype/state and voxtype/transcription
+
+# [models]
+# Model download settings
+# hf_token = "hf_..."      # For gated Hugging Face repos (or HF_TOKEN env var)
+# hf_endpoint = "https://hf-mirror.com"  # Hugging Face mirror (or HF_ENDPOINT)
+# mirror = "https://..."   # models.voxtype.io mirror (or VOXTYPE_MODELS_MIRROR)
+#
+# [models.custom.my-finetune]
+# User-defined models, usable by name in model settings, --model, profiles
+# and `voxtype setup model`
+# engine = "whisper"       # Engine that runs the model
+# url = "https://example.com/ggml-my-finetune.bin"  # Whisper downloads only
+# path = "/opt/models/my-model"  # Or a local file/directory (any engine)
+# sha256 = "..."           # Checked when downloading from url
 "#;
 
 /// Return the default config content with platform-appropriate hotkey
@@ -27,6 +27,12 @@ pub struct CohereConfig {
     #[serde(default)]
     pub threads: Option<usize>,
 
+    /// CUDA device index for the CUDA/TensorRT execution providers
+    /// (default: 0). Set this on multi-GPU machines; `nvidia-smi -L` lists
+    /// the indices. An invalid index falls back to CPU with a logged error.
+    #[serde(default)]
+    pub cuda_device: Option<u32>,
+
     /// Load model on-demand when recording starts (true) or keep loaded (false)
     #[serde(default = "default_on_demand_loading")]
     pub on_demand_loading: bool,
@@ -42,6 +48,7 @@ impl Default for CohereConfig {
             model: "cohere-transcribe-q4f16".to_string(),
             language: default_cohere_language(),
             threads: None,
+            cuda_device: None,
             on_demand_loading: false,
         }
     }
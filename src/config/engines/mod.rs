@@ -10,6 +10,8 @@ mod paraformer;
 mod parakeet;
 mod sensevoice;
 mod soniox;
+mod vosk;
+mod zipformer;
 
 pub use cohere::CohereConfig;
 pub use dolphin::DolphinConfig;
@@ -19,6 +21,8 @@ pub use paraformer::ParaformerConfig;
 pub use parakeet::{ParakeetConfig, ParakeetModelType};
 pub use sensevoice::SenseVoiceConfig;
 pub use soniox::SonioxConfig;
+pub use vosk::VoskConfig;
+pub use zipformer::ZipformerConfig;
 
 /// Transcription engine selection (which ASR technology to use)
 #[derive(
@@ -66,6 +70,14 @@ pub enum TranscriptionEngine {
     /// Use Soniox (cloud streaming WebSocket STT).
     /// Requires: cargo build --features soniox
     Soniox,
+    /// Use Vosk (Kaldi small streaming models via libvosk). Runs on very
+    /// old CPUs where even Whisper tiny is too slow.
+    /// Requires: cargo build --features vosk
+    Vosk,
+    /// Use Zipformer (sherpa-onnx streaming transducer via ONNX Runtime).
+    /// Decodes audio chunk by chunk for low-latency live output.
+    /// Requires: cargo build --features zipformer
+    Zipformer,
 }
 
 impl TranscriptionEngine {
@@ -56,6 +56,12 @@ pub struct ParakeetConfig {
     /// `UnifiedStreamingConfig::right_context_secs`.
     #[serde(default = "default_streaming_right_context_secs")]
     pub streaming_right_context_secs: f32,
+
+    /// CUDA device index for the CUDA/TensorRT execution providers
+    /// (default: 0). Set this on multi-GPU machines; `nvidia-smi -L` lists
+    /// the indices. An invalid index falls back to CPU with a logged error.
+    #[serde(default)]
+    pub cuda_device: Option<u32>,
 }
 
 fn default_streaming_chunk_secs() -> f32 {
@@ -80,6 +86,7 @@ impl Default for ParakeetConfig {
             streaming_chunk_secs: default_streaming_chunk_secs(),
             streaming_left_context_secs: default_streaming_left_context_secs(),
             streaming_right_context_secs: default_streaming_right_context_secs(),
+            cuda_device: None,
         }
     }
 }
@@ -179,6 +186,36 @@ mod tests {
         let parakeet = config.parakeet.unwrap();
         // model_type should be None (will be auto-detected at runtime)
         assert!(parakeet.model_type.is_none());
+        assert!(parakeet.cuda_device.is_none());
+    }
+
+    #[test]
+    fn test_parse_parakeet_cuda_device() {
+        let toml_str = r#"
+            engine = "parakeet"
+
+            [hotkey]
+            key = "SCROLLLOCK"
+
+            [audio]
+            device = "default"
+            sample_rate = 16000
+            max_duration_secs = 60
+
+            [whisper]
+            model = "base.en"
+            language = "en"
+
+            [output]
+            mode = "type"
+
+            [parakeet]
+            model = "parakeet-tdt-0.6b-v3"
+            cuda_device = 1
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.parakeet.unwrap().cuda_device, Some(1));
     }
 
     #[test]
@@ -0,0 +1,36 @@
+//! Vosk engine configuration.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use super::super::default_on_demand_loading;
+
+/// Vosk speech-to-text configuration (Kaldi-based, via libvosk)
+/// Requires: cargo build --features vosk
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct VoskConfig {
+    /// Model name or path to an unpacked Vosk model directory
+    /// Expects the standard Vosk layout (am/, conf/, graph/)
+    /// Short names resolve under the models directory, e.g.
+    /// "small-en-us-0.15" -> ~/.local/share/voxtype/models/vosk-model-small-en-us-0.15
+    pub model: String,
+
+    /// Path to libvosk shared library. Defaults to searching the system
+    /// library path for libvosk.so (libvosk.dylib on macOS).
+    #[serde(default)]
+    pub library: Option<PathBuf>,
+
+    /// Load model on-demand when recording starts (true) or keep loaded (false)
+    #[serde(default = "default_on_demand_loading")]
+    pub on_demand_loading: bool,
+}
+
+impl Default for VoskConfig {
+    fn default() -> Self {
+        Self {
+            model: "small-en-us-0.15".to_string(),
+            library: None,
+            on_demand_loading: false,
+        }
+    }
+}
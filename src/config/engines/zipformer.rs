@@ -0,0 +1,49 @@
+//! Zipformer engine configuration.
+
+use serde::{Deserialize, Serialize};
+
+use super::super::default_on_demand_loading;
+
+/// Zipformer speech-to-text configuration (sherpa-onnx streaming transducer)
+/// Requires: cargo build --features zipformer
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ZipformerConfig {
+    /// Model name or path to ONNX model directory
+    /// Expects: encoder, decoder and joiner .onnx files, tokens.txt
+    pub model: String,
+
+    /// Number of CPU threads for ONNX Runtime inference
+    #[serde(default)]
+    pub threads: Option<usize>,
+
+    /// CUDA device index for the CUDA/TensorRT execution providers
+    /// (default: 0). Set this on multi-GPU machines; `nvidia-smi -L` lists
+    /// the indices. An invalid index falls back to CPU with a logged error.
+    #[serde(default)]
+    pub cuda_device: Option<u32>,
+
+    /// Type text while speaking, as each audio chunk is decoded (default: true).
+    /// When false, the recording is decoded in one pass on hotkey release.
+    #[serde(default = "default_streaming")]
+    pub streaming: bool,
+
+    /// Load model on-demand when recording starts (true) or keep loaded (false)
+    #[serde(default = "default_on_demand_loading")]
+    pub on_demand_loading: bool,
+}
+
+fn default_streaming() -> bool {
+    true
+}
+
+impl Default for ZipformerConfig {
+    fn default() -> Self {
+        Self {
+            model: "zipformer-en".to_string(),
+            threads: None,
+            cuda_device: None,
+            streaming: default_streaming(),
+            on_demand_loading: false,
+        }
+    }
+}
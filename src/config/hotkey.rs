@@ -46,6 +46,24 @@ pub struct HotkeyConfig {
     #[serde(default)]
     pub cancel_key: Option<String>,
 
+    /// Optional key that toggles Whisper's translate-to-English at runtime
+    /// (evdev KEY_* constant name, without KEY_ prefix)
+    /// Examples: "F10", "PAUSE"
+    #[serde(default)]
+    pub translate_key: Option<String>,
+
+    /// Optional key that undoes the last dictation typed at the cursor
+    /// (evdev KEY_* constant name, without KEY_ prefix)
+    /// Examples: "F9", "SCROLLLOCK"
+    #[serde(default)]
+    pub undo_key: Option<String>,
+
+    /// Optional key that bookmarks the meeting being recorded
+    /// (evdev KEY_* constant name, without KEY_ prefix)
+    /// Examples: "F8", "INSERT"
+    #[serde(default)]
+    pub meeting_mark_key: Option<String>,
+
     /// Optional modifier key for secondary model selection (evdev KEY_* name, without KEY_ prefix)
     /// When held while pressing the hotkey, uses secondary_model instead of the default model
     /// Examples: "LEFTSHIFT", "RIGHTALT", "LEFTCTRL"
@@ -67,6 +85,9 @@ impl Default for HotkeyConfig {
             mode: ActivationMode::default(),
             enabled: true,
             cancel_key: None,
+            translate_key: None,
+            undo_key: None,
+            meeting_mark_key: None,
             model_modifier: None,
             profile_modifiers: HashMap::new(),
         }
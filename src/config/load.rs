@@ -1,5 +1,8 @@
 use super::parse::parse_config_with_defaults;
-use super::{Config, LanguageConfig, OutputMode, SonioxConfig, TranscriptionEngine};
+use super::{
+    Config, LanguageConfig, LowConfidenceAction, OutputMode, ProfanityMode, RemoteEndpointConfig,
+    SonioxConfig, TranscriptionEngine,
+};
 use crate::error::VoxtypeError;
 use std::path::{Path, PathBuf};
 
@@ -77,6 +80,23 @@ pub fn load_config(path: Option<&Path>) -> Result<Config, VoxtypeError> {
     if let Ok(val) = std::env::var("VOXTYPE_GPU_ISOLATION") {
         config.whisper.gpu_isolation = parse_bool_env(&val);
     }
+    if let Ok(val) = std::env::var("VOXTYPE_WORKER_IDLE_TTL") {
+        if let Ok(n) = val.parse::<u64>() {
+            config.whisper.worker_idle_ttl_secs = n;
+        }
+    }
+    if let Ok(val) = std::env::var("VOXTYPE_MIN_CONFIDENCE") {
+        if let Ok(n) = val.parse::<f32>() {
+            config.transcribe.min_confidence = Some(n);
+        }
+    }
+    if let Ok(val) = std::env::var("VOXTYPE_LOW_CONFIDENCE_ACTION") {
+        config.transcribe.low_confidence_action = match val.to_lowercase().as_str() {
+            "clipboard_only" => LowConfidenceAction::ClipboardOnly,
+            "discard" => LowConfidenceAction::Discard,
+            _ => LowConfidenceAction::Notify,
+        };
+    }
     if let Ok(val) = std::env::var("VOXTYPE_GPU_DEVICE") {
         if let Ok(n) = val.parse::<i32>() {
             config.whisper.gpu_device = Some(n);
@@ -88,6 +108,9 @@ pub fn load_config(path: Option<&Path>) -> Result<Config, VoxtypeError> {
     if let Ok(val) = std::env::var("VOXTYPE_ON_DEMAND_LOADING") {
         config.whisper.on_demand_loading = parse_bool_env(&val);
     }
+    if let Ok(val) = std::env::var("VOXTYPE_KEEP_CONTEXT_WARM") {
+        config.whisper.keep_context_warm = parse_bool_env(&val);
+    }
 
     // Audio
     if let Ok(device) = std::env::var("VOXTYPE_AUDIO_DEVICE") {
@@ -111,6 +134,9 @@ pub fn load_config(path: Option<&Path>) -> Result<Config, VoxtypeError> {
             "clipboard" => OutputMode::Clipboard,
             "paste" => OutputMode::Paste,
             "file" => OutputMode::File,
+            "primary" => OutputMode::Primary,
+            "tmux" => OutputMode::Tmux,
+            "webhook" => OutputMode::Webhook,
             _ => OutputMode::Type,
         };
     }
@@ -163,7 +189,8 @@ pub fn load_config(path: Option<&Path>) -> Result<Config, VoxtypeError> {
 
     // Remote whisper
     if let Ok(endpoint) = std::env::var("VOXTYPE_REMOTE_ENDPOINT") {
-        config.whisper.remote_endpoint = Some(endpoint);
+        config.whisper.remote_endpoint =
+            Some(RemoteEndpointConfig::from_comma_separated(&endpoint));
     }
     if let Ok(key) = std::env::var("VOXTYPE_WHISPER_API_KEY") {
         config.whisper.remote_api_key = Some(key);
@@ -184,13 +211,57 @@ pub fn load_config(path: Option<&Path>) -> Result<Config, VoxtypeError> {
             config.output.restore_clipboard_delay_ms = ms;
         }
     }
+    if let Ok(val) = std::env::var("VOXTYPE_RESTORE_CLIPBOARD_AFTER_SECS") {
+        if let Ok(secs) = val.parse::<u32>() {
+            config.output.restore_clipboard_after_secs = Some(secs);
+        }
+    }
     if let Ok(val) = std::env::var("VOXTYPE_SMART_AUTO_SUBMIT") {
         config.text.smart_auto_submit = parse_bool_env(&val);
     }
+    if let Ok(path) = std::env::var("VOXTYPE_REPLACEMENTS_FILE") {
+        config.text.replacements_file = Some(path);
+    }
+    if let Ok(val) = std::env::var("VOXTYPE_AUTO_CAPITALIZE") {
+        config.text.auto_capitalize = parse_bool_env(&val);
+    }
+    if let Ok(val) = std::env::var("VOXTYPE_SPOKEN_NUMBERS") {
+        config.text.spoken_numbers = parse_bool_env(&val);
+    }
+    if let Ok(val) = std::env::var("VOXTYPE_EMOJI") {
+        config.text.emoji = parse_bool_env(&val);
+    }
+    if let Ok(val) = std::env::var("VOXTYPE_SPELLING") {
+        config.text.spelling = parse_bool_env(&val);
+    }
+    if let Ok(val) = std::env::var("VOXTYPE_CODE_MODES") {
+        config.text.code_modes = parse_bool_env(&val);
+    }
+    if let Ok(val) = std::env::var("VOXTYPE_TEXT_LOCALE") {
+        config.text.locale = Some(val);
+    }
+    if let Ok(val) = std::env::var("VOXTYPE_MARKDOWN_COMMANDS") {
+        config.text.markdown_commands = parse_bool_env(&val);
+    }
+    if let Ok(val) = std::env::var("VOXTYPE_EDITING_COMMANDS") {
+        config.text.editing_commands = parse_bool_env(&val);
+    }
+    if let Ok(val) = std::env::var("VOXTYPE_CASE_COMMANDS") {
+        config.text.case_commands = parse_bool_env(&val);
+    }
+    if let Ok(val) = std::env::var("VOXTYPE_PROFANITY") {
+        config.text.profanity = match val.to_lowercase().as_str() {
+            "mask" => ProfanityMode::Mask,
+            "remove" => ProfanityMode::Remove,
+            _ => ProfanityMode::Off,
+        };
+    }
     if let Ok(val) = std::env::var("VOXTYPE_FILTER_FILLERS") {
         config.text.filter_filler_words = parse_bool_env(&val);
     }
 
+    config.apply_custom_models();
+
     Ok(config)
 }
 
@@ -2,7 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::default_true;
+use super::{default_true, LlmConfig};
 
 /// Meeting transcription configuration
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -39,6 +39,38 @@ pub struct MeetingConfig {
     /// Summarization configuration
     #[serde(default)]
     pub summary: MeetingSummaryConfig,
+
+    /// Calendar lookup for meeting titles and attendees
+    #[serde(default)]
+    pub calendar: MeetingCalendarConfig,
+
+    /// Commands and webhook run when a meeting ends or is summarized
+    #[serde(default)]
+    pub hooks: MeetingHooksConfig,
+
+    /// Export defaults: note template and output directory
+    #[serde(default)]
+    pub export: MeetingExportConfig,
+
+    /// Translation of transcript segments as they are transcribed
+    #[serde(default)]
+    pub translation: MeetingTranslationConfig,
+
+    /// Splitting finished meetings into titled chapters
+    #[serde(default)]
+    pub chapters: MeetingChaptersConfig,
+
+    /// Pruning old meetings and capping meeting storage
+    #[serde(default)]
+    pub retention: MeetingRetentionConfig,
+
+    /// Uploading finished meetings to a server
+    #[serde(default)]
+    pub sync: MeetingSyncConfig,
+
+    /// Starting and stopping meetings with calls in conferencing apps
+    #[serde(default)]
+    pub auto_start: MeetingAutoStartConfig,
 }
 
 /// Meeting audio configuration for dual capture
@@ -49,9 +81,10 @@ pub struct MeetingAudioConfig {
     pub mic_device: String,
 
     /// Loopback device for capturing remote participants
-    /// Options: "auto" (detect), "disabled", or specific device name
+    /// Options: "auto" (detect), "all" (every output's monitor), "disabled",
+    /// a specific device name, or a list of devices mixed together
     #[serde(default = "default_loopback")]
-    pub loopback_device: String,
+    pub loopback_device: LoopbackDevice,
 
     /// Echo cancellation mode for removing speaker bleed-through from mic
     /// Options: "auto" (GTCRN neural enhancement + transcript dedup), "disabled"
@@ -65,14 +98,49 @@ pub struct MeetingAudioConfig {
     /// Lower values are more permissive; 0.0 disables the pre-transcription gate.
     #[serde(default = "default_meeting_vad_threshold")]
     pub vad_threshold: f32,
+
+    /// External player for `voxtype meeting play`, run through `sh -c`;
+    /// `{file}` is the audio file and `{offset}` the start in seconds.
+    /// Plays through the default audio output when unset.
+    #[serde(default)]
+    pub player: Option<String>,
 }
 
 fn default_mic_device() -> String {
     "default".to_string()
 }
 
-fn default_loopback() -> String {
-    "auto".to_string()
+fn default_loopback() -> LoopbackDevice {
+    LoopbackDevice::Single("auto".to_string())
+}
+
+/// Loopback source setting: one device or several mixed together
+///
+/// - `loopback_device = "auto"` - the monitor of the output in use
+/// - `loopback_device = "all"` - the monitors of every output device
+/// - `loopback_device = ["a.monitor", "b.monitor"]` - these sources, mixed
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum LoopbackDevice {
+    /// A device name, or "auto", "all", or "disabled"
+    Single(String),
+    /// Devices whose audio is mixed into the remote channel
+    Multiple(Vec<String>),
+}
+
+impl LoopbackDevice {
+    /// The configured devices, without "disabled" and empty entries
+    pub fn devices(&self) -> Vec<&str> {
+        let devices = match self {
+            Self::Single(device) => vec![device.as_str()],
+            Self::Multiple(devices) => devices.iter().map(String::as_str).collect(),
+        };
+        devices
+            .into_iter()
+            .map(str::trim)
+            .filter(|device| !matches!(*device, "disabled" | ""))
+            .collect()
+    }
 }
 
 fn default_echo_cancel() -> String {
@@ -90,6 +158,7 @@ impl Default for MeetingAudioConfig {
             loopback_device: default_loopback(),
             echo_cancel: default_echo_cancel(),
             vad_threshold: default_meeting_vad_threshold(),
+            player: None,
         }
     }
 }
@@ -138,6 +207,11 @@ pub struct MeetingDiarizationConfig {
     /// RMS floor for treating a sub-window as silence
     #[serde(default = "default_vad_rms_floor")]
     pub vad_rms_floor: f32,
+
+    /// Cosine similarity a speaker needs with a voice print enrolled via
+    /// `voxtype meeting speakers enroll` to be named after it (ml backend)
+    #[serde(default = "default_enrollment_threshold")]
+    pub enrollment_threshold: f32,
 }
 
 fn default_diarization_backend() -> String {
@@ -171,6 +245,13 @@ fn default_vad_rms_floor() -> f32 {
     0.005
 }
 
+// Stricter than similarity_threshold: clusters are compared by their
+// centroids, which are far less noisy than single 4s windows, and a wrong
+// name is worse than SPEAKER_00.
+fn default_enrollment_threshold() -> f32 {
+    0.45
+}
+
 fn default_chunk_duration() -> u32 {
     30
 }
@@ -195,6 +276,7 @@ impl Default for MeetingDiarizationConfig {
             vad_window_secs: default_vad_window_secs(),
             vad_hop_secs: default_vad_hop_secs(),
             vad_rms_floor: default_vad_rms_floor(),
+            enrollment_threshold: default_enrollment_threshold(),
         }
     }
 }
@@ -225,6 +307,16 @@ pub struct MeetingSummaryConfig {
     /// Request timeout in seconds
     #[serde(default = "default_summary_timeout")]
     pub timeout_secs: u64,
+
+    /// Longest transcript excerpt sent in one request, in characters.
+    /// Longer transcripts are summarized in sections that are then merged.
+    #[serde(default = "default_summary_max_prompt_chars")]
+    pub max_prompt_chars: usize,
+
+    /// Update a running summary every this many minutes during a meeting
+    /// (0 = only summarize on request)
+    #[serde(default)]
+    pub rolling_interval_mins: u64,
 }
 
 fn default_summary_backend() -> String {
@@ -243,6 +335,10 @@ fn default_summary_timeout() -> u64 {
     120
 }
 
+fn default_summary_max_prompt_chars() -> usize {
+    12000
+}
+
 impl Default for MeetingSummaryConfig {
     fn default() -> Self {
         Self {
@@ -252,10 +348,430 @@ impl Default for MeetingSummaryConfig {
             remote_endpoint: None,
             remote_api_key: None,
             timeout_secs: default_summary_timeout(),
+            max_prompt_chars: default_summary_max_prompt_chars(),
+            rolling_interval_mins: 0,
+        }
+    }
+}
+
+/// Calendar lookup: when a meeting starts without a title, the event
+/// happening now supplies the title and attendees
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MeetingCalendarConfig {
+    /// Where to look up events: "disabled", "ics", or "khal"
+    #[serde(default = "default_calendar_source")]
+    pub source: String,
+
+    /// ICS files or http(s)/webcal URLs to read (source = "ics")
+    #[serde(default)]
+    pub ics: Vec<String>,
+
+    /// khal calendars to search; all when empty (source = "khal")
+    #[serde(default)]
+    pub khal_calendars: Vec<String>,
+
+    /// How many minutes before an event's start a meeting still picks it up
+    #[serde(default = "default_calendar_early_mins")]
+    pub early_mins: u32,
+
+    /// Seconds to wait for the calendar before starting the meeting untitled
+    #[serde(default = "default_calendar_timeout")]
+    pub timeout_secs: u64,
+}
+
+fn default_calendar_source() -> String {
+    "disabled".to_string()
+}
+
+fn default_calendar_early_mins() -> u32 {
+    10
+}
+
+fn default_calendar_timeout() -> u64 {
+    5
+}
+
+impl Default for MeetingCalendarConfig {
+    fn default() -> Self {
+        Self {
+            source: default_calendar_source(),
+            ics: Vec::new(),
+            khal_calendars: Vec::new(),
+            early_mins: default_calendar_early_mins(),
+            timeout_secs: default_calendar_timeout(),
+        }
+    }
+}
+
+/// Meeting hooks: a finished meeting is exported into its directory, then
+/// the commands run and the webhook is called with the meeting ID and the
+/// export paths
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MeetingHooksConfig {
+    /// Shell command run when a meeting ends
+    #[serde(default)]
+    pub on_complete: Option<String>,
+
+    /// Shell command run after `voxtype meeting summarize`
+    #[serde(default)]
+    pub on_summary: Option<String>,
+
+    /// URL that receives both events as JSON
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+
+    /// Sent as `Authorization: Bearer <token>` (optional, can also use
+    /// VOXTYPE_MEETING_WEBHOOK_TOKEN env var)
+    #[serde(default)]
+    pub webhook_token: Option<String>,
+
+    /// Formats exported before the hooks run (default: markdown)
+    #[serde(default = "default_hook_export_formats")]
+    pub export_formats: Vec<String>,
+
+    /// Seconds a command or webhook may take
+    #[serde(default = "default_hook_timeout")]
+    pub timeout_secs: u64,
+}
+
+fn default_hook_export_formats() -> Vec<String> {
+    vec!["markdown".to_string()]
+}
+
+fn default_hook_timeout() -> u64 {
+    30
+}
+
+impl Default for MeetingHooksConfig {
+    fn default() -> Self {
+        Self {
+            on_complete: None,
+            on_summary: None,
+            webhook_url: None,
+            webhook_token: None,
+            export_formats: default_hook_export_formats(),
+            timeout_secs: default_hook_timeout(),
+        }
+    }
+}
+
+/// Export defaults, for dropping meeting notes into a notes app
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MeetingExportConfig {
+    /// Template for `--format template`: "obsidian", "logseq", or the path
+    /// of a Handlebars template file
+    #[serde(default = "default_export_template")]
+    pub template: String,
+
+    /// Directory exports are written to when no `--output` is given
+    /// (default: stdout)
+    #[serde(default)]
+    pub out_dir: Option<String>,
+
+    /// Tags available to templates (the built-in ones put them in the
+    /// front matter)
+    #[serde(default = "default_export_tags")]
+    pub tags: Vec<String>,
+}
+
+fn default_export_template() -> String {
+    "obsidian".to_string()
+}
+
+fn default_export_tags() -> Vec<String> {
+    vec!["meeting".to_string()]
+}
+
+impl Default for MeetingExportConfig {
+    fn default() -> Self {
+        Self {
+            template: default_export_template(),
+            out_dir: None,
+            tags: default_export_tags(),
         }
     }
 }
 
+/// Translation of meeting segments, stored next to the original text
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MeetingTranslationConfig {
+    /// Translation backend: "whisper" (whisper's translate task, English
+    /// only), "llm", or "disabled"
+    #[serde(default = "default_translation_backend")]
+    pub backend: String,
+
+    /// Language to translate into (llm backend)
+    #[serde(default = "default_translation_language")]
+    pub target_language: String,
+
+    /// Model for the llm backend (default: `[output.llm]`, else Ollama
+    /// with its default model)
+    #[serde(default)]
+    pub llm: Option<LlmConfig>,
+}
+
+fn default_translation_backend() -> String {
+    "disabled".to_string()
+}
+
+fn default_translation_language() -> String {
+    "English".to_string()
+}
+
+impl Default for MeetingTranslationConfig {
+    fn default() -> Self {
+        Self {
+            backend: default_translation_backend(),
+            target_language: default_translation_language(),
+            llm: None,
+        }
+    }
+}
+
+/// Splitting finished meetings into titled chapters
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MeetingChaptersConfig {
+    /// Chapter backend: "similarity" (split where the vocabulary changes,
+    /// titled with keywords), "llm" (the same split, titled by the
+    /// `[meeting.summary]` model), or "disabled"
+    #[serde(default = "default_chapters_backend")]
+    pub backend: String,
+
+    /// Shortest chapter in minutes
+    #[serde(default = "default_min_chapter_mins")]
+    pub min_chapter_mins: u32,
+}
+
+fn default_chapters_backend() -> String {
+    "disabled".to_string()
+}
+
+fn default_min_chapter_mins() -> u32 {
+    5
+}
+
+impl Default for MeetingChaptersConfig {
+    fn default() -> Self {
+        Self {
+            backend: default_chapters_backend(),
+            min_chapter_mins: default_min_chapter_mins(),
+        }
+    }
+}
+
+/// Pruning of stored meetings, by the daemon and `voxtype meeting prune`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MeetingRetentionConfig {
+    /// Prune meetings that started more than this many days ago (0 = keep
+    /// them forever)
+    #[serde(default)]
+    pub max_age_days: u32,
+
+    /// Prune the oldest meetings while all meetings take more than this
+    /// many megabytes (0 = no limit)
+    #[serde(default)]
+    pub max_total_size_mb: u64,
+
+    /// Prune by deleting only the retained audio, keeping transcripts and
+    /// summaries
+    #[serde(default)]
+    pub keep_summaries: bool,
+
+    /// How often the daemon prunes, in hours
+    #[serde(default = "default_prune_interval_hours")]
+    pub prune_interval_hours: u32,
+}
+
+fn default_prune_interval_hours() -> u32 {
+    24
+}
+
+impl Default for MeetingRetentionConfig {
+    fn default() -> Self {
+        Self {
+            max_age_days: 0,
+            max_total_size_mb: 0,
+            keep_summaries: false,
+            prune_interval_hours: default_prune_interval_hours(),
+        }
+    }
+}
+
+/// Upload of finished meetings to a sync server
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MeetingSyncConfig {
+    /// Base URL meetings are uploaded to; a meeting goes to
+    /// `<endpoint>/<meeting id>`
+    #[serde(default)]
+    pub endpoint: Option<String>,
+
+    /// Sent as `Authorization: Bearer <token>` (optional, can also use
+    /// VOXTYPE_MEETING_SYNC_TOKEN env var)
+    #[serde(default)]
+    pub token: Option<String>,
+
+    /// Upload meetings from the daemon when they end, and retry failed
+    /// uploads every `interval_mins`
+    #[serde(default = "default_true")]
+    pub auto: bool,
+
+    /// Also upload the retained audio
+    #[serde(default)]
+    pub include_audio: bool,
+
+    /// Retries of a failed request, with growing delays
+    #[serde(default = "default_sync_retries")]
+    pub max_retries: u32,
+
+    /// Seconds a request may take
+    #[serde(default = "default_sync_timeout")]
+    pub timeout_secs: u64,
+
+    /// How often the daemon uploads meetings that haven't synced, in minutes
+    #[serde(default = "default_sync_interval")]
+    pub interval_mins: u32,
+}
+
+fn default_sync_retries() -> u32 {
+    3
+}
+
+fn default_sync_timeout() -> u64 {
+    60
+}
+
+fn default_sync_interval() -> u32 {
+    30
+}
+
+impl Default for MeetingSyncConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: None,
+            token: None,
+            auto: true,
+            include_audio: false,
+            max_retries: default_sync_retries(),
+            timeout_secs: default_sync_timeout(),
+            interval_mins: default_sync_interval(),
+        }
+    }
+}
+
+/// Starting meetings when a conferencing app records from the microphone
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MeetingAutoStartConfig {
+    /// What to do when a call starts: "auto" (start a meeting), "prompt"
+    /// (ask with a notification), or "disabled"
+    #[serde(default = "default_auto_start_mode")]
+    pub mode: String,
+
+    /// Apps that hold calls: case-insensitive regexes matched against the
+    /// name and binary of each app recording from an input device
+    #[serde(default = "default_auto_start_apps")]
+    pub apps: Vec<String>,
+
+    /// Stop the meeting when the call ends
+    #[serde(default = "default_true")]
+    pub stop_on_end: bool,
+
+    /// How often to check for calls, in seconds
+    #[serde(default = "default_auto_start_poll_secs")]
+    pub poll_secs: u32,
+
+    /// How long no call app may record before the call counts as ended,
+    /// in seconds
+    #[serde(default = "default_auto_start_end_grace_secs")]
+    pub end_grace_secs: u32,
+}
+
+fn default_auto_start_mode() -> String {
+    "disabled".to_string()
+}
+
+fn default_auto_start_apps() -> Vec<String> {
+    [
+        "zoom",
+        "teams",
+        "webex",
+        "slack",
+        "discord",
+        "skype",
+        "jitsi",
+        "firefox",
+        "chrom(e|ium)",
+        "brave",
+        "msedge",
+    ]
+    .iter()
+    .map(|app| app.to_string())
+    .collect()
+}
+
+fn default_auto_start_poll_secs() -> u32 {
+    5
+}
+
+fn default_auto_start_end_grace_secs() -> u32 {
+    30
+}
+
+impl Default for MeetingAutoStartConfig {
+    fn default() -> Self {
+        Self {
+            mode: default_auto_start_mode(),
+            apps: default_auto_start_apps(),
+            stop_on_end: true,
+            poll_secs: default_auto_start_poll_secs(),
+            end_grace_secs: default_auto_start_end_grace_secs(),
+        }
+    }
+}
+
+impl MeetingAutoStartConfig {
+    /// Whether calls are watched for
+    pub fn is_enabled(&self) -> bool {
+        matches!(self.mode.as_str(), "auto" | "prompt")
+    }
+
+    /// Whether meetings start without asking
+    pub fn is_auto(&self) -> bool {
+        self.mode == "auto"
+    }
+}
+
+impl MeetingSyncConfig {
+    /// Whether an endpoint is set
+    pub fn is_enabled(&self) -> bool {
+        self.endpoint
+            .as_deref()
+            .is_some_and(|e| !e.trim().is_empty())
+    }
+}
+
+impl MeetingRetentionConfig {
+    /// Whether an age or size limit is set
+    pub fn is_enabled(&self) -> bool {
+        self.max_age_days > 0 || self.max_total_size_mb > 0
+    }
+}
+
+impl MeetingChaptersConfig {
+    /// Whether meetings are split into chapters when they end
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self.backend.as_str(), "disabled" | "")
+    }
+}
+
+impl MeetingHooksConfig {
+    /// Whether any hook is set
+    pub fn is_configured(&self) -> bool {
+        [&self.on_complete, &self.on_summary, &self.webhook_url]
+            .iter()
+            .any(|hook| hook.as_deref().is_some_and(|h| !h.trim().is_empty()))
+    }
+}
+
 impl Default for MeetingConfig {
     fn default() -> Self {
         Self {
@@ -267,6 +783,14 @@ impl Default for MeetingConfig {
             audio: MeetingAudioConfig::default(),
             diarization: MeetingDiarizationConfig::default(),
             summary: MeetingSummaryConfig::default(),
+            calendar: MeetingCalendarConfig::default(),
+            hooks: MeetingHooksConfig::default(),
+            export: MeetingExportConfig::default(),
+            translation: MeetingTranslationConfig::default(),
+            chapters: MeetingChaptersConfig::default(),
+            retention: MeetingRetentionConfig::default(),
+            sync: MeetingSyncConfig::default(),
+            auto_start: MeetingAutoStartConfig::default(),
         }
     }
 }
@@ -290,8 +814,33 @@ mod tests {
     fn test_meeting_audio_config_default() {
         let config = MeetingAudioConfig::default();
         assert_eq!(config.mic_device, "default");
-        assert_eq!(config.loopback_device, "auto");
+        assert_eq!(config.loopback_device.devices(), vec!["auto"]);
         assert_eq!(config.vad_threshold, 0.01);
+        assert!(config.player.is_none());
+    }
+
+    #[test]
+    fn test_loopback_device() {
+        let config: MeetingConfig = toml::from_str(
+            r#"
+            [audio]
+            loopback_device = "disabled"
+        "#,
+        )
+        .unwrap();
+        assert!(config.audio.loopback_device.devices().is_empty());
+
+        let config: MeetingConfig = toml::from_str(
+            r#"
+            [audio]
+            loopback_device = ["alsa_output.usb.monitor", "", "alsa_output.pci.monitor"]
+        "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.audio.loopback_device.devices(),
+            vec!["alsa_output.usb.monitor", "alsa_output.pci.monitor"]
+        );
     }
 
     #[test]
@@ -300,6 +849,7 @@ mod tests {
         assert!(config.enabled);
         assert_eq!(config.backend, "simple");
         assert_eq!(config.max_speakers, 10);
+        assert!((config.enrollment_threshold - 0.45).abs() < f32::EPSILON);
     }
 
     #[test]
@@ -311,6 +861,178 @@ mod tests {
         assert!(config.remote_endpoint.is_none());
         assert!(config.remote_api_key.is_none());
         assert_eq!(config.timeout_secs, 120);
+        assert_eq!(config.max_prompt_chars, 12000);
+        assert_eq!(config.rolling_interval_mins, 0);
+    }
+
+    #[test]
+    fn test_meeting_summary_config_rolling() {
+        let config: MeetingSummaryConfig = toml::from_str(
+            r#"
+            backend = "local"
+            rolling_interval_mins = 10
+            max_prompt_chars = 8000
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.rolling_interval_mins, 10);
+        assert_eq!(config.max_prompt_chars, 8000);
+        assert_eq!(config.timeout_secs, 120);
+    }
+
+    #[test]
+    fn test_meeting_calendar_config_default() {
+        let config = MeetingCalendarConfig::default();
+        assert_eq!(config.source, "disabled");
+        assert!(config.ics.is_empty());
+        assert!(config.khal_calendars.is_empty());
+        assert_eq!(config.early_mins, 10);
+        assert_eq!(config.timeout_secs, 5);
+    }
+
+    #[test]
+    fn test_meeting_export_config() {
+        let config = MeetingExportConfig::default();
+        assert_eq!(config.template, "obsidian");
+        assert_eq!(config.out_dir, None);
+        assert_eq!(config.tags, ["meeting"]);
+
+        let config: MeetingConfig = toml::from_str(
+            r#"
+            [export]
+            template = "~/vault/templates/meeting.hbs"
+            out_dir = "~/vault/Meetings"
+        "#,
+        )
+        .unwrap();
+        assert_eq!(config.export.out_dir.as_deref(), Some("~/vault/Meetings"));
+        assert_eq!(config.export.tags, ["meeting"]);
+    }
+
+    #[test]
+    fn test_meeting_translation_config() {
+        let config = MeetingTranslationConfig::default();
+        assert_eq!(config.backend, "disabled");
+        assert_eq!(config.target_language, "English");
+        assert!(config.llm.is_none());
+
+        let config: MeetingConfig = toml::from_str(
+            r#"
+            [translation]
+            backend = "llm"
+            target_language = "German"
+
+            [translation.llm]
+            model = "qwen2.5:7b"
+        "#,
+        )
+        .unwrap();
+        assert_eq!(config.translation.backend, "llm");
+        assert_eq!(config.translation.target_language, "German");
+        assert_eq!(config.translation.llm.unwrap().model, "qwen2.5:7b");
+    }
+
+    #[test]
+    fn test_meeting_chapters_config() {
+        let config = MeetingChaptersConfig::default();
+        assert_eq!(config.backend, "disabled");
+        assert_eq!(config.min_chapter_mins, 5);
+        assert!(!config.is_enabled());
+
+        let config: MeetingConfig = toml::from_str(
+            r#"
+            [chapters]
+            backend = "llm"
+            min_chapter_mins = 3
+        "#,
+        )
+        .unwrap();
+        assert!(config.chapters.is_enabled());
+        assert_eq!(config.chapters.min_chapter_mins, 3);
+    }
+
+    #[test]
+    fn test_meeting_retention_config() {
+        let config = MeetingRetentionConfig::default();
+        assert_eq!(config.max_age_days, 0);
+        assert_eq!(config.max_total_size_mb, 0);
+        assert!(!config.keep_summaries);
+        assert_eq!(config.prune_interval_hours, 24);
+        assert!(!config.is_enabled());
+
+        let config: MeetingConfig = toml::from_str(
+            r#"
+            [retention]
+            max_total_size_mb = 2048
+            keep_summaries = true
+        "#,
+        )
+        .unwrap();
+        assert!(config.retention.is_enabled());
+        assert_eq!(config.retention.max_total_size_mb, 2048);
+        assert!(config.retention.keep_summaries);
+    }
+
+    #[test]
+    fn test_meeting_sync_config() {
+        let config = MeetingSyncConfig::default();
+        assert!(config.endpoint.is_none());
+        assert!(config.auto);
+        assert!(!config.include_audio);
+        assert_eq!(config.max_retries, 3);
+        assert_eq!(config.timeout_secs, 60);
+        assert_eq!(config.interval_mins, 30);
+        assert!(!config.is_enabled());
+
+        let config: MeetingConfig = toml::from_str(
+            r#"
+            [sync]
+            endpoint = "https://meetings.example.com/api/meetings"
+            include_audio = true
+        "#,
+        )
+        .unwrap();
+        assert!(config.sync.is_enabled());
+        assert!(config.sync.include_audio);
+        assert!(config.sync.auto);
+    }
+
+    #[test]
+    fn test_meeting_auto_start_config() {
+        let config = MeetingAutoStartConfig::default();
+        assert_eq!(config.mode, "disabled");
+        assert!(config.apps.iter().any(|app| app == "zoom"));
+        assert!(config.stop_on_end);
+        assert_eq!(config.poll_secs, 5);
+        assert_eq!(config.end_grace_secs, 30);
+        assert!(!config.is_enabled());
+
+        let config: MeetingConfig = toml::from_str(
+            r#"
+            [auto_start]
+            mode = "prompt"
+            apps = ["zoom"]
+        "#,
+        )
+        .unwrap();
+        assert!(config.auto_start.is_enabled());
+        assert!(!config.auto_start.is_auto());
+        assert_eq!(config.auto_start.apps, vec!["zoom"]);
+    }
+
+    #[test]
+    fn test_meeting_hooks_config() {
+        let config: MeetingConfig = toml::from_str(
+            r#"
+            [hooks]
+            on_complete = "wiki-upload.sh"
+        "#,
+        )
+        .unwrap();
+        assert!(config.hooks.is_configured());
+        assert_eq!(config.hooks.export_formats, ["markdown"]);
+        assert_eq!(config.hooks.timeout_secs, 30);
+        assert!(!MeetingConfig::default().hooks.is_configured());
     }
 
     #[test]
@@ -390,11 +1112,15 @@ mod tests {
             backend = "local"
             ollama_model = "mistral"
             timeout_secs = 60
+
+            [meeting.calendar]
+            source = "ics"
+            ics = ["~/calendars/work.ics", "https://example.com/team.ics"]
         "#;
 
         let config: Config = toml::from_str(toml_str).unwrap();
         assert_eq!(config.meeting.audio.mic_device, "hw:1");
-        assert_eq!(config.meeting.audio.loopback_device, "disabled");
+        assert!(config.meeting.audio.loopback_device.devices().is_empty());
         assert_eq!(config.meeting.audio.vad_threshold, 0.001);
         assert!(!config.meeting.diarization.enabled);
         assert_eq!(config.meeting.diarization.backend, "ml");
@@ -402,6 +1128,9 @@ mod tests {
         assert_eq!(config.meeting.summary.backend, "local");
         assert_eq!(config.meeting.summary.ollama_model, "mistral");
         assert_eq!(config.meeting.summary.timeout_secs, 60);
+        assert_eq!(config.meeting.calendar.source, "ics");
+        assert_eq!(config.meeting.calendar.ics.len(), 2);
+        assert_eq!(config.meeting.calendar.early_mins, 10);
     }
 
     #[test]
@@ -13,6 +13,9 @@ mod hotkey;
 mod language;
 mod load;
 mod meeting;
+mod models;
+mod mqtt;
+mod network;
 mod notification;
 mod output;
 mod parse;
@@ -20,7 +23,9 @@ mod profile;
 mod root;
 mod status;
 mod text;
+mod transcribe;
 mod vad;
+mod wake_word;
 mod whisper;
 
 pub use audio::{AudioConfig, AudioFeedbackConfig};
@@ -28,24 +33,39 @@ pub use default_config::{default_config_content, DEFAULT_CONFIG};
 pub use engines::{
     CohereConfig, DolphinConfig, MoonshineConfig, OmnilingualConfig, ParaformerConfig,
     ParakeetConfig, ParakeetModelType, SenseVoiceConfig, SonioxConfig, TranscriptionEngine,
+    VoskConfig, ZipformerConfig,
 };
 pub use hotkey::{ActivationMode, HotkeyConfig};
 pub use language::LanguageConfig;
 pub use load::{load_config, save_config};
 pub use meeting::{
-    MeetingAudioConfig, MeetingConfig, MeetingDiarizationConfig, MeetingSummaryConfig,
+    LoopbackDevice, MeetingAudioConfig, MeetingAutoStartConfig, MeetingCalendarConfig,
+    MeetingChaptersConfig, MeetingConfig, MeetingDiarizationConfig, MeetingExportConfig,
+    MeetingHooksConfig, MeetingRetentionConfig, MeetingSummaryConfig, MeetingSyncConfig,
+    MeetingTranslationConfig,
 };
+pub use models::{CustomModel, ModelsConfig};
+pub use mqtt::MqttConfig;
+pub use network::NetworkConfig;
 pub use notification::NotificationConfig;
 pub use output::{
-    default_language_to_layout, AppliedLanguageXkbHint, FileMode, OutputConfig, OutputDriver,
-    OutputMode,
+    default_language_to_layout, AppliedLanguageXkbHint, ChunkingConfig, ConfirmConfig, FileMode,
+    OutputConfig, OutputDriver, OutputMode, PacingConfig, WebhookConfig,
+};
+pub use profile::{
+    LlmBackend, LlmConfig, PostProcessConfig, PostProcessStage, Profile, ProfileRule,
+    StageErrorPolicy,
 };
-pub use profile::{PostProcessConfig, Profile};
 pub use root::Config;
 pub use status::{ResolvedIcons, StatusConfig, StatusIconOverrides};
-pub use text::TextConfig;
+pub use text::{IdentifierStyle, ProfanityMode, TextConfig, TextRule};
+pub use transcribe::{LowConfidenceAction, TranscribeConfig};
 pub use vad::{VadBackend, VadConfig};
-pub use whisper::{WhisperConfig, WhisperMode};
+pub use wake_word::{Activation, WakeWordConfig};
+pub use whisper::{
+    RedoAction, RemoteEndpoint, RemoteEndpointConfig, RemoteEndpointEntry, WhisperConfig,
+    WhisperDecodingConfig, WhisperMode,
+};
 
 pub(super) fn default_true() -> bool {
     true
@@ -0,0 +1,149 @@
+//! Model download settings and the user-defined model registry
+//! (`[models]`, `[models.custom]`).
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use super::TranscriptionEngine;
+
+/// Model registry configuration
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ModelsConfig {
+    /// Extra named models, usable anywhere a built-in model name is:
+    /// the engine's `model`, `--model`, profiles and `voxtype setup model`.
+    ///
+    /// ```toml
+    /// [models.custom.my-finetune]
+    /// engine = "whisper"
+    /// url = "https://example.com/ggml-my-finetune.bin"
+    /// sha256 = "3f2a..."
+    ///
+    /// [models.custom.parakeet-medical]
+    /// engine = "parakeet"
+    /// path = "/opt/models/parakeet-medical"
+    /// ```
+    #[serde(default)]
+    pub custom: HashMap<String, CustomModel>,
+
+    /// Hugging Face access token for gated or private model repos
+    /// (optional, can also use HF_TOKEN env var)
+    #[serde(default)]
+    pub hf_token: Option<String>,
+
+    /// Base URL of a Hugging Face mirror, e.g. "https://hf-mirror.com"
+    /// (optional, can also use HF_ENDPOINT env var)
+    #[serde(default)]
+    pub hf_endpoint: Option<String>,
+
+    /// Base URL of a mirror of models.voxtype.io
+    /// (optional, can also use VOXTYPE_MODELS_MIRROR env var)
+    #[serde(default)]
+    pub mirror: Option<String>,
+}
+
+/// A model declared under `[models.custom.<name>]`
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CustomModel {
+    /// Engine that runs this model (default: whisper)
+    #[serde(default)]
+    pub engine: TranscriptionEngine,
+
+    /// Download URL. Whisper only: the file is saved as `ggml-<name>.bin`
+    /// in the models directory by `voxtype setup model`.
+    #[serde(default)]
+    pub url: Option<String>,
+
+    /// Local model file (Whisper) or model directory (other engines).
+    /// Takes precedence over `url`.
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+
+    /// Expected SHA-256 of the downloaded file, checked by `setup model`
+    #[serde(default)]
+    pub sha256: Option<String>,
+
+    /// Short description shown in `voxtype setup model`
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl CustomModel {
+    /// Where the model comes from: the local path if set, else the URL
+    pub fn source(&self) -> Option<String> {
+        self.path
+            .as_ref()
+            .map(|p| p.to_string_lossy().into_owned())
+            .or_else(|| self.url.clone())
+    }
+
+    /// Problem with this declaration, if any. Non-Whisper engines load
+    /// model directories, which can't be fetched from a single URL.
+    pub fn validation_error(&self) -> Option<String> {
+        match (self.engine, &self.path, &self.url) {
+            (_, None, None) => Some("needs a `path` or `url`".to_string()),
+            (TranscriptionEngine::Whisper, _, _) | (_, Some(_), _) => None,
+            (engine, None, Some(_)) => Some(format!(
+                "the {} engine needs a local `path`; only Whisper models can be downloaded from a `url`",
+                engine.name()
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_custom_models() {
+        let config: ModelsConfig = toml::from_str(
+            r#"
+            [custom.my-finetune]
+            url = "https://example.com/ggml-my-finetune.bin"
+            sha256 = "abc123"
+
+            [custom.parakeet-medical]
+            engine = "parakeet"
+            path = "/opt/models/parakeet-medical"
+            "#,
+        )
+        .unwrap();
+
+        let finetune = &config.custom["my-finetune"];
+        assert_eq!(finetune.engine, TranscriptionEngine::Whisper);
+        assert_eq!(
+            finetune.source().as_deref(),
+            Some("https://example.com/ggml-my-finetune.bin")
+        );
+        assert_eq!(finetune.sha256.as_deref(), Some("abc123"));
+        assert!(finetune.validation_error().is_none());
+
+        let medical = &config.custom["parakeet-medical"];
+        assert_eq!(medical.engine, TranscriptionEngine::Parakeet);
+        assert_eq!(
+            medical.source().as_deref(),
+            Some("/opt/models/parakeet-medical")
+        );
+        assert!(medical.validation_error().is_none());
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let model = CustomModel {
+            engine: TranscriptionEngine::Moonshine,
+            url: Some("https://example.com/model.tar.gz".to_string()),
+            path: None,
+            sha256: None,
+            description: None,
+        };
+        assert!(model.validation_error().unwrap().contains("local `path`"));
+
+        let empty = CustomModel {
+            url: None,
+            engine: TranscriptionEngine::Whisper,
+            ..model
+        };
+        assert!(empty.validation_error().is_some());
+    }
+}
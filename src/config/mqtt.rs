@@ -0,0 +1,147 @@
+//! MQTT publisher configuration.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Publishes daemon state and transcriptions to an MQTT broker, for Home
+/// Assistant and other automation hubs (requires the `mqtt` feature)
+///
+/// ```toml
+/// [mqtt]
+/// enabled = true
+/// host = "homeassistant.local"
+/// username = "voxtype"
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MqttConfig {
+    /// Connect to the broker when the daemon starts (default: false)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Broker host name or address (default: "localhost")
+    #[serde(default = "default_host")]
+    pub host: String,
+
+    /// Broker port (default: 1883, or 8883 with TLS)
+    #[serde(default)]
+    pub port: Option<u16>,
+
+    /// Client ID (default: "voxtype-<pid>")
+    #[serde(default)]
+    pub client_id: Option<String>,
+
+    /// User name for broker authentication (optional)
+    #[serde(default)]
+    pub username: Option<String>,
+
+    /// Password for broker authentication (optional, can also use
+    /// VOXTYPE_MQTT_PASSWORD env var)
+    #[serde(default)]
+    pub password: Option<String>,
+
+    /// Connect over TLS (default: false)
+    #[serde(default)]
+    pub tls: bool,
+
+    /// CA certificate (PEM) for a broker with a private certificate
+    /// authority. Without it, the system roots are used.
+    #[serde(default)]
+    pub ca_file: Option<PathBuf>,
+
+    /// Topics are `<topic_prefix>/state` and `<topic_prefix>/transcription`
+    /// (default: "voxtype")
+    #[serde(default = "default_topic_prefix")]
+    pub topic_prefix: String,
+
+    /// Quality of service for published messages: 0, 1 or 2 (default: 1)
+    #[serde(default = "default_qos")]
+    pub qos: u8,
+
+    /// Publish daemon state changes, retained (default: true)
+    #[serde(default = "super::default_true")]
+    pub publish_state: bool,
+
+    /// Publish each transcription as JSON (default: true)
+    #[serde(default = "super::default_true")]
+    pub publish_transcriptions: bool,
+}
+
+fn default_host() -> String {
+    "localhost".to_string()
+}
+
+fn default_topic_prefix() -> String {
+    "voxtype".to_string()
+}
+
+fn default_qos() -> u8 {
+    1
+}
+
+impl Default for MqttConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: default_host(),
+            port: None,
+            client_id: None,
+            username: None,
+            password: None,
+            tls: false,
+            ca_file: None,
+            topic_prefix: default_topic_prefix(),
+            qos: default_qos(),
+            publish_state: true,
+            publish_transcriptions: true,
+        }
+    }
+}
+
+impl MqttConfig {
+    /// The configured port, or the standard one for plain or TLS MQTT
+    pub fn port(&self) -> u16 {
+        self.port.unwrap_or(if self.tls { 8883 } else { 1883 })
+    }
+
+    /// Full topic name for `leaf` under `topic_prefix`
+    pub fn topic(&self, leaf: &str) -> String {
+        let prefix = self.topic_prefix.trim_end_matches('/');
+        if prefix.is_empty() {
+            leaf.to_string()
+        } else {
+            format!("{}/{}", prefix, leaf)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mqtt() {
+        let config: MqttConfig = toml::from_str(
+            r#"
+            enabled = true
+            host = "broker.lan"
+            tls = true
+            topic_prefix = "home/desk/voxtype/"
+        "#,
+        )
+        .unwrap();
+        assert!(config.enabled);
+        assert_eq!(config.host, "broker.lan");
+        assert_eq!(config.port(), 8883);
+        assert_eq!(config.qos, 1);
+        assert!(config.publish_state && config.publish_transcriptions);
+        assert_eq!(config.topic("state"), "home/desk/voxtype/state");
+    }
+
+    #[test]
+    fn test_defaults() {
+        let config = MqttConfig::default();
+        assert!(!config.enabled);
+        assert_eq!(config.port(), 1883);
+        assert_eq!(config.topic("transcription"), "voxtype/transcription");
+    }
+}
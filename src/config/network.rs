@@ -0,0 +1,19 @@
+//! Network configuration (outbound HTTP proxy).
+
+use serde::{Deserialize, Serialize};
+
+/// Proxy settings for model downloads, remote transcription, meeting
+/// summaries and the update check
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct NetworkConfig {
+    /// Proxy URL, e.g. "http://proxy.corp.example:3128" (optional, defaults
+    /// to the HTTPS_PROXY/HTTP_PROXY/ALL_PROXY env vars)
+    #[serde(default)]
+    pub proxy: Option<String>,
+
+    /// Hosts reached directly, bypassing the proxy. Entries match the host
+    /// and its subdomains; "*" disables the proxy. Added to NO_PROXY.
+    /// Loopback addresses never use the proxy.
+    #[serde(default)]
+    pub no_proxy: Vec<String>,
+}
@@ -6,7 +6,7 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 
 use super::default_true;
-use super::{NotificationConfig, PostProcessConfig};
+use super::{LlmConfig, NotificationConfig, PostProcessConfig};
 
 fn default_restore_clipboard_delay() -> u32 {
     200 // 200ms - delay for paste to complete before restoring clipboard
@@ -19,16 +19,34 @@ pub struct OutputConfig {
     #[serde(default)]
     pub mode: OutputMode,
 
+    /// Send each transcription to several outputs, e.g. ["type",
+    /// "clipboard", "file"]. `mode` is the primary output (the first target
+    /// when `mode` isn't set); the others get the text after it, each
+    /// without a fallback.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<OutputMode>,
+
     /// Fall back to clipboard if typing fails
     #[serde(default = "default_true")]
     pub fallback_to_clipboard: bool,
 
-    /// Custom driver order for type mode (overrides default: wtype -> dotool -> ydotool -> clipboard)
+    /// Custom driver order for type mode (overrides default: input-method -> wtype -> eitype -> xdotool -> dotool -> ydotool -> uinput -> clipboard -> xclip)
     /// Specify which drivers to try and in what order.
     /// Example: ["ydotool", "wtype"] to prefer ydotool over wtype
     #[serde(default)]
     pub driver_order: Option<Vec<OutputDriver>>,
 
+    /// In type mode, move the drivers suited to the focused application
+    /// (native Wayland, XWayland, X11 or a text console) to the front of
+    /// the driver order before each output
+    #[serde(default)]
+    pub auto_driver: bool,
+
+    /// Keep one dotool process and one ydotoold connection open between
+    /// outputs instead of spawning the tools each time (default: true)
+    #[serde(default = "default_true")]
+    pub persistent_drivers: bool,
+
     /// Notification settings
     #[serde(default)]
     pub notification: NotificationConfig,
@@ -68,6 +86,29 @@ pub struct OutputConfig {
     #[serde(default)]
     pub wtype_shift_prefix: bool,
 
+    /// Applications where undo (`voxtype undo`, the undo key, or saying
+    /// "undo") presses Ctrl+Z instead of backspacing over the last output.
+    /// Case-insensitive regexes matched against the focused window's class.
+    /// Example: ["libreoffice", "^gimp"]
+    #[serde(default)]
+    pub undo_ctrl_z_apps: Vec<String>,
+
+    /// Show the text for Accept / Edit / Discard before it is output
+    #[serde(default)]
+    pub confirm: ConfirmConfig,
+
+    /// Human-like typing pace for the keystroke drivers
+    #[serde(default)]
+    pub pacing: PacingConfig,
+
+    /// Split long output into chunks with a pause in between
+    #[serde(default)]
+    pub chunking: ChunkingConfig,
+
+    /// HTTP endpoint for webhook mode, or for every dictation with `always`
+    #[serde(default)]
+    pub webhook: WebhookConfig,
+
     /// Command to run when recording starts (e.g., switch to compositor submap)
     /// Useful for entering a mode where cancel keybindings are effective
     #[serde(default)]
@@ -88,6 +129,11 @@ pub struct OutputConfig {
     #[serde(default)]
     pub post_process: Option<PostProcessConfig>,
 
+    /// Optional LLM post-processing without a shell command. Keeps the
+    /// model loaded and replaces `post_process` when both are set.
+    #[serde(default)]
+    pub llm: Option<LlmConfig>,
+
     /// Keystroke to simulate for paste mode (e.g., "ctrl+v", "shift+insert", "ctrl+shift+v")
     /// Defaults to "ctrl+v" if not specified
     #[serde(default)]
@@ -115,6 +161,23 @@ pub struct OutputConfig {
     #[serde(default)]
     pub eitype_xkb_variant: Option<String>,
 
+    /// Keyboard layout of the desktop for the built-in uinput driver
+    /// ("us", "gb", "de" or "fr"; default "us"). The virtual keyboard sends
+    /// key positions, so this must match the layout the compositor uses.
+    #[serde(default)]
+    pub uinput_xkb_layout: Option<String>,
+
+    /// Terminal for the osc52 driver (e.g., "/dev/pts/3"). Defaults to the
+    /// daemon's controlling terminal, which only exists when voxtype runs
+    /// in a terminal rather than as a service.
+    #[serde(default)]
+    pub osc52_tty: Option<String>,
+
+    /// Paste the tmux buffer into the active pane in tmux mode
+    /// (default: false, only load the buffer for prefix + ])
+    #[serde(default)]
+    pub tmux_paste: bool,
+
     /// Mapping from detected language code (two-letter ISO 639-1) to XKB
     /// keyboard layout. When voxtype's transcriber reports a language for the
     /// current transcription and no explicit `eitype_xkb_layout` /
@@ -142,9 +205,22 @@ pub struct OutputConfig {
 
     /// File path for file output mode (required when mode = "file")
     /// Also used as default path for --output-file CLI flag
+    /// May start with ~/ and contain placeholders like {date}, e.g.
+    /// "~/notes/{date}.md" for one file per day
     #[serde(default)]
     pub file_path: Option<PathBuf>,
 
+    /// Format of each transcription written in file mode, with {text},
+    /// {date} and {time} placeholders, e.g. "- {time} {text}\n"
+    /// Default: the text on its own line
+    #[serde(default)]
+    pub file_entry_template: Option<String>,
+
+    /// Written at the top of a file that file mode creates, e.g.
+    /// "# {date}\n\n"
+    #[serde(default)]
+    pub file_header_template: Option<String>,
+
     /// File write mode: "overwrite" (default) or "append"
     /// Applies to both config-based file output and --output-file CLI flag
     #[serde(default)]
@@ -160,6 +236,12 @@ pub struct OutputConfig {
     #[serde(default = "default_restore_clipboard_delay")]
     pub restore_clipboard_delay_ms: u32,
 
+    /// In clipboard mode, restore the previous clipboard content this many
+    /// seconds after copying the transcription, unless something else has
+    /// been copied by then. Unset (default) leaves the transcription.
+    #[serde(default)]
+    pub restore_clipboard_after_secs: Option<u32>,
+
     /// Wait for modifier keys (Ctrl/Alt/Shift/Super) to be released before
     /// typing transcribed text. Prevents the typed letters from combining
     /// with held modifiers and triggering compositor or application
@@ -182,8 +264,11 @@ impl Default for OutputConfig {
     fn default() -> Self {
         Self {
             mode: OutputMode::default(),
+            targets: Vec::new(),
             fallback_to_clipboard: true,
             driver_order: None,
+            auto_driver: false,
+            persistent_drivers: true,
             notification: NotificationConfig::default(),
             type_delay_ms: 0,
             pre_type_delay_ms: 0,
@@ -192,21 +277,33 @@ impl Default for OutputConfig {
             append_text: None,
             shift_enter_newlines: false,
             wtype_shift_prefix: false,
+            undo_ctrl_z_apps: Vec::new(),
+            confirm: ConfirmConfig::default(),
+            pacing: PacingConfig::default(),
+            chunking: ChunkingConfig::default(),
+            webhook: WebhookConfig::default(),
             pre_recording_command: None,
             pre_output_command: None,
             post_output_command: None,
             post_process: None,
+            llm: None,
             paste_keys: None,
             dotool_xkb_layout: None,
             dotool_xkb_variant: None,
             eitype_xkb_layout: None,
             eitype_xkb_variant: None,
+            uinput_xkb_layout: None,
+            osc52_tty: None,
+            tmux_paste: false,
             language_to_layout: default_language_to_layout(),
             language_to_variant: HashMap::new(),
             file_path: None,
+            file_entry_template: None,
+            file_header_template: None,
             file_mode: FileMode::default(),
             restore_clipboard: false,
             restore_clipboard_delay_ms: default_restore_clipboard_delay(),
+            restore_clipboard_after_secs: None,
             wait_for_modifier_release: true,
             modifier_release_timeout_ms: default_modifier_release_timeout_ms(),
         }
@@ -217,6 +314,185 @@ fn default_modifier_release_timeout_ms() -> u64 {
     750
 }
 
+/// Preview-and-confirm step before output
+///
+/// ```toml
+/// [output.confirm]
+/// enabled = true
+/// command = "fuzzel --dmenu --prompt 'voxtype: '"
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ConfirmConfig {
+    /// Ask before outputting each transcription (default: false)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// dmenu-style chooser: reads choices on stdin, prints the selected
+    /// line. Auto-detects fuzzel, wofi, rofi, bemenu or dmenu when unset.
+    #[serde(default)]
+    pub command: Option<String>,
+
+    /// Dialog for Edit: gets the text in `$VOXTYPE_TEXT`, prints the edited
+    /// text. Auto-detects zenity, kdialog or yad when unset.
+    #[serde(default)]
+    pub edit_command: Option<String>,
+
+    /// Seconds to wait for a choice before discarding (default: 120)
+    #[serde(default = "default_confirm_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+fn default_confirm_timeout_secs() -> u64 {
+    120
+}
+
+impl Default for ConfirmConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            command: None,
+            edit_command: None,
+            timeout_secs: default_confirm_timeout_secs(),
+        }
+    }
+}
+
+/// Human-like typing pace
+///
+/// Types one character at a time with a longer pause between words, for
+/// web editors that drop characters when text arrives all at once.
+///
+/// ```toml
+/// [output.pacing]
+/// enabled = true
+/// char_delay_ms = 20
+/// word_pause_ms = 80
+/// jitter = 0.5
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PacingConfig {
+    /// Pace typed output (default: false). Profiles can override this.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Average delay between keystrokes within a word (default: 20).
+    /// 0 types each word in one burst.
+    #[serde(default = "default_pacing_char_delay_ms")]
+    pub char_delay_ms: u32,
+
+    /// Average pause between words (default: 80)
+    #[serde(default = "default_pacing_word_pause_ms")]
+    pub word_pause_ms: u32,
+
+    /// How much each delay varies, as a fraction of it (default: 0.5,
+    /// so 20ms becomes 10-30ms)
+    #[serde(default = "default_pacing_jitter")]
+    pub jitter: f32,
+}
+
+fn default_pacing_char_delay_ms() -> u32 {
+    20
+}
+
+fn default_pacing_word_pause_ms() -> u32 {
+    80
+}
+
+fn default_pacing_jitter() -> f32 {
+    0.5
+}
+
+impl Default for PacingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            char_delay_ms: default_pacing_char_delay_ms(),
+            word_pause_ms: default_pacing_word_pause_ms(),
+            jitter: default_pacing_jitter(),
+        }
+    }
+}
+
+/// Chunked typing for long output
+///
+/// Typing drivers get long text in chunks with a short pause in between,
+/// so compositors and applications don't drop input when thousands of
+/// characters arrive at once. Each driver has its own chunk size and pause;
+/// these settings override them for all drivers.
+///
+/// ```toml
+/// [output.chunking]
+/// chunk_chars = 200
+/// pause_ms = 150
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ChunkingConfig {
+    /// Chunk long output (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Characters per chunk (default: per driver)
+    #[serde(default)]
+    pub chunk_chars: Option<usize>,
+
+    /// Pause between chunks in milliseconds (default: per driver)
+    #[serde(default)]
+    pub pause_ms: Option<u32>,
+}
+
+impl Default for ChunkingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            chunk_chars: None,
+            pause_ms: None,
+        }
+    }
+}
+
+/// HTTP webhook output
+///
+/// ```toml
+/// [output.webhook]
+/// url = "https://n8n.example.com/webhook/dictation"
+/// bearer_token = "secret"
+/// always = true
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WebhookConfig {
+    /// Endpoint that receives `{"text", "profile", "timestamp"}` as JSON
+    #[serde(default)]
+    pub url: Option<String>,
+
+    /// Sent as `Authorization: Bearer <token>` (optional, can also use
+    /// VOXTYPE_WEBHOOK_TOKEN env var)
+    #[serde(default)]
+    pub bearer_token: Option<String>,
+
+    /// Also post every dictation in the other output modes (default: false)
+    #[serde(default)]
+    pub always: bool,
+
+    /// Request timeout in milliseconds (default: 5000)
+    #[serde(default = "default_webhook_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+fn default_webhook_timeout_ms() -> u64 {
+    5000
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            url: None,
+            bearer_token: None,
+            always: false,
+            timeout_ms: default_webhook_timeout_ms(),
+        }
+    }
+}
+
 /// Result of applying a per-language XKB layout/variant hint to output config.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct AppliedLanguageXkbHint {
@@ -343,6 +619,13 @@ pub enum OutputMode {
     Paste,
     /// Write transcription to a file
     File,
+    /// Copy to the primary selection for middle-click paste (wl-copy
+    /// --primary on Wayland, xclip on X11)
+    Primary,
+    /// Load a tmux paste buffer, optionally pasting it into the active pane
+    Tmux,
+    /// POST the text as JSON to `[output.webhook] url`
+    Webhook,
 }
 
 /// Output driver for typing text
@@ -350,29 +633,49 @@ pub enum OutputMode {
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum OutputDriver {
+    /// Wayland input method protocol (zwp_input_method_v2), commits text
+    /// without keystrokes; needs the `wayland-im` build feature
+    #[serde(rename = "input-method")]
+    InputMethod,
     /// wtype - Wayland-native via virtual-keyboard protocol, best Unicode/CJK support
     Wtype,
     /// eitype - Wayland via libei/EI protocol, works on GNOME/KDE
     Eitype,
+    /// xdotool - X11 via XTEST, uses the active X keymap
+    Xdotool,
     /// dotool - Works on X11/Wayland/TTY, supports keyboard layouts
     Dotool,
     /// ydotool - Works on X11/Wayland/TTY, requires daemon
     Ydotool,
+    /// Built-in virtual keyboard via /dev/uinput, no external binary
+    Uinput,
+    /// Insert into the focused text field via the AT-SPI accessibility bus
+    Atspi,
+    /// Commit through IBus, as an input method engine would
+    Ime,
     /// Clipboard via wl-copy (Wayland)
     Clipboard,
     /// Clipboard via xclip (X11)
     Xclip,
+    /// Terminal clipboard via an OSC 52 escape sequence (works over SSH)
+    Osc52,
 }
 
 impl std::fmt::Display for OutputDriver {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            OutputDriver::InputMethod => write!(f, "input-method"),
             OutputDriver::Wtype => write!(f, "wtype"),
             OutputDriver::Eitype => write!(f, "eitype"),
+            OutputDriver::Xdotool => write!(f, "xdotool"),
             OutputDriver::Dotool => write!(f, "dotool"),
             OutputDriver::Ydotool => write!(f, "ydotool"),
+            OutputDriver::Uinput => write!(f, "uinput"),
+            OutputDriver::Atspi => write!(f, "atspi"),
+            OutputDriver::Ime => write!(f, "ime"),
             OutputDriver::Clipboard => write!(f, "clipboard"),
             OutputDriver::Xclip => write!(f, "xclip"),
+            OutputDriver::Osc52 => write!(f, "osc52"),
         }
     }
 }
@@ -382,14 +685,20 @@ impl std::str::FromStr for OutputDriver {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
+            "input-method" | "input_method" => Ok(OutputDriver::InputMethod),
             "wtype" => Ok(OutputDriver::Wtype),
             "eitype" => Ok(OutputDriver::Eitype),
+            "xdotool" => Ok(OutputDriver::Xdotool),
             "dotool" => Ok(OutputDriver::Dotool),
             "ydotool" => Ok(OutputDriver::Ydotool),
+            "uinput" => Ok(OutputDriver::Uinput),
+            "atspi" => Ok(OutputDriver::Atspi),
+            "ime" => Ok(OutputDriver::Ime),
             "clipboard" => Ok(OutputDriver::Clipboard),
             "xclip" => Ok(OutputDriver::Xclip),
+            "osc52" => Ok(OutputDriver::Osc52),
             _ => Err(format!(
-                "Unknown driver '{}'. Valid options: wtype, eitype, dotool, ydotool, clipboard, xclip",
+                "Unknown driver '{}'. Valid options: input-method, wtype, eitype, xdotool, dotool, ydotool, uinput, atspi, ime, clipboard, xclip, osc52",
                 s
             )),
         }
@@ -465,6 +774,14 @@ mod tests {
             "wtype".parse::<OutputDriver>().unwrap(),
             OutputDriver::Wtype
         );
+        assert_eq!(
+            "input-method".parse::<OutputDriver>().unwrap(),
+            OutputDriver::InputMethod
+        );
+        assert_eq!(
+            "xdotool".parse::<OutputDriver>().unwrap(),
+            OutputDriver::Xdotool
+        );
         assert_eq!(
             "dotool".parse::<OutputDriver>().unwrap(),
             OutputDriver::Dotool
@@ -473,6 +790,15 @@ mod tests {
             "ydotool".parse::<OutputDriver>().unwrap(),
             OutputDriver::Ydotool
         );
+        assert_eq!(
+            "uinput".parse::<OutputDriver>().unwrap(),
+            OutputDriver::Uinput
+        );
+        assert_eq!(
+            "atspi".parse::<OutputDriver>().unwrap(),
+            OutputDriver::Atspi
+        );
+        assert_eq!("ime".parse::<OutputDriver>().unwrap(), OutputDriver::Ime);
         assert_eq!(
             "clipboard".parse::<OutputDriver>().unwrap(),
             OutputDriver::Clipboard
@@ -481,6 +807,10 @@ mod tests {
             "xclip".parse::<OutputDriver>().unwrap(),
             OutputDriver::Xclip
         );
+        assert_eq!(
+            "osc52".parse::<OutputDriver>().unwrap(),
+            OutputDriver::Osc52
+        );
         // Case insensitive
         assert_eq!(
             "WTYPE".parse::<OutputDriver>().unwrap(),
@@ -501,10 +831,16 @@ mod tests {
     #[test]
     fn test_output_driver_display() {
         assert_eq!(OutputDriver::Wtype.to_string(), "wtype");
+        assert_eq!(OutputDriver::InputMethod.to_string(), "input-method");
+        assert_eq!(OutputDriver::Xdotool.to_string(), "xdotool");
         assert_eq!(OutputDriver::Dotool.to_string(), "dotool");
         assert_eq!(OutputDriver::Ydotool.to_string(), "ydotool");
+        assert_eq!(OutputDriver::Uinput.to_string(), "uinput");
+        assert_eq!(OutputDriver::Atspi.to_string(), "atspi");
+        assert_eq!(OutputDriver::Ime.to_string(), "ime");
         assert_eq!(OutputDriver::Clipboard.to_string(), "clipboard");
         assert_eq!(OutputDriver::Xclip.to_string(), "xclip");
+        assert_eq!(OutputDriver::Osc52.to_string(), "osc52");
     }
 
     #[test]
@@ -535,11 +871,38 @@ mod tests {
         assert_eq!(driver_order[2], OutputDriver::Clipboard);
     }
 
+    #[test]
+    fn test_parse_input_method_driver_from_toml() {
+        let output: OutputConfig = toml::from_str(
+            r#"
+            driver_order = ["input-method", "wtype"]
+        "#,
+        )
+        .unwrap();
+        assert_eq!(
+            output.driver_order,
+            Some(vec![OutputDriver::InputMethod, OutputDriver::Wtype])
+        );
+    }
+
     #[test]
     fn test_restore_clipboard_defaults() {
         let config = Config::default();
         assert!(!config.output.restore_clipboard);
         assert_eq!(config.output.restore_clipboard_delay_ms, 200);
+        assert_eq!(config.output.restore_clipboard_after_secs, None);
+    }
+
+    #[test]
+    fn test_restore_clipboard_after_secs() {
+        let output: OutputConfig = toml::from_str(
+            r#"
+            mode = "clipboard"
+            restore_clipboard_after_secs = 45
+        "#,
+        )
+        .unwrap();
+        assert_eq!(output.restore_clipboard_after_secs, Some(45));
     }
 
     #[test]
@@ -735,6 +1098,145 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_confirm() {
+        let toml_str = r#"
+            [hotkey]
+            key = "PAUSE"
+
+            [audio]
+            device = "default"
+            sample_rate = 16000
+            max_duration_secs = 60
+
+            [whisper]
+            model = "base.en"
+            language = "en"
+
+            [output]
+            mode = "type"
+
+            [output.confirm]
+            enabled = true
+            command = "rofi -dmenu"
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.output.confirm.enabled);
+        assert_eq!(
+            config.output.confirm.command,
+            Some("rofi -dmenu".to_string())
+        );
+        assert_eq!(config.output.confirm.edit_command, None);
+        assert_eq!(config.output.confirm.timeout_secs, 120);
+        assert!(!OutputConfig::default().confirm.enabled);
+    }
+
+    #[test]
+    fn test_parse_pacing() {
+        let toml_str = r#"
+            [hotkey]
+            key = "PAUSE"
+
+            [audio]
+            device = "default"
+            sample_rate = 16000
+            max_duration_secs = 60
+
+            [whisper]
+            model = "base.en"
+            language = "en"
+
+            [output]
+            mode = "type"
+
+            [output.pacing]
+            enabled = true
+            word_pause_ms = 150
+
+            [profiles.docs]
+            pacing = false
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.output.pacing.enabled);
+        assert_eq!(config.output.pacing.char_delay_ms, 20);
+        assert_eq!(config.output.pacing.word_pause_ms, 150);
+        assert_eq!(config.output.pacing.jitter, 0.5);
+        assert_eq!(config.profiles["docs"].pacing, Some(false));
+        assert!(!OutputConfig::default().pacing.enabled);
+    }
+
+    #[test]
+    fn test_parse_chunking() {
+        let output: OutputConfig = toml::from_str(
+            r#"
+            [chunking]
+            pause_ms = 150
+        "#,
+        )
+        .unwrap();
+        assert!(output.chunking.enabled);
+        assert_eq!(output.chunking.chunk_chars, None);
+        assert_eq!(output.chunking.pause_ms, Some(150));
+        assert!(OutputConfig::default().chunking.enabled);
+    }
+
+    #[test]
+    fn test_parse_webhook() {
+        let toml_str = r#"
+            [hotkey]
+            key = "PAUSE"
+
+            [audio]
+            device = "default"
+            sample_rate = 16000
+            max_duration_secs = 60
+
+            [whisper]
+            model = "base.en"
+            language = "en"
+
+            [output]
+            mode = "webhook"
+
+            [output.webhook]
+            url = "http://localhost:5678/webhook/dictation"
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.output.mode, OutputMode::Webhook);
+        assert_eq!(
+            config.output.webhook.url.as_deref(),
+            Some("http://localhost:5678/webhook/dictation")
+        );
+        assert!(!config.output.webhook.always);
+        assert_eq!(config.output.webhook.timeout_ms, 5000);
+    }
+
+    #[test]
+    fn test_parse_undo_ctrl_z_apps() {
+        let toml_str = r#"
+            [hotkey]
+            key = "PAUSE"
+            undo_key = "F9"
+
+            [audio]
+            device = "default"
+            sample_rate = 16000
+            max_duration_secs = 60
+
+            [whisper]
+            model = "base.en"
+            language = "en"
+
+            [output]
+            mode = "type"
+            undo_ctrl_z_apps = ["libreoffice", "^gimp"]
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.hotkey.undo_key, Some("F9".to_string()));
+        assert_eq!(config.output.undo_ctrl_z_apps, vec!["libreoffice", "^gimp"]);
+        assert!(OutputConfig::default().undo_ctrl_z_apps.is_empty());
+    }
+
     #[test]
     fn test_parse_language_to_layout_override() {
         // User can override individual mappings (e.g. Brazilian Portuguese
@@ -11,12 +11,61 @@ use super::Config;
 pub fn parse_config_with_defaults(contents: &str) -> Result<Config, toml::de::Error> {
     let defaults = toml::Value::try_from(Config::default())
         .expect("Config::default() must be serializable to TOML");
-    let user: toml::Value = toml::from_str(contents)?;
+    let mut user: toml::Value = toml::from_str(contents)?;
+    split_engine_chain(&mut user);
+    default_mode_from_targets(&mut user);
     let mut merged = defaults;
     merge_toml_values(&mut merged, user);
     merged.try_into()
 }
 
+/// Rewrite `engine = ["parakeet", "whisper"]` into `engine = "parakeet"`
+/// plus `engine_fallback = ["whisper"]`. Lets users write the fallback chain
+/// as a single list while the rest of the codebase keeps treating `engine`
+/// as one value. A plain string `engine` is left alone, as is an explicit
+/// `engine_fallback` key when `engine` is a string.
+fn split_engine_chain(user: &mut toml::Value) {
+    let Some(table) = user.as_table_mut() else {
+        return;
+    };
+    let Some(toml::Value::Array(chain)) = table.get("engine").cloned() else {
+        return;
+    };
+    let mut chain = chain.into_iter();
+    match chain.next() {
+        Some(primary) => {
+            table.insert("engine".to_string(), primary);
+            table.insert(
+                "engine_fallback".to_string(),
+                toml::Value::Array(chain.collect()),
+            );
+        }
+        None => {
+            table.remove("engine");
+        }
+    }
+}
+
+/// `[output] targets = ["paste", "clipboard"]` without a `mode` makes the
+/// first target the mode, which keeps its fallback chain and mode-specific
+/// handling; the other targets get the text afterwards.
+fn default_mode_from_targets(user: &mut toml::Value) {
+    let Some(output) = user.get_mut("output").and_then(|o| o.as_table_mut()) else {
+        return;
+    };
+    if output.contains_key("mode") {
+        return;
+    }
+    let first = output
+        .get("targets")
+        .and_then(|t| t.as_array())
+        .and_then(|t| t.first())
+        .cloned();
+    if let Some(first) = first {
+        output.insert("mode".to_string(), first);
+    }
+}
+
 /// Deep-merge `overlay` onto `base`. Tables merge recursively; for any other
 /// value type (or when the two sides have mismatched types), `overlay` wins.
 /// Arrays are replaced wholesale rather than concatenated. Extending a
@@ -39,7 +88,7 @@ fn merge_toml_values(base: &mut toml::Value, overlay: toml::Value) {
 
 #[cfg(test)]
 mod tests {
-    use super::super::{ActivationMode, OutputMode};
+    use super::super::{ActivationMode, OutputMode, TranscriptionEngine};
     use super::*;
 
     #[test]
@@ -78,6 +127,54 @@ mod tests {
         assert!(!config.output.notification.on_transcription);
     }
 
+    #[test]
+    fn engine_array_becomes_primary_plus_fallbacks() {
+        let cfg = parse_config_with_defaults(r#"engine = ["parakeet", "moonshine", "whisper"]"#)
+            .expect("engine array must parse");
+        assert_eq!(cfg.engine, TranscriptionEngine::Parakeet);
+        assert_eq!(
+            cfg.engine_fallback,
+            vec![TranscriptionEngine::Moonshine, TranscriptionEngine::Whisper]
+        );
+    }
+
+    #[test]
+    fn single_engine_has_no_fallbacks() {
+        let cfg = parse_config_with_defaults(r#"engine = "parakeet""#).unwrap();
+        assert_eq!(cfg.engine, TranscriptionEngine::Parakeet);
+        assert!(cfg.engine_fallback.is_empty());
+
+        let cfg = parse_config_with_defaults("engine = []").unwrap();
+        assert_eq!(cfg.engine, TranscriptionEngine::Whisper);
+    }
+
+    #[test]
+    fn output_targets_set_the_default_mode() {
+        let cfg = parse_config_with_defaults(
+            r#"
+            [output]
+            targets = ["paste", "clipboard", "file"]
+        "#,
+        )
+        .unwrap();
+        assert_eq!(cfg.output.mode, OutputMode::Paste);
+        assert_eq!(
+            cfg.output.targets,
+            vec![OutputMode::Paste, OutputMode::Clipboard, OutputMode::File]
+        );
+
+        // An explicit mode stays the primary output
+        let cfg = parse_config_with_defaults(
+            r#"
+            [output]
+            mode = "type"
+            targets = ["clipboard"]
+        "#,
+        )
+        .unwrap();
+        assert_eq!(cfg.output.mode, OutputMode::Type);
+    }
+
     #[test]
     fn empty_toml_yields_default_config() {
         // Invariant: parse_config_with_defaults("") must produce exactly
@@ -1,9 +1,11 @@
 //! Profile and post-process configuration.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 use super::default_true;
-use super::OutputMode;
+use super::{IdentifierStyle, LanguageConfig, OutputMode, TranscriptionEngine};
 
 /// Post-processing command configuration
 ///
@@ -13,6 +15,8 @@ use super::OutputMode;
 pub struct PostProcessConfig {
     /// Shell command to execute
     /// Receives transcribed text on stdin, outputs processed text on stdout
+    /// Not needed when `stages` is set
+    #[serde(default)]
     pub command: String,
 
     /// Timeout in milliseconds (default: 30000 = 30 seconds)
@@ -30,6 +34,168 @@ pub struct PostProcessConfig {
     /// e.g. filtering out unwanted transcriptions like [BLANK_AUDIO].
     #[serde(default = "default_true")]
     pub fallback_on_empty: bool,
+
+    /// Ordered pipeline run instead of `command` (default: empty)
+    /// Each stage receives the previous stage's output.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stages: Vec<PostProcessStage>,
+}
+
+/// One step of a chained post-processing pipeline
+///
+/// ```toml
+/// [[output.post_process.stages]]
+/// type = "command"
+/// command = "remove-fillers.sh"
+/// timeout_ms = 2000
+/// on_error = "skip"
+///
+/// [[output.post_process.stages]]
+/// type = "regex"
+/// pattern = "\\s+([,.])"
+/// replacement = "$1"
+///
+/// [[output.post_process.stages]]
+/// type = "llm"
+/// model = "llama3.2:1b"
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PostProcessStage {
+    /// Shell command, text on stdin and VOXTYPE_CONTEXT in the environment
+    Command {
+        command: String,
+
+        /// Timeout in milliseconds (default: 30000)
+        #[serde(default = "default_post_process_timeout")]
+        timeout_ms: u64,
+
+        #[serde(default)]
+        on_error: StageErrorPolicy,
+    },
+    /// Built-in regex replacement (`$1` refers to capture groups)
+    Regex {
+        pattern: String,
+
+        #[serde(default)]
+        replacement: String,
+
+        #[serde(default)]
+        on_error: StageErrorPolicy,
+    },
+    /// Language model, configured like `[output.llm]`
+    Llm {
+        #[serde(flatten)]
+        llm: LlmConfig,
+
+        #[serde(default)]
+        on_error: StageErrorPolicy,
+    },
+}
+
+impl PostProcessStage {
+    /// What to do when this stage fails
+    pub fn on_error(&self) -> StageErrorPolicy {
+        match self {
+            Self::Command { on_error, .. }
+            | Self::Regex { on_error, .. }
+            | Self::Llm { on_error, .. } => *on_error,
+        }
+    }
+}
+
+/// What a post-processing pipeline does when a stage fails
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StageErrorPolicy {
+    /// Stop the pipeline and output the original transcription
+    #[default]
+    Abort,
+    /// Pass this stage's input on to the next stage
+    Skip,
+}
+
+/// LLM post-processing without a shell command (`[output.llm]`)
+///
+/// Cleans dictation with a language model that stays loaded between
+/// transcriptions: over Ollama's HTTP API, or in-process from a GGUF file
+/// when built with the `llm-postprocess` feature. Replaces
+/// `[output.post_process]` when both are set.
+///
+/// ```toml
+/// [output.llm]
+/// backend = "ollama"
+/// model = "llama3.2:1b"
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LlmConfig {
+    /// Where the model runs (default: "ollama")
+    #[serde(default)]
+    pub backend: LlmBackend,
+
+    /// Ollama model name, or path to a GGUF file for "llama-cpp"
+    #[serde(default = "default_llm_model")]
+    pub model: String,
+
+    /// Ollama server URL (default: "http://localhost:11434")
+    #[serde(default = "default_ollama_url")]
+    pub ollama_url: String,
+
+    /// Instructions for the model. The dictation is sent as the user
+    /// message. Defaults to a prompt that fixes punctuation and removes
+    /// filler words.
+    #[serde(default)]
+    pub prompt: Option<String>,
+
+    /// Maximum tokens to generate (default: 512)
+    #[serde(default = "default_llm_max_tokens")]
+    pub max_tokens: u32,
+
+    /// Timeout in milliseconds (default: 30000)
+    #[serde(default = "default_post_process_timeout")]
+    pub timeout_ms: u64,
+}
+
+impl Default for LlmConfig {
+    fn default() -> Self {
+        Self {
+            backend: LlmBackend::default(),
+            model: default_llm_model(),
+            ollama_url: default_ollama_url(),
+            prompt: None,
+            max_tokens: default_llm_max_tokens(),
+            timeout_ms: default_post_process_timeout(),
+        }
+    }
+}
+
+impl LlmConfig {
+    /// GGUF path for the "llama-cpp" backend. `~/` is expanded, and a bare
+    /// file name is looked up in the models directory.
+    pub fn model_path(&self) -> PathBuf {
+        let model = self.model.trim();
+        if model == "~" || model.starts_with("~/") {
+            if let Some(home) = dirs::home_dir() {
+                return home.join(model[1..].trim_start_matches('/'));
+            }
+        }
+        let path = PathBuf::from(model);
+        if path.components().count() == 1 {
+            return super::Config::models_dir().join(path);
+        }
+        path
+    }
+}
+
+/// Where `[output.llm]` runs the model
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LlmBackend {
+    /// A running Ollama server
+    #[default]
+    Ollama,
+    /// llama.cpp inside the daemon (requires the `llm-postprocess` feature)
+    LlamaCpp,
 }
 
 /// Named profile for context-specific settings
@@ -45,6 +211,20 @@ pub struct PostProcessConfig {
 ///
 /// [profiles.code]
 /// post_process_command = "cleanup-for-code.sh"
+///
+/// [profiles.git]
+/// post_process_template = "commit_message"
+/// ```
+///
+/// Profiles can also pick the engine, model, language and VAD settings:
+///
+/// ```toml
+/// [profiles.notes]
+/// engine = "parakeet"
+///
+/// [profiles.email]
+/// model = "large-v3"
+/// language = "en"
 /// ```
 ///
 /// Use with: `voxtype record start --profile slack`
@@ -59,9 +239,146 @@ pub struct Profile {
     #[serde(default)]
     pub post_process_timeout_ms: Option<u64>,
 
+    /// Built-in LLM prompt for this profile ("email_tone", "bullet_summary",
+    /// "code_comment", "commit_message"), run on the `[output.llm]` backend.
+    /// Ignored when `post_process_command` is set.
+    #[serde(default)]
+    pub post_process_template: Option<String>,
+
     /// Output mode override for this profile
     #[serde(default)]
     pub output_mode: Option<OutputMode>,
+
+    /// Overrides [output.pacing] enabled
+    #[serde(default)]
+    pub pacing: Option<bool>,
+
+    /// Format the whole dictation as one identifier in this style, e.g.
+    /// "user profile id" → "user_profile_id" with `identifier_style = "snake"`
+    #[serde(default)]
+    pub identifier_style: Option<IdentifierStyle>,
+
+    /// Spell the whole dictation with the NATO alphabet ("alpha bravo seven"
+    /// → "ab7"), for serial numbers, usernames and passwords
+    #[serde(default)]
+    pub spelling: bool,
+
+    /// Turn markdown dictation commands ("bullet point", "heading two", ...)
+    /// into markdown while this profile is active, even if
+    /// `[text] markdown_commands` is off
+    #[serde(default)]
+    pub markdown_commands: bool,
+
+    /// GBNF grammar that constrains Whisper's output while this profile is
+    /// active, e.g. digits only or a fixed command vocabulary. Decoding
+    /// starts at the grammar's `root` rule. Requires `[whisper] backend = "cli"`.
+    #[serde(default)]
+    pub grammar_file: Option<PathBuf>,
+
+    /// Transcription engine while this profile is active
+    #[serde(default)]
+    pub engine: Option<TranscriptionEngine>,
+
+    /// Model for the profile's engine (e.g. "large-v3" for Whisper)
+    #[serde(default)]
+    pub model: Option<String>,
+
+    /// Whisper language while this profile is active
+    #[serde(default)]
+    pub language: Option<LanguageConfig>,
+
+    /// Overrides [vad] enabled
+    #[serde(default)]
+    pub vad_enabled: Option<bool>,
+
+    /// Overrides [vad] threshold
+    #[serde(default)]
+    pub vad_threshold: Option<f32>,
+
+    /// Overrides [vad] min_speech_duration_ms
+    #[serde(default)]
+    pub vad_min_speech_duration_ms: Option<u32>,
+
+    /// Overrides [text] spoken_punctuation
+    #[serde(default)]
+    pub spoken_punctuation: Option<bool>,
+
+    /// Overrides [text] auto_capitalize
+    #[serde(default)]
+    pub auto_capitalize: Option<bool>,
+
+    /// Overrides [text] filter_filler_words (also accepted as `remove_fillers`)
+    #[serde(default, alias = "remove_fillers")]
+    pub filter_filler_words: Option<bool>,
+
+    /// Word replacements added to [text] replacements; entries here win
+    #[serde(default)]
+    pub replacements: HashMap<String, String>,
+}
+
+impl Profile {
+    /// Whether the profile needs a transcriber other than the default one
+    pub fn overrides_transcriber(&self) -> bool {
+        self.engine.is_some() || self.model.is_some() || self.language.is_some()
+    }
+
+    /// Whether the profile changes voice activity detection (a different
+    /// engine can change which VAD backend "auto" picks)
+    pub fn overrides_vad(&self) -> bool {
+        self.engine.is_some()
+            || self.vad_enabled.is_some()
+            || self.vad_threshold.is_some()
+            || self.vad_min_speech_duration_ms.is_some()
+    }
+
+    /// Whether the profile needs a text processor other than the default
+    /// one (a different language changes punctuation phrases and spacing)
+    pub fn overrides_text(&self) -> bool {
+        self.language.is_some()
+            || self.spoken_punctuation.is_some()
+            || self.auto_capitalize.is_some()
+            || self.filter_filler_words.is_some()
+            || !self.replacements.is_empty()
+    }
+}
+
+/// Rule that activates a profile when the focused window matches, so
+/// dictation into Slack can use a different profile than dictation into
+/// an editor
+///
+/// ```toml
+/// [[profile_rules]]
+/// match = "class:Slack"
+/// profile = "slack"
+///
+/// [[profile_rules]]
+/// match = "title:COMMIT_EDITMSG"
+/// profile = "git"
+/// ```
+///
+/// `match` is `class:<regex>` or `title:<regex>` (a bare regex matches the
+/// class), case-insensitive. Rules are tried in order; a profile chosen
+/// with `--profile` or a profile modifier key takes precedence.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ProfileRule {
+    /// Window to match, e.g. "class:Slack" or "title:.* - Gmail"
+    #[serde(rename = "match")]
+    pub pattern: String,
+
+    /// Profile to activate
+    pub profile: String,
+}
+
+fn default_llm_model() -> String {
+    "llama3.2:1b".to_string()
+}
+
+fn default_ollama_url() -> String {
+    "http://localhost:11434".to_string()
+}
+
+fn default_llm_max_tokens() -> u32 {
+    512
 }
 
 fn default_post_process_timeout() -> u64 {
@@ -70,7 +387,131 @@ fn default_post_process_timeout() -> u64 {
 
 #[cfg(test)]
 mod tests {
-    use crate::config::{Config, OutputMode};
+    use crate::config::{
+        Config, IdentifierStyle, LlmBackend, OutputMode, PostProcessStage, StageErrorPolicy,
+        TranscriptionEngine,
+    };
+
+    #[test]
+    fn test_parse_profile_rules() {
+        let toml_str = r#"
+            [[profile_rules]]
+            match = "class:Slack"
+            profile = "slack"
+
+            [[profile_rules]]
+            match = "title:COMMIT_EDITMSG"
+            profile = "git"
+
+            [profiles.slack]
+            [profiles.git]
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.profile_rules.len(), 2);
+        assert_eq!(config.profile_rules[0].pattern, "class:Slack");
+        assert_eq!(config.profile_rules[1].profile, "git");
+        assert!(Config::default().profile_rules.is_empty());
+    }
+
+    #[test]
+    fn test_parse_output_llm() {
+        let toml_str = r#"
+            [output.llm]
+            backend = "llama-cpp"
+            model = "~/models/qwen2.5-0.5b-instruct-q4_k_m.gguf"
+            max_tokens = 256
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let llm = config.output.llm.unwrap();
+        assert_eq!(llm.backend, LlmBackend::LlamaCpp);
+        assert_eq!(llm.max_tokens, 256);
+        assert_eq!(llm.ollama_url, "http://localhost:11434");
+        assert_eq!(llm.timeout_ms, 30000);
+        assert!(llm.prompt.is_none());
+
+        let config: Config = toml::from_str("[output.llm]").unwrap();
+        let llm = config.output.llm.unwrap();
+        assert_eq!(llm.backend, LlmBackend::Ollama);
+        assert_eq!(llm.model, "llama3.2:1b");
+        assert!(Config::default().output.llm.is_none());
+        assert_eq!(llm.model_path(), Config::models_dir().join("llama3.2:1b"));
+    }
+
+    #[test]
+    fn test_parse_post_process_stages() {
+        let toml_str = r#"
+            [[output.post_process.stages]]
+            type = "command"
+            command = "remove-fillers.sh"
+            timeout_ms = 2000
+            on_error = "skip"
+
+            [[output.post_process.stages]]
+            type = "regex"
+            pattern = "\\s+([,.])"
+            replacement = "$1"
+
+            [[output.post_process.stages]]
+            type = "llm"
+            model = "qwen2.5:0.5b"
+            timeout_ms = 10000
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let post_process = config.output.post_process.unwrap();
+        assert!(post_process.command.is_empty());
+        assert_eq!(post_process.stages.len(), 3);
+
+        match &post_process.stages[0] {
+            PostProcessStage::Command {
+                command,
+                timeout_ms,
+                on_error,
+            } => {
+                assert_eq!(command, "remove-fillers.sh");
+                assert_eq!(*timeout_ms, 2000);
+                assert_eq!(*on_error, StageErrorPolicy::Skip);
+            }
+            other => panic!("expected command stage, got {:?}", other),
+        }
+        match &post_process.stages[1] {
+            PostProcessStage::Regex {
+                pattern,
+                replacement,
+                ..
+            } => {
+                assert_eq!(pattern, r"\s+([,.])");
+                assert_eq!(replacement, "$1");
+            }
+            other => panic!("expected regex stage, got {:?}", other),
+        }
+        match &post_process.stages[2] {
+            PostProcessStage::Llm { llm, .. } => {
+                assert_eq!(llm.model, "qwen2.5:0.5b");
+                assert_eq!(llm.backend, LlmBackend::Ollama);
+                assert_eq!(llm.timeout_ms, 10000);
+            }
+            other => panic!("expected llm stage, got {:?}", other),
+        }
+        assert_eq!(post_process.stages[2].on_error(), StageErrorPolicy::Abort);
+    }
+
+    #[test]
+    fn test_parse_profile_post_process_template() {
+        let toml_str = r#"
+            [profiles.git]
+            post_process_template = "commit_message"
+            post_process_timeout_ms = 10000
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let git = config.get_profile("git").unwrap();
+        assert_eq!(git.post_process_template.as_deref(), Some("commit_message"));
+        assert!(git.post_process_command.is_none());
+        assert!(Config::default().output.llm.is_none());
+    }
 
     #[test]
     fn test_profiles_default_empty() {
@@ -219,6 +660,65 @@ mod tests {
         let profile = config.get_profile("clipboard_only").unwrap();
         assert!(profile.post_process_command.is_none());
         assert_eq!(profile.output_mode, Some(OutputMode::Clipboard));
+        assert!(profile.grammar_file.is_none());
+        assert!(profile.identifier_style.is_none());
+    }
+
+    #[test]
+    fn test_parse_profile_identifier_style() {
+        let toml_str = r#"
+            [profiles.ident]
+            identifier_style = "snake"
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let profile = config.get_profile("ident").unwrap();
+        assert_eq!(profile.identifier_style, Some(IdentifierStyle::Snake));
+        assert!(!profile.spelling);
+    }
+
+    #[test]
+    fn test_parse_profile_spelling() {
+        let toml_str = r#"
+            [profiles.spell]
+            spelling = true
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.get_profile("spell").unwrap().spelling);
+    }
+
+    #[test]
+    fn test_parse_profile_markdown_commands() {
+        let toml_str = r#"
+            [profiles.notes]
+            markdown_commands = true
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.get_profile("notes").unwrap().markdown_commands);
+        assert!(!config.get_profile("notes").unwrap().spelling);
+    }
+
+    #[test]
+    fn test_parse_profile_with_grammar_file() {
+        let toml_str = r#"
+            [whisper]
+            model = "base.en"
+            backend = "cli"
+
+            [profiles.digits]
+            grammar_file = "/home/user/.config/voxtype/digits.gbnf"
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let profile = config.get_profile("digits").unwrap();
+        assert_eq!(
+            profile.grammar_file.as_deref(),
+            Some(std::path::Path::new(
+                "/home/user/.config/voxtype/digits.gbnf"
+            ))
+        );
     }
 
     #[test]
@@ -244,4 +744,32 @@ mod tests {
         let config: Config = toml::from_str(toml_str).unwrap();
         assert!(config.profiles.is_empty());
     }
+
+    #[test]
+    fn test_parse_profile_engine_overrides() {
+        let toml_str = r#"
+            [profiles.notes]
+            engine = "parakeet"
+            vad_enabled = true
+            vad_threshold = 0.7
+
+            [profiles.email]
+            model = "large-v3"
+            language = ["en", "de"]
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let notes = config.get_profile("notes").unwrap();
+        assert_eq!(notes.engine, Some(TranscriptionEngine::Parakeet));
+        assert_eq!(notes.vad_enabled, Some(true));
+        assert_eq!(notes.vad_threshold, Some(0.7));
+        assert!(notes.overrides_transcriber());
+        assert!(notes.overrides_vad());
+
+        let email = config.get_profile("email").unwrap();
+        assert_eq!(email.model.as_deref(), Some("large-v3"));
+        assert!(email.language.as_ref().unwrap().is_multiple());
+        assert!(email.overrides_transcriber());
+        assert!(!email.overrides_vad());
+    }
 }
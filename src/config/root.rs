@@ -1,7 +1,9 @@
 use super::{
-    AudioConfig, CohereConfig, DolphinConfig, HotkeyConfig, MeetingConfig, MoonshineConfig,
-    OmnilingualConfig, OutputConfig, ParaformerConfig, ParakeetConfig, Profile, SenseVoiceConfig,
-    SonioxConfig, StatusConfig, TextConfig, TranscriptionEngine, VadConfig, WhisperConfig,
+    Activation, AudioConfig, CohereConfig, DolphinConfig, HotkeyConfig, MeetingConfig,
+    ModelsConfig, MoonshineConfig, MqttConfig, NetworkConfig, OmnilingualConfig, OutputConfig,
+    ParaformerConfig, ParakeetConfig, Profile, ProfileRule, SenseVoiceConfig, SonioxConfig,
+    StatusConfig, TextConfig, TranscribeConfig, TranscriptionEngine, VadConfig, VoskConfig,
+    WakeWordConfig, WhisperConfig, ZipformerConfig,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -28,6 +30,21 @@ pub struct Config {
     #[serde(default)]
     pub engine: TranscriptionEngine,
 
+    /// What starts recordings: "hotkey" (default) or "wake_word", which also
+    /// listens for the `[wake_word]` model
+    #[serde(default)]
+    pub activation: Activation,
+
+    /// Engines to try, in order, if `engine` fails to initialize at daemon
+    /// startup. Usually written as `engine = ["parakeet", "whisper"]`, which
+    /// the parser splits into `engine` and this list.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub engine_fallback: Vec<TranscriptionEngine>,
+
+    /// Engine-agnostic transcription settings (incremental streaming)
+    #[serde(default)]
+    pub transcribe: TranscribeConfig,
+
     /// Parakeet configuration (optional, only used when engine = "parakeet")
     #[serde(default)]
     pub parakeet: Option<ParakeetConfig>,
@@ -61,6 +78,14 @@ pub struct Config {
     #[serde(default)]
     pub soniox: Option<SonioxConfig>,
 
+    /// Vosk configuration (optional, only used when engine = "vosk")
+    #[serde(default)]
+    pub vosk: Option<VoskConfig>,
+
+    /// Zipformer configuration (optional, only used when engine = "zipformer")
+    #[serde(default)]
+    pub zipformer: Option<ZipformerConfig>,
+
     /// Text processing configuration (replacements, spoken punctuation)
     #[serde(default)]
     pub text: TextConfig,
@@ -70,6 +95,10 @@ pub struct Config {
     #[serde(default)]
     pub vad: VadConfig,
 
+    /// Wake word detection for `activation = "wake_word"`
+    #[serde(default)]
+    pub wake_word: WakeWordConfig,
+
     /// Status display configuration (icons for Waybar/tray integrations)
     #[serde(default)]
     pub status: StatusConfig,
@@ -95,6 +124,22 @@ pub struct Config {
     /// Use with: `voxtype record start --profile slack`
     #[serde(default)]
     pub profiles: HashMap<String, Profile>,
+
+    /// Rules that pick a profile from the focused window, see [`ProfileRule`]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub profile_rules: Vec<ProfileRule>,
+
+    /// User-defined models, see [`ModelsConfig`]
+    #[serde(default)]
+    pub models: ModelsConfig,
+
+    /// Outbound proxy settings
+    #[serde(default)]
+    pub network: NetworkConfig,
+
+    /// MQTT state and transcription publisher
+    #[serde(default)]
+    pub mqtt: MqttConfig,
 }
 
 impl Default for Config {
@@ -105,6 +150,9 @@ impl Default for Config {
             whisper: WhisperConfig::default(),
             output: OutputConfig::default(),
             engine: TranscriptionEngine::default(),
+            activation: Activation::default(),
+            engine_fallback: Vec::new(),
+            transcribe: TranscribeConfig::default(),
             parakeet: None,
             moonshine: None,
             sensevoice: None,
@@ -113,13 +161,20 @@ impl Default for Config {
             omnilingual: None,
             cohere: None,
             soniox: None,
+            vosk: None,
+            zipformer: None,
             text: TextConfig::default(),
             vad: VadConfig::default(),
+            wake_word: WakeWordConfig::default(),
             status: StatusConfig::default(),
             osd: crate::osd::config::OsdConfig::default(),
             meeting: MeetingConfig::default(),
             state_file: default_state_file(),
             profiles: HashMap::new(),
+            profile_rules: Vec::new(),
+            models: ModelsConfig::default(),
+            network: NetworkConfig::default(),
+            mqtt: MqttConfig::default(),
         }
     }
 }
@@ -146,7 +201,37 @@ impl Config {
                 .as_ref()
                 .map(|s| s.streaming && !s.async_api)
                 .unwrap_or(false),
-            _ => false,
+            // Zipformer decodes chunk by chunk natively. Like Soniox, a
+            // missing section can't run, so it doesn't promote PTT either.
+            TranscriptionEngine::Zipformer => self
+                .zipformer
+                .as_ref()
+                .map(|z| z.streaming)
+                .unwrap_or(false),
+            // A ws:// remote endpoint streams natively
+            TranscriptionEngine::Whisper if self.whisper.remote_uses_websocket() => true,
+            _ => self.incremental_streaming_supported(),
+        }
+    }
+
+    /// Returns true if `[transcribe] streaming` is enabled and the active
+    /// engine can be re-decoded incrementally. Parakeet, Soniox and
+    /// Zipformer have native streaming pipelines and are excluded. Whisper only qualifies
+    /// when it runs in-process: a gpu_isolation worker serves one job, and
+    /// remote/cli modes would pay a full request per re-decode.
+    pub fn incremental_streaming_supported(&self) -> bool {
+        if !self.transcribe.streaming {
+            return false;
+        }
+        match self.engine {
+            TranscriptionEngine::Parakeet
+            | TranscriptionEngine::Soniox
+            | TranscriptionEngine::Zipformer => false,
+            TranscriptionEngine::Whisper => {
+                self.whisper.effective_mode() == super::WhisperMode::Local
+                    && !self.whisper.gpu_isolation
+            }
+            _ => true,
         }
     }
 
@@ -312,6 +397,16 @@ impl Config {
                 .unwrap_or(false),
             // Soniox is a cloud backend; nothing to load on demand.
             TranscriptionEngine::Soniox => false,
+            TranscriptionEngine::Vosk => self
+                .vosk
+                .as_ref()
+                .map(|v| v.on_demand_loading)
+                .unwrap_or(false),
+            TranscriptionEngine::Zipformer => self
+                .zipformer
+                .as_ref()
+                .map(|z| z.on_demand_loading)
+                .unwrap_or(false),
         }
     }
 
@@ -359,9 +454,206 @@ impl Config {
                 .as_ref()
                 .map(|s| s.model.as_str())
                 .unwrap_or("soniox (not configured)"),
+            TranscriptionEngine::Vosk => self
+                .vosk
+                .as_ref()
+                .map(|v| v.model.as_str())
+                .unwrap_or("vosk (not configured)"),
+            TranscriptionEngine::Zipformer => self
+                .zipformer
+                .as_ref()
+                .map(|z| z.model.as_str())
+                .unwrap_or("zipformer (not configured)"),
         }
     }
 
+    /// Mutable model name/path of `engine`, `None` if the engine's section
+    /// is not configured
+    fn model_name_mut(&mut self, engine: TranscriptionEngine) -> Option<&mut String> {
+        match engine {
+            TranscriptionEngine::Whisper => Some(&mut self.whisper.model),
+            TranscriptionEngine::Parakeet => self.parakeet.as_mut().map(|p| &mut p.model),
+            TranscriptionEngine::Moonshine => self.moonshine.as_mut().map(|m| &mut m.model),
+            TranscriptionEngine::SenseVoice => self.sensevoice.as_mut().map(|s| &mut s.model),
+            TranscriptionEngine::Paraformer => self.paraformer.as_mut().map(|p| &mut p.model),
+            TranscriptionEngine::Dolphin => self.dolphin.as_mut().map(|d| &mut d.model),
+            TranscriptionEngine::Omnilingual => self.omnilingual.as_mut().map(|o| &mut o.model),
+            TranscriptionEngine::Cohere => self.cohere.as_mut().map(|c| &mut c.model),
+            TranscriptionEngine::Soniox => self.soniox.as_mut().map(|s| &mut s.model),
+            TranscriptionEngine::Vosk => self.vosk.as_mut().map(|v| &mut v.model),
+            TranscriptionEngine::Zipformer => self.zipformer.as_mut().map(|z| &mut z.model),
+        }
+    }
+
+    /// Mutable model name/path of `engine`, adding a default section for
+    /// the engine if it is not configured
+    fn model_name_or_default_mut(&mut self, engine: TranscriptionEngine) -> &mut String {
+        match engine {
+            TranscriptionEngine::Whisper => &mut self.whisper.model,
+            TranscriptionEngine::Parakeet => {
+                &mut self.parakeet.get_or_insert_with(Default::default).model
+            }
+            TranscriptionEngine::Moonshine => {
+                &mut self.moonshine.get_or_insert_with(Default::default).model
+            }
+            TranscriptionEngine::SenseVoice => {
+                &mut self.sensevoice.get_or_insert_with(Default::default).model
+            }
+            TranscriptionEngine::Paraformer => {
+                &mut self.paraformer.get_or_insert_with(Default::default).model
+            }
+            TranscriptionEngine::Dolphin => {
+                &mut self.dolphin.get_or_insert_with(Default::default).model
+            }
+            TranscriptionEngine::Omnilingual => {
+                &mut self.omnilingual.get_or_insert_with(Default::default).model
+            }
+            TranscriptionEngine::Cohere => {
+                &mut self.cohere.get_or_insert_with(Default::default).model
+            }
+            TranscriptionEngine::Soniox => {
+                &mut self.soniox.get_or_insert_with(Default::default).model
+            }
+            TranscriptionEngine::Vosk => &mut self.vosk.get_or_insert_with(Default::default).model,
+            TranscriptionEngine::Zipformer => {
+                &mut self.zipformer.get_or_insert_with(Default::default).model
+            }
+        }
+    }
+
+    /// Fold `[models.custom]` into the engine sections. Whisper models join
+    /// `[whisper] custom_models`, so the Whisper backends, the model manager
+    /// and `setup --download` resolve them like any other name. An engine
+    /// `model` that names a custom model for another engine is replaced by
+    /// that model's `path`. Invalid declarations are skipped with a warning.
+    pub fn apply_custom_models(&mut self) {
+        let custom = self.models.custom.clone();
+        for (name, model) in &custom {
+            if let Some(problem) = model.validation_error() {
+                tracing::warn!("Ignoring [models.custom.{}]: {}", name, problem);
+                continue;
+            }
+            let Some(source) = model.source() else {
+                continue;
+            };
+            if model.engine == TranscriptionEngine::Whisper {
+                self.whisper
+                    .custom_models
+                    .entry(name.clone())
+                    .or_insert(source);
+            } else if let Some(current) = self.model_name_mut(model.engine) {
+                if current == name {
+                    *current = source;
+                }
+            }
+        }
+    }
+
+    /// Switch to a model declared under `[models.custom]`: sets `engine` to
+    /// the model's engine and points that engine at the model. Returns
+    /// false if `name` is not a valid custom model.
+    pub fn select_custom_model(&mut self, name: &str) -> bool {
+        let Some(model) = self.models.custom.get(name).cloned() else {
+            return false;
+        };
+        if model.validation_error().is_some() {
+            return false;
+        }
+        let source = if model.engine == TranscriptionEngine::Whisper {
+            // Resolved through [whisper] custom_models
+            name.to_string()
+        } else {
+            model.source().unwrap_or_default()
+        };
+        self.select_model(model.engine, &source);
+        true
+    }
+
+    /// Switch to `engine` running `model` (a name or path), adding a
+    /// default section for the engine if it is not configured
+    pub fn select_model(&mut self, engine: TranscriptionEngine, model: &str) {
+        self.engine = engine;
+        *self.model_name_or_default_mut(engine) = model.to_string();
+    }
+
+    /// Model names and paths referenced anywhere in this config: every
+    /// configured engine's model, the Whisper secondary and redo models,
+    /// profile models and the VAD model. `setup model --remove` refuses to
+    /// delete anything listed here without `--force`.
+    pub fn referenced_models(&self) -> Vec<String> {
+        let mut models = vec![self.whisper.model.clone()];
+        models.extend(self.whisper.secondary_model.clone());
+        models.extend(self.whisper.redo_model.clone());
+        models.extend(self.parakeet.as_ref().map(|c| c.model.clone()));
+        models.extend(self.moonshine.as_ref().map(|c| c.model.clone()));
+        models.extend(self.sensevoice.as_ref().map(|c| c.model.clone()));
+        models.extend(self.paraformer.as_ref().map(|c| c.model.clone()));
+        models.extend(self.dolphin.as_ref().map(|c| c.model.clone()));
+        models.extend(self.omnilingual.as_ref().map(|c| c.model.clone()));
+        models.extend(self.cohere.as_ref().map(|c| c.model.clone()));
+        models.extend(self.vosk.as_ref().map(|c| c.model.clone()));
+        models.extend(self.zipformer.as_ref().map(|c| c.model.clone()));
+        models.extend(self.profiles.values().filter_map(|p| p.model.clone()));
+        models.extend(self.vad.model.clone());
+        models
+    }
+
+    /// Config with a profile's engine, model, language and VAD overrides
+    /// applied. The model applies to the profile's engine if it sets one.
+    pub fn with_profile(&self, profile: &Profile) -> Self {
+        let mut cfg = self.clone();
+        if let Some(engine) = profile.engine {
+            cfg.engine = engine;
+        }
+        if let Some(ref model) = profile.model {
+            if cfg.select_custom_model(model) {
+                // Custom models carry their own engine
+            } else if let Some(name) = cfg.model_name_mut(cfg.engine) {
+                *name = model.clone();
+            } else {
+                tracing::warn!(
+                    "Profile model '{}' ignored: [{}] is not configured",
+                    model,
+                    cfg.engine.name()
+                );
+            }
+        }
+        if let Some(ref language) = profile.language {
+            cfg.whisper.language = language.clone();
+        }
+        if let Some(enabled) = profile.vad_enabled {
+            cfg.vad.enabled = enabled;
+        }
+        if let Some(threshold) = profile.vad_threshold {
+            cfg.vad.threshold = threshold;
+        }
+        if let Some(min_speech) = profile.vad_min_speech_duration_ms {
+            cfg.vad.min_speech_duration_ms = min_speech;
+        }
+        if let Some(enabled) = profile.spoken_punctuation {
+            cfg.text.spoken_punctuation = enabled;
+        }
+        if let Some(enabled) = profile.auto_capitalize {
+            cfg.text.auto_capitalize = enabled;
+        }
+        if let Some(enabled) = profile.filter_filler_words {
+            cfg.text.filter_filler_words = enabled;
+        }
+        if !profile.replacements.is_empty() {
+            // Replacements are case-insensitive, so drop any differently
+            // cased entry the profile replaces
+            cfg.text.replacements.retain(|word, _| {
+                let word = word.to_lowercase();
+                !profile
+                    .replacements
+                    .keys()
+                    .any(|p| p.to_lowercase() == word)
+            });
+            cfg.text.replacements.extend(profile.replacements.clone());
+        }
+        cfg
+    }
+
     /// Get a named profile by name
     /// Returns None if the profile doesn't exist
     pub fn get_profile(&self, name: &str) -> Option<&Profile> {
@@ -377,7 +669,7 @@ impl Config {
 #[cfg(test)]
 mod tests {
     use super::super::hotkey::default_hotkey_key;
-    use super::super::{ActivationMode, OutputMode};
+    use super::super::{ActivationMode, LanguageConfig, OutputMode};
     use super::*;
 
     #[test]
@@ -420,6 +712,47 @@ mod tests {
         assert_eq!(meeting_cfg.whisper.model, cfg.whisper.model);
     }
 
+    #[test]
+    fn incremental_streaming_requires_in_process_whisper() {
+        let mut cfg = Config::default();
+        assert!(!cfg.incremental_streaming_supported());
+
+        cfg.transcribe.streaming = true;
+        assert!(cfg.incremental_streaming_supported());
+        assert!(cfg.streaming_active());
+
+        cfg.whisper.gpu_isolation = true;
+        assert!(!cfg.incremental_streaming_supported());
+        assert!(!cfg.streaming_active());
+    }
+
+    #[test]
+    fn websocket_remote_streams_without_transcribe_streaming() {
+        let mut cfg = Config::default();
+        cfg.whisper.mode = Some(super::super::WhisperMode::Remote);
+        cfg.whisper.remote_endpoint = Some("http://localhost:8080".into());
+        assert!(!cfg.streaming_active());
+
+        cfg.whisper.remote_endpoint = Some("wss://asr.example.com/stream".into());
+        assert!(cfg.streaming_active());
+        assert!(!cfg.incremental_streaming_supported());
+    }
+
+    #[test]
+    fn incremental_streaming_skips_native_streaming_engines() {
+        let cfg = Config {
+            engine: TranscriptionEngine::Parakeet,
+            transcribe: TranscribeConfig {
+                streaming: true,
+                ..TranscribeConfig::default()
+            },
+            ..Config::default()
+        };
+        assert!(!cfg.incremental_streaming_supported());
+        // Parakeet's own flag still governs its native pipeline.
+        assert!(!cfg.streaming_active());
+    }
+
     #[test]
     fn test_default_config() {
         let config = Config::default();
@@ -440,4 +773,148 @@ mod tests {
         );
         assert_eq!(Config::SYSTEM_PATH, "/etc/voxtype/config.toml");
     }
+
+    #[test]
+    fn test_referenced_models() {
+        let config: Config = toml::from_str(
+            r#"
+            [whisper]
+            model = "base.en"
+            secondary_model = "large-v3-turbo"
+
+            [parakeet]
+            model = "parakeet-tdt-0.6b-v3"
+
+            [profiles.email]
+            model = "large-v3"
+            "#,
+        )
+        .unwrap();
+        let models = config.referenced_models();
+        for name in [
+            "base.en",
+            "large-v3-turbo",
+            "parakeet-tdt-0.6b-v3",
+            "large-v3",
+        ] {
+            assert!(models.iter().any(|m| m == name), "missing {}", name);
+        }
+    }
+
+    #[test]
+    fn test_with_profile_applies_overrides() {
+        let base = Config::default();
+        let profile = Profile {
+            model: Some("large-v3".to_string()),
+            language: Some(LanguageConfig::Single("de".to_string())),
+            vad_enabled: Some(true),
+            ..Profile::default()
+        };
+        let cfg = base.with_profile(&profile);
+        assert_eq!(cfg.whisper.model, "large-v3");
+        assert_eq!(cfg.whisper.language.primary(), "de");
+        assert!(cfg.vad.enabled);
+        assert_eq!(base.whisper.model, "base.en");
+
+        // A model for an unconfigured engine is dropped, not misapplied
+        let profile = Profile {
+            engine: Some(TranscriptionEngine::Parakeet),
+            model: Some("parakeet-tdt-0.6b-v3".to_string()),
+            ..Profile::default()
+        };
+        let cfg = base.with_profile(&profile);
+        assert_eq!(cfg.engine, TranscriptionEngine::Parakeet);
+        assert!(cfg.parakeet.is_none());
+        assert_eq!(cfg.whisper.model, "base.en");
+    }
+
+    #[test]
+    fn test_with_profile_applies_text_overrides() {
+        let mut base = Config::default();
+        base.text.replacements = HashMap::from([
+            ("vox type".to_string(), "voxtype".to_string()),
+            ("Arrow".to_string(), "arrow".to_string()),
+        ]);
+        let profile: Profile = toml::from_str(
+            r#"
+            spoken_punctuation = true
+            auto_capitalize = false
+            remove_fillers = false
+            replacements = { "arrow" = "->" }
+            "#,
+        )
+        .unwrap();
+        assert!(profile.overrides_text());
+        assert!(!Profile::default().overrides_text());
+
+        let cfg = base.with_profile(&profile);
+        assert!(cfg.text.spoken_punctuation);
+        assert!(!cfg.text.auto_capitalize);
+        assert!(!cfg.text.filter_filler_words);
+        assert_eq!(cfg.text.replacements.len(), 2);
+        assert_eq!(cfg.text.replacements["arrow"], "->");
+        assert_eq!(cfg.text.replacements["vox type"], "voxtype");
+        assert!(!base.text.spoken_punctuation);
+    }
+
+    const CUSTOM_MODELS: &str = r#"
+        [models.custom.my-finetune]
+        url = "https://example.com/ggml-my-finetune.bin"
+
+        [models.custom.parakeet-medical]
+        engine = "parakeet"
+        path = "/opt/models/parakeet-medical"
+
+        [models.custom.broken]
+        engine = "moonshine"
+        url = "https://example.com/moonshine.tar.gz"
+    "#;
+
+    #[test]
+    fn test_apply_custom_models() {
+        let mut config: Config = toml::from_str(&format!(
+            "engine = \"parakeet\"\n[parakeet]\nmodel = \"parakeet-medical\"\n{}",
+            CUSTOM_MODELS
+        ))
+        .unwrap();
+        config.apply_custom_models();
+
+        assert_eq!(
+            config.whisper.custom_models["my-finetune"],
+            "https://example.com/ggml-my-finetune.bin"
+        );
+        assert_eq!(
+            config.parakeet.as_ref().unwrap().model,
+            "/opt/models/parakeet-medical"
+        );
+        assert!(!config.whisper.custom_models.contains_key("broken"));
+    }
+
+    #[test]
+    fn test_select_custom_model() {
+        let mut config: Config = toml::from_str(CUSTOM_MODELS).unwrap();
+        config.apply_custom_models();
+
+        assert!(config.select_custom_model("parakeet-medical"));
+        assert_eq!(config.engine, TranscriptionEngine::Parakeet);
+        assert_eq!(
+            config.parakeet.as_ref().unwrap().model,
+            "/opt/models/parakeet-medical"
+        );
+
+        assert!(config.select_custom_model("my-finetune"));
+        assert_eq!(config.engine, TranscriptionEngine::Whisper);
+        assert_eq!(config.whisper.model, "my-finetune");
+
+        assert!(!config.select_custom_model("broken"));
+        assert!(!config.select_custom_model("large-v3"));
+
+        // Profiles can name custom models too, switching engine with them
+        let profile = Profile {
+            model: Some("parakeet-medical".to_string()),
+            ..Profile::default()
+        };
+        let cfg = config.with_profile(&profile);
+        assert_eq!(cfg.engine, TranscriptionEngine::Parakeet);
+    }
 }
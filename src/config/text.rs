@@ -1,6 +1,7 @@
 //! Text processing configuration.
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
@@ -11,20 +12,117 @@ pub struct TextConfig {
     #[serde(default)]
     pub spoken_punctuation: bool,
 
+    /// Locale for punctuation spacing, e.g. "fr" (narrow space before
+    /// "?;:!" and « » quotes) or "de" („…“ quotes). Defaults to the
+    /// whisper language; anything else uses English spacing.
+    #[serde(default)]
+    pub locale: Option<String>,
+
+    /// Extra or overriding spoken punctuation phrases, on top of the
+    /// built-in table for `locale` (English, German, French, Spanish).
+    /// An empty value removes a built-in phrase.
+    /// Example: { "arrow" = "->", "hash" = "" }
+    #[serde(default)]
+    pub punctuation_map: HashMap<String, String>,
+
     /// Custom word replacements (case-insensitive)
     /// Example: { "vox type" = "voxtype" }
     #[serde(default)]
     pub replacements: HashMap<String, String>,
 
+    /// TOML file of extra word replacements, merged with `replacements`
+    /// (inline entries win). Re-read when the file changes, so a synced
+    /// personal dictionary can live outside the main config.
+    /// Example: "~/.config/voxtype/dictionary.toml"
+    #[serde(default)]
+    pub replacements_file: Option<String>,
+
+    /// Emoji and symbol dictation: "thumbs up emoji" → 👍, "degree symbol" → °
+    #[serde(default)]
+    pub emoji: bool,
+
+    /// Extra emoji names for `emoji`, overriding built-ins on conflict.
+    /// Example: { "ship it" = "🚢" } for "ship it emoji"
+    #[serde(default)]
+    pub emoji_map: HashMap<String, String>,
+
+    /// Spoken macros expanded when dictated: phrase → template with
+    /// `{date}`, `{time}` (optionally `{date:%d/%m/%Y}`) and `{clipboard}`.
+    /// Example: { "insert today's date" = "{date}" }
+    #[serde(default)]
+    pub macros: HashMap<String, String>,
+
+    /// Snippets typed when a whole dictation is exactly the phrase:
+    /// { "my signature" = "Best regards,\nPete" }. Values may span lines
+    /// and use the `macros` placeholders.
+    #[serde(default)]
+    pub snippets: HashMap<String, String>,
+
+    /// Ordered regex rewrite rules, applied after `replacements`
+    /// Example: [[text.rules]] pattern = 'ticket (\d+)' replacement = "JIRA-$1"
+    #[serde(default)]
+    pub rules: Vec<TextRule>,
+
     /// Smart auto-submit: say "submit" at the end of dictation to press Enter.
     /// The word "submit" is stripped from the output and Enter is pressed.
     #[serde(default)]
     pub smart_auto_submit: bool,
 
+    /// Uppercase sentence starts, write the pronoun "i" as "I" and leave a
+    /// single space after sentence-ending punctuation. Mostly useful with
+    /// CTC engines that emit lowercase text.
+    #[serde(default)]
+    pub auto_capitalize: bool,
+
+    /// Convert spelled-out numbers to digits ("twenty three point five" →
+    /// "23.5") and spoken dates to `date_format`.
+    #[serde(default)]
+    pub spoken_numbers: bool,
+
+    /// chrono format string for dates recognized by `spoken_numbers`
+    /// ("march third twenty twenty five"). Defaults to ISO 8601 (%Y-%m-%d).
+    #[serde(default = "default_date_format")]
+    pub date_format: String,
+
+    /// Voice editing commands: "scratch that", "undo" and "delete last word"
+    /// at the end of dictation remove text instead of being typed.
+    #[serde(default)]
+    pub editing_commands: bool,
+
+    /// Case commands: "all caps", "lowercase that" or "title case that" at
+    /// the end of dictation change the case of what was said before them.
+    #[serde(default)]
+    pub case_commands: bool,
+
+    /// Code dictation modes: "camel case user profile id" → "userProfileId".
+    /// Also "pascal case", "snake case", "kebab case" and "constant case".
+    #[serde(default)]
+    pub code_modes: bool,
+
+    /// Spelling mode: "spell alpha bravo seven charlie" → "ab7c", using the
+    /// NATO alphabet, digits and "capital" for uppercase letters
+    #[serde(default)]
+    pub spelling: bool,
+
+    /// Markdown dictation commands: "bullet point", "numbered item",
+    /// "heading one" to "heading six", "block quote", "code block" and
+    /// "end code block" become markdown on new lines
+    #[serde(default)]
+    pub markdown_commands: bool,
+
+    /// Profanity filter: "off" (default), "mask" ("shit" → "s***") or
+    /// "remove". Uses a bundled English wordlist plus `profanity_words`.
+    #[serde(default)]
+    pub profanity: ProfanityMode,
+
+    /// Extra words (or phrases) for the profanity filter
+    #[serde(default)]
+    pub profanity_words: Vec<String>,
+
     /// Remove common filler words ("uh", "um", etc.) from transcribed text.
     /// Defaults to false to preserve existing behavior. The list is
-    /// configurable via `filler_words`.
-    #[serde(default)]
+    /// configurable via `filler_words`. Also accepted as `remove_fillers`.
+    #[serde(default, alias = "remove_fillers")]
     pub filter_filler_words: bool,
 
     /// Words removed when `filter_filler_words` is true. Matched
@@ -38,14 +136,94 @@ impl Default for TextConfig {
     fn default() -> Self {
         Self {
             spoken_punctuation: false,
+            locale: None,
+            punctuation_map: HashMap::new(),
             replacements: HashMap::new(),
+            replacements_file: None,
+            emoji: false,
+            emoji_map: HashMap::new(),
+            macros: HashMap::new(),
+            snippets: HashMap::new(),
+            rules: Vec::new(),
             smart_auto_submit: false,
+            auto_capitalize: false,
+            spoken_numbers: false,
+            date_format: default_date_format(),
+            editing_commands: false,
+            case_commands: false,
+            code_modes: false,
+            spelling: false,
+            markdown_commands: false,
+            profanity: ProfanityMode::Off,
+            profanity_words: Vec::new(),
             filter_filler_words: true,
             filler_words: default_filler_words(),
         }
     }
 }
 
+impl TextConfig {
+    /// `replacements_file` with a leading `~` expanded to the home directory
+    pub fn replacements_file_path(&self) -> Option<PathBuf> {
+        let path = self.replacements_file.as_deref()?.trim();
+        if path.is_empty() {
+            return None;
+        }
+        if path == "~" || path.starts_with("~/") {
+            if let Some(home) = dirs::home_dir() {
+                return Some(home.join(path[1..].trim_start_matches('/')));
+            }
+        }
+        Some(PathBuf::from(path))
+    }
+}
+
+/// What the profanity filter does with a match
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfanityMode {
+    /// Leave text alone
+    #[default]
+    Off,
+    /// Keep the first letter and replace the rest with asterisks
+    Mask,
+    /// Drop the word and tidy the surrounding spacing
+    Remove,
+}
+
+/// Identifier style for code dictation modes and `[profiles.*] identifier_style`
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IdentifierStyle {
+    /// userProfileId
+    Camel,
+    /// UserProfileId
+    Pascal,
+    /// user_profile_id
+    Snake,
+    /// user-profile-id
+    Kebab,
+    /// USER_PROFILE_ID
+    Constant,
+}
+
+/// A regex rewrite rule from `[[text.rules]]`
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct TextRule {
+    /// Regex to search for (Rust `regex` syntax)
+    pub pattern: String,
+    /// Replacement text; `$1` or `${name}` insert capture groups
+    pub replacement: String,
+    /// Match case exactly. Rules are case-insensitive by default, like
+    /// `replacements`.
+    #[serde(default)]
+    pub case_sensitive: bool,
+}
+
+fn default_date_format() -> String {
+    crate::text::numbers::DEFAULT_DATE_FORMAT.to_string()
+}
+
 /// Default filler-word list. Conservative: single-syllable disfluencies only.
 /// Multi-word phrases like "you know" or "sort of" are too aggressive for a
 /// default and can be added via the `filler_words` config.
@@ -62,3 +240,88 @@ fn default_filler_words() -> Vec<String> {
         "mhm".to_string(),
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Config;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_rules_parse_in_order() {
+        let toml_str = r#"
+            [text]
+            replacements = { "vox type" = "voxtype" }
+
+            [[text.rules]]
+            pattern = 'ticket (\d+)'
+            replacement = "JIRA-$1"
+
+            [[text.rules]]
+            pattern = "NASA"
+            replacement = "N.A.S.A."
+            case_sensitive = true
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let rules = &config.text.rules;
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].pattern, r"ticket (\d+)");
+        assert!(!rules[0].case_sensitive);
+        assert!(rules[1].case_sensitive);
+        assert!(Config::default().text.rules.is_empty());
+    }
+
+    #[test]
+    fn test_macros_table() {
+        let toml_str = r#"
+            [text]
+            spoken_punctuation = true
+
+            [text.macros]
+            "insert today's date" = "{date:%B %-d, %Y}"
+            "paste clipboard" = "{clipboard}"
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.text.spoken_punctuation);
+        assert_eq!(config.text.macros.len(), 2);
+        assert_eq!(
+            config
+                .text
+                .macros
+                .get("paste clipboard")
+                .map(String::as_str),
+            Some("{clipboard}")
+        );
+    }
+
+    #[test]
+    fn test_remove_fillers_alias() {
+        let toml_str = r#"
+            [text]
+            remove_fillers = true
+            filler_words = ["um", "uh", "like", "you know"]
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.text.filter_filler_words);
+        assert_eq!(config.text.filler_words.len(), 4);
+    }
+
+    #[test]
+    fn test_replacements_file_path_expands_tilde() {
+        let mut text = Config::default().text;
+        assert_eq!(text.replacements_file_path(), None);
+
+        text.replacements_file = Some("/etc/voxtype/dictionary.toml".to_string());
+        assert_eq!(
+            text.replacements_file_path(),
+            Some(PathBuf::from("/etc/voxtype/dictionary.toml"))
+        );
+
+        if let Some(home) = dirs::home_dir() {
+            text.replacements_file = Some("~/.config/voxtype/dictionary.toml".to_string());
+            assert_eq!(
+                text.replacements_file_path(),
+                Some(home.join(".config/voxtype/dictionary.toml"))
+            );
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Engine-agnostic transcription configuration.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Settings that apply to every transcription engine
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TranscribeConfig {
+    /// Type text while still speaking by re-decoding the growing recording
+    /// (default: false). Applies to engines without a native streaming
+    /// pipeline: local Whisper (gpu_isolation = false) and the batch ONNX
+    /// engines. Parakeet, Soniox and Zipformer use their own `streaming` options.
+    #[serde(default)]
+    pub streaming: bool,
+
+    /// How often the recording is re-decoded while streaming, in
+    /// milliseconds (default: 1000). Lower values feel snappier but cost
+    /// more CPU/GPU time per second of speech.
+    #[serde(default = "default_streaming_interval_ms")]
+    pub streaming_interval_ms: u64,
+
+    /// Longest stretch of audio re-decoded at once while streaming, in
+    /// seconds (default: 20). Once exceeded, the text for that stretch is
+    /// committed and decoding continues from the quietest recent point.
+    #[serde(default = "default_streaming_max_window_secs")]
+    pub streaming_max_window_secs: f32,
+
+    /// Transcriptions scoring below this confidence (0.0-1.0) trigger
+    /// `low_confidence_action`. Unset (the default) disables the check.
+    /// Only engines that report confidence are checked: local Whisper
+    /// (gpu_isolation = false), SenseVoice, Dolphin, Omnilingual and Vosk.
+    #[serde(default)]
+    pub min_confidence: Option<f32>,
+
+    /// What to do with a low-confidence transcription (default: notify)
+    #[serde(default)]
+    pub low_confidence_action: LowConfidenceAction,
+
+    /// Words and phrases to favor while decoding, mapped to a boost score
+    /// (roughly 1.0-5.0; higher wins more often but risks false matches).
+    /// Applied by the Zipformer transducer search, and by Parakeet TDT in
+    /// builds with the `parakeet-hotwords` feature.
+    /// Example: { "Kubernetes" = 2.0, "voxtype" = 3.0 }
+    #[serde(default)]
+    pub hotwords: HashMap<String, f32>,
+
+    /// Memory-map model files instead of reading them (default: false).
+    /// Applies to local Whisper and the ONNX engines except Parakeet.
+    /// Repeated loads (on_demand_loading, gpu_isolation) are served from
+    /// the page cache, and the file is never copied into a heap buffer.
+    #[serde(default)]
+    pub mmap_models: bool,
+}
+
+/// Handling of transcriptions below `[transcribe] min_confidence`
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LowConfidenceAction {
+    /// Output as usual, with a warning notification
+    #[default]
+    Notify,
+    /// Copy to the clipboard instead of typing, with a warning notification
+    ClipboardOnly,
+    /// Drop the text, with a warning notification
+    Discard,
+}
+
+fn default_streaming_interval_ms() -> u64 {
+    1000
+}
+
+fn default_streaming_max_window_secs() -> f32 {
+    20.0
+}
+
+impl TranscribeConfig {
+    /// Returns true if `confidence` is below the configured `min_confidence`.
+    /// Engines that report no confidence are never considered low.
+    pub fn is_low_confidence(&self, confidence: Option<f32>) -> bool {
+        matches!((self.min_confidence, confidence), (Some(min), Some(c)) if c < min)
+    }
+}
+
+impl Default for TranscribeConfig {
+    fn default() -> Self {
+        Self {
+            streaming: false,
+            streaming_interval_ms: default_streaming_interval_ms(),
+            streaming_max_window_secs: default_streaming_max_window_secs(),
+            min_confidence: None,
+            low_confidence_action: LowConfidenceAction::default(),
+            hotwords: HashMap::new(),
+            mmap_models: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn low_confidence_check_is_off_by_default() {
+        let config = TranscribeConfig::default();
+        assert!(!config.is_low_confidence(Some(0.01)));
+    }
+
+    #[test]
+    fn low_confidence_ignores_engines_without_scores() {
+        let config = TranscribeConfig {
+            min_confidence: Some(0.5),
+            ..TranscribeConfig::default()
+        };
+        assert!(config.is_low_confidence(Some(0.4)));
+        assert!(!config.is_low_confidence(Some(0.6)));
+        assert!(!config.is_low_confidence(None));
+    }
+
+    #[test]
+    fn low_confidence_action_parses_snake_case() {
+        let config: TranscribeConfig = toml::from_str(
+            r#"
+            min_confidence = 0.4
+            low_confidence_action = "clipboard_only"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.min_confidence, Some(0.4));
+        assert_eq!(
+            config.low_confidence_action,
+            LowConfidenceAction::ClipboardOnly
+        );
+    }
+
+    #[test]
+    fn hotwords_parse_from_subtable() {
+        let config: TranscribeConfig = toml::from_str(
+            r#"
+            [hotwords]
+            Kubernetes = 2.0
+            "pull request" = 1.5
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.hotwords.len(), 2);
+        assert_eq!(config.hotwords["Kubernetes"], 2.0);
+        assert_eq!(config.hotwords["pull request"], 1.5);
+    }
+}
@@ -10,7 +10,8 @@ use serde::{Deserialize, Serialize};
 pub enum VadBackend {
     /// Auto-select based on transcription engine (default)
     /// - Whisper engine: uses Whisper VAD (requires model download)
-    /// - Parakeet engine: uses Energy VAD (no model needed)
+    /// - Other engines: uses Silero VAD when built in and its model is
+    ///   downloaded, Energy VAD otherwise
     #[default]
     Auto,
     /// Energy-based VAD using RMS amplitude analysis
@@ -19,13 +20,17 @@ pub enum VadBackend {
     /// Whisper VAD using whisper-rs built-in Silero model (GGML format)
     /// More accurate but requires downloading ggml-silero-vad.bin
     Whisper,
+    /// Silero VAD run directly with ONNX Runtime, independent of whisper-rs
+    /// Works with any engine; requires the `silero-vad` feature and
+    /// downloading silero_vad.onnx
+    Silero,
 }
 
 /// Voice Activity Detection configuration
 ///
 /// VAD filters silence-only recordings before transcription to prevent
 /// Whisper hallucinations when processing silence.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct VadConfig {
     /// Enable Voice Activity Detection (default: false)
     /// When enabled, recordings with no detected speech are rejected before transcription
@@ -33,9 +38,10 @@ pub struct VadConfig {
     pub enabled: bool,
 
     /// VAD backend to use (default: auto)
-    /// - auto: Whisper VAD for Whisper engine, Energy VAD for Parakeet
+    /// - auto: Whisper VAD for Whisper engine, Silero or Energy VAD otherwise
     /// - energy: Simple RMS-based detection, no model needed
     /// - whisper: Silero VAD via whisper-rs, requires model download
+    /// - silero: Silero VAD via ONNX Runtime, requires model download
     #[serde(default)]
     pub backend: VadBackend,
 
@@ -49,7 +55,14 @@ pub struct VadConfig {
     #[serde(default = "default_min_speech_duration_ms")]
     pub min_speech_duration_ms: u32,
 
-    /// Path to VAD model file (optional, for Whisper VAD backend)
+    /// Stop toggle-mode recordings after this much trailing silence, in
+    /// milliseconds (default: 0, disabled)
+    /// Works whether or not `enabled` is set; speech is detected with the
+    /// configured backend (Energy VAD when that is Whisper VAD)
+    #[serde(default)]
+    pub auto_stop_silence_ms: u32,
+
+    /// Path to VAD model file (optional, for the Whisper and Silero backends)
     /// If not set, uses the default model location (~/.local/share/voxtype/models/)
     #[serde(default)]
     pub model: Option<String>,
@@ -70,6 +83,7 @@ impl Default for VadConfig {
             backend: VadBackend::default(),
             threshold: default_vad_threshold(),
             min_speech_duration_ms: default_min_speech_duration_ms(),
+            auto_stop_silence_ms: 0,
             model: None,
         }
     }
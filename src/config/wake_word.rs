@@ -0,0 +1,64 @@
+//! Wake word activation configuration.
+
+use serde::{Deserialize, Serialize};
+
+/// What starts a recording in the daemon
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Activation {
+    /// The hotkey, compositor keybindings or `voxtype record` (default)
+    #[default]
+    Hotkey,
+    /// Also listen for a wake word and start a hands-free recording when it
+    /// is heard; trailing silence stops it (requires the `wake-word` feature)
+    WakeWord,
+}
+
+/// Wake word detection with openWakeWord ONNX models
+///
+/// ```toml
+/// activation = "wake_word"
+///
+/// [wake_word]
+/// model = "hey_jarvis"
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WakeWordConfig {
+    /// Wake word model: a pretrained openWakeWord model ("alexa",
+    /// "hey_jarvis", "hey_mycroft" or "hey_rhasspy") or the path to a custom
+    /// one, such as a trained "hey voxtype" (default: "hey_jarvis")
+    #[serde(default = "default_model")]
+    pub model: String,
+
+    /// Detection threshold (0.0-1.0, default: 0.5)
+    /// Higher values mean fewer false activations but more missed ones
+    #[serde(default = "default_threshold")]
+    pub threshold: f32,
+
+    /// Trailing silence that ends a wake word recording, in milliseconds,
+    /// when `[vad] auto_stop_silence_ms` is not set (default: 1500)
+    #[serde(default = "default_silence_ms")]
+    pub silence_ms: u32,
+}
+
+fn default_model() -> String {
+    "hey_jarvis".to_string()
+}
+
+fn default_threshold() -> f32 {
+    0.5
+}
+
+fn default_silence_ms() -> u32 {
+    1500
+}
+
+impl Default for WakeWordConfig {
+    fn default() -> Self {
+        Self {
+            model: default_model(),
+            threshold: default_threshold(),
+            silence_ms: default_silence_ms(),
+        }
+    }
+}
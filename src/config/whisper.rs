@@ -1,6 +1,7 @@
 //! Whisper-specific configuration.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use super::{default_on_demand_loading, LanguageConfig};
 
@@ -16,6 +17,126 @@ pub enum WhisperMode {
     /// CLI transcription using whisper-cli subprocess
     /// Fallback for systems where whisper-rs FFI doesn't work (e.g., glibc 2.42+)
     Cli,
+    /// Managed whisper-server subprocess, kept running with the model loaded
+    Server,
+}
+
+/// Remote endpoint configuration: a single URL or an ordered failover list
+///
+/// - Single endpoint: `remote_endpoint = "http://gpu-box:8080"`
+/// - Failover list: `remote_endpoint = ["http://gpu-box:8080", "https://api.openai.com"]`
+/// - Per-endpoint credentials: list entries may be tables,
+///   `{ url = "https://api.openai.com", api_key = "sk-...", model = "whisper-1" }`
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum RemoteEndpointConfig {
+    /// Single endpoint URL
+    Single(String),
+    /// Endpoints tried in order when one times out or returns a 5xx
+    Multiple(Vec<RemoteEndpointEntry>),
+}
+
+/// One entry of a `remote_endpoint` failover list
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum RemoteEndpointEntry {
+    /// Bare URL, using `remote_api_key` and `remote_model`
+    Url(String),
+    /// URL with its own API key and model
+    Table(RemoteEndpoint),
+}
+
+/// A remote endpoint with the credentials and model to use for it
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct RemoteEndpoint {
+    /// Base URL (e.g., "http://192.168.1.100:8080")
+    pub url: String,
+    /// API key sent to this endpoint only (default: `remote_api_key`)
+    #[serde(default)]
+    pub api_key: Option<String>,
+    /// Model name sent to this endpoint only (default: `remote_model`)
+    #[serde(default)]
+    pub model: Option<String>,
+}
+
+impl RemoteEndpointEntry {
+    /// Base URL of this endpoint
+    pub fn url(&self) -> &str {
+        match self {
+            RemoteEndpointEntry::Url(url) => url,
+            RemoteEndpointEntry::Table(endpoint) => &endpoint.url,
+        }
+    }
+
+    /// Expand into a full endpoint; bare URLs carry no API key or model
+    pub fn to_endpoint(&self) -> RemoteEndpoint {
+        match self {
+            RemoteEndpointEntry::Url(url) => RemoteEndpoint {
+                url: url.clone(),
+                api_key: None,
+                model: None,
+            },
+            RemoteEndpointEntry::Table(endpoint) => endpoint.clone(),
+        }
+    }
+}
+
+impl From<String> for RemoteEndpointEntry {
+    fn from(s: String) -> Self {
+        RemoteEndpointEntry::Url(s)
+    }
+}
+
+impl From<&str> for RemoteEndpointEntry {
+    fn from(s: &str) -> Self {
+        RemoteEndpointEntry::Url(s.to_string())
+    }
+}
+
+impl RemoteEndpointConfig {
+    /// Convert to a vector of endpoints, in failover order
+    pub fn as_vec(&self) -> Vec<RemoteEndpoint> {
+        match self {
+            RemoteEndpointConfig::Single(s) => {
+                vec![RemoteEndpointEntry::from(s.as_str()).to_endpoint()]
+            }
+            RemoteEndpointConfig::Multiple(v) => v.iter().map(|e| e.to_endpoint()).collect(),
+        }
+    }
+
+    /// Get the first endpoint URL (empty if an empty list was configured)
+    pub fn primary(&self) -> &str {
+        match self {
+            RemoteEndpointConfig::Single(s) => s,
+            RemoteEndpointConfig::Multiple(v) => v.first().map(|e| e.url()).unwrap_or(""),
+        }
+    }
+
+    /// Parse from a comma-separated string (used for CLI and environment overrides)
+    pub fn from_comma_separated(s: &str) -> Self {
+        let mut parts: Vec<String> = s
+            .split(',')
+            .map(|p| p.trim().to_string())
+            .filter(|p| !p.is_empty())
+            .collect();
+        if parts.len() == 1 {
+            RemoteEndpointConfig::Single(parts.remove(0))
+        } else {
+            RemoteEndpointConfig::Multiple(parts.into_iter().map(Into::into).collect())
+        }
+    }
+}
+
+impl From<String> for RemoteEndpointConfig {
+    fn from(s: String) -> Self {
+        RemoteEndpointConfig::Single(s)
+    }
+}
+
+impl From<&str> for RemoteEndpointConfig {
+    fn from(s: &str) -> Self {
+        RemoteEndpointConfig::Single(s.to_string())
+    }
 }
 
 /// Whisper speech-to-text configuration
@@ -59,6 +180,22 @@ pub struct WhisperConfig {
     #[serde(default)]
     pub gpu_isolation: bool,
 
+    /// Seconds to keep a pre-spawned worker warm after each transcription
+    /// when gpu_isolation = true (default: 0 = disabled).
+    /// A replacement worker loads the model right after every job so the next
+    /// recording skips the spawn and load. It is retired after this many
+    /// seconds without a recording, releasing GPU memory again.
+    #[serde(default)]
+    pub worker_idle_ttl_secs: u64,
+
+    /// Keep the whisper decoding state (KV cache, scratch buffers) allocated
+    /// between recordings instead of rebuilding it for each one
+    /// (default: false). Trades the state's memory for less per-utterance
+    /// setup. Only applies when gpu_isolation = false and the model stays
+    /// loaded (on_demand_loading = false).
+    #[serde(default)]
+    pub keep_context_warm: bool,
+
     /// GPU device index for Vulkan/CUDA/Metal backend selection.
     /// On multi-GPU systems, whisper.cpp may select the integrated GPU (index 0)
     /// instead of the discrete GPU, causing slower transcription.
@@ -103,17 +240,67 @@ pub struct WhisperConfig {
     #[serde(default)]
     pub initial_prompt: Option<String>,
 
+    /// Known hallucinations to drop from the output, e.g. "Thanks for
+    /// watching!". Sentences matching a phrase (ignoring case and
+    /// punctuation) are removed before text processing.
+    #[serde(default)]
+    pub suppress_phrases: Vec<String>,
+
+    /// Discard the whole transcription when whisper's no-speech probability
+    /// reaches this value (0.0-1.0). Unset (the default) disables the check.
+    /// Local backend only; remote and CLI backends don't report it.
+    #[serde(default)]
+    pub no_speech_threshold: Option<f32>,
+
+    /// Decoder tuning (`[whisper.decoding]`). Unset values keep
+    /// whisper.cpp's defaults.
+    #[serde(default)]
+    pub decoding: WhisperDecodingConfig,
+
     // --- Multi-model settings ---
     /// Secondary model to use when hotkey.model_modifier is held
     /// Example: "large-v3-turbo" for difficult audio
     #[serde(default)]
     pub secondary_model: Option<String>,
 
+    /// Re-run recordings through `secondary_model` before output when the
+    /// primary model's confidence (0.0-1.0) is below this value.
+    /// Unset (the default) disables the automatic retry.
+    #[serde(default)]
+    pub secondary_retry_confidence: Option<f32>,
+
+    /// Only retry recordings up to this many seconds long (default: 10),
+    /// so a long dictation is never transcribed twice
+    #[serde(default = "default_secondary_retry_max_secs")]
+    pub secondary_retry_max_secs: f32,
+
+    /// Larger model that re-transcribes each recording in the background
+    /// after the primary model's draft has been output (two-pass mode).
+    /// Example: "large-v3-turbo" with model = "tiny.en"
+    #[serde(default)]
+    pub redo_model: Option<String>,
+
+    /// What to do when the redo pass differs from the draft
+    #[serde(default)]
+    pub redo_action: RedoAction,
+
+    /// Fraction of words (0.0-1.0) that must differ between draft and redo
+    /// before a correction is offered (default: 0.1)
+    #[serde(default = "default_redo_min_change")]
+    pub redo_min_change: f32,
+
     /// List of available models that can be selected via CLI --model flag
     /// These models can be loaded on-demand when requested
     #[serde(default)]
     pub available_models: Vec<String>,
 
+    /// Extra named models: name -> download URL (or local path) of a ggml
+    /// model. Registered names work anywhere a built-in name does (`model`,
+    /// `--model`, `voxtype setup --download --model <name>`). Downloads are
+    /// saved as `ggml-<name>.bin` in the models directory.
+    #[serde(default)]
+    pub custom_models: HashMap<String, String>,
+
     /// Maximum number of models to keep loaded in memory (LRU eviction)
     /// Default: 2 (primary model + one secondary)
     /// Only applies when gpu_isolation = false
@@ -127,10 +314,14 @@ pub struct WhisperConfig {
     pub cold_model_timeout_secs: u64,
 
     // --- Remote backend settings ---
-    /// Remote server endpoint URL (e.g., "http://192.168.1.100:8080")
-    /// Required when mode = "remote"
+    /// Remote server endpoint URL (e.g., "http://192.168.1.100:8080"), or a
+    /// list of URLs tried in order when one times out or returns a 5xx. List
+    /// entries may be `{ url, api_key, model }` tables so each endpoint gets
+    /// its own credentials.
+    /// Required when mode = "remote". A ws:// or wss:// URL streams audio
+    /// while recording (requires the `remote-ws` feature).
     #[serde(default)]
-    pub remote_endpoint: Option<String>,
+    pub remote_endpoint: Option<RemoteEndpointConfig>,
 
     /// Model name to send to remote server (default: "whisper-1")
     #[serde(default)]
@@ -149,9 +340,28 @@ pub struct WhisperConfig {
     /// Used when mode = "cli"
     #[serde(default)]
     pub whisper_cli_path: Option<String>,
+
+    // --- Server backend settings ---
+    /// Path to whisper-server binary (optional, searches PATH if not set)
+    /// Used when mode = "server"
+    #[serde(default)]
+    pub whisper_server_path: Option<String>,
+
+    /// Localhost port for the managed whisper-server (default: a free port)
+    #[serde(default)]
+    pub whisper_server_port: Option<u16>,
 }
 
 impl WhisperConfig {
+    /// Returns true if the remote backend streams over WebSocket
+    pub fn remote_uses_websocket(&self) -> bool {
+        self.effective_mode() == WhisperMode::Remote
+            && self.remote_endpoint.as_ref().is_some_and(|e| {
+                let primary = e.primary();
+                primary.starts_with("ws://") || primary.starts_with("wss://")
+            })
+    }
+
     /// Get the effective execution mode, preferring `mode` over deprecated `backend`
     pub fn effective_mode(&self) -> WhisperMode {
         // Prefer `mode` if set
@@ -167,11 +377,13 @@ impl WhisperConfig {
                     WhisperMode::Local => "local",
                     WhisperMode::Remote => "remote",
                     WhisperMode::Cli => "cli",
+                    WhisperMode::Server => "server",
                 },
                 match backend {
                     WhisperMode::Local => "local",
                     WhisperMode::Remote => "remote",
                     WhisperMode::Cli => "cli",
+                    WhisperMode::Server => "server",
                 }
             );
             return backend;
@@ -180,6 +392,63 @@ impl WhisperConfig {
     }
 }
 
+fn default_secondary_retry_max_secs() -> f32 {
+    10.0
+}
+
+fn default_redo_min_change() -> f32 {
+    0.1
+}
+
+/// Handling of a two-pass correction (see `WhisperConfig::redo_model`)
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RedoAction {
+    /// Show the corrected text in a notification with a "Copy" action
+    #[default]
+    Notify,
+    /// Backspace the typed draft and type the correction, when the draft
+    /// was typed and nothing has been dictated since. Otherwise notify.
+    Replace,
+}
+
+/// whisper.cpp decoding parameters (`[whisper.decoding]`)
+///
+/// Decoding starts at `temperature`; when a segment fails the entropy or
+/// log-probability check it is re-decoded at `temperature +
+/// temperature_increment`, and so on up to 1.0.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct WhisperDecodingConfig {
+    /// Beam search width. Unset (the default) uses greedy decoding.
+    #[serde(default)]
+    pub beam_size: Option<u32>,
+
+    /// Candidates sampled at each fallback temperature in greedy mode
+    /// (default: 1)
+    #[serde(default)]
+    pub best_of: Option<u32>,
+
+    /// Initial sampling temperature (whisper.cpp default: 0.0)
+    #[serde(default)]
+    pub temperature: Option<f32>,
+
+    /// Temperature added on each fallback (whisper.cpp default: 0.2).
+    /// 0.0 disables fallback.
+    #[serde(default)]
+    pub temperature_increment: Option<f32>,
+
+    /// Re-decode segments whose token entropy falls below this value, a
+    /// sign of repetition loops. whisper.cpp's stand-in for the
+    /// compression-ratio threshold (default: 2.4).
+    #[serde(default)]
+    pub entropy_threshold: Option<f32>,
+
+    /// Re-decode segments whose average log probability falls below this
+    /// value (whisper.cpp default: -1.0)
+    #[serde(default)]
+    pub logprob_threshold: Option<f32>,
+}
+
 impl Default for WhisperConfig {
     fn default() -> Self {
         Self {
@@ -191,6 +460,8 @@ impl Default for WhisperConfig {
             threads: None,
             on_demand_loading: default_on_demand_loading(),
             gpu_isolation: false,
+            worker_idle_ttl_secs: 0,
+            keep_context_warm: false,
             gpu_device: None,
             flash_attention: false,
             context_window_optimization: default_context_window_optimization(),
@@ -198,8 +469,17 @@ impl Default for WhisperConfig {
             eager_chunk_secs: default_eager_chunk_secs(),
             eager_overlap_secs: default_eager_overlap_secs(),
             initial_prompt: None,
+            suppress_phrases: Vec::new(),
+            no_speech_threshold: None,
+            decoding: WhisperDecodingConfig::default(),
             secondary_model: None,
+            secondary_retry_confidence: None,
+            secondary_retry_max_secs: default_secondary_retry_max_secs(),
+            redo_model: None,
+            redo_action: RedoAction::default(),
+            redo_min_change: default_redo_min_change(),
             available_models: vec![],
+            custom_models: HashMap::new(),
             max_loaded_models: default_max_loaded_models(),
             cold_model_timeout_secs: default_cold_model_timeout(),
             remote_endpoint: None,
@@ -207,6 +487,8 @@ impl Default for WhisperConfig {
             remote_api_key: None,
             remote_timeout_secs: None,
             whisper_cli_path: None,
+            whisper_server_path: None,
+            whisper_server_port: None,
         }
     }
 }
@@ -372,6 +654,66 @@ mod tests {
         assert_eq!(config.whisper.effective_mode(), WhisperMode::Remote);
     }
 
+    #[test]
+    fn test_parse_whisper_mode_server() {
+        let toml_str = r#"
+            [whisper]
+            mode = "server"
+            model = "large-v3-turbo"
+            whisper_server_path = "/opt/whisper.cpp/build/bin/whisper-server"
+            whisper_server_port = 8178
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.whisper.effective_mode(), WhisperMode::Server);
+        assert_eq!(
+            config.whisper.whisper_server_path.as_deref(),
+            Some("/opt/whisper.cpp/build/bin/whisper-server")
+        );
+        assert_eq!(config.whisper.whisper_server_port, Some(8178));
+    }
+
+    #[test]
+    fn test_parse_remote_endpoint_list() {
+        let toml_str = r#"
+            [whisper]
+            mode = "remote"
+            remote_endpoint = ["http://gpu-box:8080", "https://api.openai.com"]
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let endpoint = config.whisper.remote_endpoint.unwrap();
+        assert_eq!(endpoint.primary(), "http://gpu-box:8080");
+        assert_eq!(endpoint.as_vec().len(), 2);
+        assert_eq!(
+            RemoteEndpointConfig::from_comma_separated("http://a:1, http://b:2"),
+            RemoteEndpointConfig::Multiple(vec!["http://a:1".into(), "http://b:2".into()])
+        );
+    }
+
+    #[test]
+    fn test_parse_remote_endpoint_tables() {
+        let toml_str = r#"
+            [whisper]
+            mode = "remote"
+            remote_endpoint = [
+                { url = "http://gpu-box:8080", model = "large-v3-turbo" },
+                { url = "https://api.openai.com", api_key = "sk-test", model = "whisper-1" },
+                "http://backup:8080",
+            ]
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let endpoint = config.whisper.remote_endpoint.unwrap();
+        assert_eq!(endpoint.primary(), "http://gpu-box:8080");
+        let endpoints = endpoint.as_vec();
+        assert_eq!(endpoints[0].api_key, None);
+        assert_eq!(endpoints[0].model.as_deref(), Some("large-v3-turbo"));
+        assert_eq!(endpoints[1].api_key.as_deref(), Some("sk-test"));
+        assert_eq!(endpoints[2].url, "http://backup:8080");
+        assert_eq!(endpoints[2].model, None);
+    }
+
     #[test]
     fn test_whisper_backend_alias_local() {
         // Test that deprecated 'backend' field still works
@@ -484,6 +826,128 @@ mod tests {
         assert_eq!(config.whisper.effective_mode(), WhisperMode::Local);
     }
 
+    #[test]
+    fn test_keep_context_warm() {
+        assert!(!Config::default().whisper.keep_context_warm);
+
+        let toml_str = r#"
+            [whisper]
+            model = "base.en"
+            keep_context_warm = true
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.whisper.keep_context_warm);
+    }
+
+    #[test]
+    fn test_worker_idle_ttl_defaults_to_disabled() {
+        let config = Config::default();
+        assert_eq!(config.whisper.worker_idle_ttl_secs, 0);
+
+        let toml_str = r#"
+            [whisper]
+            model = "base.en"
+            gpu_isolation = true
+            worker_idle_ttl_secs = 120
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.whisper.gpu_isolation);
+        assert_eq!(config.whisper.worker_idle_ttl_secs, 120);
+    }
+
+    #[test]
+    fn test_secondary_retry_defaults_and_parse() {
+        let config = Config::default();
+        assert!(config.whisper.secondary_retry_confidence.is_none());
+        assert_eq!(config.whisper.secondary_retry_max_secs, 10.0);
+
+        let toml_str = r#"
+            [whisper]
+            model = "base.en"
+            secondary_model = "large-v3-turbo"
+            secondary_retry_confidence = 0.6
+            secondary_retry_max_secs = 5.0
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.whisper.secondary_retry_confidence, Some(0.6));
+        assert_eq!(config.whisper.secondary_retry_max_secs, 5.0);
+    }
+
+    #[test]
+    fn test_redo_defaults_and_parse() {
+        let config = Config::default();
+        assert!(config.whisper.redo_model.is_none());
+        assert_eq!(config.whisper.redo_action, RedoAction::Notify);
+
+        let toml_str = r#"
+            [whisper]
+            model = "tiny.en"
+            redo_model = "large-v3-turbo"
+            redo_action = "replace"
+            redo_min_change = 0.25
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.whisper.redo_model.as_deref(), Some("large-v3-turbo"));
+        assert_eq!(config.whisper.redo_action, RedoAction::Replace);
+        assert_eq!(config.whisper.redo_min_change, 0.25);
+    }
+
+    #[test]
+    fn test_custom_models_parse() {
+        let toml_str = r#"
+            [whisper]
+            model = "my-finetune"
+
+            [whisper.custom_models]
+            my-finetune = "https://example.com/ggml-model.bin"
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            config
+                .whisper
+                .custom_models
+                .get("my-finetune")
+                .map(String::as_str),
+            Some("https://example.com/ggml-model.bin")
+        );
+        assert!(Config::default().whisper.custom_models.is_empty());
+    }
+
+    #[test]
+    fn test_suppress_phrases_parse() {
+        let toml_str = r#"
+            [whisper]
+            model = "base.en"
+            suppress_phrases = ["Thanks for watching!", "Subscribe"]
+            no_speech_threshold = 0.8
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.whisper.suppress_phrases.len(), 2);
+        assert_eq!(config.whisper.no_speech_threshold, Some(0.8));
+        assert!(Config::default().whisper.suppress_phrases.is_empty());
+    }
+
+    #[test]
+    fn test_decoding_parse() {
+        let config = Config::default();
+        assert_eq!(config.whisper.decoding, WhisperDecodingConfig::default());
+
+        let toml_str = r#"
+            [whisper]
+            model = "base.en"
+
+            [whisper.decoding]
+            beam_size = 5
+            temperature_increment = 0.0
+            entropy_threshold = 2.8
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.whisper.decoding.beam_size, Some(5));
+        assert_eq!(config.whisper.decoding.temperature_increment, Some(0.0));
+        assert_eq!(config.whisper.decoding.entropy_threshold, Some(2.8));
+        assert!(config.whisper.decoding.best_of.is_none());
+    }
+
     #[test]
     fn test_config_on_demand_loading_whisper() {
         let config = Config::default();
@@ -5,7 +5,9 @@
 
 use crate::audio::feedback::{AudioFeedback, SoundEvent};
 use crate::audio::{self, AudioCapture};
-use crate::config::{ActivationMode, Config, FileMode, OutputMode};
+use crate::config::{
+    Activation, ActivationMode, Config, FileMode, LowConfidenceAction, OutputMode, RedoAction,
+};
 use crate::eager::{self, EagerConfig};
 use crate::error::Result;
 #[cfg(target_os = "linux")]
@@ -14,18 +16,22 @@ use crate::hotkey::{self, HotkeyEvent};
 use crate::hotkey_macos::{self as hotkey, HotkeyEvent};
 use crate::meeting::{self, MeetingDaemon, MeetingEvent, StorageConfig};
 use crate::model_manager::ModelManager;
-#[cfg(target_os = "macos")]
 use crate::notification;
 use crate::output;
 use crate::output::post_process::PostProcessor;
 use crate::output::streaming::StreamingSession;
 use crate::output::TextOutput;
 use crate::state::{ChunkResult, State};
-use crate::text::TextProcessor;
+use crate::status_json::{detected_language_file, engine_fallback_file, execution_provider_file};
+use crate::text::{EditCommand, TextProcessor};
+use crate::transcribe::incremental::IncrementalTranscriber;
+use crate::transcribe::two_pass::{self, RedoOutcome};
 use crate::transcribe::{StreamHandle, StreamingEvent, Transcriber};
 use pidlock::Pidlock;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::process::Command;
@@ -99,6 +105,122 @@ fn write_state_file(path: &PathBuf, state: &str) {
     }
 }
 
+/// Record a fallback as "<configured> <active>" so status consumers can
+/// report both
+fn write_engine_fallback_file(
+    configured: crate::config::TranscriptionEngine,
+    active: crate::config::TranscriptionEngine,
+) {
+    let path = engine_fallback_file();
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    if let Err(e) = std::fs::write(&path, format!("{} {}", configured.name(), active.name())) {
+        tracing::warn!("Failed to write engine fallback file: {}", e);
+    }
+}
+
+/// Record the ONNX execution provider the engine was built with for status
+/// consumers. Nothing is written until an ONNX engine has loaded.
+fn write_execution_provider_file() {
+    let Some(provider) = crate::transcribe::onnx_ep::active_provider() else {
+        return;
+    };
+    let path = execution_provider_file();
+    if std::fs::read_to_string(&path).is_ok_and(|current| current == provider) {
+        return;
+    }
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    if let Err(e) = std::fs::write(&path, provider) {
+        tracing::warn!("Failed to write execution provider file: {}", e);
+    }
+}
+
+/// Record the auto-detected language of the last transcription for status
+/// consumers, or clear it when the language was not detected
+fn write_detected_language_file(language: Option<&str>) {
+    let path = detected_language_file();
+    let Some(language) = language else {
+        let _ = std::fs::remove_file(&path);
+        return;
+    };
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    if let Err(e) = std::fs::write(&path, language) {
+        tracing::warn!("Failed to write detected language file: {}", e);
+    }
+}
+
+/// Build a transcriber for an on-demand recording or a profile's own
+/// engine, walking `engine_fallback` when the configured engine fails to
+/// initialize.
+/// Returns the transcriber and the engine that provided it.
+fn load_engine_with_fallback(
+    config: &Config,
+    config_path: Option<PathBuf>,
+    model_override: Option<&str>,
+) -> ModelLoadResult {
+    let mut config = config.clone();
+    let mut fallbacks = config.engine_fallback.clone().into_iter();
+    loop {
+        let result = match config.engine {
+            crate::config::TranscriptionEngine::Whisper => {
+                ModelManager::new(&config.whisper, config_path.clone())
+                    .get_transcriber(model_override)
+            }
+            _ => crate::transcribe::create_transcriber(&config).map(Arc::from),
+        };
+        let err = match result {
+            Ok(transcriber) => return Ok((transcriber, config.engine)),
+            Err(e) => e,
+        };
+        let Some(next) = fallbacks.next() else {
+            return Err(err);
+        };
+        tracing::warn!(
+            "Engine '{}' failed to initialize: {}. Falling back to '{}'",
+            config.engine.name(),
+            err,
+            next.name()
+        );
+        config.engine = next;
+    }
+}
+
+/// Load the model for the active engine. Whisper goes through the model
+/// manager; every other engine returns its transcriber for the daemon to
+/// hold.
+fn preload_engine(
+    config: &Config,
+    model_manager: &mut ModelManager,
+) -> std::result::Result<Option<Arc<dyn Transcriber>>, crate::error::TranscribeError> {
+    match config.engine {
+        crate::config::TranscriptionEngine::Whisper => {
+            model_manager.preload_primary()?;
+            Ok(None)
+        }
+        crate::config::TranscriptionEngine::Parakeet
+        | crate::config::TranscriptionEngine::Moonshine
+        | crate::config::TranscriptionEngine::SenseVoice
+        | crate::config::TranscriptionEngine::Paraformer
+        | crate::config::TranscriptionEngine::Dolphin
+        | crate::config::TranscriptionEngine::Omnilingual
+        | crate::config::TranscriptionEngine::Cohere
+        | crate::config::TranscriptionEngine::Soniox
+        | crate::config::TranscriptionEngine::Vosk
+        | crate::config::TranscriptionEngine::Zipformer => {
+            // Non-Whisper engines do their own setup; Soniox just validates
+            // API key + endpoint at construction (no model to download).
+            Ok(Some(Arc::from(crate::transcribe::create_transcriber(
+                config,
+            )?)))
+        }
+    }
+}
+
 /// Remove state file on shutdown
 fn cleanup_state_file(path: &PathBuf) {
     if path.exists() {
@@ -183,6 +305,29 @@ fn cleanup_cancel_file() {
     }
 }
 
+/// Read and consume a `voxtype translate` request
+/// Returns the requested translate setting given the current one
+fn read_translate_request(current: bool) -> Option<bool> {
+    let trigger_file = Config::runtime_dir().join("translate");
+    let request = std::fs::read_to_string(&trigger_file).ok()?;
+    let _ = std::fs::remove_file(&trigger_file);
+    Some(resolve_translate_request(&request, current))
+}
+
+/// Read and consume a `voxtype undo` request
+fn read_undo_request() -> bool {
+    std::fs::remove_file(Config::runtime_dir().join("undo")).is_ok()
+}
+
+/// Resolve "on", "off" or "toggle" against the current setting
+fn resolve_translate_request(request: &str, current: bool) -> bool {
+    match request.trim() {
+        "on" => true,
+        "off" => false,
+        _ => !current,
+    }
+}
+
 /// Read and consume the output mode override file
 /// Returns the override mode if the file exists and is valid, None otherwise
 /// Output mode override result, which may include a file path for file mode
@@ -193,7 +338,7 @@ enum OutputOverride {
 }
 
 /// Read and consume the output mode override file
-/// Format: "type", "clipboard", "paste", "file", or "file:/path/to/file.txt"
+/// Format: "type", "clipboard", "paste", "primary", "tmux", "webhook", "file", or "file:/path/to/file.txt"
 fn read_output_mode_override() -> Option<OutputOverride> {
     let override_file = Config::runtime_dir().join("output_mode_override");
     if !override_file.exists() {
@@ -239,6 +384,18 @@ fn read_output_mode_override() -> Option<OutputOverride> {
             tracing::info!("Using output mode override: paste");
             Some(OutputOverride::Mode(OutputMode::Paste))
         }
+        "primary" => {
+            tracing::info!("Using output mode override: primary");
+            Some(OutputOverride::Mode(OutputMode::Primary))
+        }
+        "tmux" => {
+            tracing::info!("Using output mode override: tmux");
+            Some(OutputOverride::Mode(OutputMode::Tmux))
+        }
+        "webhook" => {
+            tracing::info!("Using output mode override: webhook");
+            Some(OutputOverride::Mode(OutputMode::Webhook))
+        }
         "file" => {
             tracing::info!("Using output mode override: file (using config path)");
             Some(OutputOverride::Mode(OutputMode::File))
@@ -256,6 +413,16 @@ fn cleanup_output_mode_override() {
     let _ = std::fs::remove_file(&override_file);
 }
 
+/// Text processor for `config`. Punctuation phrases and spacing follow the
+/// transcription language unless `[text] locale` is set.
+fn build_text_processor(config: &Config) -> TextProcessor {
+    let mut text_config = config.text.clone();
+    if text_config.locale.is_none() {
+        text_config.locale = Some(config.whisper.language.primary().to_string());
+    }
+    TextProcessor::new(&text_config)
+}
+
 /// Read and consume the profile override file
 /// Returns the profile name if the file exists and is valid, None otherwise
 fn read_profile_override() -> Option<String> {
@@ -286,6 +453,18 @@ fn read_profile_override() -> Option<String> {
     Some(profile_name)
 }
 
+/// How long to wait for the desktop to report the focused window
+const FOCUS_LOOKUP_TIMEOUT_MS: u64 = 300;
+
+/// Read the profile override file without consuming it, so settings that
+/// apply before post-processing (e.g. `grammar_file`) see the same profile
+fn peek_profile_override() -> Option<String> {
+    let profile_file = Config::runtime_dir().join("profile_override");
+    let content = std::fs::read_to_string(&profile_file).ok()?;
+    let profile_name = content.trim();
+    (!profile_name.is_empty()).then(|| profile_name.to_string())
+}
+
 /// Remove the profile override file if it exists (for cleanup on cancel/error)
 fn cleanup_profile_override() {
     let profile_file = Config::runtime_dir().join("profile_override");
@@ -303,6 +482,28 @@ fn write_profile_override(profile_name: &str) {
     }
 }
 
+/// Read and consume the target window override file
+/// (`voxtype record start --target <pattern>`)
+fn read_target_override() -> Option<String> {
+    let target_file = Config::runtime_dir().join("target_override");
+    let content = std::fs::read_to_string(&target_file).ok()?;
+    if let Err(e) = std::fs::remove_file(&target_file) {
+        tracing::warn!("Failed to remove target override file: {}", e);
+    }
+    let target = content.trim();
+    (!target.is_empty()).then(|| target.to_string())
+}
+
+/// Remove the target window override files if they exist (for cleanup on
+/// cancel/error)
+fn cleanup_target_override() {
+    let _ = std::fs::remove_file(Config::runtime_dir().join("target_override"));
+    cleanup_bool_override("refocus");
+}
+
+/// Time for the compositor to move keyboard focus before typing
+const TARGET_FOCUS_SETTLE_MS: u64 = 100;
+
 /// Read and consume a boolean override file from the runtime directory.
 /// Returns Some(true) or Some(false) if the file exists and is valid, None otherwise.
 fn read_bool_override(name: &str) -> Option<bool> {
@@ -351,6 +552,8 @@ fn cleanup_bool_override(name: &str) {
 struct MeetingStartTrigger {
     title: Option<String>,
     diarization: Option<String>,
+    /// Stored meeting to reopen instead of starting a new one
+    resume: Option<String>,
 }
 
 /// Read a file and return its trimmed contents, or None if missing or empty.
@@ -419,10 +622,19 @@ fn check_meeting_start() -> Option<MeetingStartTrigger> {
         read_trimmed_nonempty(&diarization_file).and_then(validate_diarization_override);
     let _ = std::fs::remove_file(&diarization_file);
 
+    // `voxtype meeting resume <id>` names the meeting to continue
+    let resume_file = runtime_dir.join("meeting_start_resume");
+    let resume = read_trimmed_nonempty(&resume_file);
+    let _ = std::fs::remove_file(&resume_file);
+
     // Remove the start trigger last to acknowledge the command.
     let _ = std::fs::remove_file(&start_file);
 
-    Some(MeetingStartTrigger { title, diarization })
+    Some(MeetingStartTrigger {
+        title,
+        diarization,
+        resume,
+    })
 }
 
 /// Check for meeting stop command (via file trigger)
@@ -458,15 +670,29 @@ fn check_meeting_resume() -> bool {
     }
 }
 
+/// Check for meeting mark command (via file trigger). Returns the note,
+/// if any.
+fn check_meeting_mark() -> Option<Option<String>> {
+    let mark_file = Config::runtime_dir().join("meeting_mark");
+    if !mark_file.exists() {
+        return None;
+    }
+    let note = read_trimmed_nonempty(&mark_file);
+    let _ = std::fs::remove_file(&mark_file);
+    Some(note)
+}
+
 /// Clean up any stale meeting command files on startup
 fn cleanup_meeting_files() {
     let runtime_dir = Config::runtime_dir();
     for name in &[
         "meeting_start",
         "meeting_start_diarization",
+        "meeting_start_resume",
         "meeting_stop",
         "meeting_pause",
         "meeting_resume",
+        "meeting_mark",
     ] {
         let file = runtime_dir.join(name);
         if file.exists() {
@@ -475,33 +701,236 @@ fn cleanup_meeting_files() {
     }
 }
 
-/// Mark any active/paused meetings as completed on daemon startup.
-/// This handles meetings orphaned by a crash or daemon restart.
-fn cleanup_stale_meetings(config: &Config) {
+/// Split a stopped meeting into chapters, saving them with its transcript,
+/// then run the meeting's completion hooks and upload it to the sync server
+async fn finish_stopped_meeting(
+    config: crate::config::MeetingConfig,
+    storage_config: StorageConfig,
+    mut data: meeting::MeetingData,
+) {
+    if config.chapters.is_enabled() {
+        let (chapters, summary) = (config.chapters.clone(), config.summary.clone());
+        let storage_config = storage_config.clone();
+        let chaptered = tokio::task::spawn_blocking(move || {
+            data.transcript.chapters = meeting::chapters::generate(&chapters, &summary, &data);
+            let saved = meeting::MeetingStorage::open(storage_config)
+                .and_then(|storage| storage.save_transcript(&data.metadata.id, &data.transcript));
+            if let Err(e) = saved {
+                tracing::warn!("Failed to save meeting chapters: {}", e);
+            }
+            data
+        })
+        .await;
+        match chaptered {
+            Ok(chaptered) => data = chaptered,
+            Err(e) => {
+                tracing::warn!("Meeting chapters failed: {}", e);
+                return;
+            }
+        }
+    }
+
+    if config.hooks.is_configured() {
+        let event = meeting::hooks::HookEvent::Completed;
+        if let Err(e) = meeting::hooks::run_hooks(&config.hooks, event, &data, None).await {
+            tracing::warn!("Meeting hooks failed: {}", e);
+        }
+    }
+
+    if config.sync.is_enabled() && config.sync.auto {
+        let (sync, id) = (config.sync.clone(), data.metadata.id);
+        let synced = tokio::task::spawn_blocking(move || {
+            let Some(client) = meeting::sync::SyncClient::new(&sync) else {
+                return Ok(());
+            };
+            let storage = meeting::MeetingStorage::open(storage_config)?;
+            meeting::sync::sync_meeting(&storage, &client, &id).map(|_| ())
+        })
+        .await;
+        match synced {
+            Ok(Ok(())) => tracing::info!("Meeting {} synced", id),
+            // Left pending; the periodic sync retries it
+            Ok(Err(e)) => tracing::warn!("Meeting sync failed: {}", e),
+            Err(e) => tracing::warn!("Meeting sync failed: {}", e),
+        }
+    }
+}
+
+/// Meeting storage settings from the config
+fn meeting_storage_config(config: &Config) -> StorageConfig {
     let storage_path = if config.meeting.storage_path == "auto" {
         Config::data_dir().join("meetings")
     } else {
         std::path::PathBuf::from(&config.meeting.storage_path)
     };
 
-    let storage_config = StorageConfig {
+    StorageConfig {
         storage_path,
         retain_audio: config.meeting.retain_audio,
         max_meetings: 0,
-    };
+    }
+}
+
+/// Prune stored meetings under `[meeting.retention]` now and then every
+/// `prune_interval_hours`, for as long as the daemon runs
+fn spawn_meeting_pruning(config: &Config) {
+    let retention = config.meeting.retention.clone();
+    if !retention.is_enabled() {
+        return;
+    }
+    let storage_config = meeting_storage_config(config);
+    let period = Duration::from_secs(u64::from(retention.prune_interval_hours.max(1)) * 3600);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        loop {
+            interval.tick().await;
+            let (storage_config, retention) = (storage_config.clone(), retention.clone());
+            let pruned = tokio::task::spawn_blocking(move || {
+                let storage = meeting::MeetingStorage::open(storage_config)?;
+                meeting::retention::prune(&storage, &retention, false)
+            })
+            .await;
+            match pruned {
+                Ok(Ok(pruned)) if !pruned.is_empty() => {
+                    let freed: u64 = pruned.iter().map(|p| p.freed).sum();
+                    tracing::info!(
+                        "Pruned {} meeting(s) under [meeting.retention], freeing {}",
+                        pruned.len(),
+                        meeting::retention::format_size(freed)
+                    );
+                }
+                Ok(Ok(_)) => {}
+                Ok(Err(e)) => tracing::warn!("Failed to prune meetings: {}", e),
+                Err(e) => tracing::warn!("Failed to prune meetings: {}", e),
+            }
+        }
+    });
+}
+
+/// Watch for calls under `[meeting.auto_start]`, checking the apps that
+/// record from the microphone every `poll_secs`. Returns the channel call
+/// starts and ends arrive on, or `None` when auto-start is off.
+fn spawn_call_detection(
+    config: &Config,
+) -> Option<tokio::sync::mpsc::Receiver<meeting::calls::CallChange>> {
+    let auto_start = config.meeting.auto_start.clone();
+    if !config.meeting.enabled || !auto_start.is_enabled() {
+        return None;
+    }
+    let matcher = meeting::calls::CallMatcher::new(&auto_start);
+    let mut tracker =
+        meeting::calls::CallTracker::new(Duration::from_secs(u64::from(auto_start.end_grace_secs)));
+    let period = Duration::from_secs(u64::from(auto_start.poll_secs.max(1)));
+    let (tx, rx) = tokio::sync::mpsc::channel(8);
+    tracing::info!("Watching for calls to start meetings ({})", auto_start.mode);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        let mut warned = false;
+        loop {
+            interval.tick().await;
+            let recording = match tokio::task::spawn_blocking(meeting::calls::recording_apps).await
+            {
+                Ok(Ok(recording)) => recording,
+                Ok(Err(e)) if !warned => {
+                    tracing::warn!("Can't check for calls (pactl list source-outputs): {}", e);
+                    warned = true;
+                    continue;
+                }
+                _ => continue,
+            };
+            let call = matcher.find_call(&recording).map(|app| app.display_name());
+            if let Some(change) = tracker.update(call, Instant::now()) {
+                if tx.send(change).await.is_err() {
+                    return;
+                }
+            }
+        }
+    });
+    Some(rx)
+}
+
+/// Upload meetings that haven't synced under `[meeting.sync]` now and then
+/// every `interval_mins`, for as long as the daemon runs
+fn spawn_meeting_sync(config: &Config) {
+    let sync = config.meeting.sync.clone();
+    if !sync.is_enabled() || !sync.auto {
+        return;
+    }
+    let storage_config = meeting_storage_config(config);
+    let period = Duration::from_secs(u64::from(sync.interval_mins.max(1)) * 60);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        loop {
+            interval.tick().await;
+            let (storage_config, sync) = (storage_config.clone(), sync.clone());
+            let synced = tokio::task::spawn_blocking(move || {
+                let Some(client) = meeting::sync::SyncClient::new(&sync) else {
+                    return Ok(Vec::new());
+                };
+                let storage = meeting::MeetingStorage::open(storage_config)?;
+                meeting::sync::sync_pending(&storage, &client)
+            })
+            .await;
+            match synced {
+                Ok(Ok(synced)) => {
+                    let failed = synced.iter().filter(|s| s.result.is_err()).count();
+                    if let Some(failure) = synced.iter().find_map(|s| s.result.as_ref().err()) {
+                        tracing::warn!(
+                            "Failed to sync {} of {} meeting(s): {}",
+                            failed,
+                            synced.len(),
+                            failure
+                        );
+                    } else if !synced.is_empty() {
+                        tracing::info!("Synced {} meeting(s)", synced.len());
+                    }
+                }
+                Ok(Err(e)) => tracing::warn!("Failed to sync meetings: {}", e),
+                Err(e) => tracing::warn!("Failed to sync meetings: {}", e),
+            }
+        }
+    });
+}
 
-    match meeting::MeetingStorage::open(storage_config) {
-        Ok(storage) => match storage.complete_stale_meetings() {
-            Ok(count) if count > 0 => {
-                tracing::info!("Marked {} orphaned meeting(s) as completed", count);
+/// Finish meetings left active or paused by a crash or daemon restart,
+/// keeping their transcript up to the last checkpoint, and tell the user
+/// how to continue them.
+async fn recover_interrupted_meetings(config: &Config) {
+    match meeting::MeetingStorage::open(meeting_storage_config(config)) {
+        Ok(storage) => match storage.recover_interrupted_meetings() {
+            Ok(recovered) if !recovered.is_empty() => {
                 // Reset meeting state file to idle
                 let state_file = Config::runtime_dir().join("meeting_state");
                 let _ = std::fs::write(&state_file, "idle");
+
+                for metadata in recovered {
+                    tracing::warn!(
+                        "Recovered interrupted meeting {} ({}); continue it with: voxtype meeting resume {}",
+                        metadata.id,
+                        metadata.display_title(),
+                        metadata.id
+                    );
+                    send_notification(
+                        "Meeting Recovered",
+                        &format!(
+                            "{} was interrupted. Continue it with: voxtype meeting resume {}",
+                            metadata.display_title(),
+                            metadata.id
+                        ),
+                        false,
+                        config.engine,
+                        &config.output.notification.urgency,
+                    )
+                    .await;
+                }
             }
             Ok(_) => {}
-            Err(e) => tracing::warn!("Failed to clean up stale meetings: {}", e),
+            Err(e) => tracing::warn!("Failed to recover interrupted meetings: {}", e),
         },
-        Err(e) => tracing::warn!("Failed to open meeting storage for cleanup: {}", e),
+        Err(e) => tracing::warn!("Failed to open meeting storage for recovery: {}", e),
     }
 }
 
@@ -518,45 +947,6 @@ fn write_meeting_state_file(path: &PathBuf, state: &str, meeting_id: Option<&str
     }
 }
 
-/// Write transcription to a file, respecting file_mode (overwrite or append)
-async fn write_transcription_to_file(
-    path: &std::path::Path,
-    text: &str,
-    file_mode: &FileMode,
-) -> std::io::Result<()> {
-    use tokio::io::AsyncWriteExt;
-
-    // Create parent directories if needed
-    if let Some(parent) = path.parent() {
-        if !parent.as_os_str().is_empty() {
-            tokio::fs::create_dir_all(parent).await?;
-        }
-    }
-
-    // Ensure text ends with newline
-    let output_text = if text.ends_with('\n') {
-        text.to_string()
-    } else {
-        format!("{}\n", text)
-    };
-
-    match file_mode {
-        FileMode::Overwrite => {
-            tokio::fs::write(path, output_text).await?;
-        }
-        FileMode::Append => {
-            let mut file = tokio::fs::OpenOptions::new()
-                .create(true)
-                .append(true)
-                .open(path)
-                .await?;
-            file.write_all(output_text.as_bytes()).await?;
-        }
-    }
-
-    Ok(())
-}
-
 /// Read and consume the model override file
 /// Returns the model name if the file exists, None otherwise
 fn read_model_override() -> Option<String> {
@@ -593,9 +983,24 @@ fn cleanup_model_override() {
     let _ = std::fs::remove_file(&override_file);
 }
 
+/// What started a recording outside the hotkey
+enum StartTrigger {
+    /// SIGUSR1 from `voxtype record start/toggle` or a compositor binding
+    Signal,
+    /// The wake word, with its detection score
+    WakeWord(f32),
+}
+
 /// Result type for transcription task
 type TranscriptionResult = std::result::Result<String, crate::error::TranscribeError>;
 
+/// Result type for on-demand model loading: the transcriber and the engine
+/// that provided it
+type ModelLoadResult = std::result::Result<
+    (Arc<dyn Transcriber>, crate::config::TranscriptionEngine),
+    crate::error::TranscribeError,
+>;
+
 /// Main daemon that orchestrates all components
 pub struct Daemon {
     config: Config,
@@ -604,9 +1009,26 @@ pub struct Daemon {
     pid_file_path: Option<PathBuf>,
     audio_feedback: Option<AudioFeedback>,
     text_processor: TextProcessor,
+    // Text processors for profiles that override [text] settings or the
+    // language, keyed by profile name
+    profile_text_processors: HashMap<String, TextProcessor>,
     post_processor: Option<PostProcessor>,
+    // LLM post-processors for profiles with post_process_template, keyed by
+    // profile name
+    profile_post_processors: HashMap<String, PostProcessor>,
+    /// `[[profile_rules]]`, compiled
+    profile_matcher: crate::focus::ProfileMatcher,
+    /// Focused window lookup for profile rules (None without rules or on an
+    /// unsupported desktop)
+    focus_backend: Option<Box<dyn crate::focus::FocusBackend>>,
+    /// `[output] undo_ctrl_z_apps`, compiled
+    undo_ctrl_z_apps: Vec<regex::Regex>,
     /// Last post-processed text and when it was produced, for context in subsequent dictations
     last_dictation: Option<(String, Instant)>,
+    /// Text the last dictation left at the cursor, including `append_text`.
+    /// None when it went to the clipboard or a file, or Enter was pressed
+    /// after it, so voice editing commands never backspace over other text.
+    last_typed: Option<String>,
     /// Audio level broadcaster for the OSD (None when disabled or bind failed)
     level_hub: Option<audio::levels::LevelHub>,
     /// Active per-recording level emitter task; aborted when recording stops
@@ -621,11 +1043,7 @@ pub struct Daemon {
     // Model manager for multi-model support
     model_manager: Option<ModelManager>,
     // Background task for loading model on-demand
-    model_load_task: Option<
-        tokio::task::JoinHandle<
-            std::result::Result<Arc<dyn Transcriber>, crate::error::TranscribeError>,
-        >,
-    >,
+    model_load_task: Option<tokio::task::JoinHandle<ModelLoadResult>>,
     // Background task that spawns and prepares the gpu_isolation subprocess
     // worker. Awaited before transcription so audio capture can start
     // immediately while the worker loads its model in parallel.
@@ -637,6 +1055,22 @@ pub struct Daemon {
     // keyboard-layout hints to eitype/dotool, see issue #180) after the task
     // completes. Cleared when transcription_task is taken.
     active_transcriber: Option<Arc<dyn Transcriber>>,
+    // Whether the in-flight transcription may be retried with the secondary
+    // model on low confidence. False for retries themselves and for
+    // recordings that already used the secondary model.
+    secondary_retry_eligible: bool,
+    // Background re-transcription with [whisper] redo_model after the draft
+    // has been output. At most one runs at a time.
+    redo_task: Option<tokio::task::JoinHandle<RedoOutcome>>,
+    // Redo model, loaded on first use by redo_task and kept for reuse
+    redo_transcriber: Option<Arc<dyn Transcriber>>,
+    // Profile and post-processing context the running redo's draft was
+    // output with, so the correction is processed the same way
+    redo_profile: Option<String>,
+    redo_context: Option<String>,
+    // Transcribers for profiles that override the engine, model or language,
+    // keyed by profile name. Empty when on-demand loading is enabled.
+    profile_transcribers: HashMap<String, Arc<dyn Transcriber>>,
     // Background tasks for eager chunk transcriptions (chunk_index, task)
     eager_chunk_tasks: Vec<(
         usize,
@@ -644,6 +1078,17 @@ pub struct Daemon {
     )>,
     // Voice Activity Detection (filters silence-only recordings)
     vad: Option<Box<dyn crate::vad::VoiceActivityDetector>>,
+    // VAD for profiles whose VAD settings differ from the global ones,
+    // keyed by profile name (None when the profile disables VAD)
+    profile_vads: HashMap<String, Option<Box<dyn crate::vad::VoiceActivityDetector>>>,
+    /// Trailing-silence detector for `[vad] auto_stop_silence_ms`, cloned
+    /// fresh for each hands-free recording (None when disabled)
+    endpoint_detector: Option<crate::vad::EndpointDetector>,
+    /// Task feeding the current recording's audio to a clone of
+    /// `endpoint_detector`
+    auto_stop_task: Option<tokio::task::JoinHandle<()>>,
+    /// Set by `auto_stop_task` once the speaker has stopped
+    auto_stop_fired: Arc<AtomicBool>,
     // Meeting mode daemon (optional, created when meeting starts)
     meeting_daemon: Option<MeetingDaemon>,
     // Meeting state file path
@@ -655,11 +1100,26 @@ pub struct Daemon {
     meeting_loopback_buffer: Vec<f32>,
     // Meeting event receiver
     meeting_event_rx: Option<tokio::sync::mpsc::Receiver<MeetingEvent>>,
+    // Live meeting events for `voxtype meeting watch` (None when meeting
+    // mode is disabled or the socket couldn't be bound)
+    meeting_live: Option<meeting::live::LiveHub>,
+    // When the running meeting summary is next updated (None when rolling
+    // summaries are off or no meeting is running)
+    meeting_summary_due: Option<Instant>,
+    // Rolling summary update in flight
+    meeting_summary_task: Option<tokio::task::JoinHandle<()>>,
+    // App of the call `[meeting.auto_start]` detected, while it lasts
+    active_call: Option<String>,
+    // Whether the running meeting started during that call, so it stops
+    // when the call ends
+    meeting_during_call: bool,
     // GTCRN speech enhancer for mic echo cancellation
     #[cfg(feature = "onnx-common")]
     speech_enhancer: Option<std::sync::Arc<audio::enhance::GtcrnEnhancer>>,
     // Media players that were paused when recording started (for resume on stop)
     paused_media_players: Vec<String>,
+    // MQTT state and transcription publisher (None unless [mqtt] is enabled)
+    mqtt: Option<crate::mqtt::MqttPublisher>,
 }
 
 impl Daemon {
@@ -687,27 +1147,82 @@ impl Daemon {
             None
         };
 
-        // Initialize text processor
-        let text_processor = TextProcessor::new(&config.text);
+        // Initialize text processor, plus one per profile that changes
+        // text processing
+        let text_processor = build_text_processor(&config);
+        let profile_text_processors: HashMap<String, TextProcessor> = config
+            .profiles
+            .iter()
+            .filter(|(_, profile)| profile.overrides_text())
+            .map(|(name, profile)| {
+                tracing::debug!("Text processing overrides for profile '{}'", name);
+                (
+                    name.clone(),
+                    build_text_processor(&config.with_profile(profile)),
+                )
+            })
+            .collect();
         if config.text.spoken_punctuation {
             tracing::info!("Spoken punctuation enabled");
         }
+        if config.text.spoken_numbers {
+            tracing::info!(
+                "Spoken numbers enabled (dates as {:?})",
+                config.text.date_format
+            );
+        }
         if !config.text.replacements.is_empty() {
             tracing::info!(
                 "Word replacements configured: {} rules",
                 config.text.replacements.len()
             );
         }
+        if let Some(path) = config.text.replacements_file_path() {
+            tracing::info!("Word replacements file: {:?}", path);
+        }
+        if !config.text.rules.is_empty() {
+            tracing::info!("Regex text rules configured: {}", config.text.rules.len());
+        }
 
         // Initialize post-processor if configured
-        let post_processor = config.output.post_process.as_ref().map(|cfg| {
-            tracing::info!(
-                "Post-processing enabled: command={:?}, timeout={}ms",
-                cfg.command,
-                cfg.timeout_ms
-            );
-            PostProcessor::new(cfg)
-        });
+        let post_processor = PostProcessor::from_output_config(&config.output, "Post-processing");
+        let profile_post_processors = PostProcessor::for_profile_templates(&config);
+
+        // Focus tracking is only needed when profiles or undo follow the
+        // focused window
+        let profile_matcher =
+            crate::focus::ProfileMatcher::new(&config.profile_rules, &config.profiles);
+        let undo_ctrl_z_apps: Vec<regex::Regex> = config
+            .output
+            .undo_ctrl_z_apps
+            .iter()
+            .filter_map(
+                |pattern| match regex::Regex::new(&format!("(?i){}", pattern)) {
+                    Ok(regex) => Some(regex),
+                    Err(e) => {
+                        tracing::warn!("Invalid undo_ctrl_z_apps pattern '{}': {}", pattern, e);
+                        None
+                    }
+                },
+            )
+            .collect();
+        let focus_backend = if profile_matcher.is_empty() && undo_ctrl_z_apps.is_empty() {
+            None
+        } else {
+            let backend = crate::focus::detect_backend();
+            match &backend {
+                Some(backend) => tracing::info!(
+                    "Focused window tracking enabled (backend: {})",
+                    backend.name()
+                ),
+                None => tracing::warn!(
+                    "profile_rules or undo_ctrl_z_apps are configured but the focused \
+                     window can't be detected on this desktop (supported: Hyprland, Sway, \
+                     KDE, GNOME)"
+                ),
+            }
+            backend
+        };
 
         // Initialize Voice Activity Detection if enabled
         let vad = match crate::vad::create_vad(&config) {
@@ -727,6 +1242,20 @@ impl Daemon {
             }
         };
 
+        let endpoint_detector = match crate::vad::create_endpoint_detector(&config) {
+            Ok(Some(detector)) => {
+                tracing::info!(
+                    "Auto-stop after trailing silence enabled for hands-free recordings"
+                );
+                Some(detector)
+            }
+            Ok(None) => None,
+            Err(e) => {
+                tracing::warn!("Failed to initialize auto-stop, continuing without: {}", e);
+                None
+            }
+        };
+
         // Meeting state file path (separate from push-to-talk state)
         let meeting_state_file_path = if state_file_path.is_some() {
             Some(Config::runtime_dir().join("meeting_state"))
@@ -741,8 +1270,14 @@ impl Daemon {
             pid_file_path: None,
             audio_feedback,
             text_processor,
+            profile_text_processors,
             post_processor,
+            profile_post_processors,
+            profile_matcher,
+            focus_backend,
+            undo_ctrl_z_apps,
             last_dictation: None,
+            last_typed: None,
             level_hub: None,
             level_emitter_task: None,
             streaming_drain_pump: None,
@@ -752,17 +1287,33 @@ impl Daemon {
             whisper_prepare_task: None,
             transcription_task: None,
             active_transcriber: None,
+            secondary_retry_eligible: false,
+            redo_task: None,
+            redo_transcriber: None,
+            redo_profile: None,
+            redo_context: None,
+            profile_transcribers: HashMap::new(),
             eager_chunk_tasks: Vec::new(),
             vad,
+            profile_vads: HashMap::new(),
+            endpoint_detector,
+            auto_stop_task: None,
+            auto_stop_fired: Arc::new(AtomicBool::new(false)),
             meeting_daemon: None,
             meeting_state_file_path,
             meeting_audio_capture: None,
             meeting_mic_buffer: Vec::new(),
             meeting_loopback_buffer: Vec::new(),
             meeting_event_rx: None,
+            meeting_live: None,
+            meeting_summary_due: None,
+            meeting_summary_task: None,
+            active_call: None,
+            meeting_during_call: false,
             #[cfg(feature = "onnx-common")]
             speech_enhancer: None,
             paused_media_players: Vec::new(),
+            mqtt: None,
         }
     }
 
@@ -795,6 +1346,9 @@ impl Daemon {
         if let Some(ref path) = self.state_file_path {
             write_state_file(path, state_name);
         }
+        if let Some(ref mqtt) = self.mqtt {
+            mqtt.publish_state(state_name);
+        }
     }
 
     /// Start a push-to-talk audio capture and (if enabled) a level emitter.
@@ -803,20 +1357,55 @@ impl Daemon {
     /// capture is plumbed into the level hub so the OSD sees audio frames
     /// at 100 Hz during recording. The emitter task is tracked so it can
     /// be cleanly aborted when recording stops.
-    async fn start_recording_capture(&mut self) -> std::result::Result<Box<dyn AudioCapture>, ()> {
+    ///
+    /// With `auto_stop` (hands-free recordings) and `[vad]
+    /// auto_stop_silence_ms` set, the audio is also fed to the endpoint
+    /// detector, which flags `auto_stop_fired` once the speaker stops.
+    async fn start_recording_capture(
+        &mut self,
+        auto_stop: bool,
+    ) -> std::result::Result<Box<dyn AudioCapture>, ()> {
         match audio::create_capture(&self.config.audio) {
             Ok(mut capture) => match capture.start().await {
                 Ok(chunk_rx) => {
-                    if let Some(hub) = &self.level_hub {
+                    self.stop_auto_stop();
+                    let detector = self.endpoint_detector.clone().filter(|_| auto_stop);
+                    let detector_rx = if let Some(hub) = &self.level_hub {
                         // Cancel any prior emitter (defensive; should be idle).
                         if let Some(handle) = self.level_emitter_task.take() {
                             handle.abort();
                         }
-                        let handle = audio::levels::spawn_emitter(chunk_rx, hub.frame_sink());
+                        // Tap the emitter for the endpoint detector
+                        let (tap_tx, tap_rx) = match detector {
+                            Some(_) => {
+                                let (tx, rx) = tokio::sync::mpsc::channel(64);
+                                (Some(tx), Some(rx))
+                            }
+                            None => (None, None),
+                        };
+                        let handle = audio::levels::spawn_emitter_with_streaming_tap(
+                            chunk_rx,
+                            hub.frame_sink(),
+                            tap_tx,
+                        );
                         self.level_emitter_task = Some(handle);
+                        tap_rx
+                    } else {
+                        Some(chunk_rx)
+                    };
+                    if let (Some(mut detector), Some(mut rx)) = (detector, detector_rx) {
+                        let fired = self.auto_stop_fired.clone();
+                        self.auto_stop_task = Some(tokio::spawn(async move {
+                            while let Some(chunk) = rx.recv().await {
+                                if detector.push(&chunk) {
+                                    fired.store(true, Ordering::Relaxed);
+                                    break;
+                                }
+                            }
+                        }));
                     }
-                    // If level_hub is None we still return Ok; the chunk_rx
-                    // is dropped here, matching previous behaviour.
+                    // Otherwise the chunk_rx is dropped here, matching
+                    // previous behaviour.
                     Ok(capture)
                 }
                 Err(e) => {
@@ -841,6 +1430,15 @@ impl Daemon {
         if let Some(handle) = self.level_emitter_task.take() {
             handle.abort();
         }
+        self.stop_auto_stop();
+    }
+
+    /// Stop watching for trailing silence and clear a pending auto-stop
+    fn stop_auto_stop(&mut self) {
+        if let Some(handle) = self.auto_stop_task.take() {
+            handle.abort();
+        }
+        self.auto_stop_fired.store(false, Ordering::Relaxed);
     }
 
     /// Attempt to start a streaming transcription session.
@@ -848,8 +1446,10 @@ impl Daemon {
     /// Returns `true` and populates the streaming locals on success. Returns
     /// `false` (and does nothing) when:
     /// - the preloaded transcriber is `None` (e.g., on_demand_loading without
-    ///   a successful background load yet);
-    /// - the preloaded transcriber's `as_streaming()` returns `None`;
+    ///   a successful background load yet) and the engine is not an
+    ///   in-process Whisper eligible for incremental streaming;
+    /// - the transcriber's `as_streaming()` returns `None` and
+    ///   `[transcribe] streaming` does not apply;
     /// - audio capture or `start_stream` fail.
     ///
     /// On `false`, callers should fall through to the existing batch
@@ -865,8 +1465,37 @@ impl Daemon {
         streaming_chain: &mut Option<Vec<Box<dyn TextOutput>>>,
         model_override: Option<String>,
     ) -> bool {
-        let Some(transcriber) = transcriber_preloaded.as_ref() else {
-            return false;
+        let transcriber = match transcriber_preloaded {
+            Some(t) => t.clone(),
+            // Whisper is served by the model manager rather than preloaded.
+            None if self.config.engine == crate::config::TranscriptionEngine::Whisper
+                && (self.config.incremental_streaming_supported()
+                    || self.config.whisper.remote_uses_websocket()) =>
+            {
+                let Some(mm) = self.model_manager.as_mut() else {
+                    return false;
+                };
+                match mm.get_prepared_transcriber(model_override.as_deref()) {
+                    Ok(t) => t,
+                    Err(e) => {
+                        tracing::warn!("Incremental streaming unavailable: {}", e);
+                        return false;
+                    }
+                }
+            }
+            None => return false,
+        };
+        // Batch engines gain streaming through periodic re-decoding when
+        // `[transcribe] streaming` is on.
+        let transcriber: Arc<dyn Transcriber> = if transcriber.as_streaming().is_none()
+            && self.config.incremental_streaming_supported()
+        {
+            Arc::new(IncrementalTranscriber::new(
+                transcriber,
+                &self.config.transcribe,
+            ))
+        } else {
+            transcriber
         };
         if transcriber.as_streaming().is_none() {
             return false;
@@ -893,6 +1522,8 @@ impl Daemon {
         *audio_capture = Some(capture);
         *streaming_handle = Some(handle);
         *streaming_session = Some(StreamingSession::new());
+        // Streamed text is typed piecemeal; editing commands don't track it
+        self.last_typed = None;
         *streaming_chain = Some(output::create_output_chain(&self.config.output));
         *state = State::Streaming {
             started_at: std::time::Instant::now(),
@@ -1039,6 +1670,7 @@ impl Daemon {
         cleanup_output_mode_override();
         cleanup_model_override();
         cleanup_profile_override();
+        cleanup_target_override();
         cleanup_bool_override("auto_submit");
         cleanup_bool_override("shift_enter");
         cleanup_bool_override("smart_auto_submit");
@@ -1122,6 +1754,43 @@ impl Daemon {
         }
     }
 
+    /// Start loading the transcriber for an on-demand recording in the
+    /// background, falling back through `engine_fallback` like the preload
+    fn start_model_load(&mut self, model_override: Option<String>) {
+        let config = self.config.clone();
+        let config_path = self.config_path.clone();
+        self.model_load_task = Some(tokio::task::spawn_blocking(move || {
+            load_engine_with_fallback(&config, config_path, model_override.as_deref())
+        }));
+    }
+
+    /// Record and announce that `active` is standing in for `configured`.
+    /// On-demand loads repeat this every recording, so the notification is
+    /// only sent when the recorded fallback changes.
+    async fn report_engine_fallback(
+        &self,
+        configured: crate::config::TranscriptionEngine,
+        active: crate::config::TranscriptionEngine,
+    ) {
+        let recorded = format!("{} {}", configured.name(), active.name());
+        if std::fs::read_to_string(engine_fallback_file()).is_ok_and(|r| r == recorded) {
+            return;
+        }
+        write_engine_fallback_file(configured, active);
+        send_notification(
+            "Engine Fallback",
+            &format!(
+                "{} failed to start; using {} instead",
+                configured.name(),
+                active.name()
+            ),
+            self.config.output.notification.show_engine_icon,
+            active,
+            &self.config.output.notification.urgency,
+        )
+        .await;
+    }
+
     /// Get the transcriber for the current recording session
     ///
     /// For on-demand loading: waits for the background model load task to complete
@@ -1137,8 +1806,14 @@ impl Daemon {
             // Wait for background model load task
             if let Some(task) = self.model_load_task.take() {
                 match task.await {
-                    Ok(Ok(transcriber)) => {
+                    Ok(Ok((transcriber, engine))) => {
                         tracing::info!("Model loaded successfully");
+                        if engine == self.config.engine {
+                            let _ = std::fs::remove_file(engine_fallback_file());
+                        } else {
+                            self.report_engine_fallback(self.config.engine, engine)
+                                .await;
+                        }
                         Ok(transcriber)
                     }
                     Ok(Err(e)) => {
@@ -1167,7 +1842,9 @@ impl Daemon {
                 | crate::config::TranscriptionEngine::Dolphin
                 | crate::config::TranscriptionEngine::Omnilingual
                 | crate::config::TranscriptionEngine::Cohere
-                | crate::config::TranscriptionEngine::Soniox => {
+                | crate::config::TranscriptionEngine::Soniox
+                | crate::config::TranscriptionEngine::Vosk
+                | crate::config::TranscriptionEngine::Zipformer => {
                     if let Some(ref t) = transcriber_preloaded {
                         Ok(t.clone())
                     } else {
@@ -1212,11 +1889,12 @@ impl Daemon {
         }
     }
 
-    /// Start a new meeting
+    /// Start a new meeting, or reopen the stored meeting `resume`
     async fn start_meeting(
         &mut self,
         title: Option<String>,
         diarization_override: Option<String>,
+        resume: Option<String>,
     ) -> Result<()> {
         if self.meeting_daemon.is_some() {
             tracing::warn!("Meeting already in progress");
@@ -1246,6 +1924,9 @@ impl Daemon {
                 vad_window_secs: self.config.meeting.diarization.vad_window_secs,
                 vad_hop_secs: self.config.meeting.diarization.vad_hop_secs,
                 vad_rms_floor: self.config.meeting.diarization.vad_rms_floor,
+                enrollment_threshold: self.config.meeting.diarization.enrollment_threshold,
+                // Loaded from meeting storage when the meeting starts
+                enrolled_speakers: Vec::new(),
             })
         } else {
             None
@@ -1269,6 +1950,29 @@ impl Daemon {
             diarization: diarization_config,
         };
 
+        // Without --title, name the meeting after the calendar event
+        // happening now. An explicit title or a reopened meeting skips the
+        // lookup.
+        let (title, attendees) = match title {
+            Some(title) => (Some(title), Vec::new()),
+            None if resume.is_some() => (None, Vec::new()),
+            None => match meeting::calendar::current_event(&self.config.meeting.calendar).await {
+                Ok(Some(event)) => {
+                    tracing::info!(
+                        "Calendar event: {} ({} attendees)",
+                        event.title,
+                        event.attendees.len()
+                    );
+                    (Some(event.title), event.attendees)
+                }
+                Ok(None) => (None, Vec::new()),
+                Err(e) => {
+                    tracing::warn!("Calendar lookup failed: {}", e);
+                    (None, Vec::new())
+                }
+            },
+        };
+
         // Create event channel
         let (tx, rx) = tokio::sync::mpsc::channel(32);
         self.meeting_event_rx = Some(rx);
@@ -1276,18 +1980,32 @@ impl Daemon {
         // Create meeting daemon
         match MeetingDaemon::new(meeting_config, &self.config, tx) {
             Ok(mut daemon) => {
-                match daemon.start(title).await {
+                let started = match resume {
+                    Some(ref id) => match daemon.storage().resolve_meeting_id(id) {
+                        Ok(id) => daemon.reopen(&id).await,
+                        Err(e) => Err(crate::error::MeetingError::Storage(e.to_string()).into()),
+                    },
+                    None => daemon.start(title.clone(), attendees).await,
+                };
+                match started {
                     Ok(meeting_id) => {
+                        let title = title.or_else(|| {
+                            daemon
+                                .current_meeting_mut()
+                                .and_then(|m| m.metadata.title.clone())
+                        });
                         let id_str = meeting_id.to_string();
+                        self.meeting_during_call = self.active_call.is_some();
                         self.update_meeting_state("recording", Some(&id_str));
                         tracing::info!("Meeting started: {}", meeting_id);
+                        self.publish_meeting_event(meeting::live::LiveEvent::Started {
+                            meeting_id: id_str,
+                            title,
+                            started_at: chrono::Utc::now(),
+                        });
 
                         // Start dual audio capture for meeting (mic + loopback)
-                        let loopback_device =
-                            match self.config.meeting.audio.loopback_device.as_str() {
-                                "disabled" | "" => None,
-                                other => Some(other),
-                            };
+                        let loopback_devices = self.config.meeting.audio.loopback_device.devices();
                         let mut meeting_audio_config = self.config.audio.clone();
                         let meeting_mic_device = self.config.meeting.audio.mic_device.as_str();
                         if !matches!(meeting_mic_device, "default" | "") {
@@ -1299,7 +2017,7 @@ impl Daemon {
                             meeting_audio_config.device =
                                 self.config.meeting.audio.mic_device.clone();
                         }
-                        match audio::DualCapture::new(&meeting_audio_config, loopback_device) {
+                        match audio::DualCapture::new(&meeting_audio_config, &loopback_devices) {
                             Ok(mut capture) => {
                                 if let Err(e) = capture.start().await {
                                     tracing::error!("Failed to start meeting audio: {}", e);
@@ -1351,13 +2069,25 @@ impl Daemon {
                         self.meeting_mic_buffer.clear();
                         self.meeting_loopback_buffer.clear();
 
+                        let summary = &self.config.meeting.summary;
+                        if summary.rolling_interval_mins > 0 && summary.backend != "disabled" {
+                            self.meeting_summary_due = Some(
+                                Instant::now()
+                                    + Duration::from_secs(summary.rolling_interval_mins * 60),
+                            );
+                        }
+
                         // Play feedback
                         self.play_feedback(SoundEvent::RecordingStart);
 
                         // Notification
                         if self.config.output.notification.on_recording_start {
                             send_notification(
-                                "Meeting Started",
+                                if resume.is_some() {
+                                    "Meeting Resumed"
+                                } else {
+                                    "Meeting Started"
+                                },
                                 &format!("ID: {}", meeting_id),
                                 false,
                                 self.config.engine,
@@ -1405,9 +2135,34 @@ impl Daemon {
                 Ok(meeting_id) => {
                     self.update_meeting_state("idle", None);
                     tracing::info!("Meeting stopped: {}", meeting_id);
+                    self.publish_meeting_event(meeting::live::LiveEvent::Stopped {
+                        at: chrono::Utc::now(),
+                    });
 
                     self.play_feedback(SoundEvent::RecordingStop);
 
+                    // Chapters may ask a language model for titles, and hooks
+                    // and sync may upload large exports; don't hold up the daemon
+                    let meeting_config = self.config.meeting.clone();
+                    if meeting_config.chapters.is_enabled()
+                        || meeting_config.hooks.is_configured()
+                        || (meeting_config.sync.is_enabled() && meeting_config.sync.auto)
+                    {
+                        match daemon.storage().load_meeting_data(&meeting_id) {
+                            Ok(data) => {
+                                let storage_config = daemon.storage().config().clone();
+                                tokio::spawn(finish_stopped_meeting(
+                                    meeting_config,
+                                    storage_config,
+                                    data,
+                                ));
+                            }
+                            Err(e) => {
+                                tracing::warn!("Finishing meeting {} failed: {}", meeting_id, e)
+                            }
+                        }
+                    }
+
                     if self.config.output.notification.on_recording_stop {
                         send_notification(
                             "Meeting Ended",
@@ -1427,6 +2182,8 @@ impl Daemon {
             self.meeting_mic_buffer.clear();
             self.meeting_loopback_buffer.clear();
             self.meeting_event_rx = None;
+            self.meeting_summary_due = None;
+            self.meeting_summary_task = None;
         }
 
         Ok(())
@@ -1439,6 +2196,9 @@ impl Daemon {
             let meeting_id = daemon.current_meeting_id().map(|id| id.to_string());
             self.update_meeting_state("paused", meeting_id.as_deref());
             tracing::info!("Meeting paused");
+            self.publish_meeting_event(meeting::live::LiveEvent::Paused {
+                at: chrono::Utc::now(),
+            });
 
             if self.config.output.notification.on_recording_stop {
                 send_notification(
@@ -1461,6 +2221,9 @@ impl Daemon {
             let meeting_id = daemon.current_meeting_id().map(|id| id.to_string());
             self.update_meeting_state("recording", meeting_id.as_deref());
             tracing::info!("Meeting resumed");
+            self.publish_meeting_event(meeting::live::LiveEvent::Resumed {
+                at: chrono::Utc::now(),
+            });
 
             if self.config.output.notification.on_recording_start {
                 send_notification(
@@ -1476,20 +2239,103 @@ impl Daemon {
         Ok(())
     }
 
-    /// Check if a meeting is in progress
-    fn meeting_active(&self) -> bool {
-        self.meeting_daemon
-            .as_ref()
-            .is_some_and(|d| d.state().is_active())
+    /// Bookmark the moment being recorded in the current meeting
+    async fn mark_meeting(&mut self, note: Option<String>) -> Result<()> {
+        // Audio captured but not yet cut into a chunk is part of the meeting
+        let buffered = self
+            .meeting_mic_buffer
+            .len()
+            .max(self.meeting_loopback_buffer.len());
+        let buffered_ms = buffered as u64 * 1000 / 16000;
+
+        if let Some(ref mut daemon) = self.meeting_daemon {
+            let bookmark = daemon.mark(note, buffered_ms)?;
+            tracing::info!(
+                "Meeting bookmarked at {}: {}",
+                bookmark.format_timestamp(),
+                bookmark.label()
+            );
+            let message = format!("[{}] {}", bookmark.format_timestamp(), bookmark.label());
+            self.publish_meeting_event(meeting::live::LiveEvent::Marked { bookmark });
+
+            if self.config.output.notification.on_recording_stop {
+                send_notification(
+                    "Meeting Bookmarked",
+                    &message,
+                    false,
+                    self.config.engine,
+                    &self.config.output.notification.urgency,
+                )
+                .await;
+            }
+        }
+        Ok(())
     }
 
-    /// Get the chunk duration for meeting mode
-    fn meeting_chunk_samples(&self) -> usize {
-        // 16kHz sample rate * chunk duration in seconds
-        16000 * self.config.meeting.chunk_duration_secs as usize
+    /// Send a live meeting event to `voxtype meeting watch` clients
+    fn publish_meeting_event(&self, event: meeting::live::LiveEvent) {
+        if let Some(ref hub) = self.meeting_live {
+            hub.publish(&event);
+        }
     }
 
-    async fn process_meeting_audio_pair(&mut self, mic_chunk: Vec<f32>, loopback_chunk: Vec<f32>) {
+    /// Start or stop meetings with calls, under `[meeting.auto_start]`
+    async fn handle_call_change(&mut self, change: meeting::calls::CallChange) {
+        let auto = self.config.meeting.auto_start.is_auto();
+        let stop_on_end = self.config.meeting.auto_start.stop_on_end;
+        match change {
+            meeting::calls::CallChange::Started { app } => {
+                tracing::info!("Call detected in {}", app);
+                self.active_call = Some(app.clone());
+                if self.meeting_daemon.is_some() {
+                    return;
+                }
+                if auto {
+                    if let Err(e) = self.start_meeting(None, None, None).await {
+                        tracing::error!("Failed to start meeting for call: {}", e);
+                    }
+                } else {
+                    // Accepting writes the same trigger as `voxtype meeting start`
+                    tokio::spawn(async move {
+                        let body = format!("{} is using the microphone", app);
+                        if notification::send_with_action("Call Detected", &body, "Start meeting")
+                            .await
+                        {
+                            let start_file = Config::runtime_dir().join("meeting_start");
+                            if let Err(e) = std::fs::write(&start_file, "") {
+                                tracing::warn!("Failed to request meeting start: {}", e);
+                            }
+                        }
+                    });
+                }
+            }
+            meeting::calls::CallChange::Ended => {
+                tracing::info!("Call ended");
+                self.active_call = None;
+                if stop_on_end && self.meeting_during_call && self.meeting_daemon.is_some() {
+                    tracing::info!("Stopping the meeting with the call");
+                    if let Err(e) = self.stop_meeting().await {
+                        tracing::error!("Failed to stop meeting after the call: {}", e);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Check if a meeting is in progress
+    fn meeting_active(&self) -> bool {
+        self.meeting_daemon
+            .as_ref()
+            .is_some_and(|d| d.state().is_active())
+    }
+
+    /// Get the chunk duration for meeting mode
+    fn meeting_chunk_samples(&self) -> usize {
+        // 16kHz sample rate * chunk duration in seconds
+        16000 * self.config.meeting.chunk_duration_secs as usize
+    }
+
+    async fn process_meeting_audio_pair(&mut self, mic_chunk: Vec<f32>, loopback_chunk: Vec<f32>) {
         #[cfg_attr(not(feature = "onnx-common"), allow(unused_mut))]
         let mut mic_chunk = mic_chunk;
 
@@ -1514,27 +2360,47 @@ impl Daemon {
             }
         }
 
+        let live = self.meeting_live.clone();
+        let publish = |event: meeting::live::LiveEvent| {
+            if let Some(ref hub) = live {
+                hub.publish(&event);
+            }
+        };
+        let chunk_started = |source, samples: &[f32]| meeting::live::LiveEvent::ChunkStarted {
+            source,
+            audio_ms: samples.len() as u64 * 1000 / 16000,
+        };
+
         if let Some(ref mut daemon) = self.meeting_daemon {
             let mut had_loopback = false;
 
             if !mic_chunk.is_empty() {
-                match daemon
-                    .process_chunk_with_source(mic_chunk, meeting::data::AudioSource::Microphone)
-                    .await
-                {
+                let source = meeting::data::AudioSource::Microphone;
+                if daemon.state().is_active() {
+                    publish(chunk_started(source, &mic_chunk));
+                }
+                match daemon.process_chunk_with_source(mic_chunk, source).await {
                     Ok(Some(segments)) => {
                         tracing::debug!("Processed mic chunk with {} segments", segments.len());
+                        publish(meeting::live::LiveEvent::ChunkProcessed { source, segments });
                     }
                     Ok(None) => {}
                     Err(e) => {
                         tracing::error!("Error processing mic chunk: {}", e);
+                        publish(meeting::live::LiveEvent::Error {
+                            message: format!("Mic chunk failed: {}", e),
+                        });
                     }
                 }
             }
 
             if !loopback_chunk.is_empty() {
+                let source = meeting::data::AudioSource::Loopback;
+                if daemon.state().is_active() {
+                    publish(chunk_started(source, &loopback_chunk));
+                }
                 match daemon
-                    .process_chunk_with_source(loopback_chunk, meeting::data::AudioSource::Loopback)
+                    .process_chunk_with_source(loopback_chunk, source)
                     .await
                 {
                     Ok(Some(segments)) => {
@@ -1545,10 +2411,14 @@ impl Daemon {
                         if !segments.is_empty() {
                             had_loopback = true;
                         }
+                        publish(meeting::live::LiveEvent::ChunkProcessed { source, segments });
                     }
                     Ok(None) => {}
                     Err(e) => {
                         tracing::error!("Error processing loopback chunk: {}", e);
+                        publish(meeting::live::LiveEvent::Error {
+                            message: format!("Remote audio chunk failed: {}", e),
+                        });
                     }
                 }
             }
@@ -1568,7 +2438,75 @@ impl Daemon {
                     }
                 }
             }
+
+            // Persist progress so a crash doesn't lose the meeting
+            daemon.checkpoint();
         }
+
+        self.update_rolling_summary();
+    }
+
+    /// Update the running summary of the meeting when it's due. The LLM
+    /// requests run in the background, one update at a time; the result is
+    /// stored next to the transcript and sent to `meeting watch` clients.
+    fn update_rolling_summary(&mut self) {
+        let Some(due) = self.meeting_summary_due else {
+            return;
+        };
+        let in_flight = self
+            .meeting_summary_task
+            .as_ref()
+            .is_some_and(|task| !task.is_finished());
+        if Instant::now() < due || in_flight {
+            return;
+        }
+        let Some(meeting) = self
+            .meeting_daemon
+            .as_mut()
+            .and_then(|d| d.current_meeting_mut())
+            .map(|m| m.clone())
+        else {
+            return;
+        };
+
+        let config = &self.config.meeting.summary;
+        self.meeting_summary_due =
+            Some(Instant::now() + Duration::from_secs(config.rolling_interval_mins * 60));
+        let summary_config = meeting::summary::SummaryConfig::from_config(config);
+        let live = self.meeting_live.clone();
+
+        self.meeting_summary_task = Some(tokio::task::spawn_blocking(move || {
+            use meeting::summary::rolling::RollingSummary;
+
+            let Some(dir) = meeting.metadata.storage_path.clone() else {
+                return;
+            };
+            let Some(summarizer) = meeting::summary::create_summarizer(&summary_config) else {
+                return;
+            };
+            let mut rolling = RollingSummary::load(&dir).unwrap_or_default();
+            let updated = match rolling.update(summarizer.as_ref(), &meeting) {
+                Ok(updated) => updated,
+                Err(e) => {
+                    // Sections summarized before the failure are kept
+                    tracing::warn!("Rolling meeting summary failed: {}", e);
+                    true
+                }
+            };
+            if !updated {
+                return;
+            }
+            if let Err(e) = rolling.save(&dir) {
+                tracing::warn!("Failed to save rolling meeting summary: {}", e);
+            }
+            if let (Some(hub), Some(summary)) = (live, rolling.summary.clone()) {
+                tracing::debug!("Rolling meeting summary updated");
+                hub.publish(&meeting::live::LiveEvent::Summary {
+                    summary,
+                    covered_ms: rolling.covered_ms(),
+                });
+            }
+        }));
     }
 
     async fn process_buffered_meeting_audio(&mut self, include_tail: bool) {
@@ -1598,12 +2536,113 @@ impl Daemon {
         }
     }
 
+    /// Send `text` to the `[output] targets` other than `primary`, which
+    /// already has it. Each target is tried once, without a fallback.
+    async fn output_to_other_targets(
+        &self,
+        primary: &OutputMode,
+        text: &str,
+        profile: Option<&str>,
+    ) {
+        let config = &self.config.output;
+        for target in config.targets.iter().filter(|t| *t != primary) {
+            let result = match target {
+                OutputMode::File => match config.file_path {
+                    Some(ref path) => output::file::write_transcription(
+                        path,
+                        text,
+                        &config.file_mode,
+                        config.file_entry_template.as_deref(),
+                        config.file_header_template.as_deref(),
+                    )
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| e.to_string()),
+                    None => Err("file_path is not set".to_string()),
+                },
+                OutputMode::Webhook => output::webhook::send(&config.webhook, text, profile)
+                    .await
+                    .map_err(|e| e.to_string()),
+                mode => {
+                    let target_config = crate::config::OutputConfig {
+                        mode: mode.clone(),
+                        fallback_to_clipboard: false,
+                        ..config.clone()
+                    };
+                    let chain = output::create_output_chain(&target_config);
+                    let options = output::OutputOptions {
+                        pre_output_command: None,
+                        post_output_command: None,
+                        wait_for_modifier_release: false,
+                        modifier_release_timeout: Duration::ZERO,
+                    };
+                    output::output_with_fallback(&chain, text, options)
+                        .await
+                        .map_err(|e| e.to_string())
+                }
+            };
+            match result {
+                Ok(()) => tracing::debug!("Also sent transcription to {:?}", target),
+                Err(e) => tracing::warn!("Output target {:?} failed: {}", target, e),
+            }
+        }
+    }
+
+    /// The focused window, or None without a focus backend or when the
+    /// desktop doesn't answer in time
+    async fn focused_window(&self) -> Option<crate::focus::FocusedWindow> {
+        let backend = self.focus_backend.as_ref()?;
+        let window = match tokio::time::timeout(
+            std::time::Duration::from_millis(FOCUS_LOOKUP_TIMEOUT_MS),
+            backend.focused_window(),
+        )
+        .await
+        {
+            Ok(Ok(window)) => window,
+            Ok(Err(e)) => {
+                tracing::debug!("Focused window lookup failed ({}): {}", backend.name(), e);
+                return None;
+            }
+            Err(_) => {
+                tracing::debug!("Focused window lookup timed out ({})", backend.name());
+                return None;
+            }
+        };
+        tracing::debug!(
+            "Focused window: class = {:?}, title = {:?}",
+            window.class,
+            window.title
+        );
+        Some(window)
+    }
+
+    /// Pick a profile from `[[profile_rules]]` for the focused window when a
+    /// recording starts. An explicit profile (`--profile` or a profile
+    /// modifier) takes precedence.
+    async fn select_window_profile(&self) {
+        if self.profile_matcher.is_empty() || peek_profile_override().is_some() {
+            return;
+        }
+        let Some(window) = self.focused_window().await else {
+            return;
+        };
+        if let Some(profile_name) = self.profile_matcher.profile_for(&window) {
+            tracing::info!(
+                "Profile '{}' selected for focused window '{}'",
+                profile_name,
+                window.class
+            );
+            write_profile_override(profile_name);
+        }
+    }
+
     /// Reset state to idle and run post_output_command to reset compositor submap
     /// Call this when exiting from recording/transcribing without normal output flow
     async fn reset_to_idle(&mut self, state: &mut State) {
         cleanup_output_mode_override();
         cleanup_model_override();
         cleanup_profile_override();
+        cleanup_target_override();
         cleanup_bool_override("auto_submit");
         cleanup_bool_override("shift_enter");
         cleanup_bool_override("smart_auto_submit");
@@ -1830,6 +2869,27 @@ impl Daemon {
         let duration = state.recording_duration().unwrap_or_default();
         tracing::info!("Recording stopped ({:.1}s)", duration.as_secs_f32());
 
+        // An explicit model override (e.g. --model) takes precedence over
+        // the active profile's engine and model
+        let explicit_model = matches!(
+            state,
+            State::Recording {
+                model_override: Some(_),
+                ..
+            }
+        );
+        let profile = peek_profile_override()
+            .and_then(|name| Some((name.clone(), self.config.get_profile(&name)?.clone())));
+
+        self.secondary_retry_eligible = match state {
+            State::Recording { model_override, .. } => {
+                self.config.engine == crate::config::TranscriptionEngine::Whisper
+                    && (model_override.is_none()
+                        || *model_override != self.config.whisper.secondary_model)
+            }
+            _ => false,
+        };
+
         // Play audio feedback
         self.play_feedback(SoundEvent::RecordingStop);
 
@@ -1861,8 +2921,19 @@ impl Daemon {
                         return false;
                     }
 
-                    // Voice Activity Detection: skip if no speech detected
-                    if let Some(ref vad) = self.vad {
+                    // Voice Activity Detection: skip if no speech detected.
+                    // Profiles with different VAD settings get their own.
+                    let profile_vad = match &profile {
+                        Some((name, p)) if self.load_profile_vad(name, p) => {
+                            self.profile_vads.get(name)
+                        }
+                        _ => None,
+                    };
+                    let vad = match profile_vad {
+                        Some(vad) => vad.as_deref(),
+                        None => self.vad.as_deref(),
+                    };
+                    if let Some(vad) = vad {
                         match vad.detect(&samples) {
                             Ok(result) if !result.has_speech => {
                                 tracing::debug!(
@@ -1894,6 +2965,25 @@ impl Daemon {
                     };
                     self.update_state("transcribing");
 
+                    let transcriber = match &profile {
+                        Some((name, p)) if p.overrides_transcriber() && !explicit_model => {
+                            // Secondary retry belongs to the default model
+                            self.secondary_retry_eligible = false;
+                            match self.profile_transcriber(name, p).await {
+                                Ok(t) => Some(t),
+                                Err(e) => {
+                                    tracing::warn!(
+                                        "Failed to load transcriber for profile '{}', using the default: {}",
+                                        name,
+                                        e
+                                    );
+                                    transcriber
+                                }
+                            }
+                        }
+                        _ => transcriber,
+                    };
+
                     // Spawn transcription task (non-blocking)
                     if let Some(t) = transcriber {
                         // Hold an Arc clone so the result handler can query
@@ -1901,6 +2991,7 @@ impl Daemon {
                         // for layout hints, issue #180) without re-fetching
                         // the transcriber.
                         self.active_transcriber = Some(t.clone());
+                        self.apply_profile_grammar(t.as_ref());
                         self.transcription_task =
                             Some(tokio::task::spawn_blocking(move || t.transcribe(&samples)));
                         true
@@ -1923,6 +3014,100 @@ impl Daemon {
         }
     }
 
+    /// Text processor for the active profile, or the default one
+    fn text_processor_for(&self, profile: Option<&str>) -> &TextProcessor {
+        profile
+            .and_then(|name| self.profile_text_processors.get(name))
+            .unwrap_or(&self.text_processor)
+    }
+
+    /// Get the transcriber for a profile that overrides the engine, model or
+    /// language, loading it on first use. Kept loaded for later recordings
+    /// unless on-demand loading is enabled.
+    async fn profile_transcriber(
+        &mut self,
+        name: &str,
+        profile: &crate::config::Profile,
+    ) -> std::result::Result<Arc<dyn Transcriber>, crate::error::TranscribeError> {
+        if let Some(t) = self.profile_transcribers.get(name) {
+            return Ok(t.clone());
+        }
+
+        let config = self.config.with_profile(profile);
+        let config_path = self.config_path.clone();
+        tracing::info!(
+            "Loading {} model '{}' for profile '{}'",
+            config.engine.name(),
+            config.model_name(),
+            name
+        );
+        let (transcriber, _) = tokio::task::spawn_blocking(move || {
+            load_engine_with_fallback(&config, config_path, None)
+        })
+        .await
+        .map_err(|e| {
+            crate::error::TranscribeError::InitFailed(format!(
+                "Profile model load task failed: {}",
+                e
+            ))
+        })??;
+
+        if !self.config.on_demand_loading() {
+            self.profile_transcribers
+                .insert(name.to_string(), transcriber.clone());
+        }
+        Ok(transcriber)
+    }
+
+    /// Build the VAD for a profile whose VAD settings differ from the global
+    /// ones, on first use. Returns whether the profile has its own VAD in
+    /// `profile_vads`; otherwise the default one applies.
+    fn load_profile_vad(&mut self, name: &str, profile: &crate::config::Profile) -> bool {
+        if self.profile_vads.contains_key(name) {
+            return true;
+        }
+        if !profile.overrides_vad() {
+            return false;
+        }
+        let config = self.config.with_profile(profile);
+        if crate::vad::same_vad(&self.config, &config) {
+            return false;
+        }
+        match crate::vad::create_vad(&config) {
+            Ok(vad) => {
+                self.profile_vads.insert(name.to_string(), vad);
+                true
+            }
+            Err(e) => {
+                tracing::warn!("Failed to create VAD for profile '{}': {}", name, e);
+                false
+            }
+        }
+    }
+
+    /// Hand the active profile's `grammar_file` (if any) to the transcriber,
+    /// clearing any grammar left over from a previous recording
+    fn apply_profile_grammar(&self, transcriber: &dyn Transcriber) {
+        let grammar_file = peek_profile_override()
+            .and_then(|name| self.config.get_profile(&name))
+            .and_then(|profile| profile.grammar_file.clone());
+
+        let grammar = grammar_file.and_then(|path| match std::fs::read_to_string(&path) {
+            Ok(grammar) => Some(grammar),
+            Err(e) => {
+                tracing::warn!("Failed to read grammar file {:?}: {}", path, e);
+                None
+            }
+        });
+
+        let requested = grammar.is_some();
+        if !transcriber.set_grammar(grammar) && requested {
+            tracing::warn!(
+                "Profile grammar_file ignored: grammars require [whisper] backend = \"cli\""
+            );
+        }
+    }
+
     /// Handle transcription completion (called when transcription_task completes)
     async fn handle_transcription_result(
         &mut self,
@@ -1934,6 +3119,7 @@ impl Daemon {
         // task error). The Ok(Ok(_)) branch consults it for the language
         // layout hint before letting it drop.
         let active_transcriber = self.active_transcriber.take();
+        let secondary_retry_eligible = std::mem::take(&mut self.secondary_retry_eligible);
         match result {
             Ok(Ok(text)) => {
                 if text.is_empty() {
@@ -1942,17 +3128,74 @@ impl Daemon {
                 } else {
                     tracing::info!("Transcribed: {:?}", text);
 
+                    let confidence = active_transcriber
+                        .as_ref()
+                        .and_then(|t| t.last_confidence());
+                    if secondary_retry_eligible && self.retry_with_secondary(state, confidence) {
+                        return;
+                    }
+
+                    // Keep the audio for a background redo pass when a
+                    // redo model is configured.
+                    let redo_audio = match state {
+                        State::Transcribing { audio }
+                            if self.config.engine
+                                == crate::config::TranscriptionEngine::Whisper
+                                && self.config.whisper.redo_model.is_some() =>
+                        {
+                            Some(std::mem::take(audio))
+                        }
+                        _ => None,
+                    };
+
+                    // Low-confidence guard: warn, divert to the clipboard, or
+                    // drop the text per [transcribe] low_confidence_action.
+                    let low_confidence_action =
+                        if self.config.transcribe.is_low_confidence(confidence) {
+                            let action = self.config.transcribe.low_confidence_action;
+                            self.notify_low_confidence(confidence.unwrap_or_default(), action)
+                                .await;
+                            Some(action)
+                        } else {
+                            None
+                        };
+                    if low_confidence_action == Some(LowConfidenceAction::Discard) {
+                        self.reset_to_idle(state).await;
+                        return;
+                    }
+                    let clipboard_only =
+                        low_confidence_action == Some(LowConfidenceAction::ClipboardOnly);
+
+                    // Check for profile override from CLI flags
+                    let profile_override = read_profile_override();
+
                     // Apply text processing (replacements, punctuation)
-                    let processed_text = self.text_processor.process(&text);
+                    let processed_text = self
+                        .text_processor_for(profile_override.as_deref())
+                        .process(&text);
                     if processed_text != text {
                         tracing::debug!("After text processing: {:?}", processed_text);
                     }
 
+                    // Voice editing commands: "scratch that", "undo", "delete last word"
+                    let (edited_text, edit_command) = self
+                        .text_processor_for(profile_override.as_deref())
+                        .detect_edit_command(&processed_text);
+                    if let Some(command) = edit_command {
+                        self.apply_edit_command(command).await;
+                    }
+                    if edited_text.is_empty() && edited_text != processed_text {
+                        tracing::debug!("Editing command left nothing to output");
+                        self.reset_to_idle(state).await;
+                        return;
+                    }
+                    let processed_text = edited_text;
+
                     // Smart auto-submit: detect "submit" trigger word at end
                     // CLI override (--smart-auto-submit / --no-smart-auto-submit) takes priority
                     let smart_auto_submit_cli = read_bool_override("smart_auto_submit");
                     let (processed_text, smart_submit) = self
-                        .text_processor
+                        .text_processor_for(profile_override.as_deref())
                         .detect_submit(&processed_text, smart_auto_submit_cli);
                     if smart_submit {
                         tracing::debug!(
@@ -1961,8 +3204,6 @@ impl Daemon {
                         );
                     }
 
-                    // Check for profile override from CLI flags
-                    let profile_override = read_profile_override();
                     let active_profile = profile_override
                         .as_ref()
                         .and_then(|name| self.config.get_profile(name));
@@ -1976,6 +3217,39 @@ impl Daemon {
                         }
                     }
 
+                    // Markdown profile, unless text processing already did it
+                    let processed_text = if active_profile.is_some_and(|p| p.markdown_commands)
+                        && !self.config.text.markdown_commands
+                    {
+                        crate::text::markdown::apply_commands(&processed_text)
+                    } else {
+                        processed_text
+                    };
+
+                    // Spelling profile: the whole dictation is spelled out
+                    let processed_text = if active_profile.is_some_and(|p| p.spelling) {
+                        let spelled = crate::text::spelling::spell_all(&processed_text);
+                        tracing::debug!("Spelled: {:?}", spelled);
+                        spelled
+                    } else {
+                        processed_text
+                    };
+
+                    // Profile identifier style: the whole dictation becomes one identifier
+                    let processed_text = match active_profile.and_then(|p| p.identifier_style) {
+                        Some(style) => {
+                            let identifier =
+                                crate::text::code::format_identifier(&processed_text, style);
+                            tracing::debug!(
+                                "Formatted as {:?} identifier: {:?}",
+                                style,
+                                identifier
+                            );
+                            identifier
+                        }
+                        None => processed_text,
+                    };
+
                     // Get context from last dictation if within 60 seconds
                     let recent_context = self.last_dictation.as_ref().and_then(|(text, when)| {
                         if when.elapsed() < Duration::from_secs(60) {
@@ -1985,79 +3259,38 @@ impl Daemon {
                         }
                     });
                     // Apply post-processing command (profile overrides default)
-                    let final_text = if let Some(profile) = active_profile {
-                        if let Some(ref cmd) = profile.post_process_command {
-                            let timeout_ms = profile.post_process_timeout_ms.unwrap_or(30000);
-                            let profile_config = crate::config::PostProcessConfig {
-                                command: cmd.clone(),
-                                timeout_ms,
-                                trim: true,
-                                fallback_on_empty: true,
-                            };
-                            let profile_processor = PostProcessor::new(&profile_config);
-                            tracing::info!(
-                                "Post-processing with profile: {:?}, has_context: {}",
-                                profile_override.as_ref().unwrap(),
-                                recent_context.is_some()
-                            );
-                            tracing::debug!("Post-processing context: {:?}", recent_context);
-                            let result = profile_processor
-                                .process_with_context(&processed_text, recent_context.as_deref())
-                                .await;
-                            tracing::info!("Post-processed: changed: {}", result != processed_text);
-                            tracing::debug!("Post-processed result: {:?}", result);
-                            result
-                        } else {
-                            // Profile exists but has no post_process_command, use default
-                            if let Some(ref post_processor) = self.post_processor {
-                                tracing::info!(
-                                    "Post-processing, has_context: {}",
-                                    recent_context.is_some()
-                                );
-                                tracing::debug!(
-                                    "Post-processing input: {:?}, context: {:?}",
-                                    processed_text,
-                                    recent_context
-                                );
-                                let result = post_processor
-                                    .process_with_context(
-                                        &processed_text,
-                                        recent_context.as_deref(),
-                                    )
-                                    .await;
-                                tracing::info!(
-                                    "Post-processed: changed: {}",
-                                    result != processed_text
-                                );
-                                tracing::debug!("Post-processed result: {:?}", result);
-                                result
-                            } else {
-                                processed_text
+                    let final_text = self
+                        .post_process(
+                            processed_text,
+                            profile_override.as_deref(),
+                            recent_context.as_deref(),
+                        )
+                        .await;
+
+                    // Preview-and-confirm: only accepted text reaches the output chain
+                    let final_text = if self.config.output.confirm.enabled {
+                        match output::confirm::confirm(&self.config.output.confirm, &final_text)
+                            .await
+                        {
+                            output::confirm::Decision::Accept(text) => text,
+                            output::confirm::Decision::Discard => {
+                                tracing::info!("Transcription discarded at confirmation");
+                                self.reset_to_idle(state).await;
+                                return;
                             }
                         }
-                    } else if let Some(ref post_processor) = self.post_processor {
-                        tracing::info!(
-                            "Post-processing, has_context: {}",
-                            recent_context.is_some()
-                        );
-                        tracing::debug!(
-                            "Post-processing input: {:?}, context: {:?}",
-                            processed_text,
-                            recent_context
-                        );
-                        let result = post_processor
-                            .process_with_context(&processed_text, recent_context.as_deref())
-                            .await;
-                        tracing::info!("Post-processed: changed: {}", result != processed_text);
-                        tracing::debug!("Post-processed result: {:?}", result);
-                        result
                     } else {
-                        processed_text
+                        final_text
                     };
 
                     // Track last dictation for context in subsequent post-processing
                     self.last_dictation = Some((final_text.clone(), Instant::now()));
 
+                    let profile_name = active_profile.and(profile_override.as_deref());
+                    if let Some(ref mqtt) = self.mqtt {
+                        mqtt.publish_transcription(&final_text, profile_name);
+                    }
+
                     if smart_submit {
                         tracing::debug!(
                             "Smart auto-submit: final text after post-processing: {:?}",
@@ -2067,6 +3300,8 @@ impl Daemon {
 
                     // Check for output mode override from CLI flags
                     let output_override = read_output_mode_override();
+                    let target_override = read_target_override();
+                    let refocus = read_bool_override("refocus").unwrap_or(false);
 
                     // Check if profile specifies output mode override
                     let profile_output_mode = active_profile.and_then(|p| p.output_mode.clone());
@@ -2092,6 +3327,7 @@ impl Daemon {
                         }
                         _ => None,
                     };
+                    let file_output_path = file_output_path.filter(|_| !clipboard_only);
 
                     if let Some(output_path) = file_output_path {
                         *state = State::Outputting {
@@ -2099,10 +3335,16 @@ impl Daemon {
                         };
 
                         let file_mode = &self.config.output.file_mode;
-                        match write_transcription_to_file(&output_path, &final_text, file_mode)
-                            .await
+                        match output::file::write_transcription(
+                            &output_path,
+                            &final_text,
+                            file_mode,
+                            self.config.output.file_entry_template.as_deref(),
+                            self.config.output.file_header_template.as_deref(),
+                        )
+                        .await
                         {
-                            Ok(()) => {
+                            Ok(output_path) => {
                                 let mode_str = match file_mode {
                                     FileMode::Overwrite => "wrote",
                                     FileMode::Append => "appended",
@@ -2118,6 +3360,8 @@ impl Daemon {
                                 );
                             }
                         }
+                        self.output_to_other_targets(&OutputMode::File, &final_text, profile_name)
+                            .await;
 
                         self.resume_media_players();
                         *state = State::Idle;
@@ -2155,12 +3399,89 @@ impl Daemon {
                     if let Some(shift_enter) = shift_enter_override {
                         output_config.shift_enter_newlines = shift_enter;
                     }
+                    if let Some(pacing) = active_profile.and_then(|p| p.pacing) {
+                        output_config.pacing.enabled = pacing;
+                    }
 
                     // If smart auto-submit triggered, enable auto_submit for this cycle
                     if smart_submit {
                         output_config.auto_submit = true;
                     }
 
+                    // Low-confidence text goes to the clipboard only: never
+                    // typed, never submitted.
+                    if clipboard_only {
+                        output_config.mode = OutputMode::Clipboard;
+                        output_config.auto_submit = false;
+                    }
+
+                    // Webhook mode posts instead of outputting; `always` posts
+                    // in the background alongside the other modes
+                    if output_config.mode == OutputMode::Webhook {
+                        *state = State::Outputting {
+                            text: final_text.clone(),
+                        };
+                        match output::webhook::send(
+                            &output_config.webhook,
+                            &final_text,
+                            profile_name,
+                        )
+                        .await
+                        {
+                            Ok(()) => {
+                                self.output_to_other_targets(
+                                    &OutputMode::Webhook,
+                                    &final_text,
+                                    profile_name,
+                                )
+                                .await;
+                                self.play_feedback(SoundEvent::TranscriptionComplete);
+                                self.last_typed = None;
+                                self.resume_media_players();
+                                *state = State::Idle;
+                                self.update_state("idle");
+                                return;
+                            }
+                            Err(e) if output_config.fallback_to_clipboard => {
+                                tracing::error!("{}, copying to clipboard instead", e);
+                                output_config.mode = OutputMode::Clipboard;
+                            }
+                            Err(e) => {
+                                tracing::error!("{}", e);
+                                self.last_typed = None;
+                                self.resume_media_players();
+                                *state = State::Idle;
+                                self.update_state("idle");
+                                return;
+                            }
+                        }
+                    } else if output_config.webhook.always && !clipboard_only {
+                        let webhook = output_config.webhook.clone();
+                        let text = final_text.clone();
+                        let profile = profile_name.map(str::to_string);
+                        tokio::spawn(async move {
+                            if let Err(e) =
+                                output::webhook::send(&webhook, &text, profile.as_deref()).await
+                            {
+                                tracing::warn!("{}", e);
+                            }
+                        });
+                    }
+
+                    // Only report the language when it was detected rather
+                    // than configured, so a fixed language adds no noise
+                    let language_config = &self.config.whisper.language;
+                    let detected_language = active_transcriber
+                        .as_ref()
+                        .filter(|_| language_config.is_auto() || language_config.is_multiple())
+                        .and_then(|t| t.last_detected_language());
+                    if let Some(ref lang) = detected_language {
+                        tracing::info!("Detected language: {}", lang);
+                    }
+                    write_detected_language_file(detected_language.as_deref());
+                    // On-demand loading builds the session just before this
+                    write_execution_provider_file();
+
                     // Inject keyboard layout/variant hints derived from the
                     // transcriber's detected language (issue #180). Skipped
                     // per field when the user has already set explicit
@@ -2216,61 +3537,520 @@ impl Daemon {
                         }
                     }
 
-                    let output_chain = output::create_output_chain(&output_config);
+                    // Output the text
+                    *state = State::Outputting {
+                        text: final_text.clone(),
+                    };
+
+                    // --target: bring the named window to the front first.
+                    // Only modes that type or paste at the cursor need it.
+                    let target_backend = target_override
+                        .as_deref()
+                        .filter(|_| {
+                            matches!(output_config.mode, OutputMode::Type | OutputMode::Paste)
+                        })
+                        .and_then(|target| {
+                            let backend = crate::focus::detect_backend();
+                            if backend.is_none() {
+                                tracing::warn!(
+                                    "--target {:?} ignored: focusing windows needs Hyprland or Sway",
+                                    target
+                                );
+                            }
+                            Some((target, backend?))
+                        });
+                    let mut previous_window = None;
+                    if let Some((target, ref backend)) = target_backend {
+                        let focused = match crate::focus::WindowTarget::parse(target) {
+                            Ok(target) => {
+                                crate::focus::focus_target(backend.as_ref(), &target).await
+                            }
+                            Err(e) => Err(e.to_string()),
+                        };
+                        match focused {
+                            Ok(previous) => {
+                                tracing::debug!("Focused target window {:?}", target);
+                                previous_window = previous;
+                                tokio::time::sleep(std::time::Duration::from_millis(
+                                    TARGET_FOCUS_SETTLE_MS,
+                                ))
+                                .await;
+                            }
+                            Err(e) => {
+                                tracing::warn!("Couldn't focus target window {:?}: {}", target, e)
+                            }
+                        }
+                    }
+
+                    // After --target, so auto_driver looks at the window that
+                    // will receive the text
+                    let output_chain = output::create_output_chain_for_focus(&output_config).await;
+
+                    let output_options = output::OutputOptions {
+                        pre_output_command: output_config.pre_output_command.as_deref(),
+                        post_output_command: output_config.post_output_command.as_deref(),
+                        wait_for_modifier_release: output_config.wait_for_modifier_release,
+                        modifier_release_timeout: std::time::Duration::from_millis(
+                            output_config.modifier_release_timeout_ms,
+                        ),
+                    };
+
+                    let output_result =
+                        output::output_with_fallback(&output_chain, &final_text, output_options)
+                            .await;
+
+                    if let (true, Some(id), Some((_, backend))) =
+                        (refocus, &previous_window, &target_backend)
+                    {
+                        if let Err(e) = backend.focus(id).await {
+                            tracing::warn!("Couldn't return focus to the previous window: {}", e);
+                        }
+                    }
+
+                    if !clipboard_only {
+                        self.output_to_other_targets(
+                            &output_config.mode,
+                            &final_text,
+                            profile_name,
+                        )
+                        .await;
+                    }
+
+                    if let Err(e) = output_result {
+                        tracing::error!("Output failed: {}", e);
+                        self.last_typed = None;
+                    } else {
+                        self.play_feedback(SoundEvent::TranscriptionComplete);
+
+                        // After --refocus the text is in another window
+                        self.last_typed = (output_config.mode == OutputMode::Type
+                            && !output_config.auto_submit
+                            && !(refocus && previous_window.is_some())
+                            && output::last_output_at_cursor())
+                        .then(|| {
+                            format!(
+                                "{}{}",
+                                final_text,
+                                output_config.append_text.as_deref().unwrap_or("")
+                            )
+                        });
+
+                        if self.config.output.notification.on_transcription {
+                            // Send notification on successful output
+                            output::send_transcription_notification(
+                                &final_text,
+                                self.config.output.notification.show_engine_icon,
+                                self.config.engine,
+                                &self.config.output.notification.urgency,
+                                detected_language.as_deref(),
+                            )
+                            .await;
+                        }
+
+                        if let Some(samples) = redo_audio.filter(|a| !a.is_empty()) {
+                            if self.redo_task.is_some() {
+                                tracing::debug!("Redo pass already running, skipping this one");
+                            } else {
+                                let replaceable = output_config.mode == OutputMode::Type
+                                    && !output_config.auto_submit;
+                                self.redo_profile = profile_override.clone();
+                                self.redo_context = recent_context.clone();
+                                self.redo_task = Some(two_pass::spawn_redo(
+                                    &self.config.whisper,
+                                    self.config_path.clone(),
+                                    self.redo_transcriber.clone(),
+                                    samples,
+                                    final_text,
+                                    replaceable,
+                                ));
+                            }
+                        }
+                    }
+
+                    self.resume_media_players();
+                    *state = State::Idle;
+                    self.update_state("idle");
+                }
+            }
+            Ok(Err(e)) => {
+                tracing::error!("Transcription failed: {}", e);
+                self.reset_to_idle(state).await;
+            }
+            Err(e) => {
+                // JoinError - task was cancelled or panicked
+                if e.is_cancelled() {
+                    tracing::debug!("Transcription task was cancelled");
+                } else {
+                    tracing::error!("Transcription task panicked: {}", e);
+                }
+                self.reset_to_idle(state).await;
+            }
+        }
+    }
+
+    /// Apply a voice editing command to the text the last dictation typed
+    async fn apply_edit_command(&mut self, command: EditCommand) {
+        let Some(typed) = self.last_typed.take() else {
+            tracing::info!(
+                "Editing command {:?} ignored: the last output was not typed at the cursor",
+                command
+            );
+            return;
+        };
+        if command == EditCommand::DeleteLastOutput && self.undo_with_ctrl_z().await {
+            if output::streaming::emit_ctrl_z().await {
+                tracing::info!("Editing command {:?}: pressed Ctrl+Z", command);
+                self.last_dictation = None;
+                return;
+            }
+            tracing::warn!(
+                "Editing command {:?}: could not send Ctrl+Z, backspacing",
+                command
+            );
+        }
+        let count = command.backspaces(&typed);
+        if output::streaming::emit_backspaces(count).await != count {
+            tracing::warn!("Editing command {:?}: could not send backspaces", command);
+            self.last_typed = Some(typed);
+            return;
+        }
+        tracing::info!(
+            "Editing command {:?}: removed {} characters",
+            command,
+            count
+        );
+
+        let remaining: String = typed.chars().take(typed.chars().count() - count).collect();
+        self.last_dictation = self
+            .last_dictation
+            .take()
+            .filter(|_| !remaining.trim().is_empty())
+            .map(|(_, when)| (remaining.trim_end().to_string(), when));
+        // Repeated "delete last word" keeps eating into the same output
+        self.last_typed = Some(remaining).filter(|text| !text.is_empty());
+    }
+
+    /// Whether the focused application is listed in `[output] undo_ctrl_z_apps`
+    async fn undo_with_ctrl_z(&self) -> bool {
+        if self.undo_ctrl_z_apps.is_empty() {
+            return false;
+        }
+        self.focused_window().await.is_some_and(|window| {
+            self.undo_ctrl_z_apps
+                .iter()
+                .any(|r| r.is_match(&window.class))
+        })
+    }
+
+    /// Re-run the current recording through `[whisper] secondary_model` when
+    /// the primary model's confidence is below `secondary_retry_confidence`
+    /// and the clip is short enough. Returns true if a retry was started, in
+    /// which case its result arrives through `transcription_task` as usual.
+    fn retry_with_secondary(&mut self, state: &State, confidence: Option<f32>) -> bool {
+        let whisper = &self.config.whisper;
+        let (Some(secondary), Some(threshold), Some(confidence)) = (
+            whisper.secondary_model.clone(),
+            whisper.secondary_retry_confidence,
+            confidence,
+        ) else {
+            return false;
+        };
+        if confidence >= threshold {
+            return false;
+        }
+        let State::Transcribing { audio } = state else {
+            return false;
+        };
+        let audio_secs = audio.len() as f32 / 16000.0;
+        if audio_secs > whisper.secondary_retry_max_secs {
+            tracing::debug!(
+                "Low confidence ({:.2}) but {:.1}s clip exceeds secondary_retry_max_secs, not retrying",
+                confidence,
+                audio_secs
+            );
+            return false;
+        }
+        let Some(mm) = self.model_manager.as_mut() else {
+            return false;
+        };
+        let transcriber = match mm.get_transcriber(Some(&secondary)) {
+            Ok(t) => t,
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to load secondary model '{}' for retry: {}",
+                    secondary,
+                    e
+                );
+                return false;
+            }
+        };
+
+        tracing::info!(
+            "Low confidence ({:.2} < {:.2}), retrying with secondary model '{}'",
+            confidence,
+            threshold,
+            secondary
+        );
+        let samples = audio.clone();
+        self.active_transcriber = Some(transcriber.clone());
+        self.apply_profile_grammar(transcriber.as_ref());
+        self.transcription_task = Some(tokio::task::spawn_blocking(move || {
+            transcriber.transcribe(&samples)
+        }));
+        true
+    }
+
+    /// Run the post-processing step for `processed_text`: the profile's
+    /// command, else its template, else the default post-processor
+    async fn post_process(
+        &self,
+        processed_text: String,
+        profile_override: Option<&str>,
+        recent_context: Option<&str>,
+    ) -> String {
+        let active_profile = profile_override.and_then(|name| self.config.get_profile(name));
+        if let Some(profile) = active_profile {
+            if let Some(ref cmd) = profile.post_process_command {
+                let timeout_ms = profile.post_process_timeout_ms.unwrap_or(30000);
+                let profile_config = crate::config::PostProcessConfig {
+                    command: cmd.clone(),
+                    timeout_ms,
+                    trim: true,
+                    fallback_on_empty: true,
+                    stages: Vec::new(),
+                };
+                let profile_processor = PostProcessor::new(&profile_config);
+                tracing::info!(
+                    "Post-processing with profile: {:?}, has_context: {}",
+                    profile_override.unwrap_or_default(),
+                    recent_context.is_some()
+                );
+                tracing::debug!("Post-processing context: {:?}", recent_context);
+                let result = profile_processor
+                    .process_with_context(&processed_text, recent_context)
+                    .await;
+                tracing::info!("Post-processed: changed: {}", result != processed_text);
+                tracing::debug!("Post-processed result: {:?}", result);
+                result
+            } else {
+                // Profile has no post_process_command: use its template,
+                // or the default
+                let template_processor =
+                    profile_override.and_then(|name| self.profile_post_processors.get(name));
+                if let Some(post_processor) = template_processor.or(self.post_processor.as_ref()) {
+                    tracing::info!(
+                        "Post-processing{}, has_context: {}",
+                        if template_processor.is_some() {
+                            " with profile template"
+                        } else {
+                            ""
+                        },
+                        recent_context.is_some()
+                    );
+                    tracing::debug!(
+                        "Post-processing input: {:?}, context: {:?}",
+                        processed_text,
+                        recent_context
+                    );
+                    let result = post_processor
+                        .process_with_context(&processed_text, recent_context)
+                        .await;
+                    tracing::info!("Post-processed: changed: {}", result != processed_text);
+                    tracing::debug!("Post-processed result: {:?}", result);
+                    result
+                } else {
+                    processed_text
+                }
+            }
+        } else if let Some(ref post_processor) = self.post_processor {
+            tracing::info!("Post-processing, has_context: {}", recent_context.is_some());
+            tracing::debug!(
+                "Post-processing input: {:?}, context: {:?}",
+                processed_text,
+                recent_context
+            );
+            let result = post_processor
+                .process_with_context(&processed_text, recent_context)
+                .await;
+            tracing::info!("Post-processed: changed: {}", result != processed_text);
+            tracing::debug!("Post-processed result: {:?}", result);
+            result
+        } else {
+            processed_text
+        }
+    }
 
-                    // Output the text
-                    *state = State::Outputting {
-                        text: final_text.clone(),
-                    };
+    /// Handle a finished redo pass: if the redo model disagrees enough with
+    /// the draft, replace the typed draft or offer the correction per
+    /// `[whisper] redo_action`.
+    async fn handle_redo_outcome(
+        &mut self,
+        state: &State,
+        result: std::result::Result<RedoOutcome, tokio::task::JoinError>,
+    ) {
+        let outcome = match result {
+            Ok(outcome) => outcome,
+            Err(e) => {
+                tracing::warn!("Redo task failed: {}", e);
+                return;
+            }
+        };
+        if outcome.transcriber.is_some() {
+            self.redo_transcriber = outcome.transcriber;
+        }
+        let redo = match outcome.result {
+            Ok(redo) => redo,
+            Err(e) => {
+                tracing::warn!("Redo transcription failed: {}", e);
+                return;
+            }
+        };
 
-                    let output_options = output::OutputOptions {
-                        pre_output_command: output_config.pre_output_command.as_deref(),
-                        post_output_command: output_config.post_output_command.as_deref(),
-                        wait_for_modifier_release: output_config.wait_for_modifier_release,
-                        modifier_release_timeout: std::time::Duration::from_millis(
-                            output_config.modifier_release_timeout_ms,
-                        ),
-                    };
+        if redo.is_empty() {
+            tracing::debug!("Redo transcription was empty");
+            return;
+        }
 
-                    if let Err(e) =
-                        output::output_with_fallback(&output_chain, &final_text, output_options)
-                            .await
-                    {
-                        tracing::error!("Output failed: {}", e);
-                    } else {
-                        self.play_feedback(SoundEvent::TranscriptionComplete);
+        // Process the redo exactly like the draft, so the comparison and any
+        // replacement are against the text that was actually output
+        let profile = self.redo_profile.take();
+        let context = self.redo_context.take();
+        let processed = self.text_processor_for(profile.as_deref()).process(&redo);
+        let corrected = self
+            .post_process(processed, profile.as_deref(), context.as_deref())
+            .await;
+        let change = two_pass::word_change_ratio(&outcome.output_text, &corrected);
+        if corrected.is_empty() || change < self.config.whisper.redo_min_change {
+            tracing::debug!("Redo agrees with draft ({:.0}% changed)", change * 100.0);
+            return;
+        }
+        tracing::info!(
+            "Redo differs from draft ({:.0}% changed): {:?}",
+            change * 100.0,
+            corrected
+        );
 
-                        if self.config.output.notification.on_transcription {
-                            // Send notification on successful output
-                            output::send_transcription_notification(
-                                &final_text,
-                                self.config.output.notification.show_engine_icon,
-                                self.config.engine,
-                                &self.config.output.notification.urgency,
-                            )
-                            .await;
-                        }
+        // Only replace in place when nothing has happened since the draft
+        // was typed, so the backspaces land on the draft and nothing else.
+        let draft_is_latest = self
+            .last_dictation
+            .as_ref()
+            .is_some_and(|(text, _)| *text == outcome.output_text);
+        if self.config.whisper.redo_action == RedoAction::Replace
+            && outcome.replaceable
+            && draft_is_latest
+            && matches!(state, State::Idle)
+        {
+            let appended = self.config.output.append_text.as_deref().unwrap_or("");
+            let count = outcome.output_text.chars().count() + appended.chars().count();
+            if output::streaming::emit_backspaces(count).await == count {
+                let mut output_config = self.config.output.clone();
+                output_config.mode = OutputMode::Type;
+                output_config.auto_submit = false;
+                let chain = output::create_output_chain_for_focus(&output_config).await;
+                let options = output::OutputOptions {
+                    pre_output_command: None,
+                    post_output_command: None,
+                    wait_for_modifier_release: output_config.wait_for_modifier_release,
+                    modifier_release_timeout: Duration::from_millis(
+                        output_config.modifier_release_timeout_ms,
+                    ),
+                };
+                match output::output_with_fallback(&chain, &corrected, options).await {
+                    Ok(()) => {
+                        self.last_typed = Some(format!("{}{}", corrected, appended));
+                        self.last_dictation = Some((corrected, Instant::now()));
+                        return;
                     }
-
-                    self.resume_media_players();
-                    *state = State::Idle;
-                    self.update_state("idle");
+                    Err(e) => tracing::warn!("Failed to type redo correction: {}", e),
                 }
+            } else {
+                tracing::warn!("Could not backspace over draft, offering correction instead");
             }
-            Ok(Err(e)) => {
-                tracing::error!("Transcription failed: {}", e);
-                self.reset_to_idle(state).await;
-            }
-            Err(e) => {
-                // JoinError - task was cancelled or panicked
-                if e.is_cancelled() {
-                    tracing::debug!("Transcription task was cancelled");
-                } else {
-                    tracing::error!("Transcription task panicked: {}", e);
+        }
+
+        tokio::spawn(async move {
+            if notification::send_with_action("Voxtype: Correction", &corrected, "Copy").await {
+                let clipboard = output::clipboard::ClipboardOutput::new(None);
+                if let Err(e) = clipboard.output(&corrected).await {
+                    tracing::warn!("Failed to copy correction: {}", e);
                 }
-                self.reset_to_idle(state).await;
+            }
+        });
+    }
+
+    /// Switch Whisper's translate-to-English for subsequent transcriptions
+    /// and confirm the new mode with a notification
+    async fn set_translate(&mut self, translate: bool) {
+        let notification = &self.config.output.notification;
+        if self.config.engine != crate::config::TranscriptionEngine::Whisper {
+            tracing::warn!("Translate toggle ignored: only the Whisper engine translates");
+            send_notification(
+                "Translation Unavailable",
+                "Only the Whisper engine can translate",
+                notification.show_engine_icon,
+                self.config.engine,
+                &notification.urgency,
+            )
+            .await;
+            return;
+        }
+
+        self.config.whisper.translate = translate;
+        if let Some(ref mut mm) = self.model_manager {
+            mm.set_translate(translate);
+        }
+        if let Some(ref redo) = self.redo_transcriber {
+            if !redo.set_translate(translate) {
+                self.redo_transcriber = None;
             }
         }
+        // Profile transcribers that cannot switch are rebuilt on next use
+        self.profile_transcribers
+            .retain(|_, t| t.set_translate(translate));
+
+        tracing::info!(
+            "Translate to English {}",
+            if translate { "on" } else { "off" }
+        );
+        let notification = &self.config.output.notification;
+        let (title, body) = if translate {
+            ("Translate On", "Speech will be typed in English")
+        } else {
+            ("Translate Off", "Speech will be typed as spoken")
+        };
+        send_notification(
+            title,
+            body,
+            notification.show_engine_icon,
+            self.config.engine,
+            &notification.urgency,
+        )
+        .await;
+    }
+
+    /// Warn the user that a transcription scored below `min_confidence`,
+    /// saying what was done with the text.
+    async fn notify_low_confidence(&self, confidence: f32, action: LowConfidenceAction) {
+        let outcome = match action {
+            LowConfidenceAction::Notify => "Output anyway",
+            LowConfidenceAction::ClipboardOnly => "Copied to clipboard instead of typing",
+            LowConfidenceAction::Discard => "Discarded",
+        };
+        tracing::warn!(
+            "Low transcription confidence ({:.2} < {:.2}): {}",
+            confidence,
+            self.config.transcribe.min_confidence.unwrap_or_default(),
+            outcome
+        );
+        send_notification(
+            "Low Confidence Transcription",
+            &format!("{:.0}% confidence. {}.", confidence * 100.0, outcome),
+            self.config.output.notification.show_engine_icon,
+            self.config.engine,
+            &self.config.output.notification.urgency,
+        )
+        .await;
     }
 
     /// Fire a desktop notification when the running binary can't service
@@ -2350,13 +4130,24 @@ impl Daemon {
 
         // Clean up any stale cancel and profile override files from previous runs
         cleanup_cancel_file();
+        let _ = std::fs::remove_file(Config::runtime_dir().join("translate"));
+        let _ = read_undo_request();
         cleanup_profile_override();
 
         // Clean up any stale meeting command files
         cleanup_meeting_files();
 
-        // Mark any orphaned active meetings as completed
-        cleanup_stale_meetings(&self.config);
+        // Finish meetings a crash left in progress
+        recover_interrupted_meetings(&self.config).await;
+
+        // Keep meeting storage within [meeting.retention]
+        spawn_meeting_pruning(&self.config);
+
+        // Upload meetings the sync server doesn't have yet
+        spawn_meeting_sync(&self.config);
+
+        // Start meetings when calls start
+        let mut call_rx = spawn_call_detection(&self.config);
 
         // Write PID file for external control via signals
         self.pid_file_path = write_pid_file();
@@ -2395,6 +4186,22 @@ impl Daemon {
             }
         }
 
+        // Live meeting events for `voxtype meeting watch`. Not fatal either:
+        // meetings still run and export without a viewer.
+        if self.config.meeting.enabled {
+            let meeting_socket = meeting::live::default_socket_path();
+            match meeting::live::LiveHub::start(meeting_socket.clone()) {
+                Ok(hub) => self.meeting_live = Some(hub),
+                Err(e) => {
+                    tracing::warn!(
+                        "Could not start meeting event socket at {:?}: {}",
+                        meeting_socket,
+                        e
+                    );
+                }
+            }
+        }
+
         // Spawn the OSD child if enabled and the level socket bound. Without
         // the socket the frontend has nothing to render, so skip the spawn
         // rather than burning a slot in the launcher's restart logic.
@@ -2453,6 +4260,21 @@ impl Daemon {
             tracing::info!("State file: {:?}", path);
         }
 
+        if self.config.mqtt.enabled {
+            match crate::mqtt::MqttPublisher::start(&self.config.mqtt) {
+                Ok(mqtt) => {
+                    tracing::info!(
+                        "MQTT publishing to {}:{} under {:?}",
+                        self.config.mqtt.host,
+                        self.config.mqtt.port(),
+                        self.config.mqtt.topic_prefix
+                    );
+                    self.mqtt = Some(mqtt);
+                }
+                Err(e) => tracing::warn!("MQTT disabled: {}", e),
+            }
+        }
+
         // Warn about profile modifiers that reference undefined profiles. Runs
         // before either platform's hotkey listener is created so the warning
         // surfaces regardless of evdev/rdev backend.
@@ -2535,31 +4357,42 @@ impl Daemon {
 
         // Pre-load transcription model if on_demand_loading is disabled
         let mut transcriber_preloaded: Option<Arc<dyn Transcriber>> = None;
+        let _ = std::fs::remove_file(engine_fallback_file());
+        let _ = std::fs::remove_file(detected_language_file());
+        let _ = std::fs::remove_file(execution_provider_file());
         if !self.config.on_demand_loading() {
-            tracing::info!("Loading transcription model: {}", self.config.model_name());
-            match self.config.engine {
-                crate::config::TranscriptionEngine::Whisper => {
-                    // Use model manager for Whisper
-                    if let Err(e) = model_manager.preload_primary() {
-                        tracing::error!("Failed to preload model: {}", e);
-                        return Err(crate::error::VoxtypeError::Transcribe(e));
+            let configured_engine = self.config.engine;
+            let mut fallbacks = self.config.engine_fallback.clone().into_iter();
+            loop {
+                tracing::info!(
+                    "Loading transcription model: {} ({})",
+                    self.config.model_name(),
+                    self.config.engine.name()
+                );
+                let err = match preload_engine(&self.config, &mut model_manager) {
+                    Ok(t) => {
+                        transcriber_preloaded = t;
+                        break;
                     }
-                }
-                crate::config::TranscriptionEngine::Parakeet
-                | crate::config::TranscriptionEngine::Moonshine
-                | crate::config::TranscriptionEngine::SenseVoice
-                | crate::config::TranscriptionEngine::Paraformer
-                | crate::config::TranscriptionEngine::Dolphin
-                | crate::config::TranscriptionEngine::Omnilingual
-                | crate::config::TranscriptionEngine::Cohere
-                | crate::config::TranscriptionEngine::Soniox => {
-                    // Non-Whisper engines do their own setup; Soniox just validates
-                    // API key + endpoint at construction (no model to download).
-                    transcriber_preloaded = Some(Arc::from(crate::transcribe::create_transcriber(
-                        &self.config,
-                    )?));
-                }
+                    Err(e) => e,
+                };
+                let Some(next) = fallbacks.next() else {
+                    tracing::error!("Failed to preload model: {}", err);
+                    return Err(crate::error::VoxtypeError::Transcribe(err));
+                };
+                tracing::warn!(
+                    "Engine '{}' failed to initialize: {}. Falling back to '{}'",
+                    self.config.engine.name(),
+                    err,
+                    next.name()
+                );
+                self.config.engine = next;
             }
+            if self.config.engine != configured_engine {
+                self.report_engine_fallback(configured_engine, self.config.engine)
+                    .await;
+            }
+            write_execution_provider_file();
             tracing::info!("Model loaded, ready for voice input");
         } else {
             tracing::info!("On-demand loading enabled, model will be loaded when recording starts");
@@ -2591,6 +4424,28 @@ impl Daemon {
         #[cfg(not(any(target_os = "linux", target_os = "macos")))]
         let mut hotkey_rx: Option<tokio::sync::mpsc::Receiver<HotkeyEvent>> = None;
 
+        // Start wake word listener (activation = "wake_word")
+        let (mut wake_word_rx, wake_word_task) = if self.config.activation == Activation::WakeWord {
+            match crate::wake_word::spawn_listener(&self.config).await {
+                Ok((rx, task)) => {
+                    tracing::info!(
+                        "Listening for wake word: {} (trailing silence stops recording)",
+                        self.config.wake_word.model
+                    );
+                    (Some(rx), Some(task))
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to start wake word listener: {}. Use the hotkey or 'voxtype record' commands instead.",
+                        e
+                    );
+                    (None, None)
+                }
+            }
+        } else {
+            (None, None)
+        };
+
         // Current state
         let mut state = State::Idle;
 
@@ -2644,6 +4499,7 @@ impl Daemon {
                                 if let Some(ref profile_name) = profile_override {
                                     write_profile_override(profile_name);
                                 }
+                                self.select_window_profile().await;
 
                                 tracing::info!("Recording started");
 
@@ -2654,31 +4510,7 @@ impl Daemon {
 
                                 // Prepare model for transcription
                                 if self.config.on_demand_loading() {
-                                    // Start model loading in background
-                                    match self.config.engine {
-                                        crate::config::TranscriptionEngine::Whisper => {
-                                            let config = self.config.whisper.clone();
-                                            let config_path = self.config_path.clone();
-                                            let model_to_load = model_override.clone();
-                                            self.model_load_task = Some(tokio::task::spawn_blocking(move || {
-                                                let mut temp_manager = ModelManager::new(&config, config_path);
-                                                temp_manager.get_transcriber(model_to_load.as_deref())
-                                            }));
-                                        }
-                                        crate::config::TranscriptionEngine::Parakeet
-                                        | crate::config::TranscriptionEngine::Moonshine
-                                        | crate::config::TranscriptionEngine::SenseVoice
-                | crate::config::TranscriptionEngine::Paraformer
-                | crate::config::TranscriptionEngine::Dolphin
-                | crate::config::TranscriptionEngine::Omnilingual
-                | crate::config::TranscriptionEngine::Cohere
-                | crate::config::TranscriptionEngine::Soniox => {
-                                            let config = self.config.clone();
-                                            self.model_load_task = Some(tokio::task::spawn_blocking(move || {
-                                                crate::transcribe::create_transcriber(&config).map(Arc::from)
-                                            }));
-                                        }
-                                    }
+                                    self.start_model_load(model_override.clone());
                                     tracing::debug!("Started background model loading");
                                 } else {
                                     // Prepare model (spawns subprocess for gpu_isolation mode)
@@ -2702,7 +4534,9 @@ impl Daemon {
                 | crate::config::TranscriptionEngine::Dolphin
                 | crate::config::TranscriptionEngine::Omnilingual
                 | crate::config::TranscriptionEngine::Cohere
-                | crate::config::TranscriptionEngine::Soniox => {
+                | crate::config::TranscriptionEngine::Soniox
+                | crate::config::TranscriptionEngine::Vosk
+                | crate::config::TranscriptionEngine::Zipformer => {
                                             if let Some(ref t) = transcriber_preloaded {
                                                 let transcriber = t.clone();
                                                 tokio::task::spawn_blocking(move || {
@@ -2728,7 +4562,7 @@ impl Daemon {
                                 } else {
                                     // Create and start audio capture
                                     tracing::debug!("Creating audio capture with device: {}", self.config.audio.device);
-                                    match self.start_recording_capture().await {
+                                    match self.start_recording_capture(false).await {
                                         Ok(capture) => {
                                             tracing::debug!("Audio capture started successfully");
                                             audio_capture = Some(capture);
@@ -2861,6 +4695,7 @@ impl Daemon {
                                 if let Some(ref profile_name) = profile_override {
                                     write_profile_override(profile_name);
                                 }
+                                self.select_window_profile().await;
 
                                 // Start recording
                                 tracing::info!("Recording started (toggle mode)");
@@ -2871,31 +4706,7 @@ impl Daemon {
 
                                 // Prepare model for transcription
                                 if self.config.on_demand_loading() {
-                                    // Start model loading in background
-                                    match self.config.engine {
-                                        crate::config::TranscriptionEngine::Whisper => {
-                                            let config = self.config.whisper.clone();
-                                            let config_path = self.config_path.clone();
-                                            let model_to_load = model_override.clone();
-                                            self.model_load_task = Some(tokio::task::spawn_blocking(move || {
-                                                let mut temp_manager = ModelManager::new(&config, config_path);
-                                                temp_manager.get_transcriber(model_to_load.as_deref())
-                                            }));
-                                        }
-                                        crate::config::TranscriptionEngine::Parakeet
-                                        | crate::config::TranscriptionEngine::Moonshine
-                                        | crate::config::TranscriptionEngine::SenseVoice
-                | crate::config::TranscriptionEngine::Paraformer
-                | crate::config::TranscriptionEngine::Dolphin
-                | crate::config::TranscriptionEngine::Omnilingual
-                | crate::config::TranscriptionEngine::Cohere
-                | crate::config::TranscriptionEngine::Soniox => {
-                                            let config = self.config.clone();
-                                            self.model_load_task = Some(tokio::task::spawn_blocking(move || {
-                                                crate::transcribe::create_transcriber(&config).map(Arc::from)
-                                            }));
-                                        }
-                                    }
+                                    self.start_model_load(model_override.clone());
                                     tracing::debug!("Started background model loading");
                                 } else {
                                     // Prepare model (spawns subprocess for gpu_isolation mode)
@@ -2919,7 +4730,9 @@ impl Daemon {
                 | crate::config::TranscriptionEngine::Dolphin
                 | crate::config::TranscriptionEngine::Omnilingual
                 | crate::config::TranscriptionEngine::Cohere
-                | crate::config::TranscriptionEngine::Soniox => {
+                | crate::config::TranscriptionEngine::Soniox
+                | crate::config::TranscriptionEngine::Vosk
+                | crate::config::TranscriptionEngine::Zipformer => {
                                             if let Some(ref t) = transcriber_preloaded {
                                                 let transcriber = t.clone();
                                                 tokio::task::spawn_blocking(move || {
@@ -2941,7 +4754,8 @@ impl Daemon {
                                 ).await {
                                     tracing::info!("Streaming session started (toggle)");
                                 } else {
-                                    match self.start_recording_capture().await {
+                                    let auto_stop = self.config.vad.auto_stop_silence_ms > 0;
+                                    match self.start_recording_capture(auto_stop).await {
                                         Ok(capture) => {
                                             audio_capture = Some(capture);
 
@@ -3056,6 +4870,34 @@ impl Daemon {
                             tracing::trace!("Ignoring HotkeyEvent::Released in toggle mode");
                         }
 
+                        // === TRANSLATE KEY (works in both modes) ===
+                        (HotkeyEvent::ToggleTranslate, _) => {
+                            tracing::debug!("Received HotkeyEvent::ToggleTranslate");
+                            self.set_translate(!self.config.whisper.translate).await;
+                        }
+
+                        // === UNDO KEY (works in both modes) ===
+                        (HotkeyEvent::Undo, _) => {
+                            tracing::debug!("Received HotkeyEvent::Undo");
+                            if matches!(state, State::Idle) {
+                                self.apply_edit_command(EditCommand::DeleteLastOutput).await;
+                            } else {
+                                tracing::debug!("Undo ignored: not idle");
+                            }
+                        }
+
+                        // === MEETING MARK KEY (works in both modes) ===
+                        (HotkeyEvent::MeetingMark, _) => {
+                            tracing::debug!("Received HotkeyEvent::MeetingMark");
+                            if self.meeting_daemon.is_some() {
+                                if let Err(e) = self.mark_meeting(None).await {
+                                    tracing::warn!("Failed to bookmark meeting: {}", e);
+                                }
+                            } else {
+                                tracing::debug!("Meeting mark ignored: no meeting in progress");
+                            }
+                        }
+
                         // === CANCEL KEY (works in both modes) ===
                         (HotkeyEvent::Cancel, _) => {
                             tracing::debug!("Received HotkeyEvent::Cancel");
@@ -3091,6 +4933,7 @@ impl Daemon {
                                 cleanup_output_mode_override();
                                 cleanup_model_override();
                                 cleanup_profile_override();
+                                cleanup_target_override();
                                 cleanup_bool_override("smart_auto_submit");
                                 state = State::Idle;
                                 self.update_state("idle");
@@ -3120,6 +4963,7 @@ impl Daemon {
                                 cleanup_output_mode_override();
                                 cleanup_model_override();
                                 cleanup_profile_override();
+                                cleanup_target_override();
                                 cleanup_bool_override("smart_auto_submit");
                                 state = State::Idle;
                                 self.update_state("idle");
@@ -3180,6 +5024,7 @@ impl Daemon {
                         cleanup_output_mode_override();
                         cleanup_model_override();
                         cleanup_profile_override();
+                        cleanup_target_override();
                         cleanup_bool_override("smart_auto_submit");
                         state = State::Idle;
                         eager_transcriber = None;
@@ -3261,7 +5106,13 @@ impl Daemon {
                     // (state stays Streaming until Ended arrives).
                     let timeout_fired = audio_capture.is_some()
                         && state.recording_duration().is_some_and(|d| d > max_duration);
-                    if timeout_fired {
+                    // Trailing silence ends hands-free recordings the same
+                    // way, keeping the overrides for the transcription
+                    let silence_fired = !timeout_fired
+                        && audio_capture.is_some()
+                        && !state.is_streaming()
+                        && self.auto_stop_fired.swap(false, Ordering::Relaxed);
+                    if timeout_fired || silence_fired {
                         // Streaming has its own clean stop path: skip the
                         // batch_transcribe branch below to avoid opening a
                         // second WS session for audio already being processed
@@ -3275,15 +5126,23 @@ impl Daemon {
                             continue;
                         }
 
-                        tracing::warn!(
-                            "Recording timeout ({:.0}s limit), transcribing captured audio",
-                            max_duration.as_secs_f32()
-                        );
+                        if silence_fired {
+                            tracing::info!(
+                                "{}ms of trailing silence, transcribing captured audio",
+                                self.config.vad.auto_stop_silence_ms
+                            );
+                        } else {
+                            tracing::warn!(
+                                "Recording timeout ({:.0}s limit), transcribing captured audio",
+                                max_duration.as_secs_f32()
+                            );
 
-                        cleanup_output_mode_override();
-                        cleanup_model_override();
-                        cleanup_profile_override();
-                        cleanup_bool_override("smart_auto_submit");
+                            cleanup_output_mode_override();
+                            cleanup_model_override();
+                            cleanup_profile_override();
+                            cleanup_target_override();
+                            cleanup_bool_override("smart_auto_submit");
+                        }
 
                         let model_override = match &state {
                             State::Recording { model_override, .. } => model_override.as_deref(),
@@ -3339,44 +5198,46 @@ impl Daemon {
                 }
 
                 // Handle SIGUSR1 - start recording (for compositor keybindings)
-                _ = sigusr1.recv() => {
-                    tracing::debug!("Received SIGUSR1 (start recording)");
+                // Also handles the wake word, which starts recording the same
+                // way but hands-free
+                trigger = async {
+                    tokio::select! {
+                        _ = sigusr1.recv() => StartTrigger::Signal,
+                        Some(score) = async {
+                            match &mut wake_word_rx {
+                                Some(rx) => rx.recv().await,
+                                None => std::future::pending().await,
+                            }
+                        } => StartTrigger::WakeWord(score),
+                    }
+                } => {
+                    let (wake_word, source) = match trigger {
+                        StartTrigger::Signal => {
+                            tracing::debug!("Received SIGUSR1 (start recording)");
+                            (false, "external trigger".to_string())
+                        }
+                        StartTrigger::WakeWord(score) => {
+                            (true, format!("wake word, score {:.2}", score))
+                        }
+                    };
+                    // Set by `voxtype record toggle`, which is hands-free like
+                    // the toggle hotkey; read it even when busy so it can't
+                    // carry over to a later `record start`
+                    let auto_stop = read_bool_override("auto_stop").unwrap_or(false) || wake_word;
                     if state.is_idle() {
                         // Read model override from file (set by `voxtype record start --model X`)
                         let model_override = read_model_override();
-                        tracing::info!("Recording started (external trigger), model_override = {:?}", model_override);
+                        self.select_window_profile().await;
+                        tracing::info!("Recording started ({}), model_override = {:?}", source, model_override);
 
                         if self.config.output.notification.on_recording_start {
-                            send_notification("Recording Started", "External trigger", self.config.output.notification.show_engine_icon, self.config.engine, &self.config.output.notification.urgency).await;
+                            let body = if wake_word { "Wake word" } else { "External trigger" };
+                            send_notification("Recording Started", body, self.config.output.notification.show_engine_icon, self.config.engine, &self.config.output.notification.urgency).await;
                         }
 
                         // Prepare model for transcription
                         if self.config.on_demand_loading() {
-                            // Start model loading in background
-                            match self.config.engine {
-                                crate::config::TranscriptionEngine::Whisper => {
-                                    let config = self.config.whisper.clone();
-                                    let config_path = self.config_path.clone();
-                                    let model_to_load = model_override.clone();
-                                    self.model_load_task = Some(tokio::task::spawn_blocking(move || {
-                                        let mut temp_manager = ModelManager::new(&config, config_path);
-                                        temp_manager.get_transcriber(model_to_load.as_deref())
-                                    }));
-                                }
-                                crate::config::TranscriptionEngine::Parakeet
-                                | crate::config::TranscriptionEngine::Moonshine
-                                | crate::config::TranscriptionEngine::SenseVoice
-                | crate::config::TranscriptionEngine::Paraformer
-                | crate::config::TranscriptionEngine::Dolphin
-                | crate::config::TranscriptionEngine::Omnilingual
-                | crate::config::TranscriptionEngine::Cohere
-                | crate::config::TranscriptionEngine::Soniox => {
-                                    let config = self.config.clone();
-                                    self.model_load_task = Some(tokio::task::spawn_blocking(move || {
-                                        crate::transcribe::create_transcriber(&config).map(Arc::from)
-                                    }));
-                                }
-                            }
+                            self.start_model_load(model_override.clone());
                         } else {
                             // Prepare model (spawns subprocess for gpu_isolation mode)
                             match self.config.engine {
@@ -3399,7 +5260,9 @@ impl Daemon {
                 | crate::config::TranscriptionEngine::Dolphin
                 | crate::config::TranscriptionEngine::Omnilingual
                 | crate::config::TranscriptionEngine::Cohere
-                | crate::config::TranscriptionEngine::Soniox => {
+                | crate::config::TranscriptionEngine::Soniox
+                | crate::config::TranscriptionEngine::Vosk
+                | crate::config::TranscriptionEngine::Zipformer => {
                                     if let Some(ref t) = transcriber_preloaded {
                                         let transcriber = t.clone();
                                         tokio::task::spawn_blocking(move || {
@@ -3410,7 +5273,9 @@ impl Daemon {
                             }
                         }
 
-                        if self.try_start_streaming(
+                        // Wake word recordings stay batch so trailing silence
+                        // can stop them
+                        if !wake_word && self.try_start_streaming(
                             &transcriber_preloaded,
                             &mut state,
                             &mut audio_capture,
@@ -3421,7 +5286,7 @@ impl Daemon {
                         ).await {
                             tracing::info!("Streaming session started (SIGUSR1)");
                         } else {
-                            match self.start_recording_capture().await {
+                            match self.start_recording_capture(auto_stop).await {
                                 Ok(capture) => {
                                     audio_capture = Some(capture);
 
@@ -3554,6 +5419,17 @@ impl Daemon {
                     self.handle_transcription_result(&mut state, result).await;
                 }
 
+                // Background redo pass with [whisper] redo_model
+                result = async {
+                    match self.redo_task.as_mut() {
+                        Some(task) => task.await,
+                        None => std::future::pending().await,
+                    }
+                }, if self.redo_task.is_some() => {
+                    self.redo_task = None;
+                    self.handle_redo_outcome(&state, result).await;
+                }
+
                 // Streaming event pump (active only while State::Streaming).
                 event = async {
                     match streaming_handle.as_mut() {
@@ -3621,6 +5497,25 @@ impl Daemon {
                                 }
                             }
                         }
+                        Some(StreamingEvent::Hypothesis { text, .. }) => {
+                            if let (Some(s), Some(chain)) =
+                                (streaming_session.as_mut(), streaming_chain.as_ref())
+                            {
+                                if let Err(e) = s.apply_hypothesis(
+                                    chain,
+                                    &text,
+                                    self.config.output.pre_output_command.as_deref(),
+                                    self.config.output.post_output_command.as_deref(),
+                                ).await {
+                                    tracing::warn!("Streaming hypothesis type failed: {}", e);
+                                }
+                                if let State::Streaming { typed_chars, partial_buffer, .. } = &mut state {
+                                    *typed_chars = s.typed_chars();
+                                    partial_buffer.clear();
+                                    partial_buffer.push_str(s.partial());
+                                }
+                            }
+                        }
                         Some(StreamingEvent::Error(err)) => {
                             tracing::error!("Streaming backend error: {}", err);
                             send_notification(
@@ -3666,6 +5561,7 @@ impl Daemon {
                         cleanup_output_mode_override();
                         cleanup_model_override();
                         cleanup_profile_override();
+                        cleanup_target_override();
                         cleanup_bool_override("smart_auto_submit");
                         state = State::Idle;
                         self.update_state("idle");
@@ -3689,6 +5585,16 @@ impl Daemon {
                     // Silently consume any stale cancel request
                     let _ = check_cancel_requested();
 
+                    // Apply `voxtype translate` requests between recordings
+                    if let Some(translate) = read_translate_request(self.config.whisper.translate) {
+                        self.set_translate(translate).await;
+                    }
+
+                    // Apply `voxtype undo` requests
+                    if read_undo_request() {
+                        self.apply_edit_command(EditCommand::DeleteLastOutput).await;
+                    }
+
                     // Periodically evict idle models (every ~60s when idle)
                     // The check interval is 500ms, so we use a counter to approximate 60s
                     static EVICTION_COUNTER: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
@@ -3708,7 +5614,7 @@ impl Daemon {
                     if let Some(trigger) = check_meeting_start() {
                         if self.config.meeting.enabled && self.meeting_daemon.is_none() {
                             tracing::debug!("Meeting start requested via file trigger");
-                            if let Err(e) = self.start_meeting(trigger.title, trigger.diarization).await {
+                            if let Err(e) = self.start_meeting(trigger.title, trigger.diarization, trigger.resume).await {
                                 tracing::error!("Failed to start meeting: {}", e);
                             }
                         } else if !self.config.meeting.enabled {
@@ -3744,6 +5650,13 @@ impl Daemon {
                                 tracing::error!("Failed to resume meeting: {}", e);
                             }
                         }
+
+                    // Check for meeting mark command
+                    if let Some(note) = check_meeting_mark() {
+                        if let Err(e) = self.mark_meeting(note).await {
+                            tracing::warn!("Failed to bookmark meeting: {}", e);
+                        }
+                    }
                 }
 
                 // Process meeting audio chunks
@@ -3773,6 +5686,11 @@ impl Daemon {
                         }
                         continue;
                     }
+                    if let Some(note) = check_meeting_mark() {
+                        if let Err(e) = self.mark_meeting(note).await {
+                            tracing::warn!("Failed to bookmark meeting: {}", e);
+                        }
+                    }
 
                     // Get samples from dual audio capture
                     if let Some(ref mut capture) = self.meeting_audio_capture {
@@ -3802,6 +5720,16 @@ impl Daemon {
                     }
                 }
 
+                // Start or stop meetings with calls
+                Some(change) = async {
+                    match &mut call_rx {
+                        Some(rx) => rx.recv().await,
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    self.handle_call_change(change).await;
+                }
+
                 // Handle meeting events
                 event = async {
                     match self.meeting_event_rx.as_mut() {
@@ -3859,6 +5787,11 @@ impl Daemon {
         #[cfg(not(any(target_os = "linux", target_os = "macos")))]
         let _ = hotkey_listener; // Silence unused variable warning
 
+        // Stop listening for the wake word
+        if let Some(task) = wake_word_task {
+            task.abort();
+        }
+
         // Abort any pending transcription task
         if let Some(task) = self.transcription_task.take() {
             task.abort();
@@ -3878,17 +5811,29 @@ impl Daemon {
 
         // Remove override files on shutdown
         cleanup_profile_override();
+        cleanup_target_override();
 
         // Remove state file on shutdown
         if let Some(ref path) = self.state_file_path {
             cleanup_state_file(path);
         }
 
+        // Mark the daemon offline for MQTT subscribers
+        if let Some(mqtt) = self.mqtt.take() {
+            mqtt.shutdown().await;
+        }
+
         // Remove meeting state file on shutdown
         if let Some(ref path) = self.meeting_state_file_path {
             cleanup_state_file(path);
         }
 
+        // Remove engine fallback, detected language and provider markers on
+        // shutdown
+        let _ = std::fs::remove_file(engine_fallback_file());
+        let _ = std::fs::remove_file(detected_language_file());
+        let _ = std::fs::remove_file(execution_provider_file());
+
         // Remove PID file on shutdown
         if let Some(ref path) = self.pid_file_path {
             cleanup_pid_file(path);
@@ -3899,6 +5844,9 @@ impl Daemon {
         if let Some(ref hub) = self.level_hub {
             hub.cleanup();
         }
+        if let Some(ref hub) = self.meeting_live {
+            hub.cleanup();
+        }
 
         tracing::info!("Daemon stopped");
 
@@ -3925,6 +5873,14 @@ mod tests {
         f(runtime_dir)
     }
 
+    #[test]
+    fn test_resolve_translate_request() {
+        assert!(resolve_translate_request("on", false));
+        assert!(!resolve_translate_request("off\n", true));
+        assert!(resolve_translate_request("toggle", false));
+        assert!(!resolve_translate_request("toggle", true));
+    }
+
     #[test]
     fn test_validate_diarization_override_accepts_allowlist() {
         assert_eq!(
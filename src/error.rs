@@ -112,6 +112,19 @@ pub enum VadError {
     DetectionFailed(String),
 }
 
+/// Errors related to wake word activation
+#[derive(Error, Debug)]
+pub enum WakeWordError {
+    #[error("Wake word model not found: {0}\n  Run 'voxtype setup wake-word' to download.")]
+    ModelNotFound(String),
+
+    #[error("Wake word initialization failed: {0}")]
+    InitFailed(String),
+
+    #[error("Wake word detection failed: {0}")]
+    DetectionFailed(String),
+}
+
 /// Errors related to text output
 #[derive(Error, Debug)]
 pub enum OutputError {
@@ -130,6 +143,9 @@ pub enum OutputError {
     #[error("eitype not found in PATH. Install via: cargo install eitype")]
     EitypeNotFound,
 
+    #[error("xdotool not found in PATH. Install xdotool via your package manager.")]
+    XdotoolNotFound,
+
     #[error("wl-copy not found in PATH. Install wl-clipboard via your package manager.")]
     WlCopyNotFound,
 
@@ -0,0 +1,101 @@
+//! GNOME focus backend (`org.gnome.Shell.Introspect`)
+//!
+//! GNOME Shell only answers introspection calls in unsafe mode or when an
+//! extension allows the caller; otherwise the call is denied and no
+//! profile rule applies.
+
+use super::{FocusBackend, FocusedWindow};
+use std::collections::HashMap;
+use zbus::zvariant::OwnedValue;
+use zbus::Connection;
+
+pub struct Gnome;
+
+#[async_trait::async_trait]
+impl FocusBackend for Gnome {
+    async fn focused_window(&self) -> Result<FocusedWindow, String> {
+        let conn = Connection::session()
+            .await
+            .map_err(|e| format!("session bus: {}", e))?;
+        let reply = conn
+            .call_method(
+                Some("org.gnome.Shell"),
+                "/org/gnome/Shell/Introspect",
+                Some("org.gnome.Shell.Introspect"),
+                "GetWindows",
+                &(),
+            )
+            .await
+            .map_err(|e| format!("GNOME Shell introspection: {}", e))?;
+        let windows: HashMap<u64, HashMap<String, OwnedValue>> = reply
+            .body()
+            .deserialize()
+            .map_err(|e| format!("GNOME Shell introspection: {}", e))?;
+        pick_focused(&windows).ok_or_else(|| "no focused window".to_string())
+    }
+
+    fn name(&self) -> &'static str {
+        "gnome"
+    }
+}
+
+/// The window with `has-focus`, classed by `wm-class` or else `app-id`
+fn pick_focused(windows: &HashMap<u64, HashMap<String, OwnedValue>>) -> Option<FocusedWindow> {
+    let string = |props: &HashMap<String, OwnedValue>, key: &str| {
+        props
+            .get(key)
+            .and_then(|value| String::try_from(value.try_clone().ok()?).ok())
+            .filter(|s| !s.is_empty())
+    };
+    windows.values().find_map(|props| {
+        let focused = props
+            .get("has-focus")
+            .and_then(|value| bool::try_from(value).ok())
+            .unwrap_or(false);
+        if !focused {
+            return None;
+        }
+        Some(FocusedWindow {
+            class: string(props, "wm-class").or_else(|| string(props, "app-id"))?,
+            title: string(props, "title").unwrap_or_default(),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zbus::zvariant::Value;
+
+    fn props(entries: Vec<(&str, Value<'static>)>) -> HashMap<String, OwnedValue> {
+        entries
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), OwnedValue::try_from(v).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_pick_focused() {
+        let windows = HashMap::from([
+            (
+                1,
+                props(vec![
+                    ("has-focus", Value::from(false)),
+                    ("wm-class", Value::from("firefox")),
+                ]),
+            ),
+            (
+                2,
+                props(vec![
+                    ("has-focus", Value::from(true)),
+                    ("wm-class", Value::from("")),
+                    ("app-id", Value::from("org.gnome.TextEditor")),
+                    ("title", Value::from("notes.txt")),
+                ]),
+            ),
+        ]);
+        let window = pick_focused(&windows).unwrap();
+        assert_eq!(window.class, "org.gnome.TextEditor");
+        assert_eq!(window.title, "notes.txt");
+    }
+}
@@ -0,0 +1,149 @@
+//! Hyprland focus backend (`hyprctl activewindow -j`, `hyprctl clients -j`)
+
+use super::{FocusBackend, FocusedWindow, Window};
+use tokio::process::Command;
+
+pub struct Hyprland;
+
+async fn hyprctl(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("hyprctl")
+        .args(args)
+        .output()
+        .await
+        .map_err(|e| format!("hyprctl: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "hyprctl: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[async_trait::async_trait]
+impl FocusBackend for Hyprland {
+    async fn focused_window(&self) -> Result<FocusedWindow, String> {
+        parse(&hyprctl(&["activewindow", "-j"]).await?)
+    }
+
+    async fn windows(&self) -> Result<Vec<Window>, String> {
+        parse_clients(&hyprctl(&["clients", "-j"]).await?)
+    }
+
+    async fn focused_is_xwayland(&self) -> Result<bool, String> {
+        parse_xwayland(&hyprctl(&["activewindow", "-j"]).await?)
+    }
+
+    async fn focus(&self, id: &str) -> Result<(), String> {
+        // Dispatch errors are printed with a zero exit status
+        let reply = hyprctl(&["dispatch", "focuswindow", &format!("address:{}", id)]).await?;
+        match reply.trim() {
+            "ok" => Ok(()),
+            error => Err(format!("hyprctl: {}", error)),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "hyprland"
+    }
+}
+
+/// Parse `hyprctl activewindow -j`, which prints `{}` when nothing has focus
+fn parse(json: &str) -> Result<FocusedWindow, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| format!("hyprctl: {}", e))?;
+    let class = value
+        .get("class")
+        .and_then(|v| v.as_str())
+        .ok_or("no focused window")?;
+    Ok(FocusedWindow {
+        class: class.to_string(),
+        title: value
+            .get("title")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string(),
+    })
+}
+
+/// The `xwayland` flag of `hyprctl activewindow -j`
+fn parse_xwayland(json: &str) -> Result<bool, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| format!("hyprctl: {}", e))?;
+    value
+        .get("xwayland")
+        .and_then(|v| v.as_bool())
+        .ok_or_else(|| "no focused window".to_string())
+}
+
+/// Parse `hyprctl clients -j` into mapped windows, most recently focused
+/// first. `focusHistoryID` 0 is the focused window.
+fn parse_clients(json: &str) -> Result<Vec<Window>, String> {
+    let clients: Vec<serde_json::Value> =
+        serde_json::from_str(json).map_err(|e| format!("hyprctl: {}", e))?;
+    let mut windows: Vec<(i64, Window)> = clients
+        .iter()
+        .filter(|c| c.get("mapped").and_then(|v| v.as_bool()) != Some(false))
+        .filter_map(|c| {
+            let history = c.get("focusHistoryID").and_then(|v| v.as_i64())?;
+            let window = Window {
+                id: c.get("address")?.as_str()?.to_string(),
+                window: FocusedWindow {
+                    class: c.get("class")?.as_str()?.to_string(),
+                    title: c
+                        .get("title")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or_default()
+                        .to_string(),
+                },
+                focused: history == 0,
+            };
+            Some((history, window))
+        })
+        .collect();
+    windows.sort_by_key(|(history, _)| *history);
+    Ok(windows.into_iter().map(|(_, window)| window).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let window = parse(r#"{"address": "0x1", "class": "Slack", "title": "general"}"#).unwrap();
+        assert_eq!(window.class, "Slack");
+        assert_eq!(window.title, "general");
+        assert!(parse("{}").is_err());
+    }
+
+    #[test]
+    fn test_parse_xwayland() {
+        assert_eq!(
+            parse_xwayland(r#"{"class": "steam", "xwayland": true}"#),
+            Ok(true)
+        );
+        assert_eq!(
+            parse_xwayland(r#"{"class": "foot", "xwayland": false}"#),
+            Ok(false)
+        );
+        assert!(parse_xwayland("{}").is_err());
+    }
+
+    #[test]
+    fn test_parse_clients() {
+        let windows = parse_clients(
+            r#"[
+                {"address": "0xa", "mapped": true, "class": "obsidian", "title": "Inbox", "focusHistoryID": 2},
+                {"address": "0xb", "mapped": true, "class": "firefox", "title": "Docs", "focusHistoryID": 0},
+                {"address": "0xc", "mapped": false, "class": "obsidian", "title": "", "focusHistoryID": 1}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows[0].id, "0xb");
+        assert!(windows[0].focused);
+        assert_eq!(windows[1].window.class, "obsidian");
+        assert!(!windows[1].focused);
+    }
+}
@@ -0,0 +1,129 @@
+//! KDE Plasma focus backend
+//!
+//! KWin doesn't expose the active window on D-Bus. Instead this loads a
+//! one-line KWin script that calls back into voxtype with the active
+//! window's class and caption, then unloads it again.
+
+use super::{FocusBackend, FocusedWindow};
+use std::io::Write;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::oneshot;
+use zbus::Connection;
+
+const REPORT_PATH: &str = "/org/voxtype/Focus";
+const REPORT_INTERFACE: &str = "org.voxtype.Focus";
+const PLUGIN_NAME: &str = "voxtype-focus";
+
+/// How long the script gets to report back
+const REPORT_TIMEOUT: Duration = Duration::from_millis(500);
+
+pub struct Kwin;
+
+/// D-Bus object the KWin script reports to
+struct Receiver {
+    tx: Mutex<Option<oneshot::Sender<FocusedWindow>>>,
+}
+
+#[zbus::interface(name = "org.voxtype.Focus")]
+impl Receiver {
+    fn report(&self, class: String, title: String) {
+        if let Some(tx) = self.tx.lock().unwrap_or_else(|e| e.into_inner()).take() {
+            let _ = tx.send(FocusedWindow { class, title });
+        }
+    }
+}
+
+async fn scripting<B>(conn: &Connection, method: &str, body: &B) -> zbus::Result<zbus::Message>
+where
+    B: serde::Serialize + zbus::zvariant::DynamicType,
+{
+    conn.call_method(
+        Some("org.kde.KWin"),
+        "/Scripting",
+        Some("org.kde.kwin.Scripting"),
+        method,
+        body,
+    )
+    .await
+}
+
+#[async_trait::async_trait]
+impl FocusBackend for Kwin {
+    async fn focused_window(&self) -> Result<FocusedWindow, String> {
+        let conn = Connection::session()
+            .await
+            .map_err(|e| format!("session bus: {}", e))?;
+        let (tx, rx) = oneshot::channel();
+        conn.object_server()
+            .at(
+                REPORT_PATH,
+                Receiver {
+                    tx: Mutex::new(Some(tx)),
+                },
+            )
+            .await
+            .map_err(|e| format!("session bus: {}", e))?;
+        let sender = conn
+            .unique_name()
+            .ok_or("session bus: no unique name")?
+            .to_string();
+
+        // Plasma 6 calls it activeWindow, Plasma 5 activeClient
+        let script = format!(
+            "const w = workspace.activeWindow || workspace.activeClient;\n\
+             callDBus(\"{}\", \"{}\", \"{}\", \"Report\", \
+             w ? String(w.resourceClass) : \"\", w ? String(w.caption) : \"\");\n",
+            sender, REPORT_PATH, REPORT_INTERFACE
+        );
+        let mut file = tempfile::Builder::new()
+            .prefix("voxtype-focus")
+            .suffix(".js")
+            .tempfile()
+            .map_err(|e| format!("KWin script: {}", e))?;
+        file.write_all(script.as_bytes())
+            .map_err(|e| format!("KWin script: {}", e))?;
+        let path = file.path().to_string_lossy().to_string();
+
+        // A previous run may have left the script loaded
+        let _ = scripting(&conn, "unloadScript", &(PLUGIN_NAME,)).await;
+        let id: i32 = scripting(&conn, "loadScript", &(path.as_str(), PLUGIN_NAME))
+            .await
+            .and_then(|reply| reply.body().deserialize())
+            .map_err(|e| format!("KWin scripting: {}", e))?;
+
+        // Plasma 6 puts loaded scripts under /Scripting, Plasma 5 at the root
+        let mut ran = false;
+        for script_path in [format!("/Scripting/Script{}", id), format!("/{}", id)] {
+            let run = conn
+                .call_method(
+                    Some("org.kde.KWin"),
+                    script_path.as_str(),
+                    Some("org.kde.kwin.Script"),
+                    "run",
+                    &(),
+                )
+                .await;
+            if run.is_ok() {
+                ran = true;
+                break;
+            }
+        }
+
+        let result = if !ran {
+            Err("KWin did not run the script".to_string())
+        } else {
+            match tokio::time::timeout(REPORT_TIMEOUT, rx).await {
+                Ok(Ok(window)) if !window.class.is_empty() => Ok(window),
+                Ok(Ok(_)) => Err("no focused window".to_string()),
+                _ => Err("KWin script did not report back".to_string()),
+            }
+        };
+        let _ = scripting(&conn, "unloadScript", &(PLUGIN_NAME,)).await;
+        result
+    }
+
+    fn name(&self) -> &'static str {
+        "kwin"
+    }
+}
@@ -0,0 +1,368 @@
+//! Focused window detection
+//!
+//! Finds the application class and title of the focused window when a
+//! recording starts, so the daemon can pick a profile from
+//! `[[profile_rules]]`:
+//!
+//! ```toml
+//! [[profile_rules]]
+//! match = "class:Slack"
+//! profile = "slack"
+//! ```
+//!
+//! There is no cross-desktop way to ask which window has focus, so each
+//! desktop gets a [`FocusBackend`]. [`detect_backend`] picks one from the
+//! session environment:
+//!
+//! - Hyprland: `hyprctl activewindow`
+//! - Sway: `swaymsg -t get_tree`
+//! - KDE Plasma: a short KWin script that reports the active window over D-Bus
+//! - GNOME: `org.gnome.Shell.Introspect` (only allowed in unsafe mode or with
+//!   an extension that grants access)
+//!
+//! Hyprland and Sway can also focus another window, which
+//! `voxtype record start --target "class:obsidian"` uses to type into a
+//! window other than the one in front (see [`focus_target`]).
+
+#[cfg(target_os = "linux")]
+mod gnome;
+mod hyprland;
+#[cfg(target_os = "linux")]
+mod kwin;
+mod sway;
+
+use crate::config::{Profile, ProfileRule};
+use regex::Regex;
+use std::collections::HashMap;
+
+/// The focused window as reported by the desktop
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FocusedWindow {
+    /// Application class or app id ("Slack", "firefox", "org.gnome.Nautilus")
+    pub class: String,
+    /// Window title
+    pub title: String,
+}
+
+/// A window the desktop can focus
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Window {
+    /// Backend-specific handle for [`FocusBackend::focus`]
+    pub id: String,
+    pub window: FocusedWindow,
+    /// Whether the window has focus
+    pub focused: bool,
+}
+
+/// A way to ask one desktop for its focused window
+#[async_trait::async_trait]
+pub trait FocusBackend: Send + Sync {
+    /// The focused window, or why it couldn't be found
+    async fn focused_window(&self) -> Result<FocusedWindow, String>;
+
+    /// All windows that can be focused, most relevant first
+    async fn windows(&self) -> Result<Vec<Window>, String> {
+        Err(format!("{} can't list windows", self.name()))
+    }
+
+    /// Focus the window with `id`, as listed by [`FocusBackend::windows`]
+    async fn focus(&self, id: &str) -> Result<(), String> {
+        let _ = id;
+        Err(format!("{} can't focus windows", self.name()))
+    }
+
+    /// Whether the focused window is an X11 client running under XWayland
+    async fn focused_is_xwayland(&self) -> Result<bool, String> {
+        Err(format!("{} can't tell XWayland windows apart", self.name()))
+    }
+
+    /// Human-readable name for logging
+    fn name(&self) -> &'static str;
+}
+
+/// Focus the first window matching `target`. Returns the id of the window
+/// that had focus before, or `None` when the target already had it.
+pub async fn focus_target(
+    backend: &dyn FocusBackend,
+    target: &WindowTarget,
+) -> Result<Option<String>, String> {
+    let windows = backend.windows().await?;
+    let window = windows
+        .iter()
+        .find(|w| target.matches(&w.window))
+        .ok_or("no window matches")?;
+    if window.focused {
+        return Ok(None);
+    }
+    let previous = windows.iter().find(|w| w.focused).map(|w| w.id.clone());
+    backend.focus(&window.id).await?;
+    Ok(previous)
+}
+
+/// Pick the backend for the running desktop, or `None` when it isn't
+/// supported
+pub fn detect_backend() -> Option<Box<dyn FocusBackend>> {
+    detect_backend_with_env(|name| std::env::var(name).ok())
+}
+
+fn detect_backend_with_env<F>(get: F) -> Option<Box<dyn FocusBackend>>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let set = |name: &str| get(name).is_some_and(|value| !value.is_empty());
+    if set("HYPRLAND_INSTANCE_SIGNATURE") {
+        return Some(Box::new(hyprland::Hyprland));
+    }
+    if set("SWAYSOCK") {
+        return Some(Box::new(sway::Sway));
+    }
+    #[cfg(target_os = "linux")]
+    {
+        // XDG_CURRENT_DESKTOP is a colon-separated list ("ubuntu:GNOME")
+        let desktop = get("XDG_CURRENT_DESKTOP")
+            .unwrap_or_default()
+            .to_uppercase();
+        if desktop.split(':').any(|d| d == "KDE") {
+            return Some(Box::new(kwin::Kwin));
+        }
+        if desktop.split(':').any(|d| d == "GNOME") {
+            return Some(Box::new(gnome::Gnome));
+        }
+    }
+    None
+}
+
+/// Which part of the window a pattern looks at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Class,
+    Title,
+}
+
+/// A window pattern: `class:<regex>`, `title:<regex>`, or a bare class
+/// regex, matched case-insensitively
+#[derive(Debug, Clone)]
+pub struct WindowTarget {
+    field: Field,
+    regex: Regex,
+}
+
+impl WindowTarget {
+    pub fn parse(pattern: &str) -> Result<Self, regex::Error> {
+        let (field, pattern) = match pattern.split_once(':') {
+            Some(("class", pattern)) => (Field::Class, pattern),
+            Some(("title", pattern)) => (Field::Title, pattern),
+            _ => (Field::Class, pattern),
+        };
+        Ok(Self {
+            field,
+            regex: Regex::new(&format!("(?i){}", pattern))?,
+        })
+    }
+
+    pub fn matches(&self, window: &FocusedWindow) -> bool {
+        match self.field {
+            Field::Class => self.regex.is_match(&window.class),
+            Field::Title => self.regex.is_match(&window.title),
+        }
+    }
+}
+
+/// `[[profile_rules]]`, compiled. Rules are tried in order and the first
+/// match wins.
+#[derive(Default)]
+pub struct ProfileMatcher {
+    rules: Vec<(WindowTarget, String)>,
+}
+
+impl ProfileMatcher {
+    /// Compile the rules. Rules with an invalid pattern or an undefined
+    /// profile are skipped with a warning.
+    pub fn new(rules: &[ProfileRule], profiles: &HashMap<String, Profile>) -> Self {
+        let rules = rules
+            .iter()
+            .filter_map(|rule| {
+                if !profiles.contains_key(&rule.profile) {
+                    tracing::warn!(
+                        "Profile rule '{}' references undefined profile '{}' — \
+                         add a [profiles.{}] section to your config",
+                        rule.pattern,
+                        rule.profile,
+                        rule.profile
+                    );
+                    return None;
+                }
+                match WindowTarget::parse(&rule.pattern) {
+                    Ok(target) => Some((target, rule.profile.clone())),
+                    Err(e) => {
+                        tracing::warn!("Invalid profile rule '{}': {}", rule.pattern, e);
+                        None
+                    }
+                }
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// Whether there are no usable rules
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// The profile of the first rule matching `window`
+    pub fn profile_for(&self, window: &FocusedWindow) -> Option<&str> {
+        self.rules
+            .iter()
+            .find(|(target, _)| target.matches(window))
+            .map(|(_, profile)| profile.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pattern: &str, profile: &str) -> ProfileRule {
+        ProfileRule {
+            pattern: pattern.to_string(),
+            profile: profile.to_string(),
+        }
+    }
+
+    fn window(class: &str, title: &str) -> FocusedWindow {
+        FocusedWindow {
+            class: class.to_string(),
+            title: title.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_profile_matcher() {
+        let profiles: HashMap<String, Profile> = ["slack", "git", "code"]
+            .iter()
+            .map(|name| (name.to_string(), Profile::default()))
+            .collect();
+        let matcher = ProfileMatcher::new(
+            &[
+                rule("title:COMMIT_EDITMSG", "git"),
+                rule("class:slack", "slack"),
+                rule("^(code|codium)$", "code"),
+                rule("class:firefox", "missing"),
+                rule("class:(", "code"),
+            ],
+            &profiles,
+        );
+        // The undefined profile and the invalid regex are dropped
+        assert_eq!(matcher.rules.len(), 3);
+
+        assert_eq!(
+            matcher.profile_for(&window("Slack", "general")),
+            Some("slack")
+        );
+        assert_eq!(
+            matcher.profile_for(&window("code", "COMMIT_EDITMSG - repo")),
+            Some("git")
+        );
+        assert_eq!(
+            matcher.profile_for(&window("code", "main.rs")),
+            Some("code")
+        );
+        assert_eq!(matcher.profile_for(&window("vscode", "main.rs")), None);
+        assert!(ProfileMatcher::default().is_empty());
+    }
+
+    /// Windows in a fixed list, recording what gets focused
+    struct FakeDesktop {
+        windows: Vec<Window>,
+        focused: std::sync::Mutex<Option<String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl FocusBackend for FakeDesktop {
+        async fn focused_window(&self) -> Result<FocusedWindow, String> {
+            Err("unused".to_string())
+        }
+
+        async fn windows(&self) -> Result<Vec<Window>, String> {
+            Ok(self.windows.clone())
+        }
+
+        async fn focus(&self, id: &str) -> Result<(), String> {
+            *self.focused.lock().unwrap() = Some(id.to_string());
+            Ok(())
+        }
+
+        fn name(&self) -> &'static str {
+            "fake"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_focus_target() {
+        let desktop = FakeDesktop {
+            windows: vec![
+                Window {
+                    id: "1".to_string(),
+                    window: window("firefox", "Docs"),
+                    focused: true,
+                },
+                Window {
+                    id: "2".to_string(),
+                    window: window("obsidian", "Inbox - Vault"),
+                    focused: false,
+                },
+            ],
+            focused: std::sync::Mutex::new(None),
+        };
+
+        let target = WindowTarget::parse("class:Obsidian").unwrap();
+        assert_eq!(
+            focus_target(&desktop, &target).await,
+            Ok(Some("1".to_string()))
+        );
+        assert_eq!(*desktop.focused.lock().unwrap(), Some("2".to_string()));
+
+        // Already focused: nothing to do or return to
+        let target = WindowTarget::parse("title:^docs$").unwrap();
+        assert_eq!(focus_target(&desktop, &target).await, Ok(None));
+
+        let target = WindowTarget::parse("slack").unwrap();
+        assert!(focus_target(&desktop, &target).await.is_err());
+    }
+
+    #[test]
+    fn test_detect_backend() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(k, _)| *k == name)
+                    .map(|(_, v)| v.to_string())
+            }
+        };
+        let name = |backend: Option<Box<dyn FocusBackend>>| backend.map(|b| b.name());
+
+        assert_eq!(
+            name(detect_backend_with_env(env(&[(
+                "HYPRLAND_INSTANCE_SIGNATURE",
+                "abc"
+            )]))),
+            Some("hyprland")
+        );
+        assert_eq!(
+            name(detect_backend_with_env(env(&[(
+                "SWAYSOCK",
+                "/run/sway.sock"
+            )]))),
+            Some("sway")
+        );
+        assert_eq!(name(detect_backend_with_env(env(&[]))), None);
+        #[cfg(target_os = "linux")]
+        assert_eq!(
+            name(detect_backend_with_env(env(&[(
+                "XDG_CURRENT_DESKTOP",
+                "ubuntu:GNOME"
+            )]))),
+            Some("gnome")
+        );
+    }
+}
@@ -0,0 +1,145 @@
+//! Sway focus backend (`swaymsg -t get_tree`)
+
+use super::{FocusBackend, FocusedWindow, Window};
+use serde_json::Value;
+use tokio::process::Command;
+
+pub struct Sway;
+
+async fn swaymsg(args: &[&str]) -> Result<Vec<u8>, String> {
+    let output = Command::new("swaymsg")
+        .args(args)
+        .output()
+        .await
+        .map_err(|e| format!("swaymsg: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "swaymsg: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(output.stdout)
+}
+
+async fn get_tree() -> Result<Value, String> {
+    let tree = swaymsg(&["-r", "-t", "get_tree"]).await?;
+    serde_json::from_slice(&tree).map_err(|e| format!("swaymsg: {}", e))
+}
+
+#[async_trait::async_trait]
+impl FocusBackend for Sway {
+    async fn focused_window(&self) -> Result<FocusedWindow, String> {
+        find_focused(&get_tree().await?).ok_or_else(|| "no focused window".to_string())
+    }
+
+    async fn windows(&self) -> Result<Vec<Window>, String> {
+        let mut windows = Vec::new();
+        collect_windows(&get_tree().await?, &mut windows);
+        Ok(windows)
+    }
+
+    async fn focused_is_xwayland(&self) -> Result<bool, String> {
+        let tree = get_tree().await?;
+        let node = find_focused_node(&tree).ok_or("no focused window")?;
+        Ok(node.get("shell").and_then(Value::as_str) == Some("xwayland"))
+    }
+
+    async fn focus(&self, id: &str) -> Result<(), String> {
+        swaymsg(&[&format!("[con_id={}]", id), "focus"])
+            .await
+            .map(|_| ())
+    }
+
+    fn name(&self) -> &'static str {
+        "sway"
+    }
+}
+
+/// The class and title of a window node. Native Wayland windows have an
+/// `app_id`, XWayland windows a `window_properties.class`.
+fn window_of(node: &Value) -> Option<FocusedWindow> {
+    let class = node
+        .get("app_id")
+        .and_then(Value::as_str)
+        .or_else(|| node.pointer("/window_properties/class")?.as_str())?;
+    Some(FocusedWindow {
+        class: class.to_string(),
+        title: node
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+    })
+}
+
+fn children(node: &Value) -> impl Iterator<Item = &Value> {
+    ["nodes", "floating_nodes"]
+        .into_iter()
+        .filter_map(|key| node.get(key)?.as_array())
+        .flatten()
+}
+
+/// Find the focused window in a sway tree
+fn find_focused(node: &Value) -> Option<FocusedWindow> {
+    window_of(find_focused_node(node)?)
+}
+
+/// Find the focused node in a sway tree
+fn find_focused_node(node: &Value) -> Option<&Value> {
+    if node.get("focused").and_then(Value::as_bool) == Some(true) {
+        return Some(node);
+    }
+    children(node).find_map(find_focused_node)
+}
+
+/// Every window in a sway tree, in tree order
+fn collect_windows(node: &Value, windows: &mut Vec<Window>) {
+    if let (Some(id), Some(window)) = (node.get("id").and_then(Value::as_i64), window_of(node)) {
+        windows.push(Window {
+            id: id.to_string(),
+            window,
+            focused: node.get("focused").and_then(Value::as_bool) == Some(true),
+        });
+    }
+    for child in children(node) {
+        collect_windows(child, windows);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree() -> Value {
+        serde_json::from_str(
+            r#"{"id": 1, "focused": false, "nodes": [
+                {"id": 2, "focused": false, "nodes": [
+                    {"id": 10, "focused": false, "app_id": "foot", "name": "shell", "nodes": []}
+                ], "floating_nodes": [
+                    {"id": 11, "focused": true, "app_id": null, "name": "Slack | general", "shell": "xwayland",
+                     "window_properties": {"class": "Slack"}, "nodes": []}
+                ]}
+            ]}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_find_focused() {
+        let window = find_focused(&tree()).unwrap();
+        assert_eq!(window.class, "Slack");
+        assert_eq!(window.title, "Slack | general");
+        let tree = tree();
+        let node = find_focused_node(&tree).unwrap();
+        assert_eq!(node.get("shell").and_then(Value::as_str), Some("xwayland"));
+    }
+
+    #[test]
+    fn test_collect_windows() {
+        let mut windows = Vec::new();
+        collect_windows(&tree(), &mut windows);
+        let ids: Vec<_> = windows.iter().map(|w| (w.id.as_str(), w.focused)).collect();
+        assert_eq!(ids, vec![("10", false), ("11", true)]);
+        assert_eq!(windows[0].window.class, "foot");
+    }
+}
@@ -27,6 +27,12 @@ pub struct EvdevListener {
     modifier_keys: HashSet<Key>,
     /// Optional cancel key
     cancel_key: Option<Key>,
+    /// Optional translate toggle key
+    translate_key: Option<Key>,
+    /// Optional undo key
+    undo_key: Option<Key>,
+    /// Optional meeting bookmark key
+    meeting_mark_key: Option<Key>,
     /// Optional model modifier key (when held, use secondary model)
     model_modifier: Option<Key>,
     /// Secondary model to use when model_modifier is held
@@ -55,6 +61,27 @@ impl EvdevListener {
             .map(|k| parse_key_name(k))
             .transpose()?;
 
+        // Parse optional translate toggle key
+        let translate_key = config
+            .translate_key
+            .as_ref()
+            .map(|k| parse_key_name(k))
+            .transpose()?;
+
+        // Parse optional undo key
+        let undo_key = config
+            .undo_key
+            .as_ref()
+            .map(|k| parse_key_name(k))
+            .transpose()?;
+
+        // Parse optional meeting bookmark key
+        let meeting_mark_key = config
+            .meeting_mark_key
+            .as_ref()
+            .map(|k| parse_key_name(k))
+            .transpose()?;
+
         // Parse optional model modifier key
         let model_modifier = config
             .model_modifier
@@ -97,6 +124,9 @@ impl EvdevListener {
             target_key,
             modifier_keys,
             cancel_key,
+            translate_key,
+            undo_key,
+            meeting_mark_key,
             model_modifier,
             secondary_model: None, // Set later via set_secondary_model
             profile_modifiers,
@@ -119,6 +149,9 @@ impl HotkeyListener for EvdevListener {
         let target_key = self.target_key;
         let modifier_keys = self.modifier_keys.clone();
         let cancel_key = self.cancel_key;
+        let translate_key = self.translate_key;
+        let undo_key = self.undo_key;
+        let meeting_mark_key = self.meeting_mark_key;
         let model_modifier = self.model_modifier;
         let secondary_model = self.secondary_model.clone();
         let profile_modifiers = self.profile_modifiers.clone();
@@ -129,6 +162,9 @@ impl HotkeyListener for EvdevListener {
                 target_key,
                 modifier_keys,
                 cancel_key,
+                translate_key,
+                undo_key,
+                meeting_mark_key,
                 model_modifier,
                 secondary_model,
                 profile_modifiers,
@@ -396,6 +432,9 @@ fn evdev_listener_loop(
     target_key: Key,
     modifier_keys: HashSet<Key>,
     cancel_key: Option<Key>,
+    translate_key: Option<Key>,
+    undo_key: Option<Key>,
+    meeting_mark_key: Option<Key>,
     model_modifier: Option<Key>,
     secondary_model: Option<String>,
     profile_modifiers: HashMap<Key, String>,
@@ -444,6 +483,18 @@ fn evdev_listener_loop(
         }
     }
 
+    if let Some(translate) = translate_key {
+        tracing::info!("Translate toggle key {:?} configured", translate);
+    }
+
+    if let Some(undo) = undo_key {
+        tracing::info!("Undo key {:?} configured", undo);
+    }
+
+    if let Some(mark) = meeting_mark_key {
+        tracing::info!("Meeting mark key {:?} configured", mark);
+    }
+
     loop {
         // Check for stop signal (non-blocking)
         match stop_rx.try_recv() {
@@ -544,6 +595,39 @@ fn evdev_listener_loop(
                 }
             }
 
+            // Check translate toggle key (if configured)
+            if let Some(translate) = translate_key {
+                if key == translate && value == 1 {
+                    tracing::debug!("Translate key pressed");
+                    if tx.blocking_send(HotkeyEvent::ToggleTranslate).is_err() {
+                        return Ok(()); // Channel closed
+                    }
+                    continue;
+                }
+            }
+
+            // Check undo key (if configured)
+            if let Some(undo) = undo_key {
+                if key == undo && value == 1 {
+                    tracing::debug!("Undo key pressed");
+                    if tx.blocking_send(HotkeyEvent::Undo).is_err() {
+                        return Ok(()); // Channel closed
+                    }
+                    continue;
+                }
+            }
+
+            // Check meeting mark key (if configured)
+            if let Some(mark) = meeting_mark_key {
+                if key == mark && value == 1 {
+                    tracing::debug!("Meeting mark key pressed");
+                    if tx.blocking_send(HotkeyEvent::MeetingMark).is_err() {
+                        return Ok(()); // Channel closed
+                    }
+                    continue;
+                }
+            }
+
             // Check target key
             if key == target_key {
                 let modifiers_satisfied =
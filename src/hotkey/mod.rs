@@ -26,6 +26,12 @@ pub enum HotkeyEvent {
     Released,
     /// The cancel key was pressed (abort recording/transcription)
     Cancel,
+    /// The translate key was pressed (flip translate-to-English)
+    ToggleTranslate,
+    /// The undo key was pressed (remove the last typed output)
+    Undo,
+    /// The meeting mark key was pressed (bookmark the current meeting)
+    MeetingMark,
 }
 
 /// Trait for hotkey detection implementations
@@ -42,6 +42,9 @@ pub trait HotkeyListener: Send {
 pub struct RdevHotkeyListener {
     target_key: Key,
     cancel_key: Option<Key>,
+    translate_key: Option<Key>,
+    undo_key: Option<Key>,
+    meeting_mark_key: Option<Key>,
     running: Arc<AtomicBool>,
     thread_handle: Option<std::thread::JoinHandle<()>>,
 }
@@ -53,10 +56,22 @@ impl RdevHotkeyListener {
             .ok_or_else(|| HotkeyError::UnknownKey(config.key.clone()))?;
 
         let cancel_key = config.cancel_key.as_ref().and_then(|k| parse_key_name(k));
+        let translate_key = config
+            .translate_key
+            .as_ref()
+            .and_then(|k| parse_key_name(k));
+        let undo_key = config.undo_key.as_ref().and_then(|k| parse_key_name(k));
+        let meeting_mark_key = config
+            .meeting_mark_key
+            .as_ref()
+            .and_then(|k| parse_key_name(k));
 
         Ok(Self {
             target_key,
             cancel_key,
+            translate_key,
+            undo_key,
+            meeting_mark_key,
             running: Arc::new(AtomicBool::new(false)),
             thread_handle: None,
         })
@@ -78,6 +93,9 @@ impl HotkeyListener for RdevHotkeyListener {
         let (tx, rx) = mpsc::channel(32);
         let target_key = self.target_key;
         let cancel_key = self.cancel_key;
+        let translate_key = self.translate_key;
+        let undo_key = self.undo_key;
+        let meeting_mark_key = self.meeting_mark_key;
         let running = self.running.clone();
         running.store(true, Ordering::SeqCst);
 
@@ -145,6 +163,12 @@ impl HotkeyListener for RdevHotkeyListener {
                             }
                         } else if Some(key) == cancel_key {
                             let _ = tx_clone.blocking_send(HotkeyEvent::Cancel);
+                        } else if Some(key) == translate_key {
+                            let _ = tx_clone.blocking_send(HotkeyEvent::ToggleTranslate);
+                        } else if Some(key) == undo_key {
+                            let _ = tx_clone.blocking_send(HotkeyEvent::Undo);
+                        } else if Some(key) == meeting_mark_key {
+                            let _ = tx_clone.blocking_send(HotkeyEvent::MeetingMark);
                         }
                     }
                     EventType::KeyRelease(key) => {
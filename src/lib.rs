@@ -77,6 +77,7 @@ pub mod daemon;
 pub mod daemon_status;
 pub mod eager;
 pub mod error;
+pub mod focus;
 #[cfg(target_os = "linux")]
 pub mod hotkey;
 #[cfg(target_os = "macos")]
@@ -85,6 +86,8 @@ pub mod meeting;
 #[cfg(target_os = "macos")]
 pub mod menubar;
 pub mod model_manager;
+pub mod mqtt;
+pub mod network;
 pub mod notification;
 pub mod osd;
 pub mod output;
@@ -95,10 +98,11 @@ pub mod text;
 pub mod transcribe;
 pub mod tui;
 pub mod vad;
+pub mod wake_word;
 
 pub use cli::{
-    Cli, Commands, CompositorType, ConfigAction, ConfigSetKey, InfoAction, MeetingAction,
-    OutputModeOverride, RecordAction, SetupAction,
+    BatchFormat, Cli, Commands, CompositorType, ConfigAction, ConfigSetKey, InfoAction,
+    MeetingAction, OutputModeOverride, RecordAction, SetupAction, SpeakersAction, TranslateState,
 };
 pub use config::Config;
 pub use daemon::Daemon;
@@ -0,0 +1,774 @@
+//! Calendar lookup for meeting titles and attendees
+//!
+//! When a meeting starts without `--title`, the daemon asks the configured
+//! calendar (`[meeting.calendar]`) for the event happening now and uses its
+//! title and attendees. Two sources are supported:
+//!
+//! - **ics**: ICS files or URLs (a CalDAV server's export link, Google's
+//!   secret iCal address, ...). Daily, weekly, monthly and yearly
+//!   recurrences are expanded, including exceptions. Times with a TZID are
+//!   read as local time.
+//! - **khal**: asks `khal list` for events starting within the next
+//!   minutes, so khal's own vdirsyncer setup handles CalDAV.
+//!
+//! All-day and cancelled events are ignored.
+
+use crate::config::MeetingCalendarConfig;
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday};
+use std::collections::{HashMap, HashSet};
+use thiserror::Error;
+
+/// Calendar lookup errors
+#[derive(Error, Debug)]
+pub enum CalendarError {
+    #[error("Unknown calendar source '{0}' (expected \"ics\" or \"khal\")")]
+    UnknownSource(String),
+
+    #[error("Failed to read {0}: {1}")]
+    Read(String, String),
+
+    #[error("khal failed: {0}")]
+    Khal(String),
+
+    #[error("Calendar lookup timed out after {0}s")]
+    Timeout(u64),
+}
+
+/// The calendar event a meeting belongs to
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarEvent {
+    pub title: String,
+    /// Organizer and attendees, by display name where the calendar has one
+    pub attendees: Vec<String>,
+    /// Start and end in local time, when the source reports them
+    pub start: Option<NaiveDateTime>,
+    pub end: Option<NaiveDateTime>,
+}
+
+/// Look up the event happening now (or starting within `early_mins`).
+/// Returns `Ok(None)` when the lookup is disabled or nothing matches.
+pub async fn current_event(
+    config: &MeetingCalendarConfig,
+) -> Result<Option<CalendarEvent>, CalendarError> {
+    let lookup = async {
+        match config.source.as_str() {
+            "disabled" | "" => Ok(None),
+            "ics" => {
+                let config = config.clone();
+                tokio::task::spawn_blocking(move || ics_current_event(&config))
+                    .await
+                    .map_err(|e| CalendarError::Read("calendar".to_string(), e.to_string()))?
+            }
+            "khal" => khal_current_event(config).await,
+            other => Err(CalendarError::UnknownSource(other.to_string())),
+        }
+    };
+    tokio::time::timeout(std::time::Duration::from_secs(config.timeout_secs), lookup)
+        .await
+        .map_err(|_| CalendarError::Timeout(config.timeout_secs))?
+}
+
+fn ics_current_event(
+    config: &MeetingCalendarConfig,
+) -> Result<Option<CalendarEvent>, CalendarError> {
+    let mut events = Vec::new();
+    for source in &config.ics {
+        let text = read_ics(source, config.timeout_secs)?;
+        events.extend(parse_ics(&text));
+    }
+    let now = Local::now().naive_local();
+    Ok(find_event(
+        &events,
+        now,
+        Duration::minutes(config.early_mins as i64),
+    ))
+}
+
+/// Read an ICS file or download it
+fn read_ics(source: &str, timeout_secs: u64) -> Result<String, CalendarError> {
+    let err = |e: &dyn std::fmt::Display| CalendarError::Read(source.to_string(), e.to_string());
+
+    let url = match source.strip_prefix("webcal://") {
+        Some(rest) => Some(format!("https://{}", rest)),
+        None if source.starts_with("http://") || source.starts_with("https://") => {
+            Some(source.to_string())
+        }
+        None => None,
+    };
+    match url {
+        Some(url) => ureq::get(&url)
+            .timeout(std::time::Duration::from_secs(timeout_secs))
+            .call()
+            .map_err(|e| err(&e))?
+            .into_string()
+            .map_err(|e| err(&e)),
+        None => std::fs::read_to_string(super::expand_home(source)).map_err(|e| err(&e)),
+    }
+}
+
+/// A VEVENT with the properties the lookup needs. Times are local.
+#[derive(Debug, Clone, Default)]
+struct IcsEvent {
+    uid: Option<String>,
+    summary: String,
+    start: Option<NaiveDateTime>,
+    end: Option<NaiveDateTime>,
+    duration: Option<Duration>,
+    all_day: bool,
+    cancelled: bool,
+    organizer: Option<String>,
+    attendees: Vec<String>,
+    rrule: Option<String>,
+    exdates: Vec<NaiveDateTime>,
+    recurrence_id: Option<NaiveDateTime>,
+}
+
+impl IcsEvent {
+    fn length(&self) -> Duration {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) if end > start => end - start,
+            _ => self.duration.unwrap_or_else(|| Duration::hours(1)),
+        }
+    }
+
+    fn people(&self) -> Vec<String> {
+        let mut people: Vec<String> = Vec::new();
+        for person in self.organizer.iter().chain(&self.attendees) {
+            if !people.contains(person) {
+                people.push(person.clone());
+            }
+        }
+        people
+    }
+}
+
+/// Parse the VEVENTs of an ICS document
+fn parse_ics(text: &str) -> Vec<IcsEvent> {
+    // Unfold continuation lines (RFC 5545 3.1)
+    let mut lines: Vec<String> = Vec::new();
+    for raw in text.lines() {
+        match raw.strip_prefix([' ', '\t']) {
+            Some(rest) if !lines.is_empty() => lines.last_mut().unwrap().push_str(rest),
+            _ => lines.push(raw.to_string()),
+        }
+    }
+
+    let mut events = Vec::new();
+    let mut current: Option<IcsEvent> = None;
+    // Nested components (VALARM) have their own properties
+    let mut depth = 0;
+    for line in &lines {
+        let Some((name, params, value)) = split_property(line) else {
+            continue;
+        };
+        match (name.as_str(), value) {
+            ("BEGIN", "VEVENT") => {
+                current = Some(IcsEvent::default());
+                depth = 0;
+                continue;
+            }
+            ("END", "VEVENT") => {
+                events.extend(current.take());
+                continue;
+            }
+            ("BEGIN", _) if current.is_some() => depth += 1,
+            ("END", _) if current.is_some() => depth -= 1,
+            _ => {}
+        }
+        let Some(event) = current.as_mut().filter(|_| depth == 0) else {
+            continue;
+        };
+
+        match name.as_str() {
+            "UID" => event.uid = Some(value.to_string()),
+            "SUMMARY" => event.summary = unescape(value),
+            "DTSTART" => {
+                event.all_day = value.len() == 8 || param(&params, "VALUE") == Some("DATE");
+                event.start = parse_time(value);
+            }
+            "DTEND" => event.end = parse_time(value),
+            "DURATION" => event.duration = parse_duration(value),
+            "STATUS" => event.cancelled = value.eq_ignore_ascii_case("CANCELLED"),
+            "ORGANIZER" => event.organizer = person(&params, value),
+            "ATTENDEE" => {
+                let resource = matches!(param(&params, "CUTYPE"), Some("ROOM" | "RESOURCE"));
+                if !resource {
+                    event.attendees.extend(person(&params, value));
+                }
+            }
+            "RRULE" => event.rrule = Some(value.to_string()),
+            "EXDATE" => event
+                .exdates
+                .extend(value.split(',').filter_map(parse_time)),
+            "RECURRENCE-ID" => event.recurrence_id = parse_time(value),
+            _ => {}
+        }
+    }
+    events
+}
+
+/// Property parameters as (NAME, value) pairs
+type Params = Vec<(String, String)>;
+
+/// Split `NAME;PARAM=x;PARAM="y:z":value`
+fn split_property(line: &str) -> Option<(String, Params, &str)> {
+    let mut in_quotes = false;
+    let colon = line.char_indices().find_map(|(i, c)| match c {
+        '"' => {
+            in_quotes = !in_quotes;
+            None
+        }
+        ':' if !in_quotes => Some(i),
+        _ => None,
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+
+    let mut parts = Vec::new();
+    let mut part = String::new();
+    in_quotes = false;
+    for c in head.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ';' if !in_quotes => parts.push(std::mem::take(&mut part)),
+            _ => part.push(c),
+        }
+    }
+    parts.push(part);
+
+    let name = parts.remove(0).to_ascii_uppercase();
+    let params = parts
+        .into_iter()
+        .filter_map(|p| {
+            let (k, v) = p.split_once('=')?;
+            Some((k.to_ascii_uppercase(), v.to_string()))
+        })
+        .collect();
+    Some((name, params, value.trim_end()))
+}
+
+fn param<'a>(params: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|(k, _)| k == name)
+        .map(|(_, v)| v.as_str())
+}
+
+/// Display name of an ORGANIZER/ATTENDEE: its CN, or the mail address
+fn person(params: &[(String, String)], value: &str) -> Option<String> {
+    let name = match param(params, "CN") {
+        Some(cn) if !cn.trim().is_empty() => cn.trim().to_string(),
+        _ => {
+            let address = value.trim();
+            address
+                .strip_prefix("mailto:")
+                .or_else(|| address.strip_prefix("MAILTO:"))
+                .unwrap_or(address)
+                .to_string()
+        }
+    };
+    (!name.is_empty()).then_some(name)
+}
+
+/// Undo TEXT escaping; line breaks become spaces
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push(' '),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out.trim().to_string()
+}
+
+/// Parse a DATE-TIME as local time. UTC times are converted; times with a
+/// TZID and floating times are taken as local. Dates parse as midnight.
+fn parse_time(value: &str) -> Option<NaiveDateTime> {
+    let value = value.trim();
+    if let Some(utc) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(
+            Utc.from_utc_datetime(&naive)
+                .with_timezone(&Local)
+                .naive_local(),
+        );
+    }
+    NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y%m%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+        })
+}
+
+/// Parse a DURATION such as `PT1H30M` or `P1D`
+fn parse_duration(value: &str) -> Option<Duration> {
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let mut rest = value.strip_prefix('P')?;
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    while let Some(c) = rest.chars().next() {
+        rest = &rest[c.len_utf8()..];
+        match c {
+            '0'..='9' => number.push(c),
+            'T' => {}
+            'W' | 'D' | 'H' | 'M' | 'S' => {
+                let n: i64 = std::mem::take(&mut number).parse().ok()?;
+                total += match c {
+                    'W' => Duration::weeks(n),
+                    'D' => Duration::days(n),
+                    'H' => Duration::hours(n),
+                    'M' => Duration::minutes(n),
+                    _ => Duration::seconds(n),
+                };
+            }
+            _ => return None,
+        }
+    }
+    Some(if negative { -total } else { total })
+}
+
+/// Parsed RRULE parts the expansion supports
+#[derive(Debug)]
+struct Rule {
+    freq: String,
+    interval: i64,
+    count: Option<u32>,
+    until: Option<NaiveDateTime>,
+    /// (ordinal, weekday); the ordinal is only used by MONTHLY rules
+    by_day: Vec<(Option<i32>, Weekday)>,
+    by_month_day: Vec<u32>,
+}
+
+impl Rule {
+    fn parse(rrule: &str) -> Option<Self> {
+        let parts: HashMap<&str, &str> = rrule
+            .split(';')
+            .filter_map(|part| part.split_once('='))
+            .collect();
+        let by_day = parts
+            .get("BYDAY")
+            .map(|days| {
+                days.split(',')
+                    .filter_map(|day| {
+                        let split = day.len().checked_sub(2)?;
+                        let weekday = match &day[split..] {
+                            "MO" => Weekday::Mon,
+                            "TU" => Weekday::Tue,
+                            "WE" => Weekday::Wed,
+                            "TH" => Weekday::Thu,
+                            "FR" => Weekday::Fri,
+                            "SA" => Weekday::Sat,
+                            "SU" => Weekday::Sun,
+                            _ => return None,
+                        };
+                        let ordinal = day[..split].trim_start_matches('+').parse().ok();
+                        Some((ordinal, weekday))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(Self {
+            freq: parts.get("FREQ")?.to_string(),
+            interval: parts
+                .get("INTERVAL")
+                .and_then(|i| i.parse().ok())
+                .filter(|i| *i > 0)
+                .unwrap_or(1),
+            count: parts.get("COUNT").and_then(|c| c.parse().ok()),
+            until: parts.get("UNTIL").and_then(|u| parse_time(u)),
+            by_day,
+            by_month_day: parts
+                .get("BYMONTHDAY")
+                .map(|d| d.split(',').filter_map(|d| d.parse().ok()).collect())
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Whether the series starting at `first` has an occurrence on `day`,
+    /// ignoring COUNT and UNTIL
+    fn occurs_on(&self, first: NaiveDate, day: NaiveDate) -> bool {
+        if day < first {
+            return false;
+        }
+        let weekday_ok = |ordinal_allowed: bool| {
+            self.by_day.iter().any(|&(ordinal, weekday)| {
+                weekday == day.weekday()
+                    && match ordinal.filter(|_| ordinal_allowed) {
+                        None => true,
+                        Some(n) if n > 0 => (day.day() as i32 - 1) / 7 + 1 == n,
+                        Some(n) => {
+                            let days_left = days_in_month(day) as i32 - day.day() as i32;
+                            days_left / 7 + 1 == -n
+                        }
+                    }
+            })
+        };
+
+        match self.freq.as_str() {
+            "DAILY" => {
+                (day - first).num_days() % self.interval == 0
+                    && (self.by_day.is_empty() || weekday_ok(false))
+            }
+            "WEEKLY" => {
+                let week = |d: NaiveDate| {
+                    d.num_days_from_ce() as i64 - d.weekday().num_days_from_monday() as i64
+                };
+                (week(day) - week(first)) / 7 % self.interval == 0
+                    && if self.by_day.is_empty() {
+                        day.weekday() == first.weekday()
+                    } else {
+                        weekday_ok(false)
+                    }
+            }
+            "MONTHLY" => {
+                let months = (day.year() - first.year()) as i64 * 12 + day.month() as i64
+                    - first.month() as i64;
+                months % self.interval == 0
+                    && if !self.by_day.is_empty() {
+                        weekday_ok(true)
+                    } else if !self.by_month_day.is_empty() {
+                        self.by_month_day.contains(&day.day())
+                    } else {
+                        day.day() == first.day()
+                    }
+            }
+            "YEARLY" => {
+                (day.year() - first.year()) as i64 % self.interval == 0
+                    && day.month() == first.month()
+                    && day.day() == first.day()
+            }
+            _ => false,
+        }
+    }
+}
+
+fn days_in_month(day: NaiveDate) -> u32 {
+    let (year, month) = if day.month() == 12 {
+        (day.year() + 1, 1)
+    } else {
+        (day.year(), day.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|next| next.pred_opt())
+        .map(|last| last.day())
+        .unwrap_or(31)
+}
+
+/// Start times of `event` that may overlap `now`: the event itself, or
+/// the occurrences of its recurrence from yesterday to tomorrow
+fn occurrences(event: &IcsEvent, now: NaiveDateTime) -> Vec<NaiveDateTime> {
+    let Some(start) = event.start else {
+        return Vec::new();
+    };
+    let Some(rule) = event.rrule.as_deref().and_then(Rule::parse) else {
+        return vec![start];
+    };
+
+    let first = start.date();
+    let mut found = Vec::new();
+    for offset in -1..=1 {
+        let day = now.date() + Duration::days(offset);
+        if !rule.occurs_on(first, day) {
+            continue;
+        }
+        let occurrence = day.and_time(start.time());
+        if rule.until.is_some_and(|until| occurrence > until) {
+            continue;
+        }
+        if let Some(count) = rule.count {
+            // Occurrences up to this one, counting from the first
+            let index = first
+                .iter_days()
+                .take_while(|d| *d <= day)
+                .filter(|d| rule.occurs_on(first, *d))
+                .count();
+            if index > count as usize {
+                continue;
+            }
+        }
+        if !event.exdates.contains(&occurrence) {
+            found.push(occurrence);
+        }
+    }
+    found
+}
+
+/// The event overlapping `now` (or starting within `early`) whose start is
+/// closest to `now`
+fn find_event(events: &[IcsEvent], now: NaiveDateTime, early: Duration) -> Option<CalendarEvent> {
+    // Occurrences moved or changed individually replace the series' one
+    let overridden: HashSet<(&str, NaiveDateTime)> = events
+        .iter()
+        .filter_map(|e| Some((e.uid.as_deref()?, e.recurrence_id?)))
+        .collect();
+
+    events
+        .iter()
+        .filter(|e| !e.all_day && !e.cancelled && !e.summary.is_empty())
+        .flat_map(|event| {
+            occurrences(event, now)
+                .into_iter()
+                .filter(|start| {
+                    event.recurrence_id.is_some()
+                        || event
+                            .uid
+                            .as_deref()
+                            .is_none_or(|uid| !overridden.contains(&(uid, *start)))
+                })
+                .map(move |start| (event, start, start + event.length()))
+        })
+        .filter(|&(_, start, end)| start - early <= now && now < end)
+        .min_by_key(|&(_, start, _)| (start - now).num_seconds().abs())
+        .map(|(event, start, end)| CalendarEvent {
+            title: event.summary.clone(),
+            attendees: event.people(),
+            start: Some(start),
+            end: Some(end),
+        })
+}
+
+/// Fields requested from khal, tab separated
+const KHAL_FORMAT: &str = "{all-day}\t{cancelled}\t{title}\t{organizer}\t{attendees}";
+
+async fn khal_current_event(
+    config: &MeetingCalendarConfig,
+) -> Result<Option<CalendarEvent>, CalendarError> {
+    let mut cmd = tokio::process::Command::new("khal");
+    cmd.args(["list", "--day-format", "", "--format", KHAL_FORMAT]);
+    for calendar in &config.khal_calendars {
+        cmd.arg("-a").arg(calendar);
+    }
+    // Events running now or starting within early_mins
+    cmd.arg("now").arg(format!("{}m", config.early_mins.max(1)));
+    cmd.kill_on_drop(true);
+
+    let output = cmd.output().await.map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound {
+            CalendarError::Khal("khal is not installed".to_string())
+        } else {
+            CalendarError::Khal(e.to_string())
+        }
+    })?;
+    if !output.status.success() {
+        return Err(CalendarError::Khal(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(parse_khal(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// First timed, not cancelled event in `khal list` output
+fn parse_khal(output: &str) -> Option<CalendarEvent> {
+    output.lines().find_map(|line| {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [all_day, cancelled, title, organizer, attendees] = fields[..] else {
+            return None;
+        };
+        if all_day.trim() == "True" || !cancelled.trim().is_empty() || title.trim().is_empty() {
+            return None;
+        }
+
+        let mut people: Vec<String> = Vec::new();
+        for person in std::iter::once(organizer).chain(attendees.split(", ")) {
+            // "Name <mail>" or a bare address
+            let person = person.trim();
+            let name = match person.split_once(" <") {
+                Some((name, _)) => name.trim(),
+                None => person.trim_start_matches("mailto:"),
+            };
+            if !name.is_empty() && !people.iter().any(|p| p == name) {
+                people.push(name.to_string());
+            }
+        }
+        Some(CalendarEvent {
+            title: title.trim().to_string(),
+            attendees: people,
+            start: None,
+            end: None,
+        })
+    })
+}
+
+/// "10:00–11:00" for events with known times
+pub fn format_event_time(event: &CalendarEvent) -> Option<String> {
+    Some(format!(
+        "{}–{}",
+        event.start?.format("%H:%M"),
+        event.end?.format("%H:%M")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn lookup(ics: &str, now: &str) -> Option<CalendarEvent> {
+        find_event(&parse_ics(ics), at(now), Duration::minutes(10))
+    }
+
+    const CALENDAR: &str = "BEGIN:VCALENDAR\r
+BEGIN:VEVENT\r
+UID:planning\r
+SUMMARY:Sprint planning\\, Q3\r
+DTSTART;TZID=Europe/Berlin:20260302T100000\r
+DTEND;TZID=Europe/Berlin:20260302T110000\r
+ORGANIZER;CN=Alice Example:mailto:alice@example.com\r
+ATTENDEE;CN=\"Bob; the builder\";PARTSTAT=ACCEPTED:mailto:bob@examp\r
+ le.com\r
+ATTENDEE:mailto:carol@example.com\r
+ATTENDEE;CUTYPE=ROOM;CN=Room 4:mailto:room4@example.com\r
+ATTENDEE;CN=Alice Example:mailto:alice@example.com\r
+BEGIN:VALARM\r
+DESCRIPTION:Reminder\r
+END:VALARM\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+SUMMARY:Holiday\r
+DTSTART;VALUE=DATE:20260302\r
+DTEND;VALUE=DATE:20260303\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+SUMMARY:Cancelled sync\r
+STATUS:CANCELLED\r
+DTSTART:20260302T100000\r
+DURATION:PT30M\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+    #[test]
+    fn test_ics_event_now() {
+        let event = lookup(CALENDAR, "2026-03-02 10:20").unwrap();
+        assert_eq!(event.title, "Sprint planning, Q3");
+        assert_eq!(
+            event.attendees,
+            ["Alice Example", "Bob; the builder", "carol@example.com"]
+        );
+        assert_eq!(event.start, Some(at("2026-03-02 10:00")));
+
+        // Joining a few minutes early
+        assert!(lookup(CALENDAR, "2026-03-02 09:52").is_some());
+        assert!(lookup(CALENDAR, "2026-03-02 09:40").is_none());
+        // All-day and cancelled events don't count
+        assert!(lookup(CALENDAR, "2026-03-02 11:00").is_none());
+    }
+
+    #[test]
+    fn test_closest_start_wins() {
+        let ics = "BEGIN:VEVENT
+SUMMARY:Long workshop
+DTSTART:20260302T090000
+DTEND:20260302T120000
+END:VEVENT
+BEGIN:VEVENT
+SUMMARY:1:1
+DTSTART:20260302T103000
+DURATION:PT30M
+END:VEVENT
+";
+        assert_eq!(lookup(ics, "2026-03-02 10:25").unwrap().title, "1:1");
+        assert_eq!(
+            lookup(ics, "2026-03-02 10:00").unwrap().title,
+            "Long workshop"
+        );
+    }
+
+    #[test]
+    fn test_weekly_recurrence() {
+        // Mon/Wed/Fri standup, every second week, with one day skipped and
+        // one moved
+        let ics = "BEGIN:VEVENT
+UID:standup
+SUMMARY:Standup
+DTSTART:20260302T093000
+DTEND:20260302T094500
+RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE,FR;UNTIL=20260430T000000
+EXDATE:20260316T093000
+END:VEVENT
+BEGIN:VEVENT
+UID:standup
+RECURRENCE-ID:20260318T093000
+SUMMARY:Standup (moved)
+DTSTART:20260318T140000
+DTEND:20260318T141500
+END:VEVENT
+";
+        assert!(lookup(ics, "2026-03-04 09:35").is_some()); // Wednesday, week 1
+        assert!(lookup(ics, "2026-03-03 09:35").is_none()); // Tuesday
+        assert!(lookup(ics, "2026-03-09 09:35").is_none()); // off week
+        assert!(lookup(ics, "2026-03-16 09:35").is_none()); // excluded
+        assert!(lookup(ics, "2026-03-18 09:35").is_none()); // moved away...
+        assert_eq!(
+            lookup(ics, "2026-03-18 14:05").unwrap().title,
+            "Standup (moved)"
+        );
+        assert!(lookup(ics, "2026-03-20 09:35").is_some());
+        assert!(lookup(ics, "2026-05-11 09:35").is_none()); // after UNTIL
+    }
+
+    #[test]
+    fn test_daily_and_monthly_recurrence() {
+        let ics = "BEGIN:VEVENT
+SUMMARY:Check-in
+DTSTART:20260302T160000
+DTEND:20260302T161500
+RRULE:FREQ=DAILY;COUNT=3
+END:VEVENT
+BEGIN:VEVENT
+SUMMARY:All hands
+DTSTART:20260302T130000
+DTEND:20260302T140000
+RRULE:FREQ=MONTHLY;BYDAY=1MO
+END:VEVENT
+BEGIN:VEVENT
+SUMMARY:Retro
+DTSTART:20260327T150000
+DTEND:20260327T160000
+RRULE:FREQ=MONTHLY;BYDAY=-1FR
+END:VEVENT
+";
+        assert!(lookup(ics, "2026-03-04 16:05").is_some());
+        assert!(lookup(ics, "2026-03-05 16:05").is_none()); // COUNT=3
+        assert_eq!(lookup(ics, "2026-04-06 13:10").unwrap().title, "All hands");
+        assert!(lookup(ics, "2026-04-13 13:10").is_none());
+        assert_eq!(lookup(ics, "2026-04-24 15:10").unwrap().title, "Retro");
+        assert!(lookup(ics, "2026-04-17 15:10").is_none());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("PT1H30M"), Some(Duration::minutes(90)));
+        assert_eq!(parse_duration("P1DT2H"), Some(Duration::hours(26)));
+        assert_eq!(parse_duration("P2W"), Some(Duration::weeks(2)));
+        assert_eq!(parse_duration("-PT15M"), Some(Duration::minutes(-15)));
+        assert_eq!(parse_duration("1H"), None);
+    }
+
+    #[test]
+    fn test_parse_khal() {
+        let output = "True\t\tHoliday\t\t\n\
+                      False\tCANCELLED\tOld sync\t\t\n\
+                      False\t\tDesign review\tAlice <alice@example.com>\tAlice <alice@example.com>, bob@example.com\n\
+                      False\t\tLater\t\t\n";
+        let event = parse_khal(output).unwrap();
+        assert_eq!(event.title, "Design review");
+        assert_eq!(event.attendees, ["Alice", "bob@example.com"]);
+        assert!(parse_khal("\n").is_none());
+    }
+}
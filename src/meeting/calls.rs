@@ -0,0 +1,257 @@
+//! Call detection for `[meeting.auto_start]`
+//!
+//! A call is on while a conferencing app records from the microphone. The
+//! daemon polls the PipeWire/PulseAudio recording streams (`pactl list
+//! source-outputs`) and matches each stream's application name and binary
+//! against `apps`. Zoom, Teams, and browser calls (Meet, Jitsi) all show
+//! up this way, without any desktop-specific window inspection.
+//!
+//! [`CallTracker`] turns the polls into call start and end events. A call
+//! ends only after no matching stream was seen for `end_grace_secs`, so
+//! switching microphones or a reconnecting app doesn't split a meeting.
+
+use crate::config::MeetingAutoStartConfig;
+use regex::Regex;
+use std::time::{Duration, Instant};
+
+/// An application recording from an input device
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecordingApp {
+    /// `application.name` ("ZOOM VoiceEngine", "Firefox")
+    pub name: String,
+    /// `application.process.binary` ("zoom", "firefox")
+    pub binary: String,
+    /// `application.process.id`
+    pub pid: Option<u32>,
+}
+
+impl RecordingApp {
+    /// Name to show in notifications
+    pub fn display_name(&self) -> &str {
+        if self.name.is_empty() {
+            &self.binary
+        } else {
+            &self.name
+        }
+    }
+}
+
+/// Parse the output of `pactl list source-outputs`
+pub fn parse_source_outputs(output: &str) -> Vec<RecordingApp> {
+    let mut apps = Vec::new();
+    let mut current: Option<RecordingApp> = None;
+    for line in output.lines() {
+        if line.starts_with("Source Output #") {
+            apps.extend(current.take());
+            current = Some(RecordingApp::default());
+            continue;
+        }
+        let Some(app) = current.as_mut() else {
+            continue;
+        };
+        let Some((key, value)) = line.trim().split_once(" = ") else {
+            continue;
+        };
+        let value = value.trim_matches('"');
+        match key {
+            "application.name" => app.name = value.to_string(),
+            "application.process.binary" => app.binary = value.to_string(),
+            "application.process.id" => app.pid = value.parse().ok(),
+            _ => {}
+        }
+    }
+    apps.extend(current);
+    apps
+}
+
+/// Applications recording from an input device right now
+pub fn recording_apps() -> std::io::Result<Vec<RecordingApp>> {
+    let output = std::process::Command::new("pactl")
+        .args(["list", "source-outputs"])
+        // The block headers are translated in other locales
+        .env("LC_ALL", "C")
+        .output()?;
+    if !output.status.success() {
+        return Err(std::io::Error::other(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(parse_source_outputs(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// Matches recording apps against `[meeting.auto_start] apps`
+pub struct CallMatcher {
+    apps: Vec<Regex>,
+}
+
+impl CallMatcher {
+    /// Compile the configured patterns, skipping invalid ones
+    pub fn new(config: &MeetingAutoStartConfig) -> Self {
+        let apps = config
+            .apps
+            .iter()
+            .filter_map(|pattern| match Regex::new(&format!("(?i){}", pattern)) {
+                Ok(regex) => Some(regex),
+                Err(e) => {
+                    tracing::warn!(
+                        "Invalid [meeting.auto_start] app pattern '{}': {}",
+                        pattern,
+                        e
+                    );
+                    None
+                }
+            })
+            .collect();
+        Self { apps }
+    }
+
+    /// The first app that looks like a call. Voxtype's own recording
+    /// streams never count.
+    pub fn find_call<'a>(&self, recording: &'a [RecordingApp]) -> Option<&'a RecordingApp> {
+        let own_pid = std::process::id();
+        recording.iter().find(|app| {
+            app.pid != Some(own_pid)
+                && !app.binary.starts_with("voxtype")
+                && self
+                    .apps
+                    .iter()
+                    .any(|regex| regex.is_match(&app.name) || regex.is_match(&app.binary))
+        })
+    }
+}
+
+/// A change in whether a call is on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallChange {
+    /// A call started in `app`
+    Started { app: String },
+    /// The call ended
+    Ended,
+}
+
+/// Turns polls of the recording apps into call start and end events
+pub struct CallTracker {
+    end_grace: Duration,
+    /// The app of the call in progress
+    call: Option<String>,
+    /// When a matching app was last seen during the call
+    last_seen: Option<Instant>,
+}
+
+impl CallTracker {
+    pub fn new(end_grace: Duration) -> Self {
+        Self {
+            end_grace,
+            call: None,
+            last_seen: None,
+        }
+    }
+
+    /// Record one poll: `app` is the call app seen, if any
+    pub fn update(&mut self, app: Option<&str>, now: Instant) -> Option<CallChange> {
+        match (app, &self.call) {
+            (Some(app), None) => {
+                self.call = Some(app.to_string());
+                self.last_seen = Some(now);
+                Some(CallChange::Started {
+                    app: app.to_string(),
+                })
+            }
+            (Some(_), Some(_)) => {
+                self.last_seen = Some(now);
+                None
+            }
+            (None, Some(_)) => {
+                let gone_for = self
+                    .last_seen
+                    .map_or(Duration::MAX, |seen| now.duration_since(seen));
+                if gone_for >= self.end_grace {
+                    self.call = None;
+                    self.last_seen = None;
+                    Some(CallChange::Ended)
+                } else {
+                    None
+                }
+            }
+            (None, None) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE_OUTPUTS: &str = "\
+Source Output #61
+\tDriver: PipeWire
+\tOwner Module: n/a
+\tClient: 60
+\tSource: 54
+\tSample Specification: float32le 1ch 48000Hz
+\tProperties:
+\t\tclient.api = \"pipewire-pulse\"
+\t\tapplication.name = \"ZOOM VoiceEngine\"
+\t\tapplication.process.id = \"4242\"
+\t\tapplication.process.binary = \"zoom\"
+\t\tmedia.name = \"record\"
+Source Output #75
+\tDriver: PipeWire
+\tProperties:
+\t\tapplication.name = \"ALSA plug-in [voxtype]\"
+\t\tapplication.process.binary = \"voxtype\"
+";
+
+    fn matcher(apps: &[&str]) -> CallMatcher {
+        CallMatcher::new(&MeetingAutoStartConfig {
+            apps: apps.iter().map(|a| a.to_string()).collect(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_parse_source_outputs() {
+        let apps = parse_source_outputs(SOURCE_OUTPUTS);
+        assert_eq!(apps.len(), 2);
+        assert_eq!(apps[0].name, "ZOOM VoiceEngine");
+        assert_eq!(apps[0].binary, "zoom");
+        assert_eq!(apps[0].pid, Some(4242));
+        assert_eq!(apps[1].display_name(), "ALSA plug-in [voxtype]");
+        assert!(parse_source_outputs("").is_empty());
+    }
+
+    #[test]
+    fn test_find_call() {
+        let apps = parse_source_outputs(SOURCE_OUTPUTS);
+        let call = matcher(&["zoom", "teams"]).find_call(&apps);
+        assert_eq!(call.map(|a| a.binary.as_str()), Some("zoom"));
+
+        // Voxtype records from the mic during a meeting, but isn't a call
+        assert!(matcher(&["voxtype", "alsa"]).find_call(&apps).is_none());
+        assert!(matcher(&["[invalid"]).find_call(&apps).is_none());
+    }
+
+    #[test]
+    fn test_call_tracker() {
+        let mut tracker = CallTracker::new(Duration::from_secs(30));
+        let start = Instant::now();
+        let at = |secs| start + Duration::from_secs(secs);
+
+        assert_eq!(tracker.update(None, at(0)), None);
+        assert_eq!(
+            tracker.update(Some("zoom"), at(5)),
+            Some(CallChange::Started {
+                app: "zoom".to_string()
+            })
+        );
+        assert_eq!(tracker.update(Some("zoom"), at(10)), None);
+        // A short gap, e.g. switching microphones, doesn't end the call
+        assert_eq!(tracker.update(None, at(20)), None);
+        assert_eq!(tracker.update(Some("zoom"), at(25)), None);
+        assert_eq!(tracker.update(None, at(50)), None);
+        assert_eq!(tracker.update(None, at(55)), Some(CallChange::Ended));
+        assert_eq!(tracker.update(None, at(60)), None);
+    }
+}
@@ -0,0 +1,328 @@
+//! Live captions for `voxtype meeting captions`
+//!
+//! Follows the meeting events socket and turns each transcript segment into
+//! a caption as soon as its chunk is transcribed. The `text` format keeps
+//! the latest few captions: written to a file, the file is rewritten on
+//! every update, so an OBS text source reading from it always shows what
+//! was just said. `srt` and `vtt` append timed cues instead, for players
+//! and recordings that run on the meeting clock.
+//!
+//! Captions lag speech by about a chunk (`chunk_duration_secs`), and show
+//! text before bleed-through removal, like `meeting watch`.
+
+use super::data::TranscriptSegment;
+use super::export::srt::format_srt_time;
+use super::export::vtt::format_vtt_time;
+use super::export::TranscriptText;
+use super::live::{LiveClient, LiveEvent};
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// How captions are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptionFormat {
+    /// The latest captions, one per line
+    Text,
+    /// SubRip cues
+    Srt,
+    /// WebVTT cues
+    Vtt,
+}
+
+impl CaptionFormat {
+    /// Parse from a `--format` value
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "text" | "txt" => Some(Self::Text),
+            "srt" => Some(Self::Srt),
+            "vtt" | "webvtt" => Some(Self::Vtt),
+            _ => None,
+        }
+    }
+}
+
+/// What captions show
+#[derive(Debug, Clone)]
+pub struct CaptionOptions {
+    pub format: CaptionFormat,
+    /// Captions kept by the text format
+    pub lines: usize,
+    /// Prefix captions with the speaker
+    pub speakers: bool,
+    /// Original text, translation, or both
+    pub text: TranscriptText,
+}
+
+impl Default for CaptionOptions {
+    fn default() -> Self {
+        Self {
+            format: CaptionFormat::Text,
+            lines: 2,
+            speakers: true,
+            text: TranscriptText::Original,
+        }
+    }
+}
+
+/// Captions of the current meeting
+pub struct Captions {
+    options: CaptionOptions,
+    /// The latest captions, in meeting time order
+    recent: Vec<(u64, String)>,
+    /// Cues written so far
+    cues: u32,
+}
+
+impl Captions {
+    pub fn new(options: CaptionOptions) -> Self {
+        Self {
+            options,
+            recent: Vec::new(),
+            cues: 0,
+        }
+    }
+
+    /// Start over for a new meeting
+    pub fn clear(&mut self) {
+        self.recent.clear();
+        self.cues = 0;
+    }
+
+    /// What a caption file starts with
+    pub fn header(&self) -> &'static str {
+        match self.options.format {
+            CaptionFormat::Vtt => "WEBVTT\n\n",
+            CaptionFormat::Text | CaptionFormat::Srt => "",
+        }
+    }
+
+    /// Add newly transcribed segments. Returns what they add to a stream:
+    /// one line per caption for text, cues for srt and vtt.
+    pub fn push(&mut self, segments: &[TranscriptSegment]) -> String {
+        let mut output = String::new();
+        for segment in segments {
+            let Some(caption) = self.caption(segment) else {
+                continue;
+            };
+            self.cues += 1;
+            match self.options.format {
+                CaptionFormat::Text => {
+                    output.push_str(&caption.replace('\n', " "));
+                    output.push('\n');
+                }
+                CaptionFormat::Srt => output.push_str(&format!(
+                    "{}\n{} --> {}\n{}\n\n",
+                    self.cues,
+                    format_srt_time(segment.start_ms),
+                    format_srt_time(segment.end_ms),
+                    caption
+                )),
+                CaptionFormat::Vtt => output.push_str(&format!(
+                    "{} --> {}\n{}\n\n",
+                    format_vtt_time(segment.start_ms),
+                    format_vtt_time(segment.end_ms),
+                    caption
+                )),
+            }
+
+            // Mic and remote chunks cover the same stretch of the meeting,
+            // so their segments arrive out of order
+            let at = self
+                .recent
+                .partition_point(|(start, _)| *start <= segment.start_ms);
+            self.recent
+                .insert(at, (segment.start_ms, caption.replace('\n', " ")));
+        }
+        let excess = self.recent.len().saturating_sub(self.options.lines.max(1));
+        self.recent.drain(..excess);
+        output
+    }
+
+    /// The latest captions, one per line
+    pub fn screen(&self) -> String {
+        self.recent
+            .iter()
+            .map(|(_, caption)| format!("{}\n", caption))
+            .collect()
+    }
+
+    fn caption(&self, segment: &TranscriptSegment) -> Option<String> {
+        let (text, translation) = self.options.text.texts(segment);
+        let text = text.trim();
+        if text.is_empty() {
+            return None;
+        }
+        let mut caption = if self.options.speakers {
+            format!("{}: {}", segment.speaker_display(), text)
+        } else {
+            text.to_string()
+        };
+        if let Some(translation) = translation {
+            caption.push('\n');
+            caption.push_str(translation.trim());
+        }
+        Some(caption)
+    }
+}
+
+/// Where captions go
+enum Sink {
+    Stdout,
+    /// Rewritten with the latest captions
+    Screen(PathBuf),
+    /// Appended to; opened when the meeting's first cue arrives
+    Cues(PathBuf, Option<File>),
+}
+
+impl Sink {
+    fn start(&mut self, captions: &Captions) -> io::Result<()> {
+        match self {
+            Self::Stdout => {
+                print!("{}", captions.header());
+                io::stdout().flush()
+            }
+            Self::Screen(path) => write_screen(path, ""),
+            Self::Cues(_, file) => {
+                *file = None;
+                Ok(())
+            }
+        }
+    }
+
+    fn write(&mut self, captions: &Captions, added: &str) -> io::Result<()> {
+        if added.is_empty() {
+            return Ok(());
+        }
+        match self {
+            Self::Stdout => {
+                print!("{}", added);
+                io::stdout().flush()
+            }
+            Self::Screen(path) => write_screen(path, &captions.screen()),
+            Self::Cues(path, file) => {
+                if file.is_none() {
+                    let mut new = File::create(&*path)?;
+                    new.write_all(captions.header().as_bytes())?;
+                    *file = Some(new);
+                }
+                let file = file.as_mut().expect("caption file was just opened");
+                file.write_all(added.as_bytes())?;
+                file.flush()
+            }
+        }
+    }
+
+    fn stop(&mut self) -> io::Result<()> {
+        match self {
+            // Stale captions would stay on screen until the next meeting
+            Self::Screen(path) => write_screen(path, ""),
+            Self::Stdout | Self::Cues(..) => Ok(()),
+        }
+    }
+}
+
+/// Write then rename, so a reader never sees a half-written file
+fn write_screen(path: &Path, screen: &str) -> io::Result<()> {
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".tmp");
+    std::fs::write(&temp_path, screen)?;
+    std::fs::rename(&temp_path, path)
+}
+
+/// Follow the daemon's meeting events and write captions to `output` (or
+/// stdout) until the daemon closes the connection. Meetings that start
+/// while this runs get captioned too; each one starts a new srt or vtt
+/// file.
+pub fn run(socket_path: &Path, options: CaptionOptions, output: Option<&Path>) -> io::Result<()> {
+    let mut client = LiveClient::connect(socket_path)?;
+    let mut sink = match (output, options.format) {
+        (None, _) => Sink::Stdout,
+        (Some(path), CaptionFormat::Text) => Sink::Screen(path.to_path_buf()),
+        (Some(path), _) => Sink::Cues(path.to_path_buf(), None),
+    };
+    let mut captions = Captions::new(options);
+
+    while let Some(event) = client.next_event()? {
+        match event {
+            LiveEvent::Started { .. } => {
+                captions.clear();
+                sink.start(&captions)?;
+            }
+            LiveEvent::ChunkProcessed { segments, .. } => {
+                let added = captions.push(&segments);
+                sink.write(&captions, &added)?;
+            }
+            LiveEvent::Stopped { .. } => sink.stop()?,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meeting::data::AudioSource;
+
+    fn segment(start_ms: u64, text: &str, source: AudioSource) -> TranscriptSegment {
+        let mut segment = TranscriptSegment::new(0, start_ms, start_ms + 2000, text.to_string(), 0);
+        segment.source = source;
+        segment
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(CaptionFormat::parse("SRT"), Some(CaptionFormat::Srt));
+        assert_eq!(CaptionFormat::parse("webvtt"), Some(CaptionFormat::Vtt));
+        assert_eq!(CaptionFormat::parse("txt"), Some(CaptionFormat::Text));
+        assert_eq!(CaptionFormat::parse("html"), None);
+    }
+
+    #[test]
+    fn test_text_keeps_latest_lines() {
+        let mut captions = Captions::new(CaptionOptions::default());
+        let added = captions.push(&[
+            segment(0, "Let's start.", AudioSource::Microphone),
+            segment(4000, "  ", AudioSource::Microphone),
+            segment(6000, "Agreed.", AudioSource::Microphone),
+        ]);
+        assert_eq!(added, "You: Let's start.\nYou: Agreed.\n");
+
+        // The remote chunk covers the same time as the mic chunk
+        captions.push(&[segment(3000, "Sounds good.", AudioSource::Loopback)]);
+        assert_eq!(captions.screen(), "Remote: Sounds good.\nYou: Agreed.\n");
+
+        captions.clear();
+        assert_eq!(captions.screen(), "");
+    }
+
+    #[test]
+    fn test_srt_and_vtt_cues() {
+        let options = CaptionOptions {
+            format: CaptionFormat::Srt,
+            speakers: false,
+            ..Default::default()
+        };
+        let mut captions = Captions::new(options);
+        assert_eq!(captions.header(), "");
+        captions.push(&[segment(0, "One.", AudioSource::Microphone)]);
+        let added = captions.push(&[segment(61_500, "Two.", AudioSource::Microphone)]);
+        assert_eq!(added, "2\n00:01:01,500 --> 00:01:03,500\nTwo.\n\n");
+
+        let options = CaptionOptions {
+            format: CaptionFormat::Vtt,
+            text: TranscriptText::Both,
+            ..Default::default()
+        };
+        let mut captions = Captions::new(options);
+        let mut translated = segment(1000, "Hallo.", AudioSource::Loopback);
+        translated.translation = Some("Hello.".to_string());
+        assert_eq!(captions.header(), "WEBVTT\n\n");
+        assert_eq!(
+            captions.push(&[translated]),
+            "00:00:01.000 --> 00:00:03.000\nRemote: Hallo.\nHello.\n\n"
+        );
+        assert_eq!(captions.screen(), "Remote: Hallo. Hello.\n");
+    }
+}
@@ -0,0 +1,419 @@
+//! Chapters of finished meetings
+//!
+//! A long transcript is split where its vocabulary changes, in the manner
+//! of TextTiling: the transcript is cut into one-minute blocks, and the
+//! words of the few blocks before each block boundary are compared with
+//! the words of the few blocks after it. The boundaries at the deepest
+//! dips in similarity, at least `min_chapter_mins` apart, start chapters.
+//!
+//! Chapters are titled with their most distinctive words, or by the
+//! `[meeting.summary]` model with `backend = "llm"`. They are stored in the
+//! transcript and shown in exports and summaries.
+
+use super::data::{Chapter, MeetingData, TranscriptSegment};
+use super::summary::{self, Summarizer, SummaryConfig, SummaryError};
+use crate::config::{MeetingChaptersConfig, MeetingSummaryConfig};
+use std::collections::HashMap;
+
+/// Length of the blocks compared, in milliseconds
+const BLOCK_MS: u64 = 60_000;
+
+/// Blocks on each side of a boundary that are compared
+const WINDOW_BLOCKS: usize = 3;
+
+/// Smallest dip in similarity that starts a chapter
+const MIN_DEPTH: f64 = 0.1;
+
+/// Words in a keyword title
+const TITLE_WORDS: usize = 3;
+
+/// Words that say nothing about the topic, separated by spaces
+const STOPWORDS: &str =
+    "about actually after again all also and any are because been before being but can could \
+     did didn does doesn doing don down each even for from get going gonna got had has have her \
+     here him his how into its just know let like look lot make maybe mean more much need not \
+     now off okay one only other our out over really right said say see she should some \
+     something still sure that the their them then there these they thing things think this \
+     those through too want was way well were what when where which while who why will with \
+     would yeah yes you your";
+
+/// Split `meeting` into chapters with the configured backend. Blocking:
+/// the llm backend asks the summary model for titles, keeping keyword
+/// titles when that fails.
+pub fn generate(
+    config: &MeetingChaptersConfig,
+    summary_config: &MeetingSummaryConfig,
+    meeting: &MeetingData,
+) -> Vec<Chapter> {
+    let mut chapters = detect(meeting, u64::from(config.min_chapter_mins) * 60_000);
+    match config.backend.as_str() {
+        _ if chapters.is_empty() => {}
+        "llm" => match summary::create_summarizer(&SummaryConfig::from_config(summary_config)) {
+            Some(summarizer) => {
+                if let Err(e) = title_with_model(&mut chapters, meeting, summarizer.as_ref()) {
+                    tracing::warn!("Failed to title chapters, keeping keywords: {}", e);
+                }
+            }
+            None => tracing::warn!(
+                "Chapter backend \"llm\" needs a [meeting.summary] backend; titling chapters with keywords"
+            ),
+        },
+        "similarity" => {}
+        other => tracing::warn!("Unknown chapter backend '{}', using similarity", other),
+    }
+    chapters
+}
+
+/// Chapters of `meeting`, each at least `min_ms` long, with keyword
+/// titles. Empty when the meeting is too short for two chapters or has no
+/// clear change of topic.
+pub fn detect(meeting: &MeetingData, min_ms: u64) -> Vec<Chapter> {
+    let segments = spoken_segments(meeting);
+    let (Some(first), Some(end)) = (segments.first(), segments.iter().map(|s| s.end_ms).max())
+    else {
+        return Vec::new();
+    };
+    let start = first.start_ms;
+    let min_ms = min_ms.max(BLOCK_MS);
+    if end.saturating_sub(start) < 2 * min_ms {
+        return Vec::new();
+    }
+
+    // Word counts of each block
+    let block_count = ((end - start) / BLOCK_MS + 1) as usize;
+    let mut blocks = vec![HashMap::new(); block_count];
+    for segment in &segments {
+        let block = ((segment.start_ms - start) / BLOCK_MS) as usize;
+        for word in words(&segment.text) {
+            *blocks[block].entry(word).or_insert(0.0) += 1.0;
+        }
+    }
+
+    // similarity[i] compares the blocks before and after the start of
+    // block i + 1
+    let similarity: Vec<f64> = (1..block_count)
+        .map(|b| {
+            let before = merge(&blocks[b.saturating_sub(WINDOW_BLOCKS)..b]);
+            let after = merge(&blocks[b..(b + WINDOW_BLOCKS).min(block_count)]);
+            cosine(&before, &after)
+        })
+        .collect();
+    let depths = depth_scores(&similarity);
+
+    // Deepest dips first, above the usual TextTiling cutoff
+    let mean = depths.iter().sum::<f64>() / depths.len().max(1) as f64;
+    let variance =
+        depths.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / depths.len().max(1) as f64;
+    let cutoff = (mean - variance.sqrt() / 2.0).max(MIN_DEPTH);
+    let mut candidates: Vec<usize> = (0..depths.len()).filter(|&i| depths[i] > cutoff).collect();
+    candidates.sort_by(|&a, &b| depths[b].total_cmp(&depths[a]));
+
+    let mut boundaries: Vec<u64> = Vec::new();
+    for i in candidates {
+        let at = start + (i as u64 + 1) * BLOCK_MS;
+        let fits = at - start >= min_ms
+            && end.saturating_sub(at) >= min_ms
+            && boundaries.iter().all(|&b| b.abs_diff(at) >= min_ms);
+        if fits {
+            boundaries.push(at);
+        }
+    }
+    if boundaries.is_empty() {
+        return Vec::new();
+    }
+    boundaries.sort_unstable();
+
+    // Each segment goes to the chapter its start falls in
+    let mut parts: Vec<Vec<&TranscriptSegment>> = vec![Vec::new(); boundaries.len() + 1];
+    for segment in segments {
+        let part = boundaries.partition_point(|&b| b <= segment.start_ms);
+        parts[part].push(segment);
+    }
+    parts.retain(|part| !part.is_empty());
+    keyword_chapters(&parts)
+}
+
+/// Ask `summarizer` for a title of each chapter
+pub fn title_with_model(
+    chapters: &mut [Chapter],
+    meeting: &MeetingData,
+    summarizer: &dyn Summarizer,
+) -> Result<(), SummaryError> {
+    let segments = spoken_segments(meeting);
+    for i in 0..chapters.len() {
+        let from = chapters[i].start_ms;
+        let to = chapters.get(i + 1).map_or(u64::MAX, |next| next.start_ms);
+        let mut excerpt = String::new();
+        for segment in segments
+            .iter()
+            .filter(|s| s.start_ms >= from && s.start_ms < to)
+        {
+            excerpt.push_str(&format!(
+                "[{}] {}: {}\n",
+                segment.format_timestamp(),
+                segment.speaker_display(),
+                segment.text.trim()
+            ));
+        }
+        if let Some((cut, _)) = excerpt.char_indices().nth(summarizer.max_prompt_chars()) {
+            excerpt.truncate(cut);
+        }
+
+        let prompt = format!(
+            "Give a short title, at most six words, for this part of a meeting transcript. \
+             Reply with only the title.\n\n{}",
+            excerpt
+        );
+        if let Some(title) = clean_title(&summarizer.complete(&prompt)?) {
+            chapters[i].title = title;
+        }
+    }
+    Ok(())
+}
+
+/// Non-empty segments in time order
+fn spoken_segments(meeting: &MeetingData) -> Vec<&TranscriptSegment> {
+    let mut segments: Vec<&TranscriptSegment> = meeting
+        .transcript
+        .segments
+        .iter()
+        .filter(|s| !s.text.trim().is_empty())
+        .collect();
+    segments.sort_by_key(|s| s.start_ms);
+    segments
+}
+
+/// Lowercase content words of `text`
+fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|w| {
+            w.chars().count() >= 3
+                && !w.chars().all(|c| c.is_numeric())
+                && !STOPWORDS.split_whitespace().any(|s| s == w)
+        })
+}
+
+fn merge(blocks: &[HashMap<String, f64>]) -> HashMap<String, f64> {
+    let mut merged = HashMap::new();
+    for block in blocks {
+        for (word, count) in block {
+            *merged.entry(word.clone()).or_insert(0.0) += count;
+        }
+    }
+    merged
+}
+
+fn cosine(a: &HashMap<String, f64>, b: &HashMap<String, f64>) -> f64 {
+    let dot: f64 = a
+        .iter()
+        .filter_map(|(word, x)| b.get(word).map(|y| x * y))
+        .sum();
+    let norm = |v: &HashMap<String, f64>| v.values().map(|x| x * x).sum::<f64>().sqrt();
+    let norms = norm(a) * norm(b);
+    if norms == 0.0 {
+        0.0
+    } else {
+        dot / norms
+    }
+}
+
+/// How far each similarity dips below the peaks on either side of it
+fn depth_scores(similarity: &[f64]) -> Vec<f64> {
+    (0..similarity.len())
+        .map(|i| {
+            let mut left = i;
+            while left > 0 && similarity[left - 1] >= similarity[left] {
+                left -= 1;
+            }
+            let mut right = i;
+            while right + 1 < similarity.len() && similarity[right + 1] >= similarity[right] {
+                right += 1;
+            }
+            similarity[left] + similarity[right] - 2.0 * similarity[i]
+        })
+        .collect()
+}
+
+/// Chapters titled with the words most frequent in them and rare in the
+/// others
+fn keyword_chapters(parts: &[Vec<&TranscriptSegment>]) -> Vec<Chapter> {
+    let counts: Vec<HashMap<String, usize>> = parts
+        .iter()
+        .map(|part| {
+            let mut counts = HashMap::new();
+            for word in part.iter().flat_map(|s| words(&s.text)) {
+                *counts.entry(word).or_insert(0) += 1;
+            }
+            counts
+        })
+        .collect();
+
+    parts
+        .iter()
+        .zip(&counts)
+        .enumerate()
+        .map(|(i, (part, part_counts))| {
+            let mut scored: Vec<(f64, usize, &String)> = part_counts
+                .iter()
+                .map(|(word, &count)| {
+                    let chapters_with = counts.iter().filter(|c| c.contains_key(word)).count();
+                    let idf = (1.0 + counts.len() as f64 / chapters_with as f64).ln();
+                    (count as f64 * idf, count, word)
+                })
+                .collect();
+            scored.sort_by(|a, b| b.0.total_cmp(&a.0).then(b.1.cmp(&a.1)).then(a.2.cmp(b.2)));
+            let keywords: Vec<&str> = scored
+                .iter()
+                .take(TITLE_WORDS)
+                .map(|(_, _, word)| word.as_str())
+                .collect();
+
+            let title = match keywords.split_first() {
+                Some((first, rest)) => {
+                    let mut chars = first.chars();
+                    let first: String = chars
+                        .next()
+                        .into_iter()
+                        .flat_map(char::to_uppercase)
+                        .chain(chars)
+                        .collect();
+                    std::iter::once(first.as_str())
+                        .chain(rest.iter().copied())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                }
+                None => format!("Part {}", i + 1),
+            };
+            Chapter {
+                title,
+                start_ms: part[0].start_ms,
+                end_ms: part
+                    .iter()
+                    .map(|s| s.end_ms)
+                    .max()
+                    .unwrap_or(part[0].end_ms),
+            }
+        })
+        .collect()
+}
+
+/// The title in a model's reply, without quotes, markup or a label
+fn clean_title(reply: &str) -> Option<String> {
+    let line = reply.lines().map(str::trim).find(|l| !l.is_empty())?;
+    let line = line
+        .strip_prefix("Title:")
+        .or_else(|| line.strip_prefix("title:"))
+        .unwrap_or(line);
+    let title = line
+        .trim()
+        .trim_matches(|c: char| matches!(c, '"' | '\'' | '*' | '#' | '`') || c.is_whitespace())
+        .trim_end_matches('.')
+        .trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A meeting with `topics`, each `mins` long, a segment every 30 s
+    /// cycling through the topic's sentences
+    fn meeting_with_topics(topics: &[&[&str]], mins: u64) -> MeetingData {
+        let mut meeting = MeetingData::new(None);
+        let mut start = 0;
+        for sentences in topics {
+            for i in 0..(mins * 2) as usize {
+                let text = sentences[i % sentences.len()].to_string();
+                meeting.add_segment(TranscriptSegment::new(0, start, start + 25_000, text, 0));
+                start += 30_000;
+            }
+        }
+        meeting
+    }
+
+    const BILLING: &[&str] = &[
+        "The billing migration moves invoices to the new system",
+        "Invoices from the old billing system need a migration script",
+        "Payments and invoices stay in billing until the migration ends",
+    ];
+    const HIRING: &[&str] = &[
+        "We are hiring two engineers and interviews start Monday",
+        "The hiring panel reviews candidates after interviews",
+        "Candidates get offers once hiring interviews finish",
+    ];
+
+    #[test]
+    fn test_detect_topics() {
+        let meeting = meeting_with_topics(&[BILLING, HIRING], 12);
+        let chapters = detect(&meeting, 5 * 60_000);
+        assert_eq!(
+            chapters,
+            vec![
+                Chapter {
+                    title: "Billing, invoices, migration".to_string(),
+                    start_ms: 0,
+                    end_ms: 715_000,
+                },
+                Chapter {
+                    title: "Hiring, interviews, candidates".to_string(),
+                    start_ms: 720_000,
+                    end_ms: 1_435_000,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detect_needs_a_change_of_topic() {
+        // One topic throughout
+        let meeting = meeting_with_topics(&[BILLING], 30);
+        assert!(detect(&meeting, 5 * 60_000).is_empty());
+
+        // Too short for two chapters of the minimum length
+        let meeting = meeting_with_topics(&[BILLING, HIRING], 4);
+        assert!(detect(&meeting, 5 * 60_000).is_empty());
+
+        assert!(detect(&MeetingData::new(None), 5 * 60_000).is_empty());
+    }
+
+    struct FakeSummarizer;
+
+    impl Summarizer for FakeSummarizer {
+        fn complete(&self, prompt: &str) -> Result<String, SummaryError> {
+            let title = if prompt.contains("billing") {
+                "Title: \"Billing migration.\""
+            } else {
+                "**Hiring plan**\n\nThe team discussed hiring."
+            };
+            Ok(title.to_string())
+        }
+
+        fn model(&self) -> Option<String> {
+            None
+        }
+
+        fn max_prompt_chars(&self) -> usize {
+            1000
+        }
+
+        fn name(&self) -> &'static str {
+            "fake"
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_title_with_model() {
+        let meeting = meeting_with_topics(&[BILLING, HIRING], 12);
+        let mut chapters = detect(&meeting, 5 * 60_000);
+        title_with_model(&mut chapters, &meeting, &FakeSummarizer).unwrap();
+        let titles: Vec<&str> = chapters.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(titles, vec!["Billing migration", "Hiring plan"]);
+
+        assert_eq!(clean_title("  \n"), None);
+    }
+}
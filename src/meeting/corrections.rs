@@ -0,0 +1,234 @@
+//! Transcript corrections for `voxtype meeting correct`
+//!
+//! The transcript is written out as one block per segment, numbered as in
+//! `meeting play --list`:
+//!
+//! ```text
+//! [12] 00:04:10 Alice
+//! We'll ship the release on Tuesday.
+//! ```
+//!
+//! Changing the text under a header corrects that segment. A patch file
+//! uses the same format with only the segments to change. Corrections are
+//! saved as a revision of the transcript (see
+//! [`Transcript::correct`](super::data::Transcript::correct)), which keeps
+//! the transcribed text.
+
+use super::data::MeetingData;
+use super::playback::numbered_segments;
+use std::collections::HashSet;
+use thiserror::Error;
+
+/// Problems with an edited transcript or patch file
+#[derive(Debug, Error)]
+pub enum CorrectionError {
+    #[error("Line {0}: text before the first [number] header")]
+    TextOutsideBlock(usize),
+
+    #[error("Segment {0} appears more than once")]
+    Duplicate(usize),
+
+    #[error("There is no segment {number} (the transcript has {count})")]
+    NoSuchSegment { number: usize, count: usize },
+
+    #[error("Segment {0} is empty; a correction needs some text")]
+    Empty(usize),
+}
+
+/// The transcript as a document to edit
+pub fn document(meeting: &MeetingData) -> String {
+    let mut output = format!(
+        "# Corrections of \"{}\"\n\
+         # Edit the text under each [number] header and save. Keep the headers;\n\
+         # text can span several lines. Lines starting with # are ignored.\n",
+        meeting.metadata.display_title()
+    );
+    for (number, segment) in (1..).zip(numbered_segments(meeting)) {
+        output.push_str(&format!(
+            "\n[{}] {} {}\n{}\n",
+            number,
+            segment.format_timestamp(),
+            segment.speaker_display(),
+            segment.text.trim()
+        ));
+    }
+    output
+}
+
+/// Parse an edited document or patch file into `(segment number, text)`
+/// pairs
+pub fn parse(text: &str) -> Result<Vec<(usize, String)>, CorrectionError> {
+    let mut blocks: Vec<(usize, Vec<&str>)> = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.starts_with('#') {
+            continue;
+        }
+        let number = line
+            .strip_prefix('[')
+            .and_then(|rest| rest.split_once(']'))
+            .and_then(|(number, _)| number.trim().parse::<usize>().ok());
+        match (number, blocks.last_mut()) {
+            (Some(number), _) => {
+                if blocks.iter().any(|(n, _)| *n == number) {
+                    return Err(CorrectionError::Duplicate(number));
+                }
+                blocks.push((number, Vec::new()));
+            }
+            (None, Some((_, lines))) => lines.push(line),
+            (None, None) if line.is_empty() => {}
+            (None, None) => return Err(CorrectionError::TextOutsideBlock(index + 1)),
+        }
+    }
+
+    blocks
+        .into_iter()
+        .map(|(number, lines)| {
+            let text = lines
+                .into_iter()
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ");
+            if text.is_empty() {
+                Err(CorrectionError::Empty(number))
+            } else {
+                Ok((number, text))
+            }
+        })
+        .collect()
+}
+
+/// Turn parsed segment numbers into `(segment id, text)` changes for
+/// [`Transcript::correct`](super::data::Transcript::correct), leaving out
+/// segments whose text is unchanged
+pub fn changes(
+    meeting: &MeetingData,
+    edits: &[(usize, String)],
+) -> Result<Vec<(u32, String)>, CorrectionError> {
+    let segments = numbered_segments(meeting);
+    let mut seen = HashSet::new();
+    let mut changes = Vec::new();
+    for (number, text) in edits {
+        let segment = number.checked_sub(1).and_then(|i| segments.get(i)).ok_or(
+            CorrectionError::NoSuchSegment {
+                number: *number,
+                count: segments.len(),
+            },
+        )?;
+        if !seen.insert(segment.id) {
+            return Err(CorrectionError::Duplicate(*number));
+        }
+        if segment.text.trim() != text {
+            changes.push((segment.id, text.clone()));
+        }
+    }
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meeting::data::{AudioSource, TranscriptSegment};
+
+    fn create_test_meeting() -> MeetingData {
+        let mut meeting = MeetingData::new(Some("Release planning".to_string()));
+        let texts = [
+            (0, " We ship on Tuesday."),
+            (4000, "Sounds good."),
+            (9000, ""),
+        ];
+        for (id, (start_ms, text)) in (0..).zip(texts) {
+            let mut segment =
+                TranscriptSegment::new(id, start_ms, start_ms + 3000, text.to_string(), 0);
+            segment.source = AudioSource::Microphone;
+            meeting.transcript.add_segment(segment);
+        }
+        meeting
+    }
+
+    #[test]
+    fn test_document_roundtrip() {
+        let meeting = create_test_meeting();
+        let document = document(&meeting);
+        assert!(document.starts_with("# Corrections of \"Release planning\"\n"));
+        assert!(document.contains("\n[1] 00:00 You\nWe ship on Tuesday.\n"));
+        // Empty segments aren't numbered, as in `meeting play --list`
+        assert!(!document.contains("[3]"));
+
+        let edits = parse(&document).unwrap();
+        assert_eq!(edits.len(), 2);
+        assert!(changes(&meeting, &edits).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_patch() {
+        let meeting = create_test_meeting();
+        let edits = parse("[2]\nSounds good,\n  see you then.\n\n").unwrap();
+        assert_eq!(edits, vec![(2, "Sounds good, see you then.".to_string())]);
+        assert_eq!(
+            changes(&meeting, &edits).unwrap(),
+            vec![(1, "Sounds good, see you then.".to_string())]
+        );
+
+        assert!(matches!(
+            changes(&meeting, &[(3, "Hello".to_string())]),
+            Err(CorrectionError::NoSuchSegment {
+                number: 3,
+                count: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            parse("Hello\n[1]\nHi"),
+            Err(CorrectionError::TextOutsideBlock(1))
+        ));
+        assert!(matches!(
+            parse("[1]\nHi\n[1]\nHello"),
+            Err(CorrectionError::Duplicate(1))
+        ));
+        assert!(matches!(
+            parse("[1] 00:00 You\n\n[2]\nHi"),
+            Err(CorrectionError::Empty(1))
+        ));
+    }
+
+    #[test]
+    fn test_correct_and_revert() {
+        let mut meeting = create_test_meeting();
+        let transcript = &mut meeting.transcript;
+
+        let revision = transcript
+            .correct(&[(0, "We ship on Thursday.".to_string())])
+            .unwrap();
+        assert_eq!(revision.number, 1);
+        assert_eq!(revision.corrections[0].before, " We ship on Tuesday.");
+        assert_eq!(transcript.segments[0].text, "We ship on Thursday.");
+        assert_eq!(
+            transcript.segments[0].original_text.as_deref(),
+            Some(" We ship on Tuesday.")
+        );
+
+        // A second correction keeps the transcribed text, not the first fix
+        transcript.correct(&[(0, "We ship Thursday.".to_string())]);
+        assert_eq!(
+            transcript.segments[0].original_text.as_deref(),
+            Some(" We ship on Tuesday.")
+        );
+        assert!(transcript
+            .correct(&[(0, "We ship Thursday.".to_string())])
+            .is_none());
+
+        let revision = transcript.revert_corrections().unwrap();
+        assert_eq!(revision.number, 3);
+        assert_eq!(transcript.segments[0].text, " We ship on Tuesday.");
+        assert!(transcript.segments[0].original_text.is_none());
+        assert!(transcript.revert_corrections().is_none());
+
+        let json = serde_json::to_string(&transcript).unwrap();
+        let parsed: crate::meeting::data::Transcript = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.revisions.len(), 3);
+    }
+}
@@ -7,6 +7,9 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use uuid::Uuid;
 
+/// File name of the retained audio in a meeting's storage directory
+pub const AUDIO_FILE_NAME: &str = "audio.wav";
+
 /// Unique identifier for a meeting
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct MeetingId(pub Uuid);
@@ -78,6 +81,9 @@ pub struct TranscriptSegment {
     pub end_ms: u64,
     /// Transcribed text content
     pub text: String,
+    /// Translation of `text` (`[meeting.translation]`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translation: Option<String>,
     /// Audio source (mic or loopback)
     pub source: AudioSource,
     /// Speaker ID (for diarization, Phase 3)
@@ -91,6 +97,10 @@ pub struct TranscriptSegment {
     pub confidence: Option<f32>,
     /// Chunk number this segment belongs to
     pub chunk_id: u32,
+    /// The transcribed text, kept when `text` was corrected with
+    /// `voxtype meeting correct`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_text: Option<String>,
 }
 
 impl TranscriptSegment {
@@ -101,11 +111,13 @@ impl TranscriptSegment {
             start_ms,
             end_ms,
             text,
+            translation: None,
             source: AudioSource::Unknown,
             speaker_id: None,
             speaker_label: None,
             confidence: None,
             chunk_id,
+            original_text: None,
         }
     }
 
@@ -127,18 +139,87 @@ impl TranscriptSegment {
 
     /// Format timestamp as HH:MM:SS
     pub fn format_timestamp(&self) -> String {
-        let secs = self.start_ms / 1000;
-        let hours = secs / 3600;
-        let minutes = (secs % 3600) / 60;
-        let seconds = secs % 60;
-        if hours > 0 {
-            format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
-        } else {
-            format!("{:02}:{:02}", minutes, seconds)
-        }
+        format_offset(self.start_ms)
+    }
+}
+
+/// Format an offset into the meeting as HH:MM:SS, or MM:SS under an hour
+pub fn format_offset(ms: u64) -> String {
+    let secs = ms / 1000;
+    let hours = secs / 3600;
+    let minutes = (secs % 3600) / 60;
+    let seconds = secs % 60;
+    if hours > 0 {
+        format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+/// A titled part of a finished meeting
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Chapter {
+    /// Short title of what the chapter is about
+    pub title: String,
+    /// Start of the chapter's first segment, in milliseconds
+    pub start_ms: u64,
+    /// End of the chapter's last segment, in milliseconds
+    pub end_ms: u64,
+}
+
+impl Chapter {
+    /// Format the start as HH:MM:SS
+    pub fn format_timestamp(&self) -> String {
+        format_offset(self.start_ms)
+    }
+}
+
+/// A moment marked during a meeting with `voxtype meeting mark` or the
+/// mark key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bookmark {
+    /// Position in the meeting, in milliseconds
+    pub offset_ms: u64,
+    /// What the moment is about
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// When it was marked
+    pub created_at: DateTime<Utc>,
+}
+
+impl Bookmark {
+    /// Format the position as HH:MM:SS
+    pub fn format_timestamp(&self) -> String {
+        format_offset(self.offset_ms)
+    }
+
+    /// The note, or "Bookmark" without one
+    pub fn label(&self) -> &str {
+        self.note.as_deref().unwrap_or("Bookmark")
     }
 }
 
+/// One text change in a [`Revision`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Correction {
+    /// ID of the corrected segment
+    pub segment_id: u32,
+    /// Text before the change
+    pub before: String,
+    /// Text after the change
+    pub after: String,
+}
+
+/// A set of corrections saved together by `voxtype meeting correct`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Revision {
+    /// 1 for the first revision, counting up
+    pub number: u32,
+    /// When it was saved
+    pub created_at: DateTime<Utc>,
+    pub corrections: Vec<Correction>,
+}
+
 /// Complete transcript for a meeting
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Transcript {
@@ -146,6 +227,15 @@ pub struct Transcript {
     pub segments: Vec<TranscriptSegment>,
     /// Total number of chunks processed
     pub total_chunks: u32,
+    /// Chapters of the finished meeting, in time order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chapters: Vec<Chapter>,
+    /// Moments marked during the meeting, in time order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bookmarks: Vec<Bookmark>,
+    /// Corrections of the transcribed text, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub revisions: Vec<Revision>,
 }
 
 impl Transcript {
@@ -159,6 +249,65 @@ impl Transcript {
         self.segments.push(segment);
     }
 
+    /// Replace the text of segments, given as `(segment id, text)` pairs,
+    /// and record the changes as a new revision. Each segment keeps its
+    /// transcribed text in `original_text`. Returns `None` when nothing
+    /// changed.
+    pub fn correct(&mut self, changes: &[(u32, String)]) -> Option<&Revision> {
+        let mut corrections = Vec::new();
+        for (segment_id, text) in changes {
+            let Some(segment) = self.segments.iter_mut().find(|s| s.id == *segment_id) else {
+                continue;
+            };
+            if segment.text == *text {
+                continue;
+            }
+            corrections.push(Correction {
+                segment_id: *segment_id,
+                before: segment.text.clone(),
+                after: text.clone(),
+            });
+            if segment.original_text.is_none() {
+                segment.original_text = Some(segment.text.clone());
+            }
+            // Back to what was transcribed: nothing left to keep
+            if segment.original_text.as_deref() == Some(text.as_str()) {
+                segment.original_text = None;
+            }
+            segment.text = text.clone();
+        }
+        if corrections.is_empty() {
+            return None;
+        }
+
+        let number = self.revisions.last().map_or(1, |r| r.number + 1);
+        self.revisions.push(Revision {
+            number,
+            created_at: Utc::now(),
+            corrections,
+        });
+        self.revisions.last()
+    }
+
+    /// Restore the transcribed text of every corrected segment, as a new
+    /// revision. Returns `None` when nothing was corrected.
+    pub fn revert_corrections(&mut self) -> Option<&Revision> {
+        let changes: Vec<(u32, String)> = self
+            .segments
+            .iter()
+            .filter_map(|s| s.original_text.clone().map(|text| (s.id, text)))
+            .collect();
+        self.correct(&changes)
+    }
+
+    /// Add a bookmark, keeping bookmarks in time order
+    pub fn add_bookmark(&mut self, bookmark: Bookmark) {
+        let index = self
+            .bookmarks
+            .partition_point(|b| b.offset_ms <= bookmark.offset_ms);
+        self.bookmarks.insert(index, bookmark);
+    }
+
     /// Remove echoed phrases from mic segments that match loopback transcripts.
     /// Works at the phrase level: finds runs of consecutive words in a mic segment
     /// that appear in any loopback segment and strips them out, keeping the user's
@@ -368,6 +517,12 @@ pub struct MeetingMetadata {
     /// User-provided title
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    /// Attendees from the calendar event the meeting belongs to
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attendees: Vec<String>,
+    /// Tags for finding the meeting later (`voxtype meeting edit --tags`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
     /// When the meeting started
     pub started_at: DateTime<Utc>,
     /// When the meeting ended
@@ -402,6 +557,8 @@ impl MeetingMetadata {
         Self {
             id: MeetingId::new(),
             title,
+            attendees: Vec::new(),
+            tags: Vec::new(),
             started_at: Utc::now(),
             ended_at: None,
             duration_secs: None,
@@ -430,6 +587,18 @@ impl MeetingMetadata {
         self.status = MeetingStatus::Cancelled;
     }
 
+    /// Replace the tags. Tags are trimmed, a leading `#` is dropped, and
+    /// empty or repeated (ignoring case) tags are skipped.
+    pub fn set_tags(&mut self, tags: &[String]) {
+        self.tags.clear();
+        for tag in tags {
+            let tag = tag.trim().trim_start_matches('#').trim();
+            if !tag.is_empty() && !self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                self.tags.push(tag.to_string());
+            }
+        }
+    }
+
     /// Get a display title (or fallback to date)
     pub fn display_title(&self) -> String {
         self.title
@@ -437,6 +606,16 @@ impl MeetingMetadata {
             .unwrap_or_else(|| self.started_at.format("Meeting %Y-%m-%d %H:%M").to_string())
     }
 
+    /// Path of the retained meeting audio, when audio was retained
+    pub fn audio_path(&self) -> Option<PathBuf> {
+        if !self.audio_retained {
+            return None;
+        }
+        self.storage_path
+            .as_ref()
+            .map(|path| path.join(AUDIO_FILE_NAME))
+    }
+
     /// Generate the default storage directory name
     pub fn storage_dir_name(&self) -> String {
         let date = self.started_at.format("%Y-%m-%d").to_string();
@@ -484,6 +663,9 @@ pub struct MeetingSummary {
     /// Model used to generate
     #[serde(skip_serializing_if = "Option::is_none")]
     pub model: Option<String>,
+    /// Chapters of the meeting, when it was split into chapters
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chapters: Vec<Chapter>,
 }
 
 /// An action item from the meeting
@@ -867,6 +1049,22 @@ mod tests {
         assert_eq!(deserialized.speaker_id, Some("SPEAKER_00".to_string()));
     }
 
+    #[test]
+    fn test_set_tags() {
+        let mut metadata = MeetingMetadata::new(None);
+        metadata.tags = vec!["old".to_string()];
+        metadata.set_tags(&[
+            " #roadmap".to_string(),
+            "Q3 planning".to_string(),
+            "".to_string(),
+            "Roadmap".to_string(),
+        ]);
+        assert_eq!(metadata.tags, vec!["roadmap", "Q3 planning"]);
+
+        metadata.set_tags(&[]);
+        assert!(metadata.tags.is_empty());
+    }
+
     #[test]
     fn test_transcript_duration_ms() {
         let mut transcript = Transcript::new();
@@ -874,4 +1072,28 @@ mod tests {
         transcript.add_segment(TranscriptSegment::new(1, 5000, 12000, "B".to_string(), 1));
         assert_eq!(transcript.duration_ms(), 12000);
     }
+
+    #[test]
+    fn test_transcript_add_bookmark() {
+        let bookmark = |offset_ms, note: Option<&str>| Bookmark {
+            offset_ms,
+            note: note.map(String::from),
+            created_at: Utc::now(),
+        };
+        let mut transcript = Transcript::new();
+        transcript.add_bookmark(bookmark(90_000, Some("Decision")));
+        transcript.add_bookmark(bookmark(30_000, None));
+        transcript.add_bookmark(bookmark(90_000, Some("Follow-up")));
+
+        let labels: Vec<&str> = transcript.bookmarks.iter().map(|b| b.label()).collect();
+        assert_eq!(labels, vec!["Bookmark", "Decision", "Follow-up"]);
+        assert_eq!(transcript.bookmarks[1].format_timestamp(), "01:30");
+
+        let json = serde_json::to_string(&transcript).unwrap();
+        let parsed: Transcript = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.bookmarks, transcript.bookmarks);
+        assert!(!serde_json::to_string(&Transcript::new())
+            .unwrap()
+            .contains("bookmarks"));
+    }
 }
@@ -0,0 +1,147 @@
+//! Enrolled speakers for cross-meeting recognition
+//!
+//! `voxtype meeting speakers enroll <name>` turns a voice sample into an
+//! ECAPA-TDNN voice print and stores it in the meeting index. The ML
+//! diarizer compares every speaker cluster it builds against the enrolled
+//! voice prints and names the cluster after the closest one, so transcripts
+//! say "Alice" instead of SPEAKER_00 in every meeting.
+
+use super::cosine_similarity;
+
+/// Voiced sub-windows an enrollment sample needs. With the default 4s
+/// window and 2s hop that is about 8 seconds of speech.
+pub const MIN_ENROLLMENT_WINDOWS: usize = 3;
+
+/// A named voice print
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnrolledSpeaker {
+    /// Name used in transcripts
+    pub name: String,
+    /// Mean of the normalized sub-window embeddings of the sample
+    pub embedding: Vec<f32>,
+    /// Number of sub-window embeddings averaged into `embedding`
+    pub windows: u32,
+}
+
+/// Check that `name` can be used for an enrolled speaker. Names the
+/// diarizers use for their own speaker IDs are rejected so a transcript
+/// can't mix them up.
+pub fn validate_name(name: &str) -> Result<(), String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Speaker name is empty".to_string());
+    }
+    if matches!(name, "You" | "Remote" | "Unknown") || name.starts_with("SPEAKER_") {
+        return Err(format!(
+            "'{}' is reserved for automatic speaker IDs; pick another name",
+            name
+        ));
+    }
+    Ok(())
+}
+
+/// Average sub-window embeddings into a voice print. Each embedding is
+/// normalized first so loud windows don't outweigh quiet ones.
+pub fn voice_print(embeddings: &[Vec<f32>]) -> Option<Vec<f32>> {
+    let dim = embeddings.first()?.len();
+    let mut sum = vec![0.0f32; dim];
+    let mut count = 0;
+    for embedding in embeddings.iter().filter(|e| e.len() == dim) {
+        let norm = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+        for (s, x) in sum.iter_mut().zip(embedding) {
+            *s += x / norm;
+        }
+        count += 1;
+    }
+    if count == 0 {
+        return None;
+    }
+    Some(sum.into_iter().map(|s| s / count as f32).collect())
+}
+
+/// The enrolled speaker whose voice print is most similar to `centroid`,
+/// with the similarity, if it reaches `threshold`
+pub fn identify<'a>(
+    enrolled: &'a [EnrolledSpeaker],
+    centroid: &[f32],
+    threshold: f32,
+) -> Option<(&'a EnrolledSpeaker, f32)> {
+    enrolled
+        .iter()
+        .map(|speaker| (speaker, cosine_similarity(&speaker.embedding, centroid)))
+        .filter(|(_, similarity)| *similarity >= threshold)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// Encode an embedding for storage (little-endian f32s)
+pub fn embedding_to_bytes(embedding: &[f32]) -> Vec<u8> {
+    embedding.iter().flat_map(|x| x.to_le_bytes()).collect()
+}
+
+/// Decode an embedding stored by [`embedding_to_bytes`]
+pub fn embedding_from_bytes(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn speaker(name: &str, embedding: Vec<f32>) -> EnrolledSpeaker {
+        EnrolledSpeaker {
+            name: name.to_string(),
+            embedding,
+            windows: 5,
+        }
+    }
+
+    #[test]
+    fn test_validate_name() {
+        assert!(validate_name("Alice").is_ok());
+        assert!(validate_name("Dr. Bob Smith").is_ok());
+        assert!(validate_name("  ").is_err());
+        assert!(validate_name("You").is_err());
+        assert!(validate_name("SPEAKER_03").is_err());
+    }
+
+    #[test]
+    fn test_voice_print_normalizes_windows() {
+        // A loud and a quiet window count the same
+        let print = voice_print(&[vec![10.0, 0.0], vec![0.0, 0.1]]).unwrap();
+        assert!((print[0] - 0.5).abs() < 1e-6);
+        assert!((print[1] - 0.5).abs() < 1e-6);
+
+        assert_eq!(voice_print(&[]), None);
+        assert_eq!(voice_print(&[vec![0.0, 0.0]]), None);
+    }
+
+    #[test]
+    fn test_identify_best_match_above_threshold() {
+        let enrolled = [
+            speaker("Alice", vec![1.0, 0.0, 0.0]),
+            speaker("Bob", vec![0.0, 1.0, 0.0]),
+        ];
+
+        let (found, similarity) = identify(&enrolled, &[0.2, 0.9, 0.0], 0.5).unwrap();
+        assert_eq!(found.name, "Bob");
+        assert!(similarity > 0.9);
+
+        // Closest is Alice, but not close enough
+        assert!(identify(&enrolled, &[0.5, 0.1, 0.9], 0.5).is_none());
+        assert!(identify(&[], &[1.0, 0.0, 0.0], 0.5).is_none());
+    }
+
+    #[test]
+    fn test_embedding_bytes_roundtrip() {
+        let embedding = vec![0.25, -1.5, 3.0e-7];
+        let bytes = embedding_to_bytes(&embedding);
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(embedding_from_bytes(&bytes), embedding);
+    }
+}
@@ -5,6 +5,8 @@
 //!
 //! This module is only available with the `ml-diarization` feature.
 
+#[cfg(feature = "ml-diarization")]
+use super::enrolled::{self, EnrolledSpeaker};
 use super::{DiarizationConfig, DiarizedSegment, Diarizer, SpeakerId};
 use crate::meeting::data::AudioSource;
 use crate::meeting::TranscriptSegment;
@@ -32,25 +34,7 @@ pub struct SpeakerEmbedding {
 impl SpeakerEmbedding {
     /// Cosine similarity with another embedding
     pub fn cosine_similarity(&self, other: &SpeakerEmbedding) -> f32 {
-        if self.vector.len() != other.vector.len() {
-            return 0.0;
-        }
-
-        let dot: f32 = self
-            .vector
-            .iter()
-            .zip(other.vector.iter())
-            .map(|(a, b)| a * b)
-            .sum();
-
-        let norm_a: f32 = self.vector.iter().map(|x| x * x).sum::<f32>().sqrt();
-        let norm_b: f32 = other.vector.iter().map(|x| x * x).sum::<f32>().sqrt();
-
-        if norm_a == 0.0 || norm_b == 0.0 {
-            return 0.0;
-        }
-
-        dot / (norm_a * norm_b)
+        super::cosine_similarity(&self.vector, &other.vector)
     }
 }
 
@@ -62,9 +46,17 @@ struct MlDiarizerState {
     speaker_embeddings: Vec<SpeakerEmbedding>,
     /// Speaker labels (auto ID -> human label)
     speaker_labels: HashMap<u32, String>,
+    /// Clusters recognized as an enrolled speaker (auto ID -> name)
+    recognized: HashMap<u32, String>,
     /// Next speaker ID
     #[cfg(feature = "ml-diarization")]
     next_speaker_id: u32,
+    /// Enrolled voice prints to recognize
+    #[cfg(feature = "ml-diarization")]
+    enrolled: Vec<EnrolledSpeaker>,
+    /// Similarity a cluster needs with an enrolled voice print
+    #[cfg(feature = "ml-diarization")]
+    enrollment_threshold: f32,
 }
 
 impl MlDiarizerState {
@@ -73,8 +65,47 @@ impl MlDiarizerState {
             #[cfg(feature = "ml-diarization")]
             speaker_embeddings: Vec::new(),
             speaker_labels: HashMap::new(),
+            recognized: HashMap::new(),
             #[cfg(feature = "ml-diarization")]
             next_speaker_id: 0,
+            #[cfg(feature = "ml-diarization")]
+            enrolled: Vec::new(),
+            #[cfg(feature = "ml-diarization")]
+            enrollment_threshold: DiarizationConfig::default().enrollment_threshold,
+        }
+    }
+
+    /// Compare cluster `idx` with the enrolled voice prints and name it
+    /// after the closest one. Called whenever the cluster's centroid
+    /// changes, so a cluster that drifts away from a voice print loses
+    /// the name again.
+    #[cfg(feature = "ml-diarization")]
+    fn identify_speaker(&mut self, idx: usize) {
+        if self.enrolled.is_empty() {
+            return;
+        }
+        let cluster = &self.speaker_embeddings[idx];
+        let SpeakerId::Auto(auto_id) = cluster.speaker_id else {
+            return;
+        };
+
+        match enrolled::identify(&self.enrolled, &cluster.vector, self.enrollment_threshold) {
+            Some((speaker, similarity)) => {
+                if self.recognized.get(&auto_id) != Some(&speaker.name) {
+                    tracing::info!(
+                        "Recognized SPEAKER_{:02} as {} (similarity: {:.3})",
+                        auto_id,
+                        speaker.name,
+                        similarity
+                    );
+                    self.recognized.insert(auto_id, speaker.name.clone());
+                }
+            }
+            None => {
+                if let Some(name) = self.recognized.remove(&auto_id) {
+                    tracing::debug!("SPEAKER_{:02} no longer matches {}", auto_id, name);
+                }
+            }
         }
     }
 
@@ -128,7 +159,9 @@ impl MlDiarizerState {
                 sim,
                 existing.count
             );
-            existing.speaker_id.clone()
+            let speaker_id = existing.speaker_id.clone();
+            self.identify_speaker(idx);
+            speaker_id
         } else if self.next_speaker_id < max_speakers {
             // Log best similarity for debugging
             let best_sim = self
@@ -151,6 +184,7 @@ impl MlDiarizerState {
                 count: 1,
             });
             self.next_speaker_id += 1;
+            self.identify_speaker(self.speaker_embeddings.len() - 1);
             speaker_id
         } else {
             // Too many speakers, return unknown
@@ -194,11 +228,19 @@ pub struct MlDiarizer {
 impl MlDiarizer {
     /// Create a new ML diarizer
     pub fn new(config: &DiarizationConfig) -> Self {
+        #[allow(unused_mut)]
+        let mut state = MlDiarizerState::new();
+        #[cfg(feature = "ml-diarization")]
+        {
+            state.enrolled = config.enrolled_speakers.clone();
+            state.enrollment_threshold = config.enrollment_threshold;
+        }
+
         Self {
             model_path: config.model_path.as_ref().map(PathBuf::from),
             #[cfg(feature = "ml-diarization")]
             session: None,
-            state: Mutex::new(MlDiarizerState::new()),
+            state: Mutex::new(state),
             #[cfg(feature = "ml-diarization")]
             similarity_threshold: config.similarity_threshold,
             #[cfg(feature = "ml-diarization")]
@@ -290,6 +332,37 @@ impl MlDiarizer {
         Ok(embedding_data.to_vec())
     }
 
+    /// Build an enrollment voice print from a sample of one person
+    /// speaking. Returns the voice print and the number of voiced
+    /// sub-windows it was averaged from.
+    #[cfg(feature = "ml-diarization")]
+    pub fn voice_print(&self, samples: &[f32]) -> Result<(Vec<f32>, u32), String> {
+        let windows = super::vad_subwindows(
+            samples,
+            self.sample_rate,
+            self.vad_window_secs,
+            self.vad_hop_secs,
+            self.vad_rms_floor,
+        );
+        if windows.len() < enrolled::MIN_ENROLLMENT_WINDOWS {
+            return Err(format!(
+                "Not enough speech in the sample ({} voiced {:.0}s windows, need {}). \
+                 Record a longer sample, speaking continuously.",
+                windows.len(),
+                self.vad_window_secs,
+                enrolled::MIN_ENROLLMENT_WINDOWS
+            ));
+        }
+
+        let embeddings = windows
+            .iter()
+            .map(|&(start, end, _rms)| self.extract_embedding(&samples[start..end]))
+            .collect::<Result<Vec<_>, _>>()?;
+        let print = enrolled::voice_print(&embeddings)
+            .ok_or("The model returned empty embeddings for the sample")?;
+        Ok((print, embeddings.len() as u32))
+    }
+
     /// Label a speaker
     pub fn label_speaker(&self, auto_id: u32, label: String) {
         if let Ok(mut state) = self.state.lock() {
@@ -297,11 +370,16 @@ impl MlDiarizer {
         }
     }
 
-    /// Get speaker label if set
+    /// Get speaker label if set, or the enrolled speaker the cluster was
+    /// recognized as
     pub fn get_label(&self, speaker_id: &SpeakerId) -> Option<String> {
         let state = self.state.lock().ok()?;
         match speaker_id {
-            SpeakerId::Auto(id) => state.speaker_labels.get(id).cloned(),
+            SpeakerId::Auto(id) => state
+                .speaker_labels
+                .get(id)
+                .or_else(|| state.recognized.get(id))
+                .cloned(),
             _ => None,
         }
     }
@@ -461,6 +539,12 @@ impl Diarizer for MlDiarizer {
                     })
                     .map(|(sp, _)| sp)
                     .unwrap_or(SpeakerId::Unknown);
+                // Clusters labeled or recognized as an enrolled speaker
+                // are reported by name
+                let speaker = self
+                    .get_label(&speaker)
+                    .map(SpeakerId::Named)
+                    .unwrap_or(speaker);
 
                 let confidence = if matches!(speaker, SpeakerId::Unknown) {
                     0.0
@@ -673,6 +757,53 @@ mod tests {
         assert_eq!(speaker, SpeakerId::Unknown);
     }
 
+    #[test]
+    #[cfg(feature = "ml-diarization")]
+    fn test_enrolled_speaker_recognized() {
+        let mut state = MlDiarizerState::new();
+        state.enrolled = vec![EnrolledSpeaker {
+            name: "Alice".to_string(),
+            embedding: vec![1.0, 0.0, 0.0],
+            windows: 5,
+        }];
+        state.enrollment_threshold = 0.8;
+
+        // Close to Alice's voice print: the new cluster is named
+        let speaker = state.find_or_create_speaker(&[0.9, 0.1, 0.0], 0.75, 10);
+        assert_eq!(speaker, SpeakerId::Auto(0));
+        assert_eq!(state.recognized.get(&0).map(String::as_str), Some("Alice"));
+
+        // Someone else stays anonymous
+        state.find_or_create_speaker(&[0.0, 1.0, 0.0], 0.75, 10);
+        assert!(!state.recognized.contains_key(&1));
+
+        // The cluster drifts away from Alice and loses the name
+        state.find_or_create_speaker(&[0.0, 0.0, 1.0], -1.0, 10);
+        state.find_or_create_speaker(&[0.0, 0.0, 1.0], -1.0, 10);
+        assert!(!state.recognized.contains_key(&0));
+    }
+
+    #[test]
+    fn test_label_before_recognized_name() {
+        let diarizer = MlDiarizer::default();
+        diarizer
+            .state
+            .lock()
+            .unwrap()
+            .recognized
+            .insert(0, "Alice".to_string());
+        assert_eq!(
+            diarizer.get_label(&SpeakerId::Auto(0)),
+            Some("Alice".to_string())
+        );
+
+        diarizer.label_speaker(0, "Alice Smith".to_string());
+        assert_eq!(
+            diarizer.get_label(&SpeakerId::Auto(0)),
+            Some("Alice Smith".to_string())
+        );
+    }
+
     #[test]
     #[cfg(feature = "ml-diarization")]
     fn test_samples_to_ms() {
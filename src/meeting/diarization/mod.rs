@@ -7,12 +7,17 @@
 //! - **Simple**: Source-based attribution using mic vs loopback (Phase 2)
 //! - **ML**: ONNX-based speaker embeddings with clustering (Phase 3)
 //! - **Subprocess**: Memory-isolated ML diarization for resource-constrained systems
+//!
+//! The ML backend also recognizes speakers enrolled with
+//! `voxtype meeting speakers enroll` (see [`enrolled`]).
 
+pub mod enrolled;
 pub mod ml;
 pub mod simple;
 pub mod subprocess;
 
 use crate::meeting::data::AudioSource;
+use enrolled::EnrolledSpeaker;
 use std::collections::HashMap;
 
 /// Speaker identifier
@@ -67,6 +72,24 @@ pub struct DiarizedSegment {
 /// Speaker labels mapping auto IDs to names
 pub type SpeakerLabels = HashMap<SpeakerId, String>;
 
+/// Cosine similarity of two embeddings (0.0 when their lengths differ or
+/// one is all zeros)
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 0.0;
+    }
+
+    let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+
+    dot / (norm_a * norm_b)
+}
+
 /// Split audio into overlapping voiced sub-windows by RMS gating.
 ///
 /// Returns `(start_sample, end_sample, rms)` tuples for windows whose
@@ -141,6 +164,11 @@ pub struct DiarizationConfig {
     pub vad_hop_secs: f32,
     /// RMS floor below which a sub-window is treated as silence
     pub vad_rms_floor: f32,
+    /// Cosine similarity a speaker cluster needs with an enrolled voice
+    /// print to be named after it
+    pub enrollment_threshold: f32,
+    /// Enrolled voice prints the ML backend names clusters after
+    pub enrolled_speakers: Vec<EnrolledSpeaker>,
 }
 
 impl Default for DiarizationConfig {
@@ -155,6 +183,8 @@ impl Default for DiarizationConfig {
             vad_window_secs: 4.0,
             vad_hop_secs: 2.0,
             vad_rms_floor: 0.005,
+            enrollment_threshold: 0.45,
+            enrolled_speakers: Vec::new(),
         }
     }
 }
@@ -238,6 +268,8 @@ mod tests {
         assert!((config.vad_window_secs - 4.0).abs() < f32::EPSILON);
         assert!((config.vad_hop_secs - 2.0).abs() < f32::EPSILON);
         assert!((config.vad_rms_floor - 0.005).abs() < f32::EPSILON);
+        assert!((config.enrollment_threshold - 0.45).abs() < f32::EPSILON);
+        assert!(config.enrolled_speakers.is_empty());
     }
 
     #[test]
@@ -0,0 +1,366 @@
+//! HTML export format
+//!
+//! Generates a single self-contained page: the transcript grouped by
+//! speaker, each speaker in its own color, with clickable timestamps.
+//! When the meeting's audio was retained the page embeds a player;
+//! clicking a timestamp seeks to that segment and the segment being played
+//! is highlighted. Speakers and timestamps are always included. Chapters
+//! are listed under the summary and head their part of the transcript.
+
+use super::{ChapterStarts, ExportError, ExportFormat, ExportOptions, Exporter};
+use crate::meeting::data::{format_offset, MeetingData};
+
+/// Speaker colors, assigned in order of first appearance
+const SPEAKER_COLORS: &[&str] = &[
+    "#2563eb", "#16a34a", "#db2777", "#ea580c", "#7c3aed", "#0891b2", "#ca8a04", "#dc2626",
+];
+
+const STYLE: &str = r#"body { font-family: system-ui, sans-serif; max-width: 48rem; margin: 2rem auto; padding: 0 1rem; line-height: 1.5; color: #1f2937; }
+h1 { margin-bottom: 0.25rem; }
+.info { color: #6b7280; margin-top: 0; }
+audio { width: 100%; position: sticky; top: 0; background: #fff; padding: 0.5rem 0; }
+.turn { margin: 1rem 0; padding-left: 0.75rem; border-left: 4px solid var(--color); }
+.speaker { font-weight: 600; color: var(--color); }
+.segment { margin: 0.25rem 0; border-radius: 4px; }
+.segment.playing { background: #fef3c7; }
+a.ts { color: #6b7280; font-size: 0.85em; font-variant-numeric: tabular-nums; text-decoration: none; margin-right: 0.5rem; }
+a.ts:hover { text-decoration: underline; }
+.translation { display: block; color: #6b7280; font-style: italic; }
+.chapters, .bookmarks { padding-left: 1.25rem; }
+"#;
+
+/// Seek on timestamp clicks and highlight the segment being played
+const SCRIPT: &str = r#"const player = document.getElementById("player");
+const segments = Array.from(document.querySelectorAll(".segment"));
+document.querySelectorAll("a.ts").forEach((link) => {
+  link.addEventListener("click", (event) => {
+    if (!player) return;
+    event.preventDefault();
+    player.currentTime = parseFloat(link.dataset.start);
+    player.play();
+  });
+});
+if (player) {
+  player.addEventListener("timeupdate", () => {
+    const t = player.currentTime;
+    segments.forEach((s) => {
+      s.classList.toggle("playing", t >= parseFloat(s.dataset.start) && t < parseFloat(s.dataset.end));
+    });
+  });
+}
+"#;
+
+/// HTML exporter
+pub struct HtmlExporter;
+
+impl Exporter for HtmlExporter {
+    fn export(
+        &self,
+        meeting: &MeetingData,
+        options: &ExportOptions,
+    ) -> Result<String, ExportError> {
+        let title = escape(&meeting.metadata.display_title());
+        let mut output = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+        output.push_str("<meta charset=\"utf-8\">\n");
+        output.push_str(&format!("<title>{}</title>\n", title));
+        output.push_str(&format!("<style>\n{}</style>\n", STYLE));
+        output.push_str("</head>\n<body>\n");
+
+        // Header
+        output.push_str(&format!("<h1>{}</h1>\n", title));
+        let mut info = vec![meeting
+            .metadata
+            .started_at
+            .format("%Y-%m-%d %H:%M UTC")
+            .to_string()];
+        if let Some(duration) = meeting.metadata.duration_secs {
+            info.push(format_offset(duration * 1000));
+        }
+        if !meeting.metadata.attendees.is_empty() {
+            info.push(meeting.metadata.attendees.join(", "));
+        }
+        output.push_str(&format!(
+            "<p class=\"info\">{}</p>\n",
+            escape(&info.join(" · "))
+        ));
+
+        // Player
+        let audio_url = options.audio_url.clone().or_else(|| {
+            meeting
+                .metadata
+                .audio_path()
+                .filter(|path| path.exists())
+                .map(|path| file_url(&path))
+        });
+        if let Some(url) = audio_url {
+            output.push_str(&format!(
+                "<audio id=\"player\" controls preload=\"metadata\" src=\"{}\"></audio>\n",
+                escape(&url)
+            ));
+        }
+
+        // Summary (if available)
+        if let Some(ref summary) = meeting.metadata.summary {
+            output.push_str("<h2>Summary</h2>\n");
+            output.push_str(&format!("<p>{}</p>\n", escape(&summary.summary)));
+            let lists = [
+                ("Key Points", &summary.key_points),
+                ("Decisions", &summary.decisions),
+            ];
+            for (heading, items) in lists {
+                if !items.is_empty() {
+                    output.push_str(&format!("<h3>{}</h3>\n<ul>\n", heading));
+                    for item in items {
+                        output.push_str(&format!("<li>{}</li>\n", escape(item)));
+                    }
+                    output.push_str("</ul>\n");
+                }
+            }
+            if !summary.action_items.is_empty() {
+                output.push_str("<h3>Action Items</h3>\n<ul>\n");
+                for item in &summary.action_items {
+                    let assignee = item
+                        .assignee
+                        .as_ref()
+                        .map(|a| format!(" (@{})", a))
+                        .unwrap_or_default();
+                    output.push_str(&format!(
+                        "<li>{}{}</li>\n",
+                        escape(&item.description),
+                        escape(&assignee)
+                    ));
+                }
+                output.push_str("</ul>\n");
+            }
+        }
+
+        // Chapters, linked to their headings
+        let chapters = &meeting.transcript.chapters;
+        if !chapters.is_empty() {
+            output.push_str("<h2>Chapters</h2>\n<ol class=\"chapters\">\n");
+            for (i, chapter) in chapters.iter().enumerate() {
+                output.push_str(&format!(
+                    "<li><a class=\"ts\" href=\"#chapter-{i}\" data-start=\"{start:.3}\">{ts}</a>\
+                     <a href=\"#chapter-{i}\">{title}</a></li>\n",
+                    start = chapter.start_ms as f64 / 1000.0,
+                    ts = chapter.format_timestamp(),
+                    title = escape(&chapter.title),
+                ));
+            }
+            output.push_str("</ol>\n");
+        }
+
+        // Bookmarks, linked to the segment playing when they were added
+        let bookmarks = &meeting.transcript.bookmarks;
+        if !bookmarks.is_empty() {
+            output.push_str("<h2>Bookmarks</h2>\n<ul class=\"bookmarks\">\n");
+            for bookmark in bookmarks {
+                let target = meeting
+                    .transcript
+                    .segments
+                    .iter()
+                    .filter(|s| s.start_ms <= bookmark.offset_ms)
+                    .max_by_key(|s| s.start_ms)
+                    .map(|s| format!("#seg-{}", s.id))
+                    .unwrap_or_else(|| "#".to_string());
+                output.push_str(&format!(
+                    "<li><a class=\"ts\" href=\"{target}\" data-start=\"{start:.3}\">{ts}</a>{label}</li>\n",
+                    start = bookmark.offset_ms as f64 / 1000.0,
+                    ts = bookmark.format_timestamp(),
+                    label = escape(bookmark.label()),
+                ));
+            }
+            output.push_str("</ul>\n");
+        }
+
+        // Transcript, one block per speaker turn
+        output.push_str("<h2>Transcript</h2>\n");
+        let mut chapter_starts = ChapterStarts::new(meeting);
+        let mut speakers: Vec<String> = Vec::new();
+        let mut last_speaker: Option<String> = None;
+        for segment in &meeting.transcript.segments {
+            if let Some((index, chapter)) = chapter_starts.at(segment) {
+                if last_speaker.take().is_some() {
+                    output.push_str("</div>\n");
+                }
+                output.push_str(&format!(
+                    "<h3 id=\"chapter-{}\">{}</h3>\n",
+                    index,
+                    escape(&chapter.title)
+                ));
+            }
+
+            let speaker = segment.speaker_display();
+            if last_speaker.as_ref() != Some(&speaker) {
+                if last_speaker.is_some() {
+                    output.push_str("</div>\n");
+                }
+                let index = match speakers.iter().position(|s| *s == speaker) {
+                    Some(index) => index,
+                    None => {
+                        speakers.push(speaker.clone());
+                        speakers.len() - 1
+                    }
+                };
+                output.push_str(&format!(
+                    "<div class=\"turn\" style=\"--color: {}\">\n<div class=\"speaker\">{}</div>\n",
+                    SPEAKER_COLORS[index % SPEAKER_COLORS.len()],
+                    escape(&speaker)
+                ));
+                last_speaker = Some(speaker);
+            }
+
+            let start = segment.start_ms as f64 / 1000.0;
+            let end = segment.end_ms as f64 / 1000.0;
+            let (text, translation) = options.text.texts(segment);
+            let translation = translation
+                .map(|t| format!("<span class=\"translation\">{}</span>", escape(t)))
+                .unwrap_or_default();
+            output.push_str(&format!(
+                "<p class=\"segment\" id=\"seg-{id}\" data-start=\"{start:.3}\" data-end=\"{end:.3}\">\
+                 <a class=\"ts\" href=\"#seg-{id}\" data-start=\"{start:.3}\">{ts}</a>{text}{translation}</p>\n",
+                id = segment.id,
+                ts = segment.format_timestamp(),
+                text = escape(text),
+            ));
+        }
+        if last_speaker.is_some() {
+            output.push_str("</div>\n");
+        }
+
+        output.push_str(&format!("<script>\n{}</script>\n", SCRIPT));
+        output.push_str("</body>\n</html>\n");
+        Ok(output)
+    }
+
+    fn format(&self) -> ExportFormat {
+        ExportFormat::Html
+    }
+}
+
+/// `file://` URL for a local path
+fn file_url(path: &std::path::Path) -> String {
+    let mut url = String::from("file://");
+    for byte in path.to_string_lossy().bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'/' | b'-' | b'_' | b'.' | b'~' => {
+                url.push(byte as char)
+            }
+            _ => url.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    url
+}
+
+/// Escape text for HTML content and attribute values
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meeting::data::{AudioSource, Chapter, TranscriptSegment};
+
+    fn create_test_meeting() -> MeetingData {
+        let mut meeting = MeetingData::new(Some("Q3 <Planning>".to_string()));
+
+        let mut seg1 = TranscriptSegment::new(0, 0, 5000, "Good morning everyone.".to_string(), 0);
+        seg1.source = AudioSource::Microphone;
+        let mut seg2 = TranscriptSegment::new(1, 5000, 9000, "Morning!".to_string(), 0);
+        seg2.source = AudioSource::Loopback;
+        let mut seg3 = TranscriptSegment::new(2, 65_500, 70_000, "Tom & Jerry".to_string(), 1);
+        seg3.source = AudioSource::Loopback;
+
+        meeting.transcript.add_segment(seg1);
+        meeting.transcript.add_segment(seg2);
+        meeting.transcript.add_segment(seg3);
+        meeting
+    }
+
+    #[test]
+    fn test_html_export_transcript() {
+        let meeting = create_test_meeting();
+        let output = HtmlExporter
+            .export(&meeting, &ExportOptions::default())
+            .unwrap();
+
+        assert!(output.starts_with("<!DOCTYPE html>"));
+        assert!(output.contains("<h1>Q3 &lt;Planning&gt;</h1>"));
+        assert!(output.contains("Tom &amp; Jerry"));
+        // Two speaker turns: You, then Remote for both loopback segments
+        assert_eq!(output.matches("<div class=\"turn\"").count(), 2);
+        assert!(output.contains("--color: #2563eb\">\n<div class=\"speaker\">You</div>"));
+        assert!(output.contains("--color: #16a34a\">\n<div class=\"speaker\">Remote</div>"));
+        assert!(output.contains("data-start=\"65.500\">01:05</a>"));
+        // No retained audio, no player
+        assert!(!output.contains("<audio"));
+    }
+
+    #[test]
+    fn test_html_export_audio_player() {
+        let meeting = create_test_meeting();
+        let options = ExportOptions {
+            audio_url: Some("standup.wav".to_string()),
+            ..Default::default()
+        };
+        let output = HtmlExporter.export(&meeting, &options).unwrap();
+        assert!(output
+            .contains("<audio id=\"player\" controls preload=\"metadata\" src=\"standup.wav\">"));
+    }
+
+    #[test]
+    fn test_html_export_chapters() {
+        let mut meeting = create_test_meeting();
+        meeting.transcript.chapters = vec![
+            Chapter {
+                title: "Hello".to_string(),
+                start_ms: 0,
+                end_ms: 9000,
+            },
+            Chapter {
+                title: "Cartoons & more".to_string(),
+                start_ms: 65_500,
+                end_ms: 70_000,
+            },
+        ];
+        let output = HtmlExporter
+            .export(&meeting, &ExportOptions::default())
+            .unwrap();
+
+        assert!(output.contains(
+            "<li><a class=\"ts\" href=\"#chapter-1\" data-start=\"65.500\">01:05</a>\
+             <a href=\"#chapter-1\">Cartoons &amp; more</a></li>"
+        ));
+        assert!(output.contains("</div>\n<h3 id=\"chapter-1\">Cartoons &amp; more</h3>\n"));
+        // The chapter starts a new turn of the same speaker
+        assert_eq!(output.matches("<div class=\"turn\"").count(), 3);
+    }
+
+    #[test]
+    fn test_file_url() {
+        assert_eq!(
+            file_url(std::path::Path::new("/home/me/My Meetings/audio.wav")),
+            "file:///home/me/My%20Meetings/audio.wav"
+        );
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape("a \"b\" <c> & 'd'"),
+            "a &quot;b&quot; &lt;c&gt; &amp; &#39;d&#39;"
+        );
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::meeting::data::MeetingData;
 use crate::meeting::export::{ExportError, ExportFormat, ExportOptions, Exporter};
+use crate::meeting::stats::{self, SpeakerStats};
 use serde::Serialize;
 
 /// JSON exporter
@@ -20,6 +21,10 @@ struct ExportedMeeting {
 struct ExportedMetadata {
     id: String,
     title: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    attendees: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
     #[serde(rename = "startedAt")]
     started_at: String,
     #[serde(rename = "endedAt", skip_serializing_if = "Option::is_none")]
@@ -41,6 +46,31 @@ struct ExportedTranscript {
     #[serde(rename = "durationMs")]
     duration_ms: u64,
     speakers: Vec<String>,
+    #[serde(rename = "speakerStats", skip_serializing_if = "Vec::is_empty")]
+    speaker_stats: Vec<SpeakerStats>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    chapters: Vec<ExportedChapter>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    bookmarks: Vec<ExportedBookmark>,
+}
+
+#[derive(Serialize)]
+struct ExportedBookmark {
+    #[serde(rename = "offsetMs")]
+    offset_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    #[serde(rename = "createdAt")]
+    created_at: String,
+}
+
+#[derive(Serialize)]
+struct ExportedChapter {
+    title: String,
+    #[serde(rename = "startMs")]
+    start_ms: u64,
+    #[serde(rename = "endMs")]
+    end_ms: u64,
 }
 
 #[derive(Serialize)]
@@ -51,6 +81,11 @@ struct ExportedSegment {
     #[serde(rename = "endMs")]
     end_ms: u64,
     text: String,
+    /// The transcribed text of a corrected segment
+    #[serde(rename = "originalText", skip_serializing_if = "Option::is_none")]
+    original_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    translation: Option<String>,
     source: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     speaker: Option<String>,
@@ -88,12 +123,14 @@ impl Exporter for JsonExporter {
     fn export(
         &self,
         meeting: &MeetingData,
-        _options: &ExportOptions,
+        options: &ExportOptions,
     ) -> Result<String, ExportError> {
         let exported = ExportedMeeting {
             metadata: ExportedMetadata {
                 id: meeting.metadata.id.to_string(),
                 title: meeting.metadata.title.clone(),
+                attendees: meeting.metadata.attendees.clone(),
+                tags: meeting.metadata.tags.clone(),
                 started_at: meeting.metadata.started_at.to_rfc3339(),
                 ended_at: meeting.metadata.ended_at.map(|dt| dt.to_rfc3339()),
                 duration_secs: meeting.metadata.duration_secs,
@@ -105,20 +142,46 @@ impl Exporter for JsonExporter {
                     .transcript
                     .segments
                     .iter()
-                    .map(|s| ExportedSegment {
-                        id: s.id,
-                        start_ms: s.start_ms,
-                        end_ms: s.end_ms,
-                        text: s.text.clone(),
-                        source: format!("{:?}", s.source).to_lowercase(),
-                        speaker: s.speaker_label.clone().or_else(|| s.speaker_id.clone()),
-                        chunk_id: s.chunk_id,
+                    .map(|s| {
+                        let (text, translation) = options.text.texts(s);
+                        ExportedSegment {
+                            id: s.id,
+                            start_ms: s.start_ms,
+                            end_ms: s.end_ms,
+                            text: text.to_string(),
+                            original_text: s.original_text.clone(),
+                            translation: translation.map(str::to_string),
+                            source: format!("{:?}", s.source).to_lowercase(),
+                            speaker: s.speaker_label.clone().or_else(|| s.speaker_id.clone()),
+                            chunk_id: s.chunk_id,
+                        }
                     })
                     .collect(),
                 total_chunks: meeting.transcript.total_chunks,
                 word_count: meeting.transcript.word_count(),
                 duration_ms: meeting.transcript.duration_ms(),
                 speakers: meeting.transcript.speakers(),
+                speaker_stats: stats::speaker_stats(meeting),
+                chapters: meeting
+                    .transcript
+                    .chapters
+                    .iter()
+                    .map(|c| ExportedChapter {
+                        title: c.title.clone(),
+                        start_ms: c.start_ms,
+                        end_ms: c.end_ms,
+                    })
+                    .collect(),
+                bookmarks: meeting
+                    .transcript
+                    .bookmarks
+                    .iter()
+                    .map(|b| ExportedBookmark {
+                        offset_ms: b.offset_ms,
+                        note: b.note.clone(),
+                        created_at: b.created_at.to_rfc3339(),
+                    })
+                    .collect(),
             },
             summary: meeting.metadata.summary.as_ref().map(|s| ExportedSummary {
                 summary: s.summary.clone(),
@@ -146,7 +209,7 @@ impl Exporter for JsonExporter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::meeting::data::TranscriptSegment;
+    use crate::meeting::data::{Bookmark, TranscriptSegment};
 
     fn create_test_meeting() -> MeetingData {
         let mut meeting = MeetingData::new(Some("Test Meeting".to_string()));
@@ -178,6 +241,52 @@ mod tests {
             parsed["transcript"]["segments"][0]["text"].as_str(),
             Some("Hello world.")
         );
+        let stats = &parsed["transcript"]["speakerStats"][0];
+        assert_eq!(stats["talkMs"].as_u64(), Some(5000));
+        assert_eq!(stats["words"].as_u64(), Some(2));
+        assert_eq!(stats["interruptions"].as_u64(), Some(0));
+        assert!(parsed["transcript"].get("bookmarks").is_none());
+    }
+
+    #[test]
+    fn test_json_export_bookmarks() {
+        let mut meeting = create_test_meeting();
+        meeting.transcript.add_bookmark(Bookmark {
+            offset_ms: 2500,
+            note: Some("Budget".to_string()),
+            created_at: chrono::Utc::now(),
+        });
+
+        let output = JsonExporter
+            .export(&meeting, &ExportOptions::default())
+            .unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        let bookmark = &parsed["transcript"]["bookmarks"][0];
+        assert_eq!(bookmark["offsetMs"].as_u64(), Some(2500));
+        assert_eq!(bookmark["note"].as_str(), Some("Budget"));
+        assert!(bookmark["createdAt"].is_string());
+    }
+
+    #[test]
+    fn test_json_export_corrections() {
+        let mut meeting = create_test_meeting();
+        let id = meeting.transcript.segments[0].id;
+        let original = meeting.transcript.segments[0].text.clone();
+        meeting
+            .transcript
+            .correct(&[(id, "Corrected text.".to_string())]);
+
+        let output = JsonExporter
+            .export(&meeting, &ExportOptions::default())
+            .unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        let segments = &parsed["transcript"]["segments"];
+        assert_eq!(segments[0]["text"].as_str(), Some("Corrected text."));
+        assert_eq!(
+            segments[0]["originalText"].as_str(),
+            Some(original.as_str())
+        );
+        assert!(segments[1].get("originalText").is_none());
     }
 
     #[test]
@@ -1,7 +1,8 @@
 //! Markdown exporter for meeting transcriptions
 
 use crate::meeting::data::MeetingData;
-use crate::meeting::export::{ExportError, ExportFormat, ExportOptions, Exporter};
+use crate::meeting::export::{ChapterStarts, ExportError, ExportFormat, ExportOptions, Exporter};
+use crate::meeting::stats;
 
 /// Markdown exporter
 pub struct MarkdownExporter;
@@ -38,6 +39,18 @@ impl Exporter for MarkdownExporter {
                     output.push_str(&format!("- **Duration:** {}m {}s\n", mins, secs));
                 }
             }
+            if !meeting.metadata.attendees.is_empty() {
+                output.push_str(&format!(
+                    "- **Attendees:** {}\n",
+                    meeting.metadata.attendees.join(", ")
+                ));
+            }
+            if !meeting.metadata.tags.is_empty() {
+                output.push_str(&format!(
+                    "- **Tags:** {}\n",
+                    meeting.metadata.tags.join(", ")
+                ));
+            }
             output.push_str(&format!(
                 "- **Word Count:** {}\n",
                 meeting.transcript.word_count()
@@ -53,6 +66,28 @@ impl Exporter for MarkdownExporter {
             }
 
             output.push('\n');
+
+            let speaker_stats = stats::speaker_stats(meeting);
+            if speaker_stats.len() > 1 {
+                output.push_str("## Talk Time\n\n");
+                output.push_str(
+                    "| Speaker | Talk time | Share | Words | Turns | Interruptions | Interrupted |\n",
+                );
+                output.push_str("|---|---|---|---|---|---|---|\n");
+                for s in &speaker_stats {
+                    output.push_str(&format!(
+                        "| {} | {} | {} | {} | {} | {} | {} |\n",
+                        s.speaker,
+                        stats::format_talk_time(s),
+                        stats::format_share(s),
+                        s.words,
+                        s.turns,
+                        s.interruptions,
+                        s.interrupted
+                    ));
+                }
+                output.push('\n');
+            }
         }
 
         // Summary (if available, Phase 5)
@@ -95,19 +130,59 @@ impl Exporter for MarkdownExporter {
             }
         }
 
-        // Transcript
+        // Chapters
+        let chapters = &meeting.transcript.chapters;
+        if !chapters.is_empty() {
+            output.push_str("## Chapters\n\n");
+            for chapter in chapters {
+                output.push_str(&format!(
+                    "- [{}] {}\n",
+                    chapter.format_timestamp(),
+                    chapter.title
+                ));
+            }
+            output.push('\n');
+        }
+
+        // Bookmarks
+        let bookmarks = &meeting.transcript.bookmarks;
+        if !bookmarks.is_empty() {
+            output.push_str("## Bookmarks\n\n");
+            for bookmark in bookmarks {
+                output.push_str(&format!(
+                    "- [{}] {}\n",
+                    bookmark.format_timestamp(),
+                    bookmark.label()
+                ));
+            }
+            output.push('\n');
+        }
+
+        // Transcript, with a heading per chapter; speakers go one level
+        // below the chapters
         output.push_str("## Transcript\n\n");
 
+        let speaker_heading = if chapters.is_empty() { "###" } else { "####" };
+        let mut chapter_starts = ChapterStarts::new(meeting);
         let mut last_speaker = String::new();
 
         for segment in &meeting.transcript.segments {
+            if let Some((_, chapter)) = chapter_starts.at(segment) {
+                output.push_str(&format!(
+                    "### {} ({})\n\n",
+                    chapter.title,
+                    chapter.format_timestamp()
+                ));
+                last_speaker.clear();
+            }
+
             if options.include_speakers {
                 let speaker = segment.speaker_display();
                 if speaker != last_speaker {
                     if !last_speaker.is_empty() {
                         output.push('\n');
                     }
-                    output.push_str(&format!("### {}\n\n", speaker));
+                    output.push_str(&format!("{} {}\n\n", speaker_heading, speaker));
                     last_speaker = speaker;
                 }
             }
@@ -116,7 +191,11 @@ impl Exporter for MarkdownExporter {
                 output.push_str(&format!("*[{}]* ", segment.format_timestamp()));
             }
 
-            output.push_str(&segment.text);
+            let (text, translation) = options.text.texts(segment);
+            output.push_str(text);
+            if let Some(translation) = translation {
+                output.push_str(&format!("\n> {}", translation));
+            }
             output.push_str("\n\n");
         }
 
@@ -127,7 +206,7 @@ impl Exporter for MarkdownExporter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::meeting::data::{AudioSource, TranscriptSegment};
+    use crate::meeting::data::{AudioSource, Bookmark, Chapter, TranscriptSegment};
 
     fn create_test_meeting() -> MeetingData {
         let mut meeting = MeetingData::new(Some("Weekly Standup".to_string()));
@@ -156,7 +235,8 @@ mod tests {
 
     #[test]
     fn test_markdown_export_with_metadata() {
-        let meeting = create_test_meeting();
+        let mut meeting = create_test_meeting();
+        meeting.metadata.tags = vec!["standup".to_string(), "team".to_string()];
         let exporter = MarkdownExporter;
         let options = ExportOptions {
             include_metadata: true,
@@ -167,6 +247,14 @@ mod tests {
         assert!(output.contains("## Meeting Info"));
         assert!(output.contains("**Date:**"));
         assert!(output.contains("**Word Count:**"));
+        assert!(output.contains("- **Tags:** standup, team\n"));
+        assert!(output.contains(
+            "## Talk Time\n\n\
+             | Speaker | Talk time | Share | Words | Turns | Interruptions | Interrupted |\n\
+             |---|---|---|---|---|---|---|\n\
+             | Remote | 00:05 | 50% | 3 | 1 | 0 | 0 |\n\
+             | You | 00:05 | 50% | 3 | 1 | 0 | 0 |\n\n"
+        ));
     }
 
     #[test]
@@ -196,4 +284,51 @@ mod tests {
         assert!(output.contains("*[00:00]*"));
         assert!(output.contains("*[00:05]*"));
     }
+
+    #[test]
+    fn test_markdown_export_with_chapters() {
+        let mut meeting = create_test_meeting();
+        meeting.transcript.chapters = vec![
+            Chapter {
+                title: "Greetings".to_string(),
+                start_ms: 0,
+                end_ms: 4000,
+            },
+            Chapter {
+                title: "Replies".to_string(),
+                start_ms: 5000,
+                end_ms: 10000,
+            },
+        ];
+        let options = ExportOptions {
+            include_speakers: true,
+            ..Default::default()
+        };
+
+        let output = MarkdownExporter.export(&meeting, &options).unwrap();
+        assert!(output.contains("## Chapters\n\n- [00:00] Greetings\n- [00:05] Replies\n"));
+        assert!(output.contains(
+            "### Greetings (00:00)\n\n#### You\n\nGood morning everyone.\n\n### Replies (00:05)\n\n#### Remote\n\n"
+        ));
+    }
+
+    #[test]
+    fn test_markdown_export_with_bookmarks() {
+        let mut meeting = create_test_meeting();
+        meeting.transcript.add_bookmark(Bookmark {
+            offset_ms: 6000,
+            note: Some("Action item".to_string()),
+            created_at: chrono::Utc::now(),
+        });
+        meeting.transcript.add_bookmark(Bookmark {
+            offset_ms: 1000,
+            note: None,
+            created_at: chrono::Utc::now(),
+        });
+
+        let output = MarkdownExporter
+            .export(&meeting, &ExportOptions::default())
+            .unwrap();
+        assert!(output.contains("## Bookmarks\n\n- [00:01] Bookmark\n- [00:06] Action item\n"));
+    }
 }
@@ -2,13 +2,15 @@
 //!
 //! Provides exporters for various output formats.
 
+pub mod html;
 pub mod json;
 pub mod markdown;
 pub mod srt;
+pub mod template;
 pub mod txt;
 pub mod vtt;
 
-use crate::meeting::data::MeetingData;
+use crate::meeting::data::{Chapter, MeetingData, TranscriptSegment};
 use thiserror::Error;
 
 /// Export format types
@@ -24,6 +26,10 @@ pub enum ExportFormat {
     Srt,
     /// VTT subtitles (Phase 2)
     Vtt,
+    /// HTML page with an audio player
+    Html,
+    /// Markdown note rendered from a template (Obsidian, Logseq, custom)
+    Template,
 }
 
 impl ExportFormat {
@@ -35,6 +41,8 @@ impl ExportFormat {
             "json" => Some(ExportFormat::Json),
             "srt" => Some(ExportFormat::Srt),
             "vtt" => Some(ExportFormat::Vtt),
+            "html" | "htm" => Some(ExportFormat::Html),
+            "template" => Some(ExportFormat::Template),
             _ => None,
         }
     }
@@ -47,12 +55,16 @@ impl ExportFormat {
             ExportFormat::Json => "json",
             ExportFormat::Srt => "srt",
             ExportFormat::Vtt => "vtt",
+            ExportFormat::Html => "html",
+            ExportFormat::Template => "md",
         }
     }
 
     /// Get all supported format names
     pub fn all_names() -> &'static [&'static str] {
-        &["text", "txt", "markdown", "md", "json", "srt", "vtt"]
+        &[
+            "text", "txt", "markdown", "md", "json", "srt", "vtt", "html", "htm", "template",
+        ]
     }
 }
 
@@ -64,6 +76,8 @@ impl std::fmt::Display for ExportFormat {
             ExportFormat::Json => write!(f, "json"),
             ExportFormat::Srt => write!(f, "srt"),
             ExportFormat::Vtt => write!(f, "vtt"),
+            ExportFormat::Html => write!(f, "html"),
+            ExportFormat::Template => write!(f, "template"),
         }
     }
 }
@@ -79,6 +93,71 @@ pub enum ExportError {
 
     #[error("Format not supported: {0}")]
     UnsupportedFormat(String),
+
+    #[error("Template error: {0}")]
+    Template(String),
+}
+
+/// Which text of each segment an export shows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TranscriptText {
+    /// The transcribed text
+    #[default]
+    Original,
+    /// The translation, or the original where there is none
+    Translation,
+    /// The original followed by its translation
+    Both,
+}
+
+impl TranscriptText {
+    /// Parse from a `--text` value
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "original" => Some(Self::Original),
+            "translation" | "translated" => Some(Self::Translation),
+            "both" => Some(Self::Both),
+            _ => None,
+        }
+    }
+
+    /// The text to show for `segment`, and with `Both` the translation to
+    /// show after it
+    pub fn texts(self, segment: &TranscriptSegment) -> (&str, Option<&str>) {
+        match (self, segment.translation.as_deref()) {
+            (Self::Translation, Some(translation)) => (translation, None),
+            (Self::Both, Some(translation)) => (&segment.text, Some(translation)),
+            _ => (&segment.text, None),
+        }
+    }
+}
+
+/// Finds where chapters start while an exporter writes segments in order
+pub(crate) struct ChapterStarts<'a> {
+    chapters: &'a [Chapter],
+    next: usize,
+}
+
+impl<'a> ChapterStarts<'a> {
+    pub(crate) fn new(meeting: &'a MeetingData) -> Self {
+        Self {
+            chapters: &meeting.transcript.chapters,
+            next: 0,
+        }
+    }
+
+    /// The chapter that starts at `segment` and its index, if any
+    pub(crate) fn at(&mut self, segment: &TranscriptSegment) -> Option<(usize, &'a Chapter)> {
+        let mut started = None;
+        while let Some(chapter) = self.chapters.get(self.next) {
+            if segment.start_ms < chapter.start_ms {
+                break;
+            }
+            started = Some((self.next, chapter));
+            self.next += 1;
+        }
+        started
+    }
 }
 
 /// Export options
@@ -92,6 +171,14 @@ pub struct ExportOptions {
     pub include_metadata: bool,
     /// Line width for wrapping (0 = no wrap)
     pub line_width: usize,
+    /// Audio source for the HTML player (default: the retained audio file)
+    pub audio_url: Option<String>,
+    /// Template source for the template format (default: built-in Obsidian)
+    pub template: Option<String>,
+    /// Tags for the template format
+    pub tags: Vec<String>,
+    /// Original text, translation, or both
+    pub text: TranscriptText,
 }
 
 /// Trait for meeting exporters
@@ -116,6 +203,8 @@ pub fn export_meeting(
         ExportFormat::Json => Box::new(json::JsonExporter),
         ExportFormat::Srt => Box::new(srt::SrtExporter),
         ExportFormat::Vtt => Box::new(vtt::VttExporter),
+        ExportFormat::Html => Box::new(html::HtmlExporter),
+        ExportFormat::Template => Box::new(template::TemplateExporter),
     };
 
     exporter.export(meeting, options)
@@ -147,6 +236,11 @@ mod tests {
         );
         assert_eq!(ExportFormat::parse("md"), Some(ExportFormat::Markdown));
         assert_eq!(ExportFormat::parse("json"), Some(ExportFormat::Json));
+        assert_eq!(ExportFormat::parse("html"), Some(ExportFormat::Html));
+        assert_eq!(
+            ExportFormat::parse("template"),
+            Some(ExportFormat::Template)
+        );
         assert_eq!(ExportFormat::parse("invalid"), None);
     }
 
@@ -157,6 +251,8 @@ mod tests {
         assert_eq!(ExportFormat::Json.extension(), "json");
         assert_eq!(ExportFormat::Srt.extension(), "srt");
         assert_eq!(ExportFormat::Vtt.extension(), "vtt");
+        assert_eq!(ExportFormat::Html.extension(), "html");
+        assert_eq!(ExportFormat::Template.extension(), "md");
     }
 
     #[test]
@@ -166,6 +262,8 @@ mod tests {
         assert_eq!(ExportFormat::Json.to_string(), "json");
         assert_eq!(ExportFormat::Srt.to_string(), "srt");
         assert_eq!(ExportFormat::Vtt.to_string(), "vtt");
+        assert_eq!(ExportFormat::Html.to_string(), "html");
+        assert_eq!(ExportFormat::Template.to_string(), "template");
     }
 
     #[test]
@@ -190,6 +288,8 @@ mod tests {
         assert!(names.contains(&"json"));
         assert!(names.contains(&"srt"));
         assert!(names.contains(&"vtt"));
+        assert!(names.contains(&"html"));
+        assert!(names.contains(&"template"));
     }
 
     #[test]
@@ -231,5 +331,80 @@ mod tests {
         assert!(!opts.include_speakers);
         assert!(!opts.include_metadata);
         assert_eq!(opts.line_width, 0);
+        assert!(opts.audio_url.is_none());
+        assert!(opts.template.is_none());
+        assert_eq!(opts.text, TranscriptText::Original);
+    }
+
+    #[test]
+    fn test_chapter_starts() {
+        let mut meeting = MeetingData::new(None);
+        meeting.transcript.chapters = ["Intro", "Budget", "Hiring"]
+            .iter()
+            .zip([0, 60_000, 65_000])
+            .map(|(title, start_ms)| Chapter {
+                title: title.to_string(),
+                start_ms,
+                end_ms: start_ms + 5000,
+            })
+            .collect();
+        let segment =
+            |start_ms| TranscriptSegment::new(0, start_ms, start_ms + 1000, String::new(), 0);
+
+        let mut starts = ChapterStarts::new(&meeting);
+        let title = |started: Option<(usize, &Chapter)>| started.map(|(i, c)| (i, c.title.clone()));
+        assert_eq!(
+            title(starts.at(&segment(0))),
+            Some((0, "Intro".to_string()))
+        );
+        assert!(starts.at(&segment(30_000)).is_none());
+        // A chapter without segments of its own is passed over
+        assert_eq!(
+            title(starts.at(&segment(70_000))),
+            Some((2, "Hiring".to_string()))
+        );
+        assert!(starts.at(&segment(90_000)).is_none());
+    }
+
+    #[test]
+    fn test_transcript_text() {
+        use crate::meeting::data::TranscriptSegment;
+
+        assert_eq!(TranscriptText::parse("Both"), Some(TranscriptText::Both));
+        assert_eq!(
+            TranscriptText::parse("translated"),
+            Some(TranscriptText::Translation)
+        );
+        assert_eq!(TranscriptText::parse("english"), None);
+
+        let mut segment = TranscriptSegment::new(0, 0, 1000, "Hallo zusammen".to_string(), 0);
+        assert_eq!(
+            TranscriptText::Translation.texts(&segment),
+            ("Hallo zusammen", None)
+        );
+        segment.translation = Some("Hello everyone".to_string());
+        assert_eq!(
+            TranscriptText::Original.texts(&segment),
+            ("Hallo zusammen", None)
+        );
+        assert_eq!(
+            TranscriptText::Translation.texts(&segment),
+            ("Hello everyone", None)
+        );
+        assert_eq!(
+            TranscriptText::Both.texts(&segment),
+            ("Hallo zusammen", Some("Hello everyone"))
+        );
+
+        let mut meeting = MeetingData::new(None);
+        meeting.add_segment(segment);
+        let options = ExportOptions {
+            text: TranscriptText::Both,
+            ..Default::default()
+        };
+        let srt = export_meeting(&meeting, ExportFormat::Srt, &options).unwrap();
+        assert!(srt.contains("Hallo zusammen\nHello everyone\n"));
+        let md = export_meeting(&meeting, ExportFormat::Markdown, &options).unwrap();
+        assert!(md.contains("Hallo zusammen\n> Hello everyone\n"));
     }
 }
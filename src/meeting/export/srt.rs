@@ -25,16 +25,20 @@ impl Exporter for SrtExporter {
             let end = format_srt_time(segment.end_ms);
             output.push_str(&format!("{} --> {}\n", start, end));
 
-            // Text with optional speaker
+            // Text with optional speaker, and the translation as a second line
+            let (text, translation) = options.text.texts(segment);
             if options.include_speakers {
                 let speaker = segment.speaker_display();
                 if !speaker.is_empty() && speaker != "Unknown" {
-                    output.push_str(&format!("[{}] {}\n", speaker, segment.text));
+                    output.push_str(&format!("[{}] {}\n", speaker, text));
                 } else {
-                    output.push_str(&format!("{}\n", segment.text));
+                    output.push_str(&format!("{}\n", text));
                 }
             } else {
-                output.push_str(&format!("{}\n", segment.text));
+                output.push_str(&format!("{}\n", text));
+            }
+            if let Some(translation) = translation {
+                output.push_str(&format!("{}\n", translation));
             }
 
             // Blank line between entries
@@ -50,7 +54,7 @@ impl Exporter for SrtExporter {
 }
 
 /// Format milliseconds as SRT timestamp (HH:MM:SS,mmm)
-fn format_srt_time(ms: u64) -> String {
+pub(crate) fn format_srt_time(ms: u64) -> String {
     let total_secs = ms / 1000;
     let hours = total_secs / 3600;
     let minutes = (total_secs % 3600) / 60;
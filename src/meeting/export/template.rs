@@ -0,0 +1,483 @@
+//! Templated markdown export
+//!
+//! Renders a meeting through a Handlebars template, so notes land in an
+//! Obsidian vault or a Logseq graph with front matter, tags and backlinks.
+//! Two templates are built in (`obsidian`, `logseq`); `[meeting.export]
+//! template` can also name a template file.
+//!
+//! Templates see: `title`, `id`, `date` and `time` (local), `started_at`,
+//! `ended_at`, `duration`, `duration_secs`, `attendees`, `speakers`,
+//! `tags` (`[meeting.export] tags`, then the meeting's own), `word_count`,
+//! `summary` (with `summary`, `key_points`,
+//! `action_items`, `decisions`; absent until summarized), `chapters`
+//! (`title`, `timestamp`, `start_ms`, `end_ms`), `bookmarks` (`label`,
+//! `note`, `timestamp`, `offset_ms`), `segments`
+//! (`timestamp`, `start_ms`, `end_ms`, `speaker`, `text`, `translation`)
+//! and `turns`, consecutive segments of one speaker joined (`speaker`,
+//! `timestamp`, `text`, `translation`, and `chapter`, the title of the
+//! chapter the turn starts). `translation` is only set when exporting both
+//! texts. Helpers: `{{yaml value}}` quotes a value for front
+//! matter and `{{wikilink name}}` makes `[[name]]`. Nothing is HTML-escaped.
+
+use super::{ChapterStarts, ExportError, ExportFormat, ExportOptions, Exporter};
+use crate::meeting::data::{format_offset, MeetingData};
+use chrono::Local;
+use handlebars::{handlebars_helper, Handlebars};
+
+/// Built-in Obsidian note: YAML properties, attendees as links to their
+/// notes, summary, then the transcript by speaker turn
+const OBSIDIAN: &str = r#"---
+title: {{yaml title}}
+date: {{date}}
+time: {{yaml time}}
+duration: {{yaml duration}}
+{{#if attendees}}
+attendees:
+{{#each attendees}}
+  - {{yaml (wikilink this)}}
+{{/each}}
+{{/if}}
+tags:
+{{#each tags}}
+  - {{yaml this}}
+{{/each}}
+meeting_id: {{id}}
+---
+
+# {{title}}
+
+{{#if summary}}
+## Summary
+
+{{summary.summary}}
+
+{{#if summary.key_points}}
+### Key Points
+
+{{#each summary.key_points}}
+- {{this}}
+{{/each}}
+
+{{/if}}
+{{#if summary.action_items}}
+### Action Items
+
+{{#each summary.action_items}}
+- [{{#if completed}}x{{else}} {{/if}}] {{description}}{{#if assignee}} ({{wikilink assignee}}){{/if}}
+{{/each}}
+
+{{/if}}
+{{#if summary.decisions}}
+### Decisions
+
+{{#each summary.decisions}}
+- {{this}}
+{{/each}}
+
+{{/if}}
+{{/if}}
+{{#if chapters}}
+## Chapters
+
+{{#each chapters}}
+- [{{timestamp}}] {{title}}
+{{/each}}
+
+{{/if}}
+{{#if bookmarks}}
+## Bookmarks
+
+{{#each bookmarks}}
+- [{{timestamp}}] {{label}}
+{{/each}}
+
+{{/if}}
+## Transcript
+
+{{#each turns}}
+{{#if chapter}}
+### {{chapter}}
+
+{{/if}}
+**{{speaker}}** ({{timestamp}}): {{text}}
+{{#if translation}}
+> {{translation}}
+{{/if}}
+
+{{/each}}
+"#;
+
+/// Built-in Logseq page: page properties, then one block per speaker turn
+const LOGSEQ: &str = r#"title:: {{title}}
+date:: [[{{date}}]]
+{{#if attendees}}
+attendees:: {{#each attendees}}{{wikilink this}}{{#unless @last}}, {{/unless}}{{/each}}
+{{/if}}
+tags:: {{#each tags}}{{this}}{{#unless @last}}, {{/unless}}{{/each}}
+meeting-id:: {{id}}
+
+{{#if summary}}
+- ## Summary
+	- {{summary.summary}}
+{{#each summary.key_points}}
+	- {{this}}
+{{/each}}
+{{#if summary.action_items}}
+- ## Action Items
+{{#each summary.action_items}}
+	- {{#if completed}}DONE{{else}}TODO{{/if}} {{description}}{{#if assignee}} {{wikilink assignee}}{{/if}}
+{{/each}}
+{{/if}}
+{{#if summary.decisions}}
+- ## Decisions
+{{#each summary.decisions}}
+	- {{this}}
+{{/each}}
+{{/if}}
+{{/if}}
+{{#if chapters}}
+- ## Chapters
+{{#each chapters}}
+	- [{{timestamp}}] {{title}}
+{{/each}}
+{{/if}}
+{{#if bookmarks}}
+- ## Bookmarks
+{{#each bookmarks}}
+	- [{{timestamp}}] {{label}}
+{{/each}}
+{{/if}}
+- ## Transcript
+{{#each turns}}
+{{#if chapter}}
+	- ### {{chapter}}
+{{/if}}
+	- **{{speaker}}** ({{timestamp}}): {{text}}
+{{#if translation}}
+		- {{translation}}
+{{/if}}
+{{/each}}
+"#;
+
+/// Names of the built-in templates
+pub const BUILTIN_TEMPLATES: &[&str] = &["obsidian", "logseq"];
+
+handlebars_helper!(yaml: |value: Json| serde_json::to_string(value).unwrap_or_default());
+handlebars_helper!(wikilink: |name: str| format!("[[{}]]", name));
+
+/// Template source for a built-in name or a template file (`~/` expanded)
+pub fn load_template(name_or_path: &str) -> Result<String, ExportError> {
+    match name_or_path.trim().to_lowercase().as_str() {
+        "obsidian" => return Ok(OBSIDIAN.to_string()),
+        "logseq" => return Ok(LOGSEQ.to_string()),
+        _ => {}
+    }
+    let path = crate::meeting::expand_home(name_or_path);
+    std::fs::read_to_string(&path).map_err(|e| {
+        ExportError::Template(format!(
+            "can't read template {} ({}); built-in templates: {}",
+            path.display(),
+            e,
+            BUILTIN_TEMPLATES.join(", ")
+        ))
+    })
+}
+
+/// Template exporter
+pub struct TemplateExporter;
+
+impl Exporter for TemplateExporter {
+    fn export(
+        &self,
+        meeting: &MeetingData,
+        options: &ExportOptions,
+    ) -> Result<String, ExportError> {
+        let mut registry = Handlebars::new();
+        registry.register_escape_fn(handlebars::no_escape);
+        registry.register_helper("yaml", Box::new(yaml));
+        registry.register_helper("wikilink", Box::new(wikilink));
+
+        let template = options.template.as_deref().unwrap_or(OBSIDIAN);
+        registry
+            .render_template(template, &context(meeting, options))
+            .map_err(|e| ExportError::Template(e.to_string()))
+    }
+
+    fn format(&self) -> ExportFormat {
+        ExportFormat::Template
+    }
+}
+
+/// The data templates render
+fn context(meeting: &MeetingData, options: &ExportOptions) -> serde_json::Value {
+    let metadata = &meeting.metadata;
+    let started = metadata.started_at.with_timezone(&Local);
+
+    let segments: Vec<_> = meeting
+        .transcript
+        .segments
+        .iter()
+        .map(|s| {
+            let (text, translation) = options.text.texts(s);
+            serde_json::json!({
+                "timestamp": s.format_timestamp(),
+                "start_ms": s.start_ms,
+                "end_ms": s.end_ms,
+                "speaker": s.speaker_display(),
+                "text": text,
+                "translation": translation,
+            })
+        })
+        .collect();
+
+    let chapters: Vec<_> = meeting
+        .transcript
+        .chapters
+        .iter()
+        .map(|c| {
+            serde_json::json!({
+                "title": c.title,
+                "timestamp": c.format_timestamp(),
+                "start_ms": c.start_ms,
+                "end_ms": c.end_ms,
+            })
+        })
+        .collect();
+
+    let bookmarks: Vec<_> = meeting
+        .transcript
+        .bookmarks
+        .iter()
+        .map(|b| {
+            serde_json::json!({
+                "label": b.label(),
+                "note": b.note,
+                "timestamp": b.format_timestamp(),
+                "offset_ms": b.offset_ms,
+            })
+        })
+        .collect();
+
+    let mut chapter_starts = ChapterStarts::new(meeting);
+    let mut turns: Vec<serde_json::Value> = Vec::new();
+    for segment in &meeting.transcript.segments {
+        let speaker = segment.speaker_display();
+        let (text, translation) = options.text.texts(segment);
+        // A chapter always starts a new turn
+        let chapter = chapter_starts.at(segment).map(|(_, c)| c.title.as_str());
+        match turns.last_mut() {
+            Some(turn) if chapter.is_none() && turn["speaker"] == speaker.as_str() => {
+                for (key, part) in [("text", Some(text)), ("translation", translation)] {
+                    let Some(part) = part else { continue };
+                    turn[key] = match turn[key].as_str() {
+                        Some(joined) => format!("{} {}", joined, part),
+                        None => part.to_string(),
+                    }
+                    .into();
+                }
+            }
+            _ => turns.push(serde_json::json!({
+                "speaker": speaker,
+                "timestamp": segment.format_timestamp(),
+                "text": text,
+                "translation": translation,
+                "chapter": chapter,
+            })),
+        }
+    }
+
+    let mut tags = options.tags.clone();
+    for tag in &metadata.tags {
+        if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            tags.push(tag.clone());
+        }
+    }
+
+    serde_json::json!({
+        "title": metadata.display_title(),
+        "id": metadata.id.to_string(),
+        "date": started.format("%Y-%m-%d").to_string(),
+        "time": started.format("%H:%M").to_string(),
+        "started_at": started.to_rfc3339(),
+        "ended_at": metadata.ended_at.map(|t| t.with_timezone(&Local).to_rfc3339()),
+        "duration": metadata.duration_secs.map(|d| format_offset(d * 1000)),
+        "duration_secs": metadata.duration_secs,
+        "attendees": metadata.attendees,
+        "speakers": meeting.transcript.speakers(),
+        "tags": tags,
+        "word_count": meeting.transcript.word_count(),
+        "summary": metadata.summary,
+        "chapters": chapters,
+        "bookmarks": bookmarks,
+        "segments": segments,
+        "turns": turns,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meeting::data::{AudioSource, Bookmark, Chapter, TranscriptSegment};
+    use crate::meeting::export::TranscriptText;
+
+    fn create_test_meeting() -> MeetingData {
+        let mut meeting = MeetingData::new(Some("Roadmap: Q3".to_string()));
+        meeting.metadata.attendees = vec!["Alice".to_string(), "Bob".to_string()];
+        meeting.metadata.tags = vec!["Meeting".to_string(), "q3".to_string()];
+
+        let texts = [
+            (AudioSource::Microphone, "Let's start."),
+            (AudioSource::Loopback, "Sounds good."),
+            (AudioSource::Loopback, "I have two items."),
+        ];
+        for (i, (source, text)) in texts.into_iter().enumerate() {
+            let start = i as u64 * 5000;
+            let mut segment = TranscriptSegment::new(i as u32, start, start + 4000, text.into(), 0);
+            segment.source = source;
+            meeting.transcript.add_segment(segment);
+        }
+        meeting.complete();
+        meeting
+    }
+
+    fn render(template: Option<&str>) -> String {
+        let options = ExportOptions {
+            template: template.map(str::to_string),
+            tags: vec!["meeting".to_string(), "work/roadmap".to_string()],
+            ..Default::default()
+        };
+        TemplateExporter
+            .export(&create_test_meeting(), &options)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_obsidian_template() {
+        let output = render(None);
+        assert!(output.starts_with("---\ntitle: \"Roadmap: Q3\"\n"));
+        assert!(output.contains("attendees:\n  - \"[[Alice]]\"\n  - \"[[Bob]]\"\n"));
+        // The meeting's tags follow the configured ones
+        assert!(output.contains("tags:\n  - \"meeting\"\n  - \"work/roadmap\"\n  - \"q3\"\n"));
+        assert!(output.contains("**You** (00:00): Let's start."));
+        // Consecutive segments of a speaker form one turn
+        assert!(output.contains("**Remote** (00:05): Sounds good. I have two items."));
+        assert!(!output.contains("## Summary"));
+    }
+
+    #[test]
+    fn test_chapters() {
+        let mut meeting = create_test_meeting();
+        meeting.transcript.chapters = vec![
+            Chapter {
+                title: "Opening".to_string(),
+                start_ms: 0,
+                end_ms: 9000,
+            },
+            Chapter {
+                title: "Items".to_string(),
+                start_ms: 10_000,
+                end_ms: 14_000,
+            },
+        ];
+        let output = TemplateExporter
+            .export(&meeting, &ExportOptions::default())
+            .unwrap();
+        assert!(output.contains("## Chapters\n\n- [00:00] Opening\n- [00:10] Items\n\n"));
+        assert!(output.contains("### Opening\n\n**You** (00:00): Let's start.\n"));
+        // The chapter splits the remote speaker's turn
+        assert!(output.contains("**Remote** (00:05): Sounds good.\n\n"));
+        assert!(output.contains("### Items\n\n**Remote** (00:10): I have two items.\n"));
+
+        let options = ExportOptions {
+            template: Some(load_template("logseq").unwrap()),
+            ..Default::default()
+        };
+        let output = TemplateExporter.export(&meeting, &options).unwrap();
+        assert!(output.contains("- ## Chapters\n\t- [00:00] Opening\n\t- [00:10] Items\n"));
+        assert!(output.contains("\t- ### Items\n\t- **Remote** (00:10): I have two items.\n"));
+    }
+
+    #[test]
+    fn test_bookmarks() {
+        let mut meeting = create_test_meeting();
+        meeting.transcript.add_bookmark(Bookmark {
+            offset_ms: 7000,
+            note: Some("Two items".to_string()),
+            created_at: chrono::Utc::now(),
+        });
+        let output = TemplateExporter
+            .export(&meeting, &ExportOptions::default())
+            .unwrap();
+        assert!(output.contains("## Bookmarks\n\n- [00:07] Two items\n\n"));
+
+        let options = ExportOptions {
+            template: Some("{{#each bookmarks}}{{offset_ms}} {{note}}{{/each}}".to_string()),
+            ..Default::default()
+        };
+        let output = TemplateExporter.export(&meeting, &options).unwrap();
+        assert_eq!(output, "7000 Two items");
+    }
+
+    #[test]
+    fn test_translations() {
+        let mut meeting = create_test_meeting();
+        meeting.transcript.segments[1].translation = Some("Klingt gut.".to_string());
+        meeting.transcript.segments[2].translation = Some("Ich habe zwei Punkte.".to_string());
+
+        let options = ExportOptions {
+            text: TranscriptText::Both,
+            ..Default::default()
+        };
+        let output = TemplateExporter.export(&meeting, &options).unwrap();
+        assert!(output.contains("**You** (00:00): Let's start.\n\n"));
+        assert!(output.contains(
+            "**Remote** (00:05): Sounds good. I have two items.\n> Klingt gut. Ich habe zwei Punkte.\n"
+        ));
+
+        let options = ExportOptions {
+            text: TranscriptText::Translation,
+            ..Default::default()
+        };
+        let output = TemplateExporter.export(&meeting, &options).unwrap();
+        assert!(output.contains("**Remote** (00:05): Klingt gut. Ich habe zwei Punkte.\n\n"));
+    }
+
+    #[test]
+    fn test_logseq_template() {
+        let output = render(Some(&load_template("logseq").unwrap()));
+        assert!(output.starts_with("title:: Roadmap: Q3\n"));
+        assert!(output.contains("attendees:: [[Alice]], [[Bob]]\n"));
+        assert!(output.contains("tags:: meeting, work/roadmap, q3\n"));
+        assert!(output.contains("\t- **You** (00:00): Let's start.\n"));
+    }
+
+    #[test]
+    fn test_custom_template() {
+        let output = render(Some(
+            "{{title}} <{{#each segments}}{{speaker}}{{#unless @last}},{{/unless}}{{/each}}>",
+        ));
+        // No HTML escaping in markdown notes
+        assert_eq!(output, "Roadmap: Q3 <You,Remote,Remote>");
+
+        let options = ExportOptions {
+            template: Some("{{#each}}".to_string()),
+            ..Default::default()
+        };
+        let result = TemplateExporter.export(&create_test_meeting(), &options);
+        assert!(matches!(result, Err(ExportError::Template(_))));
+    }
+
+    #[test]
+    fn test_load_template() {
+        assert_eq!(load_template("Obsidian").unwrap(), OBSIDIAN);
+        assert!(load_template("/nonexistent/meeting.hbs").is_err());
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("meeting.hbs");
+        std::fs::write(&path, "# {{title}}").unwrap();
+        assert_eq!(
+            load_template(path.to_str().unwrap()).unwrap(),
+            "# {{title}}"
+        );
+    }
+}
@@ -1,7 +1,7 @@
 //! Plain text exporter for meeting transcriptions
 
 use crate::meeting::data::MeetingData;
-use crate::meeting::export::{ExportError, ExportFormat, ExportOptions, Exporter};
+use crate::meeting::export::{ChapterStarts, ExportError, ExportFormat, ExportOptions, Exporter};
 
 /// Plain text exporter
 pub struct TextExporter;
@@ -31,16 +31,53 @@ impl Exporter for TextExporter {
                 let secs = duration % 60;
                 output.push_str(&format!("Duration: {}:{:02}\n", mins, secs));
             }
+            if !meeting.metadata.attendees.is_empty() {
+                output.push_str(&format!(
+                    "Attendees: {}\n",
+                    meeting.metadata.attendees.join(", ")
+                ));
+            }
+            if !meeting.metadata.tags.is_empty() {
+                output.push_str(&format!("Tags: {}\n", meeting.metadata.tags.join(", ")));
+            }
             output.push_str(&format!("Words: {}\n", meeting.transcript.word_count()));
             output.push('\n');
             output.push_str(&"=".repeat(60));
             output.push_str("\n\n");
         }
 
-        // Transcript
+        // Bookmarks
+        let bookmarks = &meeting.transcript.bookmarks;
+        if !bookmarks.is_empty() {
+            output.push_str("Bookmarks:\n");
+            for bookmark in bookmarks {
+                output.push_str(&format!(
+                    "  [{}] {}\n",
+                    bookmark.format_timestamp(),
+                    bookmark.label()
+                ));
+            }
+            output.push('\n');
+        }
+
+        // Transcript, with an underlined title where each chapter starts
+        let mut chapter_starts = ChapterStarts::new(meeting);
         let mut last_speaker = String::new();
 
         for segment in &meeting.transcript.segments {
+            if let Some((_, chapter)) = chapter_starts.at(segment) {
+                if !output.is_empty() && !output.ends_with("\n\n") {
+                    output.push('\n');
+                }
+                let heading = format!("{} ({})", chapter.title, chapter.format_timestamp());
+                output.push_str(&format!(
+                    "{}\n{}\n\n",
+                    heading,
+                    "-".repeat(heading.chars().count())
+                ));
+                last_speaker.clear();
+            }
+
             let mut line = String::new();
 
             // Timestamp
@@ -60,16 +97,23 @@ impl Exporter for TextExporter {
                 }
             }
 
-            // Text
-            line.push_str(&segment.text);
+            // Text, and the translation indented below it
+            let (text, translation) = options.text.texts(segment);
+            line.push_str(text);
+            let mut lines = vec![line];
+            if let Some(translation) = translation {
+                lines.push(format!("  {}", translation));
+            }
 
-            // Word wrap if configured
-            if options.line_width > 0 {
-                output.push_str(&wrap_text(&line, options.line_width));
-            } else {
-                output.push_str(&line);
+            for line in lines {
+                // Word wrap if configured
+                if options.line_width > 0 {
+                    output.push_str(&wrap_text(&line, options.line_width));
+                } else {
+                    output.push_str(&line);
+                }
+                output.push('\n');
             }
-            output.push('\n');
         }
 
         Ok(output)
@@ -108,7 +152,7 @@ fn wrap_text(text: &str, width: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::meeting::data::TranscriptSegment;
+    use crate::meeting::data::{Chapter, TranscriptSegment};
 
     fn create_test_meeting() -> MeetingData {
         let mut meeting = MeetingData::new(Some("Test Meeting".to_string()));
@@ -168,6 +212,32 @@ mod tests {
         assert!(output.contains("Date:"));
     }
 
+    #[test]
+    fn test_text_export_with_chapters() {
+        let mut meeting = create_test_meeting();
+        meeting.transcript.chapters = vec![
+            Chapter {
+                title: "Intro".to_string(),
+                start_ms: 0,
+                end_ms: 5000,
+            },
+            Chapter {
+                title: "Next".to_string(),
+                start_ms: 5000,
+                end_ms: 10000,
+            },
+        ];
+
+        let output = TextExporter
+            .export(&meeting, &ExportOptions::default())
+            .unwrap();
+        assert_eq!(
+            output,
+            "Intro (00:00)\n-------------\n\nHello world, this is a test.\n\n\
+             Next (00:05)\n------------\n\nThis is the second segment.\n"
+        );
+    }
+
     #[test]
     fn test_wrap_text() {
         let text = "This is a long line that should be wrapped at a certain width.";
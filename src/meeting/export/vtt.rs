@@ -40,16 +40,21 @@ impl Exporter for VttExporter {
             let end = format_vtt_time(segment.end_ms);
             output.push_str(&format!("{} --> {}\n", start, end));
 
-            // Text with optional speaker (VTT supports <v> voice spans)
+            // Text with optional speaker (VTT supports <v> voice spans), and
+            // the translation as a second line
+            let (text, translation) = options.text.texts(segment);
             if options.include_speakers {
                 let speaker = segment.speaker_display();
                 if !speaker.is_empty() && speaker != "Unknown" {
-                    output.push_str(&format!("<v {}>{}\n", speaker, segment.text));
+                    output.push_str(&format!("<v {}>{}\n", speaker, text));
                 } else {
-                    output.push_str(&format!("{}\n", segment.text));
+                    output.push_str(&format!("{}\n", text));
                 }
             } else {
-                output.push_str(&format!("{}\n", segment.text));
+                output.push_str(&format!("{}\n", text));
+            }
+            if let Some(translation) = translation {
+                output.push_str(&format!("{}\n", translation));
             }
 
             // Blank line between cues
@@ -65,7 +70,7 @@ impl Exporter for VttExporter {
 }
 
 /// Format milliseconds as VTT timestamp (HH:MM:SS.mmm)
-fn format_vtt_time(ms: u64) -> String {
+pub(crate) fn format_vtt_time(ms: u64) -> String {
     let total_secs = ms / 1000;
     let hours = total_secs / 3600;
     let minutes = (total_secs % 3600) / 60;
@@ -0,0 +1,366 @@
+//! Hooks run when a meeting ends or is summarized
+//!
+//! Configured in `[meeting.hooks]`. Before the hooks run, the meeting is
+//! exported (`export_formats`) into its storage directory as
+//! `export.<ext>`, and a summary as `summary.md`. Then:
+//!
+//! - `on_complete` / `on_summary` run through `sh -c` with the event as
+//!   JSON on stdin and `VOXTYPE_MEETING_*` environment variables
+//! - `webhook_url` receives the same JSON in a POST
+//!
+//! ```json
+//! {"event": "meeting_completed", "meeting_id": "…", "title": "Standup",
+//!  "started_at": "…", "duration_secs": 900, "attendees": [],
+//!  "meeting_dir": "…", "exports": {"markdown": "…/export.md"}}
+//! ```
+
+use super::data::{MeetingData, MeetingSummary};
+use super::export::{export_meeting, ExportFormat, ExportOptions};
+use crate::config::MeetingHooksConfig;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+
+/// What happened to the meeting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookEvent {
+    /// The meeting was stopped and its transcript saved
+    Completed,
+    /// `voxtype meeting summarize` produced a summary
+    Summarized,
+}
+
+impl HookEvent {
+    fn name(self) -> &'static str {
+        match self {
+            HookEvent::Completed => "meeting_completed",
+            HookEvent::Summarized => "meeting_summarized",
+        }
+    }
+}
+
+/// Export the meeting and run the hooks configured for `event`. Every hook
+/// runs even if an earlier one fails; the failures are returned together.
+pub async fn run_hooks(
+    config: &MeetingHooksConfig,
+    event: HookEvent,
+    meeting: &MeetingData,
+    summary: Option<&MeetingSummary>,
+) -> Result<(), String> {
+    let command = match event {
+        HookEvent::Completed => config.on_complete.as_deref(),
+        HookEvent::Summarized => config.on_summary.as_deref(),
+    }
+    .filter(|c| !c.trim().is_empty());
+    let webhook = config
+        .webhook_url
+        .as_deref()
+        .filter(|u| !u.trim().is_empty());
+    if command.is_none() && webhook.is_none() {
+        return Ok(());
+    }
+
+    let mut errors = Vec::new();
+    let exports = write_exports(config, meeting, summary).unwrap_or_else(|e| {
+        errors.push(e);
+        BTreeMap::new()
+    });
+    let payload = payload(event, meeting, &exports);
+
+    if let Some(command) = command {
+        if let Err(e) = run_command(command, config.timeout_secs, &payload).await {
+            errors.push(e);
+        }
+    }
+    if let Some(url) = webhook {
+        if let Err(e) = post_webhook(config, url, &payload).await {
+            errors.push(e);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("; "))
+    }
+}
+
+/// Write the configured exports (and the summary) into the meeting
+/// directory. Returns format name → path.
+fn write_exports(
+    config: &MeetingHooksConfig,
+    meeting: &MeetingData,
+    summary: Option<&MeetingSummary>,
+) -> Result<BTreeMap<String, PathBuf>, String> {
+    let dir = meeting
+        .metadata
+        .storage_path
+        .as_ref()
+        .ok_or_else(|| "meeting has no storage directory".to_string())?;
+    let options = ExportOptions {
+        include_timestamps: true,
+        include_speakers: true,
+        include_metadata: true,
+        ..Default::default()
+    };
+
+    let mut exports = BTreeMap::new();
+    for name in &config.export_formats {
+        let format = ExportFormat::parse(name)
+            .ok_or_else(|| format!("unknown export format '{}' in [meeting.hooks]", name))?;
+        let path = dir.join(format!("export.{}", format.extension()));
+        let content = export_meeting(meeting, format, &options)
+            .map_err(|e| format!("{} export failed: {}", format, e))?;
+        std::fs::write(&path, content).map_err(|e| format!("{}: {}", path.display(), e))?;
+        exports.insert(format.to_string(), path);
+    }
+    if let Some(summary) = summary {
+        let path = dir.join("summary.md");
+        std::fs::write(&path, super::summary::summary_to_markdown(summary))
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        exports.insert("summary".to_string(), path);
+    }
+    Ok(exports)
+}
+
+/// The JSON sent to commands and the webhook
+fn payload(
+    event: HookEvent,
+    meeting: &MeetingData,
+    exports: &BTreeMap<String, PathBuf>,
+) -> serde_json::Value {
+    let metadata = &meeting.metadata;
+    serde_json::json!({
+        "event": event.name(),
+        "meeting_id": metadata.id.to_string(),
+        "title": metadata.display_title(),
+        "started_at": metadata.started_at.to_rfc3339(),
+        "duration_secs": metadata.duration_secs,
+        "attendees": metadata.attendees,
+        "meeting_dir": metadata.storage_path,
+        "exports": exports,
+    })
+}
+
+async fn run_command(
+    command: &str,
+    timeout_secs: u64,
+    payload: &serde_json::Value,
+) -> Result<(), String> {
+    tracing::debug!("Running meeting hook: {}", command);
+
+    let mut cmd = tokio::process::Command::new("sh");
+    cmd.arg("-c")
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    for (key, value) in hook_env(payload) {
+        cmd.env(key, value);
+    }
+
+    let run = async {
+        let mut child = cmd
+            .spawn()
+            .map_err(|e| format!("meeting hook failed to execute: {}", e))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            // A command that doesn't read stdin closes it early; that's fine
+            let _ = stdin.write_all(payload.to_string().as_bytes()).await;
+        }
+        child
+            .wait_with_output()
+            .await
+            .map_err(|e| format!("meeting hook failed: {}", e))
+    };
+    let output = tokio::time::timeout(Duration::from_secs(timeout_secs), run)
+        .await
+        .map_err(|_| format!("meeting hook timed out after {}s", timeout_secs))??;
+
+    if output.status.success() {
+        tracing::info!("Meeting hook completed successfully");
+        Ok(())
+    } else {
+        Err(format!(
+            "meeting hook failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+/// Environment for hook commands: `VOXTYPE_MEETING_EVENT`, `_ID`, `_TITLE`,
+/// `_DIR`, and one `VOXTYPE_MEETING_EXPORT_<FORMAT>` per export
+fn hook_env(payload: &serde_json::Value) -> Vec<(String, String)> {
+    let field = |key: &str| payload[key].as_str().unwrap_or_default().to_string();
+    let mut env = vec![
+        ("VOXTYPE_MEETING_EVENT".to_string(), field("event")),
+        ("VOXTYPE_MEETING_ID".to_string(), field("meeting_id")),
+        ("VOXTYPE_MEETING_TITLE".to_string(), field("title")),
+        ("VOXTYPE_MEETING_DIR".to_string(), field("meeting_dir")),
+    ];
+    if let Some(exports) = payload["exports"].as_object() {
+        for (format, path) in exports {
+            env.push((
+                format!("VOXTYPE_MEETING_EXPORT_{}", format.to_uppercase()),
+                path.as_str().unwrap_or_default().to_string(),
+            ));
+        }
+    }
+    env
+}
+
+async fn post_webhook(
+    config: &MeetingHooksConfig,
+    url: &str,
+    payload: &serde_json::Value,
+) -> Result<(), String> {
+    let url = url.to_string();
+    let token = config
+        .webhook_token
+        .clone()
+        .or_else(|| std::env::var("VOXTYPE_MEETING_WEBHOOK_TOKEN").ok());
+    let body = payload.clone();
+    let timeout = Duration::from_secs(config.timeout_secs);
+
+    tokio::task::spawn_blocking(move || {
+        let mut request = crate::network::agent_builder(&url)
+            .build()
+            .post(&url)
+            .timeout(timeout);
+        if let Some(ref token) = token {
+            request = request.set("Authorization", &format!("Bearer {}", token));
+        }
+        request.send_json(body).map_err(|e| match e {
+            ureq::Error::Status(code, resp) => format!(
+                "meeting webhook: {} returned {}: {}",
+                url,
+                code,
+                resp.into_string().unwrap_or_default().trim()
+            ),
+            ureq::Error::Transport(t) => format!("meeting webhook: {}: {}", url, t),
+        })
+    })
+    .await
+    .map_err(|e| format!("meeting webhook: {}", e))??;
+
+    tracing::info!("Meeting event posted to webhook");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meeting::data::TranscriptSegment;
+    use tempfile::TempDir;
+
+    fn finished_meeting(dir: &TempDir) -> MeetingData {
+        let mut meeting = MeetingData::new(Some("Standup".to_string()));
+        meeting.add_segment(TranscriptSegment::new(
+            0,
+            0,
+            2000,
+            "Shipping today.".to_string(),
+            0,
+        ));
+        meeting.metadata.storage_path = Some(dir.path().to_path_buf());
+        meeting.complete();
+        meeting
+    }
+
+    fn hooks(on_complete: Option<&str>) -> MeetingHooksConfig {
+        MeetingHooksConfig {
+            on_complete: on_complete.map(str::to_string),
+            export_formats: vec!["markdown".to_string(), "json".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_exports_and_payload() {
+        let dir = TempDir::new().unwrap();
+        let meeting = finished_meeting(&dir);
+        let summary = MeetingSummary {
+            summary: "We ship today.".to_string(),
+            key_points: Vec::new(),
+            action_items: Vec::new(),
+            decisions: Vec::new(),
+            generated_at: chrono::Utc::now(),
+            model: None,
+            chapters: Vec::new(),
+        };
+
+        let exports = write_exports(&hooks(None), &meeting, Some(&summary)).unwrap();
+        assert_eq!(exports["markdown"], dir.path().join("export.md"));
+        assert_eq!(exports["json"], dir.path().join("export.json"));
+        assert!(std::fs::read_to_string(&exports["markdown"])
+            .unwrap()
+            .contains("Shipping today."));
+        assert!(std::fs::read_to_string(&exports["summary"])
+            .unwrap()
+            .contains("We ship today."));
+
+        let payload = payload(HookEvent::Summarized, &meeting, &exports);
+        assert_eq!(payload["event"], "meeting_summarized");
+        assert_eq!(payload["title"], "Standup");
+        assert_eq!(payload["meeting_id"], meeting.metadata.id.to_string());
+
+        let env = hook_env(&payload);
+        assert!(env.contains(&(
+            "VOXTYPE_MEETING_EXPORT_MARKDOWN".to_string(),
+            dir.path().join("export.md").display().to_string()
+        )));
+    }
+
+    #[test]
+    fn test_unknown_export_format() {
+        let dir = TempDir::new().unwrap();
+        let mut config = hooks(None);
+        config.export_formats = vec!["docx".to_string()];
+        assert!(write_exports(&config, &finished_meeting(&dir), None).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_command_gets_env_and_payload() {
+        let dir = TempDir::new().unwrap();
+        let meeting = finished_meeting(&dir);
+        let out = dir.path().join("hook.out");
+        let command = format!(
+            "echo \"$VOXTYPE_MEETING_EVENT $VOXTYPE_MEETING_TITLE\" > {0}; cat >> {0}",
+            out.display()
+        );
+
+        run_hooks(&hooks(Some(&command)), HookEvent::Completed, &meeting, None)
+            .await
+            .unwrap();
+        let written = std::fs::read_to_string(&out).unwrap();
+        assert!(written.starts_with("meeting_completed Standup\n"));
+        assert!(written.contains("\"exports\":{"));
+
+        // Only on_complete is set, so summarizing runs nothing
+        std::fs::remove_file(&out).unwrap();
+        run_hooks(
+            &hooks(Some(&command)),
+            HookEvent::Summarized,
+            &meeting,
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(!out.exists());
+    }
+
+    #[tokio::test]
+    async fn test_failing_command() {
+        let dir = TempDir::new().unwrap();
+        let result = run_hooks(
+            &hooks(Some("echo broken >&2; exit 3")),
+            HookEvent::Completed,
+            &finished_meeting(&dir),
+            None,
+        )
+        .await;
+        assert_eq!(result.unwrap_err(), "meeting hook failed: broken");
+    }
+}
@@ -0,0 +1,274 @@
+//! Live meeting events for `voxtype meeting watch`
+//!
+//! While a meeting runs, the daemon publishes what happens to it (start,
+//! chunks being transcribed, new transcript segments, bookmarks, pause,
+//! resume, stop)
+//! on a Unix socket at `$XDG_RUNTIME_DIR/voxtype/meeting.sock`, one JSON
+//! object per line.
+//!
+//! A client that connects mid-meeting first receives every event since the
+//! meeting started, so it can rebuild the transcript so far. Events of the
+//! last meeting stay available until the next one starts. Clients that fall
+//! behind are disconnected; the daemon never waits on them.
+
+use super::data::{AudioSource, Bookmark, MeetingSummary, TranscriptSegment};
+use crate::config::Config;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio::io::AsyncWriteExt;
+use tokio::net::UnixListener;
+use tokio::sync::mpsc;
+
+/// Lines queued per client beyond the replayed history. A client that
+/// lets this many events pile up is dropped.
+const CLIENT_QUEUE_DEPTH: usize = 256;
+
+/// One thing that happened to the current meeting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum LiveEvent {
+    /// A meeting started
+    Started {
+        meeting_id: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+        started_at: DateTime<Utc>,
+    },
+    /// Audio from `source` is being transcribed
+    ChunkStarted { source: AudioSource, audio_ms: u64 },
+    /// A chunk was transcribed into `segments` (empty when it was silent)
+    ChunkProcessed {
+        source: AudioSource,
+        segments: Vec<TranscriptSegment>,
+    },
+    /// The running summary was updated; it covers the transcript up to
+    /// `covered_ms`
+    Summary {
+        summary: MeetingSummary,
+        covered_ms: u64,
+    },
+    /// The meeting was bookmarked
+    Marked { bookmark: Bookmark },
+    /// Recording paused
+    Paused { at: DateTime<Utc> },
+    /// Recording resumed
+    Resumed { at: DateTime<Utc> },
+    /// The meeting ended
+    Stopped { at: DateTime<Utc> },
+    /// Processing failed; the meeting continues
+    Error { message: String },
+}
+
+/// Default path for the meeting events socket
+pub fn default_socket_path() -> PathBuf {
+    Config::runtime_dir().join("meeting.sock")
+}
+
+#[derive(Default)]
+struct HubState {
+    /// Events of the current (or last) meeting, serialized
+    history: Vec<Arc<str>>,
+    clients: Vec<mpsc::Sender<Arc<str>>>,
+}
+
+/// Publishes meeting events to `meeting watch` clients
+#[derive(Clone)]
+pub struct LiveHub {
+    state: Arc<Mutex<HubState>>,
+    socket_path: PathBuf,
+}
+
+impl LiveHub {
+    /// Bind the socket and start accepting clients. A stale socket file
+    /// from a crashed daemon is replaced.
+    pub fn start(socket_path: PathBuf) -> io::Result<Self> {
+        if let Some(parent) = socket_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        if socket_path.exists() {
+            let _ = std::fs::remove_file(&socket_path);
+        }
+        let listener = UnixListener::bind(&socket_path)?;
+
+        let hub = Self {
+            state: Arc::new(Mutex::new(HubState::default())),
+            socket_path,
+        };
+        tokio::spawn(accept_loop(listener, hub.state.clone()));
+        Ok(hub)
+    }
+
+    /// Send `event` to every client and keep it for clients that connect
+    /// later
+    pub fn publish(&self, event: &LiveEvent) {
+        let line: Arc<str> = match serde_json::to_string(event) {
+            Ok(line) => line.into(),
+            Err(e) => {
+                tracing::warn!("Failed to serialize meeting event: {}", e);
+                return;
+            }
+        };
+
+        let mut state = self.state.lock().expect("meeting hub state lock");
+        if matches!(event, LiveEvent::Started { .. }) {
+            state.history.clear();
+        }
+        state.history.push(line.clone());
+        state
+            .clients
+            .retain(|client| match client.try_send(line.clone()) {
+                Ok(()) => true,
+                Err(mpsc::error::TrySendError::Full(_)) => {
+                    tracing::debug!("Meeting watch client fell behind, disconnecting");
+                    false
+                }
+                Err(mpsc::error::TrySendError::Closed(_)) => false,
+            });
+    }
+
+    /// Path of the bound socket
+    pub fn socket_path(&self) -> &Path {
+        &self.socket_path
+    }
+
+    /// Best-effort removal of the socket file. Called on shutdown.
+    pub fn cleanup(&self) {
+        let _ = std::fs::remove_file(&self.socket_path);
+    }
+}
+
+async fn accept_loop(listener: UnixListener, state: Arc<Mutex<HubState>>) {
+    loop {
+        let mut stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                tracing::warn!("Meeting socket accept failed: {}", e);
+                continue;
+            }
+        };
+
+        let mut rx = {
+            let mut state = state.lock().expect("meeting hub state lock");
+            let (tx, rx) = mpsc::channel(state.history.len() + CLIENT_QUEUE_DEPTH);
+            for line in &state.history {
+                let _ = tx.try_send(line.clone());
+            }
+            state.clients.push(tx);
+            rx
+        };
+        tracing::debug!("Meeting watch client connected");
+
+        tokio::spawn(async move {
+            while let Some(line) = rx.recv().await {
+                let written = async {
+                    stream.write_all(line.as_bytes()).await?;
+                    stream.write_all(b"\n").await
+                }
+                .await;
+                if written.is_err() {
+                    break;
+                }
+            }
+        });
+    }
+}
+
+/// Blocking reader for the meeting events socket
+pub struct LiveClient {
+    reader: io::BufReader<std::os::unix::net::UnixStream>,
+}
+
+impl LiveClient {
+    /// Connect to the daemon's meeting socket
+    pub fn connect(socket_path: &Path) -> io::Result<Self> {
+        let stream = std::os::unix::net::UnixStream::connect(socket_path)?;
+        Ok(Self {
+            reader: io::BufReader::new(stream),
+        })
+    }
+
+    /// Wait for the next event. Returns `None` when the daemon closes the
+    /// connection. Lines this version doesn't understand are skipped.
+    pub fn next_event(&mut self) -> io::Result<Option<LiveEvent>> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if self.reader.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            match serde_json::from_str(&line) {
+                Ok(event) => return Ok(Some(event)),
+                Err(e) => tracing::debug!("Skipping meeting event {:?}: {}", line.trim(), e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_event_json() {
+        let event = LiveEvent::ChunkStarted {
+            source: AudioSource::Loopback,
+            audio_ms: 30_000,
+        };
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"event":"chunk_started","source":"loopback","audio_ms":30000}"#
+        );
+
+        let parsed: LiveEvent = serde_json::from_str(
+            r#"{"event":"started","meeting_id":"abc","started_at":"2026-01-01T10:00:00Z"}"#,
+        )
+        .unwrap();
+        assert!(matches!(parsed, LiveEvent::Started { title: None, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_late_client_gets_current_meeting() {
+        let dir = TempDir::new().unwrap();
+        let hub = LiveHub::start(dir.path().join("meeting.sock")).unwrap();
+        let started = |id: &str| LiveEvent::Started {
+            meeting_id: id.to_string(),
+            title: None,
+            started_at: Utc::now(),
+        };
+
+        hub.publish(&started("old"));
+        hub.publish(&LiveEvent::Error {
+            message: "from the old meeting".to_string(),
+        });
+        hub.publish(&started("new"));
+        hub.publish(&LiveEvent::ChunkStarted {
+            source: AudioSource::Microphone,
+            audio_ms: 1000,
+        });
+
+        let path = hub.socket_path().to_path_buf();
+        let events = tokio::task::spawn_blocking(move || {
+            let mut client = LiveClient::connect(&path).unwrap();
+            [
+                client.next_event().unwrap().unwrap(),
+                client.next_event().unwrap().unwrap(),
+            ]
+        })
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            &events[0],
+            LiveEvent::Started { meeting_id, .. } if meeting_id == "new"
+        ));
+        assert!(matches!(
+            events[1],
+            LiveEvent::ChunkStarted { audio_ms: 1000, .. }
+        ));
+        hub.cleanup();
+    }
+}
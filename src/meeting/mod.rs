@@ -22,22 +22,36 @@
 //! - **Phase 4 (v0.6.0):** Remote server sync for corporate deployments
 //! - **Phase 5 (v0.6.1):** AI summarization with action items
 
+pub mod calendar;
+pub mod calls;
+pub mod captions;
+pub mod chapters;
 pub mod chunk;
+pub mod corrections;
 pub mod data;
 pub mod diarization;
 pub mod export;
+pub mod hooks;
+pub mod live;
+pub mod playback;
+pub mod retention;
 pub mod state;
+pub mod stats;
 pub mod storage;
 pub mod summary;
+pub mod sync;
+pub mod translate;
 
 pub use chunk::{ChunkBuffer, ChunkConfig, ChunkProcessor, ProcessedChunk, VoiceActivityDetector};
 pub use data::{
-    ActionItem, AudioSource, MeetingData, MeetingId, MeetingMetadata, MeetingStatus,
-    MeetingSummary, Transcript, TranscriptSegment,
+    ActionItem, AudioSource, Bookmark, Correction, MeetingData, MeetingId, MeetingMetadata,
+    MeetingStatus, MeetingSummary, Revision, Transcript, TranscriptSegment,
+};
+pub use export::{
+    export_meeting, export_meeting_to_file, ExportFormat, ExportOptions, TranscriptText,
 };
-pub use export::{export_meeting, export_meeting_to_file, ExportFormat, ExportOptions};
 pub use state::{ChunkState, MeetingState};
-pub use storage::{MeetingStorage, StorageConfig, StorageError};
+pub use storage::{MeetingStorage, SearchHit, StorageConfig, StorageError};
 
 use crate::error::{MeetingError, Result};
 use crate::output::post_process::PostProcessor;
@@ -110,6 +124,8 @@ pub struct MeetingDaemon {
     engine_name: String,
     event_tx: mpsc::Sender<MeetingEvent>,
     post_processor: Option<PostProcessor>,
+    /// Adds translations to segments (`[meeting.translation]`)
+    translator: Option<translate::Translator>,
     /// Previous chunk's post-processed text, tracked per audio source
     /// so mic and loopback contexts don't bleed into each other
     last_chunk_text: HashMap<AudioSource, String>,
@@ -118,6 +134,14 @@ pub struct MeetingDaemon {
     /// timelines stay anchored to real wall-clock elapsed time instead
     /// of being pushed forward by the other source's segments.
     source_offsets: HashMap<AudioSource, u64>,
+    /// Recording of the meeting (mic and loopback mixed) when
+    /// `retain_audio` is set
+    audio_writer: Option<hound::WavWriter<std::io::BufWriter<std::fs::File>>>,
+    /// Audio received per source since the last `sync_source_offsets`,
+    /// mixed into the recording there
+    pending_audio: HashMap<AudioSource, Vec<f32>>,
+    /// Duration a reopened meeting had before this session, in seconds
+    prior_duration_secs: u64,
 }
 
 impl MeetingDaemon {
@@ -135,19 +159,26 @@ impl MeetingDaemon {
             Arc::from(transcribe::create_transcriber(&meeting_app_config)?);
         let engine_name = format!("{:?}", meeting_app_config.engine).to_lowercase();
 
-        let post_processor = app_config.output.post_process.as_ref().map(|cfg| {
-            tracing::info!(
-                "Meeting post-processing enabled: command={:?}, timeout={}ms",
-                cfg.command,
-                cfg.timeout_ms
-            );
-            PostProcessor::new(cfg)
-        });
+        let post_processor =
+            PostProcessor::from_output_config(&app_config.output, "Meeting post-processing");
+        let translator = translate::Translator::new(
+            &app_config.meeting.translation,
+            &meeting_app_config,
+            &transcriber,
+        );
 
         // Create diarizer if configured
         let diarizer = config.diarization.as_ref().and_then(|diar_config| {
             if diar_config.enabled {
-                let d = diarization::create_diarizer(diar_config);
+                let mut diar_config = diar_config.clone();
+                match storage.list_enrolled_speakers() {
+                    Ok(enrolled) => {
+                        diar_config.enrolled_speakers =
+                            enrolled.into_iter().map(|(speaker, _)| speaker).collect();
+                    }
+                    Err(e) => tracing::warn!("Failed to load enrolled speakers: {}", e),
+                }
+                let d = diarization::create_diarizer(&diar_config);
                 tracing::info!("Meeting diarization enabled: {}", d.name());
                 Some(d)
             } else {
@@ -165,19 +196,29 @@ impl MeetingDaemon {
             engine_name,
             event_tx,
             post_processor,
+            translator,
             last_chunk_text: HashMap::new(),
             source_offsets: HashMap::new(),
+            audio_writer: None,
+            pending_audio: HashMap::new(),
+            prior_duration_secs: 0,
         })
     }
 
-    /// Start a new meeting
-    pub async fn start(&mut self, title: Option<String>) -> Result<MeetingId> {
+    /// Start a new meeting. `attendees` come from the calendar event the
+    /// meeting belongs to, if any.
+    pub async fn start(
+        &mut self,
+        title: Option<String>,
+        attendees: Vec<String>,
+    ) -> Result<MeetingId> {
         if !self.state.is_idle() {
             return Err(MeetingError::AlreadyInProgress.into());
         }
 
         // Create meeting
         let mut meeting = MeetingData::new(title);
+        meeting.metadata.attendees = attendees;
         meeting.metadata.model = Some(self.engine_name.clone());
 
         // Create storage directory
@@ -185,11 +226,14 @@ impl MeetingDaemon {
             .storage
             .create_meeting(&meeting.metadata)
             .map_err(|e| MeetingError::Storage(e.to_string()))?;
+
         meeting.metadata.storage_path = Some(storage_path);
+        self.open_audio(&mut meeting.metadata, 0);
 
         let meeting_id = meeting.metadata.id;
         self.current_meeting = Some(meeting);
         self.state = MeetingState::start();
+        self.prior_duration_secs = 0;
 
         let _ = self
             .event_tx
@@ -200,6 +244,93 @@ impl MeetingDaemon {
         Ok(meeting_id)
     }
 
+    /// Reopen a stored meeting, such as one recovered after a crash, and
+    /// keep appending to its transcript (and retained audio). The timeline
+    /// continues where the meeting's recording ended.
+    pub async fn reopen(&mut self, meeting_id: &MeetingId) -> Result<MeetingId> {
+        if !self.state.is_idle() {
+            return Err(MeetingError::AlreadyInProgress.into());
+        }
+
+        let mut meeting = self
+            .storage
+            .load_meeting_data(meeting_id)
+            .map_err(|e| MeetingError::Storage(e.to_string()))?;
+        let offset_ms = meeting
+            .metadata
+            .duration_secs
+            .unwrap_or(0)
+            .saturating_mul(1000)
+            .max(meeting.transcript.duration_ms());
+
+        meeting.metadata.status = MeetingStatus::Active;
+        meeting.metadata.ended_at = None;
+        // Chapters are redone when the longer meeting ends
+        meeting.transcript.chapters.clear();
+        self.open_audio(&mut meeting.metadata, offset_ms);
+        self.storage
+            .update_meeting(&meeting.metadata)
+            .map_err(|e| MeetingError::Storage(e.to_string()))?;
+
+        for source in [AudioSource::Microphone, AudioSource::Loopback] {
+            self.source_offsets.insert(source, offset_ms);
+        }
+        self.prior_duration_secs = offset_ms / 1000;
+        self.state = MeetingState::start_from(meeting.transcript.total_chunks);
+        self.current_meeting = Some(meeting);
+
+        let meeting_id = *meeting_id;
+        let _ = self
+            .event_tx
+            .send(MeetingEvent::Started { meeting_id })
+            .await;
+        tracing::info!("Meeting reopened: {}", meeting_id);
+
+        Ok(meeting_id)
+    }
+
+    /// Start retaining audio when `retain_audio` is set, appending to the
+    /// meeting's recording if it has one. The recording is padded with
+    /// silence up to `offset_ms` so it stays aligned with the transcript.
+    fn open_audio(&mut self, metadata: &mut MeetingMetadata, offset_ms: u64) {
+        if !self.config.retain_audio {
+            return;
+        }
+        let Some(audio_path) = metadata
+            .storage_path
+            .as_ref()
+            .map(|path| path.join(data::AUDIO_FILE_NAME))
+        else {
+            return;
+        };
+
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 16000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let opened = if audio_path.exists() {
+            hound::WavWriter::append(&audio_path)
+        } else {
+            hound::WavWriter::create(&audio_path, spec)
+        };
+        let padded = opened.and_then(|mut writer| {
+            let offset_samples = offset_ms * spec.sample_rate as u64 / 1000;
+            for _ in (writer.len() as u64)..offset_samples {
+                writer.write_sample(0i16)?;
+            }
+            Ok(writer)
+        });
+        match padded {
+            Ok(writer) => {
+                self.audio_writer = Some(writer);
+                metadata.audio_retained = true;
+            }
+            Err(e) => tracing::warn!("Failed to open {:?}: {}", audio_path, e),
+        }
+    }
+
     /// Pause the current meeting
     pub async fn pause(&mut self) -> Result<()> {
         if !self.state.is_active() {
@@ -235,11 +366,23 @@ impl MeetingDaemon {
         self.state = std::mem::take(&mut self.state).stop();
         self.last_chunk_text.clear();
         self.source_offsets.clear();
+        let session_secs = self.state.meeting_duration().unwrap_or_default().as_secs();
+
+        self.write_pending_audio();
+        if let Some(writer) = self.audio_writer.take() {
+            if let Err(e) = writer.finalize() {
+                tracing::warn!("Failed to finish the meeting audio file: {}", e);
+            }
+        }
 
         // Finalize meeting
         if let Some(ref mut meeting) = self.current_meeting {
             meeting.complete();
             meeting.metadata.chunk_count = meeting.transcript.total_chunks;
+            if self.prior_duration_secs > 0 {
+                // A reopened meeting's wall-clock span includes the downtime
+                meeting.metadata.duration_secs = Some(self.prior_duration_secs + session_secs);
+            }
 
             // Save transcript
             self.storage
@@ -291,6 +434,74 @@ impl MeetingDaemon {
         for offset in self.source_offsets.values_mut() {
             *offset = max_offset;
         }
+        self.write_pending_audio();
+    }
+
+    /// Save the meeting so far (transcript, metadata and the retained audio's
+    /// header), so a crash loses at most the chunk being transcribed. Call
+    /// after each round of chunks.
+    pub fn checkpoint(&mut self) {
+        if let Some(ref mut writer) = self.audio_writer {
+            if let Err(e) = writer.flush() {
+                tracing::warn!("Failed to flush meeting audio: {}", e);
+            }
+        }
+        let Some(ref mut meeting) = self.current_meeting else {
+            return;
+        };
+        let recorded_ms = self.source_offsets.values().copied().max().unwrap_or(0);
+        meeting.metadata.duration_secs = Some(recorded_ms / 1000);
+        meeting.metadata.chunk_count = meeting.transcript.total_chunks;
+        if let Err(e) = self.storage.save_checkpoint(meeting) {
+            tracing::warn!("Failed to checkpoint meeting: {}", e);
+        }
+    }
+
+    /// Bookmark the current meeting at the moment being recorded: the audio
+    /// handed to `process_chunk` so far plus `buffered_ms` not yet chunked.
+    /// Saved right away, so it survives a crash.
+    pub fn mark(&mut self, note: Option<String>, buffered_ms: u64) -> Result<Bookmark> {
+        if self.state.is_idle() {
+            return Err(MeetingError::NotInProgress.into());
+        }
+        let recorded_ms = self.source_offsets.values().copied().max().unwrap_or(0);
+        let Some(ref mut meeting) = self.current_meeting else {
+            return Err(MeetingError::NotInProgress.into());
+        };
+        let bookmark = Bookmark {
+            offset_ms: recorded_ms + buffered_ms,
+            note: note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty()),
+            created_at: chrono::Utc::now(),
+        };
+        meeting.transcript.add_bookmark(bookmark.clone());
+        self.checkpoint();
+        Ok(bookmark)
+    }
+
+    /// Mix the audio received since the last call into the recording. The
+    /// shorter source is padded with silence, like its timestamp offset.
+    fn write_pending_audio(&mut self) {
+        let Some(ref mut writer) = self.audio_writer else {
+            return;
+        };
+        let len = self.pending_audio.values().map(Vec::len).max().unwrap_or(0);
+        let mut mixed = vec![0.0f32; len];
+        for samples in self.pending_audio.values_mut() {
+            for (mixed, sample) in mixed.iter_mut().zip(samples.drain(..)) {
+                *mixed += sample;
+            }
+        }
+
+        let written: std::result::Result<(), hound::Error> = mixed
+            .into_iter()
+            .try_for_each(|s| writer.write_sample((s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16));
+        if let Err(e) = written {
+            tracing::warn!(
+                "Failed to write meeting audio, no longer retaining it: {}",
+                e
+            );
+            self.audio_writer = None;
+        }
     }
 
     /// Get mutable access to current meeting data (for dedup, etc.)
@@ -317,6 +528,13 @@ impl MeetingDaemon {
             return Ok(None);
         }
 
+        if self.audio_writer.is_some() {
+            self.pending_audio
+                .entry(source)
+                .or_default()
+                .extend_from_slice(&samples);
+        }
+
         let Some(ref transcriber) = self.transcriber else {
             return Err(MeetingError::TranscriberNotInitialized.into());
         };
@@ -369,6 +587,12 @@ impl MeetingDaemon {
             }
         }
 
+        if let Some(ref translator) = self.translator {
+            translator
+                .translate(&samples, start_offset_ms, &mut result.segments)
+                .await;
+        }
+
         // Run diarization on the transcribed segments
         if let Some(ref diarizer) = self.diarizer {
             if !result.segments.is_empty() {
@@ -409,13 +633,26 @@ impl MeetingDaemon {
     }
 }
 
-/// List meetings from storage
+/// List meetings from storage, optionally only those with `tag`
 pub fn list_meetings(
     config: &MeetingConfig,
     limit: Option<u32>,
+    tag: Option<&str>,
 ) -> std::result::Result<Vec<MeetingMetadata>, StorageError> {
     let storage = MeetingStorage::open(config.storage.clone())?;
-    storage.list_meetings(limit)
+    storage.list_meetings(limit, tag)
+}
+
+/// Search the transcripts of stored meetings, optionally only those with
+/// `tag`
+pub fn search_meetings(
+    config: &MeetingConfig,
+    query: &str,
+    limit: u32,
+    tag: Option<&str>,
+) -> std::result::Result<Vec<SearchHit>, StorageError> {
+    let storage = MeetingStorage::open(config.storage.clone())?;
+    storage.search(query, limit, tag)
 }
 
 /// Get a meeting by ID (or "latest")
@@ -440,6 +677,14 @@ pub fn export_meeting_by_id(
         .map_err(|e| StorageError::Io(std::io::Error::other(e.to_string())))
 }
 
+/// Expand a leading `~/` in a configured path
+pub fn expand_home(path: &str) -> std::path::PathBuf {
+    match (path.strip_prefix("~/"), directories::BaseDirs::new()) {
+        (Some(rest), Some(dirs)) => dirs.home_dir().join(rest),
+        _ => std::path::PathBuf::from(path),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,251 @@
+//! Playback of retained meeting audio
+//!
+//! `voxtype meeting play` plays a meeting's `audio.wav` from a transcript
+//! position or bookmark, to check what was said against the transcript.
+//! The audio runs
+//! on the meeting clock, so a segment's `start_ms` is its position in the
+//! file. Audio plays through rodio, or through `[meeting.audio] player`.
+
+use super::data::{format_offset, Bookmark, MeetingData, TranscriptSegment};
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+/// Parse a meeting position: `HH:MM:SS`, `MM:SS` or seconds, each with
+/// optional fractional seconds. Returns milliseconds.
+pub fn parse_offset(s: &str) -> Option<u64> {
+    let mut ms = 0.0;
+    let parts: Vec<&str> = s.trim().split(':').collect();
+    if parts.len() > 3 {
+        return None;
+    }
+    for (i, part) in parts.iter().enumerate() {
+        let value: f64 = part.parse().ok()?;
+        let last = i == parts.len() - 1;
+        // Only the seconds may have a fraction, and only leading fields
+        // may exceed 59
+        if value < 0.0 || (!last && value.fract() != 0.0) || (i > 0 && value >= 60.0) {
+            return None;
+        }
+        ms = ms * 60.0 + value * 1000.0;
+    }
+    Some(ms.round() as u64)
+}
+
+/// Transcript segments as `meeting play --list` numbers them: in meeting
+/// time order, without empty ones
+pub fn numbered_segments(meeting: &MeetingData) -> Vec<&TranscriptSegment> {
+    let mut segments: Vec<&TranscriptSegment> = meeting
+        .transcript
+        .segments
+        .iter()
+        .filter(|s| !s.text.trim().is_empty())
+        .collect();
+    segments.sort_by_key(|s| s.start_ms);
+    segments
+}
+
+/// One line of `meeting play --list`
+pub fn format_numbered(number: usize, segment: &TranscriptSegment) -> String {
+    format!(
+        "{:>4}  [{}] {}: {}",
+        number,
+        segment.format_timestamp(),
+        segment.speaker_display(),
+        segment.text
+    )
+}
+
+/// One bookmark line of `meeting play --list`
+pub fn format_bookmark(number: usize, bookmark: &Bookmark) -> String {
+    format!(
+        "{:>4}  [{}] {}",
+        format!("B{}", number),
+        bookmark.format_timestamp(),
+        bookmark.label()
+    )
+}
+
+/// Where to start playing: `--from` a position, `--segment` N or
+/// `--bookmark` N (1-based, as listed by `--list`), else the beginning
+pub fn start_position(
+    meeting: &MeetingData,
+    from: Option<&str>,
+    segment: Option<usize>,
+    bookmark: Option<usize>,
+) -> Result<u64, String> {
+    if let Some(from) = from {
+        return parse_offset(from).ok_or_else(|| {
+            format!(
+                "invalid position '{}'; use HH:MM:SS, MM:SS or seconds",
+                from
+            )
+        });
+    }
+    if let Some(number) = bookmark {
+        let bookmarks = &meeting.transcript.bookmarks;
+        return number
+            .checked_sub(1)
+            .and_then(|i| bookmarks.get(i))
+            .map(|b| b.offset_ms)
+            .ok_or_else(|| {
+                format!(
+                    "no bookmark {}; the meeting has {} (see --list)",
+                    number,
+                    bookmarks.len()
+                )
+            });
+    }
+    let Some(number) = segment else {
+        return Ok(0);
+    };
+    let segments = numbered_segments(meeting);
+    number
+        .checked_sub(1)
+        .and_then(|i| segments.get(i))
+        .map(|s| s.start_ms)
+        .ok_or_else(|| {
+            format!(
+                "no segment {}; the meeting has {} (see --list)",
+                number,
+                segments.len()
+            )
+        })
+}
+
+/// Play `path` from `from_ms` until it ends
+pub fn play(path: &Path, from_ms: u64) -> Result<(), String> {
+    use rodio::{Decoder, OutputStream, Sink, Source};
+
+    let file = std::fs::File::open(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut source = Decoder::new(std::io::BufReader::new(file))
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    if let Some(total) = source.total_duration() {
+        if Duration::from_millis(from_ms) >= total {
+            return Err(format!(
+                "{} is past the end of the audio ({})",
+                format_offset(from_ms),
+                format_offset(total.as_millis() as u64)
+            ));
+        }
+    }
+    source
+        .try_seek(Duration::from_millis(from_ms))
+        .map_err(|e| format!("Failed to seek: {}", e))?;
+
+    let (_stream, handle) =
+        OutputStream::try_default().map_err(|e| format!("Failed to open audio output: {}", e))?;
+    let sink = Sink::try_new(&handle).map_err(|e| format!("Failed to play audio: {}", e))?;
+    sink.append(source);
+    sink.sleep_until_end();
+    Ok(())
+}
+
+/// Play `path` from `from_ms` with an external player command. `{file}` is
+/// replaced with the quoted path and `{offset}` with the start in seconds.
+pub fn play_external(command: &str, path: &Path, from_ms: u64) -> Result<(), String> {
+    let command = player_command(command, path, from_ms);
+    tracing::debug!("Running audio player: {}", command);
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(&command)
+        .status()
+        .map_err(|e| format!("Failed to run audio player: {}", e))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("Audio player exited with {}", status))
+    }
+}
+
+fn player_command(command: &str, path: &Path, from_ms: u64) -> String {
+    let quoted = format!("'{}'", path.display().to_string().replace('\'', r"'\''"));
+    command
+        .replace("{file}", &quoted)
+        .replace("{offset}", &format!("{:.3}", from_ms as f64 / 1000.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_offset() {
+        assert_eq!(parse_offset("00:12:30"), Some(750_000));
+        assert_eq!(parse_offset("12:30"), Some(750_000));
+        assert_eq!(parse_offset("1:02:03.5"), Some(3_723_500));
+        assert_eq!(parse_offset("90"), Some(90_000));
+        assert_eq!(parse_offset("75:00"), Some(4_500_000));
+        assert_eq!(parse_offset("12:60"), None);
+        assert_eq!(parse_offset("1.5:00"), None);
+        assert_eq!(parse_offset("1:2:3:4"), None);
+        assert_eq!(parse_offset("soon"), None);
+        assert_eq!(parse_offset(""), None);
+    }
+
+    #[test]
+    fn test_start_position() {
+        let mut meeting = MeetingData::new(None);
+        // Sources are added out of time order, as the daemon does
+        for (start, text) in [
+            (20_000, "third"),
+            (5_000, "first"),
+            (9_000, " "),
+            (12_000, "second"),
+        ] {
+            meeting.add_segment(TranscriptSegment::new(
+                0,
+                start,
+                start + 2000,
+                text.to_string(),
+                0,
+            ));
+        }
+
+        let numbered: Vec<&str> = numbered_segments(&meeting)
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect();
+        assert_eq!(numbered, vec!["first", "second", "third"]);
+
+        assert_eq!(start_position(&meeting, None, None, None), Ok(0));
+        assert_eq!(start_position(&meeting, None, Some(2), None), Ok(12_000));
+        assert_eq!(
+            start_position(&meeting, Some("0:07"), None, None),
+            Ok(7_000)
+        );
+        assert!(start_position(&meeting, None, Some(0), None).is_err());
+        assert!(start_position(&meeting, None, Some(4), None).is_err());
+        assert!(start_position(&meeting, Some("later"), None, None).is_err());
+    }
+
+    #[test]
+    fn test_start_at_bookmark() {
+        let mut meeting = MeetingData::new(None);
+        meeting.transcript.add_bookmark(Bookmark {
+            offset_ms: 95_000,
+            note: Some("Pricing decision".to_string()),
+            created_at: chrono::Utc::now(),
+        });
+
+        assert_eq!(start_position(&meeting, None, None, Some(1)), Ok(95_000));
+        assert_eq!(
+            start_position(&meeting, None, None, Some(2)),
+            Err("no bookmark 2; the meeting has 1 (see --list)".to_string())
+        );
+        assert_eq!(
+            format_bookmark(1, &meeting.transcript.bookmarks[0]),
+            "  B1  [01:35] Pricing decision"
+        );
+    }
+
+    #[test]
+    fn test_player_command() {
+        let command = player_command(
+            "mpv --start={offset} {file}",
+            Path::new("/tmp/it's/audio.wav"),
+            750_250,
+        );
+        assert_eq!(command, r"mpv --start=750.250 '/tmp/it'\''s/audio.wav'");
+    }
+}
@@ -0,0 +1,296 @@
+//! Pruning of stored meetings
+//!
+//! `[meeting.retention]` limits how long meetings are kept and how much
+//! space they take. Meetings that started more than `max_age_days` ago
+//! are pruned, then the oldest meetings until all of them fit in
+//! `max_total_size_mb`. Pruning deletes a meeting, or with
+//! `keep_summaries` only its retained audio, which is most of its size.
+//! Meetings still being recorded are never pruned.
+//!
+//! The daemon prunes every `prune_interval_hours`; `voxtype meeting prune`
+//! prunes now.
+
+use super::data::{MeetingMetadata, MeetingStatus, AUDIO_FILE_NAME};
+use super::storage::{MeetingStorage, StorageError};
+use crate::config::MeetingRetentionConfig;
+use chrono::{DateTime, Duration, Utc};
+use std::path::Path;
+
+/// What pruning does to a meeting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruneAction {
+    /// Delete the meeting and all its files
+    Delete,
+    /// Delete the retained audio, keeping the transcript and summaries
+    DeleteAudio,
+}
+
+/// Why a meeting is pruned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruneReason {
+    /// Older than `max_age_days`
+    Age,
+    /// Needed to get under `max_total_size_mb`
+    Size,
+}
+
+/// A stored meeting and the space it takes
+#[derive(Debug, Clone)]
+pub struct StoredMeeting {
+    pub metadata: MeetingMetadata,
+    /// Bytes in the meeting's directory
+    pub size: u64,
+    /// Bytes of retained audio
+    pub audio_size: u64,
+}
+
+impl StoredMeeting {
+    /// Measure a meeting's directory
+    pub fn measure(metadata: MeetingMetadata) -> Self {
+        let (size, audio_size) = match metadata.storage_path {
+            Some(ref path) => (
+                dir_size(path),
+                std::fs::metadata(path.join(AUDIO_FILE_NAME)).map_or(0, |m| m.len()),
+            ),
+            None => (0, 0),
+        };
+        Self {
+            metadata,
+            size,
+            audio_size,
+        }
+    }
+}
+
+/// A meeting chosen for pruning
+#[derive(Debug, Clone)]
+pub struct Pruned {
+    pub metadata: MeetingMetadata,
+    pub action: PruneAction,
+    pub reason: PruneReason,
+    /// Bytes pruning frees
+    pub freed: u64,
+}
+
+/// Meetings to prune under `config`, oldest first
+pub fn plan(
+    config: &MeetingRetentionConfig,
+    meetings: &[StoredMeeting],
+    now: DateTime<Utc>,
+) -> Vec<Pruned> {
+    let cutoff =
+        (config.max_age_days > 0).then(|| now - Duration::days(config.max_age_days.into()));
+    let limit = config.max_total_size_mb.saturating_mul(1024 * 1024);
+    let mut total: u64 = meetings.iter().map(|m| m.size).sum();
+
+    let mut candidates: Vec<&StoredMeeting> = meetings
+        .iter()
+        .filter(|m| {
+            !matches!(
+                m.metadata.status,
+                MeetingStatus::Active | MeetingStatus::Paused
+            )
+        })
+        .collect();
+    candidates.sort_by_key(|m| m.metadata.started_at);
+
+    let mut pruned = Vec::new();
+    for meeting in candidates {
+        let reason = if cutoff.is_some_and(|cutoff| meeting.metadata.started_at < cutoff) {
+            PruneReason::Age
+        } else if limit > 0 && total > limit {
+            PruneReason::Size
+        } else {
+            continue;
+        };
+        let (action, freed) = if config.keep_summaries {
+            if meeting.audio_size == 0 {
+                continue;
+            }
+            (PruneAction::DeleteAudio, meeting.audio_size)
+        } else {
+            (PruneAction::Delete, meeting.size)
+        };
+        total = total.saturating_sub(freed);
+        pruned.push(Pruned {
+            metadata: meeting.metadata.clone(),
+            action,
+            reason,
+            freed,
+        });
+    }
+    pruned
+}
+
+/// Prune the meetings in `storage` under `config`. With `dry_run`, only
+/// returns what would be pruned.
+pub fn prune(
+    storage: &MeetingStorage,
+    config: &MeetingRetentionConfig,
+    dry_run: bool,
+) -> Result<Vec<Pruned>, StorageError> {
+    let meetings: Vec<StoredMeeting> = storage
+        .list_meetings(None, None)?
+        .into_iter()
+        .map(StoredMeeting::measure)
+        .collect();
+    let pruned = plan(config, &meetings, Utc::now());
+    if !dry_run {
+        for meeting in &pruned {
+            match meeting.action {
+                PruneAction::Delete => storage.delete_meeting(&meeting.metadata.id)?,
+                PruneAction::DeleteAudio => {
+                    storage.delete_audio(&meeting.metadata.id)?;
+                }
+            }
+        }
+    }
+    Ok(pruned)
+}
+
+/// Bytes as `12.3 MB`
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+/// Total size of the files under `path`
+fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|entry| match entry.metadata() {
+            Ok(m) if m.is_dir() => dir_size(&entry.path()),
+            Ok(m) => m.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meeting::storage::StorageConfig;
+
+    const MB: u64 = 1024 * 1024;
+
+    fn stored(days_ago: i64, size: u64, audio_size: u64) -> StoredMeeting {
+        let mut metadata = MeetingMetadata::new(Some(format!("{} days ago", days_ago)));
+        metadata.started_at = Utc::now() - Duration::days(days_ago);
+        metadata.status = MeetingStatus::Completed;
+        StoredMeeting {
+            metadata,
+            size,
+            audio_size,
+        }
+    }
+
+    fn titles(pruned: &[Pruned]) -> Vec<String> {
+        pruned.iter().map(|p| p.metadata.display_title()).collect()
+    }
+
+    #[test]
+    fn test_plan() {
+        let mut recording = stored(200, 50 * MB, 40 * MB);
+        recording.metadata.status = MeetingStatus::Active;
+        let meetings = vec![
+            stored(10, 30 * MB, 25 * MB),
+            stored(100, 20 * MB, 0),
+            stored(40, 30 * MB, 25 * MB),
+            recording,
+            stored(20, 30 * MB, 25 * MB),
+        ];
+
+        // Nothing without limits
+        let config = MeetingRetentionConfig::default();
+        assert!(plan(&config, &meetings, Utc::now()).is_empty());
+
+        // Old meetings, then the oldest until 160 MB fits in 100 MB
+        let config = MeetingRetentionConfig {
+            max_age_days: 90,
+            max_total_size_mb: 100,
+            ..Default::default()
+        };
+        let pruned = plan(&config, &meetings, Utc::now());
+        assert_eq!(
+            titles(&pruned),
+            vec!["100 days ago", "40 days ago", "20 days ago"]
+        );
+        assert_eq!(pruned[0].reason, PruneReason::Age);
+        assert_eq!(pruned[1].reason, PruneReason::Size);
+        assert!(pruned.iter().all(|p| p.action == PruneAction::Delete));
+
+        // Keeping summaries only deletes audio, and needs more meetings
+        let config = MeetingRetentionConfig {
+            max_total_size_mb: 100,
+            keep_summaries: true,
+            ..Default::default()
+        };
+        let pruned = plan(&config, &meetings, Utc::now());
+        assert_eq!(
+            titles(&pruned),
+            vec!["40 days ago", "20 days ago", "10 days ago"]
+        );
+        assert!(pruned.iter().all(|p| p.action == PruneAction::DeleteAudio));
+        assert_eq!(pruned.iter().map(|p| p.freed).sum::<u64>(), 75 * MB);
+    }
+
+    #[test]
+    fn test_prune() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let storage = MeetingStorage::open(StorageConfig {
+            storage_path: temp_dir.path().to_path_buf(),
+            retain_audio: true,
+            max_meetings: 0,
+        })
+        .unwrap();
+
+        let mut ids = Vec::new();
+        for days_ago in [400, 1] {
+            let mut metadata = MeetingMetadata::new(None);
+            metadata.started_at = Utc::now() - Duration::days(days_ago);
+            let path = storage.create_meeting(&metadata).unwrap();
+            std::fs::write(path.join(AUDIO_FILE_NAME), [0u8; 100]).unwrap();
+            metadata.complete();
+            metadata.audio_retained = true;
+            metadata.storage_path = Some(path);
+            storage.update_meeting(&metadata).unwrap();
+            ids.push(metadata.id);
+        }
+
+        let config = MeetingRetentionConfig {
+            max_age_days: 365,
+            ..Default::default()
+        };
+        let pruned = prune(&storage, &config, true).unwrap();
+        assert_eq!(pruned.len(), 1);
+        assert!(storage.get_meeting(&ids[0]).unwrap().is_some());
+
+        let pruned = prune(&storage, &config, false).unwrap();
+        assert_eq!(pruned[0].metadata.id, ids[0]);
+        assert!(pruned[0].freed > 100);
+        assert!(storage.get_meeting(&ids[0]).unwrap().is_none());
+        assert!(storage.get_meeting(&ids[1]).unwrap().is_some());
+        assert!(prune(&storage, &config, false).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(25 * MB), "25.0 MB");
+        assert_eq!(format_size(3 * 1024 * MB), "3.0 GB");
+    }
+}
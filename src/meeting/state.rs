@@ -134,11 +134,17 @@ impl MeetingState {
 
     /// Start a new meeting
     pub fn start() -> Self {
+        Self::start_from(0)
+    }
+
+    /// Continue a stored meeting whose first `chunks_processed` chunks are
+    /// already transcribed, so new chunk IDs follow on
+    pub fn start_from(chunks_processed: u32) -> Self {
         let now = Instant::now();
         MeetingState::Active {
             started_at: now,
             current_chunk: ChunkState::Recording { started_at: now },
-            chunks_processed: 0,
+            chunks_processed,
         }
     }
 
@@ -297,6 +303,13 @@ mod tests {
         assert_eq!(state.chunks_processed(), 0);
     }
 
+    #[test]
+    fn test_start_from() {
+        let state = MeetingState::start_from(12);
+        assert!(state.is_active());
+        assert_eq!(state.next_chunk().chunks_processed(), 13);
+    }
+
     #[test]
     fn test_pause_resume() {
         let state = MeetingState::start();
@@ -0,0 +1,210 @@
+//! Talk-time statistics per speaker
+//!
+//! How long each speaker talked, how many words and turns they had, and
+//! how often they interrupted someone: started talking while another
+//! speaker's segment still had at least `INTERRUPT_MS` to go. Segment
+//! times come from transcription, so these are estimates; the brief
+//! overlaps of an ordinary change of speaker are not interruptions.
+//!
+//! Shown by `voxtype meeting stats` and in markdown and JSON exports.
+
+use super::data::{format_offset, MeetingData, TranscriptSegment};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Overlap with another speaker's segment that counts as interrupting it
+const INTERRUPT_MS: u64 = 1000;
+
+/// Statistics of one speaker
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeakerStats {
+    /// Speaker label, ID, or audio source, as in exports
+    pub speaker: String,
+    /// Total length of the speaker's segments
+    pub talk_ms: u64,
+    /// Share of all talk time, from 0 to 1
+    pub share: f64,
+    /// Words spoken
+    pub words: usize,
+    /// Times the speaker took over from someone else
+    pub turns: usize,
+    /// Times the speaker cut in while someone else was talking
+    pub interruptions: usize,
+    /// Times someone else cut in while the speaker was talking
+    pub interrupted: usize,
+}
+
+/// Statistics of each speaker in `meeting`, most talk time first
+pub fn speaker_stats(meeting: &MeetingData) -> Vec<SpeakerStats> {
+    let mut segments: Vec<&TranscriptSegment> = meeting
+        .transcript
+        .segments
+        .iter()
+        .filter(|s| !s.text.trim().is_empty())
+        .collect();
+    segments.sort_by_key(|s| s.start_ms);
+
+    let mut stats: Vec<SpeakerStats> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    // The segment of each speaker that ends last so far
+    let mut latest: HashMap<usize, &TranscriptSegment> = HashMap::new();
+    let mut previous: Option<usize> = None;
+
+    for segment in segments {
+        let speaker = segment.speaker_display();
+        let i = *index.entry(speaker.clone()).or_insert_with(|| {
+            stats.push(SpeakerStats {
+                speaker,
+                talk_ms: 0,
+                share: 0.0,
+                words: 0,
+                turns: 0,
+                interruptions: 0,
+                interrupted: 0,
+            });
+            stats.len() - 1
+        });
+
+        stats[i].talk_ms += segment.duration_ms();
+        stats[i].words += segment.text.split_whitespace().count();
+        if previous != Some(i) {
+            stats[i].turns += 1;
+        }
+        previous = Some(i);
+
+        // The other speaker with the most left to say when this one starts
+        let cut_off = latest
+            .iter()
+            .filter(|&(&j, other)| {
+                j != i
+                    && other.start_ms < segment.start_ms
+                    && other.end_ms >= segment.start_ms + INTERRUPT_MS
+            })
+            .max_by_key(|&(&j, other)| (other.end_ms, std::cmp::Reverse(j)))
+            .map(|(&j, _)| j);
+        if let Some(j) = cut_off {
+            stats[i].interruptions += 1;
+            stats[j].interrupted += 1;
+        }
+
+        let last = latest.entry(i).or_insert(segment);
+        if segment.end_ms > last.end_ms {
+            *last = segment;
+        }
+    }
+
+    let total: u64 = stats.iter().map(|s| s.talk_ms).sum();
+    for s in &mut stats {
+        s.share = if total > 0 {
+            s.talk_ms as f64 / total as f64
+        } else {
+            0.0
+        };
+    }
+    stats.sort_by(|a, b| b.talk_ms.cmp(&a.talk_ms).then(a.speaker.cmp(&b.speaker)));
+    stats
+}
+
+/// Talk time as `MM:SS`, or `HH:MM:SS` from an hour
+pub fn format_talk_time(stats: &SpeakerStats) -> String {
+    format_offset(stats.talk_ms)
+}
+
+/// Share of talk time as a whole percentage
+pub fn format_share(stats: &SpeakerStats) -> String {
+    format!("{:.0}%", stats.share * 100.0)
+}
+
+/// A plain-text table of `stats`, as `voxtype meeting stats` prints it
+pub fn format_table(stats: &[SpeakerStats]) -> String {
+    let width = stats
+        .iter()
+        .map(|s| s.speaker.chars().count())
+        .chain(std::iter::once("Speaker".len()))
+        .max()
+        .unwrap_or_default();
+    let mut output = format!(
+        "{:<width$}  {:>9}  {:>5}  {:>6}  {:>5}  {:>13}  {:>11}\n",
+        "Speaker", "Talk time", "Share", "Words", "Turns", "Interruptions", "Interrupted"
+    );
+    for s in stats {
+        output.push_str(&format!(
+            "{:<width$}  {:>9}  {:>5}  {:>6}  {:>5}  {:>13}  {:>11}\n",
+            s.speaker,
+            format_talk_time(s),
+            format_share(s),
+            s.words,
+            s.turns,
+            s.interruptions,
+            s.interrupted
+        ));
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meeting(segments: &[(&str, u64, u64, &str)]) -> MeetingData {
+        let mut meeting = MeetingData::new(None);
+        for (i, (speaker, start, end, text)) in segments.iter().enumerate() {
+            let mut segment = TranscriptSegment::new(i as u32, *start, *end, text.to_string(), 0);
+            segment.speaker_label = Some(speaker.to_string());
+            meeting.add_segment(segment);
+        }
+        meeting
+    }
+
+    #[test]
+    fn test_speaker_stats() {
+        // Bob's segments arrive first, as a second audio source would
+        let meeting = meeting(&[
+            ("Bob", 10_000, 16_000, "Sure, the numbers look fine"),
+            ("Bob", 20_000, 24_000, "One more thing"),
+            (
+                "Alice",
+                0,
+                10_200,
+                "Let's look at the quarterly numbers first",
+            ),
+            ("Alice", 15_000, 20_000, "Wait, which numbers"),
+            ("Alice", 30_000, 40_000, "Thanks everyone"),
+            ("Carol", 26_000, 26_000, " "),
+        ]);
+        let stats = speaker_stats(&meeting);
+        assert_eq!(stats.len(), 2);
+
+        let alice = &stats[0];
+        assert_eq!(alice.speaker, "Alice");
+        assert_eq!(alice.talk_ms, 25_200);
+        assert_eq!(alice.words, 12);
+        assert_eq!(alice.turns, 3);
+        // Starting 1s before Bob finished cuts him off; Bob starting 200ms
+        // before Alice finished is just a change of speaker
+        assert_eq!(alice.interruptions, 1);
+        assert_eq!(alice.interrupted, 0);
+
+        let bob = &stats[1];
+        assert_eq!(bob.talk_ms, 10_000);
+        assert_eq!(bob.turns, 2);
+        assert_eq!(bob.interruptions, 0);
+        assert_eq!(bob.interrupted, 1);
+        assert!((alice.share + bob.share - 1.0).abs() < 1e-9);
+        assert_eq!(format_share(bob), "28%");
+        assert_eq!(format_talk_time(alice), "00:25");
+    }
+
+    #[test]
+    fn test_format_table() {
+        let meeting = meeting(&[("Alice", 0, 61_000, "Hello there")]);
+        let table = format_table(&speaker_stats(&meeting));
+        assert_eq!(
+            table,
+            "Speaker  Talk time  Share   Words  Turns  Interruptions  Interrupted\n\
+             Alice        01:01   100%       2      1              0            0\n"
+        );
+        assert!(speaker_stats(&MeetingData::new(None)).is_empty());
+    }
+}
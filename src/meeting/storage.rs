@@ -2,8 +2,19 @@
 //!
 //! Provides SQLite-based index for meeting metadata and filesystem
 //! storage for transcripts and audio files.
-
-use crate::meeting::data::{MeetingData, MeetingId, MeetingMetadata, MeetingStatus, Transcript};
+//!
+//! Transcript segments are also kept in an FTS5 table for
+//! `voxtype meeting search`. It is updated whenever a transcript is saved;
+//! meetings recorded before the table existed are indexed on the first
+//! search.
+
+use crate::meeting::data::{
+    format_offset, MeetingData, MeetingId, MeetingMetadata, MeetingStatus, Transcript,
+    AUDIO_FILE_NAME,
+};
+use crate::meeting::diarization::enrolled::{
+    embedding_from_bytes, embedding_to_bytes, EnrolledSpeaker,
+};
 use chrono::{DateTime, TimeZone, Utc};
 use rusqlite::{params, Connection, OptionalExtension};
 use std::path::PathBuf;
@@ -64,6 +75,36 @@ impl StorageConfig {
     }
 }
 
+/// A transcript segment matching a search
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub meeting_id: MeetingId,
+    pub meeting_title: Option<String>,
+    pub meeting_started_at: DateTime<Utc>,
+    pub segment_id: u32,
+    /// Offset of the segment into the meeting
+    pub start_ms: u64,
+    pub speaker: String,
+    /// Segment text around the match, matched terms wrapped in `**`
+    pub snippet: String,
+}
+
+impl SearchHit {
+    /// Meeting title, or its start date for untitled meetings
+    pub fn display_title(&self) -> String {
+        self.meeting_title.clone().unwrap_or_else(|| {
+            self.meeting_started_at
+                .format("Meeting %Y-%m-%d %H:%M")
+                .to_string()
+        })
+    }
+
+    /// Segment offset as HH:MM:SS
+    pub fn format_timestamp(&self) -> String {
+        format_offset(self.start_ms)
+    }
+}
+
 /// Meeting storage manager
 pub struct MeetingStorage {
     config: StorageConfig,
@@ -71,6 +112,11 @@ pub struct MeetingStorage {
 }
 
 impl MeetingStorage {
+    /// The configuration storage was opened with
+    pub fn config(&self) -> &StorageConfig {
+        &self.config
+    }
+
     /// Open or create meeting storage
     pub fn open(config: StorageConfig) -> Result<Self, StorageError> {
         // Ensure storage directory exists
@@ -116,8 +162,45 @@ impl MeetingStorage {
                 PRIMARY KEY (meeting_id, speaker_num),
                 FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
             );
+
+            -- Full-text index of transcript segments
+            CREATE VIRTUAL TABLE IF NOT EXISTS segments_fts USING fts5(
+                text,
+                speaker,
+                meeting_id UNINDEXED,
+                segment_id UNINDEXED,
+                start_ms UNINDEXED,
+                tokenize = 'porter unicode61'
+            );
+
+            -- Meetings whose transcript is in segments_fts
+            CREATE TABLE IF NOT EXISTS segments_indexed (
+                meeting_id TEXT PRIMARY KEY
+            );
+
+            -- Voice prints for recognizing speakers across meetings
+            CREATE TABLE IF NOT EXISTS enrolled_speakers (
+                name TEXT PRIMARY KEY,
+                embedding BLOB NOT NULL,
+                windows INTEGER NOT NULL,
+                created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now'))
+            );
             "#,
         )?;
+
+        // Columns added after the first release
+        for column in ["attendees", "tags"] {
+            let exists = self
+                .conn
+                .prepare("SELECT 1 FROM pragma_table_info('meetings') WHERE name = ?1")?
+                .exists([column])?;
+            if !exists {
+                self.conn.execute(
+                    &format!("ALTER TABLE meetings ADD COLUMN {} TEXT", column),
+                    [],
+                )?;
+            }
+        }
         Ok(())
     }
 
@@ -130,8 +213,9 @@ impl MeetingStorage {
         // Insert into database
         self.conn.execute(
             r#"
-            INSERT INTO meetings (id, title, started_at, status, storage_path, audio_retained, model)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            INSERT INTO meetings (id, title, started_at, status, storage_path, audio_retained, model,
+                                  attendees, tags)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
             "#,
             params![
                 metadata.id.to_string(),
@@ -141,6 +225,8 @@ impl MeetingStorage {
                 meeting_dir.to_string_lossy().to_string(),
                 metadata.audio_retained as i32,
                 metadata.model,
+                strings_to_json(&metadata.attendees)?,
+                strings_to_json(&metadata.tags)?,
             ],
         )?;
 
@@ -164,7 +250,9 @@ impl MeetingStorage {
                 chunk_count = ?6,
                 audio_retained = ?7,
                 model = ?8,
-                synced_at = ?9
+                synced_at = ?9,
+                attendees = ?10,
+                tags = ?11
             WHERE id = ?1
             "#,
             params![
@@ -177,6 +265,8 @@ impl MeetingStorage {
                 metadata.audio_retained as i32,
                 metadata.model,
                 metadata.synced_at.map(|dt| dt.timestamp()),
+                strings_to_json(&metadata.attendees)?,
+                strings_to_json(&metadata.tags)?,
             ],
         )?;
 
@@ -190,21 +280,44 @@ impl MeetingStorage {
         Ok(())
     }
 
-    /// Mark any active/paused meetings as completed.
-    /// Called on daemon startup to clean up meetings orphaned by a previous crash or restart.
-    pub fn complete_stale_meetings(&self) -> Result<u32, StorageError> {
-        let now = Utc::now().timestamp();
-        let count = self.conn.execute(
-            r#"
-            UPDATE meetings SET
-                status = 'completed',
-                ended_at = ?1,
-                duration_secs = (?1 - started_at)
-            WHERE status IN ('active', 'paused')
-            "#,
-            params![now],
-        )?;
-        Ok(count as u32)
+    /// Finish meetings left active or paused by a crash or restart, keeping
+    /// the transcript up to their last checkpoint. Called on daemon startup.
+    /// Returns the recovered meetings, which can be continued with
+    /// `voxtype meeting resume <id>`.
+    pub fn recover_interrupted_meetings(&self) -> Result<Vec<MeetingMetadata>, StorageError> {
+        let ids = self
+            .conn
+            .prepare("SELECT id FROM meetings WHERE status IN ('active', 'paused')")?
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut recovered = Vec::new();
+        for id in ids.iter().filter_map(|id| MeetingId::parse(id).ok()) {
+            let mut meeting = self.load_meeting_data(&id)?;
+            let transcript_path = meeting
+                .metadata
+                .storage_path
+                .as_ref()
+                .map(|path| path.join("transcript.json"));
+
+            // The meeting ended at its last checkpoint, not now
+            let checkpointed_at = transcript_path
+                .and_then(|path| std::fs::metadata(path).ok())
+                .and_then(|m| m.modified().ok())
+                .map(DateTime::<Utc>::from);
+            let metadata = &mut meeting.metadata;
+            metadata.ended_at = Some(checkpointed_at.unwrap_or(metadata.started_at));
+            metadata.status = MeetingStatus::Completed;
+            metadata.chunk_count = meeting.transcript.total_chunks;
+            if metadata.duration_secs.is_none() {
+                metadata.duration_secs = Some(meeting.transcript.duration_ms() / 1000);
+            }
+
+            self.update_meeting(&meeting.metadata)?;
+            self.index_transcript(&id, &meeting.transcript)?;
+            recovered.push(meeting.metadata);
+        }
+        Ok(recovered)
     }
 
     /// Get meeting by ID
@@ -214,7 +327,8 @@ impl MeetingStorage {
             .query_row(
                 r#"
                 SELECT id, title, started_at, ended_at, duration_secs, status,
-                       chunk_count, storage_path, audio_retained, model, synced_at
+                       chunk_count, storage_path, audio_retained, model, synced_at, attendees,
+                       tags
                 FROM meetings WHERE id = ?1
                 "#,
                 params![id.to_string()],
@@ -222,6 +336,8 @@ impl MeetingStorage {
                     Ok(MeetingMetadata {
                         id: MeetingId::parse(&row.get::<_, String>(0)?).unwrap_or_default(),
                         title: row.get(1)?,
+                        attendees: strings_from_json(row.get(11)?),
+                        tags: strings_from_json(row.get(12)?),
                         started_at: timestamp_to_datetime(row.get(2)?),
                         ended_at: row.get::<_, Option<i64>>(3)?.map(timestamp_to_datetime),
                         duration_secs: row.get::<_, Option<i64>>(4)?.map(|d| d as u64),
@@ -240,30 +356,30 @@ impl MeetingStorage {
         Ok(result)
     }
 
-    /// List meetings with optional limit
-    pub fn list_meetings(&self, limit: Option<u32>) -> Result<Vec<MeetingMetadata>, StorageError> {
-        let sql = if limit.is_some() {
-            r#"
-                SELECT id, title, started_at, ended_at, duration_secs, status,
-                       chunk_count, storage_path, audio_retained, model, synced_at
-                FROM meetings
-                ORDER BY started_at DESC
-                LIMIT ?1
-                "#
-        } else {
+    /// List meetings, newest first, with optional limit. With `tag`, only
+    /// meetings tagged with it (ignoring case).
+    pub fn list_meetings(
+        &self,
+        limit: Option<u32>,
+        tag: Option<&str>,
+    ) -> Result<Vec<MeetingMetadata>, StorageError> {
+        let mut stmt = self.conn.prepare(&format!(
             r#"
-                SELECT id, title, started_at, ended_at, duration_secs, status,
-                       chunk_count, storage_path, audio_retained, model, synced_at
-                FROM meetings
-                ORDER BY started_at DESC
-                "#
-        };
-
-        let mut stmt = self.conn.prepare(sql)?;
+            SELECT id, title, started_at, ended_at, duration_secs, status,
+                   chunk_count, storage_path, audio_retained, model, synced_at, attendees, tags
+            FROM meetings
+            WHERE {}
+            ORDER BY started_at DESC
+            LIMIT ?2
+            "#,
+            tag_filter("tags", "?1")
+        ))?;
         let row_mapper = |row: &rusqlite::Row| {
             Ok(MeetingMetadata {
                 id: MeetingId::parse(&row.get::<_, String>(0)?).unwrap_or_default(),
                 title: row.get(1)?,
+                attendees: strings_from_json(row.get(11)?),
+                tags: strings_from_json(row.get(12)?),
                 started_at: timestamp_to_datetime(row.get(2)?),
                 ended_at: row.get::<_, Option<i64>>(3)?.map(timestamp_to_datetime),
                 duration_secs: row.get::<_, Option<i64>>(4)?.map(|d| d as u64),
@@ -277,20 +393,18 @@ impl MeetingStorage {
             })
         };
 
-        let meetings = if let Some(limit) = limit {
-            stmt.query_map(params![limit], row_mapper)?
-                .collect::<Result<Vec<_>, _>>()?
-        } else {
-            stmt.query_map([], row_mapper)?
-                .collect::<Result<Vec<_>, _>>()?
-        };
+        // A negative limit is no limit
+        let limit = limit.map_or(-1, i64::from);
+        let meetings = stmt
+            .query_map(params![tag, limit], row_mapper)?
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(meetings)
     }
 
     /// Get the most recent meeting
     pub fn get_latest_meeting(&self) -> Result<Option<MeetingMetadata>, StorageError> {
-        let meetings = self.list_meetings(Some(1))?;
+        let meetings = self.list_meetings(Some(1), None)?;
         Ok(meetings.into_iter().next())
     }
 
@@ -312,9 +426,136 @@ impl MeetingStorage {
         let json = serde_json::to_string_pretty(transcript)?;
         std::fs::write(transcript_path, json)?;
 
+        self.index_transcript(meeting_id, transcript)?;
+
+        Ok(())
+    }
+
+    /// Save an in-progress meeting, so a crash loses at most the chunk being
+    /// transcribed. Unlike `save_transcript` this leaves the search index
+    /// alone; it's updated when the meeting ends.
+    pub fn save_checkpoint(&self, meeting: &MeetingData) -> Result<(), StorageError> {
+        let storage_path = meeting
+            .metadata
+            .storage_path
+            .as_ref()
+            .ok_or(StorageError::PathNotConfigured)?;
+
+        // Write then rename, so a crash mid-write keeps the previous checkpoint
+        let transcript_path = storage_path.join("transcript.json");
+        let temp_path = storage_path.join("transcript.json.tmp");
+        std::fs::write(&temp_path, serde_json::to_string(&meeting.transcript)?)?;
+        std::fs::rename(&temp_path, &transcript_path)?;
+
+        self.update_meeting(&meeting.metadata)
+    }
+
+    /// Replace the search index entries of a meeting with `transcript`
+    fn index_transcript(
+        &self,
+        meeting_id: &MeetingId,
+        transcript: &Transcript,
+    ) -> Result<(), StorageError> {
+        let id = meeting_id.to_string();
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "DELETE FROM segments_fts WHERE meeting_id = ?1",
+            params![id],
+        )?;
+        {
+            let mut insert = tx.prepare(
+                r#"
+                INSERT INTO segments_fts (text, speaker, meeting_id, segment_id, start_ms)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                "#,
+            )?;
+            for segment in &transcript.segments {
+                insert.execute(params![
+                    segment.text,
+                    segment.speaker_display(),
+                    id,
+                    segment.id,
+                    segment.start_ms as i64,
+                ])?;
+            }
+        }
+        tx.execute(
+            "INSERT OR IGNORE INTO segments_indexed (meeting_id) VALUES (?1)",
+            params![id],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Index meetings saved before the search index existed
+    fn index_missing_transcripts(&self) -> Result<(), StorageError> {
+        let ids = self
+            .conn
+            .prepare(
+                "SELECT id FROM meetings WHERE id NOT IN (SELECT meeting_id FROM segments_indexed)",
+            )?
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for id in ids {
+            let Ok(meeting_id) = MeetingId::parse(&id) else {
+                continue;
+            };
+            // A meeting without a transcript file yet is indexed as empty;
+            // saving its transcript later fills it in
+            let transcript = self.load_transcript(&meeting_id).unwrap_or_default();
+            self.index_transcript(&meeting_id, &transcript)?;
+        }
         Ok(())
     }
 
+    /// Search transcript segments of all meetings, best matches first.
+    ///
+    /// Words in `query` must all appear in a segment (in any form the
+    /// stemmer folds together, so "deploy" finds "deployed"). Double-quoted
+    /// parts match as a phrase and a trailing `*` matches a prefix. Speaker
+    /// names are searched too. With `tag`, only meetings tagged with it
+    /// are searched.
+    pub fn search(
+        &self,
+        query: &str,
+        limit: u32,
+        tag: Option<&str>,
+    ) -> Result<Vec<SearchHit>, StorageError> {
+        let Some(fts_query) = fts_query(query) else {
+            return Ok(Vec::new());
+        };
+        self.index_missing_transcripts()?;
+
+        let mut stmt = self.conn.prepare(&format!(
+            r#"
+            SELECT f.meeting_id, m.title, m.started_at, f.segment_id, f.start_ms, f.speaker,
+                   snippet(segments_fts, 0, '**', '**', '...', 16)
+            FROM segments_fts f
+            JOIN meetings m ON m.id = f.meeting_id
+            WHERE segments_fts MATCH ?1 AND {}
+            ORDER BY rank, m.started_at DESC, f.start_ms
+            LIMIT ?2
+            "#,
+            tag_filter("m.tags", "?3")
+        ))?;
+        let hits = stmt
+            .query_map(params![fts_query, limit, tag], |row| {
+                Ok(SearchHit {
+                    meeting_id: MeetingId::parse(&row.get::<_, String>(0)?).unwrap_or_default(),
+                    meeting_title: row.get(1)?,
+                    meeting_started_at: timestamp_to_datetime(row.get(2)?),
+                    segment_id: row.get::<_, i64>(3)? as u32,
+                    start_ms: row.get::<_, i64>(4)? as u64,
+                    speaker: row.get(5)?,
+                    snippet: row.get(6)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(hits)
+    }
+
     /// Load transcript from filesystem
     pub fn load_transcript(&self, meeting_id: &MeetingId) -> Result<Transcript, StorageError> {
         let metadata = self
@@ -356,6 +597,14 @@ impl MeetingStorage {
             "DELETE FROM meetings WHERE id = ?1",
             params![meeting_id.to_string()],
         )?;
+        self.conn.execute(
+            "DELETE FROM segments_fts WHERE meeting_id = ?1",
+            params![meeting_id.to_string()],
+        )?;
+        self.conn.execute(
+            "DELETE FROM segments_indexed WHERE meeting_id = ?1",
+            params![meeting_id.to_string()],
+        )?;
 
         // Delete files if storage path exists
         if let Some(metadata) = metadata {
@@ -369,6 +618,31 @@ impl MeetingStorage {
         Ok(())
     }
 
+    /// Delete a meeting's retained audio, keeping its transcript and
+    /// summaries. Returns the bytes freed.
+    pub fn delete_audio(&self, meeting_id: &MeetingId) -> Result<u64, StorageError> {
+        let mut metadata = self
+            .get_meeting(meeting_id)?
+            .ok_or_else(|| StorageError::NotFound(meeting_id.to_string()))?;
+
+        let mut freed = 0;
+        if let Some(path) = metadata
+            .storage_path
+            .as_ref()
+            .map(|p| p.join(AUDIO_FILE_NAME))
+        {
+            if path.exists() {
+                freed = std::fs::metadata(&path)?.len();
+                std::fs::remove_file(&path)?;
+            }
+        }
+        if metadata.audio_retained {
+            metadata.audio_retained = false;
+            self.update_meeting(&metadata)?;
+        }
+        Ok(freed)
+    }
+
     /// Get the storage path for a meeting
     pub fn get_meeting_path(&self, meeting_id: &MeetingId) -> Result<PathBuf, StorageError> {
         let metadata = self
@@ -435,6 +709,53 @@ impl MeetingStorage {
         Ok(labels)
     }
 
+    /// Store a voice print, replacing an earlier one with the same name
+    pub fn enroll_speaker(&self, speaker: &EnrolledSpeaker) -> Result<(), StorageError> {
+        self.conn.execute(
+            r#"
+            INSERT OR REPLACE INTO enrolled_speakers (name, embedding, windows)
+            VALUES (?1, ?2, ?3)
+            "#,
+            params![
+                speaker.name,
+                embedding_to_bytes(&speaker.embedding),
+                speaker.windows as i64,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// All enrolled voice prints with their enrollment time, by name
+    pub fn list_enrolled_speakers(
+        &self,
+    ) -> Result<Vec<(EnrolledSpeaker, DateTime<Utc>)>, StorageError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT name, embedding, windows, created_at FROM enrolled_speakers ORDER BY name",
+        )?;
+        let speakers = stmt
+            .query_map([], |row| {
+                Ok((
+                    EnrolledSpeaker {
+                        name: row.get(0)?,
+                        embedding: embedding_from_bytes(&row.get::<_, Vec<u8>>(1)?),
+                        windows: row.get::<_, i64>(2)? as u32,
+                    },
+                    timestamp_to_datetime(row.get(3)?),
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(speakers)
+    }
+
+    /// Remove an enrolled voice print. Returns whether it existed.
+    pub fn remove_enrolled_speaker(&self, name: &str) -> Result<bool, StorageError> {
+        let removed = self.conn.execute(
+            "DELETE FROM enrolled_speakers WHERE name = ?1",
+            params![name],
+        )?;
+        Ok(removed > 0)
+    }
+
     /// Apply speaker labels to transcript segments
     fn apply_speaker_labels_to_transcript(
         &self,
@@ -499,6 +820,57 @@ fn timestamp_to_datetime(ts: i64) -> DateTime<Utc> {
     Utc.timestamp_opt(ts, 0).single().unwrap_or_else(Utc::now)
 }
 
+// Attendees and tags are stored as a JSON array, NULL when there are none
+fn strings_to_json(strings: &[String]) -> Result<Option<String>, StorageError> {
+    if strings.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(serde_json::to_string(strings)?))
+}
+
+fn strings_from_json(json: Option<String>) -> Vec<String> {
+    json.and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// SQL condition: the JSON array in `column` contains the tag bound to
+/// `param`, ignoring case. True when the parameter is NULL.
+fn tag_filter(column: &str, param: &str) -> String {
+    format!(
+        "({param} IS NULL OR EXISTS (SELECT 1 FROM json_each({column}) \
+         WHERE lower(json_each.value) = lower({param})))"
+    )
+}
+
+/// Turn a user query into FTS5 syntax. Every word and "quoted phrase"
+/// becomes an FTS5 string, so punctuation and operators in the input are
+/// matched literally instead of failing to parse. A trailing `*` on a word
+/// is kept as a prefix match. Returns `None` when there is nothing to
+/// search for.
+fn fts_query(input: &str) -> Option<String> {
+    let quote = |term: &str| format!("\"{}\"", term.replace('"', "\"\""));
+
+    let mut terms = Vec::new();
+    for (i, part) in input.split('"').enumerate() {
+        if i % 2 == 1 {
+            // Inside quotes: a phrase
+            if !part.trim().is_empty() {
+                terms.push(quote(part.trim()));
+            }
+            continue;
+        }
+        for word in part.split_whitespace() {
+            match word.strip_suffix('*') {
+                Some(prefix) if !prefix.is_empty() => terms.push(format!("{}*", quote(prefix))),
+                Some(_) => {}
+                None => terms.push(quote(word)),
+            }
+        }
+    }
+
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -538,7 +910,7 @@ mod tests {
         storage.create_meeting(&metadata1).unwrap();
         storage.create_meeting(&metadata2).unwrap();
 
-        let meetings = storage.list_meetings(None).unwrap();
+        let meetings = storage.list_meetings(None, None).unwrap();
         assert_eq!(meetings.len(), 2);
     }
 
@@ -551,7 +923,7 @@ mod tests {
             storage.create_meeting(&metadata).unwrap();
         }
 
-        let meetings = storage.list_meetings(Some(2)).unwrap();
+        let meetings = storage.list_meetings(Some(2), None).unwrap();
         assert_eq!(meetings.len(), 2);
     }
 
@@ -573,6 +945,33 @@ mod tests {
         assert_eq!(loaded.status, MeetingStatus::Completed);
     }
 
+    #[test]
+    fn test_attendees_roundtrip() {
+        let (storage, temp) = create_test_storage();
+
+        let mut metadata = MeetingMetadata::new(Some("Design review".to_string()));
+        metadata.attendees = vec!["Alice".to_string(), "bob@example.com".to_string()];
+        storage.create_meeting(&metadata).unwrap();
+        let untitled = MeetingMetadata::new(None);
+        storage.create_meeting(&untitled).unwrap();
+
+        let loaded = storage.get_meeting(&metadata.id).unwrap().unwrap();
+        assert_eq!(loaded.attendees, metadata.attendees);
+        let listed = storage.list_meetings(None, None).unwrap();
+        let listed_untitled = listed.iter().find(|m| m.id == untitled.id).unwrap();
+        assert!(listed_untitled.attendees.is_empty());
+
+        // Reopening an existing index doesn't add the column twice
+        drop(storage);
+        let storage = MeetingStorage::open(StorageConfig {
+            storage_path: temp.path().to_path_buf(),
+            ..Default::default()
+        })
+        .unwrap();
+        let loaded = storage.get_meeting(&metadata.id).unwrap().unwrap();
+        assert_eq!(loaded.attendees.len(), 2);
+    }
+
     #[test]
     fn test_save_and_load_transcript() {
         let (storage, _temp) = create_test_storage();
@@ -600,6 +999,40 @@ mod tests {
         assert_eq!(loaded.segments[0].text, "Hello world");
     }
 
+    #[test]
+    fn test_checkpoint_and_recover() {
+        let (storage, _temp) = create_test_storage();
+
+        let mut meeting = MeetingData::new(Some("Crashed".to_string()));
+        let path = storage.create_meeting(&meeting.metadata).unwrap();
+        meeting.metadata.storage_path = Some(path);
+        meeting.add_segment(crate::meeting::data::TranscriptSegment::new(
+            0,
+            0,
+            30_000,
+            "Before the crash".to_string(),
+            0,
+        ));
+        meeting.transcript.total_chunks = 1;
+        meeting.metadata.duration_secs = Some(30);
+        storage.save_checkpoint(&meeting).unwrap();
+
+        let recovered = storage.recover_interrupted_meetings().unwrap();
+        assert_eq!(recovered.len(), 1);
+        assert_eq!(recovered[0].id, meeting.metadata.id);
+        assert_eq!(recovered[0].status, MeetingStatus::Completed);
+        assert_eq!(recovered[0].duration_secs, Some(30));
+        assert_eq!(recovered[0].chunk_count, 1);
+        assert!(recovered[0].ended_at.is_some());
+
+        let data = storage.load_meeting_data(&meeting.metadata.id).unwrap();
+        assert_eq!(data.transcript.segments[0].text, "Before the crash");
+        assert_eq!(storage.search("crash", 10, None).unwrap().len(), 1);
+
+        // Nothing left to recover
+        assert!(storage.recover_interrupted_meetings().unwrap().is_empty());
+    }
+
     #[test]
     fn test_delete_meeting() {
         let (storage, _temp) = create_test_storage();
@@ -734,6 +1167,24 @@ mod tests {
         assert!(storage.get_meeting(&id).unwrap().is_none());
     }
 
+    #[test]
+    fn test_delete_audio() {
+        let (storage, _temp) = create_test_storage();
+        let mut metadata = MeetingMetadata::new(Some("Audio".to_string()));
+        let id = metadata.id;
+        let path = storage.create_meeting(&metadata).unwrap();
+        std::fs::write(path.join(AUDIO_FILE_NAME), [0u8; 64]).unwrap();
+        metadata.audio_retained = true;
+        metadata.storage_path = Some(path.clone());
+        storage.update_meeting(&metadata).unwrap();
+
+        assert_eq!(storage.delete_audio(&id).unwrap(), 64);
+        assert!(!path.join(AUDIO_FILE_NAME).exists());
+        assert!(path.join("metadata.json").exists());
+        assert!(!storage.get_meeting(&id).unwrap().unwrap().audio_retained);
+        assert_eq!(storage.delete_audio(&id).unwrap(), 0);
+    }
+
     #[test]
     fn test_status_roundtrip() {
         assert_eq!(
@@ -783,7 +1234,7 @@ mod tests {
     #[test]
     fn test_list_meetings_empty() {
         let (storage, _temp) = create_test_storage();
-        let meetings = storage.list_meetings(None).unwrap();
+        let meetings = storage.list_meetings(None, None).unwrap();
         assert!(meetings.is_empty());
     }
 
@@ -794,7 +1245,7 @@ mod tests {
             let metadata = MeetingMetadata::new(Some(format!("Meeting {}", i)));
             storage.create_meeting(&metadata).unwrap();
         }
-        let meetings = storage.list_meetings(Some(0)).unwrap();
+        let meetings = storage.list_meetings(Some(0), None).unwrap();
         assert!(meetings.is_empty());
     }
 
@@ -921,7 +1372,7 @@ mod tests {
         metadata2.started_at = chrono::Utc.timestamp_opt(2000000, 0).single().unwrap();
         storage.create_meeting(&metadata2).unwrap();
 
-        let meetings = storage.list_meetings(None).unwrap();
+        let meetings = storage.list_meetings(None, None).unwrap();
         assert_eq!(meetings.len(), 2);
         // Ordered by started_at DESC, so Second should be first
         assert_eq!(meetings[0].title, Some("Second".to_string()));
@@ -934,4 +1385,178 @@ mod tests {
         let dt = timestamp_to_datetime(0);
         assert_eq!(dt.timestamp(), 0);
     }
+
+    #[test]
+    fn test_enrolled_speakers() {
+        let (storage, _temp) = create_test_storage();
+        let speaker = |name: &str, embedding: Vec<f32>| EnrolledSpeaker {
+            name: name.to_string(),
+            embedding,
+            windows: 4,
+        };
+
+        storage
+            .enroll_speaker(&speaker("Bob", vec![0.0, 1.0]))
+            .unwrap();
+        storage
+            .enroll_speaker(&speaker("Alice", vec![1.0, 0.0]))
+            .unwrap();
+        // Enrolling again replaces the voice print
+        storage
+            .enroll_speaker(&speaker("Alice", vec![0.6, 0.8]))
+            .unwrap();
+
+        let enrolled = storage.list_enrolled_speakers().unwrap();
+        let names: Vec<_> = enrolled.iter().map(|(s, _)| s.name.as_str()).collect();
+        assert_eq!(names, ["Alice", "Bob"]);
+        assert_eq!(enrolled[0].0, speaker("Alice", vec![0.6, 0.8]));
+
+        assert!(storage.remove_enrolled_speaker("Bob").unwrap());
+        assert!(!storage.remove_enrolled_speaker("Bob").unwrap());
+        assert_eq!(storage.list_enrolled_speakers().unwrap().len(), 1);
+    }
+
+    fn create_meeting_with_segments(
+        storage: &MeetingStorage,
+        title: &str,
+        texts: &[&str],
+    ) -> MeetingId {
+        let mut metadata = MeetingMetadata::new(Some(title.to_string()));
+        let path = storage.create_meeting(&metadata).unwrap();
+        metadata.storage_path = Some(path);
+        storage.update_meeting(&metadata).unwrap();
+
+        let mut transcript = Transcript::new();
+        for (i, text) in texts.iter().enumerate() {
+            let start = i as u64 * 60_000;
+            transcript.add_segment(crate::meeting::data::TranscriptSegment::new(
+                i as u32,
+                start,
+                start + 5000,
+                text.to_string(),
+                0,
+            ));
+        }
+        storage.save_transcript(&metadata.id, &transcript).unwrap();
+        metadata.id
+    }
+
+    #[test]
+    fn test_search_transcripts() {
+        let (storage, _temp) = create_test_storage();
+        let standup = create_meeting_with_segments(
+            &storage,
+            "Standup",
+            &[
+                "Good morning everyone",
+                "We deployed the new billing service",
+            ],
+        );
+        create_meeting_with_segments(&storage, "Retro", &["The deploy went smoothly"]);
+
+        let hits = storage.search("billing", 10, None).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].meeting_id, standup);
+        assert_eq!(hits[0].display_title(), "Standup");
+        assert_eq!(hits[0].segment_id, 1);
+        assert_eq!(hits[0].format_timestamp(), "01:00");
+        assert!(hits[0].snippet.contains("**billing**"));
+
+        // Stemming matches "deployed" and "deploy"
+        assert_eq!(storage.search("deploy", 10, None).unwrap().len(), 2);
+        assert_eq!(storage.search("deploy", 1, None).unwrap().len(), 1);
+        // All words must appear
+        assert!(storage
+            .search("deploy morning", 10, None)
+            .unwrap()
+            .is_empty());
+        // Phrases and prefixes
+        assert_eq!(
+            storage.search("\"new billing\"", 10, None).unwrap().len(),
+            1
+        );
+        assert!(storage
+            .search("\"billing new\"", 10, None)
+            .unwrap()
+            .is_empty());
+        assert_eq!(storage.search("bill*", 10, None).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_search_reindexes_and_deletes() {
+        let (storage, _temp) = create_test_storage();
+        let id = create_meeting_with_segments(&storage, "Sync", &["Budget review"]);
+
+        let mut transcript = storage.load_transcript(&id).unwrap();
+        transcript.segments[0].text = "Hiring plan".to_string();
+        storage.save_transcript(&id, &transcript).unwrap();
+        assert!(storage.search("budget", 10, None).unwrap().is_empty());
+        assert_eq!(storage.search("hiring", 10, None).unwrap().len(), 1);
+
+        storage.delete_meeting(&id).unwrap();
+        assert!(storage.search("hiring", 10, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_search_indexes_older_meetings() {
+        let (storage, _temp) = create_test_storage();
+        create_meeting_with_segments(&storage, "Old", &["Quarterly roadmap"]);
+        // As if the transcript was saved before the index existed
+        storage
+            .conn
+            .execute_batch("DELETE FROM segments_fts; DELETE FROM segments_indexed;")
+            .unwrap();
+
+        assert_eq!(storage.search("roadmap", 10, None).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_tag_filters() {
+        let (storage, _temp) = create_test_storage();
+        let roadmap = create_meeting_with_segments(&storage, "Roadmap", &["Deploy in March"]);
+        create_meeting_with_segments(&storage, "Standup", &["Deploy today"]);
+
+        let mut metadata = storage.get_meeting(&roadmap).unwrap().unwrap();
+        metadata.set_tags(&["Planning".to_string(), "q3".to_string()]);
+        storage.update_meeting(&metadata).unwrap();
+        assert_eq!(
+            storage.get_meeting(&roadmap).unwrap().unwrap().tags,
+            vec!["Planning", "q3"]
+        );
+
+        let tagged = storage.list_meetings(None, Some("planning")).unwrap();
+        assert_eq!(tagged.len(), 1);
+        assert_eq!(tagged[0].id, roadmap);
+        assert_eq!(storage.list_meetings(None, None).unwrap().len(), 2);
+        assert!(storage
+            .list_meetings(None, Some("plan"))
+            .unwrap()
+            .is_empty());
+
+        assert_eq!(storage.search("deploy", 10, None).unwrap().len(), 2);
+        let hits = storage.search("deploy", 10, Some("Q3")).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].meeting_id, roadmap);
+    }
+
+    #[test]
+    fn test_search_query_syntax() {
+        assert_eq!(fts_query("  "), None);
+        assert_eq!(fts_query("*"), None);
+        assert_eq!(fts_query("ship it"), Some("\"ship\" \"it\"".to_string()));
+        assert_eq!(
+            fts_query("\"next week\" deploy*"),
+            Some("\"next week\" \"deploy\"*".to_string())
+        );
+        // FTS5 operators and punctuation are matched as text, not parsed
+        assert_eq!(
+            fts_query("NOT c++ a\"b"),
+            Some("\"NOT\" \"c++\" \"a\" \"b\"".to_string())
+        );
+
+        let (storage, _temp) = create_test_storage();
+        create_meeting_with_segments(&storage, "Lang", &["We write it in C++ (mostly)"]);
+        assert_eq!(storage.search("c++ (mostly", 10, None).unwrap().len(), 1);
+        assert!(storage.search("NOT AND OR -", 10, None).unwrap().is_empty());
+    }
 }
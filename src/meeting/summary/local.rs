@@ -3,8 +3,7 @@
 //! Integrates with a locally running Ollama instance for meeting summarization.
 //! Requires Ollama to be installed and running.
 
-use super::{generate_prompt, parse_summary_response, Summarizer, SummaryConfig, SummaryError};
-use crate::meeting::data::{MeetingData, MeetingSummary};
+use super::{Summarizer, SummaryConfig, SummaryError};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
@@ -16,6 +15,8 @@ pub struct OllamaSummarizer {
     model: String,
     /// Request timeout
     timeout: Duration,
+    /// Longest transcript excerpt per request
+    max_prompt_chars: usize,
 }
 
 impl OllamaSummarizer {
@@ -25,12 +26,13 @@ impl OllamaSummarizer {
             url: config.ollama_url.clone(),
             model: config.ollama_model.clone(),
             timeout: Duration::from_secs(config.timeout_secs),
+            max_prompt_chars: config.max_prompt_chars,
         }
     }
 
     /// Check if Ollama is running and the model is available
     pub fn check_availability(&self) -> Result<(), SummaryError> {
-        let client = ureq::AgentBuilder::new()
+        let client = crate::network::agent_builder(&self.url)
             .timeout(Duration::from_secs(5))
             .build();
 
@@ -79,7 +81,9 @@ impl OllamaSummarizer {
 
     /// Call Ollama generate API
     fn generate(&self, prompt: &str) -> Result<String, SummaryError> {
-        let client = ureq::AgentBuilder::new().timeout(self.timeout).build();
+        let client = crate::network::agent_builder(&self.url)
+            .timeout(self.timeout)
+            .build();
 
         let generate_url = format!("{}/api/generate", self.url);
 
@@ -133,28 +137,18 @@ impl OllamaSummarizer {
 }
 
 impl Summarizer for OllamaSummarizer {
-    fn summarize(&self, meeting: &MeetingData) -> Result<MeetingSummary, SummaryError> {
-        // Check transcript is not empty
-        if meeting.transcript.segments.is_empty() {
-            return Err(SummaryError::EmptyTranscript);
-        }
-
-        // Generate prompt
-        let prompt = generate_prompt(meeting);
-        tracing::debug!(
-            "Generated summarization prompt ({} chars, {} segments)",
-            prompt.len(),
-            meeting.transcript.segments.len()
-        );
-
-        // Call Ollama
-        let response = self.generate(&prompt)?;
+    fn complete(&self, prompt: &str) -> Result<String, SummaryError> {
+        let response = self.generate(prompt)?;
         tracing::debug!("Received response ({} chars)", response.len());
+        Ok(response)
+    }
 
-        // Parse response
-        let summary = parse_summary_response(&response, Some(self.model.clone()))?;
+    fn model(&self) -> Option<String> {
+        Some(self.model.clone())
+    }
 
-        Ok(summary)
+    fn max_prompt_chars(&self) -> usize {
+        self.max_prompt_chars
     }
 
     fn name(&self) -> &'static str {
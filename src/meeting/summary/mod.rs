@@ -8,15 +8,26 @@
 //! - **Local**: Uses Ollama for local LLM inference
 //! - **Remote**: Uses a remote API endpoint for summarization
 //! - **Disabled**: Summarization disabled
+//!
+//! Long transcripts are summarized in sections and merged; see [`rolling`].
 
 pub mod local;
 pub mod remote;
+pub mod rolling;
 
-use crate::meeting::data::{ActionItem, MeetingData, MeetingSummary};
+use crate::meeting::data::{
+    format_offset, ActionItem, MeetingData, MeetingSummary, TranscriptSegment,
+};
 use chrono::Utc;
+use rolling::RollingSummary;
 use serde::Deserialize;
+use std::path::Path;
 use thiserror::Error;
 
+/// File in the meeting directory holding the summary from
+/// `voxtype meeting summarize`
+pub const SUMMARY_FILE: &str = "summary.json";
+
 /// Summary-related errors
 #[derive(Error, Debug)]
 pub enum SummaryError {
@@ -79,9 +90,36 @@ pub fn summary_to_markdown(summary: &MeetingSummary) -> String {
         output.push('\n');
     }
 
+    if !summary.chapters.is_empty() {
+        output.push_str("## Chapters\n\n");
+        for chapter in &summary.chapters {
+            output.push_str(&format!(
+                "- [{}] {}\n",
+                chapter.format_timestamp(),
+                chapter.title
+            ));
+        }
+        output.push('\n');
+    }
+
     output
 }
 
+/// Store a meeting's summary in its directory
+pub fn save_summary(meeting_dir: &Path, summary: &MeetingSummary) -> std::io::Result<()> {
+    let json = serde_json::to_string_pretty(summary).map_err(std::io::Error::other)?;
+    std::fs::write(meeting_dir.join(SUMMARY_FILE), json)
+}
+
+/// The stored summary of a meeting: the last `voxtype meeting summarize`,
+/// else the rolling summary kept while it ran
+pub fn load_summary(meeting_dir: &Path) -> Option<MeetingSummary> {
+    let saved = std::fs::read_to_string(meeting_dir.join(SUMMARY_FILE))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok());
+    saved.or_else(|| RollingSummary::load(meeting_dir).and_then(|rolling| rolling.summary))
+}
+
 /// Summarization configuration
 #[derive(Debug, Clone)]
 pub struct SummaryConfig {
@@ -102,6 +140,24 @@ pub struct SummaryConfig {
 
     /// Request timeout in seconds
     pub timeout_secs: u64,
+
+    /// Longest transcript excerpt sent in one request, in characters
+    pub max_prompt_chars: usize,
+}
+
+impl SummaryConfig {
+    /// Summarization settings from `[meeting.summary]`
+    pub fn from_config(config: &crate::config::MeetingSummaryConfig) -> Self {
+        Self {
+            backend: config.backend.clone(),
+            ollama_url: config.ollama_url.clone(),
+            ollama_model: config.ollama_model.clone(),
+            remote_endpoint: config.remote_endpoint.clone(),
+            remote_api_key: config.remote_api_key.clone(),
+            timeout_secs: config.timeout_secs,
+            max_prompt_chars: config.max_prompt_chars,
+        }
+    }
 }
 
 impl Default for SummaryConfig {
@@ -113,14 +169,29 @@ impl Default for SummaryConfig {
             remote_endpoint: None,
             remote_api_key: None,
             timeout_secs: 120,
+            max_prompt_chars: 12000,
         }
     }
 }
 
 /// Trait for summarization backends
 pub trait Summarizer: Send + Sync {
-    /// Generate a summary from meeting data
-    fn summarize(&self, meeting: &MeetingData) -> Result<MeetingSummary, SummaryError>;
+    /// Send a prompt to the LLM and return its raw response
+    fn complete(&self, prompt: &str) -> Result<String, SummaryError>;
+
+    /// Model name recorded in generated summaries
+    fn model(&self) -> Option<String>;
+
+    /// Longest transcript excerpt to send in one request, in characters
+    fn max_prompt_chars(&self) -> usize;
+
+    /// Generate a summary from meeting data. Transcripts longer than
+    /// `max_prompt_chars` are summarized in sections that are then merged.
+    fn summarize(&self, meeting: &MeetingData) -> Result<MeetingSummary, SummaryError> {
+        let mut rolling = RollingSummary::default();
+        rolling.update(self, meeting)?;
+        rolling.summary.ok_or(SummaryError::EmptyTranscript)
+    }
 
     /// Get the backend name
     fn name(&self) -> &'static str;
@@ -151,6 +222,17 @@ pub fn create_summarizer(config: &SummaryConfig) -> Option<Box<dyn Summarizer>>
 
 /// Generate the prompt for summarization
 pub fn generate_prompt(meeting: &MeetingData) -> String {
+    let segments: Vec<&TranscriptSegment> = meeting.transcript.segments.iter().collect();
+    transcript_prompt(meeting, &segments, None)
+}
+
+/// Prompt summarizing `segments`. With `part` (start and end in ms), the
+/// model is told the excerpt is one part of a longer meeting.
+pub(crate) fn transcript_prompt(
+    meeting: &MeetingData,
+    segments: &[&TranscriptSegment],
+    part: Option<(u64, u64)>,
+) -> String {
     let mut prompt = String::from(
         r#"Analyze the following meeting transcript and provide a structured summary.
 
@@ -168,15 +250,29 @@ Format your response as JSON with this structure:
     if let Some(ref title) = meeting.metadata.title {
         prompt.push_str(&format!("Meeting Title: {}\n", title));
     }
+    if !meeting.metadata.attendees.is_empty() {
+        prompt.push_str(&format!(
+            "Attendees: {}\n",
+            meeting.metadata.attendees.join(", ")
+        ));
+    }
 
     prompt.push_str(&format!(
         "Date: {}\n\n",
         meeting.metadata.started_at.format("%Y-%m-%d %H:%M")
     ));
 
+    if let Some((start_ms, end_ms)) = part {
+        prompt.push_str(&format!(
+            "This transcript is one part of a longer meeting ({} to {}). Summarize only this part.\n\n",
+            format_offset(start_ms),
+            format_offset(end_ms)
+        ));
+    }
+
     prompt.push_str("## Transcript\n\n");
 
-    for segment in &meeting.transcript.segments {
+    for segment in segments {
         let speaker = segment.speaker_display();
         if !speaker.is_empty() && speaker != "Unknown" {
             prompt.push_str(&format!("{}: {}\n", speaker, segment.text));
@@ -241,6 +337,7 @@ pub fn parse_summary_response(
         decisions: raw.decisions.unwrap_or_default(),
         generated_at: Utc::now(),
         model,
+        chapters: Vec::new(),
     })
 }
 
@@ -260,6 +357,7 @@ fn extract_json(s: &str) -> Option<&str> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::meeting::data::Chapter;
 
     #[test]
     fn test_extract_json_simple() {
@@ -310,6 +408,11 @@ mod tests {
             decisions: vec!["Decision 1".to_string()],
             generated_at: Utc::now(),
             model: None,
+            chapters: vec![Chapter {
+                title: "Billing migration".to_string(),
+                start_ms: 754_000,
+                end_ms: 1_200_000,
+            }],
         };
 
         let md = summary_to_markdown(&summary);
@@ -317,6 +420,32 @@ mod tests {
         assert!(md.contains("Test meeting summary"));
         assert!(md.contains("## Action Items"));
         assert!(md.contains("[ ] Do thing (Alice)"));
+        assert!(md.contains("## Chapters\n\n- [12:34] Billing migration\n"));
+    }
+
+    #[test]
+    fn test_save_and_load_summary() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert!(load_summary(dir.path()).is_none());
+
+        let summary = |text: &str| MeetingSummary {
+            summary: text.to_string(),
+            key_points: Vec::new(),
+            action_items: Vec::new(),
+            decisions: Vec::new(),
+            generated_at: Utc::now(),
+            model: None,
+            chapters: Vec::new(),
+        };
+        let rolling = RollingSummary {
+            sections: Vec::new(),
+            summary: Some(summary("So far")),
+        };
+        rolling.save(dir.path()).unwrap();
+        assert_eq!(load_summary(dir.path()).unwrap().summary, "So far");
+
+        save_summary(dir.path(), &summary("Final")).unwrap();
+        assert_eq!(load_summary(dir.path()).unwrap().summary, "Final");
     }
 
     #[test]
@@ -3,8 +3,7 @@
 //! Integrates with a remote summarization service for meetings.
 //! Useful for corporate deployments with centralized AI infrastructure.
 
-use super::{generate_prompt, parse_summary_response, Summarizer, SummaryConfig, SummaryError};
-use crate::meeting::data::{MeetingData, MeetingSummary};
+use super::{Summarizer, SummaryConfig, SummaryError};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
@@ -16,6 +15,8 @@ pub struct RemoteSummarizer {
     api_key: Option<String>,
     /// Request timeout
     timeout: Duration,
+    /// Longest transcript excerpt per request
+    max_prompt_chars: usize,
 }
 
 impl RemoteSummarizer {
@@ -28,12 +29,15 @@ impl RemoteSummarizer {
                 .unwrap_or_else(|| "http://localhost:8080/api/summarize".to_string()),
             api_key: config.remote_api_key.clone(),
             timeout: Duration::from_secs(config.timeout_secs),
+            max_prompt_chars: config.max_prompt_chars,
         }
     }
 
     /// Call the remote summarization API
     fn call_api(&self, prompt: &str) -> Result<String, SummaryError> {
-        let client = ureq::AgentBuilder::new().timeout(self.timeout).build();
+        let client = crate::network::agent_builder(&self.endpoint)
+            .timeout(self.timeout)
+            .build();
 
         #[derive(Serialize)]
         struct SummarizeRequest<'a> {
@@ -90,28 +94,18 @@ impl RemoteSummarizer {
 }
 
 impl Summarizer for RemoteSummarizer {
-    fn summarize(&self, meeting: &MeetingData) -> Result<MeetingSummary, SummaryError> {
-        // Check transcript is not empty
-        if meeting.transcript.segments.is_empty() {
-            return Err(SummaryError::EmptyTranscript);
-        }
-
-        // Generate prompt
-        let prompt = generate_prompt(meeting);
-        tracing::debug!(
-            "Generated summarization prompt ({} chars, {} segments)",
-            prompt.len(),
-            meeting.transcript.segments.len()
-        );
-
-        // Call remote API
-        let response = self.call_api(&prompt)?;
+    fn complete(&self, prompt: &str) -> Result<String, SummaryError> {
+        let response = self.call_api(prompt)?;
         tracing::debug!("Received response ({} chars)", response.len());
+        Ok(response)
+    }
 
-        // Parse response
-        let summary = parse_summary_response(&response, Some("remote".to_string()))?;
+    fn model(&self) -> Option<String> {
+        Some("remote".to_string())
+    }
 
-        Ok(summary)
+    fn max_prompt_chars(&self) -> usize {
+        self.max_prompt_chars
     }
 
     fn name(&self) -> &'static str {
@@ -120,7 +114,7 @@ impl Summarizer for RemoteSummarizer {
 
     fn is_available(&self) -> bool {
         // Try a simple health check
-        let client = ureq::AgentBuilder::new()
+        let client = crate::network::agent_builder(&self.endpoint)
             .timeout(Duration::from_secs(5))
             .build();
 
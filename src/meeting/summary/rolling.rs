@@ -0,0 +1,409 @@
+//! Summaries of long and running meetings
+//!
+//! A transcript too long for one prompt is summarized in sections, each
+//! within `[meeting.summary] max_prompt_chars` (map), and the section
+//! summaries are merged into one (reduce). With `rolling_interval_mins`
+//! set, the daemon does this while the meeting runs: every few minutes it
+//! summarizes only what was said since the last update and merges it with
+//! the earlier sections, so an update costs the same early and late in a
+//! three-hour meeting. The sections are stored next to the transcript as
+//! `summary_rolling.json`, and `voxtype meeting summarize` reuses them.
+
+use super::{
+    parse_summary_response, summary_to_markdown, transcript_prompt, Summarizer, SummaryError,
+};
+use crate::meeting::data::{format_offset, MeetingData, MeetingSummary, TranscriptSegment};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// File in the meeting directory holding the rolling summary
+pub const ROLLING_SUMMARY_FILE: &str = "summary_rolling.json";
+
+/// Summary of one stretch of the transcript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SectionSummary {
+    /// Start of the first segment summarized, in meeting time
+    pub start_ms: u64,
+    /// End of the last segment summarized
+    pub end_ms: u64,
+    pub summary: MeetingSummary,
+}
+
+/// Section summaries of a meeting so far and their merged summary
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RollingSummary {
+    pub sections: Vec<SectionSummary>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<MeetingSummary>,
+}
+
+impl RollingSummary {
+    /// Load the rolling summary stored in `meeting_dir`, if any
+    pub fn load(meeting_dir: &Path) -> Option<Self> {
+        let json = std::fs::read_to_string(meeting_dir.join(ROLLING_SUMMARY_FILE)).ok()?;
+        match serde_json::from_str(&json) {
+            Ok(rolling) => Some(rolling),
+            Err(e) => {
+                tracing::warn!("Ignoring unreadable {}: {}", ROLLING_SUMMARY_FILE, e);
+                None
+            }
+        }
+    }
+
+    /// Store the rolling summary in `meeting_dir`
+    pub fn save(&self, meeting_dir: &Path) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(meeting_dir.join(ROLLING_SUMMARY_FILE), json)
+    }
+
+    /// Meeting time the sections cover, in milliseconds
+    pub fn covered_ms(&self) -> u64 {
+        self.sections.last().map(|s| s.end_ms).unwrap_or(0)
+    }
+
+    /// Summarize the transcript after `covered_ms` and merge it into the
+    /// summary. Returns false when nothing new was said. Sections finished
+    /// before an error are kept.
+    pub fn update<S: Summarizer + ?Sized>(
+        &mut self,
+        summarizer: &S,
+        meeting: &MeetingData,
+    ) -> Result<bool, SummaryError> {
+        let covered_ms = self.covered_ms();
+        let new: Vec<&TranscriptSegment> = meeting
+            .transcript
+            .segments
+            .iter()
+            .filter(|s| s.start_ms >= covered_ms && !s.text.trim().is_empty())
+            .collect();
+        if new.is_empty() {
+            return Ok(false);
+        }
+
+        let max_chars = summarizer.max_prompt_chars();
+        let parts = split_segments(&new, max_chars);
+        let whole_meeting = self.sections.is_empty() && parts.len() == 1;
+        for part in parts {
+            let start_ms = part[0].start_ms;
+            let end_ms = part.iter().map(|s| s.end_ms).max().unwrap_or(start_ms);
+            let range = (!whole_meeting).then_some((start_ms, end_ms));
+            let prompt = transcript_prompt(meeting, &part, range);
+            tracing::debug!(
+                "Summarizing {}-{} ({} chars, {} segments)",
+                format_offset(start_ms),
+                format_offset(end_ms),
+                prompt.len(),
+                part.len()
+            );
+            let summary =
+                parse_summary_response(&summarizer.complete(&prompt)?, summarizer.model())?;
+            self.sections.push(SectionSummary {
+                start_ms,
+                end_ms,
+                summary,
+            });
+        }
+
+        self.summary = Some(self.merge(summarizer, meeting)?);
+        Ok(true)
+    }
+
+    /// Merge the section summaries, in groups that fit one prompt, until
+    /// one is left
+    fn merge<S: Summarizer + ?Sized>(
+        &self,
+        summarizer: &S,
+        meeting: &MeetingData,
+    ) -> Result<MeetingSummary, SummaryError> {
+        let mut summaries: Vec<(String, MeetingSummary)> = self
+            .sections
+            .iter()
+            .map(|s| {
+                let label = format!("{}-{}", format_offset(s.start_ms), format_offset(s.end_ms));
+                (label, s.summary.clone())
+            })
+            .collect();
+
+        while summaries.len() > 1 {
+            let mut merged = Vec::new();
+            for group in group_summaries(summaries, summarizer.max_prompt_chars()) {
+                if group.len() == 1 {
+                    merged.extend(group);
+                    continue;
+                }
+                let label = format!(
+                    "{}-{}",
+                    group[0].0.split('-').next().unwrap_or_default(),
+                    group[group.len() - 1]
+                        .0
+                        .rsplit('-')
+                        .next()
+                        .unwrap_or_default()
+                );
+                let prompt = merge_prompt(meeting, &group);
+                tracing::debug!("Merging {} section summaries ({})", group.len(), label);
+                let summary =
+                    parse_summary_response(&summarizer.complete(&prompt)?, summarizer.model())?;
+                merged.push((label, summary));
+            }
+            summaries = merged;
+        }
+
+        summaries
+            .pop()
+            .map(|(_, summary)| summary)
+            .ok_or(SummaryError::EmptyTranscript)
+    }
+}
+
+/// Split segments into consecutive parts whose transcript lines stay within
+/// `max_chars`. A part always has at least one segment.
+fn split_segments<'a>(
+    segments: &[&'a TranscriptSegment],
+    max_chars: usize,
+) -> Vec<Vec<&'a TranscriptSegment>> {
+    let mut parts: Vec<Vec<&TranscriptSegment>> = Vec::new();
+    let mut chars = 0;
+    for segment in segments {
+        let len = segment.speaker_display().len() + segment.text.len() + 3;
+        match parts.last_mut() {
+            Some(part) if chars + len <= max_chars => part.push(segment),
+            _ => {
+                parts.push(vec![segment]);
+                chars = 0;
+            }
+        }
+        chars += len;
+    }
+    parts
+}
+
+/// Group consecutive summaries for merging. Groups stay within `max_chars`
+/// but take at least two summaries, so every round shrinks the list.
+fn group_summaries(
+    summaries: Vec<(String, MeetingSummary)>,
+    max_chars: usize,
+) -> Vec<Vec<(String, MeetingSummary)>> {
+    let mut groups: Vec<Vec<(String, MeetingSummary)>> = Vec::new();
+    let mut chars = 0;
+    for (label, summary) in summaries {
+        let len = summary_to_markdown(&summary).len();
+        match groups.last_mut() {
+            Some(group) if group.len() < 2 || chars + len <= max_chars => {
+                group.push((label, summary))
+            }
+            _ => {
+                groups.push(vec![(label, summary)]);
+                chars = 0;
+            }
+        }
+        chars += len;
+    }
+    groups
+}
+
+/// Prompt asking to merge the summaries of consecutive parts of a meeting
+fn merge_prompt(meeting: &MeetingData, parts: &[(String, MeetingSummary)]) -> String {
+    let mut prompt = String::from(
+        r#"Below are summaries of consecutive parts of one meeting. Merge them into a single structured summary of the whole meeting. Combine duplicate points, keep every action item and decision, and keep the summary to 3-5 sentences.
+
+Format your response as JSON with this structure:
+{
+  "summary": "3-5 sentence summary of the meeting",
+  "key_points": ["point 1", "point 2"],
+  "action_items": [{"description": "task description", "assignee": "person or null", "due_date": "date or null"}],
+  "decisions": ["decision 1", "decision 2"]
+}
+
+"#,
+    );
+
+    if let Some(ref title) = meeting.metadata.title {
+        prompt.push_str(&format!("Meeting Title: {}\n", title));
+    }
+    if !meeting.metadata.attendees.is_empty() {
+        prompt.push_str(&format!(
+            "Attendees: {}\n",
+            meeting.metadata.attendees.join(", ")
+        ));
+    }
+    prompt.push('\n');
+
+    for (label, summary) in parts {
+        prompt.push_str(&format!("# Part {}\n\n", label));
+        prompt.push_str(&summary_to_markdown(summary));
+    }
+
+    prompt.push_str("Provide the merged JSON summary:");
+    prompt
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Answers every prompt with a summary naming the prompt number, and
+    /// records the prompts
+    struct FakeSummarizer {
+        max_prompt_chars: usize,
+        prompts: Mutex<Vec<String>>,
+    }
+
+    impl FakeSummarizer {
+        fn new(max_prompt_chars: usize) -> Self {
+            Self {
+                max_prompt_chars,
+                prompts: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn prompts(&self) -> Vec<String> {
+            self.prompts.lock().unwrap().clone()
+        }
+    }
+
+    impl Summarizer for FakeSummarizer {
+        fn complete(&self, prompt: &str) -> Result<String, SummaryError> {
+            let mut prompts = self.prompts.lock().unwrap();
+            prompts.push(prompt.to_string());
+            Ok(format!(
+                r#"{{"summary": "Summary {}", "action_items": [{{"description": "Item {}"}}]}}"#,
+                prompts.len(),
+                prompts.len()
+            ))
+        }
+
+        fn model(&self) -> Option<String> {
+            Some("fake".to_string())
+        }
+
+        fn max_prompt_chars(&self) -> usize {
+            self.max_prompt_chars
+        }
+
+        fn name(&self) -> &'static str {
+            "fake"
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+    }
+
+    fn meeting_with(texts: &[&str]) -> MeetingData {
+        let mut meeting = MeetingData::new(Some("Planning".to_string()));
+        for (i, text) in texts.iter().enumerate() {
+            let start = i as u64 * 10_000;
+            meeting.add_segment(TranscriptSegment::new(
+                i as u32,
+                start,
+                start + 9_000,
+                text.to_string(),
+                i as u32,
+            ));
+        }
+        meeting
+    }
+
+    #[test]
+    fn test_short_meeting_is_one_request() {
+        let summarizer = FakeSummarizer::new(10_000);
+        let summary = summarizer
+            .summarize(&meeting_with(&["Hello.", "Let's plan."]))
+            .unwrap();
+
+        assert_eq!(summary.summary, "Summary 1");
+        assert_eq!(summary.model, Some("fake".to_string()));
+        let prompts = summarizer.prompts();
+        assert_eq!(prompts.len(), 1);
+        assert!(prompts[0].contains("Let's plan."));
+        assert!(!prompts[0].contains("part of a longer meeting"));
+    }
+
+    #[test]
+    fn test_long_meeting_is_mapped_and_reduced() {
+        let text = "word ".repeat(20);
+        let texts: Vec<&str> = std::iter::repeat_n(text.as_str(), 6).collect();
+        // Room for two segments per part
+        let summarizer = FakeSummarizer::new(250);
+        let mut rolling = RollingSummary::default();
+        assert!(rolling.update(&summarizer, &meeting_with(&texts)).unwrap());
+
+        assert_eq!(rolling.sections.len(), 3);
+        assert_eq!(rolling.sections[1].start_ms, 20_000);
+        assert_eq!(rolling.covered_ms(), 59_000);
+        let prompts = summarizer.prompts();
+        assert!(prompts[0].contains("part of a longer meeting (00:00 to 00:19)"));
+        // Three section prompts, then merges until one summary is left
+        assert!(prompts.len() > 3);
+        assert!(prompts[3].starts_with("Below are summaries"));
+        assert!(prompts[3].contains("# Part 00:00-00:19"));
+        assert!(rolling.summary.is_some());
+    }
+
+    #[test]
+    fn test_update_summarizes_only_new_segments() {
+        let summarizer = FakeSummarizer::new(10_000);
+        let mut rolling = RollingSummary::default();
+        let mut meeting = meeting_with(&["First topic."]);
+        assert!(rolling.update(&summarizer, &meeting).unwrap());
+        assert_eq!(rolling.summary.as_ref().unwrap().summary, "Summary 1");
+
+        // Nothing new, no request
+        assert!(!rolling.update(&summarizer, &meeting).unwrap());
+        assert_eq!(summarizer.prompts().len(), 1);
+
+        meeting.add_segment(TranscriptSegment::new(
+            1,
+            9_000,
+            15_000,
+            "Second topic.".to_string(),
+            1,
+        ));
+        assert!(rolling.update(&summarizer, &meeting).unwrap());
+        let prompts = summarizer.prompts();
+        assert_eq!(prompts.len(), 3);
+        assert!(prompts[1].contains("Second topic."));
+        assert!(!prompts[1].contains("First topic."));
+        assert!(prompts[2].starts_with("Below are summaries"));
+        assert_eq!(rolling.summary.as_ref().unwrap().summary, "Summary 3");
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert!(RollingSummary::load(dir.path()).is_none());
+
+        let summarizer = FakeSummarizer::new(10_000);
+        let mut rolling = RollingSummary::default();
+        rolling
+            .update(&summarizer, &meeting_with(&["Hello."]))
+            .unwrap();
+        rolling.save(dir.path()).unwrap();
+
+        let loaded = RollingSummary::load(dir.path()).unwrap();
+        assert_eq!(loaded.sections.len(), 1);
+        assert_eq!(loaded.covered_ms(), 9_000);
+    }
+
+    #[test]
+    fn test_group_summaries_always_progresses() {
+        let summary = |text: &str| MeetingSummary {
+            summary: text.to_string(),
+            key_points: Vec::new(),
+            action_items: Vec::new(),
+            decisions: Vec::new(),
+            generated_at: chrono::Utc::now(),
+            model: None,
+            chapters: Vec::new(),
+        };
+        let summaries: Vec<_> = (0..5)
+            .map(|i| (i.to_string(), summary(&"x".repeat(100))))
+            .collect();
+        // Too small for any two summaries, still paired
+        let groups = group_summaries(summaries, 10);
+        let sizes: Vec<_> = groups.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![2, 2, 1]);
+    }
+}
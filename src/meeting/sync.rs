@@ -0,0 +1,404 @@
+//! Upload of finished meetings to a sync server
+//!
+//! With `[meeting.sync] endpoint` set, finished meetings are uploaded so
+//! an organization can keep its meeting records in one place. Each
+//! meeting is sent as:
+//!
+//! - `PUT <endpoint>/<meeting id>`: the JSON export (metadata, transcript
+//!   segments and chapters, and the summary once there is one)
+//! - `PUT <endpoint>/<meeting id>/audio`: the retained `audio.wav`, with
+//!   `include_audio = true`
+//!
+//! with `Authorization: Bearer <token>` when a token is set. Uploads
+//! replace what the server has, so a meeting that changes is simply sent
+//! again. Connection errors, timeouts, 408, 429 and 5xx responses are
+//! retried `max_retries` times, each after twice the previous delay. A
+//! meeting whose uploads succeed gets `synced_at`; editing or summarizing
+//! it clears that, so it is sent again.
+//!
+//! The daemon uploads meetings as they end and retries the rest every
+//! `interval_mins`; `voxtype meeting sync` uploads on demand.
+
+use super::data::{MeetingData, MeetingId, MeetingMetadata, MeetingStatus};
+use super::export::{export_meeting, ExportFormat, ExportOptions};
+use super::storage::{MeetingStorage, StorageError};
+use super::summary::load_summary;
+use crate::config::MeetingSyncConfig;
+use chrono::Utc;
+use std::path::Path;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Delay before the first retry
+const RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// Sync errors
+#[derive(Error, Debug)]
+pub enum SyncError {
+    #[error("{url} returned {status}: {body}")]
+    Status {
+        url: String,
+        status: u16,
+        body: String,
+    },
+
+    #[error("{url}: {message}")]
+    Transport { url: String, message: String },
+
+    #[error("Export failed: {0}")]
+    Export(String),
+
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl SyncError {
+    /// Whether the request may succeed when sent again
+    fn is_retryable(&self) -> bool {
+        match self {
+            SyncError::Status { status, .. } => matches!(status, 408 | 429 | 500..),
+            SyncError::Transport { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+/// What a request uploads
+enum Body<'a> {
+    Json(&'a str),
+    Audio(&'a Path),
+}
+
+/// Client for the sync server
+pub struct SyncClient {
+    endpoint: String,
+    token: Option<String>,
+    include_audio: bool,
+    max_retries: u32,
+    retry_delay: Duration,
+    agent: ureq::Agent,
+}
+
+impl SyncClient {
+    /// Client for `config`, or `None` when no endpoint is set
+    pub fn new(config: &MeetingSyncConfig) -> Option<Self> {
+        let endpoint = config
+            .endpoint
+            .as_deref()
+            .map(str::trim)
+            .filter(|e| !e.is_empty())?;
+        let token = config
+            .token
+            .clone()
+            .or_else(|| std::env::var("VOXTYPE_MEETING_SYNC_TOKEN").ok())
+            .filter(|t| !t.trim().is_empty());
+        let agent = crate::network::agent_builder(endpoint)
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build();
+        Some(Self {
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            token,
+            include_audio: config.include_audio,
+            max_retries: config.max_retries,
+            retry_delay: RETRY_DELAY,
+            agent,
+        })
+    }
+
+    /// Where a meeting is uploaded
+    pub fn meeting_url(&self, id: &MeetingId) -> String {
+        format!("{}/{}", self.endpoint, id)
+    }
+
+    /// Upload a meeting, and its audio with `include_audio`. Blocking.
+    pub fn upload(&self, meeting: &MeetingData) -> Result<(), SyncError> {
+        let options = ExportOptions {
+            include_timestamps: true,
+            include_speakers: true,
+            include_metadata: true,
+            ..Default::default()
+        };
+        let json = export_meeting(meeting, ExportFormat::Json, &options)
+            .map_err(|e| SyncError::Export(e.to_string()))?;
+        let url = self.meeting_url(&meeting.metadata.id);
+        self.put(&url, Body::Json(&json))?;
+
+        if self.include_audio {
+            if let Some(audio) = meeting.metadata.audio_path().filter(|p| p.exists()) {
+                self.put(&format!("{}/audio", url), Body::Audio(&audio))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// PUT `body` to `url`, retrying failures that may pass
+    fn put(&self, url: &str, body: Body) -> Result<(), SyncError> {
+        let mut attempt = 0;
+        loop {
+            match self.put_once(url, &body) {
+                Err(e) if e.is_retryable() && attempt < self.max_retries => {
+                    let delay = self.retry_delay * 2u32.saturating_pow(attempt);
+                    tracing::debug!("Meeting upload failed ({}), retrying in {:?}", e, delay);
+                    std::thread::sleep(delay);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn put_once(&self, url: &str, body: &Body) -> Result<(), SyncError> {
+        let mut request = self.agent.put(url);
+        if let Some(ref token) = self.token {
+            request = request.set("Authorization", &format!("Bearer {}", token));
+        }
+        let response = match body {
+            Body::Json(json) => request
+                .set("Content-Type", "application/json")
+                .send_string(json),
+            Body::Audio(path) => {
+                // Streamed with its length, so long recordings aren't
+                // read into memory or sent chunked
+                let file = std::fs::File::open(path)?;
+                let length = file.metadata()?.len();
+                request
+                    .set("Content-Type", "audio/wav")
+                    .set("Content-Length", &length.to_string())
+                    .send(file)
+            }
+        };
+        response.map(|_| ()).map_err(|e| match e {
+            ureq::Error::Status(status, response) => SyncError::Status {
+                url: url.to_string(),
+                status,
+                body: response
+                    .into_string()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string(),
+            },
+            ureq::Error::Transport(t) => SyncError::Transport {
+                url: url.to_string(),
+                message: t.to_string(),
+            },
+        })
+    }
+}
+
+/// Finished meetings not uploaded since they last changed, oldest first
+pub fn pending(storage: &MeetingStorage) -> Result<Vec<MeetingMetadata>, StorageError> {
+    let mut meetings: Vec<MeetingMetadata> = storage
+        .list_meetings(None, None)?
+        .into_iter()
+        .filter(|m| m.status == MeetingStatus::Completed && m.synced_at.is_none())
+        .collect();
+    meetings.reverse();
+    Ok(meetings)
+}
+
+/// Upload a stored meeting with its summary and mark it synced. Blocking.
+pub fn sync_meeting(
+    storage: &MeetingStorage,
+    client: &SyncClient,
+    id: &MeetingId,
+) -> Result<MeetingMetadata, SyncError> {
+    let mut meeting = storage.load_meeting_data(id)?;
+    meeting.metadata.summary = meeting
+        .metadata
+        .storage_path
+        .as_deref()
+        .and_then(load_summary);
+    client.upload(&meeting)?;
+
+    let mut metadata = meeting.metadata;
+    metadata.summary = None;
+    metadata.synced_at = Some(Utc::now());
+    storage.update_meeting(&metadata)?;
+    Ok(metadata)
+}
+
+/// A pending meeting and the outcome of its upload
+#[derive(Debug)]
+pub struct Synced {
+    pub metadata: MeetingMetadata,
+    pub result: Result<(), SyncError>,
+}
+
+/// Upload every pending meeting, continuing past failures. Blocking.
+pub fn sync_pending(
+    storage: &MeetingStorage,
+    client: &SyncClient,
+) -> Result<Vec<Synced>, StorageError> {
+    Ok(pending(storage)?
+        .into_iter()
+        .map(|metadata| {
+            let result = sync_meeting(storage, client, &metadata.id).map(|_| ());
+            Synced { metadata, result }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meeting::data::{TranscriptSegment, AUDIO_FILE_NAME};
+    use crate::meeting::storage::StorageConfig;
+    use std::sync::{Arc, Mutex};
+
+    /// A request the mock server received
+    #[derive(Debug, Clone)]
+    struct Request {
+        line: String,
+        authorization: Option<String>,
+        body: Vec<u8>,
+    }
+
+    /// Answer requests with `statuses` in turn, repeating the last
+    fn mock_server(statuses: &[&'static str]) -> (String, Arc<Mutex<Vec<Request>>>) {
+        use std::io::{BufRead, BufReader, Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let received = Arc::clone(&requests);
+        let statuses = statuses.to_vec();
+        std::thread::spawn(move || {
+            for (i, stream) in listener.incoming().enumerate() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let (mut length, mut authorization) = (0, None);
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header == "\r\n" {
+                        break;
+                    }
+                    let (name, value) = header.split_once(':').unwrap();
+                    match name.to_ascii_lowercase().as_str() {
+                        "content-length" => length = value.trim().parse().unwrap(),
+                        "authorization" => authorization = Some(value.trim().to_string()),
+                        _ => {}
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                received.lock().unwrap().push(Request {
+                    line: line.trim().to_string(),
+                    authorization,
+                    body,
+                });
+                let status = statuses[i.min(statuses.len() - 1)];
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    status
+                );
+            }
+        });
+        (format!("http://{}/api/meetings/", addr), requests)
+    }
+
+    fn client(endpoint: &str, include_audio: bool) -> SyncClient {
+        let config = MeetingSyncConfig {
+            endpoint: Some(endpoint.to_string()),
+            token: Some("secret".to_string()),
+            include_audio,
+            max_retries: 2,
+            ..Default::default()
+        };
+        let mut client = SyncClient::new(&config).unwrap();
+        client.retry_delay = Duration::ZERO;
+        client
+    }
+
+    fn finished_meeting() -> MeetingData {
+        let mut meeting = MeetingData::new(Some("Standup".to_string()));
+        meeting.add_segment(TranscriptSegment::new(
+            0,
+            0,
+            2000,
+            "Shipping today.".to_string(),
+            0,
+        ));
+        meeting.complete();
+        meeting
+    }
+
+    #[test]
+    fn test_upload_retries() {
+        let (endpoint, requests) = mock_server(&["503 Service Unavailable", "204 No Content"]);
+        let meeting = finished_meeting();
+        client(&endpoint, false).upload(&meeting).unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(
+            requests[1].line,
+            format!("PUT /api/meetings/{} HTTP/1.1", meeting.metadata.id)
+        );
+        assert_eq!(requests[1].authorization.as_deref(), Some("Bearer secret"));
+        let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(body["metadata"]["title"], "Standup");
+        assert_eq!(body["transcript"]["segments"][0]["text"], "Shipping today.");
+    }
+
+    #[test]
+    fn test_upload_gives_up() {
+        // Client errors aren't retried
+        let (endpoint, requests) = mock_server(&["403 Forbidden"]);
+        let result = client(&endpoint, false).upload(&finished_meeting());
+        assert!(matches!(result, Err(SyncError::Status { status: 403, .. })));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        // Server errors are retried max_retries times
+        let (endpoint, requests) = mock_server(&["500 Internal Server Error"]);
+        let result = client(&endpoint, false).upload(&finished_meeting());
+        assert!(matches!(result, Err(SyncError::Status { status: 500, .. })));
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_sync_pending() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let storage = MeetingStorage::open(StorageConfig {
+            storage_path: dir.path().to_path_buf(),
+            retain_audio: true,
+            max_meetings: 0,
+        })
+        .unwrap();
+
+        let mut meeting = finished_meeting();
+        let path = storage.create_meeting(&meeting.metadata).unwrap();
+        std::fs::write(path.join(AUDIO_FILE_NAME), [1u8; 32]).unwrap();
+        meeting.metadata.storage_path = Some(path);
+        meeting.metadata.audio_retained = true;
+        storage.update_meeting(&meeting.metadata).unwrap();
+        storage
+            .save_transcript(&meeting.metadata.id, &meeting.transcript)
+            .unwrap();
+        // Meetings being recorded wait until they end
+        storage
+            .create_meeting(&MeetingData::new(None).metadata)
+            .unwrap();
+
+        let (endpoint, requests) = mock_server(&["200 OK"]);
+        let client = client(&endpoint, true);
+        let results = sync_pending(&storage, &client).unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].result.is_ok());
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].line.ends_with("/audio HTTP/1.1"));
+        assert_eq!(requests[1].body, vec![1u8; 32]);
+
+        let stored = storage.get_meeting(&meeting.metadata.id).unwrap().unwrap();
+        assert!(stored.synced_at.is_some());
+        assert!(pending(&storage).unwrap().is_empty());
+    }
+}
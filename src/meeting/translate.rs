@@ -0,0 +1,260 @@
+//! Translation of meeting segments
+//!
+//! With `[meeting.translation]` set, each transcribed segment also gets a
+//! translation, stored in the transcript next to the original text:
+//!
+//! - `backend = "whisper"` runs whisper's translate task over the segment's
+//!   audio. Whisper only translates into English. The meeting transcriber
+//!   is reused when it can switch tasks per call; otherwise a second one is
+//!   created with `translate = true`.
+//! - `backend = "llm"` sends the segment text to a language model
+//!   (`[meeting.translation.llm]`, else `[output.llm]`).
+//!
+//! A segment whose translation matches the original (it was already in the
+//! target language) keeps no translation. A failed translation is logged
+//! and the segment is kept untranslated.
+
+use super::data::TranscriptSegment;
+use crate::config::{Config, MeetingTranslationConfig, TranscriptionEngine};
+use crate::output::llm::LlmClient;
+use crate::transcribe::{self, Transcriber};
+use std::sync::Arc;
+
+/// Audio sample rate of meeting chunks
+const SAMPLE_RATE: u64 = 16_000;
+
+/// Adds translations to transcribed segments
+pub enum Translator {
+    /// Whisper's translate task over each segment's audio
+    Whisper {
+        transcriber: Arc<dyn Transcriber>,
+        /// Translate is switched on for each call and back to this value
+        /// afterwards; `None` when the transcriber always translates
+        restore: Option<bool>,
+    },
+    /// A language model prompted to translate the text
+    Llm(Arc<LlmClient>),
+}
+
+impl Translator {
+    /// Create the configured translator. `transcriber` is the meeting's
+    /// transcriber, built from `app_config`. Returns `None` when
+    /// translation is disabled or can't be set up (logged).
+    pub fn new(
+        config: &MeetingTranslationConfig,
+        app_config: &Config,
+        transcriber: &Arc<dyn Transcriber>,
+    ) -> Option<Self> {
+        match config.backend.as_str() {
+            "whisper" => Self::whisper(config, app_config, transcriber),
+            "llm" => {
+                let llm_config = config
+                    .llm
+                    .clone()
+                    .or_else(|| app_config.output.llm.clone())
+                    .unwrap_or_default();
+                match LlmClient::new(&llm_config) {
+                    Ok(client) => {
+                        tracing::info!(
+                            "Meeting translation into {} with {}",
+                            config.target_language,
+                            llm_config.model
+                        );
+                        let prompt = translation_prompt(&config.target_language);
+                        Some(Self::Llm(Arc::new(client.with_prompt(&prompt))))
+                    }
+                    Err(e) => {
+                        tracing::warn!("Meeting translation disabled: {}", e);
+                        None
+                    }
+                }
+            }
+            "disabled" | "" => None,
+            other => {
+                tracing::warn!("Unknown translation backend '{}', disabling", other);
+                None
+            }
+        }
+    }
+
+    fn whisper(
+        config: &MeetingTranslationConfig,
+        app_config: &Config,
+        transcriber: &Arc<dyn Transcriber>,
+    ) -> Option<Self> {
+        if app_config.engine != TranscriptionEngine::Whisper {
+            tracing::warn!(
+                "Translation backend \"whisper\" needs engine = \"whisper\"; use backend = \"llm\" instead"
+            );
+            return None;
+        }
+        if !config.target_language.eq_ignore_ascii_case("english") {
+            tracing::warn!(
+                "Whisper only translates into English, not {}; use backend = \"llm\"",
+                config.target_language
+            );
+        }
+
+        let restore = app_config.whisper.translate;
+        if transcriber.set_translate(restore) {
+            tracing::info!("Meeting translation into English with whisper");
+            return Some(Self::Whisper {
+                transcriber: Arc::clone(transcriber),
+                restore: Some(restore),
+            });
+        }
+
+        // The backend fixed the task when it was created
+        let mut translate_config = app_config.clone();
+        translate_config.whisper.translate = true;
+        match transcribe::create_transcriber(&translate_config) {
+            Ok(transcriber) => {
+                tracing::info!("Meeting translation into English with a second whisper instance");
+                Some(Self::Whisper {
+                    transcriber: Arc::from(transcriber),
+                    restore: None,
+                })
+            }
+            Err(e) => {
+                tracing::warn!("Meeting translation disabled: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Translate `segments`, transcribed from `samples`, a chunk starting
+    /// at `chunk_start_ms` in the meeting
+    pub async fn translate(
+        &self,
+        samples: &[f32],
+        chunk_start_ms: u64,
+        segments: &mut [TranscriptSegment],
+    ) {
+        for segment in segments.iter_mut().filter(|s| !s.text.trim().is_empty()) {
+            let translated = match self {
+                Self::Whisper {
+                    transcriber,
+                    restore,
+                } => {
+                    let audio = segment_audio(samples, chunk_start_ms, segment);
+                    if let Some(restore) = restore {
+                        transcriber.set_translate(true);
+                        let result = transcriber.transcribe(audio);
+                        transcriber.set_translate(*restore);
+                        result.map_err(|e| e.to_string())
+                    } else {
+                        transcriber.transcribe(audio).map_err(|e| e.to_string())
+                    }
+                }
+                Self::Llm(client) => {
+                    let client = Arc::clone(client);
+                    let text = segment.text.clone();
+                    tokio::task::spawn_blocking(move || client.complete(&text, None))
+                        .await
+                        .map_err(|e| e.to_string())
+                        .and_then(|result| result.map_err(|e| e.to_string()))
+                }
+            };
+            match translated {
+                Ok(translation) => segment.translation = keep_translation(segment, translation),
+                Err(e) => tracing::warn!("Failed to translate segment: {}", e),
+            }
+        }
+    }
+}
+
+/// Instructions for the llm backend
+fn translation_prompt(target_language: &str) -> String {
+    format!(
+        "You translate meeting transcripts. Translate the text into {0}, keeping names, \
+         numbers and technical terms. If it is already in {0}, reply with it unchanged. \
+         Reply with only the translation.",
+        target_language
+    )
+}
+
+/// The audio of `segment` within its chunk
+fn segment_audio<'a>(
+    samples: &'a [f32],
+    chunk_start_ms: u64,
+    segment: &TranscriptSegment,
+) -> &'a [f32] {
+    let index = |ms: u64| {
+        let offset = ms.saturating_sub(chunk_start_ms) * SAMPLE_RATE / 1000;
+        (offset as usize).min(samples.len())
+    };
+    let start = index(segment.start_ms);
+    let end = index(segment.end_ms).max(start);
+    &samples[start..end]
+}
+
+/// The translation to store: none when it's empty or just the original
+fn keep_translation(segment: &TranscriptSegment, translation: String) -> Option<String> {
+    let translation = translation.trim();
+    let normalize = |s: &str| {
+        s.chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect::<String>()
+    };
+    if translation.is_empty() || normalize(translation) == normalize(&segment.text) {
+        None
+    } else {
+        Some(translation.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::TranscribeError;
+
+    #[test]
+    fn test_segment_audio() {
+        let samples = vec![0.0; 32_000];
+        let segment = TranscriptSegment::new(0, 30_500, 31_000, "Hallo".to_string(), 1);
+        assert_eq!(segment_audio(&samples, 30_000, &segment).len(), 8_000);
+
+        // Timestamps past the chunk are clamped
+        let segment = TranscriptSegment::new(0, 31_500, 40_000, "Hallo".to_string(), 1);
+        assert_eq!(segment_audio(&samples, 30_000, &segment).len(), 8_000);
+    }
+
+    #[test]
+    fn test_keep_translation() {
+        let segment = TranscriptSegment::new(0, 0, 1000, "Guten Morgen.".to_string(), 0);
+        assert_eq!(
+            keep_translation(&segment, " Good morning.\n".to_string()),
+            Some("Good morning.".to_string())
+        );
+        assert_eq!(keep_translation(&segment, "guten morgen".to_string()), None);
+        assert_eq!(keep_translation(&segment, "  ".to_string()), None);
+    }
+
+    struct Silent;
+
+    impl Transcriber for Silent {
+        fn transcribe(&self, _samples: &[f32]) -> Result<String, TranscribeError> {
+            Ok(String::new())
+        }
+    }
+
+    #[test]
+    fn test_disabled() {
+        let config = MeetingTranslationConfig::default();
+        let app_config = Config::default();
+        let transcriber: Arc<dyn Transcriber> = Arc::new(Silent);
+        assert!(Translator::new(&config, &app_config, &transcriber).is_none());
+
+        // Whisper translation needs the whisper engine
+        let config = MeetingTranslationConfig {
+            backend: "whisper".to_string(),
+            ..Default::default()
+        };
+        let app_config = Config {
+            engine: TranscriptionEngine::Parakeet,
+            ..Default::default()
+        };
+        assert!(Translator::new(&config, &app_config, &transcriber).is_none());
+    }
+}
@@ -205,6 +205,9 @@ fn set_output_mode(mode: OutputMode) -> bool {
         OutputMode::Type => "type",
         OutputMode::Clipboard => "clipboard",
         OutputMode::Paste => "paste",
+        OutputMode::Primary => "primary",
+        OutputMode::Tmux => "tmux",
+        OutputMode::Webhook => "webhook",
         OutputMode::File => "file",
     };
 
@@ -4,7 +4,9 @@
 //! - LRU caching of loaded models (when gpu_isolation = false)
 //! - On-demand loading with automatic eviction
 //! - Fresh subprocess per model (when gpu_isolation = true)
+//! - Warm subprocess pool per model (when worker_idle_ttl_secs > 0)
 //! - Remote backend model selection
+//! - One managed whisper-server per cached model (mode = "server")
 
 use crate::config::{WhisperConfig, WhisperMode};
 use crate::error::TranscribeError;
@@ -33,6 +35,9 @@ pub struct ModelManager {
     max_loaded: usize,
     /// Timeout before evicting idle models
     cold_timeout: Duration,
+    /// Healthy remote endpoint index, shared by all remote transcribers so
+    /// a failover is remembered across recordings
+    remote_health: Arc<transcribe::remote::EndpointHealth>,
 }
 
 impl ModelManager {
@@ -44,10 +49,12 @@ impl ModelManager {
             loaded_models: HashMap::new(),
             max_loaded: config.max_loaded_models,
             cold_timeout: Duration::from_secs(config.cold_model_timeout_secs),
+            remote_health: Arc::default(),
         }
     }
 
-    /// Check if a model is available (configured as primary, secondary, or in available_models)
+    /// Check if a model is available (configured as primary, secondary, in
+    /// available_models, or registered in custom_models)
     pub fn is_model_available(&self, model: &str) -> bool {
         if model == self.config.model {
             return true;
@@ -58,6 +65,7 @@ impl ModelManager {
             }
         }
         self.config.available_models.contains(&model.to_string())
+            || self.config.custom_models.contains_key(model)
     }
 
     /// Get a transcriber for the specified model
@@ -93,8 +101,19 @@ impl ModelManager {
             return self.create_cli_transcriber(&model_name);
         }
 
-        // For GPU isolation, always create fresh subprocess
+        // Managed servers are cached like in-process models; the server
+        // process already isolates the GPU
+        if self.config.effective_mode() == WhisperMode::Server {
+            return self.get_or_load_cached(&model_name);
+        }
+
+        // For GPU isolation, create a fresh subprocess unless the warm pool
+        // is enabled, in which case the pooled transcriber is reused so its
+        // pre-spawned worker survives across recordings
         if self.config.gpu_isolation {
+            if self.worker_pool_enabled() {
+                return self.get_or_create_pooled(&model_name);
+            }
             return self.create_subprocess_transcriber(&model_name);
         }
 
@@ -110,7 +129,8 @@ impl ModelManager {
         let mut config = self.config.clone();
         // Override remote_model with requested model
         config.remote_model = Some(model.to_string());
-        let transcriber = transcribe::remote::RemoteTranscriber::new(&config)?;
+        let transcriber = transcribe::remote::RemoteTranscriber::new(&config)?
+            .with_shared_health(Arc::clone(&self.remote_health));
         Ok(Arc::new(transcriber))
     }
 
@@ -135,6 +155,42 @@ impl ModelManager {
         Ok(Arc::new(transcriber))
     }
 
+    /// Whether gpu_isolation workers are kept warm between recordings
+    fn worker_pool_enabled(&self) -> bool {
+        self.config.worker_idle_ttl_secs > 0
+    }
+
+    /// Get the long-lived pooled subprocess transcriber for a model
+    fn get_or_create_pooled(
+        &mut self,
+        model: &str,
+    ) -> Result<Arc<dyn Transcriber>, TranscribeError> {
+        if let Some(loaded) = self.loaded_models.get_mut(model) {
+            loaded.last_used = Instant::now();
+            return Ok(Arc::clone(&loaded.transcriber));
+        }
+
+        if self.loaded_models.len() >= self.max_loaded {
+            self.evict_lru();
+        }
+
+        tracing::info!(
+            "Creating warm worker pool for model '{}' (idle TTL {}s)",
+            model,
+            self.config.worker_idle_ttl_secs
+        );
+        let transcriber = self.create_subprocess_transcriber(model)?;
+        self.loaded_models.insert(
+            model.to_string(),
+            LoadedModel {
+                transcriber: Arc::clone(&transcriber),
+                last_used: Instant::now(),
+                is_primary: model == self.config.model,
+            },
+        );
+        Ok(transcriber)
+    }
+
     /// Get transcriber from cache or load on demand (non-isolated mode)
     fn get_or_load_cached(&mut self, model: &str) -> Result<Arc<dyn Transcriber>, TranscribeError> {
         // Check if already loaded
@@ -154,13 +210,18 @@ impl ModelManager {
         let mut config = self.config.clone();
         config.model = model.to_string();
 
-        let transcriber = transcribe::whisper::WhisperTranscriber::new(&config)?;
+        let transcriber: Arc<dyn Transcriber> =
+            if self.config.effective_mode() == WhisperMode::Server {
+                Arc::new(transcribe::server::ServerTranscriber::new(&config)?)
+            } else {
+                Arc::new(transcribe::whisper::WhisperTranscriber::new(&config)?)
+            };
         let is_primary = model == self.config.model;
 
         self.loaded_models.insert(
             model.to_string(),
             LoadedModel {
-                transcriber: Arc::new(transcriber),
+                transcriber,
                 last_used: Instant::now(),
                 is_primary,
             },
@@ -171,6 +232,19 @@ impl ModelManager {
         ))
     }
 
+    /// Switch translate-to-English for future transcriptions. Cached models
+    /// that cannot switch in place are dropped and reloaded on next use.
+    pub fn set_translate(&mut self, translate: bool) {
+        self.config.translate = translate;
+        self.loaded_models.retain(|model, loaded| {
+            let switched = loaded.transcriber.set_translate(translate);
+            if !switched {
+                tracing::info!("Reloading model '{}' on next use to apply translate", model);
+            }
+            switched
+        });
+    }
+
     /// Evict the least recently used non-primary model
     fn evict_lru(&mut self) {
         // Find LRU non-primary model
@@ -270,6 +344,14 @@ impl ModelManager {
 
         // For GPU isolation, spawn subprocess early
         if self.config.gpu_isolation && self.config.effective_mode() == WhisperMode::Local {
+            if self.worker_pool_enabled() {
+                // prepare() is a no-op when the pool already holds a warm worker
+                let transcriber = self.get_or_create_pooled(&model_name)?;
+                let handle = tokio::task::spawn_blocking(move || {
+                    transcriber.prepare();
+                });
+                return Ok(Some(handle));
+            }
             let transcriber = self.create_subprocess_transcriber(&model_name)?;
             // Store the Arc immediately so get_prepared_transcriber can retrieve it.
             // The worker spawn happens on a blocking thread; the prepared_worker
@@ -359,6 +441,51 @@ mod tests {
         assert!(!manager.is_model_available("tiny.en"));
     }
 
+    #[test]
+    fn test_pooled_transcriber_is_reused() {
+        let config = WhisperConfig {
+            gpu_isolation: true,
+            worker_idle_ttl_secs: 60,
+            ..test_config()
+        };
+        let mut manager = ModelManager::new(&config, None);
+
+        let first = manager.get_transcriber(None).unwrap();
+        let second = manager.get_transcriber(None).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(manager.loaded_model_names(), vec!["base.en"]);
+    }
+
+    #[test]
+    fn test_unpooled_isolation_creates_fresh_transcriber() {
+        let config = WhisperConfig {
+            gpu_isolation: true,
+            ..test_config()
+        };
+        let mut manager = ModelManager::new(&config, None);
+
+        let first = manager.get_transcriber(None).unwrap();
+        let second = manager.get_transcriber(None).unwrap();
+        assert!(!Arc::ptr_eq(&first, &second));
+        assert!(manager.loaded_model_names().is_empty());
+    }
+
+    #[test]
+    fn test_set_translate_drops_models_that_cannot_switch() {
+        let config = WhisperConfig {
+            gpu_isolation: true,
+            worker_idle_ttl_secs: 60,
+            ..test_config()
+        };
+        let mut manager = ModelManager::new(&config, None);
+        manager.get_transcriber(None).unwrap();
+
+        // Pooled workers are spawned with the flag, so they are replaced
+        manager.set_translate(true);
+        assert!(manager.config.translate);
+        assert!(manager.loaded_model_names().is_empty());
+    }
+
     #[test]
     fn test_new_manager() {
         let config = test_config();
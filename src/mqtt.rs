@@ -0,0 +1,217 @@
+//! MQTT state and transcription publisher
+//!
+//! Publishes to a broker so Home Assistant (or any MQTT automation) can
+//! react to dictation at the desktop:
+//!
+//! - `<topic_prefix>/state`: "idle", "recording", "streaming",
+//!   "transcribing", retained. The broker replaces it with "offline" (the
+//!   last will) when the daemon stops or drops off the network.
+//! - `<topic_prefix>/transcription`: each dictation as
+//!   `{"text", "profile", "timestamp"}`, the same JSON the webhook output
+//!   posts.
+//!
+//! The connection runs in a background task that reconnects on its own;
+//! messages published while the broker is unreachable are dropped rather
+//! than delaying output.
+//!
+//! Requires the `mqtt` feature.
+
+use crate::config::MqttConfig;
+
+/// Retained state payload while the daemon isn't running
+#[cfg(feature = "mqtt")]
+const OFFLINE: &str = "offline";
+
+/// Connected MQTT publisher
+pub struct MqttPublisher {
+    #[cfg(feature = "mqtt")]
+    client: rumqttc::AsyncClient,
+    #[cfg(feature = "mqtt")]
+    event_loop: tokio::task::JoinHandle<()>,
+    /// Last published state, re-sent after a reconnect because the broker
+    /// has published the last will in the meantime
+    #[cfg(feature = "mqtt")]
+    state: std::sync::Arc<std::sync::Mutex<String>>,
+    config: MqttConfig,
+}
+
+impl MqttPublisher {
+    /// Start connecting to the broker. Must be called within the tokio
+    /// runtime; the connection itself is made in the background.
+    #[cfg(feature = "mqtt")]
+    pub fn start(config: &MqttConfig) -> Result<Self, String> {
+        use rumqttc::{
+            AsyncClient, Event, LastWill, MqttOptions, Outgoing, Packet, TlsConfiguration,
+            Transport,
+        };
+        use std::sync::{Arc, Mutex};
+        use std::time::Duration;
+
+        let client_id = config
+            .client_id
+            .clone()
+            .unwrap_or_else(|| format!("voxtype-{}", std::process::id()));
+        let mut options = MqttOptions::new(client_id, &config.host, config.port());
+        options.set_keep_alive(Duration::from_secs(30));
+        options.set_last_will(LastWill::new(
+            config.topic("state"),
+            OFFLINE,
+            qos(config.qos),
+            true,
+        ));
+        if let Some(ref username) = config.username {
+            let password = config
+                .password
+                .clone()
+                .or_else(|| std::env::var("VOXTYPE_MQTT_PASSWORD").ok())
+                .unwrap_or_default();
+            options.set_credentials(username, password);
+        }
+        if config.tls {
+            let transport = match config.ca_file {
+                Some(ref path) => {
+                    let ca = std::fs::read(path)
+                        .map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+                    Transport::Tls(TlsConfiguration::Simple {
+                        ca,
+                        alpn: None,
+                        client_auth: None,
+                    })
+                }
+                None => Transport::tls_with_default_config(),
+            };
+            options.set_transport(transport);
+        }
+
+        let (client, mut event_loop) = AsyncClient::new(options, 16);
+        let state = Arc::new(Mutex::new(String::from("idle")));
+
+        let broker = format!("{}:{}", config.host, config.port());
+        let resend = config
+            .publish_state
+            .then(|| (client.clone(), Arc::clone(&state), config.clone()));
+        let event_loop = tokio::spawn(async move {
+            loop {
+                match event_loop.poll().await {
+                    Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                        tracing::info!("Connected to MQTT broker {}", broker);
+                        if let Some((ref client, ref state, ref config)) = resend {
+                            let state = state.lock().unwrap().clone();
+                            let _ = client.try_publish(
+                                config.topic("state"),
+                                qos(config.qos),
+                                true,
+                                state,
+                            );
+                        }
+                    }
+                    Ok(Event::Outgoing(Outgoing::Disconnect)) => break,
+                    Ok(_) => {}
+                    Err(e) => {
+                        tracing::warn!("MQTT broker {}: {}, retrying in 5s", broker, e);
+                        tokio::time::sleep(Duration::from_secs(5)).await;
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            client,
+            event_loop,
+            state,
+            config: config.clone(),
+        })
+    }
+
+    #[cfg(not(feature = "mqtt"))]
+    pub fn start(_config: &MqttConfig) -> Result<Self, String> {
+        Err("voxtype was built without the mqtt feature".to_string())
+    }
+
+    /// Publish a daemon state change (retained)
+    pub fn publish_state(&self, state: &str) {
+        if !self.config.publish_state {
+            return;
+        }
+        #[cfg(feature = "mqtt")]
+        {
+            *self.state.lock().unwrap() = state.to_string();
+            self.publish("state", true, state.as_bytes().to_vec());
+        }
+        #[cfg(not(feature = "mqtt"))]
+        let _ = state;
+    }
+
+    /// Publish a finished dictation
+    pub fn publish_transcription(&self, text: &str, profile: Option<&str>) {
+        if !self.config.publish_transcriptions {
+            return;
+        }
+        #[cfg(feature = "mqtt")]
+        {
+            let payload =
+                crate::output::webhook::payload(text, profile, &chrono::Local::now().to_rfc3339());
+            self.publish("transcription", false, payload.to_string().into_bytes());
+        }
+        #[cfg(not(feature = "mqtt"))]
+        let _ = (text, profile);
+    }
+
+    #[cfg(feature = "mqtt")]
+    fn publish(&self, leaf: &str, retain: bool, payload: Vec<u8>) {
+        let topic = self.config.topic(leaf);
+        if let Err(e) = self
+            .client
+            .try_publish(&topic, qos(self.config.qos), retain, payload)
+        {
+            tracing::debug!("MQTT: dropped message to {}: {}", topic, e);
+        }
+    }
+
+    /// Mark the daemon offline and disconnect, waiting briefly for the
+    /// broker to receive both
+    pub async fn shutdown(self) {
+        #[cfg(feature = "mqtt")]
+        {
+            if self.config.publish_state {
+                self.publish("state", true, OFFLINE.as_bytes().to_vec());
+            }
+            let _ = self.client.try_disconnect();
+            let _ = tokio::time::timeout(std::time::Duration::from_secs(1), self.event_loop).await;
+        }
+    }
+}
+
+/// QoS for the configured level; anything other than 0 or 2 is 1
+#[cfg(feature = "mqtt")]
+fn qos(level: u8) -> rumqttc::QoS {
+    match level {
+        0 => rumqttc::QoS::AtMostOnce,
+        2 => rumqttc::QoS::ExactlyOnce,
+        _ => rumqttc::QoS::AtLeastOnce,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "mqtt"))]
+    #[test]
+    fn test_start_requires_feature() {
+        let config = MqttConfig {
+            enabled: true,
+            ..MqttConfig::default()
+        };
+        assert!(MqttPublisher::start(&config).is_err());
+    }
+
+    #[cfg(feature = "mqtt")]
+    #[test]
+    fn test_qos() {
+        assert_eq!(qos(0), rumqttc::QoS::AtMostOnce);
+        assert_eq!(qos(1), rumqttc::QoS::AtLeastOnce);
+        assert_eq!(qos(2), rumqttc::QoS::ExactlyOnce);
+        assert_eq!(qos(7), rumqttc::QoS::AtLeastOnce);
+    }
+}
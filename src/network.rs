@@ -0,0 +1,197 @@
+//! Outbound HTTP proxy selection.
+//!
+//! `[network] proxy` (or the usual HTTPS_PROXY/HTTP_PROXY/ALL_PROXY env
+//! vars) is applied to every HTTP client voxtype builds: curl for model
+//! downloads, ureq for remote Whisper, meeting summaries and the update
+//! check, and reqwest for Soniox. Loopback hosts, `[network] no_proxy` and
+//! NO_PROXY are reached directly, so a local Ollama or whisper.cpp server
+//! keeps working behind a corporate proxy. Like `[models]` download
+//! settings, the config is applied once in `app::run`.
+//!
+//! WebSocket connections (Soniox streaming, `ws://` remote endpoints) do
+//! not go through the proxy.
+
+use crate::config::NetworkConfig;
+use std::sync::OnceLock;
+
+static SETTINGS: OnceLock<ProxySettings> = OnceLock::new();
+
+/// Resolved proxy settings
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProxySettings {
+    /// Explicit proxy from `[network] proxy`
+    pub proxy: Option<String>,
+    /// Bypass list from `[network] no_proxy` and NO_PROXY
+    pub no_proxy: Vec<String>,
+}
+
+impl ProxySettings {
+    /// Settings from `[network]` plus the NO_PROXY env var
+    pub fn from_config(config: &NetworkConfig) -> Self {
+        let mut no_proxy = config.no_proxy.clone();
+        if let Some(env) = env_var(&["NO_PROXY", "no_proxy"]) {
+            no_proxy.extend(env.split(',').map(|s| s.trim().to_string()));
+        }
+        no_proxy.retain(|s| !s.is_empty());
+        Self {
+            proxy: config.proxy.clone().filter(|p| !p.trim().is_empty()),
+            no_proxy,
+        }
+    }
+
+    /// Proxy to use for `url`: the configured proxy, else the scheme's proxy
+    /// env var. None when the host bypasses the proxy.
+    pub fn proxy_for(&self, url: &str) -> Option<String> {
+        let host = host_of(url);
+        if is_loopback(host) || self.bypasses(host) {
+            return None;
+        }
+        self.proxy.clone().or_else(|| {
+            let scheme_vars: &[&str] = if url.starts_with("https://") || url.starts_with("wss://") {
+                &["HTTPS_PROXY", "https_proxy"]
+            } else {
+                &["HTTP_PROXY", "http_proxy"]
+            };
+            env_var(scheme_vars).or_else(|| env_var(&["ALL_PROXY", "all_proxy"]))
+        })
+    }
+
+    /// Whether `host` matches the bypass list
+    fn bypasses(&self, host: &str) -> bool {
+        self.no_proxy.iter().any(|entry| {
+            let entry = entry.trim_start_matches("*.").trim_start_matches('.');
+            entry == "*"
+                || host.eq_ignore_ascii_case(entry)
+                || host
+                    .to_ascii_lowercase()
+                    .ends_with(&format!(".{}", entry.to_ascii_lowercase()))
+        })
+    }
+}
+
+fn env_var(names: &[&str]) -> Option<String> {
+    names
+        .iter()
+        .find_map(|name| std::env::var(name).ok())
+        .filter(|v| !v.trim().is_empty())
+}
+
+/// Host part of a URL, without port, credentials or IPv6 brackets
+fn host_of(url: &str) -> &str {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or("");
+    let host_port = authority.rsplit_once('@').map_or(authority, |(_, h)| h);
+    if let Some(v6) = host_port.strip_prefix('[') {
+        return v6.split(']').next().unwrap_or("");
+    }
+    host_port.split(':').next().unwrap_or("")
+}
+
+fn is_loopback(host: &str) -> bool {
+    host.eq_ignore_ascii_case("localhost")
+        || host
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
+/// Apply `[network]` settings for this process
+pub fn configure(config: &NetworkConfig) {
+    let _ = SETTINGS.set(ProxySettings::from_config(config));
+}
+
+/// Proxy settings for this process (env vars only if `configure` was never
+/// called)
+pub fn settings() -> &'static ProxySettings {
+    SETTINGS.get_or_init(|| ProxySettings::from_config(&NetworkConfig::default()))
+}
+
+/// Proxy to use for `url`, if any
+pub fn proxy_for(url: &str) -> Option<String> {
+    settings().proxy_for(url)
+}
+
+/// ureq agent builder for requests to `url`, with the proxy applied.
+/// ureq supports `http://` proxies; anything else is logged and ignored.
+pub fn agent_builder(url: &str) -> ureq::AgentBuilder {
+    let builder = ureq::AgentBuilder::new();
+    match proxy_for(url) {
+        Some(proxy) => match ureq::Proxy::new(&proxy) {
+            Ok(p) => builder.proxy(p),
+            Err(e) => {
+                tracing::warn!("Ignoring unsupported proxy {}: {}", proxy, e);
+                builder
+            }
+        },
+        None => builder,
+    }
+}
+
+/// Explicit `[network] proxy` for reqwest clients. reqwest reads the proxy
+/// env vars itself; this only adds the config value and its bypass list.
+#[cfg(feature = "soniox")]
+pub fn reqwest_proxy() -> Option<reqwest::Proxy> {
+    let settings = settings();
+    let proxy = settings.proxy.as_deref()?;
+    let mut no_proxy = settings.no_proxy.clone();
+    no_proxy.extend(["localhost", "127.0.0.1", "::1"].map(String::from));
+    match reqwest::Proxy::all(proxy) {
+        Ok(p) => Some(p.no_proxy(reqwest::NoProxy::from_string(&no_proxy.join(",")))),
+        Err(e) => {
+            tracing::warn!("Ignoring invalid proxy {}: {}", proxy, e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> ProxySettings {
+        ProxySettings {
+            proxy: Some("http://proxy.corp.example:3128".to_string()),
+            no_proxy: vec!["internal.example".to_string(), ".lan".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_host_of() {
+        assert_eq!(host_of("https://api.openai.com/v1/audio"), "api.openai.com");
+        assert_eq!(host_of("http://user:pw@10.0.0.5:8080/x"), "10.0.0.5");
+        assert_eq!(host_of("http://[::1]:11434/api/tags"), "::1");
+        assert_eq!(host_of("localhost:8080"), "localhost");
+    }
+
+    #[test]
+    fn test_configured_proxy_applies_to_remote_hosts() {
+        assert_eq!(
+            settings().proxy_for("https://huggingface.co/x").as_deref(),
+            Some("http://proxy.corp.example:3128")
+        );
+    }
+
+    #[test]
+    fn test_loopback_and_no_proxy_bypass() {
+        let settings = settings();
+        for url in [
+            "http://localhost:11434/api/generate",
+            "http://127.0.0.1:8080/inference",
+            "http://[::1]:8080/",
+            "http://whisper.internal.example/v1",
+            "http://internal.example/v1",
+            "http://gpu-box.lan:8080/",
+        ] {
+            assert!(settings.proxy_for(url).is_none(), "{} was proxied", url);
+        }
+        assert!(settings.proxy_for("https://notinternal.example/").is_some());
+    }
+
+    #[test]
+    fn test_wildcard_disables_proxy() {
+        let settings = ProxySettings {
+            no_proxy: vec!["*".to_string()],
+            ..settings()
+        };
+        assert!(settings.proxy_for("https://api.openai.com/").is_none());
+    }
+}
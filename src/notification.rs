@@ -92,7 +92,9 @@ fn send_macos_native(title: &str, body: &str, engine: Option<TranscriptionEngine
         | TranscriptionEngine::Dolphin
         | TranscriptionEngine::Omnilingual
         | TranscriptionEngine::Cohere
-        | TranscriptionEngine::Soniox => None,
+        | TranscriptionEngine::Soniox
+        | TranscriptionEngine::Vosk
+        | TranscriptionEngine::Zipformer => None,
     });
 
     for notifier in notifier_paths {
@@ -147,6 +149,47 @@ fn send_macos_osascript_sync(title: &str, body: &str) {
         .spawn();
 }
 
+/// Send a notification with a single action button and wait for the user.
+///
+/// Returns `true` if the action was clicked, `false` if the notification was
+/// dismissed, timed out, or the platform has no action support. Unlike
+/// [`send`], the notification is not transient so it stays in the history
+/// until acted on. Callers should run this on a spawned task since it
+/// blocks until the notification closes.
+pub async fn send_with_action(title: &str, body: &str, action_label: &str) -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        let action = format!("--action=default={}", action_label);
+        let output = Command::new("notify-send")
+            .args([
+                "--app-name=Voxtype",
+                "--expire-time=15000",
+                "--wait",
+                &action,
+                title,
+                body,
+            ])
+            .stderr(Stdio::null())
+            .output()
+            .await;
+
+        match output {
+            Ok(out) => String::from_utf8_lossy(&out.stdout).trim() == "default",
+            Err(e) => {
+                tracing::debug!("Failed to send action notification: {}", e);
+                false
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        send(title, body).await;
+        let _ = action_label;
+        false
+    }
+}
+
 /// Send a notification synchronously (blocking).
 ///
 /// Used in non-async contexts like early startup warnings.
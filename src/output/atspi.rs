@@ -0,0 +1,300 @@
+//! AT-SPI text insertion
+//!
+//! Inserts text straight into the focused widget through the AT-SPI2
+//! accessibility bus (the `EditableText` interface) instead of simulating
+//! keystrokes. Insertion is exact regardless of keyboard layout, held
+//! modifiers or input method, and screen readers announce the change like
+//! any other edit.
+//!
+//! Works in GTK and Qt applications, browsers and most Electron apps with
+//! accessibility enabled. When the focused widget isn't an editable text
+//! field (terminals, games, apps without accessibility support), this
+//! driver fails and the fallback chain moves on to keystroke simulation.
+//!
+//! Requires:
+//! - The at-spi2 accessibility bus (at-spi2-core, running on most desktops)
+//! - Applications exposing accessibility (Qt apps may need
+//!   `QT_LINUX_ACCESSIBILITY_ALWAYS_ON=1`)
+
+use super::TextOutput;
+use crate::error::OutputError;
+use serde::de::DeserializeOwned;
+use std::time::Duration;
+use zbus::zvariant::{DynamicType, OwnedObjectPath, OwnedValue, Type};
+use zbus::Connection;
+
+const REGISTRY: &str = "org.a11y.atspi.Registry";
+const ROOT_PATH: &str = "/org/a11y/atspi/accessible/root";
+const ACCESSIBLE: &str = "org.a11y.atspi.Accessible";
+const TEXT: &str = "org.a11y.atspi.Text";
+const EDITABLE_TEXT: &str = "org.a11y.atspi.EditableText";
+
+// AtspiStateType values
+const STATE_ACTIVE: u32 = 1;
+const STATE_EDITABLE: u32 = 7;
+const STATE_FOCUSED: u32 = 12;
+const STATE_SHOWING: u32 = 25;
+const STATE_MANAGES_DESCENDANTS: u32 = 31;
+
+/// Upper bound on accessibles visited looking for the focused widget
+const MAX_NODES: usize = 2000;
+
+/// Give up on the accessibility tree after this long; slow or hung apps
+/// shouldn't hold up the transcription
+const INSERT_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// An accessible object: bus name and object path
+type ObjectRef = (String, OwnedObjectPath);
+
+/// AT-SPI state set, a bitfield spread over 32-bit words
+struct States(Vec<u32>);
+
+impl States {
+    fn has(&self, state: u32) -> bool {
+        self.0
+            .get((state / 32) as usize)
+            .is_some_and(|word| word & (1 << (state % 32)) != 0)
+    }
+}
+
+/// Connect to the accessibility bus, which is separate from the session bus
+async fn a11y_connection() -> zbus::Result<Connection> {
+    let address = match std::env::var("AT_SPI_BUS_ADDRESS") {
+        Ok(address) if !address.is_empty() => address,
+        _ => {
+            let session = Connection::session().await?;
+            let reply = session
+                .call_method(
+                    Some("org.a11y.Bus"),
+                    "/org/a11y/bus",
+                    Some("org.a11y.Bus"),
+                    "GetAddress",
+                    &(),
+                )
+                .await?;
+            reply.body().deserialize::<String>()?
+        }
+    };
+    zbus::connection::Builder::address(address.as_str())?
+        .build()
+        .await
+}
+
+async fn call<B, R>(
+    conn: &Connection,
+    object: &ObjectRef,
+    interface: &str,
+    method: &str,
+    body: &B,
+) -> zbus::Result<R>
+where
+    B: serde::Serialize + DynamicType,
+    R: DeserializeOwned + Type,
+{
+    let reply = conn
+        .call_method(
+            Some(object.0.as_str()),
+            object.1.as_str(),
+            Some(interface),
+            method,
+            body,
+        )
+        .await?;
+    reply.body().deserialize()
+}
+
+async fn children(conn: &Connection, object: &ObjectRef) -> zbus::Result<Vec<ObjectRef>> {
+    call(conn, object, ACCESSIBLE, "GetChildren", &()).await
+}
+
+async fn states(conn: &Connection, object: &ObjectRef) -> zbus::Result<States> {
+    call(conn, object, ACCESSIBLE, "GetState", &())
+        .await
+        .map(States)
+}
+
+/// Find the focused widget in the active windows, if it is editable
+async fn focused_editable(conn: &Connection) -> Result<ObjectRef, String> {
+    let root = (
+        REGISTRY.to_string(),
+        OwnedObjectPath::try_from(ROOT_PATH).map_err(|e| e.to_string())?,
+    );
+    let apps = children(conn, &root)
+        .await
+        .map_err(|e| format!("cannot list applications: {}", e))?;
+
+    // Only an active window can hold keyboard focus
+    let mut stack = Vec::new();
+    for app in &apps {
+        for window in children(conn, app).await.unwrap_or_default() {
+            if states(conn, &window)
+                .await
+                .is_ok_and(|s| s.has(STATE_ACTIVE))
+            {
+                stack.push(window);
+            }
+        }
+    }
+    if stack.is_empty() {
+        return Err("no active window exposes accessibility".to_string());
+    }
+
+    let mut visited = 0;
+    while let Some(node) = stack.pop() {
+        visited += 1;
+        if visited > MAX_NODES {
+            return Err(format!(
+                "no focused widget in the first {} accessibles",
+                MAX_NODES
+            ));
+        }
+        let Ok(node_states) = states(conn, &node).await else {
+            continue;
+        };
+        if node_states.has(STATE_FOCUSED) {
+            if node_states.has(STATE_EDITABLE) {
+                return Ok(node);
+            }
+            return Err("focused widget is not editable text".to_string());
+        }
+        // Hidden subtrees can't contain the focus, and lists that manage
+        // their descendants can hold thousands of rows
+        if !node_states.has(STATE_SHOWING) || node_states.has(STATE_MANAGES_DESCENDANTS) {
+            continue;
+        }
+        if let Ok(mut nodes) = children(conn, &node).await {
+            // Depth-first in document order
+            nodes.reverse();
+            stack.extend(nodes);
+        }
+    }
+    Err("no focused widget found".to_string())
+}
+
+/// Insert `text` at the caret of the focused widget and move the caret
+/// past it
+async fn insert_at_caret(text: &str) -> Result<(), String> {
+    let conn = a11y_connection()
+        .await
+        .map_err(|e| format!("accessibility bus unavailable: {}", e))?;
+    let target = focused_editable(&conn).await?;
+
+    let caret: OwnedValue = call(
+        &conn,
+        &target,
+        "org.freedesktop.DBus.Properties",
+        "Get",
+        &(TEXT, "CaretOffset"),
+    )
+    .await
+    .map_err(|e| format!("cannot read caret: {}", e))?;
+    let caret = i32::try_from(caret).map_err(|e| format!("cannot read caret: {}", e))?;
+    if caret < 0 {
+        return Err("focused widget has no caret".to_string());
+    }
+
+    // InsertText takes the length in bytes, the caret offset in characters
+    let inserted: bool = call(
+        &conn,
+        &target,
+        EDITABLE_TEXT,
+        "InsertText",
+        &(caret, text, text.len() as i32),
+    )
+    .await
+    .map_err(|e| format!("InsertText failed: {}", e))?;
+    if !inserted {
+        return Err("widget rejected the text".to_string());
+    }
+
+    // Toolkits differ on whether the caret follows inserted text
+    let caret_after = caret + text.chars().count() as i32;
+    if let Err(e) = call::<_, bool>(&conn, &target, TEXT, "SetCaretOffset", &(caret_after,)).await {
+        tracing::debug!("atspi: could not move caret: {}", e);
+    }
+    Ok(())
+}
+
+/// AT-SPI based text output
+pub struct AtspiOutput {
+    /// Delay before inserting (ms)
+    pre_type_delay_ms: u32,
+    /// Whether Enter should follow the text; AT-SPI can't press keys
+    auto_submit: bool,
+    /// Text to append after transcription
+    append_text: Option<String>,
+}
+
+impl AtspiOutput {
+    /// Create a new AT-SPI output
+    pub fn new(pre_type_delay_ms: u32, auto_submit: bool, append_text: Option<String>) -> Self {
+        Self {
+            pre_type_delay_ms,
+            auto_submit,
+            append_text,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TextOutput for AtspiOutput {
+    async fn output(&self, text: &str) -> Result<(), OutputError> {
+        if text.is_empty() {
+            return Ok(());
+        }
+
+        if self.pre_type_delay_ms > 0 {
+            tracing::debug!(
+                "atspi: sleeping {}ms before inserting",
+                self.pre_type_delay_ms
+            );
+            tokio::time::sleep(Duration::from_millis(self.pre_type_delay_ms as u64)).await;
+        }
+
+        let mut full_text = text.to_string();
+        if let Some(ref append) = self.append_text {
+            full_text.push_str(append);
+        }
+
+        tracing::debug!(
+            "atspi: inserting \"{}\"",
+            full_text.chars().take(20).collect::<String>()
+        );
+        match tokio::time::timeout(INSERT_TIMEOUT, insert_at_caret(&full_text)).await {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(OutputError::InjectionFailed(format!("atspi: {}", e))),
+            Err(_) => Err(OutputError::InjectionFailed(
+                "atspi: accessibility tree did not respond in time".to_string(),
+            )),
+        }
+    }
+
+    async fn is_available(&self) -> bool {
+        // Submitting needs a real Enter key press
+        if self.auto_submit {
+            tracing::debug!("atspi: auto_submit needs a keystroke driver");
+            return false;
+        }
+        true
+    }
+
+    fn name(&self) -> &'static str {
+        "atspi"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_bits() {
+        // FOCUSED (12) and EDITABLE (7) in the first word, 32 in the second
+        let states = States(vec![(1 << STATE_FOCUSED) | (1 << STATE_EDITABLE), 1]);
+        assert!(states.has(STATE_FOCUSED));
+        assert!(states.has(STATE_EDITABLE));
+        assert!(!states.has(STATE_ACTIVE));
+        assert!(states.has(32));
+        assert!(!states.has(64));
+    }
+}
@@ -0,0 +1,169 @@
+//! Driver selection for the focused application
+//!
+//! The default driver order suits native Wayland apps, but keystrokes have
+//! to reach the app that has focus, and that depends on what it is:
+//!
+//! - Native Wayland: wtype and eitype (the configured order, unchanged)
+//! - XWayland: on some compositors wtype's virtual keyboard doesn't reach
+//!   X11 clients, so xdotool (through XWayland's XTEST) and the
+//!   kernel-level drivers go first
+//! - X11 session: xdotool, then the kernel-level drivers
+//! - Text console: only the kernel-level drivers (dotool, ydotool, uinput)
+//!   reach it
+//!
+//! With `auto_driver = true` the daemon works out which of these has focus
+//! when the text is ready and moves the matching drivers to the front of the
+//! configured order. Drivers outside the configured order are never added.
+//! Whether a Wayland window is an XWayland client comes from the compositor
+//! (Hyprland and Sway); elsewhere it is treated as native.
+
+use super::session::{self, DisplaySession};
+use crate::config::OutputDriver;
+
+/// Where the focused application takes its input from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusedApp {
+    /// Native Wayland client
+    Wayland,
+    /// X11 client under XWayland
+    Xwayland,
+    /// X11 session
+    X11,
+    /// Text console, no display server
+    Tty,
+}
+
+impl std::fmt::Display for FocusedApp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FocusedApp::Wayland => write!(f, "native Wayland"),
+            FocusedApp::Xwayland => write!(f, "XWayland"),
+            FocusedApp::X11 => write!(f, "X11"),
+            FocusedApp::Tty => write!(f, "console"),
+        }
+    }
+}
+
+/// Work out what kind of application has focus
+pub async fn detect() -> FocusedApp {
+    if is_tty_with_env(|name| std::env::var(name).ok()) {
+        return FocusedApp::Tty;
+    }
+    if session::detect() == DisplaySession::X11 {
+        return FocusedApp::X11;
+    }
+
+    let Some(backend) = crate::focus::detect_backend() else {
+        return FocusedApp::Wayland;
+    };
+    match backend.focused_is_xwayland().await {
+        Ok(true) => FocusedApp::Xwayland,
+        Ok(false) => FocusedApp::Wayland,
+        Err(e) => {
+            tracing::debug!("auto_driver: assuming a native Wayland window: {}", e);
+            FocusedApp::Wayland
+        }
+    }
+}
+
+/// A console session: `XDG_SESSION_TYPE=tty` with no display to talk to
+fn is_tty_with_env<F>(get: F) -> bool
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let set = |name: &str| get(name).is_some_and(|value| !value.is_empty());
+    get("XDG_SESSION_TYPE").as_deref() == Some("tty") && !set("WAYLAND_DISPLAY") && !set("DISPLAY")
+}
+
+/// Drivers to try first for `app`, best first
+fn preferred(app: FocusedApp) -> &'static [OutputDriver] {
+    match app {
+        FocusedApp::Wayland => &[],
+        FocusedApp::Xwayland | FocusedApp::X11 => &[
+            OutputDriver::Xdotool,
+            OutputDriver::Dotool,
+            OutputDriver::Ydotool,
+            OutputDriver::Uinput,
+        ],
+        FocusedApp::Tty => &[
+            OutputDriver::Dotool,
+            OutputDriver::Ydotool,
+            OutputDriver::Uinput,
+        ],
+    }
+}
+
+/// `order` with the drivers suited to `app` moved to the front. The rest
+/// keep their configured order.
+pub fn driver_order(app: FocusedApp, order: &[OutputDriver]) -> Vec<OutputDriver> {
+    let preferred = preferred(app);
+    let mut drivers: Vec<OutputDriver> = preferred
+        .iter()
+        .filter(|d| order.contains(d))
+        .copied()
+        .collect();
+    drivers.extend(order.iter().filter(|d| !preferred.contains(d)));
+    drivers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let map: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| map.get(name).cloned()
+    }
+
+    #[test]
+    fn test_is_tty() {
+        assert!(is_tty_with_env(env(&[("XDG_SESSION_TYPE", "tty")])));
+        // Started from a console, but a compositor is running
+        assert!(!is_tty_with_env(env(&[
+            ("XDG_SESSION_TYPE", "tty"),
+            ("WAYLAND_DISPLAY", "wayland-1"),
+        ])));
+        assert!(!is_tty_with_env(env(&[("XDG_SESSION_TYPE", "wayland")])));
+    }
+
+    #[test]
+    fn test_driver_order() {
+        let order = [
+            OutputDriver::InputMethod,
+            OutputDriver::Wtype,
+            OutputDriver::Xdotool,
+            OutputDriver::Ydotool,
+            OutputDriver::Clipboard,
+        ];
+        assert_eq!(driver_order(FocusedApp::Wayland, &order), order);
+        assert_eq!(
+            driver_order(FocusedApp::Xwayland, &order),
+            vec![
+                OutputDriver::Xdotool,
+                OutputDriver::Ydotool,
+                OutputDriver::InputMethod,
+                OutputDriver::Wtype,
+                OutputDriver::Clipboard,
+            ]
+        );
+        assert_eq!(
+            driver_order(FocusedApp::Tty, &order),
+            vec![
+                OutputDriver::Ydotool,
+                OutputDriver::InputMethod,
+                OutputDriver::Wtype,
+                OutputDriver::Xdotool,
+                OutputDriver::Clipboard,
+            ]
+        );
+        // Drivers outside the configured order aren't added
+        assert_eq!(
+            driver_order(FocusedApp::X11, &[OutputDriver::Wtype]),
+            vec![OutputDriver::Wtype]
+        );
+    }
+}
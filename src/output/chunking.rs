@@ -0,0 +1,261 @@
+//! Chunked typing for long output
+//!
+//! Some compositors and applications drop input when thousands of
+//! characters are typed in one go: the virtual keyboard queue overflows, or
+//! an input method commit exceeds the protocol message size. Typing drivers
+//! therefore get long text in chunks with a short pause in between. Chunks
+//! end after whitespace where possible, so a word is never split across a
+//! pause.
+//!
+//! Each driver has a default chunk size and pause; `[output.chunking]`
+//! overrides them. Text that fits in one chunk is typed as before.
+
+use super::TextOutput;
+use crate::config::{ChunkingConfig, OutputDriver};
+use crate::error::OutputError;
+use std::time::Duration;
+
+/// Chunk size and pause for one driver
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkSize {
+    /// Characters per chunk
+    pub chars: usize,
+    /// Pause between chunks in milliseconds
+    pub pause_ms: u32,
+}
+
+/// Default chunking for a driver, or `None` for drivers that don't type
+/// (clipboard, xclip, OSC 52) and for IBus, which commits the text in one
+/// message
+fn driver_default(driver: OutputDriver) -> Option<ChunkSize> {
+    let (chars, pause_ms) = match driver {
+        // One commit_string per chunk; keep well under the 4 KiB Wayland
+        // message limit even for 4-byte characters
+        OutputDriver::InputMethod => (800, 20),
+        OutputDriver::Atspi => (1000, 20),
+        OutputDriver::Xdotool => (1000, 50),
+        OutputDriver::Wtype | OutputDriver::Eitype | OutputDriver::Dotool => (500, 50),
+        OutputDriver::Uinput => (500, 50),
+        // ydotoold drops events under load
+        OutputDriver::Ydotool => (200, 100),
+        OutputDriver::Ime | OutputDriver::Clipboard | OutputDriver::Xclip | OutputDriver::Osc52 => {
+            return None
+        }
+    };
+    Some(ChunkSize { chars, pause_ms })
+}
+
+/// Chunking for `driver` with the configured overrides, or `None` when it
+/// is disabled or the driver doesn't type
+pub fn chunk_size(driver: OutputDriver, config: &ChunkingConfig) -> Option<ChunkSize> {
+    if !config.enabled {
+        return None;
+    }
+    let default = driver_default(driver)?;
+    Some(ChunkSize {
+        chars: config.chunk_chars.unwrap_or(default.chars).max(1),
+        pause_ms: config.pause_ms.unwrap_or(default.pause_ms),
+    })
+}
+
+/// Split text into chunks of at most `size` characters, ending each one
+/// after the last whitespace in it when there is any
+fn chunks(text: &str, size: usize) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut rest = text;
+    while rest.chars().count() > size {
+        let (limit, _) = rest.char_indices().nth(size).unwrap();
+        let end = rest[..limit]
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+            .map(|(i, c)| i + c.len_utf8())
+            .unwrap_or(limit);
+        chunks.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    if !rest.is_empty() {
+        chunks.push(rest);
+    }
+    chunks
+}
+
+/// Typing driver that types long text chunk by chunk
+pub struct ChunkedOutput {
+    /// The driver without auto_submit and append_text, for all but the
+    /// last chunk
+    body: Box<dyn TextOutput>,
+    /// The same driver with auto_submit and append_text, for the last chunk
+    last: Box<dyn TextOutput>,
+    /// Delay before the first chunk (the drivers get none)
+    pre_type_delay_ms: u32,
+    size: ChunkSize,
+}
+
+impl ChunkedOutput {
+    pub fn new(
+        body: Box<dyn TextOutput>,
+        last: Box<dyn TextOutput>,
+        pre_type_delay_ms: u32,
+        size: ChunkSize,
+    ) -> Self {
+        Self {
+            body,
+            last,
+            pre_type_delay_ms,
+            size,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TextOutput for ChunkedOutput {
+    async fn output(&self, text: &str) -> Result<(), OutputError> {
+        if self.pre_type_delay_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(self.pre_type_delay_ms as u64)).await;
+        }
+
+        let chunks = chunks(text, self.size.chars);
+        let Some((&last_chunk, body_chunks)) = chunks.split_last() else {
+            return self.last.output(text).await;
+        };
+
+        if !body_chunks.is_empty() {
+            tracing::debug!(
+                "{}: typing {} chars in {} chunks",
+                self.body.name(),
+                text.chars().count(),
+                chunks.len()
+            );
+        }
+        let mut typed = 0;
+        for &chunk in body_chunks {
+            if let Err(e) = self.body.output(chunk).await {
+                if typed == 0 {
+                    // Nothing typed yet, so the next driver can start over
+                    return Err(e);
+                }
+                // Finish in one go rather than let a fallback retype the start
+                tracing::warn!(
+                    "{}: chunked typing failed after {} bytes ({}), typing the rest at once",
+                    self.body.name(),
+                    typed,
+                    e
+                );
+                return self.last.output(&text[typed..]).await;
+            }
+            typed += chunk.len();
+            tokio::time::sleep(Duration::from_millis(self.size.pause_ms as u64)).await;
+        }
+        self.last.output(last_chunk).await
+    }
+
+    async fn is_available(&self) -> bool {
+        self.body.is_available().await
+    }
+
+    fn name(&self) -> &'static str {
+        self.body.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_chunks_break_after_whitespace() {
+        assert_eq!(
+            chunks("one two three four", 8),
+            vec!["one two ", "three ", "four"]
+        );
+        assert_eq!(chunks("short", 8), vec!["short"]);
+        assert!(chunks("", 8).is_empty());
+    }
+
+    #[test]
+    fn test_chunks_split_long_words_on_char_boundaries() {
+        assert_eq!(chunks("ééééé", 2), vec!["éé", "éé", "é"]);
+        assert_eq!(chunks("abcdefg hi", 3), vec!["abc", "def", "g ", "hi"]);
+    }
+
+    #[test]
+    fn test_chunk_size() {
+        let config = ChunkingConfig::default();
+        assert_eq!(
+            chunk_size(OutputDriver::Ydotool, &config),
+            Some(ChunkSize {
+                chars: 200,
+                pause_ms: 100
+            })
+        );
+        assert_eq!(chunk_size(OutputDriver::Clipboard, &config), None);
+
+        let config = ChunkingConfig {
+            chunk_chars: Some(0),
+            pause_ms: Some(10),
+            ..ChunkingConfig::default()
+        };
+        assert_eq!(
+            chunk_size(OutputDriver::Wtype, &config),
+            Some(ChunkSize {
+                chars: 1,
+                pause_ms: 10
+            })
+        );
+
+        let config = ChunkingConfig {
+            enabled: false,
+            ..ChunkingConfig::default()
+        };
+        assert_eq!(chunk_size(OutputDriver::Wtype, &config), None);
+    }
+
+    /// Records what each output call received
+    struct Recorder {
+        calls: Arc<Mutex<Vec<String>>>,
+        suffix: &'static str,
+    }
+
+    #[async_trait::async_trait]
+    impl TextOutput for Recorder {
+        async fn output(&self, text: &str) -> Result<(), OutputError> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("{}{}", text, self.suffix));
+            Ok(())
+        }
+
+        async fn is_available(&self) -> bool {
+            true
+        }
+
+        fn name(&self) -> &'static str {
+            "recorder"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_only_last_chunk_gets_append_and_submit() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let output = ChunkedOutput::new(
+            Box::new(Recorder {
+                calls: calls.clone(),
+                suffix: "",
+            }),
+            Box::new(Recorder {
+                calls: calls.clone(),
+                suffix: "⏎",
+            }),
+            0,
+            ChunkSize {
+                chars: 6,
+                pause_ms: 1,
+            },
+        );
+        output.output("echo hello world").await.unwrap();
+        assert_eq!(*calls.lock().unwrap(), vec!["echo ", "hello ", "world⏎"]);
+    }
+}
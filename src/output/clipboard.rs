@@ -8,10 +8,12 @@
 //!
 //! Requires: wl-clipboard package installed
 
+use super::clipboard_snapshot::{self, ClipboardSnapshot};
 use super::session::{detect, DisplaySession};
 use super::TextOutput;
 use crate::error::OutputError;
 use std::process::Stdio;
+use std::time::Duration;
 use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 
@@ -19,12 +21,37 @@ use tokio::process::Command;
 pub struct ClipboardOutput {
     /// Text to append after transcription
     append_text: Option<String>,
+    /// Restore the previous clipboard this long after copying
+    restore_after: Option<Duration>,
+    /// Copy to the primary selection (`wl-copy --primary`) instead of the
+    /// clipboard
+    primary: bool,
 }
 
 impl ClipboardOutput {
     /// Create a new clipboard output
     pub fn new(append_text: Option<String>) -> Self {
-        Self { append_text }
+        Self {
+            append_text,
+            primary: false,
+            restore_after: None,
+        }
+    }
+
+    /// Create an output for the primary selection (middle-click paste)
+    pub fn primary(append_text: Option<String>) -> Self {
+        Self {
+            append_text,
+            primary: true,
+            restore_after: None,
+        }
+    }
+
+    /// Put the previous clipboard content back after `ttl`, unless something
+    /// else has been copied by then. Has no effect on the primary selection.
+    pub fn restore_after(mut self, ttl: Option<Duration>) -> Self {
+        self.restore_after = ttl;
+        self
     }
 }
 
@@ -42,8 +69,24 @@ impl TextOutput for ClipboardOutput {
             std::borrow::Cow::Borrowed(text)
         };
 
+        // Save the clipboard before overwriting it
+        let snapshot = match self.restore_after {
+            Some(ttl) if !self.primary => match ClipboardSnapshot::save().await {
+                Ok(snapshot) => snapshot.map(|s| (s, ttl)),
+                Err(e) => {
+                    tracing::warn!("Failed to read clipboard for restoration: {}", e);
+                    None
+                }
+            },
+            _ => None,
+        };
+
         // Spawn wl-copy with stdin pipe
-        let mut child = Command::new("wl-copy")
+        let mut cmd = Command::new("wl-copy");
+        if self.primary {
+            cmd.arg("--primary");
+        }
+        let mut child = cmd
             .stdin(Stdio::piped())
             .stdout(Stdio::null())
             .stderr(Stdio::piped())
@@ -79,7 +122,15 @@ impl TextOutput for ClipboardOutput {
             ));
         }
 
-        tracing::info!("Text copied to clipboard ({} chars)", text.len());
+        if let Some((snapshot, ttl)) = snapshot {
+            clipboard_snapshot::restore_after(snapshot, text.to_string(), ttl);
+        }
+
+        if self.primary {
+            tracing::info!("Text copied to primary selection ({} chars)", text.len());
+        } else {
+            tracing::info!("Text copied to clipboard ({} chars)", text.len());
+        }
         Ok(())
     }
 
@@ -102,7 +153,11 @@ impl TextOutput for ClipboardOutput {
     }
 
     fn name(&self) -> &'static str {
-        "clipboard (wl-copy)"
+        if self.primary {
+            "primary selection (wl-copy)"
+        } else {
+            "clipboard (wl-copy)"
+        }
     }
 }
 
@@ -117,5 +172,10 @@ mod tests {
 
         let output = ClipboardOutput::new(Some(" ".to_string()));
         assert_eq!(output.append_text, Some(" ".to_string()));
+        assert!(!output.primary);
+
+        let output = ClipboardOutput::primary(None);
+        assert!(output.primary);
+        assert_eq!(output.name(), "primary selection (wl-copy)");
     }
 }
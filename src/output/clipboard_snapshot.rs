@@ -0,0 +1,373 @@
+//! Clipboard save and restore
+//!
+//! Used by paste mode (`restore_clipboard`) and clipboard mode
+//! (`restore_clipboard_after_secs`) to put back whatever the user had
+//! copied before a dictation.
+//!
+//! The clipboard owner usually offers several MIME types, but wl-copy and
+//! xclip can only serve one. The snapshot keeps the most useful one with its
+//! type: an image stays an image, and plain text is preferred over the rich
+//! text variants so the restored content still pastes everywhere.
+//!
+//! Uses wl-paste/wl-copy on Wayland and xclip on X11.
+
+use crate::error::OutputError;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+/// Largest clipboard content that will be saved for restoration
+const MAX_CLIPBOARD_SIZE: usize = 100 * 1024 * 1024; // 100 MB
+
+/// X11 selection targets that describe the selection rather than hold data
+const X11_META_TARGETS: &[&str] = &["TARGETS", "TIMESTAMP", "MULTIPLE", "SAVE_TARGETS", "DELETE"];
+
+/// Plain text types, most specific first
+const TEXT_TYPES: &[&str] = &[
+    "text/plain;charset=utf-8",
+    "UTF8_STRING",
+    "text/plain",
+    "STRING",
+    "TEXT",
+];
+
+/// Clipboard content with MIME type for restoration
+#[derive(Clone)]
+pub struct ClipboardSnapshot {
+    data: Vec<u8>,
+    mime_type: String,
+}
+
+impl std::fmt::Debug for ClipboardSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClipboardSnapshot")
+            .field("mime_type", &self.mime_type)
+            .field("data", &format!("[{} bytes]", self.data.len()))
+            .finish()
+    }
+}
+
+impl ClipboardSnapshot {
+    /// Read current clipboard content using wl-paste (Wayland) or xclip (X11 fallback).
+    /// Returns `None` when the clipboard is empty or too large to keep.
+    pub async fn save() -> Result<Option<Self>, OutputError> {
+        // Try wl-paste first (Wayland)
+        if std::env::var("WAYLAND_DISPLAY").is_ok() {
+            match read_wl_paste().await {
+                Ok(content) => return Ok(content),
+                Err(e) => {
+                    tracing::debug!("wl-paste failed, trying xclip: {}", e);
+                }
+            }
+        }
+
+        // Fallback to xclip (X11)
+        read_xclip().await
+    }
+
+    /// Restore clipboard content using wl-copy or xclip
+    pub async fn restore(&self) -> Result<(), OutputError> {
+        // Try wl-copy first (Wayland)
+        if std::env::var("WAYLAND_DISPLAY").is_ok() {
+            match self.restore_wl_copy().await {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    tracing::debug!("wl-copy restore failed, trying xclip: {}", e);
+                }
+            }
+        }
+
+        // Fallback to xclip
+        self.restore_xclip().await
+    }
+
+    /// Whether this snapshot holds exactly `text`
+    fn is_text(&self, text: &str) -> bool {
+        is_text_type(&self.mime_type) && self.data == text.as_bytes()
+    }
+
+    async fn restore_wl_copy(&self) -> Result<(), OutputError> {
+        let child = Command::new("wl-copy")
+            .arg("--type")
+            .arg(&self.mime_type)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    OutputError::WlCopyNotFound
+                } else {
+                    OutputError::InjectionFailed(e.to_string())
+                }
+            })?;
+        self.write_to(child, "wl-copy").await
+    }
+
+    async fn restore_xclip(&self) -> Result<(), OutputError> {
+        let child = Command::new("xclip")
+            .args(["-selection", "clipboard", "-t", &self.mime_type])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    OutputError::XclipNotFound
+                } else {
+                    OutputError::InjectionFailed(e.to_string())
+                }
+            })?;
+        self.write_to(child, "xclip").await
+    }
+
+    async fn write_to(
+        &self,
+        mut child: tokio::process::Child,
+        tool: &str,
+    ) -> Result<(), OutputError> {
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(&self.data)
+                .await
+                .map_err(|e| OutputError::InjectionFailed(e.to_string()))?;
+            drop(stdin);
+        }
+
+        let status = child
+            .wait()
+            .await
+            .map_err(|e| OutputError::InjectionFailed(e.to_string()))?;
+
+        if !status.success() {
+            return Err(OutputError::InjectionFailed(format!(
+                "{} exited with error during restore",
+                tool
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Restore `snapshot` after `ttl` in the background, unless the clipboard no
+/// longer holds `copied` (the user has copied something else since)
+pub fn restore_after(snapshot: ClipboardSnapshot, copied: String, ttl: Duration) {
+    tokio::spawn(async move {
+        tokio::time::sleep(ttl).await;
+
+        match ClipboardSnapshot::save().await {
+            Ok(Some(current)) if current.is_text(&copied) => {}
+            Ok(_) => {
+                tracing::debug!("Clipboard changed since dictation, not restoring");
+                return;
+            }
+            Err(e) => {
+                tracing::warn!("Failed to read clipboard for restoration: {}", e);
+                return;
+            }
+        }
+
+        match snapshot.restore().await {
+            Ok(()) => tracing::debug!("Restored original clipboard content"),
+            Err(e) => tracing::warn!("Failed to restore clipboard content: {}", e),
+        }
+    });
+}
+
+fn is_text_type(mime_type: &str) -> bool {
+    TEXT_TYPES.iter().any(|t| t.eq_ignore_ascii_case(mime_type))
+}
+
+/// Pick the type to save from the offered ones: an image if there is one,
+/// then plain text, then whatever the owner listed first
+fn preferred_type<'a>(types: &[&'a str]) -> Option<&'a str> {
+    let types: Vec<&str> = types
+        .iter()
+        .map(|t| t.trim())
+        .filter(|t| !t.is_empty() && !X11_META_TARGETS.contains(t))
+        .collect();
+
+    types
+        .iter()
+        .find(|t| t.starts_with("image/"))
+        .or_else(|| {
+            TEXT_TYPES
+                .iter()
+                .find_map(|wanted| types.iter().find(|t| t.eq_ignore_ascii_case(wanted)))
+        })
+        .or_else(|| types.first())
+        .copied()
+}
+
+/// Read the clipboard with wl-paste
+async fn read_wl_paste() -> Result<Option<ClipboardSnapshot>, OutputError> {
+    // First, check if clipboard is empty by listing types
+    let types_output = Command::new("wl-paste")
+        .arg("--list-types")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                OutputError::WlPasteNotFound
+            } else {
+                OutputError::InjectionFailed(e.to_string())
+            }
+        })?;
+
+    if !types_output.status.success() {
+        // Clipboard might be empty or error occurred
+        let stderr = String::from_utf8_lossy(&types_output.stderr);
+        tracing::debug!("wl-paste --list-types failed: {}", stderr);
+        return Ok(None);
+    }
+
+    let types_str = String::from_utf8_lossy(&types_output.stdout);
+    let types: Vec<&str> = types_str.lines().collect();
+    let Some(mime_type) = preferred_type(&types) else {
+        return Ok(None);
+    };
+
+    // Read the actual content
+    let content_output = Command::new("wl-paste")
+        .args(["--no-newline", "--type", mime_type])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|e| OutputError::InjectionFailed(e.to_string()))?;
+
+    if !content_output.status.success() {
+        let stderr = String::from_utf8_lossy(&content_output.stderr);
+        tracing::debug!("wl-paste failed to read content: {}", stderr);
+        return Ok(None);
+    }
+
+    snapshot(content_output.stdout, mime_type)
+}
+
+/// Read the clipboard with xclip (X11 fallback)
+async fn read_xclip() -> Result<Option<ClipboardSnapshot>, OutputError> {
+    // Check if DISPLAY is set (X11 environment)
+    if std::env::var("DISPLAY").is_err() {
+        return Ok(None);
+    }
+
+    let xclip = |target: &str| {
+        let mut cmd = Command::new("xclip");
+        cmd.args(["-selection", "clipboard", "-t", target, "-o"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        cmd
+    };
+    let not_found = |e: std::io::Error| {
+        if e.kind() == std::io::ErrorKind::NotFound {
+            OutputError::XclipNotFound
+        } else {
+            OutputError::InjectionFailed(e.to_string())
+        }
+    };
+
+    let targets_output = xclip("TARGETS").output().await.map_err(not_found)?;
+    if !targets_output.status.success() {
+        // No selection owner
+        let stderr = String::from_utf8_lossy(&targets_output.stderr);
+        tracing::debug!("xclip -t TARGETS failed: {}", stderr);
+        return Ok(None);
+    }
+
+    let targets_str = String::from_utf8_lossy(&targets_output.stdout);
+    let targets: Vec<&str> = targets_str.lines().collect();
+    let Some(mime_type) = preferred_type(&targets) else {
+        return Ok(None);
+    };
+
+    let output = xclip(mime_type).output().await.map_err(not_found)?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        tracing::debug!("xclip failed: {}", stderr);
+        return Ok(None);
+    }
+
+    snapshot(output.stdout, mime_type)
+}
+
+fn snapshot(data: Vec<u8>, mime_type: &str) -> Result<Option<ClipboardSnapshot>, OutputError> {
+    if data.is_empty() {
+        return Ok(None);
+    }
+
+    if data.len() > MAX_CLIPBOARD_SIZE {
+        tracing::warn!(
+            "Clipboard content too large ({} bytes), skipping restoration",
+            data.len()
+        );
+        return Ok(None);
+    }
+
+    Ok(Some(ClipboardSnapshot {
+        data,
+        mime_type: mime_type.to_string(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_redacts_data() {
+        let content = ClipboardSnapshot {
+            data: vec![1, 2, 3, 4, 5],
+            mime_type: "text/plain".to_string(),
+        };
+        let debug_str = format!("{:?}", content);
+        assert!(debug_str.contains("[5 bytes]"));
+        assert!(debug_str.contains("text/plain"));
+        assert!(!debug_str.contains("[1, 2, 3"));
+    }
+
+    #[test]
+    fn test_preferred_type() {
+        // Browser selection: plain text over HTML
+        assert_eq!(
+            preferred_type(&["text/html", "text/plain;charset=utf-8", "text/plain"]),
+            Some("text/plain;charset=utf-8")
+        );
+        // Copied image: keep the image
+        assert_eq!(
+            preferred_type(&["text/uri-list", "image/png", "text/plain"]),
+            Some("image/png")
+        );
+        // X11 targets
+        assert_eq!(
+            preferred_type(&["TARGETS", "TIMESTAMP", "STRING", "UTF8_STRING"]),
+            Some("UTF8_STRING")
+        );
+        // Nothing we recognize: the owner's first choice
+        assert_eq!(
+            preferred_type(&["TARGETS", "application/x-kde-cutselection"]),
+            Some("application/x-kde-cutselection")
+        );
+        assert_eq!(preferred_type(&["TARGETS", ""]), None);
+    }
+
+    #[test]
+    fn test_is_text() {
+        let snapshot = ClipboardSnapshot {
+            data: b"hello".to_vec(),
+            mime_type: "UTF8_STRING".to_string(),
+        };
+        assert!(snapshot.is_text("hello"));
+        assert!(!snapshot.is_text("hello world"));
+
+        let image = ClipboardSnapshot {
+            data: b"hello".to_vec(),
+            mime_type: "image/png".to_string(),
+        };
+        assert!(!image.is_text("hello"));
+    }
+}
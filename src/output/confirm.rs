@@ -0,0 +1,266 @@
+//! Preview-and-confirm step before output
+//!
+//! With `[output.confirm] enabled = true`, each transcription is shown in a
+//! dmenu-style chooser before anything is typed or pasted:
+//!
+//! - **Accept** outputs the text as shown
+//! - **Edit** opens the text in a dialog and asks again with the result
+//! - **Discard** (or Escape) drops it
+//!
+//! Typing into the chooser's input field and pressing Enter on text that
+//! matches no entry outputs the typed text instead. A chooser that can't be
+//! started or times out discards, so unconfirmed text never reaches a
+//! terminal.
+//!
+//! Both commands run through `sh -c` with the text in `$VOXTYPE_TEXT`.
+
+use super::xclip::binary_on_path;
+use crate::config::ConfirmConfig;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+const ACCEPT_PREFIX: &str = "Accept: ";
+const EDIT: &str = "Edit";
+const DISCARD: &str = "Discard";
+
+/// Choosers tried in order when `command` is unset
+const CHOOSERS: &[(&str, &str)] = &[
+    ("fuzzel", "fuzzel --dmenu --prompt 'voxtype: '"),
+    ("wofi", "wofi --dmenu --prompt voxtype"),
+    ("rofi", "rofi -dmenu -p voxtype"),
+    ("bemenu", "bemenu -p voxtype"),
+    ("dmenu", "dmenu -p voxtype"),
+];
+
+/// Edit dialogs tried in order when `edit_command` is unset
+const EDITORS: &[(&str, &str)] = &[
+    (
+        "zenity",
+        "zenity --entry --title voxtype --text 'Edit dictation' --entry-text \"$VOXTYPE_TEXT\"",
+    ),
+    (
+        "kdialog",
+        "kdialog --title voxtype --inputbox 'Edit dictation' \"$VOXTYPE_TEXT\"",
+    ),
+    (
+        "yad",
+        "yad --entry --title voxtype --entry-text \"$VOXTYPE_TEXT\"",
+    ),
+];
+
+/// Outcome of the confirmation step
+#[derive(Debug, PartialEq)]
+pub enum Decision {
+    /// Output this text (the transcription, or what the user changed it to)
+    Accept(String),
+    /// Output nothing
+    Discard,
+}
+
+/// What the user picked in the chooser
+#[derive(Debug, PartialEq)]
+enum Choice {
+    Accept,
+    Edit,
+    Discard,
+    Replace(String),
+}
+
+/// Ask the user what to do with `text`
+pub async fn confirm(config: &ConfirmConfig, text: &str) -> Decision {
+    let Some(chooser) = resolve(config.command.as_deref(), CHOOSERS).await else {
+        tracing::warn!(
+            "Confirmation is enabled but no chooser was found (install fuzzel, wofi, \
+             rofi, bemenu or dmenu, or set [output.confirm] command); discarding text"
+        );
+        return Decision::Discard;
+    };
+    let editor = resolve(config.edit_command.as_deref(), EDITORS).await;
+    let limit = Duration::from_secs(config.timeout_secs);
+
+    let mut text = text.to_string();
+    loop {
+        let menu = menu(&text, editor.is_some());
+        let selected = match run(&chooser, &menu, &text, limit).await {
+            Ok(Some(selected)) => selected,
+            Ok(None) => return Decision::Discard,
+            Err(e) => {
+                tracing::warn!("Confirmation chooser failed: {}; discarding text", e);
+                return Decision::Discard;
+            }
+        };
+
+        match parse_choice(&selected, &text, editor.is_some()) {
+            Choice::Accept => return Decision::Accept(text),
+            Choice::Replace(replacement) => return Decision::Accept(replacement),
+            Choice::Discard => return Decision::Discard,
+            Choice::Edit => {
+                // Only offered when an editor was found
+                let Some(ref editor) = editor else { continue };
+                match run(editor, &text, &text, limit).await {
+                    Ok(Some(edited)) if !edited.is_empty() => text = edited,
+                    // Cancelled or emptied: ask again with the previous text
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!("Edit dialog failed: {}", e),
+                }
+            }
+        }
+    }
+}
+
+/// The configured command, or the first known tool that is installed
+async fn resolve(configured: Option<&str>, known: &[(&str, &str)]) -> Option<String> {
+    if let Some(command) = configured {
+        return Some(command.to_string());
+    }
+    for (binary, command) in known {
+        if binary_on_path(binary).await {
+            return Some(command.to_string());
+        }
+    }
+    None
+}
+
+/// Chooser entries, one per line. The preview is flattened to one line;
+/// accepting it still outputs the original newlines.
+fn menu(text: &str, can_edit: bool) -> String {
+    let mut menu = format!("{}{}\n", ACCEPT_PREFIX, text.replace('\n', " "));
+    if can_edit {
+        menu.push_str(EDIT);
+        menu.push('\n');
+    }
+    menu.push_str(DISCARD);
+    menu.push('\n');
+    menu
+}
+
+fn parse_choice(selected: &str, text: &str, can_edit: bool) -> Choice {
+    let selected = selected.trim_end_matches('\n');
+    if selected.is_empty() || selected == DISCARD {
+        Choice::Discard
+    } else if selected == format!("{}{}", ACCEPT_PREFIX, text.replace('\n', " ")) {
+        Choice::Accept
+    } else if can_edit && selected == EDIT {
+        Choice::Edit
+    } else {
+        Choice::Replace(selected.to_string())
+    }
+}
+
+/// Run `command` with `input` on stdin. `Ok(None)` means the user
+/// cancelled (non-zero exit).
+async fn run(
+    command: &str,
+    input: &str,
+    text: &str,
+    limit: Duration,
+) -> Result<Option<String>, String> {
+    let mut child = Command::new("sh")
+        .args(["-c", command])
+        .env("VOXTYPE_TEXT", text)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| format!("{}: {}", command, e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // Dialogs that don't read stdin may exit before we finish writing
+        let _ = stdin.write_all(input.as_bytes()).await;
+        drop(stdin);
+    }
+
+    let output = tokio::time::timeout(limit, child.wait_with_output())
+        .await
+        .map_err(|_| format!("no choice within {}s", limit.as_secs()))?
+        .map_err(|e| format!("{}: {}", command, e))?;
+    if !output.status.success() {
+        return Ok(None);
+    }
+    Ok(Some(
+        String::from_utf8_lossy(&output.stdout)
+            .trim_end_matches('\n')
+            .to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(command: &str, edit_command: Option<&str>) -> ConfirmConfig {
+        ConfirmConfig {
+            enabled: true,
+            command: Some(command.to_string()),
+            edit_command: edit_command.map(str::to_string),
+            timeout_secs: 5,
+        }
+    }
+
+    #[test]
+    fn test_menu() {
+        assert_eq!(
+            menu("rm -rf build\nls", true),
+            "Accept: rm -rf build ls\nEdit\nDiscard\n"
+        );
+        assert_eq!(menu("hello", false), "Accept: hello\nDiscard\n");
+    }
+
+    #[test]
+    fn test_parse_choice() {
+        let text = "two\nlines";
+        assert_eq!(
+            parse_choice("Accept: two lines\n", text, true),
+            Choice::Accept
+        );
+        assert_eq!(parse_choice("Edit", text, true), Choice::Edit);
+        assert_eq!(
+            parse_choice("Edit", text, false),
+            Choice::Replace("Edit".to_string())
+        );
+        assert_eq!(parse_choice("Discard", text, true), Choice::Discard);
+        assert_eq!(parse_choice("", text, true), Choice::Discard);
+        assert_eq!(
+            parse_choice("typed instead", text, true),
+            Choice::Replace("typed instead".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_confirm_accept_keeps_newlines() {
+        let decision = confirm(&config("head -n 1", None), "one\ntwo").await;
+        assert_eq!(decision, Decision::Accept("one\ntwo".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_discard() {
+        assert_eq!(
+            confirm(&config("tail -n 1", None), "text").await,
+            Decision::Discard
+        );
+        // Escape in a chooser exits non-zero
+        assert_eq!(
+            confirm(&config("exit 1", None), "text").await,
+            Decision::Discard
+        );
+    }
+
+    #[tokio::test]
+    async fn test_confirm_edit_then_accept() {
+        // Pick Edit until the text has been edited, then accept it
+        let chooser = r#"case "$VOXTYPE_TEXT" in *!) head -n 1 ;; *) echo Edit ;; esac"#;
+        let editor = r#"printf '%s!' "$VOXTYPE_TEXT""#;
+        let decision = confirm(&config(chooser, Some(editor)), "hello").await;
+        assert_eq!(decision, Decision::Accept("hello!".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_timeout_discards() {
+        let mut config = config("sleep 5", None);
+        config.timeout_secs = 0;
+        assert_eq!(confirm(&config, "text").await, Decision::Discard);
+    }
+}
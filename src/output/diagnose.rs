@@ -0,0 +1,265 @@
+//! Driver chain diagnostics for `voxtype test-output`
+//!
+//! Walks the type mode driver order and reports, for each driver, whether
+//! the tool it runs is installed, whether the driver considers itself
+//! available right now, and what to change when it isn't. The checks are
+//! the drivers' own `is_available()` plus a few facts about the system
+//! (session type, `/dev/uinput` access, the ydotoold socket) that explain
+//! the common failures.
+
+use super::session::{self, DisplaySession};
+use super::TextOutput;
+use crate::config::{OutputConfig, OutputDriver};
+
+/// Result of checking one driver
+#[derive(Debug, Clone)]
+pub struct DriverCheck {
+    pub driver: OutputDriver,
+    /// External command the driver runs, or `None` for built-in drivers
+    pub tool: Option<&'static str>,
+    /// Whether `tool` is on PATH (always true for built-in drivers)
+    pub installed: bool,
+    /// What the driver's own availability check says
+    pub available: bool,
+    /// Suggested fixes, empty when the driver is available
+    pub fixes: Vec<String>,
+}
+
+/// System facts the fixes depend on
+#[derive(Debug, Clone, Copy)]
+struct Facts {
+    session: DisplaySession,
+    installed: bool,
+    uinput_writable: bool,
+    in_input_group: bool,
+    ydotoold_socket: bool,
+}
+
+/// External command a driver runs
+pub fn driver_tool(driver: OutputDriver) -> Option<&'static str> {
+    match driver {
+        OutputDriver::Wtype => Some("wtype"),
+        OutputDriver::Eitype => Some("eitype"),
+        OutputDriver::Xdotool => Some("xdotool"),
+        OutputDriver::Dotool => Some("dotool"),
+        OutputDriver::Ydotool => Some("ydotool"),
+        OutputDriver::Clipboard => Some("wl-copy"),
+        OutputDriver::Xclip => Some("xclip"),
+        OutputDriver::InputMethod
+        | OutputDriver::Uinput
+        | OutputDriver::Atspi
+        | OutputDriver::Ime
+        | OutputDriver::Osc52 => None,
+    }
+}
+
+/// Package that provides `tool` on most distributions
+fn package(tool: &str) -> &str {
+    match tool {
+        "wl-copy" => "wl-clipboard",
+        other => other,
+    }
+}
+
+/// Whether `command` is an executable file in one of the PATH directories
+fn on_path(command: &str) -> bool {
+    std::env::var_os("PATH").is_some_and(|path| {
+        std::env::split_paths(&path).any(|dir| {
+            std::fs::metadata(dir.join(command)).is_ok_and(|m| {
+                use std::os::unix::fs::PermissionsExt;
+                m.is_file() && m.permissions().mode() & 0o111 != 0
+            })
+        })
+    })
+}
+
+/// Driver order type mode uses with `config`
+pub fn driver_order(config: &OutputConfig) -> Vec<OutputDriver> {
+    config
+        .driver_order
+        .clone()
+        .unwrap_or_else(|| super::DEFAULT_DRIVER_ORDER.to_vec())
+}
+
+/// Output for `driver` with `config`, without the pre-type delay
+pub fn driver_output(driver: OutputDriver, config: &OutputConfig) -> Box<dyn TextOutput> {
+    super::create_driver_output(driver, config, 0)
+}
+
+/// Check one driver
+pub async fn check_driver(driver: OutputDriver, config: &OutputConfig) -> DriverCheck {
+    let tool = driver_tool(driver);
+    let available = driver_output(driver, config).is_available().await;
+    let facts = Facts {
+        session: session::detect(),
+        installed: tool.is_none_or(on_path),
+        uinput_writable: std::fs::OpenOptions::new()
+            .write(true)
+            .open("/dev/uinput")
+            .is_ok(),
+        in_input_group: crate::setup::user_in_group("input"),
+        ydotoold_socket: super::find_ydotool_socket().is_some(),
+    };
+    DriverCheck {
+        driver,
+        tool,
+        installed: facts.installed,
+        available,
+        fixes: if available {
+            Vec::new()
+        } else {
+            fixes(driver, config, &facts)
+        },
+    }
+}
+
+/// How to make an unavailable driver work, most likely cause first
+fn fixes(driver: OutputDriver, config: &OutputConfig, facts: &Facts) -> Vec<String> {
+    let mut fixes = Vec::new();
+    if let Some(tool) = driver_tool(driver).filter(|_| !facts.installed) {
+        fixes.push(format!(
+            "Install {} (package `{}` on most distributions)",
+            tool,
+            package(tool)
+        ));
+    }
+
+    let wayland_only = matches!(
+        driver,
+        OutputDriver::Wtype
+            | OutputDriver::Eitype
+            | OutputDriver::InputMethod
+            | OutputDriver::Clipboard
+    );
+    if wayland_only && facts.session == DisplaySession::X11 {
+        fixes.push(format!(
+            "{} needs a Wayland session; this is X11. Put xdotool or xclip earlier in driver_order",
+            driver
+        ));
+    }
+
+    let uinput_access = || {
+        if facts.in_input_group {
+            "/dev/uinput isn't writable: load the module (`sudo modprobe uinput`) \
+             and check its udev rule"
+                .to_string()
+        } else {
+            "Add yourself to the input group (`sudo usermod -aG input $USER`), \
+             then log out and back in"
+                .to_string()
+        }
+    };
+
+    match driver {
+        OutputDriver::Xdotool if facts.session == DisplaySession::Wayland => {
+            fixes.push(
+                "xdotool only reaches XWayland windows under Wayland; \
+                 enable auto_driver to use it just for those"
+                    .to_string(),
+            );
+        }
+        OutputDriver::Xclip if facts.session == DisplaySession::Wayland => {
+            fixes.push(
+                "xclip is only used in an X11 session; under Wayland the clipboard \
+                 driver (wl-copy) takes its place"
+                    .to_string(),
+            );
+        }
+        OutputDriver::Dotool | OutputDriver::Uinput if !facts.uinput_writable => {
+            fixes.push(uinput_access());
+        }
+        OutputDriver::Ydotool if facts.installed && !facts.ydotoold_socket => {
+            fixes.push(
+                "Start ydotoold: `systemctl --user enable --now ydotool` \
+                 (or set YDOTOOL_SOCKET to its socket)"
+                    .to_string(),
+            );
+        }
+        OutputDriver::InputMethod | OutputDriver::Atspi if config.auto_submit => {
+            fixes.push(format!(
+                "{} can't press Enter; auto_submit needs a keystroke driver",
+                driver
+            ));
+        }
+        OutputDriver::InputMethod if !cfg!(feature = "wayland-im") => {
+            fixes.push("Rebuild voxtype with `--features wayland-im`".to_string());
+        }
+        OutputDriver::Ime => {
+            fixes.push(
+                "ime needs a running ibus-daemon (Fcitx5 can't commit text for \
+                 other programs)"
+                    .to_string(),
+            );
+        }
+        OutputDriver::Osc52 => {
+            fixes.push("osc52 needs a terminal: set osc52_tty to the terminal's tty".to_string());
+        }
+        _ => {}
+    }
+
+    if fixes.is_empty() {
+        fixes.push(format!(
+            "{} reports unavailable; run `voxtype -vv test-output` for details",
+            driver
+        ));
+    }
+    fixes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facts() -> Facts {
+        Facts {
+            session: DisplaySession::Wayland,
+            installed: true,
+            uinput_writable: true,
+            in_input_group: true,
+            ydotoold_socket: true,
+        }
+    }
+
+    #[test]
+    fn test_fixes_missing_tool() {
+        let config = OutputConfig::default();
+        let missing = Facts {
+            installed: false,
+            ..facts()
+        };
+        let fixes = fixes(OutputDriver::Clipboard, &config, &missing);
+        assert_eq!(fixes.len(), 1);
+        assert!(fixes[0].contains("wl-clipboard"));
+    }
+
+    #[test]
+    fn test_fixes_session_mismatch() {
+        let config = OutputConfig::default();
+        let x11 = Facts {
+            session: DisplaySession::X11,
+            ..facts()
+        };
+        assert!(fixes(OutputDriver::Wtype, &config, &x11)[0].contains("Wayland session"));
+        assert!(fixes(OutputDriver::Xdotool, &config, &facts())[0].contains("XWayland"));
+    }
+
+    #[test]
+    fn test_fixes_permissions_and_daemons() {
+        let config = OutputConfig::default();
+        let no_group = Facts {
+            uinput_writable: false,
+            in_input_group: false,
+            ..facts()
+        };
+        assert!(fixes(OutputDriver::Dotool, &config, &no_group)[0].contains("usermod"));
+
+        let no_socket = Facts {
+            ydotoold_socket: false,
+            ..facts()
+        };
+        assert!(fixes(OutputDriver::Ydotool, &config, &no_socket)[0].contains("ydotoold"));
+
+        // Nothing specific to suggest
+        assert!(fixes(OutputDriver::Wtype, &config, &facts())[0].contains("-vv"));
+    }
+}
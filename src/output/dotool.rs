@@ -32,10 +32,12 @@
 //!
 //! ## Fallback path: direct dotool
 //!
-//! When `dotoold` isn't running, voxtype spawns `dotool` directly per
-//! call. This is correct but pays the full uinput init cost (~700ms) on
-//! every typed segment — fine for one-shot batch transcription, painful
-//! for streaming.
+//! When `dotoold` isn't running, voxtype starts `dotool` itself. With
+//! `persistent_drivers` (the default) one process is kept running and fed
+//! every output over its stdin (see `keepalive`), so the uinput init cost
+//! (~700ms) is paid once. Otherwise `dotool` is spawned per call, which
+//! pays it on every typed segment — fine for one-shot batch transcription,
+//! painful for streaming.
 //! This is also the path used when voxtype needs an XKB layout or variant
 //! hint, because direct `dotool` can receive those hints per invocation.
 //!
@@ -47,6 +49,7 @@
 //!   voxtype config for direct dotool fallback) for non-US keyboard layouts,
 //!   with the matching desktop layout active
 
+use super::keepalive::{dotool_write, DotoolEnv};
 use super::TextOutput;
 use crate::error::OutputError;
 use std::path::PathBuf;
@@ -55,6 +58,10 @@ use std::time::Duration;
 use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 
+/// dotool's own `typedelay` and `typehold` defaults
+const DOTOOL_DEFAULT_TYPEDELAY_MS: u32 = 2;
+const DOTOOL_DEFAULT_TYPEHOLD_MS: u32 = 8;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct DotoolInvocation {
     binary: &'static str,
@@ -89,6 +96,8 @@ pub struct DotoolOutput {
     xkb_layout: Option<String>,
     /// Keyboard layout variant (e.g., "nodeadkeys")
     xkb_variant: Option<String>,
+    /// Feed a long-lived dotool instead of spawning one per output
+    persistent: bool,
 }
 
 impl DotoolOutput {
@@ -111,9 +120,16 @@ impl DotoolOutput {
             append_text,
             xkb_layout,
             xkb_variant,
+            persistent: false,
         }
     }
 
+    /// Keep one dotool running between outputs (when dotoold isn't)
+    pub fn persistent(mut self, persistent: bool) -> Self {
+        self.persistent = persistent;
+        self
+    }
+
     /// Public wrapper for the FIFO-detection helper so backspace paths
     /// (in `output/streaming.rs`) can decide whether to use `dotoolc` too.
     pub fn live_daemon_pipe_path() -> Option<PathBuf> {
@@ -234,6 +250,39 @@ impl TextOutput for DotoolOutput {
             );
         }
 
+        // A dotool kept running between outputs skips its uinput setup.
+        // Delays carry over between scripts, so reset them to dotool's
+        // defaults when none are configured.
+        if self.persistent && invocation.pipe.is_none() {
+            let mut script = commands.clone();
+            if self.type_delay_ms == 0 {
+                script.insert_str(
+                    0,
+                    &format!(
+                        "typedelay {}\ntypehold {}\n",
+                        DOTOOL_DEFAULT_TYPEDELAY_MS, DOTOOL_DEFAULT_TYPEHOLD_MS
+                    ),
+                );
+            }
+            let env = DotoolEnv {
+                xkb_layout: self.xkb_layout.clone(),
+                xkb_variant: self.xkb_variant.clone(),
+            };
+            match dotool_write(&script, &env).await {
+                Ok(()) => {
+                    tracing::info!(
+                        "Text typed via persistent dotool ({} chars)",
+                        text.chars().count()
+                    );
+                    return Ok(());
+                }
+                Err(OutputError::DotoolNotFound) => return Err(OutputError::DotoolNotFound),
+                Err(e) => {
+                    tracing::debug!("dotool: persistent process unavailable ({}), spawning", e)
+                }
+            }
+        }
+
         let mut cmd = Command::new(invocation.binary);
         cmd.stdin(Stdio::piped())
             .stdout(Stdio::null())
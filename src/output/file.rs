@@ -0,0 +1,195 @@
+//! File output (`mode = "file"`)
+//!
+//! `file_path` may start with `~/` and contain date placeholders, so each
+//! day gets its own note:
+//!
+//! ```toml
+//! [output]
+//! mode = "file"
+//! file_mode = "append"
+//! file_path = "~/notes/{date}.md"
+//! file_entry_template = "- {time} {text}\n"
+//! file_header_template = "# {weekday}, {date}\n\n"
+//! ```
+//!
+//! Placeholders: `{date}` (2026-03-14), `{time}` (09:41), `{year}`,
+//! `{month}`, `{day}`, `{hour}`, `{minute}`, `{weekday}` (Saturday), and
+//! `{text}` in the entry template. Unknown placeholders are left as is.
+
+use crate::config::FileMode;
+use chrono::{DateTime, Local};
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+
+/// Substitute `{placeholder}`s in one pass, so braces inside the dictated
+/// text are never expanded
+fn expand(template: &str, now: &DateTime<Local>, text: Option<&str>) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let value = after.find('}').and_then(|close| {
+            let value = match &after[..close] {
+                "date" => now.format("%Y-%m-%d").to_string(),
+                "time" => now.format("%H:%M").to_string(),
+                "year" => now.format("%Y").to_string(),
+                "month" => now.format("%m").to_string(),
+                "day" => now.format("%d").to_string(),
+                "hour" => now.format("%H").to_string(),
+                "minute" => now.format("%M").to_string(),
+                "weekday" => now.format("%A").to_string(),
+                "text" => text?.to_string(),
+                _ => return None,
+            };
+            Some((value, close))
+        });
+        match value {
+            Some((value, close)) => {
+                out.push_str(&value);
+                rest = &after[close + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// The file to write: placeholders filled in and `~` expanded
+pub fn resolve_path(path: &Path, now: &DateTime<Local>) -> PathBuf {
+    let path = expand(&path.to_string_lossy(), now, None);
+    if path == "~" || path.starts_with("~/") {
+        if let Some(home) = dirs::home_dir() {
+            return home.join(path[1..].trim_start_matches('/'));
+        }
+    }
+    PathBuf::from(path)
+}
+
+/// The text to write for one transcription. Without a template, the text
+/// on its own line.
+pub fn format_entry(template: Option<&str>, text: &str, now: &DateTime<Local>) -> String {
+    match template {
+        Some(template) => expand(template, now, Some(text)),
+        None if text.ends_with('\n') => text.to_string(),
+        None => format!("{}\n", text),
+    }
+}
+
+/// Write a transcription to `path`, respecting `file_mode`. Missing parent
+/// directories are created, and a new file starts with the header.
+pub async fn write_transcription(
+    path: &Path,
+    text: &str,
+    file_mode: &FileMode,
+    entry_template: Option<&str>,
+    header_template: Option<&str>,
+) -> std::io::Result<PathBuf> {
+    let now = Local::now();
+    let path = resolve_path(path, &now);
+
+    // Create parent directories if needed
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+    }
+
+    let is_new = *file_mode == FileMode::Overwrite || !tokio::fs::try_exists(&path).await?;
+    let mut output_text = String::new();
+    if let Some(header) = header_template.filter(|_| is_new) {
+        output_text.push_str(&expand(header, &now, None));
+    }
+    output_text.push_str(&format_entry(entry_template, text, &now));
+
+    match file_mode {
+        FileMode::Overwrite => {
+            tokio::fs::write(&path, output_text).await?;
+        }
+        FileMode::Append => {
+            let mut file = tokio::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .await?;
+            file.write_all(output_text.as_bytes()).await?;
+            // tokio finishes the write in the background otherwise
+            file.flush().await?;
+        }
+    }
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Local> {
+        Local.with_ymd_and_hms(2026, 3, 14, 9, 5, 0).unwrap()
+    }
+
+    #[test]
+    fn test_resolve_path() {
+        assert_eq!(
+            resolve_path(Path::new("/notes/{year}/{date}.md"), &now()),
+            PathBuf::from("/notes/2026/2026-03-14.md")
+        );
+        assert_eq!(
+            resolve_path(Path::new("/tmp/{unknown}.txt"), &now()),
+            PathBuf::from("/tmp/{unknown}.txt")
+        );
+        if let Some(home) = dirs::home_dir() {
+            assert_eq!(
+                resolve_path(Path::new("~/journal/{month}-{day}.md"), &now()),
+                home.join("journal/03-14.md")
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_entry() {
+        assert_eq!(format_entry(None, "hello", &now()), "hello\n");
+        assert_eq!(format_entry(None, "hello\n", &now()), "hello\n");
+        assert_eq!(
+            format_entry(Some("- {time} {text}\n"), "walked the dog", &now()),
+            "- 09:05 walked the dog\n"
+        );
+        // Braces in the dictated text are not placeholders
+        assert_eq!(
+            format_entry(Some("{weekday}: {text}"), "say {date}", &now()),
+            "Saturday: say {date}"
+        );
+        assert_eq!(format_entry(Some("{text"), "x", &now()), "{text");
+    }
+
+    #[tokio::test]
+    async fn test_write_transcription_header_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let template = dir.path().join("notes/{year}.md");
+        let header = Some("# Journal\n");
+        let entry = Some("- {text}\n");
+
+        let path = write_transcription(&template, "one", &FileMode::Append, entry, header)
+            .await
+            .unwrap();
+        write_transcription(&template, "two", &FileMode::Append, entry, header)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            path,
+            dir.path()
+                .join(format!("notes/{}.md", Local::now().format("%Y")))
+        );
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "# Journal\n- one\n- two\n"
+        );
+    }
+}
@@ -0,0 +1,622 @@
+//! IBus input method commit
+//!
+//! Commits text through the IBus daemon, the way an input method engine
+//! delivers a finished CJK conversion. Applications receive the whole
+//! string at once, independent of keyboard layout and held modifiers, in
+//! every toolkit IBus supports (GTK, Qt, X11 and Wayland clients alike).
+//!
+//! IBus only accepts committed text from the focused context's engine, so
+//! for each output voxtype registers a small engine of its own on the IBus
+//! bus, switches to it with `SetGlobalEngine`, emits `CommitText`, and
+//! switches back to the engine that was active before. The panel shows
+//! the voxtype engine for that moment.
+//!
+//! Fcitx5 has no equivalent: its engines are in-process addons and its
+//! D-Bus API has no call to commit text into another application, so with
+//! Fcitx5 this driver is unavailable and the chain moves on.
+//!
+//! Requires:
+//! - A running ibus-daemon (the default input method framework on GNOME)
+
+use super::TextOutput;
+use crate::error::OutputError;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::process::Command;
+use tokio::sync::oneshot;
+use zbus::names::BusName;
+use zbus::zvariant::{OwnedObjectPath, OwnedValue, StructureBuilder, Value};
+use zbus::Connection;
+
+const IBUS: &str = "org.freedesktop.IBus";
+const IBUS_PATH: &str = "/org/freedesktop/IBus";
+const FACTORY_PATH: &str = "/org/freedesktop/IBus/Factory";
+const ENGINE_PATH: &str = "/org/freedesktop/IBus/Engine/Voxtype";
+const ENGINE_INTERFACE: &str = "org.freedesktop.IBus.Engine";
+const ENGINE_NAME: &str = "voxtype";
+
+/// Return key, for auto_submit
+const KEYSYM_RETURN: u32 = 0xff0d;
+const KEYCODE_RETURN: u32 = 28;
+const IBUS_RELEASE_MASK: u32 = 1 << 30;
+
+/// How long IBus gets to switch the focused context to the voxtype engine
+const ENGINE_READY_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Give up on each step of a commit after this long
+const COMMIT_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// Switching back gets longer, since giving up leaves the user's engine off
+const RESTORE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Engine the last output switched away from, until it switched back. A
+/// later output that finds the voxtype engine still active returns to it.
+static SWITCHED_FROM: Mutex<Option<String>> = Mutex::new(None);
+
+/// Engine factory IBus calls once the voxtype engine is selected
+struct Factory;
+
+#[zbus::interface(name = "org.freedesktop.IBus.Factory")]
+impl Factory {
+    fn create_engine(&self, name: String) -> zbus::fdo::Result<OwnedObjectPath> {
+        if name != ENGINE_NAME {
+            return Err(zbus::fdo::Error::Failed(format!("unknown engine {}", name)));
+        }
+        OwnedObjectPath::try_from(ENGINE_PATH).map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
+    }
+}
+
+/// The voxtype engine. It only commits; key events pass through untouched.
+struct Engine {
+    ready: Mutex<Option<oneshot::Sender<()>>>,
+}
+
+impl Engine {
+    fn notify_ready(&self) {
+        if let Some(tx) = self.ready.lock().unwrap_or_else(|e| e.into_inner()).take() {
+            let _ = tx.send(());
+        }
+    }
+}
+
+#[zbus::interface(name = "org.freedesktop.IBus.Engine")]
+impl Engine {
+    fn process_key_event(&self, _keyval: u32, _keycode: u32, _state: u32) -> bool {
+        false
+    }
+
+    fn enable(&self) {
+        self.notify_ready();
+    }
+
+    fn focus_in(&self) {
+        self.notify_ready();
+    }
+
+    fn focus_in_id(&self, _object_path: String, _client: String) {
+        self.notify_ready();
+    }
+
+    fn focus_out(&self) {}
+
+    fn disable(&self) {}
+
+    fn reset(&self) {}
+
+    fn set_capabilities(&self, _caps: u32) {}
+
+    fn set_cursor_location(&self, _x: i32, _y: i32, _w: i32, _h: i32) {}
+
+    fn set_surrounding_text(&self, _text: OwnedValue, _cursor: u32, _anchor: u32) {}
+}
+
+/// IBus destroys engines through this interface when switching away
+struct Service;
+
+#[zbus::interface(name = "org.freedesktop.IBus.Service")]
+impl Service {
+    fn destroy(&self) {}
+}
+
+/// Candidate names of the IBus address file for the current display, as
+/// `ibus_get_socket_path` builds them: `<host>-<display number>`, with
+/// `unix` for a local display. Wayland sessions are tried first, then X11.
+fn socket_suffixes(wayland_display: Option<&str>, display: Option<&str>) -> Vec<String> {
+    let mut suffixes = Vec::new();
+    if let Some(wayland) = wayland_display.filter(|d| !d.is_empty()) {
+        suffixes.push(format!("unix-{}", wayland));
+    }
+    if let Some((host, rest)) = display.and_then(|d| d.split_once(':')) {
+        let number = rest.split('.').next().unwrap_or(rest);
+        let host = if host.is_empty() { "unix" } else { host };
+        suffixes.push(format!("{}-{}", host, number));
+    }
+    suffixes
+}
+
+/// The bus address and daemon PID from an IBus address file
+fn parse_address_file(content: &str) -> Option<(String, Option<u32>)> {
+    let mut address = None;
+    let mut pid = None;
+    for line in content.lines() {
+        if let Some(value) = line.strip_prefix("IBUS_ADDRESS=") {
+            address = Some(value.trim().to_string());
+        } else if let Some(value) = line.strip_prefix("IBUS_DAEMON_PID=") {
+            pid = value.trim().parse().ok();
+        }
+    }
+    address.filter(|a| !a.is_empty()).map(|a| (a, pid))
+}
+
+/// Address of the running ibus-daemon's private bus
+fn ibus_address() -> Result<String, String> {
+    if let Ok(address) = std::env::var("IBUS_ADDRESS") {
+        if !address.is_empty() {
+            return Ok(address);
+        }
+    }
+
+    let machine_id = ["/var/lib/dbus/machine-id", "/etc/machine-id"]
+        .iter()
+        .find_map(|path| std::fs::read_to_string(path).ok())
+        .map(|id| id.trim().to_string())
+        .ok_or("no machine-id")?;
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".config")))
+        .ok_or("no config directory")?;
+    let bus_dir = config_dir.join("ibus").join("bus");
+
+    let wayland_display = std::env::var("WAYLAND_DISPLAY").ok();
+    let display = std::env::var("DISPLAY").ok();
+    for suffix in socket_suffixes(wayland_display.as_deref(), display.as_deref()) {
+        let path = bus_dir.join(format!("{}-{}", machine_id, suffix));
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let Some((address, pid)) = parse_address_file(&content) else {
+            continue;
+        };
+        // The file outlives a daemon that was killed
+        if pid.is_some_and(|pid| !std::path::Path::new(&format!("/proc/{}", pid)).exists()) {
+            continue;
+        }
+        return Ok(address);
+    }
+    Err("ibus-daemon is not running".to_string())
+}
+
+/// An IBusSerializable value: type name, empty attachments, then `fields`
+fn serializable(type_name: &str, fields: Vec<Value<'static>>) -> Value<'static> {
+    let mut builder = StructureBuilder::new()
+        .add_field(type_name.to_string())
+        .add_field(HashMap::<String, Value<'static>>::new());
+    for field in fields {
+        builder = builder.append_field(field);
+    }
+    Value::from(builder.build())
+}
+
+/// IBusText with no attributes
+fn ibus_text(text: &str) -> Value<'static> {
+    let attributes = serializable("IBusAttrList", vec![Value::from(Vec::<Value>::new())]);
+    serializable(
+        "IBusText",
+        vec![
+            Value::from(text.to_string()),
+            Value::Value(Box::new(attributes)),
+        ],
+    )
+}
+
+/// IBusComponent describing the voxtype engine
+fn component() -> Value<'static> {
+    let s = |v: &str| Value::from(v.to_string());
+    let version = env!("CARGO_PKG_VERSION");
+    let engine = serializable(
+        "IBusEngineDesc",
+        vec![
+            s(ENGINE_NAME),       // name
+            s("Voxtype"),         // longname
+            s("Voice dictation"), // description
+            s(""),                // language
+            s("MIT"),             // license
+            s("voxtype"),         // author
+            s(""),                // icon
+            s("default"),         // layout
+            Value::from(0u32),    // rank
+            s(""),                // hotkeys
+            s(""),                // symbol
+            s(""),                // setup
+            s(""),                // layout_variant
+            s(""),                // layout_option
+            s(version),           // version
+            s(""),                // textdomain
+            s(""),                // icon_prop_key
+        ],
+    );
+    let engines = Value::from(vec![Value::Value(Box::new(engine))]);
+    serializable(
+        "IBusComponent",
+        vec![
+            s("org.freedesktop.IBus.Voxtype"), // name
+            s("Voxtype dictation"),            // description
+            s(version),                        // version
+            s("MIT"),                          // license
+            s("voxtype"),                      // author
+            s(""),                             // homepage
+            s(""),                             // exec
+            s(""),                             // textdomain
+            Value::from(Vec::<Value>::new()),  // observed_paths
+            engines,
+        ],
+    )
+}
+
+/// Name of the engine in a (possibly variant-wrapped) IBusEngineDesc
+fn engine_name(value: &Value) -> Option<String> {
+    let mut value = value;
+    while let Value::Value(inner) = value {
+        value = inner;
+    }
+    match value {
+        Value::Structure(desc) => match desc.fields().get(2) {
+            Some(Value::Str(name)) if !name.is_empty() => Some(name.to_string()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+async fn ibus_call<B>(conn: &Connection, method: &str, body: &B) -> zbus::Result<zbus::Message>
+where
+    B: serde::Serialize + zbus::zvariant::DynamicType,
+{
+    conn.call_method(Some(IBUS), IBUS_PATH, Some(IBUS), method, body)
+        .await
+}
+
+/// The engine the focused context uses now
+async fn global_engine(conn: &Connection) -> Result<String, String> {
+    // IBus 1.5 exposes it as a property; older daemons only have the method
+    let reply = match conn
+        .call_method(
+            Some(IBUS),
+            IBUS_PATH,
+            Some("org.freedesktop.DBus.Properties"),
+            "Get",
+            &(IBUS, "GlobalEngine"),
+        )
+        .await
+    {
+        Ok(reply) => reply,
+        Err(_) => ibus_call(conn, "GetGlobalEngine", &())
+            .await
+            .map_err(|e| format!("cannot read the current engine: {}", e))?,
+    };
+    let value: OwnedValue = reply
+        .body()
+        .deserialize()
+        .map_err(|e| format!("cannot read the current engine: {}", e))?;
+    engine_name(&value).ok_or_else(|| "no input method engine is active".to_string())
+}
+
+async fn emit_engine_signal<B>(conn: &Connection, signal: &str, body: &B) -> zbus::Result<()>
+where
+    B: serde::Serialize + zbus::zvariant::DynamicType,
+{
+    conn.emit_signal(None::<BusName>, ENGINE_PATH, ENGINE_INTERFACE, signal, body)
+        .await
+}
+
+/// Await `fut`, giving up after `limit`
+async fn within<T>(
+    limit: Duration,
+    fut: impl std::future::Future<Output = Result<T, String>>,
+) -> Result<T, String> {
+    tokio::time::timeout(limit, fut)
+        .await
+        .unwrap_or_else(|_| Err("ibus-daemon did not respond in time".to_string()))
+}
+
+/// Engines in IBus's `preload-engines` setting, as printed by gsettings
+fn parse_preload_engines(output: &str) -> Vec<String> {
+    output
+        .trim()
+        .trim_start_matches("@as")
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(|name| name.trim().trim_matches('\'').to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// The engine to return to when an earlier output left the voxtype engine
+/// active: the one that output switched away from, or else the first
+/// configured engine, which IBus itself starts with
+async fn recovery_engine() -> Result<String, String> {
+    if let Some(engine) = SWITCHED_FROM
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+    {
+        return Ok(engine);
+    }
+    let output = Command::new("gsettings")
+        .args(["get", "org.freedesktop.ibus.general", "preload-engines"])
+        .output()
+        .await
+        .map_err(|e| format!("cannot read preload-engines: {}", e))?;
+    parse_preload_engines(&String::from_utf8_lossy(&output.stdout))
+        .into_iter()
+        .find(|name| name != ENGINE_NAME)
+        .ok_or_else(|| "no IBus engine to switch back to".to_string())
+}
+
+/// Connect to IBus, serve the voxtype engine and register it. Returns the
+/// connection, the engine to switch back to and the engine's ready signal.
+async fn prepare() -> Result<(Connection, String, oneshot::Receiver<()>), String> {
+    let address = ibus_address()?;
+    let conn = zbus::connection::Builder::address(address.as_str())
+        .map_err(|e| format!("IBus bus: {}", e))?
+        .build()
+        .await
+        .map_err(|e| format!("IBus bus: {}", e))?;
+
+    let mut previous = global_engine(&conn).await?;
+    if previous == ENGINE_NAME {
+        previous = recovery_engine().await?;
+        tracing::warn!(
+            "ime: the voxtype engine was left active by an earlier output, returning to {}",
+            previous
+        );
+    }
+
+    let (tx, rx) = oneshot::channel();
+    let served = async {
+        let server = conn.object_server();
+        server.at(FACTORY_PATH, Factory).await?;
+        server
+            .at(
+                ENGINE_PATH,
+                Engine {
+                    ready: Mutex::new(Some(tx)),
+                },
+            )
+            .await?;
+        server.at(ENGINE_PATH, Service).await
+    };
+    served.await.map_err(|e| format!("IBus bus: {}", e))?;
+
+    ibus_call(&conn, "RegisterComponent", &(component(),))
+        .await
+        .map_err(|e| format!("cannot register the voxtype engine: {}", e))?;
+    Ok((conn, previous, rx))
+}
+
+/// Commit `text` (and press Enter if `submit`) once the voxtype engine is
+/// active
+async fn deliver(
+    conn: &Connection,
+    ready: oneshot::Receiver<()>,
+    text: &str,
+    submit: bool,
+) -> Result<(), String> {
+    // IBus attaches the engine to the focused context before enabling it,
+    // so commit anyway if the notification is slow
+    let _ = tokio::time::timeout(ENGINE_READY_TIMEOUT, ready).await;
+
+    emit_engine_signal(conn, "CommitText", &(ibus_text(text),))
+        .await
+        .map_err(|e| format!("CommitText failed: {}", e))?;
+    if submit {
+        for state in [0, IBUS_RELEASE_MASK] {
+            emit_engine_signal(
+                conn,
+                "ForwardKeyEvent",
+                &(KEYSYM_RETURN, KEYCODE_RETURN, state),
+            )
+            .await
+            .map_err(|e| format!("ForwardKeyEvent failed: {}", e))?;
+        }
+    }
+    Ok(())
+}
+
+/// Switch IBus back to `previous`
+async fn switch_back(conn: &Connection, previous: &str) {
+    let restored = within(RESTORE_TIMEOUT, async {
+        ibus_call(conn, "SetGlobalEngine", &(previous,))
+            .await
+            .map_err(|e| e.to_string())
+    })
+    .await;
+    match restored {
+        Ok(_) => *SWITCHED_FROM.lock().unwrap_or_else(|e| e.into_inner()) = None,
+        Err(e) => tracing::warn!("ime: could not switch back to {}: {}", previous, e),
+    }
+}
+
+/// Switches IBus back to the previous engine: awaited through `run` on
+/// every return path, and spawned from `drop` if the output is cancelled
+struct SwitchBack {
+    conn: Connection,
+    previous: Option<String>,
+}
+
+impl SwitchBack {
+    async fn run(mut self) {
+        if let Some(previous) = self.previous.take() {
+            switch_back(&self.conn, &previous).await;
+        }
+    }
+}
+
+impl Drop for SwitchBack {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            let conn = self.conn.clone();
+            tokio::spawn(async move { switch_back(&conn, &previous).await });
+        }
+    }
+}
+
+/// Switch to the voxtype engine, commit `text` (and press Enter if
+/// `submit`), then switch back
+async fn commit(text: &str, submit: bool) -> Result<(), String> {
+    let (conn, previous, ready) = within(COMMIT_TIMEOUT, prepare()).await?;
+
+    // Once SetGlobalEngine is sent IBus may switch even if the reply never
+    // arrives, so from here on the switch back runs whatever happens
+    *SWITCHED_FROM.lock().unwrap_or_else(|e| e.into_inner()) = Some(previous.clone());
+    let guard = SwitchBack {
+        conn: conn.clone(),
+        previous: Some(previous),
+    };
+    let switched = within(COMMIT_TIMEOUT, async {
+        ibus_call(&conn, "SetGlobalEngine", &(ENGINE_NAME,))
+            .await
+            .map_err(|e| format!("cannot switch to the voxtype engine: {}", e))
+    })
+    .await;
+    let result = match switched {
+        Ok(_) => within(COMMIT_TIMEOUT, deliver(&conn, ready, text, submit)).await,
+        Err(e) => Err(e),
+    };
+
+    // Messages on one connection are handled in order, so the commit lands
+    // before the switch back
+    guard.run().await;
+    result
+}
+
+/// IBus input method based text output
+pub struct ImeOutput {
+    /// Delay before committing (ms)
+    pre_type_delay_ms: u32,
+    /// Whether to press Enter after the text
+    auto_submit: bool,
+    /// Text to append after transcription
+    append_text: Option<String>,
+}
+
+impl ImeOutput {
+    /// Create a new IBus output
+    pub fn new(pre_type_delay_ms: u32, auto_submit: bool, append_text: Option<String>) -> Self {
+        Self {
+            pre_type_delay_ms,
+            auto_submit,
+            append_text,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TextOutput for ImeOutput {
+    async fn output(&self, text: &str) -> Result<(), OutputError> {
+        if text.is_empty() {
+            return Ok(());
+        }
+
+        if self.pre_type_delay_ms > 0 {
+            tracing::debug!(
+                "ime: sleeping {}ms before committing",
+                self.pre_type_delay_ms
+            );
+            tokio::time::sleep(Duration::from_millis(self.pre_type_delay_ms as u64)).await;
+        }
+
+        let mut full_text = text.to_string();
+        if let Some(ref append) = self.append_text {
+            full_text.push_str(append);
+        }
+
+        tracing::debug!(
+            "ime: committing \"{}\"",
+            full_text.chars().take(20).collect::<String>()
+        );
+        commit(&full_text, self.auto_submit)
+            .await
+            .map_err(|e| OutputError::InjectionFailed(format!("ime: {}", e)))
+    }
+
+    async fn is_available(&self) -> bool {
+        match ibus_address() {
+            Ok(_) => true,
+            Err(e) => {
+                tracing::debug!("ime: {} (Fcitx5 is not supported)", e);
+                false
+            }
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "ime"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_socket_suffixes() {
+        assert_eq!(
+            socket_suffixes(Some("wayland-0"), Some(":0")),
+            vec!["unix-wayland-0", "unix-0"]
+        );
+        assert_eq!(socket_suffixes(None, Some(":1.0")), vec!["unix-1"]);
+        assert_eq!(socket_suffixes(None, Some("box:2")), vec!["box-2"]);
+        assert!(socket_suffixes(Some(""), None).is_empty());
+    }
+
+    #[test]
+    fn test_parse_address_file() {
+        let content = "# This file is created by ibus-daemon, please do not modify it.\n\
+                       IBUS_ADDRESS=unix:path=/run/user/1000/ibus/dbus-abc,guid=123\n\
+                       IBUS_DAEMON_PID=4242\n";
+        assert_eq!(
+            parse_address_file(content),
+            Some((
+                "unix:path=/run/user/1000/ibus/dbus-abc,guid=123".to_string(),
+                Some(4242)
+            ))
+        );
+        assert_eq!(parse_address_file("IBUS_ADDRESS=\n"), None);
+    }
+
+    #[test]
+    fn test_engine_name_from_desc() {
+        let desc = serializable(
+            "IBusEngineDesc",
+            vec![
+                Value::from("anthy".to_string()),
+                Value::from("Anthy".to_string()),
+            ],
+        );
+        assert_eq!(
+            engine_name(&Value::Value(Box::new(desc))),
+            Some("anthy".to_string())
+        );
+        assert_eq!(engine_name(&Value::from(1u32)), None);
+    }
+
+    #[test]
+    fn test_parse_preload_engines() {
+        assert_eq!(
+            parse_preload_engines("['xkb:us::eng', 'anthy']\n"),
+            vec!["xkb:us::eng", "anthy"]
+        );
+        assert!(parse_preload_engines("@as []\n").is_empty());
+    }
+
+    #[test]
+    fn test_serialized_signatures() {
+        assert_eq!(ibus_text("hi").value_signature(), "(sa{sv}sv)");
+        assert_eq!(component().value_signature(), "(sa{sv}ssssssssavav)");
+    }
+}
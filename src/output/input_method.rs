@@ -0,0 +1,205 @@
+//! Wayland input-method text output
+//!
+//! Commits text through the compositor's input method protocol
+//! (`zwp_input_method_v2`), the same path an on-screen keyboard or IME
+//! uses. The focused application receives the whole string at once through
+//! `text-input-v3`, so nothing depends on keymaps, held modifiers or key
+//! timing.
+//!
+//! Only works when:
+//! - voxtype is built with the `wayland-im` feature
+//! - the compositor supports `zwp_input_method_v2` (Sway, Hyprland, river
+//!   and other wlroots compositors)
+//! - no other input method (fcitx5, IBus) is bound to the seat
+//! - the focused text field supports `text-input-v3` (GTK, Qt, Chromium
+//!   and Firefox do)
+//!
+//! Otherwise it reports a failure and the fallback chain moves on to the
+//! keystroke drivers.
+
+use super::TextOutput;
+use crate::error::OutputError;
+use std::time::Duration;
+
+#[cfg(feature = "wayland-im")]
+mod imp {
+    use wayland_client::globals::{registry_queue_init, GlobalListContents};
+    use wayland_client::protocol::{wl_registry, wl_seat};
+    use wayland_client::{delegate_noop, Connection, Dispatch, QueueHandle};
+    use wayland_protocols_misc::zwp_input_method_v2::client::{
+        zwp_input_method_manager_v2::ZwpInputMethodManagerV2,
+        zwp_input_method_v2::{self, ZwpInputMethodV2},
+    };
+
+    /// Input method state as sent by the compositor. Activate and
+    /// deactivate only take effect with the following `done`.
+    #[derive(Default)]
+    struct State {
+        pending_active: bool,
+        active: bool,
+        /// Number of `done` events, echoed back in `commit`
+        serial: u32,
+        unavailable: bool,
+    }
+
+    impl Dispatch<wl_registry::WlRegistry, GlobalListContents> for State {
+        fn event(
+            _: &mut Self,
+            _: &wl_registry::WlRegistry,
+            _: wl_registry::Event,
+            _: &GlobalListContents,
+            _: &Connection,
+            _: &QueueHandle<Self>,
+        ) {
+        }
+    }
+
+    impl Dispatch<ZwpInputMethodV2, ()> for State {
+        fn event(
+            state: &mut Self,
+            _: &ZwpInputMethodV2,
+            event: zwp_input_method_v2::Event,
+            _: &(),
+            _: &Connection,
+            _: &QueueHandle<Self>,
+        ) {
+            match event {
+                zwp_input_method_v2::Event::Activate => state.pending_active = true,
+                zwp_input_method_v2::Event::Deactivate => state.pending_active = false,
+                zwp_input_method_v2::Event::Done => {
+                    state.serial += 1;
+                    state.active = state.pending_active;
+                }
+                zwp_input_method_v2::Event::Unavailable => state.unavailable = true,
+                _ => {}
+            }
+        }
+    }
+
+    delegate_noop!(State: ignore wl_seat::WlSeat);
+    delegate_noop!(State: ZwpInputMethodManagerV2);
+
+    /// Commit `text` to the focused text field. Blocking.
+    pub fn commit(text: &str) -> Result<(), String> {
+        let conn = Connection::connect_to_env()
+            .map_err(|e| format!("cannot connect to Wayland: {}", e))?;
+        let (globals, mut queue) = registry_queue_init::<State>(&conn)
+            .map_err(|e| format!("cannot read Wayland globals: {}", e))?;
+        let qh = queue.handle();
+
+        let seat: wl_seat::WlSeat = globals
+            .bind(&qh, 1..=7, ())
+            .map_err(|e| format!("no seat: {}", e))?;
+        let manager: ZwpInputMethodManagerV2 = globals
+            .bind(&qh, 1..=1, ())
+            .map_err(|_| "compositor does not support zwp_input_method_v2".to_string())?;
+        let input_method = manager.get_input_method(&seat, &qh, ());
+
+        // The compositor activates a new input method right away when a
+        // text field with text-input enabled has focus
+        let mut state = State::default();
+        for _ in 0..2 {
+            queue
+                .roundtrip(&mut state)
+                .map_err(|e| format!("Wayland roundtrip failed: {}", e))?;
+        }
+
+        let result = if state.unavailable {
+            Err("another input method is already running".to_string())
+        } else if !state.active {
+            Err("focused window has no text field with text-input support".to_string())
+        } else {
+            input_method.commit_string(text.to_string());
+            input_method.commit(state.serial);
+            queue
+                .roundtrip(&mut state)
+                .map(|_| ())
+                .map_err(|e| format!("Wayland roundtrip failed: {}", e))
+        };
+
+        input_method.destroy();
+        manager.destroy();
+        let _ = conn.flush();
+        result
+    }
+}
+
+/// Wayland input-method output
+pub struct InputMethodOutput {
+    /// Delay before committing (ms)
+    pre_type_delay_ms: u32,
+    /// Whether Enter should follow the text; an input method can't press keys
+    auto_submit: bool,
+    /// Text to append after transcription
+    append_text: Option<String>,
+}
+
+impl InputMethodOutput {
+    /// Create a new input-method output
+    pub fn new(pre_type_delay_ms: u32, auto_submit: bool, append_text: Option<String>) -> Self {
+        Self {
+            pre_type_delay_ms,
+            auto_submit,
+            append_text,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TextOutput for InputMethodOutput {
+    async fn output(&self, text: &str) -> Result<(), OutputError> {
+        if text.is_empty() {
+            return Ok(());
+        }
+
+        if self.pre_type_delay_ms > 0 {
+            tracing::debug!(
+                "input-method: sleeping {}ms before committing",
+                self.pre_type_delay_ms
+            );
+            tokio::time::sleep(Duration::from_millis(self.pre_type_delay_ms as u64)).await;
+        }
+
+        let mut full_text = text.to_string();
+        if let Some(ref append) = self.append_text {
+            full_text.push_str(append);
+        }
+
+        tracing::debug!(
+            "input-method: committing \"{}\"",
+            full_text.chars().take(20).collect::<String>()
+        );
+
+        #[cfg(feature = "wayland-im")]
+        {
+            tokio::task::spawn_blocking(move || imp::commit(&full_text))
+                .await
+                .map_err(|e| OutputError::InjectionFailed(format!("input-method: {}", e)))?
+                .map_err(|e| OutputError::InjectionFailed(format!("input-method: {}", e)))
+        }
+        #[cfg(not(feature = "wayland-im"))]
+        {
+            drop(full_text);
+            Err(OutputError::InjectionFailed(
+                "input-method: voxtype was built without the wayland-im feature".to_string(),
+            ))
+        }
+    }
+
+    async fn is_available(&self) -> bool {
+        if !cfg!(feature = "wayland-im") {
+            tracing::debug!("input-method: not compiled in (build with --features wayland-im)");
+            return false;
+        }
+        // Submitting needs a real Enter key press
+        if self.auto_submit {
+            tracing::debug!("input-method: auto_submit needs a keystroke driver");
+            return false;
+        }
+        std::env::var_os("WAYLAND_DISPLAY").is_some_and(|display| !display.is_empty())
+    }
+
+    fn name(&self) -> &'static str {
+        "input-method"
+    }
+}
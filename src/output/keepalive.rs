@@ -0,0 +1,318 @@
+//! Long-lived dotool process and ydotoold connection
+//!
+//! Spawning a typing tool per output adds noticeable delay to short
+//! dictations: `dotool` sets up a new uinput keyboard (~700ms) every time it
+//! starts, and the ydotool driver runs one `ydotool` client for the text,
+//! one for `append_text` and one for Enter. With `persistent_drivers` (the
+//! default) the output module keeps:
+//!
+//! - one `dotool` process, fed commands over its stdin. It is restarted
+//!   when it has exited or the XKB layout hint changes.
+//! - one datagram socket to ydotoold, sending the key events the `ydotool`
+//!   client would send. It reconnects when ydotoold has been restarted.
+//!
+//! Each is checked before use. If the connection can't be made, the driver
+//! falls back to spawning the tool as before.
+
+use crate::error::OutputError;
+use std::process::Stdio;
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::process::{Child, ChildStdin, Command};
+use tokio::sync::Mutex;
+
+/// Time for a new dotool to fail on a missing uinput permission or bad
+/// layout. Its keyboard setup takes longer, but commands written meanwhile
+/// are buffered.
+const DOTOOL_STARTUP_CHECK: Duration = Duration::from_millis(50);
+
+/// XKB hints a dotool process was started with
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DotoolEnv {
+    pub xkb_layout: Option<String>,
+    pub xkb_variant: Option<String>,
+}
+
+struct DotoolProcess {
+    child: Child,
+    stdin: ChildStdin,
+    env: DotoolEnv,
+}
+
+fn dotool_slot() -> &'static Mutex<Option<DotoolProcess>> {
+    static DOTOOL: OnceLock<Mutex<Option<DotoolProcess>>> = OnceLock::new();
+    DOTOOL.get_or_init(|| Mutex::new(None))
+}
+
+/// Start `dotool` reading commands from a pipe
+async fn spawn_dotool(env: &DotoolEnv) -> Result<DotoolProcess, OutputError> {
+    let mut cmd = Command::new("dotool");
+    cmd.stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped());
+    if let Some(ref layout) = env.xkb_layout {
+        cmd.env("DOTOOL_XKB_LAYOUT", layout);
+        cmd.env("XKB_DEFAULT_LAYOUT", layout);
+    }
+    if let Some(ref variant) = env.xkb_variant {
+        cmd.env("DOTOOL_XKB_VARIANT", variant);
+        cmd.env("XKB_DEFAULT_VARIANT", variant);
+    }
+
+    let mut child = cmd.spawn().map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound {
+            OutputError::DotoolNotFound
+        } else {
+            OutputError::InjectionFailed(format!("Failed to spawn dotool: {}", e))
+        }
+    })?;
+    let stdin = child
+        .stdin
+        .take()
+        .ok_or_else(|| OutputError::InjectionFailed("dotool: no stdin".to_string()))?;
+
+    tokio::time::sleep(DOTOOL_STARTUP_CHECK).await;
+    if let Ok(Some(_)) = child.try_wait() {
+        let output = child.wait_with_output().await.ok();
+        let stderr = output
+            .map(|o| String::from_utf8_lossy(&o.stderr).trim().to_string())
+            .unwrap_or_default();
+        if stderr.contains("uinput") || stderr.contains("permission") {
+            return Err(OutputError::InjectionFailed(
+                "dotool: uinput permission denied. Is user in 'input' group?".to_string(),
+            ));
+        }
+        return Err(OutputError::InjectionFailed(format!(
+            "dotool exited on startup: {}",
+            stderr
+        )));
+    }
+
+    tracing::debug!("dotool: started persistent process");
+    Ok(DotoolProcess {
+        child,
+        stdin,
+        env: env.clone(),
+    })
+}
+
+/// Send a dotool script to the persistent dotool, starting or restarting it
+/// as needed. Returns once the script is written; dotool types it
+/// asynchronously.
+pub async fn dotool_write(script: &str, env: &DotoolEnv) -> Result<(), OutputError> {
+    let mut slot = dotool_slot().lock().await;
+
+    // Health check: drop a process that has exited or has other XKB hints
+    if let Some(process) = slot.as_mut() {
+        if process.env != *env {
+            tracing::debug!("dotool: XKB hint changed, restarting");
+            *slot = None;
+        } else if !matches!(process.child.try_wait(), Ok(None)) {
+            tracing::debug!("dotool: persistent process exited, restarting");
+            *slot = None;
+        }
+    }
+
+    for attempt in 0..2 {
+        let process = match slot.as_mut() {
+            Some(process) => process,
+            None => slot.insert(spawn_dotool(env).await?),
+        };
+        let written = async {
+            process.stdin.write_all(script.as_bytes()).await?;
+            process.stdin.flush().await
+        }
+        .await;
+        match written {
+            Ok(()) => return Ok(()),
+            Err(e) => {
+                // Broken pipe: dotool went away between the check and the write
+                tracing::debug!("dotool: write failed (attempt {}): {}", attempt + 1, e);
+                *slot = None;
+            }
+        }
+    }
+    Err(OutputError::InjectionFailed(
+        "dotool: persistent process keeps exiting".to_string(),
+    ))
+}
+
+/// Key events over a persistent ydotoold connection
+#[cfg(target_os = "linux")]
+pub mod ydotool {
+    use super::super::uinput::{strokes, Layout, Stroke};
+    use crate::error::OutputError;
+    use evdev::Key;
+    use std::os::unix::net::UnixDatagram;
+    use std::sync::OnceLock;
+    use std::time::Duration;
+    use tokio::sync::Mutex;
+
+    const EV_SYN: u16 = 0;
+    const EV_KEY: u16 = 1;
+    const SYN_REPORT: u16 = 0;
+
+    /// Default key delay of `ydotool type`
+    const DEFAULT_KEY_DELAY_MS: u32 = 12;
+
+    fn socket_slot() -> &'static Mutex<Option<UnixDatagram>> {
+        static SOCKET: OnceLock<Mutex<Option<UnixDatagram>>> = OnceLock::new();
+        SOCKET.get_or_init(|| Mutex::new(None))
+    }
+
+    fn connect() -> std::io::Result<UnixDatagram> {
+        let path = crate::output::find_ydotool_socket().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, "no ydotoold socket")
+        })?;
+        let socket = UnixDatagram::unbound()?;
+        socket.connect(&path)?;
+        tracing::debug!("ydotool: connected to {}", path.display());
+        Ok(socket)
+    }
+
+    /// One `struct input_event` as ydotoold expects it
+    fn event(kind: u16, code: u16, value: i32) -> libc::input_event {
+        libc::input_event {
+            time: libc::timeval {
+                tv_sec: 0,
+                tv_usec: 0,
+            },
+            type_: kind,
+            code,
+            value,
+        }
+    }
+
+    fn send(socket: &UnixDatagram, key: Key, value: i32) -> std::io::Result<()> {
+        for ev in [
+            event(EV_KEY, key.code(), value),
+            event(EV_SYN, SYN_REPORT, 0),
+        ] {
+            // SAFETY: input_event is a plain C struct; ydotoold reads the
+            // same bytes back into one.
+            let bytes = unsafe {
+                std::slice::from_raw_parts(
+                    &ev as *const libc::input_event as *const u8,
+                    std::mem::size_of::<libc::input_event>(),
+                )
+            };
+            socket.send(bytes)?;
+        }
+        Ok(())
+    }
+
+    fn press(socket: &UnixDatagram, stroke: Stroke) -> std::io::Result<()> {
+        if stroke.shift {
+            send(socket, Key::KEY_LEFTSHIFT, 1)?;
+        }
+        send(socket, stroke.key, 1)
+    }
+
+    fn release(socket: &UnixDatagram, stroke: Stroke) -> std::io::Result<()> {
+        send(socket, stroke.key, 0)?;
+        if stroke.shift {
+            send(socket, Key::KEY_LEFTSHIFT, 0)?;
+        }
+        Ok(())
+    }
+
+    /// Health check: (re)connect to ydotoold. A datagram socket can't tell
+    /// that ydotoold restarted until a send fails, so this always connects
+    /// afresh.
+    pub async fn check() -> bool {
+        let mut slot = socket_slot().lock().await;
+        *slot = connect().ok();
+        slot.is_some()
+    }
+
+    /// Keys for `text` on the US layout `ydotool type` assumes, or `None`
+    /// when it has a character the table doesn't cover
+    pub fn keys(text: &str, auto_submit: bool) -> Option<Vec<Stroke>> {
+        let mut keys = strokes(text, Layout::Us, false).ok()?;
+        if auto_submit {
+            keys.push(Stroke {
+                key: Key::KEY_ENTER,
+                shift: false,
+                altgr: false,
+            });
+        }
+        Some(keys)
+    }
+
+    /// Type `keys` through ydotoold, connecting or reconnecting as needed
+    pub async fn type_keys(keys: &[Stroke], type_delay_ms: u32) -> Result<(), OutputError> {
+        let mut slot = socket_slot().lock().await;
+        let delay = Duration::from_millis(if type_delay_ms > 0 {
+            type_delay_ms as u64
+        } else {
+            DEFAULT_KEY_DELAY_MS as u64
+        });
+
+        let mut typed = 0;
+        let mut reconnected = false;
+        while typed < keys.len() {
+            let socket = match slot.as_ref() {
+                Some(socket) => socket,
+                None => slot.insert(connect().map_err(|e| {
+                    tracing::debug!("ydotool: can't connect to ydotoold: {}", e);
+                    OutputError::YdotoolNotRunning
+                })?),
+            };
+
+            let stroke = keys[typed];
+            let pressed = press(socket, stroke);
+            if pressed.is_ok() {
+                tokio::time::sleep(delay).await;
+            }
+            let socket = slot.as_ref().expect("connected above");
+            match pressed.and_then(|()| release(socket, stroke)) {
+                Ok(()) => {
+                    typed += 1;
+                    tokio::time::sleep(delay).await;
+                }
+                // ydotoold restarted: its socket is new. Reconnect once,
+                // but only before anything was typed, so nothing repeats.
+                Err(e) if typed == 0 && !reconnected => {
+                    tracing::debug!("ydotool: send failed, reconnecting: {}", e);
+                    *slot = None;
+                    reconnected = true;
+                }
+                Err(e) => {
+                    *slot = None;
+                    return Err(if typed == 0 {
+                        OutputError::YdotoolNotRunning
+                    } else {
+                        OutputError::InjectionFailed(format!(
+                            "ydotool: connection lost after {} keys: {}",
+                            typed, e
+                        ))
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::ydotool::keys;
+    use evdev::Key;
+
+    #[test]
+    fn test_ydotool_keys() {
+        let strokes = keys("Hi", true).unwrap();
+        let codes: Vec<(Key, bool)> = strokes.iter().map(|k| (k.key, k.shift)).collect();
+        assert_eq!(
+            codes,
+            vec![
+                (Key::KEY_H, true),
+                (Key::KEY_I, false),
+                (Key::KEY_ENTER, false)
+            ]
+        );
+        // Outside the US table: left to the ydotool client
+        assert!(keys("naïve", false).is_none());
+    }
+}
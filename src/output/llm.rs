@@ -0,0 +1,402 @@
+//! LLM post-processing without a shell command
+//!
+//! Sends dictation to a language model that stays loaded between
+//! transcriptions, instead of forking `sh -c "ollama run ..."` each time:
+//!
+//! - `backend = "ollama"`: Ollama's `/api/chat` endpoint with the prompt as
+//!   the system message and a JSON schema for the reply, so the model can't
+//!   wrap the text in "Here is the cleaned up version:". `keep_alive` keeps
+//!   the model resident.
+//! - `backend = "llama-cpp"`: a GGUF model loaded into the daemon with
+//!   llama.cpp (requires the `llm-postprocess` feature).
+//!
+//! # Example Configuration
+//!
+//! ```toml
+//! [output.llm]
+//! backend = "llama-cpp"
+//! model = "~/.local/share/voxtype/models/qwen2.5-0.5b-instruct-q4_k_m.gguf"
+//! ```
+
+use crate::config::{LlmBackend, LlmConfig};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Instructions used when `[output.llm] prompt` is not set
+pub const DEFAULT_PROMPT: &str = "You clean up dictated text. Fix punctuation, capitalization \
+and obvious transcription errors, and remove filler words (um, uh). Keep the wording, \
+meaning and language. Reply with only the cleaned text.";
+
+/// How long Ollama keeps the model loaded after a request
+const OLLAMA_KEEP_ALIVE: &str = "30m";
+
+/// Language model client for post-processing
+pub struct LlmClient {
+    /// Shared by clients made with [`LlmClient::with_prompt`], so a
+    /// llama.cpp model is loaded once
+    backend: Arc<Backend>,
+    prompt: String,
+    max_tokens: u32,
+}
+
+enum Backend {
+    Ollama {
+        url: String,
+        model: String,
+        timeout: Duration,
+    },
+    #[cfg(feature = "llm-postprocess")]
+    LlamaCpp(llama::LlamaEngine),
+}
+
+impl LlmClient {
+    /// Create a client from configuration. For llama.cpp this loads the
+    /// model, which can take a few seconds.
+    pub fn new(config: &LlmConfig) -> Result<Self, LlmError> {
+        let backend = match config.backend {
+            LlmBackend::Ollama => Backend::Ollama {
+                url: config.ollama_url.trim_end_matches('/').to_string(),
+                model: config.model.clone(),
+                timeout: Duration::from_millis(config.timeout_ms),
+            },
+            #[cfg(feature = "llm-postprocess")]
+            LlmBackend::LlamaCpp => {
+                Backend::LlamaCpp(llama::LlamaEngine::load(&config.model_path())?)
+            }
+            #[cfg(not(feature = "llm-postprocess"))]
+            LlmBackend::LlamaCpp => return Err(LlmError::NotCompiled),
+        };
+        Ok(Self {
+            backend: Arc::new(backend),
+            prompt: config
+                .prompt
+                .clone()
+                .unwrap_or_else(|| DEFAULT_PROMPT.to_string()),
+            max_tokens: config.max_tokens,
+        })
+    }
+
+    /// A client on the same backend and model with a different prompt
+    pub fn with_prompt(&self, prompt: &str) -> Self {
+        Self {
+            backend: Arc::clone(&self.backend),
+            prompt: prompt.to_string(),
+            max_tokens: self.max_tokens,
+        }
+    }
+
+    /// Clean `text`, with the previous dictation as optional context.
+    /// Blocks until the model has answered.
+    pub fn complete(&self, text: &str, context: Option<&str>) -> Result<String, LlmError> {
+        let message = user_message(text, context);
+        let reply = match self.backend.as_ref() {
+            Backend::Ollama {
+                url,
+                model,
+                timeout,
+            } => ollama_chat(
+                url,
+                model,
+                *timeout,
+                &self.prompt,
+                &message,
+                self.max_tokens,
+            )?,
+            #[cfg(feature = "llm-postprocess")]
+            Backend::LlamaCpp(engine) => {
+                engine.generate(&self.prompt, &message, self.max_tokens)?
+            }
+        };
+        Ok(clean_reply(&reply))
+    }
+}
+
+/// User message for `text`. Context goes first and is marked as such, so
+/// the model continues it instead of repeating it.
+fn user_message(text: &str, context: Option<&str>) -> String {
+    match context {
+        Some(ctx) if !ctx.trim().is_empty() => format!(
+            "Previous dictation, for context only (do not repeat it):\n{}\n\nDictation to clean up:\n{}",
+            ctx.trim(),
+            text
+        ),
+        _ => text.to_string(),
+    }
+}
+
+/// The text from a model reply: the `text` field when the reply is the
+/// structured JSON, otherwise the reply without surrounding quotes
+fn clean_reply(reply: &str) -> String {
+    #[derive(Deserialize)]
+    struct Structured {
+        text: String,
+    }
+
+    let reply = reply.trim();
+    if let Ok(structured) = serde_json::from_str::<Structured>(reply) {
+        return structured.text.trim().to_string();
+    }
+    reply
+        .strip_prefix('"')
+        .and_then(|r| r.strip_suffix('"'))
+        .unwrap_or(reply)
+        .trim()
+        .to_string()
+}
+
+fn ollama_chat(
+    url: &str,
+    model: &str,
+    timeout: Duration,
+    system: &str,
+    message: &str,
+    max_tokens: u32,
+) -> Result<String, LlmError> {
+    #[derive(Serialize)]
+    struct ChatMessage<'a> {
+        role: &'a str,
+        content: &'a str,
+    }
+
+    #[derive(Serialize)]
+    struct ChatRequest<'a> {
+        model: &'a str,
+        messages: [ChatMessage<'a>; 2],
+        stream: bool,
+        format: serde_json::Value,
+        keep_alive: &'a str,
+        options: serde_json::Value,
+    }
+
+    #[derive(Deserialize)]
+    struct ChatResponse {
+        message: ResponseMessage,
+    }
+
+    #[derive(Deserialize)]
+    struct ResponseMessage {
+        content: String,
+    }
+
+    let request = ChatRequest {
+        model,
+        messages: [
+            ChatMessage {
+                role: "system",
+                content: system,
+            },
+            ChatMessage {
+                role: "user",
+                content: message,
+            },
+        ],
+        stream: false,
+        format: serde_json::json!({
+            "type": "object",
+            "properties": { "text": { "type": "string" } },
+            "required": ["text"]
+        }),
+        keep_alive: OLLAMA_KEEP_ALIVE,
+        options: serde_json::json!({ "temperature": 0, "num_predict": max_tokens }),
+    };
+
+    let chat_url = format!("{}/api/chat", url);
+    let client = crate::network::agent_builder(url).timeout(timeout).build();
+    let response: ChatResponse = client
+        .post(&chat_url)
+        .send_json(&request)
+        .map_err(|e| LlmError::Request(format!("{}: {}", chat_url, e)))?
+        .into_json()
+        .map_err(|e| LlmError::Request(format!("invalid Ollama response: {}", e)))?;
+    Ok(response.message.content)
+}
+
+#[cfg(feature = "llm-postprocess")]
+mod llama {
+    //! In-process inference with llama.cpp
+
+    use super::LlmError;
+    use llama_cpp_2::context::params::LlamaContextParams;
+    use llama_cpp_2::llama_backend::LlamaBackend;
+    use llama_cpp_2::llama_batch::LlamaBatch;
+    use llama_cpp_2::model::params::LlamaModelParams;
+    use llama_cpp_2::model::{AddBos, LlamaChatMessage, LlamaModel, Special};
+    use llama_cpp_2::sampling::LlamaSampler;
+    use std::num::NonZeroU32;
+    use std::path::Path;
+
+    /// A loaded GGUF model. A fresh context is created per request, so
+    /// dictations never see each other's KV cache.
+    pub struct LlamaEngine {
+        backend: LlamaBackend,
+        model: LlamaModel,
+    }
+
+    fn model_error(e: impl std::fmt::Display) -> LlmError {
+        LlmError::Model(e.to_string())
+    }
+
+    impl LlamaEngine {
+        pub fn load(path: &Path) -> Result<Self, LlmError> {
+            if !path.exists() {
+                return Err(LlmError::Model(format!(
+                    "model file not found: {}",
+                    path.display()
+                )));
+            }
+            let mut backend = LlamaBackend::init().map_err(model_error)?;
+            backend.void_logs();
+            let model = LlamaModel::load_from_file(&backend, path, &LlamaModelParams::default())
+                .map_err(model_error)?;
+            tracing::info!("Loaded LLM post-processing model {:?}", path);
+            Ok(Self { backend, model })
+        }
+
+        pub fn generate(
+            &self,
+            system: &str,
+            message: &str,
+            max_tokens: u32,
+        ) -> Result<String, LlmError> {
+            let template = self.model.chat_template(None).map_err(model_error)?;
+            let messages = [
+                LlamaChatMessage::new("system".to_string(), system.to_string())
+                    .map_err(model_error)?,
+                LlamaChatMessage::new("user".to_string(), message.to_string())
+                    .map_err(model_error)?,
+            ];
+            let prompt = self
+                .model
+                .apply_chat_template(&template, &messages, true)
+                .map_err(model_error)?;
+            let tokens = self
+                .model
+                .str_to_token(&prompt, AddBos::Always)
+                .map_err(model_error)?;
+
+            let n_ctx = tokens.len() as u32 + max_tokens;
+            let ctx_params = LlamaContextParams::default().with_n_ctx(NonZeroU32::new(n_ctx));
+            let mut ctx = self
+                .model
+                .new_context(&self.backend, ctx_params)
+                .map_err(model_error)?;
+
+            let mut batch = LlamaBatch::new(tokens.len().max(1), 1);
+            let last = tokens.len() as i32 - 1;
+            for (pos, token) in (0_i32..).zip(tokens) {
+                batch
+                    .add(token, pos, &[0], pos == last)
+                    .map_err(model_error)?;
+            }
+            ctx.decode(&mut batch).map_err(model_error)?;
+
+            // Greedy decoding: cleanup should be deterministic
+            let mut sampler = LlamaSampler::greedy();
+            let mut position = batch.n_tokens();
+            let mut output = Vec::new();
+            for _ in 0..max_tokens {
+                let token = sampler.sample(&ctx, batch.n_tokens() - 1);
+                sampler.accept(token);
+                if self.model.is_eog_token(token) {
+                    break;
+                }
+                output.extend(
+                    self.model
+                        .token_to_bytes(token, Special::Plaintext)
+                        .map_err(model_error)?,
+                );
+                batch.clear();
+                batch
+                    .add(token, position, &[0], true)
+                    .map_err(model_error)?;
+                position += 1;
+                ctx.decode(&mut batch).map_err(model_error)?;
+            }
+            Ok(String::from_utf8_lossy(&output).into_owned())
+        }
+    }
+}
+
+/// Errors from LLM post-processing
+#[derive(Debug)]
+pub enum LlmError {
+    /// `backend = "llama-cpp"` in a build without the feature
+    NotCompiled,
+    /// The Ollama request failed
+    Request(String),
+    /// Loading or running the llama.cpp model failed
+    Model(String),
+}
+
+impl std::fmt::Display for LlmError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotCompiled => write!(
+                f,
+                "backend \"llama-cpp\" requires voxtype built with --features llm-postprocess"
+            ),
+            Self::Request(e) => write!(f, "LLM request failed: {}", e),
+            Self::Model(e) => write!(f, "LLM model error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for LlmError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_reply() {
+        assert_eq!(
+            clean_reply(r#"{"text": " Hello, world. "}"#),
+            "Hello, world."
+        );
+        assert_eq!(clean_reply("\"Hello.\"\n"), "Hello.");
+        assert_eq!(clean_reply("Plain reply"), "Plain reply");
+    }
+
+    #[test]
+    fn test_user_message_context() {
+        assert_eq!(user_message("hi there", None), "hi there");
+        assert_eq!(user_message("hi there", Some("  ")), "hi there");
+        let message = user_message("second part", Some("First part."));
+        assert!(message.contains("First part.\n\nDictation to clean up:\nsecond part"));
+    }
+
+    #[test]
+    fn test_ollama_unreachable_is_an_error() {
+        let config = LlmConfig {
+            backend: LlmBackend::Ollama,
+            model: "llama3.2:1b".to_string(),
+            ollama_url: "http://127.0.0.1:9/".to_string(),
+            prompt: None,
+            max_tokens: 16,
+            timeout_ms: 1000,
+        };
+        let client = LlmClient::new(&config).unwrap();
+        assert!(matches!(
+            client.complete("hello", None),
+            Err(LlmError::Request(_))
+        ));
+    }
+
+    #[cfg(not(feature = "llm-postprocess"))]
+    #[test]
+    fn test_llama_cpp_requires_feature() {
+        let config = LlmConfig {
+            backend: LlmBackend::LlamaCpp,
+            model: "model.gguf".to_string(),
+            ollama_url: "http://localhost:11434".to_string(),
+            prompt: None,
+            max_tokens: 16,
+            timeout_ms: 1000,
+        };
+        assert!(matches!(
+            LlmClient::new(&config),
+            Err(LlmError::NotCompiled)
+        ));
+    }
+}
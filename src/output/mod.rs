@@ -5,12 +5,16 @@
 //! Fallback chain for `mode = "type"`:
 //!
 //! Linux:
-//! 1. wtype - Wayland-native via virtual-keyboard protocol, best Unicode/CJK support, no daemon needed
-//! 2. eitype - Wayland via libei/EI protocol, works on GNOME/KDE (no virtual-keyboard support)
-//! 3. dotool - Works on X11/Wayland/TTY, supports keyboard layouts, no daemon needed
-//! 4. ydotool - Works on X11/Wayland/TTY, requires daemon
-//! 5. clipboard (wl-copy) - Wayland clipboard fallback
-//! 6. xclip - X11 clipboard fallback
+//! 1. input-method - Wayland input method protocol, commits text without keystrokes
+//!    (wlroots compositors, needs the `wayland-im` build feature)
+//! 2. wtype - Wayland-native via virtual-keyboard protocol, best Unicode/CJK support, no daemon needed
+//! 3. eitype - Wayland via libei/EI protocol, works on GNOME/KDE (no virtual-keyboard support)
+//! 4. xdotool - X11 via XTEST, uses the active X keymap, X11 sessions only
+//! 5. dotool - Works on X11/Wayland/TTY, supports keyboard layouts, no daemon needed
+//! 6. ydotool - Works on X11/Wayland/TTY, requires daemon
+//! 7. uinput - Built-in virtual keyboard, no external binary, needs /dev/uinput access
+//! 8. clipboard (wl-copy) - Wayland clipboard fallback
+//! 9. xclip - X11 clipboard fallback
 //!
 //! macOS:
 //! 1. cgevent - Native CGEvent API for keyboard simulation (best performance)
@@ -19,24 +23,47 @@
 //!
 //! Paste mode (clipboard + Ctrl+V) helps with system with non US keyboard layouts.
 
+#[cfg(target_os = "linux")]
+pub mod atspi;
+#[cfg(not(target_os = "macos"))]
+pub mod auto_driver;
 #[cfg(target_os = "macos")]
 pub mod cgevent;
+pub mod chunking;
 pub mod clipboard;
+pub mod clipboard_snapshot;
+pub mod confirm;
+#[cfg(not(target_os = "macos"))]
+pub mod diagnose;
 pub mod dotool;
 pub mod eitype;
+pub mod file;
+#[cfg(target_os = "linux")]
+pub mod ime;
+pub mod input_method;
+mod keepalive;
+pub mod llm;
 // modifier_guard is evdev-based; macOS has its own osascript modifier handling.
 #[cfg(target_os = "linux")]
 pub mod modifier_guard;
 #[cfg(target_os = "macos")]
 pub mod osascript;
+pub mod osc52;
+pub mod pacing;
 pub mod paste;
 #[cfg(target_os = "macos")]
 pub mod pbcopy;
 pub mod post_process;
+pub mod prompt_templates;
 pub mod session;
 pub mod streaming;
+pub mod tmux;
+#[cfg(target_os = "linux")]
+pub mod uinput;
+pub mod webhook;
 pub mod wtype;
 pub mod xclip;
+pub mod xdotool;
 pub mod ydotool;
 
 pub use streaming::StreamingSession;
@@ -48,8 +75,13 @@ use std::fs;
 use std::os::unix::fs::FileTypeExt;
 use std::path::PathBuf;
 use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::process::Command;
 
+/// Whether the last successful `output_with_fallback` put its text at the
+/// cursor (typed or pasted) rather than only on the clipboard
+static LAST_OUTPUT_AT_CURSOR: AtomicBool = AtomicBool::new(false);
+
 /// Find the ydotool daemon socket by checking known locations.
 ///
 /// Fedora places the socket at `/tmp/.ydotool_socket`, while the ydotool CLI
@@ -179,6 +211,8 @@ pub fn engine_icon(engine: crate::config::TranscriptionEngine) -> &'static str {
         crate::config::TranscriptionEngine::Omnilingual => "\u{1F30D}", // 🌍
         crate::config::TranscriptionEngine::Cohere => "\u{1F4DD}",   // 📝
         crate::config::TranscriptionEngine::Soniox => "\u{2601}\u{FE0F}", // ☁️
+        crate::config::TranscriptionEngine::Vosk => "\u{1F43F}\u{FE0F}", // 🐿️
+        crate::config::TranscriptionEngine::Zipformer => "\u{26A1}", // ⚡
     }
 }
 
@@ -192,12 +226,32 @@ pub fn sanitize_urgency(urgency: &str) -> &str {
     }
 }
 
-/// Send a transcription notification with optional engine icon
+/// Title for the transcription notification, e.g. "Transcribed (fr)" when
+/// the language was auto-detected
+fn transcription_notification_title(
+    show_engine_icon: bool,
+    engine: crate::config::TranscriptionEngine,
+    language: Option<&str>,
+) -> String {
+    let mut title = if show_engine_icon {
+        format!("{} Transcribed", engine_icon(engine))
+    } else {
+        "Transcribed".to_string()
+    };
+    if let Some(lang) = language {
+        title.push_str(&format!(" ({})", lang));
+    }
+    title
+}
+
+/// Send a transcription notification with optional engine icon and the
+/// auto-detected language
 pub async fn send_transcription_notification(
     text: &str,
     show_engine_icon: bool,
     engine: crate::config::TranscriptionEngine,
     urgency: &str,
+    language: Option<&str>,
 ) {
     // Truncate preview for notification (use chars() to handle multi-byte UTF-8)
     let preview = if text.chars().count() > 80 {
@@ -206,11 +260,7 @@ pub async fn send_transcription_notification(
         text.to_string()
     };
 
-    let title = if show_engine_icon {
-        format!("{} Transcribed", engine_icon(engine))
-    } else {
-        "Transcribed".to_string()
-    };
+    let title = transcription_notification_title(show_engine_icon, engine, language);
 
     let urgency_arg = format!("--urgency={}", sanitize_urgency(urgency));
     // Synchronous + transient hints ([#345]): single Voxtype notification slot
@@ -249,10 +299,13 @@ pub trait TextOutput: Send + Sync {
 /// Default driver order for type mode
 #[cfg(not(target_os = "macos"))]
 const DEFAULT_DRIVER_ORDER: &[OutputDriver] = &[
+    OutputDriver::InputMethod,
     OutputDriver::Wtype,
     OutputDriver::Eitype,
+    OutputDriver::Xdotool,
     OutputDriver::Dotool,
     OutputDriver::Ydotool,
+    OutputDriver::Uinput,
     OutputDriver::Clipboard,
     OutputDriver::Xclip,
 ];
@@ -265,6 +318,11 @@ fn create_driver_output(
     pre_type_delay_ms: u32,
 ) -> Box<dyn TextOutput> {
     match driver {
+        OutputDriver::InputMethod => Box::new(input_method::InputMethodOutput::new(
+            pre_type_delay_ms,
+            config.auto_submit,
+            config.append_text.clone(),
+        )),
         OutputDriver::Wtype => Box::new(wtype::WtypeOutput::new(
             config.auto_submit,
             config.append_text.clone(),
@@ -282,24 +340,125 @@ fn create_driver_output(
             config.eitype_xkb_layout.clone(),
             config.eitype_xkb_variant.clone(),
         )),
-        OutputDriver::Dotool => Box::new(dotool::DotoolOutput::new(
+        OutputDriver::Xdotool => Box::new(xdotool::XdotoolOutput::new(
             config.type_delay_ms,
             pre_type_delay_ms,
             config.auto_submit,
             config.append_text.clone(),
-            config.dotool_xkb_layout.clone(),
-            config.dotool_xkb_variant.clone(),
+            config.shift_enter_newlines,
         )),
-        OutputDriver::Ydotool => Box::new(ydotool::YdotoolOutput::new(
+        OutputDriver::Dotool => Box::new(
+            dotool::DotoolOutput::new(
+                config.type_delay_ms,
+                pre_type_delay_ms,
+                config.auto_submit,
+                config.append_text.clone(),
+                config.dotool_xkb_layout.clone(),
+                config.dotool_xkb_variant.clone(),
+            )
+            .persistent(config.persistent_drivers),
+        ),
+        OutputDriver::Ydotool => Box::new(
+            ydotool::YdotoolOutput::new(
+                config.type_delay_ms,
+                pre_type_delay_ms,
+                config.auto_submit,
+                config.append_text.clone(),
+            )
+            .persistent(config.persistent_drivers),
+        ),
+        #[cfg(target_os = "linux")]
+        OutputDriver::Uinput => Box::new(uinput::UinputOutput::new(
             config.type_delay_ms,
             pre_type_delay_ms,
             config.auto_submit,
             config.append_text.clone(),
+            config.shift_enter_newlines,
+            config.uinput_xkb_layout.clone(),
         )),
+        #[cfg(target_os = "linux")]
+        OutputDriver::Atspi => Box::new(atspi::AtspiOutput::new(
+            pre_type_delay_ms,
+            config.auto_submit,
+            config.append_text.clone(),
+        )),
+        #[cfg(target_os = "linux")]
+        OutputDriver::Ime => Box::new(ime::ImeOutput::new(
+            pre_type_delay_ms,
+            config.auto_submit,
+            config.append_text.clone(),
+        )),
+        // /dev/uinput, AT-SPI and IBus are Linux-only; ydotool is the
+        // nearest equivalent elsewhere
+        #[cfg(not(target_os = "linux"))]
+        OutputDriver::Uinput | OutputDriver::Atspi | OutputDriver::Ime => Box::new(
+            ydotool::YdotoolOutput::new(
+                config.type_delay_ms,
+                pre_type_delay_ms,
+                config.auto_submit,
+                config.append_text.clone(),
+            )
+            .persistent(config.persistent_drivers),
+        ),
         OutputDriver::Clipboard => {
             Box::new(clipboard::ClipboardOutput::new(config.append_text.clone()))
         }
         OutputDriver::Xclip => Box::new(xclip::XclipOutput::new(config.append_text.clone())),
+        OutputDriver::Osc52 => Box::new(osc52::Osc52Output::new(
+            config.osc52_tty.clone(),
+            config.append_text.clone(),
+        )),
+    }
+}
+
+/// Like [`create_driver_output`], but keystroke drivers are wrapped in
+/// [`pacing::PacedOutput`] when `[output.pacing]` is enabled, and typing
+/// drivers in [`chunking::ChunkedOutput`] otherwise
+#[cfg(not(target_os = "macos"))]
+fn create_paced_driver_output(
+    driver: OutputDriver,
+    config: &OutputConfig,
+    pre_type_delay_ms: u32,
+) -> Box<dyn TextOutput> {
+    let keystrokes = matches!(
+        driver,
+        OutputDriver::Wtype
+            | OutputDriver::Eitype
+            | OutputDriver::Xdotool
+            | OutputDriver::Dotool
+            | OutputDriver::Ydotool
+            | OutputDriver::Uinput
+    );
+    let paced = config.pacing.enabled && keystrokes;
+    // Pacing already types in small pieces
+    let chunk_size = if paced {
+        None
+    } else {
+        chunking::chunk_size(driver, &config.chunking)
+    };
+    if !paced && chunk_size.is_none() {
+        return create_driver_output(driver, config, pre_type_delay_ms);
+    }
+
+    // Only the last piece may append text or press Enter
+    let mut body_config = config.clone();
+    body_config.auto_submit = false;
+    body_config.append_text = None;
+    let body = create_driver_output(driver, &body_config, 0);
+    let last = create_driver_output(driver, config, 0);
+    match chunk_size {
+        Some(size) => Box::new(chunking::ChunkedOutput::new(
+            body,
+            last,
+            pre_type_delay_ms,
+            size,
+        )),
+        None => Box::new(pacing::PacedOutput::new(
+            body,
+            last,
+            pre_type_delay_ms,
+            config.pacing.clone(),
+        )),
     }
 }
 
@@ -308,6 +467,25 @@ pub fn create_output_chain(config: &OutputConfig) -> Vec<Box<dyn TextOutput>> {
     create_output_chain_with_override(config, None)
 }
 
+/// Like [`create_output_chain`], but with `auto_driver` the type mode
+/// drivers suited to the focused application go first
+pub async fn create_output_chain_for_focus(config: &OutputConfig) -> Vec<Box<dyn TextOutput>> {
+    #[cfg(not(target_os = "macos"))]
+    if config.auto_driver && config.mode == crate::config::OutputMode::Type {
+        let app = auto_driver::detect().await;
+        let order = auto_driver::driver_order(
+            app,
+            config
+                .driver_order
+                .as_deref()
+                .unwrap_or(DEFAULT_DRIVER_ORDER),
+        );
+        tracing::debug!("auto_driver: focused window is {}", app);
+        return create_output_chain_with_override(config, Some(&order));
+    }
+    create_output_chain(config)
+}
+
 /// Factory function that returns a fallback chain of output methods with an optional driver override
 pub fn create_output_chain_with_override(
     config: &OutputConfig,
@@ -366,7 +544,11 @@ pub fn create_output_chain_with_override(
                 }
 
                 for driver in driver_order.iter() {
-                    chain.push(create_driver_output(*driver, config, pre_type_delay_ms));
+                    chain.push(create_paced_driver_output(
+                        *driver,
+                        config,
+                        pre_type_delay_ms,
+                    ));
                 }
 
                 // If fallback_to_clipboard is true but clipboard wasn't in the custom order, add it
@@ -389,14 +571,62 @@ pub fn create_output_chain_with_override(
             #[cfg(not(target_os = "macos"))]
             {
                 // Clipboard with X11 fallback: wl-copy first, then xclip
-                chain.push(Box::new(clipboard::ClipboardOutput::new(
+                let restore_after = config
+                    .restore_clipboard_after_secs
+                    .map(|secs| std::time::Duration::from_secs(secs as u64));
+                chain.push(Box::new(
+                    clipboard::ClipboardOutput::new(config.append_text.clone())
+                        .restore_after(restore_after),
+                ));
+                chain.push(Box::new(
+                    xclip::XclipOutput::new(config.append_text.clone())
+                        .restore_after(restore_after),
+                ));
+            }
+        }
+        crate::config::OutputMode::Primary => {
+            // macOS has no primary selection; use the clipboard instead
+            #[cfg(target_os = "macos")]
+            chain.push(Box::new(pbcopy::PbcopyOutput::new(
+                config.notification.on_transcription,
+            )));
+
+            #[cfg(not(target_os = "macos"))]
+            {
+                // Primary selection with X11 fallback: wl-copy first, then xclip
+                chain.push(Box::new(clipboard::ClipboardOutput::primary(
                     config.append_text.clone(),
                 )));
-                chain.push(Box::new(xclip::XclipOutput::new(
+                chain.push(Box::new(xclip::XclipOutput::primary(
                     config.append_text.clone(),
                 )));
             }
         }
+        crate::config::OutputMode::Tmux => {
+            chain.push(Box::new(tmux::TmuxOutput::new(
+                config.tmux_paste,
+                config.auto_submit,
+                config.append_text.clone(),
+            )));
+
+            // No tmux server: fall back to the clipboard
+            if config.fallback_to_clipboard {
+                #[cfg(target_os = "macos")]
+                chain.push(Box::new(pbcopy::PbcopyOutput::new(
+                    config.notification.on_transcription,
+                )));
+
+                #[cfg(not(target_os = "macos"))]
+                {
+                    chain.push(Box::new(clipboard::ClipboardOutput::new(
+                        config.append_text.clone(),
+                    )));
+                    chain.push(Box::new(xclip::XclipOutput::new(
+                        config.append_text.clone(),
+                    )));
+                }
+            }
+        }
         crate::config::OutputMode::Paste => {
             // Only paste mode (no fallback as requested)
             chain.push(Box::new(paste::PasteOutput::new(
@@ -409,6 +639,13 @@ pub fn create_output_chain_with_override(
                 config.restore_clipboard_delay_ms,
             )));
         }
+        crate::config::OutputMode::Webhook => {
+            // Webhook output is handled in the daemon, which knows the
+            // active profile. Other callers get the clipboard.
+            chain.push(Box::new(clipboard::ClipboardOutput::new(
+                config.append_text.clone(),
+            )));
+        }
         crate::config::OutputMode::File => {
             // File output is handled in the daemon before reaching the output chain.
             // If we get here, it means mode = "file" but no file_path is configured.
@@ -462,7 +699,23 @@ pub struct OutputOptions<'a> {
 /// keybindings when modifiers are held. Used to filter the chain when the
 /// modifier-release wait times out.
 fn is_keystroke_method(name: &str) -> bool {
-    matches!(name, "wtype" | "eitype" | "dotool" | "ydotool") || name.starts_with("paste")
+    matches!(
+        name,
+        "wtype" | "eitype" | "xdotool" | "dotool" | "ydotool" | "uinput"
+    ) || name.starts_with("paste")
+}
+
+/// Output methods that put the text at the cursor, where backspaces can
+/// remove it again: the keystroke methods plus AT-SPI, input-method and
+/// IBus insertion, which don't send keys but leave the caret after the text.
+fn is_cursor_method(name: &str) -> bool {
+    is_keystroke_method(name) || matches!(name, "atspi" | "input-method" | "ime")
+}
+
+/// Whether the last successful output landed at the cursor, so it can be
+/// removed again with backspaces (voice editing commands)
+pub fn last_output_at_cursor() -> bool {
+    LAST_OUTPUT_AT_CURSOR.load(Ordering::Relaxed)
 }
 
 /// Try each output method in the chain until one succeeds
@@ -537,6 +790,7 @@ pub async fn output_with_fallback(
         match output.output(&normalized_text).await {
             Ok(()) => {
                 tracing::debug!("Text output via {}", output.name());
+                LAST_OUTPUT_AT_CURSOR.store(is_cursor_method(output.name()), Ordering::Relaxed);
                 result = Ok(());
                 break;
             }
@@ -617,11 +871,37 @@ mod tests {
     fn test_is_keystroke_method_classification() {
         assert!(is_keystroke_method("wtype"));
         assert!(is_keystroke_method("eitype"));
+        assert!(is_keystroke_method("xdotool"));
         assert!(is_keystroke_method("dotool"));
         assert!(is_keystroke_method("ydotool"));
+        assert!(is_keystroke_method("uinput"));
         assert!(is_keystroke_method("paste (clipboard + keystroke)"));
         assert!(!is_keystroke_method("clipboard (wl-copy)"));
         assert!(!is_keystroke_method("clipboard (xclip/xsel)"));
+        assert!(!is_keystroke_method("atspi"));
+        assert!(is_cursor_method("atspi"));
+        assert!(is_cursor_method("input-method"));
+        assert!(!is_keystroke_method("input-method"));
+        assert!(is_cursor_method("ime"));
+        assert!(!is_keystroke_method("ime"));
+        assert!(is_cursor_method("wtype"));
+        assert!(!is_cursor_method("clipboard (wl-copy)"));
+        assert!(!is_keystroke_method("tmux"));
+    }
+
+    #[test]
+    fn test_tmux_chain() {
+        let mut config = crate::config::OutputConfig {
+            mode: crate::config::OutputMode::Tmux,
+            ..Default::default()
+        };
+        let chain = create_output_chain(&config);
+        assert_eq!(chain[0].name(), "tmux");
+        assert!(chain.len() > 1);
+
+        config.fallback_to_clipboard = false;
+        let chain = create_output_chain(&config);
+        assert_eq!(chain.len(), 1);
     }
 
     #[test]
@@ -666,4 +946,17 @@ mod tests {
         assert_eq!(sanitize_urgency("urgent"), "normal");
         assert_eq!(sanitize_urgency("--rm -rf /"), "normal");
     }
+
+    #[test]
+    fn test_transcription_title_includes_detected_language() {
+        use crate::config::TranscriptionEngine;
+        assert_eq!(
+            transcription_notification_title(false, TranscriptionEngine::Whisper, None),
+            "Transcribed"
+        );
+        assert_eq!(
+            transcription_notification_title(false, TranscriptionEngine::Whisper, Some("fr")),
+            "Transcribed (fr)"
+        );
+    }
 }
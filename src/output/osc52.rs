@@ -0,0 +1,157 @@
+//! OSC 52 clipboard output
+//!
+//! Writes an OSC 52 escape sequence to a terminal. The terminal emulator
+//! puts the payload on the clipboard of the machine it runs on, so text
+//! dictated on a remote host over SSH lands in the local clipboard. Inside
+//! tmux the sequence is wrapped in a DCS passthrough (needs tmux
+//! `set-clipboard on` or `allow-passthrough on`).
+//!
+//! The terminal must support OSC 52 and have it enabled (kitty, foot,
+//! WezTerm, Alacritty, iTerm2, Windows Terminal, and xterm with
+//! `allowWindowOps`).
+//!
+//! Requires: a writable terminal device, by default the daemon's
+//! controlling terminal (`/dev/tty`).
+
+use super::TextOutput;
+use crate::error::OutputError;
+use std::io::Write;
+
+/// Terminal used when `osc52_tty` isn't set
+const DEFAULT_TTY: &str = "/dev/tty";
+
+/// OSC 52 terminal clipboard output
+pub struct Osc52Output {
+    /// Terminal device to write the escape sequence to
+    tty: String,
+    /// Text to append after transcription
+    append_text: Option<String>,
+    /// Wrap the sequence for tmux passthrough
+    tmux: bool,
+}
+
+impl Osc52Output {
+    /// Create a new OSC 52 output writing to `tty`, or the controlling
+    /// terminal when `None`
+    pub fn new(tty: Option<String>, append_text: Option<String>) -> Self {
+        Self {
+            tty: tty.unwrap_or_else(|| DEFAULT_TTY.to_string()),
+            append_text,
+            tmux: std::env::var_os("TMUX").is_some(),
+        }
+    }
+
+    fn open_tty(&self) -> std::io::Result<std::fs::File> {
+        std::fs::OpenOptions::new().write(true).open(&self.tty)
+    }
+}
+
+/// Build the OSC 52 sequence that sets the clipboard to `text`
+fn sequence(text: &str, tmux: bool) -> String {
+    let osc = format!("\x1b]52;c;{}\x07", base64(text.as_bytes()));
+    if tmux {
+        // DCS passthrough: ESC P tmux; <sequence with ESC doubled> ESC \
+        format!("\x1bPtmux;{}\x1b\\", osc.replace('\x1b', "\x1b\x1b"))
+    } else {
+        osc
+    }
+}
+
+/// Standard base64 with padding
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[((n >> (18 - 6 * i)) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[async_trait::async_trait]
+impl TextOutput for Osc52Output {
+    async fn output(&self, text: &str) -> Result<(), OutputError> {
+        if text.is_empty() {
+            return Ok(());
+        }
+
+        let text = if let Some(ref append) = self.append_text {
+            std::borrow::Cow::Owned(format!("{}{}", text, append))
+        } else {
+            std::borrow::Cow::Borrowed(text)
+        };
+
+        let mut tty = self
+            .open_tty()
+            .map_err(|e| OutputError::InjectionFailed(format!("{}: {}", self.tty, e)))?;
+        tty.write_all(sequence(&text, self.tmux).as_bytes())
+            .and_then(|_| tty.flush())
+            .map_err(|e| OutputError::InjectionFailed(format!("{}: {}", self.tty, e)))?;
+
+        tracing::info!(
+            "Text sent to terminal clipboard via OSC 52 on {} ({} chars)",
+            self.tty,
+            text.len()
+        );
+        Ok(())
+    }
+
+    async fn is_available(&self) -> bool {
+        // A daemon started by systemd or the compositor has no controlling
+        // terminal, so opening /dev/tty fails there
+        match self.open_tty() {
+            Ok(_) => true,
+            Err(e) => {
+                tracing::debug!("osc52 skipped: cannot open {}: {}", self.tty, e);
+                false
+            }
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "osc52"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64("héllo".as_bytes()), "aMOpbGxv");
+    }
+
+    #[test]
+    fn test_sequence() {
+        assert_eq!(sequence("hi", false), "\x1b]52;c;aGk=\x07");
+        assert_eq!(
+            sequence("hi", true),
+            "\x1bPtmux;\x1b\x1b]52;c;aGk=\x07\x1b\\"
+        );
+    }
+
+    #[test]
+    fn test_default_tty() {
+        let output = Osc52Output::new(None, None);
+        assert_eq!(output.tty, "/dev/tty");
+        let output = Osc52Output::new(Some("/dev/pts/3".to_string()), None);
+        assert_eq!(output.tty, "/dev/pts/3");
+    }
+}
@@ -0,0 +1,232 @@
+//! Human-like typing pace
+//!
+//! Some web editors (Google Docs, Notion) drop characters when a whole
+//! dictation arrives at once. With `[output.pacing] enabled = true`, each
+//! keystroke driver gets the text in small pieces: one character at a time
+//! within a word and a longer pause between words, every delay randomly
+//! varied by `jitter`. Pacing wraps the driver, so wtype, eitype, xdotool,
+//! dotool, ydotool and uinput all behave the same.
+
+use super::TextOutput;
+use crate::config::PacingConfig;
+use crate::error::OutputError;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Keystroke driver that types the text piece by piece
+pub struct PacedOutput {
+    /// The driver without auto_submit and append_text, for all but the
+    /// last piece
+    body: Box<dyn TextOutput>,
+    /// The same driver with auto_submit and append_text, for the last piece
+    last: Box<dyn TextOutput>,
+    /// Delay before the first piece (the drivers get none)
+    pre_type_delay_ms: u32,
+    config: PacingConfig,
+}
+
+impl PacedOutput {
+    pub fn new(
+        body: Box<dyn TextOutput>,
+        last: Box<dyn TextOutput>,
+        pre_type_delay_ms: u32,
+        config: PacingConfig,
+    ) -> Self {
+        Self {
+            body,
+            last,
+            pre_type_delay_ms,
+            config,
+        }
+    }
+}
+
+/// Split text into typing pieces: single characters, or whole words with
+/// their trailing whitespace when `per_char` is false. The flag marks the
+/// end of a word, where the longer pause goes.
+fn pieces(text: &str, per_char: bool) -> Vec<(&str, bool)> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let end = i + c.len_utf8();
+        let word_end = c.is_whitespace() && chars.peek().is_some_and(|(_, n)| !n.is_whitespace());
+        if per_char || word_end {
+            pieces.push((&text[start..end], word_end));
+            start = end;
+        }
+    }
+    if start < text.len() {
+        pieces.push((&text[start..], false));
+    }
+    pieces
+}
+
+/// xorshift64, seeded from the clock. Only used to vary delays.
+struct Jitter(u64);
+
+impl Jitter {
+    fn new() -> Self {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        Self(seed | 1)
+    }
+
+    /// `base_ms` varied by up to ±`fraction` of it
+    fn vary(&mut self, base_ms: u32, fraction: f32) -> Duration {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        // Uniform in [-1, 1]
+        let unit = (self.0 >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0;
+        let fraction = fraction.clamp(0.0, 1.0) as f64;
+        let ms = base_ms as f64 * (1.0 + fraction * unit);
+        Duration::from_micros((ms.max(0.0) * 1000.0) as u64)
+    }
+}
+
+#[async_trait::async_trait]
+impl TextOutput for PacedOutput {
+    async fn output(&self, text: &str) -> Result<(), OutputError> {
+        let pieces = pieces(text, self.config.char_delay_ms > 0);
+        let Some((&(last_piece, _), body_pieces)) = pieces.split_last() else {
+            return self.last.output(text).await;
+        };
+
+        if self.pre_type_delay_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(self.pre_type_delay_ms as u64)).await;
+        }
+
+        tracing::debug!(
+            "{}: typing {} pieces with pacing",
+            self.body.name(),
+            pieces.len()
+        );
+        let mut jitter = Jitter::new();
+        let mut typed = 0;
+        for &(piece, word_end) in body_pieces {
+            if let Err(e) = self.body.output(piece).await {
+                if typed == 0 {
+                    // Nothing typed yet, so the next driver can start over
+                    return Err(e);
+                }
+                // Finish in one go rather than let a fallback retype the start
+                tracing::warn!(
+                    "{}: paced typing failed after {} bytes ({}), typing the rest at once",
+                    self.body.name(),
+                    typed,
+                    e
+                );
+                return self.last.output(&text[typed..]).await;
+            }
+            typed += piece.len();
+            let base_ms = if word_end {
+                self.config.word_pause_ms
+            } else {
+                self.config.char_delay_ms
+            };
+            tokio::time::sleep(jitter.vary(base_ms, self.config.jitter)).await;
+        }
+        self.last.output(last_piece).await
+    }
+
+    async fn is_available(&self) -> bool {
+        self.body.is_available().await
+    }
+
+    fn name(&self) -> &'static str {
+        self.body.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_pieces_per_char() {
+        assert_eq!(
+            pieces("hi  yo", true),
+            vec![
+                ("h", false),
+                ("i", false),
+                (" ", false),
+                (" ", true),
+                ("y", false),
+                ("o", false),
+            ]
+        );
+        assert_eq!(pieces("é!", true), vec![("é", false), ("!", false)]);
+    }
+
+    #[test]
+    fn test_pieces_per_word() {
+        assert_eq!(
+            pieces("hello  big\nworld ", false),
+            vec![("hello  ", true), ("big\n", true), ("world ", false)]
+        );
+        assert!(pieces("", false).is_empty());
+    }
+
+    #[test]
+    fn test_jitter_range() {
+        let mut jitter = Jitter::new();
+        for _ in 0..1000 {
+            let delay = jitter.vary(20, 0.5);
+            assert!(delay >= Duration::from_millis(10) && delay <= Duration::from_millis(30));
+        }
+        assert_eq!(jitter.vary(20, 0.0), Duration::from_millis(20));
+        assert_eq!(jitter.vary(0, 0.5), Duration::ZERO);
+    }
+
+    /// Records what each output call received
+    struct Recorder {
+        calls: Arc<Mutex<Vec<String>>>,
+        suffix: &'static str,
+    }
+
+    #[async_trait::async_trait]
+    impl TextOutput for Recorder {
+        async fn output(&self, text: &str) -> Result<(), OutputError> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("{}{}", text, self.suffix));
+            Ok(())
+        }
+
+        async fn is_available(&self) -> bool {
+            true
+        }
+
+        fn name(&self) -> &'static str {
+            "recorder"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_only_last_piece_gets_append_and_submit() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let output = PacedOutput::new(
+            Box::new(Recorder {
+                calls: calls.clone(),
+                suffix: "",
+            }),
+            Box::new(Recorder {
+                calls: calls.clone(),
+                suffix: "⏎",
+            }),
+            0,
+            PacingConfig {
+                enabled: true,
+                char_delay_ms: 0,
+                word_pause_ms: 1,
+                jitter: 0.5,
+            },
+        );
+        output.output("ls -la /tmp").await.unwrap();
+        assert_eq!(*calls.lock().unwrap(), vec!["ls ", "-la ", "/tmp⏎"]);
+    }
+}
@@ -13,33 +13,282 @@
 //!
 //! The command receives the transcribed text on stdin and should output
 //! the processed text on stdout. On any failure, the original text is used.
+//!
+//! With `[output.llm]` configured, a resident language model is used instead
+//! of the command (see [`super::llm`]).
+//!
+//! # Pipelines
+//!
+//! `stages` replaces the single command with an ordered list of shell
+//! commands, regex replacements and LLM calls. Each stage receives the
+//! previous stage's output and has its own timeout and `on_error` policy:
+//! `abort` (default) stops and outputs the original text, `skip` passes the
+//! stage's input on unchanged.
+//!
+//! ```toml
+//! [[output.post_process.stages]]
+//! type = "command"
+//! command = "remove-fillers.sh"
+//! on_error = "skip"
+//!
+//! [[output.post_process.stages]]
+//! type = "llm"
+//! model = "llama3.2:1b"
+//! ```
 
-use crate::config::PostProcessConfig;
+use super::llm::{LlmClient, LlmError};
+use super::prompt_templates;
+use crate::config::{
+    Config, LlmConfig, OutputConfig, PostProcessConfig, PostProcessStage, StageErrorPolicy,
+};
+use regex::Regex;
+use std::collections::HashMap;
 use std::process::Stdio;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 use tokio::time::timeout;
 
-/// Post-processor that runs an external command on transcribed text
+/// Post-processor that runs transcribed text through one or more stages
 pub struct PostProcessor {
-    command: String,
-    timeout: Duration,
+    stages: Vec<Stage>,
     trim: bool,
     fallback_on_empty: bool,
 }
 
+/// A pipeline step, built from [`PostProcessStage`]
+struct Stage {
+    kind: StageKind,
+    on_error: StageErrorPolicy,
+}
+
+enum StageKind {
+    Command {
+        command: String,
+        timeout: Duration,
+    },
+    Regex {
+        regex: Regex,
+        replacement: String,
+    },
+    Llm {
+        client: Arc<LlmClient>,
+        timeout: Duration,
+    },
+}
+
+impl StageKind {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Command { .. } => "command",
+            Self::Regex { .. } => "regex",
+            Self::Llm { .. } => "llm",
+        }
+    }
+}
+
+impl Stage {
+    fn from_config(config: &PostProcessStage) -> Result<Self, String> {
+        let kind = match config {
+            PostProcessStage::Command {
+                command,
+                timeout_ms,
+                ..
+            } => StageKind::Command {
+                command: command.clone(),
+                timeout: Duration::from_millis(*timeout_ms),
+            },
+            PostProcessStage::Regex {
+                pattern,
+                replacement,
+                ..
+            } => StageKind::Regex {
+                regex: Regex::new(pattern)
+                    .map_err(|e| format!("invalid pattern {:?}: {}", pattern, e))?,
+                replacement: replacement.clone(),
+            },
+            PostProcessStage::Llm { llm, .. } => StageKind::Llm {
+                client: Arc::new(LlmClient::new(llm).map_err(|e| e.to_string())?),
+                timeout: Duration::from_millis(llm.timeout_ms),
+            },
+        };
+        Ok(Self {
+            kind,
+            on_error: config.on_error(),
+        })
+    }
+}
+
 impl PostProcessor {
     /// Create a new post-processor from configuration
+    ///
+    /// Stages that can't be built (an invalid regex, an LLM model that fails
+    /// to load) are left out with a warning.
     pub fn new(config: &PostProcessConfig) -> Self {
+        let timeout = Duration::from_millis(config.timeout_ms);
+        let stages = if config.stages.is_empty() {
+            vec![Stage {
+                kind: StageKind::Command {
+                    command: config.command.clone(),
+                    timeout,
+                },
+                on_error: StageErrorPolicy::Abort,
+            }]
+        } else {
+            if !config.command.is_empty() {
+                tracing::warn!("[output.post_process] stages are set, ignoring command");
+            }
+            config
+                .stages
+                .iter()
+                .enumerate()
+                .filter_map(|(i, stage)| match Stage::from_config(stage) {
+                    Ok(stage) => Some(stage),
+                    Err(e) => {
+                        tracing::warn!("Post-process stage {} disabled: {}", i + 1, e);
+                        None
+                    }
+                })
+                .collect()
+        };
         Self {
-            command: config.command.clone(),
-            timeout: Duration::from_millis(config.timeout_ms),
+            stages,
             trim: config.trim,
             fallback_on_empty: config.fallback_on_empty,
         }
     }
 
+    /// Create a post-processor that asks a language model instead of
+    /// running a command. Loads the model for the llama.cpp backend.
+    pub fn with_llm(config: &LlmConfig) -> Result<Self, LlmError> {
+        Ok(Self::with_llm_client(
+            LlmClient::new(config)?,
+            Duration::from_millis(config.timeout_ms),
+        ))
+    }
+
+    /// Create a post-processor around an existing language model client
+    pub fn with_llm_client(client: LlmClient, timeout: Duration) -> Self {
+        Self {
+            stages: vec![Stage {
+                kind: StageKind::Llm {
+                    client: Arc::new(client),
+                    timeout,
+                },
+                on_error: StageErrorPolicy::Abort,
+            }],
+            trim: true,
+            fallback_on_empty: true,
+        }
+    }
+
+    /// The post-processor for `[output]`: `llm` when set, otherwise the
+    /// `post_process` command or stages. `label` prefixes the log lines.
+    pub fn from_output_config(output: &OutputConfig, label: &str) -> Option<Self> {
+        if let Some(llm) = &output.llm {
+            if output.post_process.is_some() {
+                tracing::warn!("[output.llm] is set, ignoring [output.post_process]");
+            }
+            return match Self::with_llm(llm) {
+                Ok(processor) => {
+                    tracing::info!(
+                        "{} enabled: llm={:?} model={:?}, timeout={}ms",
+                        label,
+                        llm.backend,
+                        llm.model,
+                        llm.timeout_ms
+                    );
+                    Some(processor)
+                }
+                Err(e) => {
+                    tracing::warn!("{} disabled: {}", label, e);
+                    None
+                }
+            };
+        }
+        output.post_process.as_ref().map(|cfg| {
+            if cfg.stages.is_empty() {
+                tracing::info!(
+                    "{} enabled: command={:?}, timeout={}ms",
+                    label,
+                    cfg.command,
+                    cfg.timeout_ms
+                );
+            }
+            let processor = Self::new(cfg);
+            if !cfg.stages.is_empty() {
+                tracing::info!(
+                    "{} enabled: stages=[{}]",
+                    label,
+                    processor.stage_names().join(", ")
+                );
+            }
+            processor
+        })
+    }
+
+    /// Post-processors for profiles with `post_process_template`, keyed by
+    /// profile name. They share one model on the `[output.llm]` backend
+    /// (Ollama defaults when unset); only the prompt differs.
+    pub fn for_profile_templates(config: &Config) -> HashMap<String, Self> {
+        let llm_config = config.output.llm.clone().unwrap_or_default();
+        let mut base: Option<LlmClient> = None;
+        let mut processors = HashMap::new();
+
+        for (name, profile) in &config.profiles {
+            let Some(template) = profile.post_process_template.as_deref() else {
+                continue;
+            };
+            if profile.post_process_command.is_some() {
+                tracing::warn!(
+                    "Profile '{}' sets post_process_command and post_process_template, using the command",
+                    name
+                );
+                continue;
+            }
+            let Some(prompt) = prompt_templates::get(template) else {
+                tracing::warn!(
+                    "Profile '{}': unknown post_process_template {:?} (available: {})",
+                    name,
+                    template,
+                    prompt_templates::names()
+                );
+                continue;
+            };
+            if base.is_none() {
+                match LlmClient::new(&llm_config) {
+                    Ok(client) => base = Some(client),
+                    Err(e) => {
+                        tracing::warn!("Post-process templates disabled: {}", e);
+                        break;
+                    }
+                }
+            }
+            let Some(client) = base.as_ref().map(|base| base.with_prompt(prompt)) else {
+                break;
+            };
+            let timeout_ms = profile
+                .post_process_timeout_ms
+                .unwrap_or(llm_config.timeout_ms);
+            tracing::info!(
+                "Profile '{}' post-processing with template {:?} (model {:?})",
+                name,
+                template,
+                llm_config.model
+            );
+            processors.insert(
+                name.clone(),
+                Self::with_llm_client(client, Duration::from_millis(timeout_ms)),
+            );
+        }
+        processors
+    }
+
+    fn stage_names(&self) -> Vec<&'static str> {
+        self.stages.iter().map(|s| s.kind.name()).collect()
+    }
+
     /// Process text with optional context from a previous chunk
     ///
     /// When context is provided, it is passed via the VOXTYPE_CONTEXT environment
@@ -47,27 +296,21 @@ impl PostProcessor {
     /// Stdin always contains only the current text, keeping existing scripts compatible.
     /// Returns the processed text on success, or the original text on any failure.
     pub async fn process_with_context(&self, text: &str, context: Option<&str>) -> String {
-        match self.execute_command_with_env(text, context).await {
+        match self.run_stages(text, context).await {
             Ok(processed) => {
-                if processed.is_empty() && self.fallback_on_empty {
-                    tracing::warn!(
-                        "Post-process command returned empty output, using original text"
-                    );
-                    text.to_string()
-                } else if processed.is_empty() {
-                    tracing::debug!("Post-process command returned empty output");
-                    String::new()
+                if processed.is_empty() {
+                    tracing::debug!("Post-processing returned empty output");
                 } else {
                     tracing::debug!(
                         "Post-processed ({} -> {} chars)",
                         text.len(),
                         processed.len()
                     );
-                    processed
                 }
+                processed
             }
             Err(e) => {
-                tracing::warn!("Post-process command failed: {}, using original text", e);
+                tracing::warn!("Post-processing failed: {}, using original text", e);
                 text.to_string()
             }
         }
@@ -81,13 +324,72 @@ impl PostProcessor {
         self.process_with_context(text, None).await
     }
 
+    /// Run every stage in order, applying each stage's `on_error` policy
+    async fn run_stages(
+        &self,
+        text: &str,
+        context: Option<&str>,
+    ) -> Result<String, PostProcessError> {
+        let mut current = text.to_string();
+        for (i, stage) in self.stages.iter().enumerate() {
+            let result = match self.run_stage(&stage.kind, &current, context).await {
+                Ok(output) if output.is_empty() && self.fallback_on_empty => {
+                    Err(PostProcessError::EmptyOutput)
+                }
+                result => result,
+            };
+            match result {
+                Ok(output) => current = output,
+                Err(e) if stage.on_error == StageErrorPolicy::Skip => {
+                    tracing::warn!(
+                        "Post-process stage {} ({}) failed: {}, skipping",
+                        i + 1,
+                        stage.kind.name(),
+                        e
+                    );
+                }
+                Err(e) if self.stages.len() > 1 => {
+                    return Err(PostProcessError::Stage {
+                        index: i + 1,
+                        name: stage.kind.name(),
+                        error: Box::new(e),
+                    });
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(current)
+    }
+
+    async fn run_stage(
+        &self,
+        kind: &StageKind,
+        text: &str,
+        context: Option<&str>,
+    ) -> Result<String, PostProcessError> {
+        match kind {
+            StageKind::Command { command, timeout } => {
+                self.execute_command_with_env(command, *timeout, text, context)
+                    .await
+            }
+            StageKind::Regex { regex, replacement } => {
+                Ok(regex.replace_all(text, replacement.as_str()).into_owned())
+            }
+            StageKind::Llm { client, timeout } => {
+                Self::execute_llm(client, *timeout, text, context).await
+            }
+        }
+    }
+
     async fn execute_command_with_env(
         &self,
+        command: &str,
+        limit: Duration,
         text: &str,
         context: Option<&str>,
     ) -> Result<String, PostProcessError> {
         let mut cmd = Command::new("sh");
-        cmd.args(["-c", &self.command])
+        cmd.args(["-c", command])
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped());
@@ -113,9 +415,9 @@ impl PostProcessor {
         }
 
         // Wait for completion with timeout
-        let output = timeout(self.timeout, child.wait_with_output())
+        let output = timeout(limit, child.wait_with_output())
             .await
-            .map_err(|_| PostProcessError::Timeout(self.timeout.as_secs()))?
+            .map_err(|_| PostProcessError::Timeout(limit.as_secs()))?
             .map_err(|e| PostProcessError::WaitFailed(e.to_string()))?;
 
         // Check exit status
@@ -138,6 +440,25 @@ impl PostProcessor {
             Ok(processed.trim_end_matches('\n').to_string())
         }
     }
+
+    /// Ask the language model on a blocking thread, within the timeout
+    async fn execute_llm(
+        llm: &Arc<LlmClient>,
+        limit: Duration,
+        text: &str,
+        context: Option<&str>,
+    ) -> Result<String, PostProcessError> {
+        let llm = Arc::clone(llm);
+        let text = text.to_string();
+        let context = context.map(str::to_string);
+        let task = tokio::task::spawn_blocking(move || llm.complete(&text, context.as_deref()));
+
+        timeout(limit, task)
+            .await
+            .map_err(|_| PostProcessError::Timeout(limit.as_secs()))?
+            .map_err(|e| PostProcessError::WaitFailed(e.to_string()))?
+            .map_err(|e| PostProcessError::Llm(e.to_string()))
+    }
 }
 
 /// Errors that can occur during post-processing
@@ -155,6 +476,16 @@ pub enum PostProcessError {
     NonZeroExit { code: Option<i32>, stderr: String },
     /// Command output was not valid UTF-8
     InvalidUtf8(String),
+    /// The `[output.llm]` model failed
+    Llm(String),
+    /// Output was empty and `fallback_on_empty` is set
+    EmptyOutput,
+    /// A stage of a multi-stage pipeline failed
+    Stage {
+        index: usize,
+        name: &'static str,
+        error: Box<PostProcessError>,
+    },
 }
 
 impl std::fmt::Display for PostProcessError {
@@ -172,6 +503,11 @@ impl std::fmt::Display for PostProcessError {
                 }
             }
             Self::InvalidUtf8(e) => write!(f, "output is not valid UTF-8: {}", e),
+            Self::Llm(e) => write!(f, "{}", e),
+            Self::EmptyOutput => write!(f, "output was empty"),
+            Self::Stage { index, name, error } => {
+                write!(f, "stage {} ({}): {}", index, name, error)
+            }
         }
     }
 }
@@ -188,6 +524,7 @@ mod tests {
             timeout_ms,
             trim: true,
             fallback_on_empty: true,
+            stages: Vec::new(),
         }
     }
 
@@ -249,6 +586,126 @@ mod tests {
         assert_eq!(result, "original text"); // Falls back to original
     }
 
+    #[tokio::test]
+    async fn test_llm_failure_fallback() {
+        let llm = LlmConfig {
+            backend: crate::config::LlmBackend::Ollama,
+            model: "llama3.2:1b".to_string(),
+            ollama_url: "http://127.0.0.1:9".to_string(),
+            prompt: None,
+            max_tokens: 16,
+            timeout_ms: 2000,
+        };
+        let output = OutputConfig {
+            post_process: Some(make_config("tr '[:lower:]' '[:upper:]'", 5000)),
+            llm: Some(llm),
+            ..OutputConfig::default()
+        };
+        // [output.llm] wins over the command, and failures fall back
+        let processor = PostProcessor::from_output_config(&output, "Test").unwrap();
+        let result = processor.process("original text").await;
+        assert_eq!(result, "original text");
+    }
+
+    fn pipeline(stages: Vec<PostProcessStage>) -> PostProcessConfig {
+        PostProcessConfig {
+            stages,
+            ..make_config("", 5000)
+        }
+    }
+
+    fn command_stage(command: &str, on_error: StageErrorPolicy) -> PostProcessStage {
+        PostProcessStage::Command {
+            command: command.to_string(),
+            timeout_ms: 5000,
+            on_error,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_runs_stages_in_order() {
+        let config = pipeline(vec![
+            command_stage("sed 's/um //g'", StageErrorPolicy::Abort),
+            PostProcessStage::Regex {
+                pattern: r"\bteh\b".to_string(),
+                replacement: "the".to_string(),
+                on_error: StageErrorPolicy::Abort,
+            },
+            command_stage(
+                "echo \"$(cat) [$VOXTYPE_CONTEXT]\"",
+                StageErrorPolicy::Abort,
+            ),
+        ]);
+        let processor = PostProcessor::new(&config);
+        let result = processor
+            .process_with_context("um fix teh bug", Some("earlier"))
+            .await;
+        assert_eq!(result, "fix the bug [earlier]");
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_skip_and_abort() {
+        // A skipped stage passes its input on
+        let config = pipeline(vec![
+            command_stage("exit 1", StageErrorPolicy::Skip),
+            command_stage("printf ''", StageErrorPolicy::Skip),
+            command_stage("tr '[:lower:]' '[:upper:]'", StageErrorPolicy::Abort),
+        ]);
+        let processor = PostProcessor::new(&config);
+        assert_eq!(processor.process("hello").await, "HELLO");
+
+        // An aborting stage discards earlier stages' work
+        let config = pipeline(vec![
+            command_stage("tr '[:lower:]' '[:upper:]'", StageErrorPolicy::Abort),
+            command_stage("sleep 10", StageErrorPolicy::Abort),
+            command_stage("cat", StageErrorPolicy::Abort),
+        ]);
+        let mut processor = PostProcessor::new(&config);
+        if let StageKind::Command { timeout, .. } = &mut processor.stages[1].kind {
+            *timeout = Duration::from_millis(100);
+        }
+        assert_eq!(processor.process("hello").await, "hello");
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_drops_invalid_stage() {
+        let config = pipeline(vec![
+            PostProcessStage::Regex {
+                pattern: "(unclosed".to_string(),
+                replacement: String::new(),
+                on_error: StageErrorPolicy::Abort,
+            },
+            command_stage("tr '[:lower:]' '[:upper:]'", StageErrorPolicy::Abort),
+        ]);
+        let processor = PostProcessor::new(&config);
+        assert_eq!(processor.stage_names(), vec!["command"]);
+        assert_eq!(processor.process("hello").await, "HELLO");
+    }
+
+    #[test]
+    fn test_for_profile_templates() {
+        let config: Config = toml::from_str(
+            r#"
+            [profiles.git]
+            post_process_template = "commit-message"
+
+            [profiles.script]
+            post_process_command = "cat"
+            post_process_template = "email_tone"
+
+            [profiles.typo]
+            post_process_template = "haiku"
+
+            [profiles.plain]
+            output_mode = "clipboard"
+            "#,
+        )
+        .unwrap();
+        let processors = PostProcessor::for_profile_templates(&config);
+        assert_eq!(processors.len(), 1);
+        assert_eq!(processors["git"].stage_names(), vec!["llm"]);
+    }
+
     #[tokio::test]
     async fn test_multiline_input() {
         let config = make_config("cat", 5000);
@@ -292,6 +749,7 @@ mod tests {
             timeout_ms: 5000,
             trim: false,
             fallback_on_empty: true,
+            stages: Vec::new(),
         };
         let processor = PostProcessor::new(&config);
         let result = processor.process("hello world.").await;
@@ -306,6 +764,7 @@ mod tests {
             timeout_ms: 5000,
             trim: false,
             fallback_on_empty: true,
+            stages: Vec::new(),
         };
         let processor = PostProcessor::new(&config);
         let result = processor.process("ignored").await;
@@ -320,6 +779,7 @@ mod tests {
             timeout_ms: 5000,
             trim: true,
             fallback_on_empty: false,
+            stages: Vec::new(),
         };
         let processor = PostProcessor::new(&config);
         let result = processor.process("original text").await;
@@ -344,6 +804,7 @@ mod tests {
             timeout_ms: 5000,
             trim: false,
             fallback_on_empty: false,
+            stages: Vec::new(),
         };
         let processor = PostProcessor::new(&config);
         let result = processor.process("original text").await;
@@ -359,6 +820,7 @@ mod tests {
             timeout_ms: 5000,
             trim: true,
             fallback_on_empty: true,
+            stages: Vec::new(),
         };
         let processor = PostProcessor::new(&config);
         let result = processor.process("original text").await;
@@ -373,6 +835,7 @@ mod tests {
             timeout_ms: 5000,
             trim: true,
             fallback_on_empty: false,
+            stages: Vec::new(),
         };
         let processor = PostProcessor::new(&config);
         let result = processor.process("original text").await;
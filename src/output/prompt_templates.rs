@@ -0,0 +1,74 @@
+//! Built-in prompts for LLM post-processing
+//!
+//! Profiles pick one with `post_process_template` instead of writing their
+//! own prompt. The template becomes the system prompt for the `[output.llm]`
+//! backend (Ollama with default settings when `[output.llm]` is not set):
+//!
+//! ```toml
+//! [profiles.git]
+//! post_process_template = "commit_message"
+//! ```
+
+/// Template names and their prompts
+pub const TEMPLATES: &[(&str, &str)] = &[
+    (
+        "email_tone",
+        "Rewrite the dictated text as part of a polite, professional email. Fix grammar \
+and punctuation, remove filler words, and keep every fact, name and number. Do not add a \
+greeting, signature or subject line unless they were dictated. Reply with only the text.",
+    ),
+    (
+        "bullet_summary",
+        "Summarize the dictated text as a short markdown bullet list, one idea per bullet, \
+each starting with \"- \". Keep names, numbers and decisions. Reply with only the list.",
+    ),
+    (
+        "code_comment",
+        "Turn the dictated text into a concise code comment: fix grammar and punctuation, \
+remove filler words, use the present tense and technical terms as spoken. Do not add comment \
+markers like // or #. Reply with only the comment text.",
+    ),
+    (
+        "commit_message",
+        "Turn the dictated text into a git commit message: a summary line in the imperative \
+mood, at most 72 characters, without a trailing period. If there are details beyond the \
+summary, add a blank line and a short body wrapped at 72 characters. Reply with only the \
+commit message.",
+    ),
+];
+
+/// The prompt for template `name`. Case and `-`/`_` don't matter, so
+/// "Commit-Message" finds "commit_message".
+pub fn get(name: &str) -> Option<&'static str> {
+    let name = name.trim().to_lowercase().replace('-', "_");
+    TEMPLATES
+        .iter()
+        .find(|(template, _)| *template == name)
+        .map(|(_, prompt)| *prompt)
+}
+
+/// Comma-separated template names, for error messages
+pub fn names() -> String {
+    TEMPLATES
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_template() {
+        assert!(get("commit_message").unwrap().contains("imperative"));
+        assert_eq!(get("Commit-Message"), get("commit_message"));
+        assert!(get("bullet_summary").is_some());
+        assert!(get("haiku").is_none());
+        assert_eq!(
+            names(),
+            "email_tone, bullet_summary, code_comment, commit_message"
+        );
+    }
+}
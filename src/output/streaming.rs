@@ -257,6 +257,52 @@ impl StreamingSession {
         Ok(())
     }
 
+    /// Bring the cursor in line with a cumulative hypothesis from a
+    /// re-decoding backend ([`StreamingEvent::Hypothesis`]).
+    ///
+    /// The partial buffer holds the hypothesis text currently at the
+    /// cursor. Only the part after the longest common prefix is touched:
+    /// the stale tail is backspaced and the new tail typed. When no
+    /// backspace-capable backend is available the correction is skipped
+    /// entirely, so the cursor and `typed_chars` never drift apart; text
+    /// that merely extends what was typed still goes through.
+    ///
+    /// [`StreamingEvent::Hypothesis`]: crate::transcribe::StreamingEvent::Hypothesis
+    pub async fn apply_hypothesis(
+        &mut self,
+        chain: &[Box<dyn TextOutput>],
+        text: &str,
+        pre_output_command: Option<&str>,
+        post_output_command: Option<&str>,
+    ) -> Result<(), OutputError> {
+        let (keep, backspace) = hypothesis_diff(&self.partial, text);
+        if backspace > 0 {
+            if emit_backspaces(backspace).await == 0 {
+                tracing::warn!(
+                    "Streaming hypothesis: no backspace-capable backend available; \
+                     keeping previously typed text"
+                );
+                return Ok(());
+            }
+            self.partial = self.partial.chars().take(keep).collect();
+            self.typed_chars = self.typed_chars.saturating_sub(backspace);
+        }
+
+        let suffix: String = text.chars().skip(keep).collect();
+        if !suffix.is_empty() {
+            let opts = OutputOptions {
+                pre_output_command,
+                post_output_command,
+                wait_for_modifier_release: false,
+                modifier_release_timeout: std::time::Duration::from_millis(0),
+            };
+            output_with_fallback(chain, &suffix, opts).await?;
+            self.typed_chars += suffix.chars().count();
+            self.partial.push_str(&suffix);
+        }
+        Ok(())
+    }
+
     /// Best-effort rewind: emit `typed_chars` BackSpace key events via
     /// wtype, falling back to dotool then ydotool. Returns `Ok(())`
     /// even if no backspace backend is available, since the user has
@@ -292,24 +338,72 @@ impl Default for StreamingSession {
     }
 }
 
+/// Split a hypothesis update into the number of already-typed chars to
+/// keep and the number to backspace. Counts are Unicode scalar values.
+fn hypothesis_diff(typed: &str, hypothesis: &str) -> (usize, usize) {
+    let keep = typed
+        .chars()
+        .zip(hypothesis.chars())
+        .take_while(|(a, b)| a == b)
+        .count();
+    (keep, typed.chars().count() - keep)
+}
+
 /// Backspace `count` chars using the first available method.
 /// Returns the actual number of backspaces emitted.
-async fn emit_backspaces(count: usize) -> usize {
+pub(crate) async fn emit_backspaces(count: usize) -> usize {
     if count == 0 {
         return 0;
     }
     if try_wtype_backspaces(count).await {
         return count;
     }
+    if try_xdotool_backspaces(count).await {
+        return count;
+    }
     if try_dotool_backspaces(count).await {
         return count;
     }
     if try_ydotool_backspaces(count).await {
         return count;
     }
+    #[cfg(target_os = "linux")]
+    if try_uinput_backspaces(count).await {
+        return count;
+    }
     0
 }
 
+/// Press Ctrl+Z once using the first available method.
+/// Returns whether the keystroke was sent.
+pub(crate) async fn emit_ctrl_z() -> bool {
+    let mut wtype = Command::new("wtype");
+    wtype.args(["-M", "ctrl", "-k", "z", "-m", "ctrl"]);
+    if run_quietly(wtype).await {
+        return true;
+    }
+    // Under Wayland xdotool only reaches XWayland windows
+    if crate::output::session::detect() == crate::output::session::DisplaySession::X11 {
+        let mut xdotool = Command::new("xdotool");
+        xdotool.args(["key", "--clearmodifiers", "ctrl+z"]);
+        if run_quietly(xdotool).await {
+            return true;
+        }
+    }
+    if run_dotool("key ctrl+z\n").await {
+        return true;
+    }
+    // Linux key codes: LeftCtrl = 29, Z = 44
+    let mut ydotool = Command::new("ydotool");
+    ydotool.args(["key", "29:1", "44:1", "44:0", "29:0"]);
+    run_quietly(ydotool).await
+}
+
+async fn run_quietly(mut cmd: Command) -> bool {
+    cmd.stdout(Stdio::null()).stderr(Stdio::null());
+    matches!(cmd.status().await, Ok(s) if s.success())
+}
+
 async fn try_wtype_backspaces(count: usize) -> bool {
     // wtype invocation: `wtype -k BackSpace` repeated. Build args
     // dynamically to send N keypresses in a single subprocess.
@@ -321,7 +415,26 @@ async fn try_wtype_backspaces(count: usize) -> bool {
     matches!(cmd.status().await, Ok(s) if s.success())
 }
 
+async fn try_xdotool_backspaces(count: usize) -> bool {
+    // Under Wayland xdotool only reaches XWayland windows
+    use crate::output::session::{detect, DisplaySession};
+    if detect() != DisplaySession::X11 {
+        return false;
+    }
+    let mut cmd = Command::new("xdotool");
+    cmd.args(["key", "--clearmodifiers", "--repeat"])
+        .arg(count.to_string())
+        .arg("BackSpace");
+    cmd.stdout(Stdio::null()).stderr(Stdio::null());
+    matches!(cmd.status().await, Ok(s) if s.success())
+}
+
 async fn try_dotool_backspaces(count: usize) -> bool {
+    run_dotool(&"key backspace\n".repeat(count)).await
+}
+
+/// Feed a dotool script to dotoolc/dotool
+async fn run_dotool(script: &str) -> bool {
     // Prefer `dotoolc` whenever dotoold is actually accepting input.
     // Spawning raw `dotool` creates a *new* uinput keyboard per call;
     // KDE Plasma can drop events on the typing keyboard while these
@@ -344,11 +457,7 @@ async fn try_dotool_backspaces(count: usize) -> bool {
         Err(_) => return false,
     };
     if let Some(mut stdin) = child.stdin.take() {
-        let mut buf = String::with_capacity(count * "key backspace\n".len());
-        for _ in 0..count {
-            buf.push_str("key backspace\n");
-        }
-        if stdin.write_all(buf.as_bytes()).await.is_err() {
+        if stdin.write_all(script.as_bytes()).await.is_err() {
             return false;
         }
         drop(stdin);
@@ -368,6 +477,17 @@ async fn try_ydotool_backspaces(count: usize) -> bool {
     matches!(cmd.status().await, Ok(s) if s.success())
 }
 
+#[cfg(target_os = "linux")]
+async fn try_uinput_backspaces(count: usize) -> bool {
+    if !crate::output::uinput::uinput_writable() {
+        return false;
+    }
+    matches!(
+        tokio::task::spawn_blocking(move || crate::output::uinput::backspaces(count)).await,
+        Ok(Ok(()))
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -499,6 +619,37 @@ mod tests {
         assert_eq!(session.partial(), "");
     }
 
+    #[test]
+    fn hypothesis_diff_keeps_common_prefix() {
+        assert_eq!(hypothesis_diff("", "hello"), (0, 0));
+        assert_eq!(hypothesis_diff("hello", "hello world"), (5, 0));
+        assert_eq!(hypothesis_diff("hello word", "hello world"), (9, 1));
+        assert_eq!(hypothesis_diff("hello there", "hello"), (5, 6));
+        assert_eq!(hypothesis_diff("你好世", "你好吗"), (2, 1));
+    }
+
+    #[tokio::test]
+    async fn hypothesis_that_extends_typed_text_only_appends() {
+        let rec = std::sync::Arc::new(RecordingOutput::new());
+        let chain = chain_with(rec.clone());
+        let mut session = StreamingSession::new();
+        session
+            .apply_hypothesis(&chain, "hello", None, None)
+            .await
+            .unwrap();
+        session
+            .apply_hypothesis(&chain, "hello world", None, None)
+            .await
+            .unwrap();
+        session
+            .apply_hypothesis(&chain, "hello world", None, None)
+            .await
+            .unwrap();
+        assert_eq!(rec.typed(), vec!["hello".to_string(), " world".to_string()]);
+        assert_eq!(session.partial(), "hello world");
+        assert_eq!(session.typed_chars(), 11);
+    }
+
     #[tokio::test]
     async fn rewind_with_zero_chars_is_ok() {
         let mut session = StreamingSession::new();
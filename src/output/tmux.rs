@@ -0,0 +1,131 @@
+//! tmux paste buffer output
+//!
+//! Loads the text into a new tmux paste buffer (`tmux load-buffer -`), where
+//! prefix + ] pastes it. With `tmux_paste` it is pasted straight into the
+//! active pane (`tmux paste-buffer -p`), bracketed when the program in the
+//! pane asked for bracketed paste, so a shell doesn't run a dictated
+//! command before Enter. No keystrokes are simulated, so nothing collides
+//! with the tmux prefix key.
+//!
+//! The daemon talks to the default tmux server, or the one in `$TMUX` when
+//! it was started inside tmux.
+//!
+//! Requires: tmux with a running server
+
+use super::TextOutput;
+use crate::error::OutputError;
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+/// tmux paste buffer output
+pub struct TmuxOutput {
+    /// Paste the buffer into the active pane
+    paste: bool,
+    /// Press Enter in the active pane after pasting
+    auto_submit: bool,
+    /// Text to append after transcription
+    append_text: Option<String>,
+}
+
+impl TmuxOutput {
+    pub fn new(paste: bool, auto_submit: bool, append_text: Option<String>) -> Self {
+        Self {
+            paste,
+            auto_submit,
+            append_text,
+        }
+    }
+
+    async fn tmux(args: &[&str]) -> Result<(), OutputError> {
+        let output = Command::new("tmux")
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .map_err(|e| OutputError::InjectionFailed(format!("tmux: {}", e)))?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(OutputError::InjectionFailed(format!(
+                "tmux {}: {}",
+                args[0],
+                String::from_utf8_lossy(&output.stderr).trim()
+            )))
+        }
+    }
+
+    async fn load_buffer(text: &str) -> Result<(), OutputError> {
+        let mut child = Command::new("tmux")
+            .args(["load-buffer", "-"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| OutputError::InjectionFailed(format!("tmux: {}", e)))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(text.as_bytes())
+                .await
+                .map_err(|e| OutputError::InjectionFailed(format!("tmux: {}", e)))?;
+            drop(stdin);
+        }
+
+        let output = child
+            .wait_with_output()
+            .await
+            .map_err(|e| OutputError::InjectionFailed(format!("tmux: {}", e)))?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(OutputError::InjectionFailed(format!(
+                "tmux load-buffer: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TextOutput for TmuxOutput {
+    async fn output(&self, text: &str) -> Result<(), OutputError> {
+        if text.is_empty() {
+            return Ok(());
+        }
+
+        let text = if let Some(ref append) = self.append_text {
+            std::borrow::Cow::Owned(format!("{}{}", text, append))
+        } else {
+            std::borrow::Cow::Borrowed(text)
+        };
+
+        Self::load_buffer(&text).await?;
+        if !self.paste {
+            tracing::info!(
+                "Text loaded into tmux buffer ({} chars), paste with prefix + ]",
+                text.len()
+            );
+            return Ok(());
+        }
+
+        // -p: bracketed paste when the pane's program requested it
+        Self::tmux(&["paste-buffer", "-p"]).await?;
+        if self.auto_submit {
+            Self::tmux(&["send-keys", "Enter"]).await?;
+        }
+        tracing::info!("Text pasted into tmux pane ({} chars)", text.len());
+        Ok(())
+    }
+
+    async fn is_available(&self) -> bool {
+        // Fails when tmux isn't installed or no server is running
+        Self::tmux(&["has-session"]).await.is_ok()
+    }
+
+    fn name(&self) -> &'static str {
+        "tmux"
+    }
+}
@@ -0,0 +1,650 @@
+//! Built-in uinput text output
+//!
+//! Creates a virtual keyboard through `/dev/uinput` and types the text
+//! itself, so no wtype, dotool or ydotool binary is needed. The keyboard is
+//! created on first use and kept for the life of the daemon: compositors
+//! take a moment to pick up a new input device, and KDE Plasma can drop
+//! events while keyboards appear and disappear.
+//!
+//! The virtual keyboard sends key codes, which the compositor turns into
+//! characters with the active keymap. Voxtype therefore has to know that
+//! layout: `uinput_xkb_layout` selects a built-in table (`us`, `gb`, `de`,
+//! `fr`). Text containing a character the layout can't type (emoji, CJK,
+//! dead-key accents) fails as a whole, and the fallback chain moves on.
+//!
+//! ## Requirements
+//!
+//! - Write access to `/dev/uinput` (usually the `input` group, or a udev
+//!   rule granting it)
+//! - `uinput_xkb_layout` matching the desktop layout for non-US keyboards
+
+use super::TextOutput;
+use crate::error::OutputError;
+use evdev::uinput::{VirtualDevice, VirtualDeviceBuilder};
+use evdev::{AttributeSet, EventType, InputEvent, Key};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Virtual keyboard shared by every output chain; created on first use
+static KEYBOARD: Mutex<Option<VirtualDevice>> = Mutex::new(None);
+
+/// Time for the compositor to register a newly created keyboard
+const DEVICE_SETTLE: Duration = Duration::from_millis(200);
+
+/// Minimum key hold and gap between keys. Events sent back to back can
+/// arrive faster than some compositors process them.
+const MIN_KEY_DELAY_MS: u32 = 2;
+
+/// Keyboard layouts with a built-in character table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    Us,
+    Gb,
+    De,
+    Fr,
+}
+
+impl Layout {
+    /// Layout for an XKB layout name ("us", "gb", "de", "fr")
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "us" => Some(Layout::Us),
+            "gb" => Some(Layout::Gb),
+            "de" => Some(Layout::De),
+            "fr" => Some(Layout::Fr),
+            _ => None,
+        }
+    }
+
+    /// Keys that type `c`, or `None` if this layout can't type it directly
+    fn stroke(self, c: char) -> Option<Stroke> {
+        match self {
+            Layout::Us => us(c),
+            Layout::Gb => gb(c).or_else(|| us(c)),
+            Layout::De => de(c),
+            Layout::Fr => fr(c),
+        }
+    }
+}
+
+/// One key press, with the modifiers held around it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Stroke {
+    pub(super) key: Key,
+    pub(super) shift: bool,
+    pub(super) altgr: bool,
+}
+
+fn plain(key: Key) -> Option<Stroke> {
+    Some(Stroke {
+        key,
+        shift: false,
+        altgr: false,
+    })
+}
+
+fn shift(key: Key) -> Option<Stroke> {
+    Some(Stroke {
+        key,
+        shift: true,
+        altgr: false,
+    })
+}
+
+fn altgr(key: Key) -> Option<Stroke> {
+    Some(Stroke {
+        key,
+        shift: false,
+        altgr: true,
+    })
+}
+
+const LETTER_KEYS: [Key; 26] = [
+    Key::KEY_A,
+    Key::KEY_B,
+    Key::KEY_C,
+    Key::KEY_D,
+    Key::KEY_E,
+    Key::KEY_F,
+    Key::KEY_G,
+    Key::KEY_H,
+    Key::KEY_I,
+    Key::KEY_J,
+    Key::KEY_K,
+    Key::KEY_L,
+    Key::KEY_M,
+    Key::KEY_N,
+    Key::KEY_O,
+    Key::KEY_P,
+    Key::KEY_Q,
+    Key::KEY_R,
+    Key::KEY_S,
+    Key::KEY_T,
+    Key::KEY_U,
+    Key::KEY_V,
+    Key::KEY_W,
+    Key::KEY_X,
+    Key::KEY_Y,
+    Key::KEY_Z,
+];
+
+const DIGIT_KEYS: [Key; 10] = [
+    Key::KEY_0,
+    Key::KEY_1,
+    Key::KEY_2,
+    Key::KEY_3,
+    Key::KEY_4,
+    Key::KEY_5,
+    Key::KEY_6,
+    Key::KEY_7,
+    Key::KEY_8,
+    Key::KEY_9,
+];
+
+/// Key for an ASCII letter at its QWERTY position
+fn letter(c: char) -> Key {
+    LETTER_KEYS[(c.to_ascii_lowercase() as u8 - b'a') as usize]
+}
+
+fn digit(c: char) -> Key {
+    DIGIT_KEYS[(c as u8 - b'0') as usize]
+}
+
+/// Letters, typed with Shift when uppercase, with `position` giving the
+/// key for each lowercase letter
+fn letter_stroke(c: char, position: impl Fn(char) -> Key) -> Option<Stroke> {
+    if c.is_ascii_lowercase() {
+        plain(position(c))
+    } else if c.is_ascii_uppercase() {
+        shift(position(c.to_ascii_lowercase()))
+    } else {
+        None
+    }
+}
+
+/// Whitespace, the same on every layout
+fn whitespace(c: char) -> Option<Stroke> {
+    match c {
+        ' ' => plain(Key::KEY_SPACE),
+        '\n' => plain(Key::KEY_ENTER),
+        '\t' => plain(Key::KEY_TAB),
+        _ => None,
+    }
+}
+
+/// US QWERTY, also the ASCII fast path
+fn us(c: char) -> Option<Stroke> {
+    if c.is_ascii_alphabetic() {
+        return letter_stroke(c, letter);
+    }
+    if c.is_ascii_digit() {
+        return plain(digit(c));
+    }
+    match c {
+        '!' => shift(Key::KEY_1),
+        '@' => shift(Key::KEY_2),
+        '#' => shift(Key::KEY_3),
+        '$' => shift(Key::KEY_4),
+        '%' => shift(Key::KEY_5),
+        '^' => shift(Key::KEY_6),
+        '&' => shift(Key::KEY_7),
+        '*' => shift(Key::KEY_8),
+        '(' => shift(Key::KEY_9),
+        ')' => shift(Key::KEY_0),
+        '-' => plain(Key::KEY_MINUS),
+        '_' => shift(Key::KEY_MINUS),
+        '=' => plain(Key::KEY_EQUAL),
+        '+' => shift(Key::KEY_EQUAL),
+        '[' => plain(Key::KEY_LEFTBRACE),
+        '{' => shift(Key::KEY_LEFTBRACE),
+        ']' => plain(Key::KEY_RIGHTBRACE),
+        '}' => shift(Key::KEY_RIGHTBRACE),
+        '\\' => plain(Key::KEY_BACKSLASH),
+        '|' => shift(Key::KEY_BACKSLASH),
+        ';' => plain(Key::KEY_SEMICOLON),
+        ':' => shift(Key::KEY_SEMICOLON),
+        '\'' => plain(Key::KEY_APOSTROPHE),
+        '"' => shift(Key::KEY_APOSTROPHE),
+        '`' => plain(Key::KEY_GRAVE),
+        '~' => shift(Key::KEY_GRAVE),
+        ',' => plain(Key::KEY_COMMA),
+        '<' => shift(Key::KEY_COMMA),
+        '.' => plain(Key::KEY_DOT),
+        '>' => shift(Key::KEY_DOT),
+        '/' => plain(Key::KEY_SLASH),
+        '?' => shift(Key::KEY_SLASH),
+        _ => whitespace(c),
+    }
+}
+
+/// UK QWERTY: the characters whose keys differ from US. Every US key that
+/// types something else on a UK keyboard is covered here, so the rest can
+/// fall back to the US table.
+fn gb(c: char) -> Option<Stroke> {
+    match c {
+        '"' => shift(Key::KEY_2),
+        '£' => shift(Key::KEY_3),
+        '@' => shift(Key::KEY_APOSTROPHE),
+        '#' => plain(Key::KEY_BACKSLASH),
+        '~' => shift(Key::KEY_BACKSLASH),
+        '\\' => plain(Key::KEY_102ND),
+        '|' => shift(Key::KEY_102ND),
+        '¬' => shift(Key::KEY_GRAVE),
+        '€' => altgr(Key::KEY_4),
+        _ => None,
+    }
+}
+
+/// German QWERTZ (no dead keys)
+fn de(c: char) -> Option<Stroke> {
+    if c.is_ascii_alphabetic() {
+        // Y and Z swap places
+        return letter_stroke(c, |c| match c {
+            'y' => Key::KEY_Z,
+            'z' => Key::KEY_Y,
+            c => letter(c),
+        });
+    }
+    if c.is_ascii_digit() {
+        return plain(digit(c));
+    }
+    match c {
+        '!' => shift(Key::KEY_1),
+        '"' => shift(Key::KEY_2),
+        '§' => shift(Key::KEY_3),
+        '$' => shift(Key::KEY_4),
+        '%' => shift(Key::KEY_5),
+        '&' => shift(Key::KEY_6),
+        '/' => shift(Key::KEY_7),
+        '(' => shift(Key::KEY_8),
+        ')' => shift(Key::KEY_9),
+        '=' => shift(Key::KEY_0),
+        '²' => altgr(Key::KEY_2),
+        '³' => altgr(Key::KEY_3),
+        '{' => altgr(Key::KEY_7),
+        '[' => altgr(Key::KEY_8),
+        ']' => altgr(Key::KEY_9),
+        '}' => altgr(Key::KEY_0),
+        'ß' => plain(Key::KEY_MINUS),
+        '?' => shift(Key::KEY_MINUS),
+        '\\' => altgr(Key::KEY_MINUS),
+        'ü' => plain(Key::KEY_LEFTBRACE),
+        'Ü' => shift(Key::KEY_LEFTBRACE),
+        '+' => plain(Key::KEY_RIGHTBRACE),
+        '*' => shift(Key::KEY_RIGHTBRACE),
+        '~' => altgr(Key::KEY_RIGHTBRACE),
+        'ö' => plain(Key::KEY_SEMICOLON),
+        'Ö' => shift(Key::KEY_SEMICOLON),
+        'ä' => plain(Key::KEY_APOSTROPHE),
+        'Ä' => shift(Key::KEY_APOSTROPHE),
+        '#' => plain(Key::KEY_BACKSLASH),
+        '\'' => shift(Key::KEY_BACKSLASH),
+        '°' => shift(Key::KEY_GRAVE),
+        ',' => plain(Key::KEY_COMMA),
+        ';' => shift(Key::KEY_COMMA),
+        '.' => plain(Key::KEY_DOT),
+        ':' => shift(Key::KEY_DOT),
+        '-' => plain(Key::KEY_SLASH),
+        '_' => shift(Key::KEY_SLASH),
+        '<' => plain(Key::KEY_102ND),
+        '>' => shift(Key::KEY_102ND),
+        '|' => altgr(Key::KEY_102ND),
+        '@' => altgr(Key::KEY_Q),
+        '€' => altgr(Key::KEY_E),
+        'µ' => altgr(Key::KEY_M),
+        _ => whitespace(c),
+    }
+}
+
+/// French AZERTY (no dead keys)
+fn fr(c: char) -> Option<Stroke> {
+    if c.is_ascii_alphabetic() {
+        // A/Q and Z/W swap places, and M sits right of L
+        return letter_stroke(c, |c| match c {
+            'a' => Key::KEY_Q,
+            'q' => Key::KEY_A,
+            'z' => Key::KEY_W,
+            'w' => Key::KEY_Z,
+            'm' => Key::KEY_SEMICOLON,
+            c => letter(c),
+        });
+    }
+    // Digits need Shift on the number row
+    if c.is_ascii_digit() {
+        return shift(digit(c));
+    }
+    match c {
+        '&' => plain(Key::KEY_1),
+        'é' => plain(Key::KEY_2),
+        '"' => plain(Key::KEY_3),
+        '\'' => plain(Key::KEY_4),
+        '(' => plain(Key::KEY_5),
+        '-' => plain(Key::KEY_6),
+        'è' => plain(Key::KEY_7),
+        '_' => plain(Key::KEY_8),
+        'ç' => plain(Key::KEY_9),
+        'à' => plain(Key::KEY_0),
+        '#' => altgr(Key::KEY_3),
+        '{' => altgr(Key::KEY_4),
+        '[' => altgr(Key::KEY_5),
+        '|' => altgr(Key::KEY_6),
+        '\\' => altgr(Key::KEY_8),
+        '^' => altgr(Key::KEY_9),
+        '@' => altgr(Key::KEY_0),
+        ')' => plain(Key::KEY_MINUS),
+        '°' => shift(Key::KEY_MINUS),
+        ']' => altgr(Key::KEY_MINUS),
+        '=' => plain(Key::KEY_EQUAL),
+        '+' => shift(Key::KEY_EQUAL),
+        '}' => altgr(Key::KEY_EQUAL),
+        '$' => plain(Key::KEY_RIGHTBRACE),
+        '£' => shift(Key::KEY_RIGHTBRACE),
+        'ù' => plain(Key::KEY_APOSTROPHE),
+        '%' => shift(Key::KEY_APOSTROPHE),
+        '*' => plain(Key::KEY_BACKSLASH),
+        'µ' => shift(Key::KEY_BACKSLASH),
+        '²' => plain(Key::KEY_GRAVE),
+        ',' => plain(Key::KEY_M),
+        '?' => shift(Key::KEY_M),
+        ';' => plain(Key::KEY_COMMA),
+        '.' => shift(Key::KEY_COMMA),
+        ':' => plain(Key::KEY_DOT),
+        '/' => shift(Key::KEY_DOT),
+        '!' => plain(Key::KEY_SLASH),
+        '§' => shift(Key::KEY_SLASH),
+        '<' => plain(Key::KEY_102ND),
+        '>' => shift(Key::KEY_102ND),
+        '€' => altgr(Key::KEY_E),
+        _ => whitespace(c),
+    }
+}
+
+/// Keys for all of `text`, or the first character `layout` can't type
+pub(super) fn strokes(
+    text: &str,
+    layout: Layout,
+    shift_enter_newlines: bool,
+) -> Result<Vec<Stroke>, char> {
+    text.chars()
+        .map(|c| {
+            let stroke = layout.stroke(c).ok_or(c)?;
+            Ok(match c {
+                '\n' if shift_enter_newlines => Stroke {
+                    shift: true,
+                    ..stroke
+                },
+                _ => stroke,
+            })
+        })
+        .collect()
+}
+
+fn create_keyboard() -> std::io::Result<VirtualDevice> {
+    // Every key on a standard keyboard, KEY_ESC through KEY_COMPOSE
+    let mut keys = AttributeSet::<Key>::new();
+    for code in Key::KEY_ESC.code()..=Key::KEY_COMPOSE.code() {
+        keys.insert(Key::new(code));
+    }
+    VirtualDeviceBuilder::new()?
+        .name("voxtype virtual keyboard")
+        .with_keys(&keys)?
+        .build()
+}
+
+fn key_event(key: Key, value: i32) -> InputEvent {
+    InputEvent::new(EventType::KEY, key.code(), value)
+}
+
+/// Press and release every stroke on the shared virtual keyboard.
+/// Blocking; call from a blocking thread.
+fn type_strokes(strokes: &[Stroke], delay: Duration) -> std::io::Result<()> {
+    let mut keyboard = KEYBOARD.lock().unwrap_or_else(|e| e.into_inner());
+    let device = match &mut *keyboard {
+        Some(device) => device,
+        slot @ None => {
+            let device = create_keyboard()?;
+            tracing::debug!("uinput: created virtual keyboard");
+            std::thread::sleep(DEVICE_SETTLE);
+            slot.insert(device)
+        }
+    };
+
+    let result = strokes.iter().try_for_each(|stroke| {
+        let modifiers: Vec<Key> = [
+            (stroke.shift, Key::KEY_LEFTSHIFT),
+            (stroke.altgr, Key::KEY_RIGHTALT),
+        ]
+        .into_iter()
+        .filter_map(|(held, key)| held.then_some(key))
+        .collect();
+
+        for &modifier in &modifiers {
+            device.emit(&[key_event(modifier, 1)])?;
+        }
+        device.emit(&[key_event(stroke.key, 1)])?;
+        std::thread::sleep(delay);
+        device.emit(&[key_event(stroke.key, 0)])?;
+        for &modifier in modifiers.iter().rev() {
+            device.emit(&[key_event(modifier, 0)])?;
+        }
+        std::thread::sleep(delay);
+        Ok(())
+    });
+
+    // Recreate the keyboard next time if it stopped working
+    if result.is_err() {
+        *keyboard = None;
+    }
+    result
+}
+
+/// Whether `/dev/uinput` can be opened for writing
+pub fn uinput_writable() -> bool {
+    std::fs::OpenOptions::new()
+        .write(true)
+        .open("/dev/uinput")
+        .is_ok()
+}
+
+/// Send `count` backspaces through the virtual keyboard. Blocking.
+pub fn backspaces(count: usize) -> std::io::Result<()> {
+    let backspace = Stroke {
+        key: Key::KEY_BACKSPACE,
+        shift: false,
+        altgr: false,
+    };
+    type_strokes(
+        &vec![backspace; count],
+        Duration::from_millis(MIN_KEY_DELAY_MS as u64),
+    )
+}
+
+/// Text output through a built-in uinput virtual keyboard
+pub struct UinputOutput {
+    /// Delay between keypresses in milliseconds
+    type_delay_ms: u32,
+    /// Delay before typing starts in milliseconds
+    pre_type_delay_ms: u32,
+    /// Whether to send Enter key after output
+    auto_submit: bool,
+    /// Text to append after transcription (before auto_submit)
+    append_text: Option<String>,
+    /// Type newlines as Shift+Enter
+    shift_enter_newlines: bool,
+    /// Character table for the desktop keyboard layout, `None` when the
+    /// configured layout has no table
+    layout: Option<Layout>,
+}
+
+impl UinputOutput {
+    /// Create a new uinput output. `xkb_layout` is the desktop keyboard
+    /// layout (default "us").
+    pub fn new(
+        type_delay_ms: u32,
+        pre_type_delay_ms: u32,
+        auto_submit: bool,
+        append_text: Option<String>,
+        shift_enter_newlines: bool,
+        xkb_layout: Option<String>,
+    ) -> Self {
+        let name = xkb_layout.as_deref().unwrap_or("us");
+        let layout = Layout::from_name(name);
+        if layout.is_none() {
+            tracing::warn!(
+                "uinput: no built-in table for keyboard layout '{}' (supported: us, gb, de, fr)",
+                name
+            );
+        }
+        Self {
+            type_delay_ms,
+            pre_type_delay_ms,
+            auto_submit,
+            append_text,
+            shift_enter_newlines,
+            layout,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TextOutput for UinputOutput {
+    async fn output(&self, text: &str) -> Result<(), OutputError> {
+        if text.is_empty() {
+            return Ok(());
+        }
+        let layout = self.layout.ok_or_else(|| {
+            OutputError::InjectionFailed("uinput: unsupported keyboard layout".to_string())
+        })?;
+
+        // Map everything before typing anything, so unsupported text falls
+        // through to the next driver instead of being typed halfway
+        let mut full_text = text.to_string();
+        if let Some(ref append) = self.append_text {
+            full_text.push_str(append);
+        }
+        let mut keys = strokes(&full_text, layout, self.shift_enter_newlines).map_err(|c| {
+            OutputError::InjectionFailed(format!(
+                "uinput: no key for {:?} on layout {:?}",
+                c, layout
+            ))
+        })?;
+        if self.auto_submit {
+            keys.push(Stroke {
+                key: Key::KEY_ENTER,
+                shift: false,
+                altgr: false,
+            });
+        }
+
+        // Pre-typing delay if configured
+        if self.pre_type_delay_ms > 0 {
+            tracing::debug!(
+                "uinput: sleeping {}ms before typing",
+                self.pre_type_delay_ms
+            );
+            tokio::time::sleep(Duration::from_millis(self.pre_type_delay_ms as u64)).await;
+        }
+
+        tracing::debug!(
+            "uinput: typing {} keys \"{}\"",
+            keys.len(),
+            text.chars().take(20).collect::<String>()
+        );
+        let delay = Duration::from_millis(self.type_delay_ms.max(MIN_KEY_DELAY_MS) as u64);
+        tokio::task::spawn_blocking(move || type_strokes(&keys, delay))
+            .await
+            .map_err(|e| OutputError::InjectionFailed(format!("uinput: {}", e)))?
+            .map_err(|e| OutputError::InjectionFailed(format!("uinput: {}", e)))
+    }
+
+    async fn is_available(&self) -> bool {
+        self.layout.is_some() && uinput_writable()
+    }
+
+    fn name(&self) -> &'static str {
+        "uinput"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(text: &str, layout: Layout) -> Vec<(Key, bool, bool)> {
+        strokes(text, layout, false)
+            .unwrap()
+            .into_iter()
+            .map(|s| (s.key, s.shift, s.altgr))
+            .collect()
+    }
+
+    #[test]
+    fn test_us_ascii() {
+        assert_eq!(
+            keys("Hi!", Layout::Us),
+            vec![
+                (Key::KEY_H, true, false),
+                (Key::KEY_I, false, false),
+                (Key::KEY_1, true, false),
+            ]
+        );
+        // Every printable ASCII character is typeable on US
+        for c in ' '..='~' {
+            assert!(Layout::Us.stroke(c).is_some(), "{:?}", c);
+        }
+        assert_eq!(strokes("café", Layout::Us, false), Err('é'));
+    }
+
+    #[test]
+    fn test_gb_overrides() {
+        assert_eq!(keys("\"", Layout::Gb), vec![(Key::KEY_2, true, false)]);
+        assert_eq!(
+            keys("@", Layout::Gb),
+            vec![(Key::KEY_APOSTROPHE, true, false)]
+        );
+        assert_eq!(keys("a", Layout::Gb), vec![(Key::KEY_A, false, false)]);
+    }
+
+    #[test]
+    fn test_de_layout() {
+        assert_eq!(
+            keys("zY", Layout::De),
+            vec![(Key::KEY_Y, false, false), (Key::KEY_Z, true, false)]
+        );
+        assert_eq!(
+            keys("ö@", Layout::De),
+            vec![
+                (Key::KEY_SEMICOLON, false, false),
+                (Key::KEY_Q, false, true)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fr_layout() {
+        assert_eq!(
+            keys("am1,", Layout::Fr),
+            vec![
+                (Key::KEY_Q, false, false),
+                (Key::KEY_SEMICOLON, false, false),
+                (Key::KEY_1, true, false),
+                (Key::KEY_M, false, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shift_enter_newlines() {
+        let typed = strokes("a\nb", Layout::Us, true).unwrap();
+        assert_eq!(typed[1].key, Key::KEY_ENTER);
+        assert!(typed[1].shift);
+    }
+
+    #[test]
+    fn test_layout_names() {
+        assert_eq!(Layout::from_name("DE"), Some(Layout::De));
+        assert_eq!(Layout::from_name("gb"), Some(Layout::Gb));
+        // "uk" is Ukrainian in XKB, not the UK layout
+        assert_eq!(Layout::from_name("uk"), None);
+    }
+}
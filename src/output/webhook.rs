@@ -0,0 +1,94 @@
+//! HTTP webhook output
+//!
+//! Posts each dictation as JSON to `[output.webhook] url`, for automation
+//! tools (n8n, Node-RED, Home Assistant), note apps or ticket systems:
+//!
+//! ```json
+//! {"text": "call the dentist", "profile": "todo", "timestamp": "2026-03-14T09:41:00+01:00"}
+//! ```
+//!
+//! `mode = "webhook"` posts instead of typing; `always = true` posts every
+//! dictation in addition to the normal output.
+
+use crate::config::WebhookConfig;
+use crate::error::OutputError;
+use std::time::Duration;
+
+/// Request body, also published by the MQTT integration
+pub(crate) fn payload(text: &str, profile: Option<&str>, timestamp: &str) -> serde_json::Value {
+    serde_json::json!({
+        "text": text,
+        "profile": profile,
+        "timestamp": timestamp,
+    })
+}
+
+/// POST `text` to the configured webhook
+pub async fn send(
+    config: &WebhookConfig,
+    text: &str,
+    profile: Option<&str>,
+) -> Result<(), OutputError> {
+    let url = config
+        .url
+        .clone()
+        .filter(|url| !url.trim().is_empty())
+        .ok_or_else(|| {
+            OutputError::InjectionFailed("webhook: [output.webhook] url is not set".to_string())
+        })?;
+    let token = config
+        .bearer_token
+        .clone()
+        .or_else(|| std::env::var("VOXTYPE_WEBHOOK_TOKEN").ok());
+    let body = payload(text, profile, &chrono::Local::now().to_rfc3339());
+    let timeout = Duration::from_millis(config.timeout_ms);
+
+    let result = tokio::task::spawn_blocking(move || {
+        let mut request = crate::network::agent_builder(&url)
+            .build()
+            .post(&url)
+            .timeout(timeout);
+        if let Some(ref token) = token {
+            request = request.set("Authorization", &format!("Bearer {}", token));
+        }
+        request.send_json(body).map_err(|e| match e {
+            ureq::Error::Status(code, resp) => format!(
+                "{} returned {}: {}",
+                url,
+                code,
+                resp.into_string().unwrap_or_default().trim()
+            ),
+            ureq::Error::Transport(t) => format!("{}: {}", url, t),
+        })
+    })
+    .await
+    .map_err(|e| OutputError::InjectionFailed(format!("webhook: {}", e)))?;
+
+    result
+        .map(|_| tracing::info!("Text posted to webhook ({} chars)", text.len()))
+        .map_err(|e| OutputError::InjectionFailed(format!("webhook: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payload() {
+        assert_eq!(
+            payload("hello", Some("todo"), "2026-03-14T09:41:00+01:00"),
+            serde_json::json!({
+                "text": "hello",
+                "profile": "todo",
+                "timestamp": "2026-03-14T09:41:00+01:00",
+            })
+        );
+        assert_eq!(payload("hi", None, "t")["profile"], serde_json::Value::Null);
+    }
+
+    #[tokio::test]
+    async fn test_send_without_url_fails() {
+        let result = send(&WebhookConfig::default(), "hello", None).await;
+        assert!(result.is_err());
+    }
+}
@@ -1,7 +1,7 @@
 //! X11 clipboard output
 //!
-//! Copies text to the X11 CLIPBOARD selection using `xclip` (preferred) or
-//! `xsel` (fallback). Activates only under an X11 session; Wayland sessions
+//! Copies text to the X11 CLIPBOARD selection (or PRIMARY, for middle-click
+//! paste) using `xclip` (preferred) or `xsel` (fallback). Activates only under an X11 session; Wayland sessions
 //! are handled by `ClipboardOutput` (wl-copy).
 //!
 //! See GitHub issue #346 for the original report: under XLibre/X11 sessions
@@ -10,10 +10,12 @@
 //!
 //! Requires one of: `xclip` or `xsel` installed.
 
+use super::clipboard_snapshot::{self, ClipboardSnapshot};
 use super::session::{detect, DisplaySession};
 use super::TextOutput;
 use crate::error::OutputError;
 use std::process::Stdio;
+use std::time::Duration;
 use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 
@@ -21,12 +23,36 @@ use tokio::process::Command;
 pub struct XclipOutput {
     /// Text to append after transcription
     append_text: Option<String>,
+    /// Copy to the PRIMARY selection instead of CLIPBOARD
+    primary: bool,
+    /// Restore the previous clipboard this long after copying
+    restore_after: Option<Duration>,
 }
 
 impl XclipOutput {
     /// Create a new X11 clipboard output
     pub fn new(append_text: Option<String>) -> Self {
-        Self { append_text }
+        Self {
+            append_text,
+            primary: false,
+            restore_after: None,
+        }
+    }
+
+    /// Create an output for the X11 PRIMARY selection (middle-click paste)
+    pub fn primary(append_text: Option<String>) -> Self {
+        Self {
+            append_text,
+            primary: true,
+            restore_after: None,
+        }
+    }
+
+    /// Put the previous clipboard content back after `ttl`, unless something
+    /// else has been copied by then. Has no effect on the primary selection.
+    pub fn restore_after(mut self, ttl: Option<Duration>) -> Self {
+        self.restore_after = ttl;
+        self
     }
 }
 
@@ -45,16 +71,18 @@ impl X11ClipboardTool {
         }
     }
 
-    fn args(self) -> &'static [&'static str] {
-        match self {
-            X11ClipboardTool::Xclip => &["-selection", "clipboard"],
-            X11ClipboardTool::Xsel => &["--clipboard", "--input"],
+    fn args(self, primary: bool) -> &'static [&'static str] {
+        match (self, primary) {
+            (X11ClipboardTool::Xclip, false) => &["-selection", "clipboard"],
+            (X11ClipboardTool::Xclip, true) => &["-selection", "primary"],
+            (X11ClipboardTool::Xsel, false) => &["--clipboard", "--input"],
+            (X11ClipboardTool::Xsel, true) => &["--primary", "--input"],
         }
     }
 }
 
 /// Probe `which $cmd` to see if a binary is on PATH.
-async fn binary_on_path(cmd: &str) -> bool {
+pub(super) async fn binary_on_path(cmd: &str) -> bool {
     Command::new("which")
         .arg(cmd)
         .stdout(Stdio::null())
@@ -77,9 +105,9 @@ async fn find_tool() -> Option<X11ClipboardTool> {
 }
 
 /// Run an X11 clipboard tool, piping `text` to its stdin.
-async fn copy_via(tool: X11ClipboardTool, text: &[u8]) -> Result<(), OutputError> {
+async fn copy_via(tool: X11ClipboardTool, text: &[u8], primary: bool) -> Result<(), OutputError> {
     let mut child = Command::new(tool.command())
-        .args(tool.args())
+        .args(tool.args(primary))
         .stdin(Stdio::piped())
         .stdout(Stdio::null())
         .stderr(Stdio::piped())
@@ -124,11 +152,15 @@ async fn copy_via(tool: X11ClipboardTool, text: &[u8]) -> Result<(), OutputError
 /// Used by both `XclipOutput` and `PasteOutput` so they share the same
 /// dispatch logic.
 pub(crate) async fn copy_to_x11_clipboard(text: &[u8]) -> Result<(), OutputError> {
+    copy_to_x11_selection(text, false).await
+}
+
+async fn copy_to_x11_selection(text: &[u8], primary: bool) -> Result<(), OutputError> {
     let tool = find_tool()
         .await
         .ok_or(OutputError::X11ClipboardToolMissing)?;
     tracing::debug!("Using {} for X11 clipboard", tool.command());
-    copy_via(tool, text).await
+    copy_via(tool, text, primary).await
 }
 
 #[async_trait::async_trait]
@@ -144,9 +176,32 @@ impl TextOutput for XclipOutput {
             std::borrow::Cow::Borrowed(text)
         };
 
-        copy_to_x11_clipboard(text.as_bytes()).await?;
+        // Save the clipboard before overwriting it
+        let snapshot = match self.restore_after {
+            Some(ttl) if !self.primary => match ClipboardSnapshot::save().await {
+                Ok(snapshot) => snapshot.map(|s| (s, ttl)),
+                Err(e) => {
+                    tracing::warn!("Failed to read clipboard for restoration: {}", e);
+                    None
+                }
+            },
+            _ => None,
+        };
+
+        copy_to_x11_selection(text.as_bytes(), self.primary).await?;
 
-        tracing::info!("Text copied to X11 clipboard ({} chars)", text.len());
+        if let Some((snapshot, ttl)) = snapshot {
+            clipboard_snapshot::restore_after(snapshot, text.to_string(), ttl);
+        }
+
+        if self.primary {
+            tracing::info!(
+                "Text copied to X11 primary selection ({} chars)",
+                text.len()
+            );
+        } else {
+            tracing::info!("Text copied to X11 clipboard ({} chars)", text.len());
+        }
         Ok(())
     }
 
@@ -160,7 +215,11 @@ impl TextOutput for XclipOutput {
     }
 
     fn name(&self) -> &'static str {
-        "clipboard (xclip/xsel)"
+        if self.primary {
+            "primary selection (xclip/xsel)"
+        } else {
+            "clipboard (xclip/xsel)"
+        }
     }
 }
 
@@ -175,13 +234,29 @@ mod tests {
 
         let output = XclipOutput::new(Some(" ".to_string()));
         assert_eq!(output.append_text, Some(" ".to_string()));
+        assert!(!output.primary);
+
+        let output = XclipOutput::primary(None);
+        assert!(output.primary);
+        assert_eq!(output.name(), "primary selection (xclip/xsel)");
     }
 
     #[test]
     fn test_tool_command_and_args() {
         assert_eq!(X11ClipboardTool::Xclip.command(), "xclip");
-        assert_eq!(X11ClipboardTool::Xclip.args(), &["-selection", "clipboard"]);
+        assert_eq!(
+            X11ClipboardTool::Xclip.args(false),
+            &["-selection", "clipboard"]
+        );
+        assert_eq!(
+            X11ClipboardTool::Xclip.args(true),
+            &["-selection", "primary"]
+        );
         assert_eq!(X11ClipboardTool::Xsel.command(), "xsel");
-        assert_eq!(X11ClipboardTool::Xsel.args(), &["--clipboard", "--input"]);
+        assert_eq!(
+            X11ClipboardTool::Xsel.args(false),
+            &["--clipboard", "--input"]
+        );
+        assert_eq!(X11ClipboardTool::Xsel.args(true), &["--primary", "--input"]);
     }
 }
@@ -0,0 +1,195 @@
+//! xdotool-based text output
+//!
+//! Uses `xdotool type` to simulate keyboard input through the X11 XTEST
+//! extension. xdotool looks characters up in the active X keymap, so
+//! non-US layouts and most Unicode work, and no daemon is needed. Only
+//! available in X11 sessions, or under Wayland while an XWayland window
+//! has focus (Hyprland and Sway can tell); Wayland sessions otherwise use
+//! wtype, eitype or the uinput-based drivers.
+//!
+//! Requires:
+//! - xdotool installed
+//! - An X11 session (DISPLAY set)
+
+use super::session::{detect, DisplaySession};
+use super::TextOutput;
+use crate::error::OutputError;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::process::Command;
+
+/// xdotool-based text output
+pub struct XdotoolOutput {
+    /// Delay between keypresses in milliseconds
+    type_delay_ms: u32,
+    /// Delay before typing starts in milliseconds
+    pre_type_delay_ms: u32,
+    /// Whether to send Enter key after output
+    auto_submit: bool,
+    /// Text to append after transcription (before auto_submit)
+    append_text: Option<String>,
+    /// Convert newlines to Shift+Enter (for apps where Enter submits)
+    shift_enter_newlines: bool,
+}
+
+impl XdotoolOutput {
+    /// Create a new xdotool output
+    pub fn new(
+        type_delay_ms: u32,
+        pre_type_delay_ms: u32,
+        auto_submit: bool,
+        append_text: Option<String>,
+        shift_enter_newlines: bool,
+    ) -> Self {
+        Self {
+            type_delay_ms,
+            pre_type_delay_ms,
+            auto_submit,
+            append_text,
+            shift_enter_newlines,
+        }
+    }
+
+    /// Run xdotool with the given arguments
+    async fn run(&self, args: &[&str]) -> Result<(), OutputError> {
+        let output = Command::new("xdotool")
+            .args(args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .map_err(|e| {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    OutputError::XdotoolNotFound
+                } else {
+                    OutputError::InjectionFailed(e.to_string())
+                }
+            })?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(OutputError::InjectionFailed(format!(
+                "xdotool failed: {}",
+                stderr
+            )));
+        }
+        Ok(())
+    }
+
+    /// Type text (without newline handling)
+    async fn type_text(&self, text: &str) -> Result<(), OutputError> {
+        if text.is_empty() {
+            return Ok(());
+        }
+        let delay = self.type_delay_ms.to_string();
+        // --clearmodifiers releases held modifiers while typing so they
+        // don't combine with the typed keys; the -- ensures text starting
+        // with - isn't treated as an option
+        self.run(&["type", "--clearmodifiers", "--delay", &delay, "--", text])
+            .await
+    }
+
+    /// Type text, sending newlines as Shift+Enter when configured
+    async fn type_with_newlines(&self, text: &str) -> Result<(), OutputError> {
+        if !self.shift_enter_newlines {
+            return self.type_text(text).await;
+        }
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.run(&["key", "--clearmodifiers", "shift+Return"])
+                    .await?;
+            }
+            self.type_text(line).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl TextOutput for XdotoolOutput {
+    async fn output(&self, text: &str) -> Result<(), OutputError> {
+        if text.is_empty() {
+            return Ok(());
+        }
+
+        // Pre-typing delay if configured
+        if self.pre_type_delay_ms > 0 {
+            tracing::debug!(
+                "xdotool: sleeping {}ms before typing",
+                self.pre_type_delay_ms
+            );
+            tokio::time::sleep(Duration::from_millis(self.pre_type_delay_ms as u64)).await;
+        }
+
+        tracing::debug!(
+            "Running: xdotool type --clearmodifiers --delay {} -- \"{}\"",
+            self.type_delay_ms,
+            text.chars().take(20).collect::<String>()
+        );
+        self.type_with_newlines(text).await?;
+
+        // Append text if configured (e.g., a space to separate sentences)
+        if let Some(ref append) = self.append_text {
+            if let Err(e) = self.type_with_newlines(append).await {
+                tracing::warn!("Failed to append text: {}", e);
+            }
+        }
+
+        // Send Enter key if configured
+        if self.auto_submit {
+            if let Err(e) = self.run(&["key", "--clearmodifiers", "Return"]).await {
+                tracing::warn!("Failed to send Enter key: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn is_available(&self) -> bool {
+        // XTEST needs an X server; under Wayland xdotool only reaches
+        // XWayland windows, so it is only used when one has focus
+        if detect() != DisplaySession::X11 && !focused_on_xwayland().await {
+            tracing::debug!("xdotool skipped: not an X11 session or XWayland window");
+            return false;
+        }
+        Command::new("which")
+            .arg("xdotool")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await
+            .map(|s| s.success())
+            .unwrap_or(false)
+    }
+
+    fn name(&self) -> &'static str {
+        "xdotool"
+    }
+}
+
+/// Whether an XWayland window has focus, so XTEST through `DISPLAY` reaches
+/// it
+async fn focused_on_xwayland() -> bool {
+    if std::env::var("DISPLAY").map_or(true, |d| d.is_empty()) {
+        return false;
+    }
+    match crate::focus::detect_backend() {
+        Some(backend) => backend.focused_is_xwayland().await.unwrap_or(false),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let output = XdotoolOutput::new(12, 100, true, Some(" ".to_string()), false);
+        assert_eq!(output.type_delay_ms, 12);
+        assert_eq!(output.pre_type_delay_ms, 100);
+        assert!(output.auto_submit);
+        assert_eq!(output.append_text.as_deref(), Some(" "));
+        assert!(!output.shift_enter_newlines);
+    }
+}
@@ -3,11 +3,18 @@
 //! Uses ydotool to simulate keyboard input. This works on all Wayland
 //! compositors because ydotool uses the uinput kernel interface.
 //!
+//! With `persistent_drivers` (the default), text the US key table covers
+//! is sent straight to ydotoold over one kept-open socket instead of
+//! spawning `ydotool` for the text, the appended text and Enter. Other text
+//! still goes through the `ydotool` client.
+//!
 //! Requires:
 //! - ydotool installed
 //! - ydotoold daemon running (systemctl --user start ydotool)
 //! - User in 'input' group
 
+#[cfg(target_os = "linux")]
+use super::keepalive::ydotool as keepalive;
 use super::TextOutput;
 use crate::error::OutputError;
 use crate::output::find_ydotool_socket;
@@ -30,6 +37,8 @@ pub struct YdotoolOutput {
     append_text: Option<String>,
     /// Path to ydotoold socket, if found at a non-default location
     socket_path: Option<PathBuf>,
+    /// Send key events over a kept-open ydotoold socket when possible
+    persistent: bool,
 }
 
 impl YdotoolOutput {
@@ -56,9 +65,16 @@ impl YdotoolOutput {
             auto_submit,
             append_text,
             socket_path,
+            persistent: false,
         }
     }
 
+    /// Talk to ydotoold over one socket kept open between outputs
+    pub fn persistent(mut self, persistent: bool) -> Self {
+        self.persistent = persistent;
+        self
+    }
+
     /// Apply the discovered socket path to a ydotool Command, if any.
     fn apply_socket_env(&self, cmd: &mut Command) {
         if let Some(ref path) = self.socket_path {
@@ -99,6 +115,28 @@ impl TextOutput for YdotoolOutput {
             tokio::time::sleep(Duration::from_millis(self.pre_type_delay_ms as u64)).await;
         }
 
+        #[cfg(target_os = "linux")]
+        if self.persistent {
+            let mut full_text = text.to_string();
+            if let Some(ref append) = self.append_text {
+                full_text.push_str(append);
+            }
+            if let Some(keys) = keepalive::keys(&full_text, self.auto_submit) {
+                match keepalive::type_keys(&keys, self.type_delay_ms).await {
+                    Ok(()) => {
+                        tracing::info!(
+                            "Text typed via ydotoold socket ({} chars)",
+                            text.chars().count()
+                        );
+                        return Ok(());
+                    }
+                    // Nothing typed yet; the client reports what's wrong
+                    Err(OutputError::YdotoolNotRunning) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+
         let mut cmd = Command::new("ydotool");
         self.apply_socket_env(&mut cmd);
         cmd.arg("type");
@@ -218,6 +256,11 @@ impl TextOutput for YdotoolOutput {
             return false;
         }
 
+        #[cfg(target_os = "linux")]
+        if self.persistent {
+            return keepalive::check().await;
+        }
+
         // Check if ydotoold is running by trying a no-op
         // ydotool type "" should succeed quickly if daemon is running
         let mut cmd = Command::new("ydotool");
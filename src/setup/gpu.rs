@@ -16,6 +16,10 @@
 //!   - VOXTYPE_VULKAN_DEVICE=intel   (selects Intel GPU)
 //!
 //! This sets VK_LOADER_DRIVERS_SELECT internally to filter Vulkan ICDs.
+//!
+//! macOS has no backend binaries to switch between: Metal (and the CoreML
+//! encoder) is chosen at build time with the `gpu-metal` feature, so
+//! `setup gpu` only reports the detected GPU and what this build uses.
 
 use super::binary::install_active_binary;
 use std::fs;
@@ -112,6 +116,7 @@ pub enum GpuVendor {
     Nvidia,
     Amd,
     Intel,
+    Apple,
     Other,
 }
 
@@ -130,6 +135,8 @@ impl GpuVendor {
             GpuVendor::Amd
         } else if lower.contains("intel") {
             GpuVendor::Intel
+        } else if lower.contains("apple") {
+            GpuVendor::Apple
         } else {
             GpuVendor::Other
         }
@@ -141,7 +148,7 @@ impl GpuVendor {
             GpuVendor::Nvidia => "nvidia*",
             GpuVendor::Amd => "*radeon*,*amd*",
             GpuVendor::Intel => "*intel*",
-            GpuVendor::Other => "*",
+            GpuVendor::Apple | GpuVendor::Other => "*",
         }
     }
 
@@ -150,6 +157,7 @@ impl GpuVendor {
             GpuVendor::Nvidia => "NVIDIA",
             GpuVendor::Amd => "AMD",
             GpuVendor::Intel => "Intel",
+            GpuVendor::Apple => "Apple",
             GpuVendor::Other => "Other",
         }
     }
@@ -232,6 +240,10 @@ pub fn detect_available_backends() -> Vec<Backend> {
 
 /// Detect all available GPUs
 pub fn detect_gpus() -> Vec<GpuInfo> {
+    if cfg!(target_os = "macos") {
+        return detect_gpus_macos();
+    }
+
     let mut gpus = Vec::new();
 
     // Check for DRI render nodes (indicates GPU with working driver)
@@ -294,6 +306,29 @@ pub fn detect_gpus() -> Vec<GpuInfo> {
     gpus
 }
 
+/// Detect GPUs on macOS from `system_profiler SPDisplaysDataType`
+fn detect_gpus_macos() -> Vec<GpuInfo> {
+    let Ok(output) = Command::new("system_profiler")
+        .arg("SPDisplaysDataType")
+        .output()
+    else {
+        return Vec::new();
+    };
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("Chipset Model:"))
+        .map(|name| {
+            let name = name.trim().to_string();
+            GpuInfo {
+                vendor: GpuVendor::from_name(&name),
+                name,
+                pci_slot: None,
+            }
+        })
+        .collect()
+}
+
 /// Detect if GPU is available for Vulkan (returns first GPU for backward compatibility)
 pub fn detect_gpu() -> Option<String> {
     detect_gpus().first().map(|g| g.name.clone())
@@ -338,6 +373,19 @@ pub fn check_vulkan_runtime() -> bool {
     vulkan_paths.iter().any(|p| Path::new(p).exists())
 }
 
+/// Check if the ROCm HIP runtime is installed (needed by the MIGraphX and
+/// ROCm execution providers of the ONNX engines)
+pub fn check_rocm_runtime() -> bool {
+    let hip_paths = [
+        "/opt/rocm/lib/libamdhip64.so",
+        "/usr/lib/libamdhip64.so",
+        "/usr/lib64/libamdhip64.so",
+        "/usr/lib/x86_64-linux-gnu/libamdhip64.so",
+    ];
+
+    hip_paths.iter().any(|p| Path::new(p).exists())
+}
+
 /// Switch to a different backend (tiered mode only)
 fn switch_backend_tiered(backend: Backend) -> anyhow::Result<()> {
     let binary_path = Path::new(VOXTYPE_LIB_DIR).join(backend.binary_name());
@@ -489,6 +537,11 @@ fn disable_simple_mode() -> anyhow::Result<()> {
 pub fn show_status() {
     println!("=== Voxtype Backend Status ===\n");
 
+    if cfg!(target_os = "macos") {
+        show_status_macos();
+        return;
+    }
+
     let tiered = is_tiered_mode();
     let active_bin = get_active_binary_path();
     let is_parakeet = is_parakeet_binary_active();
@@ -655,6 +708,16 @@ pub fn show_status() {
             println!("  Install vulkan-icd-loader for GPU acceleration");
         }
 
+        // ONNX engines reach AMD GPUs through ROCm (MIGraphX or ROCm EP)
+        if gpus.iter().any(|g| g.vendor == GpuVendor::Amd) {
+            if check_rocm_runtime() {
+                println!("ROCm runtime: installed");
+            } else {
+                println!("ROCm runtime: NOT FOUND");
+                println!("  Install rocm-hip-runtime for ONNX engines on the GPU");
+            }
+        }
+
         // Show GPU selection status if multiple GPUs
         if gpus.len() > 1 {
             println!();
@@ -678,6 +741,8 @@ pub fn show_status() {
         }
     }
 
+    show_onnx_providers();
+
     // Usage hints
     println!();
     if is_parakeet {
@@ -706,6 +771,93 @@ pub fn show_status() {
     }
 }
 
+/// Pre-flight result for each GPU execution provider in this binary, and
+/// the provider the running daemon's engine picked
+fn show_onnx_providers() {
+    let config = crate::config::load_config(None).unwrap_or_default();
+    let cuda_device = match config.engine {
+        crate::config::TranscriptionEngine::Parakeet => {
+            config.parakeet.as_ref().and_then(|c| c.cuda_device)
+        }
+        crate::config::TranscriptionEngine::Cohere => {
+            config.cohere.as_ref().and_then(|c| c.cuda_device)
+        }
+        crate::config::TranscriptionEngine::Zipformer => {
+            config.zipformer.as_ref().and_then(|c| c.cuda_device)
+        }
+        _ => None,
+    };
+
+    let checks = crate::transcribe::onnx_ep::preflight_report(cuda_device);
+    if !checks.is_empty() {
+        println!();
+        println!("ONNX execution providers:");
+        for check in checks {
+            match check.result {
+                Ok(details) => println!("  {} - ready ({})", check.provider, details),
+                Err(reason) => {
+                    println!(
+                        "  {} - unavailable, ONNX engines will run on CPU",
+                        check.provider
+                    );
+                    for line in reason.lines() {
+                        println!("    {}", line.trim());
+                    }
+                }
+            }
+        }
+    }
+
+    if let Ok(provider) = std::fs::read_to_string(crate::status_json::execution_provider_file()) {
+        println!();
+        println!("Daemon execution provider: {}", provider.trim());
+    }
+}
+
+/// macOS status: detected GPU, whether this build uses Metal, and whether
+/// the configured Whisper model has a CoreML encoder
+fn show_status_macos() {
+    let metal = cfg!(feature = "gpu-metal");
+    println!(
+        "Active backend: {}",
+        if metal { "GPU (Metal)" } else { "CPU" }
+    );
+
+    println!();
+    let gpus = detect_gpus();
+    if gpus.is_empty() {
+        println!("GPU: not detected");
+    } else {
+        println!("GPUs detected:");
+        for (i, gpu) in gpus.iter().enumerate() {
+            println!("  {}. [{}] {}", i + 1, gpu.vendor.display_name(), gpu.name);
+        }
+    }
+
+    println!();
+    if metal {
+        let config = crate::config::load_config(None).unwrap_or_default();
+        let model = crate::config::Config::models_dir().join(
+            crate::transcribe::whisper::get_model_filename(&config.whisper.model),
+        );
+        let encoder = crate::transcribe::whisper::coreml_encoder_path(&model);
+        if encoder.exists() {
+            println!(
+                "CoreML encoder: {} (runs on the Neural Engine)",
+                encoder.display()
+            );
+        } else {
+            println!("CoreML encoder: not found, the encoder runs on Metal");
+            println!("  For faster encoding, place the model's CoreML encoder at:");
+            println!("    {}", encoder.display());
+            println!("  Prebuilt encoders: https://huggingface.co/ggerganov/whisper.cpp/tree/main");
+        }
+    } else {
+        println!("This build has no GPU support. To use Metal and CoreML, rebuild with:");
+        println!("  cargo build --release --features gpu-metal");
+    }
+}
+
 /// Detect the best ONNX GPU backend based on available hardware and installed binaries
 fn detect_best_parakeet_gpu_backend() -> Option<(&'static str, &'static str)> {
     let gpus = detect_gpus();
@@ -789,6 +941,17 @@ fn detect_best_parakeet_gpu_backend() -> Option<(&'static str, &'static str)> {
 
 /// Enable GPU backend (engine-aware: Vulkan for Whisper, CUDA/MIGraphX for Parakeet)
 pub fn enable() -> anyhow::Result<()> {
+    if cfg!(target_os = "macos") {
+        if !cfg!(feature = "gpu-metal") {
+            anyhow::bail!(
+                "This build has no GPU support. On macOS, Metal is enabled at build time:\n  \
+                 cargo build --release --features gpu-metal"
+            );
+        }
+        println!("Metal GPU acceleration is already enabled in this build.");
+        return Ok(());
+    }
+
     // Check which engine is active by looking at the current symlink
     let is_parakeet = is_parakeet_binary_active();
 
@@ -882,6 +1045,17 @@ pub fn enable() -> anyhow::Result<()> {
 
 /// Disable GPU backend (engine-aware: switch to best CPU backend)
 pub fn disable() -> anyhow::Result<()> {
+    if cfg!(target_os = "macos") {
+        if cfg!(feature = "gpu-metal") {
+            anyhow::bail!(
+                "Metal is compiled into this build and cannot be switched off at runtime.\n\
+                 For CPU-only inference, rebuild without the gpu-metal feature."
+            );
+        }
+        println!("This build already uses CPU inference.");
+        return Ok(());
+    }
+
     // Check which engine is active by looking at the current symlink
     let is_parakeet = is_parakeet_binary_active();
 
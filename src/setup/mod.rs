@@ -14,6 +14,7 @@ pub mod app_bundle;
 pub mod binary;
 pub mod compositor;
 pub mod dms;
+pub mod download;
 pub mod gpu;
 #[cfg(target_os = "macos")]
 pub mod hammerspoon;
@@ -22,11 +23,14 @@ pub mod launchd;
 pub mod macos;
 pub mod manifest;
 pub mod model;
+pub mod model_disk;
 pub mod parakeet;
 pub mod quickshell;
+pub mod recommend;
 pub mod systemd;
 pub mod vad;
 pub mod variant_check;
+pub mod wake_word;
 pub mod waybar;
 
 use crate::config::Config;
@@ -528,12 +532,14 @@ pub async fn run_setup(
     }
 
     // Create default config file if it doesn't exist
+    let mut fresh_config = false;
     if let Some(config_path) = Config::default_path() {
         if !config_path.exists() {
             if !quiet {
                 println!("\nCreating default config file...");
             }
             std::fs::write(&config_path, crate::config::default_config_content())?;
+            fresh_config = true;
             if !quiet {
                 print_success(&format!("Created: {:?}", config_path));
             }
@@ -542,6 +548,52 @@ pub async fn run_setup(
         }
     }
 
+    // Recommend a model for this hardware. A fresh config starts with it
+    // unless --model was given.
+    let hardware = recommend::Hardware::detect();
+    let recommendation = recommend::recommend(&hardware, config.whisper.language.primary() == "en");
+    if !quiet {
+        println!("\nHardware...");
+        print_success(&format!(
+            "CPU: {}, RAM: {}, GPU: {}",
+            hardware.cpu_summary(),
+            hardware.ram_summary(),
+            hardware
+                .discrete_gpu()
+                .map(|g| g.name.as_str())
+                .unwrap_or("none")
+        ));
+        print_info(&format!(
+            "Recommended: {} ({}, {})",
+            recommendation.model,
+            recommendation.engine.name(),
+            recommendation.reason
+        ));
+        if let Some(ref hint) = recommendation.hint {
+            println!("       {}", hint);
+        }
+    }
+    let model_override = match model_override {
+        Some(name) => Some(name),
+        None if fresh_config => {
+            if recommendation.engine == crate::config::TranscriptionEngine::Parakeet {
+                model::set_parakeet_config(&recommendation.model)?;
+            } else {
+                model::set_model_config(&recommendation.model)?;
+            }
+            Some(recommendation.model.as_str())
+        }
+        None => {
+            if !quiet && !config.referenced_models().contains(&recommendation.model) {
+                println!(
+                    "       Switch with: voxtype setup --download --model {}",
+                    recommendation.model
+                );
+            }
+            None
+        }
+    };
+
     let models_dir = Config::models_dir();
 
     // Check if model_override is a Parakeet or SenseVoice model
@@ -556,7 +608,7 @@ pub async fn run_setup(
     let _model_name: &str = match model_override {
         Some(name) => {
             // Validate the model name (check Whisper, Parakeet, and SenseVoice)
-            if !model::is_valid_model(name)
+            if !model::is_known_whisper_model(name, &config.whisper)
                 && !model::is_parakeet_model(name)
                 && !model::is_sensevoice_model(name)
             {
@@ -692,7 +744,7 @@ pub async fn run_setup(
         let model_name: &str = match model_override {
             Some(name) => {
                 // Validate the model name
-                if !model::is_valid_model(name) {
+                if !model::is_known_whisper_model(name, &config.whisper) {
                     let whisper_models = model::valid_model_names().join(", ");
                     let parakeet_models = model::valid_parakeet_model_names().join(", ");
                     anyhow::bail!(
@@ -802,6 +854,43 @@ pub async fn run_checks(config: &Config) -> anyhow::Result<()> {
         print_info("Running in a virtual machine - ensure CPU features are passed through");
     }
 
+    // Hardware detection and model recommendation
+    println!("\nHardware:");
+    let hardware = recommend::Hardware::detect();
+    print_success(&format!("CPU: {}", hardware.cpu_summary()));
+    print_success(&format!("RAM: {}", hardware.ram_summary()));
+    if hardware.gpus.is_empty() {
+        print_info("GPU: none detected");
+    }
+    for gpu in &hardware.gpus {
+        print_success(&format!(
+            "GPU: {} ({})",
+            gpu.name,
+            gpu.vendor.display_name()
+        ));
+    }
+    print_info(&format!(
+        "GPU acceleration: {}",
+        if hardware.gpu_build {
+            "built in"
+        } else if hardware.vulkan_installed {
+            "Vulkan binary installed, not active"
+        } else {
+            "not available in this build"
+        }
+    ));
+    let recommendation = recommend::recommend(&hardware, config.whisper.language.primary() == "en");
+    print_info(&format!(
+        "Recommended: {} ({}, {})",
+        recommendation.model,
+        recommendation.engine.name(),
+        recommendation.reason
+    ));
+    if let Some(ref hint) = recommendation.hint {
+        println!("       {}", hint);
+    }
+    println!();
+
     // Check directories
     println!("Directories:");
     if let Some(config_dir) = Config::config_dir() {
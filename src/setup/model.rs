@@ -2,11 +2,10 @@
 
 use super::manifest::{ExpectedFile, ModelArtifact};
 use super::{print_failure, print_info, print_success, print_warning};
-use crate::config::{Config, TranscriptionEngine};
-use crate::transcribe::whisper::{get_model_filename, get_model_url};
+use crate::config::{Config, CustomModel, TranscriptionEngine};
+use crate::transcribe::whisper::{custom_model_url, get_model_filename, get_model_url};
 use std::io::{self, Write};
 use std::path::Path;
-use std::process::Command;
 
 /// Section-header tag rendered next to the engines whose ONNX graphs the
 /// MIGraphX 7.2 EP can't compile (Moonshine/SenseVoice/Paraformer/Dolphin/
@@ -92,6 +91,38 @@ const MODELS: &[ModelInfo] = &[
         description: "Fast + accurate (recommended for GPU)",
         english_only: false,
     },
+    // Quantized large models
+    ModelInfo {
+        name: "large-v3-turbo-q5_0",
+        size_mb: 547,
+        description: "Quantized turbo, a third of the memory",
+        english_only: false,
+    },
+    ModelInfo {
+        name: "large-v3-turbo-q8_0",
+        size_mb: 834,
+        description: "Quantized turbo, near full accuracy",
+        english_only: false,
+    },
+    ModelInfo {
+        name: "large-v3-q5_0",
+        size_mb: 1080,
+        description: "Quantized large-v3",
+        english_only: false,
+    },
+    // Distil-Whisper models
+    ModelInfo {
+        name: "distil-large-v3",
+        size_mb: 1520,
+        description: "Distilled large-v3, ~6x faster",
+        english_only: true,
+    },
+    ModelInfo {
+        name: "distil-large-v3.5",
+        size_mb: 1520,
+        description: "Distilled large-v3, newer training data",
+        english_only: true,
+    },
 ];
 
 // =============================================================================
@@ -668,6 +699,72 @@ const COHERE_MODELS: &[CohereModelInfo] = &[
     },
 ];
 
+// =============================================================================
+// Zipformer Model Definitions
+// =============================================================================
+// sherpa-onnx streaming transducer exports: encoder/decoder/joiner plus
+// tokens.txt. Upstream file names carry the training epoch and chunk
+// geometry; they're renamed to plain encoder/decoder/joiner locally.
+
+struct ZipformerModelInfo {
+    name: &'static str,
+    dir_name: &'static str,
+    size_mb: u32,
+    description: &'static str,
+    languages: &'static str,
+    files: &'static [(&'static str, &'static str)],
+    huggingface_repo: &'static str,
+}
+
+const ZIPFORMER_MODELS: &[ZipformerModelInfo] = &[
+    ZipformerModelInfo {
+        name: "en",
+        dir_name: "zipformer-en",
+        size_mb: 71,
+        description: "English streaming, low latency (recommended)",
+        languages: "en",
+        files: &[
+            (
+                "encoder-epoch-99-avg-1-chunk-16-left-128.int8.onnx",
+                "encoder.int8.onnx",
+            ),
+            (
+                "decoder-epoch-99-avg-1-chunk-16-left-128.onnx",
+                "decoder.onnx",
+            ),
+            (
+                "joiner-epoch-99-avg-1-chunk-16-left-128.int8.onnx",
+                "joiner.int8.onnx",
+            ),
+            ("tokens.txt", "tokens.txt"),
+        ],
+        huggingface_repo: "csukuangfj/sherpa-onnx-streaming-zipformer-en-2023-06-26",
+    },
+    ZipformerModelInfo {
+        name: "zh",
+        dir_name: "zipformer-zh",
+        size_mb: 72,
+        description: "Chinese streaming, low latency",
+        languages: "zh",
+        files: &[
+            (
+                "encoder-epoch-20-avg-1-chunk-16-left-128.int8.onnx",
+                "encoder.int8.onnx",
+            ),
+            (
+                "decoder-epoch-20-avg-1-chunk-16-left-128.onnx",
+                "decoder.onnx",
+            ),
+            (
+                "joiner-epoch-20-avg-1-chunk-16-left-128.int8.onnx",
+                "joiner.int8.onnx",
+            ),
+            ("tokens.txt", "tokens.txt"),
+        ],
+        huggingface_repo: "csukuangfj/sherpa-onnx-streaming-zipformer-multi-zh-hans-2023-12-12",
+    },
+];
+
 // =============================================================================
 // ModelArtifact implementations
 // =============================================================================
@@ -676,7 +773,7 @@ const COHERE_MODELS: &[CohereModelInfo] = &[
 // unified `download_artifact` consumes them uniformly. The trait's
 // `name()` is the URL segment + on-disk directory name; for engines that
 // historically used a `dir_name` distinct from `name` (Moonshine,
-// SenseVoice, Paraformer, Dolphin, Omnilingual, Cohere), we return
+// SenseVoice, Paraformer, Dolphin, Omnilingual, Cohere, Zipformer), we return
 // `dir_name` so the R2 layout matches what's on disk. For Parakeet the
 // model `name` was always the directory name; nothing to translate.
 
@@ -855,6 +952,31 @@ impl ModelArtifact for CohereModelInfo {
     }
 }
 
+impl ModelArtifact for ZipformerModelInfo {
+    // Intentional: the trait method is `name()` but the struct field that
+    // serves as the canonical identifier is `dir_name`. Clippy's
+    // misnamed_getters lint fires on the mismatch; it's not a bug.
+    #[allow(clippy::misnamed_getters)]
+    fn name(&self) -> &str {
+        self.dir_name
+    }
+    fn engine_prefix(&self) -> &'static str {
+        "zipformer"
+    }
+    fn upstream_repo(&self) -> &str {
+        self.huggingface_repo
+    }
+    fn expected_files(&self) -> Vec<ExpectedFile> {
+        self.files
+            .iter()
+            .map(|(_repo, local)| ExpectedFile {
+                path: (*local).to_string(),
+                size: 0,
+            })
+            .collect()
+    }
+}
+
 // =============================================================================
 // Registry export (for the mirror script)
 // =============================================================================
@@ -881,7 +1003,7 @@ pub struct RegistryFile {
 }
 
 /// Snapshot the full ONNX-engine model registry (Parakeet, Moonshine,
-/// SenseVoice, Paraformer, Dolphin, Omnilingual, Cohere) into a single
+/// SenseVoice, Paraformer, Dolphin, Omnilingual, Cohere, Zipformer) into a single
 /// flat list. Used by `voxtype-mirror-registry` to drive
 /// `scripts/mirror-models-to-r2.sh`.
 pub fn registry_snapshot() -> Vec<RegistryEntry> {
@@ -993,6 +1115,21 @@ pub fn registry_snapshot() -> Vec<RegistryEntry> {
                 .collect(),
         });
     }
+    for m in ZIPFORMER_MODELS {
+        out.push(RegistryEntry {
+            engine_prefix: "zipformer",
+            name: m.dir_name.to_string(),
+            upstream_repo: m.huggingface_repo.to_string(),
+            files: m
+                .files
+                .iter()
+                .map(|(remote, local)| RegistryFile {
+                    upstream_path: (*remote).to_string(),
+                    local_path: (*local).to_string(),
+                })
+                .collect(),
+        });
+    }
     out
 }
 
@@ -1070,23 +1207,7 @@ pub fn download_artifact<T: ModelArtifact + ?Sized>(
 
         let url = file_url(artifact, &file.path);
         println!("Downloading {}...", file.path);
-        curl_download(&url, &dest)?;
-
-        let observed = sha256_file(&dest).map_err(|e| {
-            let _ = std::fs::remove_file(&dest);
-            anyhow::anyhow!("Failed to hash {}: {}", file.path, e)
-        })?;
-        let expected = file.sha256.to_lowercase();
-        if observed != expected {
-            let _ = std::fs::remove_file(&dest);
-            anyhow::bail!(
-                "sha256 mismatch for {} (downloaded from {}): expected {}, got {}",
-                file.path,
-                url,
-                expected,
-                observed,
-            );
-        }
+        download_verified(&url, &dest, Some(&file.sha256))?;
     }
 
     print_success(&format!(
@@ -1099,9 +1220,7 @@ pub fn download_artifact<T: ModelArtifact + ?Sized>(
 
 /// Fetch a small text body via curl. Used for `manifest.json`.
 fn curl_fetch_text(url: &str) -> anyhow::Result<String> {
-    let output = Command::new("curl")
-        .args(["-fsSL", "--retry", "2", "--max-time", "30", url])
-        .output()
+    let output = super::download::curl(url, &["-fsSL", "--retry", "2", "--max-time", "30"], true)
         .map_err(|e| anyhow::anyhow!("failed to run curl: {}", e))?;
     if !output.status.success() {
         anyhow::bail!(
@@ -1113,28 +1232,110 @@ fn curl_fetch_text(url: &str) -> anyhow::Result<String> {
     Ok(String::from_utf8(output.stdout)?)
 }
 
-/// Download a single URL to `dest` via curl with a progress bar. Cleans up
-/// the partial file on failure.
+/// Download `url` to `dest`, verifying its sha256 when `expected_sha256` is
+/// known.
+///
+/// The body is written to `<dest>.part` and only renamed onto `dest` once
+/// the download completes and the checksum matches, so an interrupted
+/// download never leaves a truncated model where the engines look for it.
+/// A leftover `.part` file is resumed with an HTTP Range request on the
+/// next attempt; one that fails verification is deleted.
+pub(crate) fn download_verified(
+    url: &str,
+    dest: &Path,
+    expected_sha256: Option<&str>,
+) -> anyhow::Result<()> {
+    let part = partial_path(dest);
+    let expected = expected_sha256.map(str::to_lowercase);
+
+    if let Err(e) = curl_download(url, &part) {
+        // A .part that was already complete makes the server answer the
+        // resume request with 416, which curl reports as a failure.
+        let complete = match &expected {
+            Some(expected) => sha256_file(&part).is_ok_and(|hash| &hash == expected),
+            None => false,
+        };
+        if !complete {
+            return Err(e);
+        }
+    }
+
+    if let Some(expected) = &expected {
+        println!("Verifying sha256...");
+        let observed = sha256_file(&part).map_err(|e| {
+            let _ = std::fs::remove_file(&part);
+            anyhow::anyhow!("Failed to hash {}: {}", part.display(), e)
+        })?;
+        if &observed != expected {
+            let _ = std::fs::remove_file(&part);
+            print_failure("Checksum mismatch, download deleted");
+            anyhow::bail!(
+                "sha256 mismatch for {} (downloaded from {}): expected {}, got {}",
+                dest.display(),
+                url,
+                expected,
+                observed,
+            );
+        }
+    }
+
+    std::fs::rename(&part, dest)?;
+    Ok(())
+}
+
+/// Path of the in-progress download for `dest`
+fn partial_path(dest: &Path) -> std::path::PathBuf {
+    let mut name = dest.file_name().unwrap_or_default().to_os_string();
+    name.push(".part");
+    dest.with_file_name(name)
+}
+
+/// Download a single URL to `dest` via curl with a progress bar, resuming
+/// from the end of `dest` if it already exists. A partial file is kept on
+/// failure so the next attempt can pick up where this one stopped.
 fn curl_download(url: &str, dest: &Path) -> anyhow::Result<()> {
-    let status = Command::new("curl")
-        .args([
+    if dest.exists() {
+        println!("Resuming partial download {:?}", dest);
+    }
+    let status = super::download::curl(
+        url,
+        &[
             "-L",
             "--fail",
+            "--retry",
+            "3",
+            "--continue-at",
+            "-",
             "--progress-bar",
             "-o",
             dest.to_str().unwrap_or("file"),
-            url,
-        ])
-        .status();
+        ],
+        false,
+    )
+    .map(|output| output.status);
 
     match status {
         Ok(s) if s.success() => Ok(()),
+        // 33: the server ignores Range requests, so start over
+        Ok(s) if s.code() == Some(33) && dest.exists() => {
+            print_info("Server cannot resume downloads, starting over");
+            std::fs::remove_file(dest)?;
+            curl_download(url, dest)
+        }
         Ok(s) => {
-            let _ = std::fs::remove_file(dest);
             print_failure(&format!(
                 "Download failed: curl exited with code {}",
                 s.code().unwrap_or(-1)
             ));
+            if dest.exists() {
+                print_info("Run the command again to resume the download.");
+            }
+            let source = super::download::source();
+            if source.hf_token.is_none() && source.is_hugging_face(&source.resolve(url)) {
+                print_info(
+                    "Gated Hugging Face repos need an access token: set HF_TOKEN or [models] hf_token.",
+                );
+            }
             anyhow::bail!(
                 "Download failed for {} from {}.\n  \
                  If this persists, check models.voxtype.io status: \
@@ -1151,6 +1352,36 @@ fn curl_download(url: &str, dest: &Path) -> anyhow::Result<()> {
     }
 }
 
+/// Look up the sha256 that Hugging Face publishes for a Git LFS file.
+///
+/// `resolve/` URLs answer a HEAD request with an `X-Linked-Etag` header
+/// holding the LFS object's sha256. Returns None for other hosts, non-LFS
+/// files, or when the request fails; callers then skip verification.
+pub(crate) fn fetch_published_sha256(url: &str) -> Option<String> {
+    let source = super::download::source();
+    if !source.is_hugging_face(&source.resolve(url)) {
+        return None;
+    }
+    let output = super::download::curl(url, &["-sSI", "--max-time", "30"], true).ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_linked_etag(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Extract a sha256 from an `X-Linked-Etag` response header
+fn parse_linked_etag(headers: &str) -> Option<String> {
+    headers.lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        if !name.trim().eq_ignore_ascii_case("x-linked-etag") {
+            return None;
+        }
+        let value = value.trim().trim_start_matches("W/").trim_matches('"');
+        (value.len() == 64 && value.chars().all(|c| c.is_ascii_hexdigit()))
+            .then(|| value.to_lowercase())
+    })
+}
+
 /// Streaming sha256 of a file on disk. Used both for post-download
 /// verification and for re-validating a previously cached file.
 fn sha256_file(path: &Path) -> anyhow::Result<String> {
@@ -1184,6 +1415,12 @@ pub fn valid_model_names() -> Vec<&'static str> {
     MODELS.iter().map(|m| m.name).collect()
 }
 
+/// Check if a name is a built-in Whisper model or one registered under
+/// `[whisper] custom_models`
+pub fn is_known_whisper_model(name: &str, config: &crate::config::WhisperConfig) -> bool {
+    is_valid_model(name) || config.custom_models.contains_key(name)
+}
+
 /// Run interactive model selection (single menu with all models)
 pub async fn interactive_select() -> anyhow::Result<()> {
     println!("Voxtype Model Selection\n");
@@ -1203,6 +1440,7 @@ pub async fn interactive_select() -> anyhow::Result<()> {
     let is_dolphin_engine = matches!(config.engine, TranscriptionEngine::Dolphin);
     let is_omnilingual_engine = matches!(config.engine, TranscriptionEngine::Omnilingual);
     let is_cohere_engine = matches!(config.engine, TranscriptionEngine::Cohere);
+    let is_zipformer_engine = matches!(config.engine, TranscriptionEngine::Zipformer);
     let current_whisper_model = &config.whisper.model;
     let current_parakeet_model = config.parakeet.as_ref().map(|p| p.model.as_str());
     let current_moonshine_model = config.moonshine.as_ref().map(|m| m.model.as_str());
@@ -1211,6 +1449,7 @@ pub async fn interactive_select() -> anyhow::Result<()> {
     let current_dolphin_model = config.dolphin.as_ref().map(|d| d.model.as_str());
     let current_omnilingual_model = config.omnilingual.as_ref().map(|o| o.model.as_str());
     let current_cohere_model = config.cohere.as_ref().map(|c| c.model.as_str());
+    let current_zipformer_model = config.zipformer.as_ref().map(|z| z.model.as_str());
     let parakeet_available = cfg!(feature = "parakeet");
     let moonshine_available = cfg!(feature = "moonshine");
     let sensevoice_available = cfg!(feature = "sensevoice");
@@ -1218,6 +1457,7 @@ pub async fn interactive_select() -> anyhow::Result<()> {
     let dolphin_available = cfg!(feature = "dolphin");
     let omnilingual_available = cfg!(feature = "omnilingual");
     let cohere_available = cfg!(feature = "cohere");
+    let zipformer_available = cfg!(feature = "zipformer");
     let whisper_count = MODELS.len();
     let parakeet_count = PARAKEET_MODELS.len();
     let moonshine_count = MOONSHINE_MODELS.len();
@@ -1226,6 +1466,7 @@ pub async fn interactive_select() -> anyhow::Result<()> {
     let dolphin_count = DOLPHIN_MODELS.len();
     let omnilingual_count = OMNILINGUAL_MODELS.len();
     let cohere_count = COHERE_MODELS.len();
+    let zipformer_count = ZIPFORMER_MODELS.len();
 
     let available_count = |available: bool, count: usize| if available { count } else { 0 };
     let total_count = whisper_count
@@ -1235,7 +1476,8 @@ pub async fn interactive_select() -> anyhow::Result<()> {
         + available_count(paraformer_available, paraformer_count)
         + available_count(dolphin_available, dolphin_count)
         + available_count(omnilingual_available, omnilingual_count)
-        + available_count(cohere_available, cohere_count);
+        + available_count(cohere_available, cohere_count)
+        + available_count(zipformer_available, zipformer_count);
 
     // --- Whisper Section ---
     println!("--- Whisper (OpenAI, 99+ languages) ---\n");
@@ -1535,16 +1777,126 @@ pub async fn interactive_select() -> anyhow::Result<()> {
         println!("  \x1b[90m(not available - rebuild with --features cohere)\x1b[0m");
     }
 
+    // --- Zipformer Section ---
+    let zipformer_offset = cohere_offset + available_count(cohere_available, cohere_count);
+    println!(
+        "\n--- Zipformer (sherpa-onnx, streaming transducer){} ---\n",
+        AMD_CPU_ONLY_TAG
+    );
+
+    if zipformer_available {
+        for (i, model) in ZIPFORMER_MODELS.iter().enumerate() {
+            let model_path = models_dir.join(model.dir_name);
+            let installed = model_path.exists() && validate_zipformer_model(&model_path).is_ok();
+
+            let is_current = is_zipformer_engine
+                && (current_zipformer_model == Some(model.dir_name)
+                    || current_zipformer_model == Some(model.name));
+            let star = if is_current { "*" } else { " " };
+
+            let status = if installed {
+                "\x1b[32m[installed]\x1b[0m"
+            } else {
+                ""
+            };
+
+            println!(
+                " {}[{:>2}] {:<20} ({:>4} MB) {} - {} {}",
+                star,
+                zipformer_offset + i + 1,
+                model.dir_name,
+                model.size_mb,
+                model.languages,
+                model.description,
+                status
+            );
+        }
+    } else {
+        println!("  \x1b[90m(not available - rebuild with --features zipformer)\x1b[0m");
+    }
+
+    // --- Custom Section ---
+    let custom_offset = zipformer_offset + available_count(zipformer_available, zipformer_count);
+    let mut custom_models: Vec<(&String, &CustomModel)> = config
+        .models
+        .custom
+        .iter()
+        .filter(|(_, m)| m.validation_error().is_none())
+        .collect();
+    custom_models.sort_by(|a, b| a.0.cmp(b.0));
+    if !custom_models.is_empty() {
+        println!("\n--- Custom ([models.custom]) ---\n");
+        for (i, (name, model)) in custom_models.iter().enumerate() {
+            let is_current = match model.engine {
+                TranscriptionEngine::Whisper => is_whisper_engine && current_whisper_model == *name,
+                _ => {
+                    config.engine == model.engine
+                        && config
+                            .referenced_models()
+                            .contains(&model.source().unwrap_or_default())
+                }
+            };
+            let star = if is_current { "*" } else { " " };
+            let status = if custom_model_installed(name, model) {
+                "\x1b[32m[installed]\x1b[0m"
+            } else {
+                ""
+            };
+            println!(
+                " {}[{:>2}] {:<28} {:<11} - {} {}",
+                star,
+                custom_offset + i + 1,
+                name,
+                model.engine.name(),
+                model.description.as_deref().unwrap_or("user-defined model"),
+                status
+            );
+        }
+    }
+    let total_count = total_count + custom_models.len();
+
     println!("\n  [ 0] Cancel\n");
 
+    // Pre-select the model recommended for this hardware
+    let recommendation = super::recommend::recommend(
+        &super::recommend::Hardware::detect(),
+        config.whisper.language.primary() == "en",
+    );
+    let recommended_index = match recommendation.engine {
+        TranscriptionEngine::Whisper => MODELS
+            .iter()
+            .position(|m| m.name == recommendation.model)
+            .map(|i| i + 1),
+        TranscriptionEngine::Parakeet if parakeet_available => PARAKEET_MODELS
+            .iter()
+            .position(|m| m.name == recommendation.model)
+            .map(|i| whisper_count + i + 1),
+        _ => None,
+    };
+
     // Get user selection
-    print!("Select model [0-{}]: ", total_count);
+    match recommended_index {
+        Some(index) => {
+            println!(
+                "Recommended for this machine: [{}] {} ({})",
+                index, recommendation.model, recommendation.reason
+            );
+            if let Some(ref hint) = recommendation.hint {
+                println!("  {}", hint);
+            }
+            print!("\nSelect model [0-{}, Enter = {}]: ", total_count, index);
+        }
+        None => print!("Select model [0-{}]: ", total_count),
+    }
     io::stdout().flush()?;
 
     let mut input = String::new();
     io::stdin().read_line(&mut input)?;
 
-    let selection: usize = input.trim().parse().unwrap_or(0);
+    let selection: usize = match (input.trim(), recommended_index) {
+        ("", Some(index)) => index,
+        (input, _) => input.parse().unwrap_or(0),
+    };
 
     if selection == 0 {
         println!("\nCancelled.");
@@ -1581,6 +1933,14 @@ pub async fn interactive_select() -> anyhow::Result<()> {
     } else if cohere_available && selection <= cohere_offset + cohere_count {
         let idx = selection - cohere_offset;
         handle_cohere_selection(idx).await
+    } else if zipformer_available && selection <= zipformer_offset + zipformer_count {
+        let idx = selection - zipformer_offset;
+        let entries: Vec<(&str, &ZipformerModelInfo)> =
+            ZIPFORMER_MODELS.iter().map(|m| (m.dir_name, m)).collect();
+        handle_onnx_engine_selection("zipformer", &entries, idx, validate_zipformer_model).await
+    } else if selection > custom_offset && selection <= custom_offset + custom_models.len() {
+        let (name, model) = custom_models[selection - custom_offset - 1];
+        handle_custom_selection(name, model).await
     } else {
         println!("\nInvalid selection.");
         Ok(())
@@ -1641,6 +2001,36 @@ async fn handle_whisper_selection(selection: usize) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Whether a `[models.custom]` model is available locally
+fn custom_model_installed(name: &str, model: &CustomModel) -> bool {
+    match &model.path {
+        Some(path) => path.exists(),
+        None => Config::models_dir().join(get_model_filename(name)).exists(),
+    }
+}
+
+/// Handle selection of a `[models.custom]` model: download it if it is a
+/// Whisper model with a URL, then make it the default
+async fn handle_custom_selection(name: &str, model: &CustomModel) -> anyhow::Result<()> {
+    if !custom_model_installed(name, model) {
+        match &model.path {
+            Some(path) => {
+                print_failure(&format!("Custom model '{}' not found at {:?}", name, path));
+                return Ok(());
+            }
+            None => download_model(name)?,
+        }
+    }
+
+    if model.engine == TranscriptionEngine::Whisper {
+        update_config_model(name)?;
+    } else {
+        update_config_engine(model.engine.name(), name)?;
+    }
+    restart_daemon_if_running().await;
+    Ok(())
+}
+
 /// Handle Parakeet model selection (download/config)
 async fn handle_parakeet_selection(selection: usize) -> anyhow::Result<()> {
     let models_dir = Config::models_dir();
@@ -1802,7 +2192,8 @@ async fn restart_daemon_if_running() {
 // Whisper Download Functions
 // =============================================================================
 
-/// Download a specific Whisper model using curl
+/// Download a specific Whisper model using curl, verifying it against the
+/// checksum Hugging Face publishes for the file
 pub fn download_model(model_name: &str) -> anyhow::Result<()> {
     let models_dir = Config::models_dir();
     let filename = get_model_filename(model_name);
@@ -1811,52 +2202,46 @@ pub fn download_model(model_name: &str) -> anyhow::Result<()> {
     // Ensure directory exists
     std::fs::create_dir_all(&models_dir)?;
 
-    let url = get_model_url(model_name);
+    let config = crate::config::load_config(Config::default_path().as_deref()).unwrap_or_default();
+    let url = match config.whisper.custom_models.get(model_name) {
+        Some(source) => custom_model_url(&config.whisper.custom_models, model_name)
+            .map(str::to_string)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Custom model '{}' is a local file ({}), nothing to download",
+                    model_name,
+                    source
+                )
+            })?,
+        None => get_model_url(model_name),
+    };
 
     println!("\nDownloading {}...", model_name);
     println!("URL: {}", url);
 
-    // Use curl for downloading - it handles progress display and redirects
-    let status = Command::new("curl")
-        .args([
-            "-L",             // Follow redirects
-            "--progress-bar", // Show progress bar
-            "-o",
-            model_path.to_str().unwrap_or("model.bin"),
-            &url,
-        ])
-        .status();
-
-    match status {
-        Ok(exit_status) if exit_status.success() => {
-            print_success(&format!("Saved to {:?}", model_path));
-            Ok(())
-        }
-        Ok(exit_status) => {
-            print_failure(&format!(
-                "Download failed: curl exited with code {}",
-                exit_status.code().unwrap_or(-1)
-            ));
-            // Clean up partial download
-            let _ = std::fs::remove_file(&model_path);
-            anyhow::bail!("Download failed")
-        }
-        Err(e) => {
-            print_failure(&format!("Failed to run curl: {}", e));
-            print_info("Please ensure curl is installed (e.g., 'sudo pacman -S curl')");
-            anyhow::bail!("curl not available: {}", e)
-        }
+    let expected = config
+        .models
+        .custom
+        .get(model_name)
+        .and_then(|m| m.sha256.clone())
+        .or_else(|| fetch_published_sha256(&url));
+    if expected.is_none() {
+        print_warning("No published checksum for this URL, the download will not be verified");
     }
+
+    download_verified(&url, &model_path, expected.as_deref())?;
+    print_success(&format!("Saved to {:?}", model_path));
+    Ok(())
 }
 
 /// GTCRN speech enhancement model URL and filename
 const GTCRN_MODEL_URL: &str = "https://github.com/k2-fsa/sherpa-onnx/releases/download/speech-enhancement-models/gtcrn_simple.onnx";
-const GTCRN_MODEL_FILENAME: &str = "gtcrn_simple.onnx";
+pub(crate) const GTCRN_MODEL_FILENAME: &str = "gtcrn_simple.onnx";
 
 /// ECAPA-TDNN speaker embedding model URL and filename
 const ECAPA_MODEL_URL: &str =
     "https://huggingface.co/pranjal-pravesh/ecapa_tdnn_onnx/resolve/main/ecapa_tdnn.onnx";
-const ECAPA_MODEL_FILENAME: &str = "ecapa_tdnn.onnx";
+pub(crate) const ECAPA_MODEL_FILENAME: &str = "ecapa_tdnn.onnx";
 
 /// Ensure the GTCRN speech enhancement model is downloaded.
 /// Returns the path to the model file if available, or None if download fails.
@@ -1876,15 +2261,18 @@ pub fn ensure_gtcrn_model() -> Option<std::path::PathBuf> {
 
     println!("Downloading GTCRN speech enhancement model (523 KB)...");
 
-    let status = Command::new("curl")
-        .args([
+    let status = super::download::curl(
+        GTCRN_MODEL_URL,
+        &[
             "-L",
+            "--fail",
             "--progress-bar",
             "-o",
             model_path.to_str().unwrap_or("gtcrn_simple.onnx"),
-            GTCRN_MODEL_URL,
-        ])
-        .status();
+        ],
+        false,
+    )
+    .map(|output| output.status);
 
     match status {
         Ok(exit_status) if exit_status.success() => {
@@ -1922,15 +2310,18 @@ pub fn ensure_ecapa_model() -> Option<std::path::PathBuf> {
 
     println!("Downloading ECAPA-TDNN speaker embedding model (~26 MB)...");
 
-    let status = Command::new("curl")
-        .args([
+    let status = super::download::curl(
+        ECAPA_MODEL_URL,
+        &[
             "-L",
+            "--fail",
             "--progress-bar",
             "-o",
             model_path.to_str().unwrap_or(ECAPA_MODEL_FILENAME),
-            ECAPA_MODEL_URL,
-        ])
-        .status();
+        ],
+        false,
+    )
+    .map(|output| output.status);
 
     match status {
         Ok(exit_status) if exit_status.success() => {
@@ -3044,6 +3435,41 @@ fn validate_onnx_ctc_model(path: &Path) -> anyhow::Result<()> {
     }
 }
 
+/// Validate that a Zipformer model directory has the encoder, decoder,
+/// joiner and tokens.txt (plain or sherpa-onnx release file names).
+fn validate_zipformer_model(path: &Path) -> anyhow::Result<()> {
+    if !path.exists() {
+        anyhow::bail!("Model directory does not exist: {:?}", path);
+    }
+
+    let names: Vec<String> = std::fs::read_dir(path)?
+        .filter_map(|e| e.ok())
+        .filter_map(|e| e.file_name().into_string().ok())
+        .collect();
+    let has_part = |stem: &str| {
+        names.iter().any(|n| {
+            n.ends_with(".onnx")
+                && (n.starts_with(&format!("{stem}.")) || n.starts_with(&format!("{stem}-")))
+        })
+    };
+
+    let mut missing = Vec::new();
+    for stem in ["encoder", "decoder", "joiner"] {
+        if !has_part(stem) {
+            missing.push(format!("{stem}.onnx"));
+        }
+    }
+    if !path.join("tokens.txt").exists() {
+        missing.push("tokens.txt".to_string());
+    }
+
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        anyhow::bail!("Incomplete model, missing: {}", missing.join(", "))
+    }
+}
+
 /// Generic handler for ONNX engine model selection (download/config/restart).
 ///
 /// `models` is a slice of any type implementing `ModelArtifact` plus the
@@ -3350,9 +3776,40 @@ language = "en"
         let names = valid_model_names();
         assert!(names.contains(&"tiny.en"));
         assert!(names.contains(&"large-v3-turbo"));
+        assert!(names.contains(&"distil-large-v3"));
         assert_eq!(names.len(), MODELS.len());
     }
 
+    #[test]
+    fn test_whisper_models_have_download_urls() {
+        // Every listed model must map to a distinct file with a known URL
+        for model in MODELS {
+            assert!(get_model_filename(model.name).ends_with(".bin"));
+            assert!(get_model_url(model.name).starts_with("https://huggingface.co/"));
+        }
+        assert_eq!(
+            get_model_url("distil-large-v3.5"),
+            "https://huggingface.co/distil-whisper/distil-large-v3.5-ggml/resolve/main/ggml-model.bin"
+        );
+        assert_eq!(
+            get_model_filename("distil-large-v3.5"),
+            "ggml-distil-large-v3.5.bin"
+        );
+    }
+
+    #[test]
+    fn test_custom_whisper_models_are_known() {
+        let mut config = crate::config::WhisperConfig::default();
+        assert!(!is_known_whisper_model("my-finetune", &config));
+        config.custom_models.insert(
+            "my-finetune".to_string(),
+            "https://example.com/ggml-model.bin".to_string(),
+        );
+        assert!(is_known_whisper_model("my-finetune", &config));
+        assert!(is_known_whisper_model("base.en", &config));
+        assert_eq!(get_model_filename("my-finetune"), "ggml-my-finetune.bin");
+    }
+
     // =========================================================================
     // Parakeet Model Tests
     // =========================================================================
@@ -3885,6 +4342,68 @@ mode = "type"
         }
     }
 
+    #[test]
+    fn zipformer_engine_prefix() {
+        for m in ZIPFORMER_MODELS {
+            assert_eq!(m.engine_prefix(), "zipformer");
+            assert_eq!(m.name(), m.dir_name);
+        }
+    }
+
+    #[test]
+    fn validate_zipformer_model_accepts_local_and_sherpa_names() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        std::fs::write(dir.join("encoder.int8.onnx"), b"x").unwrap();
+        std::fs::write(dir.join("decoder-epoch-99-avg-1.onnx"), b"x").unwrap();
+        std::fs::write(dir.join("tokens.txt"), b"x").unwrap();
+        let err = validate_zipformer_model(dir).unwrap_err().to_string();
+        assert!(err.contains("joiner.onnx"), "{err}");
+
+        std::fs::write(dir.join("joiner.int8.onnx"), b"x").unwrap();
+        assert!(validate_zipformer_model(dir).is_ok());
+    }
+
+    #[test]
+    fn partial_path_appends_part_suffix() {
+        assert_eq!(
+            partial_path(Path::new("/models/ggml-base.en.bin")),
+            Path::new("/models/ggml-base.en.bin.part")
+        );
+    }
+
+    #[test]
+    fn parse_linked_etag_reads_lfs_sha256() {
+        let sha = "a03779c86df3323075f5e796cb2ce5029f00ec8869eee3fdfb897afe36c6d002";
+        let headers = format!(
+            "HTTP/2 302\r\ncontent-type: text/plain\r\nx-linked-etag: \"{}\"\r\nx-linked-size: 147964211\r\n",
+            sha.to_uppercase()
+        );
+        assert_eq!(parse_linked_etag(&headers), Some(sha.to_string()));
+    }
+
+    #[test]
+    fn parse_linked_etag_ignores_git_etags() {
+        // Non-LFS files carry a 40-char git blob id instead of a sha256
+        let headers = "x-linked-etag: \"3b18e512dba79e4c8300dd08aeb37f8e728b8dad\"\r\n";
+        assert_eq!(parse_linked_etag(headers), None);
+        assert_eq!(parse_linked_etag("etag: \"abc\"\r\n"), None);
+    }
+
+    #[test]
+    fn download_verified_accepts_complete_partial_file() {
+        // A finished .part that curl cannot resume (unreachable URL here)
+        // is accepted when its checksum matches, then renamed into place.
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("model.bin");
+        std::fs::write(partial_path(&dest), b"hello world").unwrap();
+        let sha = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+
+        download_verified("http://127.0.0.1:9/model.bin", &dest, Some(sha)).unwrap();
+        assert_eq!(std::fs::read(&dest).unwrap(), b"hello world");
+        assert!(!partial_path(&dest).exists());
+    }
+
     #[test]
     fn sha256_file_matches_known_vector() {
         // sha256 of "hello world" (no trailing newline)
@@ -0,0 +1,404 @@
+//! Disk usage and pruning of the models directory
+//!
+//! `voxtype setup model --disk-usage` lists everything under
+//! `Config::models_dir()` with its size, and `--remove <name>` deletes
+//! entries from that listing. Removal only ever targets entries found by
+//! the scan, so a name can never reach outside the models directory, and
+//! models still referenced by the config are kept unless `--force` is given.
+
+use super::model::{ECAPA_MODEL_FILENAME, GTCRN_MODEL_FILENAME};
+use super::{print_failure, print_info, print_success, print_warning};
+use crate::config::{Activation, Config};
+use crate::transcribe::whisper::{coreml_encoder_path, get_model_filename};
+use crate::vad::{get_silero_vad_model_filename, get_whisper_vad_model_filename};
+use crate::wake_word::MODELS_DIRNAME as WAKE_WORD_DIR;
+use std::path::{Path, PathBuf};
+
+/// What an entry in the models directory holds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModelKind {
+    /// Whisper GGML model (`ggml-<name>.bin`)
+    Whisper,
+    /// CoreML encoder for a Whisper model (`ggml-<name>-encoder.mlmodelc`)
+    CoreMl,
+    /// Model directory for an ONNX or Vosk engine, labelled with the engine
+    /// when it matches a known model
+    Directory(Option<&'static str>),
+    /// Silero VAD model for the Whisper VAD backend
+    Vad,
+    /// openWakeWord models directory (wake word activation)
+    WakeWord,
+    /// GTCRN speech enhancement model (meeting echo cancellation)
+    SpeechEnhancement,
+    /// ECAPA-TDNN speaker embedding model (meeting diarization)
+    SpeakerEmbedding,
+    /// Interrupted download (`*.part`)
+    Partial,
+    /// Anything else
+    Other,
+}
+
+impl ModelKind {
+    fn label(&self) -> &'static str {
+        match self {
+            ModelKind::Whisper => "whisper",
+            ModelKind::CoreMl => "coreml",
+            ModelKind::Directory(Some(engine)) => engine,
+            ModelKind::Directory(None) => "directory",
+            ModelKind::Vad => "vad",
+            ModelKind::WakeWord => "wake-word",
+            ModelKind::SpeechEnhancement => "enhancement",
+            ModelKind::SpeakerEmbedding => "speaker",
+            ModelKind::Partial => "partial",
+            ModelKind::Other => "other",
+        }
+    }
+}
+
+/// One file or directory in the models directory
+#[derive(Debug, Clone)]
+pub struct InstalledModel {
+    /// Name used by `--remove` (the model name for Whisper, else the entry name)
+    pub name: String,
+    pub kind: ModelKind,
+    pub path: PathBuf,
+    /// Size in bytes, recursive for directories
+    pub size: u64,
+}
+
+impl InstalledModel {
+    fn file_name(&self) -> &str {
+        self.path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default()
+    }
+
+    /// Whether one of the config's model references points at this entry
+    fn is_referenced(&self, references: &[String]) -> bool {
+        let file_name = self.file_name();
+        references.iter().any(|reference| {
+            let reference_file = Path::new(reference)
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or(reference);
+            reference_file == file_name
+                || (self.kind == ModelKind::Whisper && get_model_filename(reference) == file_name)
+        })
+    }
+}
+
+/// List the entries of `models_dir`, largest first
+pub fn scan(models_dir: &Path) -> Vec<InstalledModel> {
+    let Ok(entries) = std::fs::read_dir(models_dir) else {
+        return Vec::new();
+    };
+    let registry = super::model::registry_snapshot();
+
+    let mut models: Vec<InstalledModel> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.file_name().to_str()?.to_string();
+            // file_type() does not follow symlinks, so a linked model
+            // directory is listed (and removed) as the link itself
+            let is_dir = entry.file_type().ok()?.is_dir();
+            let path = entry.path();
+            let (name, kind) = classify(&file_name, is_dir, |dir| {
+                registry
+                    .iter()
+                    .find(|r| r.name == dir)
+                    .map(|r| r.engine_prefix)
+            });
+            Some(InstalledModel {
+                name,
+                kind,
+                size: disk_size(&path),
+                path,
+            })
+        })
+        .collect();
+    models.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.name.cmp(&b.name)));
+    models
+}
+
+/// Work out the kind and `--remove` name of a models directory entry
+fn classify(
+    file_name: &str,
+    is_dir: bool,
+    engine_for_dir: impl Fn(&str) -> Option<&'static str>,
+) -> (String, ModelKind) {
+    let name = file_name.to_string();
+    if file_name.ends_with(".part") {
+        return (name, ModelKind::Partial);
+    }
+    if is_dir {
+        if let Some(stem) = file_name.strip_suffix(".mlmodelc") {
+            return (
+                stem.strip_prefix("ggml-").unwrap_or(stem).to_string(),
+                ModelKind::CoreMl,
+            );
+        }
+        if file_name == WAKE_WORD_DIR {
+            return (name, ModelKind::WakeWord);
+        }
+        let engine = engine_for_dir(file_name);
+        return (name, ModelKind::Directory(engine));
+    }
+    if file_name == get_whisper_vad_model_filename()
+        || file_name == get_silero_vad_model_filename()
+        || file_name.starts_with("ggml-silero")
+    {
+        return (name, ModelKind::Vad);
+    }
+    if file_name == GTCRN_MODEL_FILENAME {
+        return (name, ModelKind::SpeechEnhancement);
+    }
+    if file_name == ECAPA_MODEL_FILENAME {
+        return (name, ModelKind::SpeakerEmbedding);
+    }
+    match file_name
+        .strip_prefix("ggml-")
+        .and_then(|n| n.strip_suffix(".bin"))
+    {
+        Some(model) => (model.to_string(), ModelKind::Whisper),
+        None => (name, ModelKind::Other),
+    }
+}
+
+/// Size of a file, or the total size of a directory tree. Symlinks count
+/// as the link itself.
+fn disk_size(path: &Path) -> u64 {
+    let Ok(meta) = std::fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !meta.is_dir() {
+        return meta.len();
+    }
+    std::fs::read_dir(path)
+        .map(|entries| entries.flatten().map(|e| disk_size(&e.path())).sum())
+        .unwrap_or(0)
+}
+
+/// Human-readable size (binary units)
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+/// Model references from the config, plus the default VAD models when the
+/// VAD is enabled without an explicit model path, and the wake word models
+/// with wake word activation
+fn config_references(config: &Config) -> Vec<String> {
+    let mut references = config.referenced_models();
+    if config.vad.enabled && config.vad.model.is_none() {
+        references.push(get_whisper_vad_model_filename().to_string());
+        references.push(get_silero_vad_model_filename().to_string());
+    }
+    if config.activation == Activation::WakeWord {
+        references.push(WAKE_WORD_DIR.to_string());
+    }
+    references
+}
+
+/// Print every entry in the models directory with its size
+pub fn show_disk_usage(config: &Config) {
+    let models_dir = Config::models_dir();
+    println!("Model Disk Usage ({})\n", models_dir.display());
+
+    let models = scan(&models_dir);
+    if models.is_empty() {
+        println!("  No models installed.");
+        return;
+    }
+
+    let references = config_references(config);
+    let name_width = models.iter().map(|m| m.name.len()).max().unwrap_or(0);
+    for model in &models {
+        let note = if model.is_referenced(&references) {
+            "  (in use)"
+        } else {
+            ""
+        };
+        println!(
+            "  {:<12} {:<width$}  {:>9}{}",
+            model.kind.label(),
+            model.name,
+            format_size(model.size),
+            note,
+            width = name_width
+        );
+    }
+
+    let total: u64 = models.iter().map(|m| m.size).sum();
+    println!("\n  Total: {}", format_size(total));
+    println!("\n  Remove with: voxtype setup model --remove <name>");
+}
+
+/// Delete the named entries from the models directory. Every name is
+/// checked before anything is deleted, so a typo or an in-use model aborts
+/// the whole command.
+pub fn remove(config: &Config, names: &[String], force: bool) -> anyhow::Result<()> {
+    let models_dir = Config::models_dir();
+    let models = scan(&models_dir);
+    let references = config_references(config);
+
+    let mut selected = Vec::new();
+    for name in names {
+        let Some(model) = models
+            .iter()
+            .find(|m| &m.name == name || m.file_name() == name)
+        else {
+            print_failure(&format!("No installed model named '{}'", name));
+            print_info("Run 'voxtype setup model --disk-usage' to list installed models.");
+            anyhow::bail!("Model '{}' not found in {:?}", name, models_dir);
+        };
+        if model.is_referenced(&references) && !force {
+            print_failure(&format!("'{}' is used by the current config", name));
+            print_info("Switch to another model first, or pass --force to remove it anyway.");
+            anyhow::bail!("Refusing to remove in-use model '{}'", name);
+        }
+        selected.push(model);
+    }
+
+    let mut freed = 0;
+    for model in selected {
+        let mut paths = vec![model.path.clone()];
+        // A Whisper model's CoreML encoder is useless without it
+        if model.kind == ModelKind::Whisper {
+            let coreml = coreml_encoder_path(&model.path);
+            if coreml.exists() {
+                paths.push(coreml);
+            }
+        }
+        for path in paths {
+            let size = disk_size(&path);
+            let result = if std::fs::symlink_metadata(&path).is_ok_and(|m| m.is_dir()) {
+                std::fs::remove_dir_all(&path)
+            } else {
+                std::fs::remove_file(&path)
+            };
+            result.map_err(|e| anyhow::anyhow!("Failed to remove {:?}: {}", path, e))?;
+            freed += size;
+            print_success(&format!(
+                "Removed {} ({})",
+                path.display(),
+                format_size(size)
+            ));
+        }
+        if matches!(
+            model.kind,
+            ModelKind::SpeechEnhancement | ModelKind::SpeakerEmbedding
+        ) {
+            print_warning("Meeting mode downloads this model again the next time it needs it.");
+        }
+    }
+
+    println!("\nFreed {}", format_size(freed));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_engine(_: &str) -> Option<&'static str> {
+        None
+    }
+
+    #[test]
+    fn test_classify_entries() {
+        assert_eq!(
+            classify("ggml-large-v3-turbo.bin", false, no_engine),
+            ("large-v3-turbo".to_string(), ModelKind::Whisper)
+        );
+        assert_eq!(
+            classify("ggml-base.en-encoder.mlmodelc", true, no_engine),
+            ("base.en-encoder".to_string(), ModelKind::CoreMl)
+        );
+        assert_eq!(
+            classify(get_whisper_vad_model_filename(), false, no_engine).1,
+            ModelKind::Vad
+        );
+        assert_eq!(
+            classify(get_silero_vad_model_filename(), false, no_engine).1,
+            ModelKind::Vad
+        );
+        assert_eq!(
+            classify("gtcrn_simple.onnx", false, no_engine).1,
+            ModelKind::SpeechEnhancement
+        );
+        assert_eq!(
+            classify("ggml-base.en.bin.part", false, no_engine).1,
+            ModelKind::Partial
+        );
+        assert_eq!(
+            classify("parakeet-tdt-0.6b-v3", true, |_| Some("parakeet")),
+            (
+                "parakeet-tdt-0.6b-v3".to_string(),
+                ModelKind::Directory(Some("parakeet"))
+            )
+        );
+        assert_eq!(
+            classify("wake-word", true, no_engine).1,
+            ModelKind::WakeWord
+        );
+        assert_eq!(classify("notes.txt", false, no_engine).1, ModelKind::Other);
+    }
+
+    #[test]
+    fn test_is_referenced() {
+        let whisper = InstalledModel {
+            name: "base.en".to_string(),
+            kind: ModelKind::Whisper,
+            path: PathBuf::from("/models/ggml-base.en.bin"),
+            size: 0,
+        };
+        assert!(whisper.is_referenced(&["base.en".to_string()]));
+        assert!(whisper.is_referenced(&["/models/ggml-base.en.bin".to_string()]));
+        assert!(!whisper.is_referenced(&["base".to_string()]));
+
+        let dir = InstalledModel {
+            name: "parakeet-tdt-0.6b-v3".to_string(),
+            kind: ModelKind::Directory(Some("parakeet")),
+            path: PathBuf::from("/models/parakeet-tdt-0.6b-v3"),
+            size: 0,
+        };
+        assert!(dir.is_referenced(&["parakeet-tdt-0.6b-v3".to_string()]));
+        assert!(!dir.is_referenced(&["base.en".to_string()]));
+    }
+
+    #[test]
+    fn test_scan_sizes_files_and_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("ggml-tiny.bin"), vec![0u8; 100]).unwrap();
+        let onnx = dir.path().join("moonshine-base");
+        std::fs::create_dir_all(onnx.join("nested")).unwrap();
+        std::fs::write(onnx.join("encoder.onnx"), vec![0u8; 300]).unwrap();
+        std::fs::write(onnx.join("nested/tokens.txt"), vec![0u8; 50]).unwrap();
+
+        let models = scan(dir.path());
+        assert_eq!(models.len(), 2);
+        assert_eq!(models[0].name, "moonshine-base");
+        assert_eq!(models[0].size, 350);
+        assert_eq!(models[1].name, "tiny");
+        assert_eq!(models[1].kind, ModelKind::Whisper);
+        assert_eq!(models[1].size, 100);
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(1_610_612_736), "1.5 GB");
+    }
+}
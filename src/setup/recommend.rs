@@ -0,0 +1,308 @@
+//! Hardware detection and engine/model recommendation
+//!
+//! Used by `voxtype setup` to pick a model for a fresh config, by
+//! `voxtype setup model` to pre-select one, and by `voxtype setup check`
+//! to show what was detected. Detection is best-effort: anything that
+//! can't be read is reported as unknown and the recommendation falls back
+//! to the cautious choice.
+
+use super::gpu::{self, Backend, GpuInfo, GpuVendor};
+use crate::config::TranscriptionEngine;
+
+/// What voxtype could find out about this machine
+#[derive(Debug, Clone)]
+pub struct Hardware {
+    /// Logical CPUs available to this process
+    pub cpu_threads: usize,
+    /// Total memory in MB
+    pub ram_mb: Option<u64>,
+    pub avx2: bool,
+    pub avx512: bool,
+    pub in_vm: bool,
+    pub gpus: Vec<GpuInfo>,
+    /// This binary was built with Whisper GPU support
+    pub gpu_build: bool,
+    /// The Vulkan binary is installed and can be switched to
+    pub vulkan_installed: bool,
+    /// This binary was built with the Parakeet engine
+    pub parakeet_build: bool,
+}
+
+impl Hardware {
+    /// Detect the current machine
+    pub fn detect() -> Self {
+        #[cfg(target_arch = "x86_64")]
+        let (avx2, avx512) = (
+            std::arch::is_x86_feature_detected!("avx2"),
+            std::arch::is_x86_feature_detected!("avx512f"),
+        );
+        #[cfg(not(target_arch = "x86_64"))]
+        let (avx2, avx512) = (false, false);
+
+        Self {
+            cpu_threads: std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1),
+            ram_mb: total_ram_mb(),
+            avx2,
+            avx512,
+            in_vm: crate::cpu::is_running_in_vm(),
+            gpus: gpu::detect_gpus(),
+            gpu_build: cfg!(any(
+                feature = "gpu-vulkan",
+                feature = "gpu-cuda",
+                feature = "gpu-metal",
+                feature = "gpu-hipblas"
+            )),
+            vulkan_installed: gpu::detect_available_backends().contains(&Backend::Vulkan),
+            parakeet_build: cfg!(feature = "parakeet"),
+        }
+    }
+
+    /// First GPU with its own memory (NVIDIA, AMD) or Apple Silicon
+    pub fn discrete_gpu(&self) -> Option<&GpuInfo> {
+        self.gpus.iter().find(|g| {
+            matches!(
+                g.vendor,
+                GpuVendor::Nvidia | GpuVendor::Amd | GpuVendor::Apple
+            )
+        })
+    }
+
+    /// One-line CPU summary, e.g. "16 threads, AVX2, AVX-512"
+    pub fn cpu_summary(&self) -> String {
+        let mut parts = vec![format!("{} threads", self.cpu_threads)];
+        if self.avx2 {
+            parts.push("AVX2".to_string());
+        }
+        if self.avx512 {
+            parts.push("AVX-512".to_string());
+        }
+        if self.in_vm {
+            parts.push("virtual machine".to_string());
+        }
+        parts.join(", ")
+    }
+
+    /// Total memory for display, e.g. "15.6 GB"
+    pub fn ram_summary(&self) -> String {
+        match self.ram_mb {
+            Some(mb) => format!("{:.1} GB", mb as f64 / 1024.0),
+            None => "unknown".to_string(),
+        }
+    }
+}
+
+/// Total physical memory in MB
+fn total_ram_mb() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+        parse_meminfo_total_mb(&meminfo)
+    }
+    #[cfg(target_os = "macos")]
+    {
+        let output = std::process::Command::new("sysctl")
+            .args(["-n", "hw.memsize"])
+            .output()
+            .ok()?;
+        let bytes: u64 = String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .parse()
+            .ok()?;
+        Some(bytes / 1024 / 1024)
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        None
+    }
+}
+
+/// `MemTotal` from /proc/meminfo, in MB
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_meminfo_total_mb(meminfo: &str) -> Option<u64> {
+    let line = meminfo.lines().find(|l| l.starts_with("MemTotal:"))?;
+    let kb: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kb / 1024)
+}
+
+/// Suggested engine and model for a machine
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recommendation {
+    pub engine: TranscriptionEngine,
+    pub model: String,
+    /// Why this model was picked
+    pub reason: String,
+    /// Extra step the user should take, e.g. enabling the GPU build
+    pub hint: Option<String>,
+}
+
+/// Pick an engine and model for `hw`. `english` selects the `.en` Whisper
+/// variants and allows English-only engines.
+pub fn recommend(hw: &Hardware, english: bool) -> Recommendation {
+    let whisper = |base: &str, reason: String, hint: Option<String>| {
+        // large-v3-turbo has no English-only variant
+        let model = if english && base != "large-v3-turbo" {
+            format!("{}.en", base)
+        } else {
+            base.to_string()
+        };
+        Recommendation {
+            engine: TranscriptionEngine::Whisper,
+            model,
+            reason,
+            hint,
+        }
+    };
+    let ram_mb = hw.ram_mb.unwrap_or(0);
+    let gpu = hw.discrete_gpu();
+
+    if hw.cpu_threads <= 2 || ram_mb < 4096 {
+        return whisper(
+            "tiny",
+            format!(
+                "low-resource machine ({} threads, {} RAM)",
+                hw.cpu_threads,
+                hw.ram_summary()
+            ),
+            None,
+        );
+    }
+
+    if let Some(gpu) = gpu {
+        if hw.gpu_build && ram_mb >= 8192 {
+            return whisper(
+                "large-v3-turbo",
+                format!("{} GPU acceleration", gpu.vendor.display_name()),
+                None,
+            );
+        }
+    }
+
+    // If the GPU could be used with a different binary, say so
+    let gpu_hint = gpu.filter(|_| !hw.gpu_build && hw.vulkan_installed).map(|g| {
+        format!(
+            "{} GPU detected: run `voxtype setup gpu --enable`, then `voxtype setup model` and pick large-v3-turbo",
+            g.vendor.display_name()
+        )
+    });
+
+    if english && hw.parakeet_build && hw.cpu_threads >= 4 && ram_mb >= 8192 {
+        return Recommendation {
+            engine: TranscriptionEngine::Parakeet,
+            model: "parakeet-tdt-0.6b-v3-int8".to_string(),
+            reason: format!(
+                "fast and accurate on CPU ({} threads, {} RAM)",
+                hw.cpu_threads,
+                hw.ram_summary()
+            ),
+            hint: gpu_hint,
+        };
+    }
+
+    if hw.cpu_threads >= 8 && ram_mb >= 8192 {
+        return whisper(
+            "small",
+            format!("{} CPU threads, no GPU acceleration", hw.cpu_threads),
+            gpu_hint,
+        );
+    }
+
+    whisper(
+        "base",
+        format!("{} CPU threads, no GPU acceleration", hw.cpu_threads),
+        gpu_hint,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hardware(cpu_threads: usize, ram_gb: u64) -> Hardware {
+        Hardware {
+            cpu_threads,
+            ram_mb: Some(ram_gb * 1024),
+            avx2: true,
+            avx512: false,
+            in_vm: false,
+            gpus: Vec::new(),
+            gpu_build: false,
+            vulkan_installed: false,
+            parakeet_build: false,
+        }
+    }
+
+    fn nvidia() -> GpuInfo {
+        GpuInfo {
+            name: "NVIDIA GeForce RTX 4070".to_string(),
+            vendor: GpuVendor::Nvidia,
+            pci_slot: None,
+        }
+    }
+
+    #[test]
+    fn test_small_vm_gets_tiny() {
+        let rec = recommend(&hardware(2, 4), true);
+        assert_eq!(rec.model, "tiny.en");
+        let rec = recommend(&hardware(8, 2), false);
+        assert_eq!(rec.model, "tiny");
+    }
+
+    #[test]
+    fn test_gpu_build_with_dgpu_gets_turbo() {
+        let hw = Hardware {
+            gpus: vec![nvidia()],
+            gpu_build: true,
+            ..hardware(8, 16)
+        };
+        let rec = recommend(&hw, true);
+        assert_eq!(rec.engine, TranscriptionEngine::Whisper);
+        assert_eq!(rec.model, "large-v3-turbo");
+    }
+
+    #[test]
+    fn test_integrated_gpu_is_not_enough() {
+        let hw = Hardware {
+            gpus: vec![GpuInfo {
+                name: "Intel UHD Graphics 620".to_string(),
+                vendor: GpuVendor::Intel,
+                pci_slot: None,
+            }],
+            gpu_build: true,
+            ..hardware(4, 8)
+        };
+        assert_eq!(recommend(&hw, true).model, "base.en");
+    }
+
+    #[test]
+    fn test_dgpu_without_gpu_build_hints_at_vulkan() {
+        let hw = Hardware {
+            gpus: vec![nvidia()],
+            vulkan_installed: true,
+            ..hardware(16, 32)
+        };
+        let rec = recommend(&hw, true);
+        assert_eq!(rec.model, "small.en");
+        assert!(rec.hint.unwrap().contains("setup gpu --enable"));
+    }
+
+    #[test]
+    fn test_parakeet_only_for_english() {
+        let hw = Hardware {
+            parakeet_build: true,
+            ..hardware(8, 16)
+        };
+        assert_eq!(recommend(&hw, true).engine, TranscriptionEngine::Parakeet);
+        let rec = recommend(&hw, false);
+        assert_eq!(rec.engine, TranscriptionEngine::Whisper);
+        assert_eq!(rec.model, "small");
+    }
+
+    #[test]
+    fn test_parse_meminfo() {
+        let meminfo = "MemTotal:       16318712 kB\nMemFree:         1234 kB\n";
+        assert_eq!(parse_meminfo_total_mb(meminfo), Some(15936));
+        assert_eq!(parse_meminfo_total_mb("garbage"), None);
+    }
+}
@@ -1,14 +1,46 @@
 //! VAD model download and status
 
 use super::{print_info, print_success, print_warning};
-use crate::config::Config;
-use crate::vad::{get_whisper_vad_model_filename, get_whisper_vad_model_url};
-use std::process::Command;
+use crate::config::{Config, TranscriptionEngine, VadBackend};
+use crate::vad::{
+    get_silero_vad_model_filename, get_silero_vad_model_url, get_whisper_vad_model_filename,
+    get_whisper_vad_model_url,
+};
+
+/// Whether `[vad]` runs Silero with ONNX Runtime rather than through
+/// whisper-rs
+fn uses_silero(config: &Config) -> bool {
+    match config.vad.backend {
+        VadBackend::Silero => true,
+        VadBackend::Auto => {
+            cfg!(feature = "silero-vad") && config.engine != TranscriptionEngine::Whisper
+        }
+        VadBackend::Energy | VadBackend::Whisper => false,
+    }
+}
+
+/// Download the Silero VAD model: the ONNX export for `backend` "silero",
+/// the GGML one for "whisper", or the one the config uses
+pub fn download_model(config: &Config, backend: Option<&str>) -> anyhow::Result<()> {
+    let silero = match backend {
+        Some(backend) => backend == "silero",
+        None => uses_silero(config),
+    };
+    let (filename, url, backend) = if silero {
+        (
+            get_silero_vad_model_filename(),
+            get_silero_vad_model_url(),
+            "silero",
+        )
+    } else {
+        (
+            get_whisper_vad_model_filename(),
+            get_whisper_vad_model_url(),
+            "whisper",
+        )
+    };
 
-/// Download the Silero VAD model
-pub fn download_model() -> anyhow::Result<()> {
     let models_dir = Config::models_dir();
-    let filename = get_whisper_vad_model_filename();
     let model_path = models_dir.join(filename);
 
     if model_path.exists() {
@@ -19,63 +51,58 @@ pub fn download_model() -> anyhow::Result<()> {
 
     std::fs::create_dir_all(&models_dir)?;
 
-    let url = get_whisper_vad_model_url();
-
     println!("Downloading Silero VAD model...");
     println!("URL: {}", url);
 
-    let status = Command::new("curl")
-        .args([
-            "-L",
-            "--progress-bar",
-            "-o",
-            model_path.to_str().unwrap_or("model.bin"),
-            url,
-        ])
-        .status();
+    let expected = super::model::fetch_published_sha256(url);
+    super::model::download_verified(url, &model_path, expected.as_deref())?;
 
-    match status {
-        Ok(exit_status) if exit_status.success() => {
-            print_success(&format!("Saved to {:?}", model_path));
-            println!();
-            print_info("Enable in config.toml:");
-            println!("  [vad]");
-            println!("  enabled = true");
-            println!("  backend = \"whisper\"");
-            Ok(())
-        }
-        Ok(exit_status) => {
-            let _ = std::fs::remove_file(&model_path);
-            anyhow::bail!(
-                "Download failed: curl exited with code {}",
-                exit_status.code().unwrap_or(-1)
-            )
-        }
-        Err(e) => {
-            print_info("Please ensure curl is installed (e.g., 'sudo pacman -S curl')");
-            anyhow::bail!("curl not available: {}", e)
-        }
+    print_success(&format!("Saved to {:?}", model_path));
+    if silero && !cfg!(feature = "silero-vad") {
+        print_warning("This build can't run it; the silero backend needs an ONNX build.");
     }
+    println!();
+    print_info("Enable in config.toml:");
+    println!("  [vad]");
+    println!("  enabled = true");
+    println!("  backend = \"{}\"", backend);
+    Ok(())
 }
 
 /// Show VAD model status
 pub fn show_status() {
     let models_dir = Config::models_dir();
-    let filename = get_whisper_vad_model_filename();
-    let model_path = models_dir.join(filename);
 
     println!("VAD Model Status\n");
 
-    if model_path.exists() {
-        let size = std::fs::metadata(&model_path).map(|m| m.len()).unwrap_or(0);
-        print_success(&format!(
-            "Silero VAD model installed: {:?} ({:.1} MB)",
-            model_path,
-            size as f64 / 1_048_576.0
-        ));
-    } else {
+    let models = [
+        (
+            "Silero VAD model (whisper)",
+            get_whisper_vad_model_filename(),
+        ),
+        ("Silero VAD model (silero)", get_silero_vad_model_filename()),
+    ];
+    let mut installed = false;
+    for (name, filename) in models {
+        let model_path = models_dir.join(filename);
+        if model_path.exists() {
+            installed = true;
+            let size = std::fs::metadata(&model_path).map(|m| m.len()).unwrap_or(0);
+            print_success(&format!(
+                "{} installed: {:?} ({:.1} MB)",
+                name,
+                model_path,
+                size as f64 / 1_048_576.0
+            ));
+        }
+    }
+
+    if !installed {
         print_warning("Silero VAD model not installed");
         print_info("Download with: voxtype setup vad");
         print_info("Energy VAD (no model needed) is available as an alternative.");
     }
+    if !cfg!(feature = "silero-vad") {
+        print_info("The silero backend needs an ONNX build; this one has whisper and energy.");
+    }
 }
@@ -231,6 +231,8 @@ mod tests {
             TranscriptionEngine::Omnilingual,
             TranscriptionEngine::Cohere,
             TranscriptionEngine::Soniox,
+            TranscriptionEngine::Vosk,
+            TranscriptionEngine::Zipformer,
         ];
         for e in engines {
             assert!(
@@ -0,0 +1,82 @@
+//! Wake word model download and status
+
+use super::{print_info, print_success, print_warning};
+use crate::config::{Activation, Config};
+use crate::wake_word::{
+    model_url, models_dir, pretrained_filename, EMBEDDING_FILENAME, MELSPECTROGRAM_FILENAME,
+    PRETRAINED_MODELS,
+};
+
+/// Download the openWakeWord models: the shared melspectrogram and
+/// embedding models, and `model` (or `[wake_word] model`) when it names a
+/// pretrained wake word
+pub fn download_models(config: &Config, model: Option<&str>) -> anyhow::Result<()> {
+    let model = model.unwrap_or(&config.wake_word.model);
+    let mut filenames = vec![
+        MELSPECTROGRAM_FILENAME.to_string(),
+        EMBEDDING_FILENAME.to_string(),
+    ];
+    let pretrained = pretrained_filename(model);
+    filenames.extend(pretrained.clone());
+
+    let dir = models_dir();
+    std::fs::create_dir_all(&dir)?;
+
+    for filename in filenames {
+        let path = dir.join(&filename);
+        if path.exists() {
+            print_success(&format!("Already installed: {:?}", path));
+            continue;
+        }
+        let url = model_url(&filename);
+        println!("Downloading {}...", filename);
+        println!("URL: {}", url);
+        super::model::download_verified(&url, &path, None)?;
+        print_success(&format!("Saved to {:?}", path));
+    }
+
+    if pretrained.is_none() {
+        print_info(&format!(
+            "\"{}\" isn't a pretrained model ({}); set [wake_word] model to the path of \
+             your own openWakeWord model.",
+            model,
+            PRETRAINED_MODELS.join(", ")
+        ));
+    }
+    if !cfg!(feature = "wake-word") {
+        print_warning("This build can't run them; wake word activation needs an ONNX build.");
+    }
+    println!();
+    print_info("Enable in config.toml:");
+    println!("  activation = \"wake_word\"");
+    println!();
+    println!("  [wake_word]");
+    println!("  model = \"{}\"", model);
+    Ok(())
+}
+
+/// Show wake word model status
+pub fn show_status(config: &Config) {
+    println!("Wake Word Status\n");
+
+    let activation = match config.activation {
+        Activation::Hotkey => "hotkey",
+        Activation::WakeWord => "wake_word",
+    };
+    println!("Activation: {}", activation);
+    println!("Wake word:  {}\n", config.wake_word.model);
+
+    match crate::wake_word::resolve_model_paths(&config.wake_word) {
+        Ok(paths) => {
+            for path in [paths.melspectrogram, paths.embedding, paths.classifier] {
+                print_success(&format!("Installed: {:?}", path));
+            }
+        }
+        Err(e) => {
+            print_warning(&e.to_string());
+        }
+    }
+    if !cfg!(feature = "wake-word") {
+        print_info("Wake word activation needs an ONNX build; this one can't run it.");
+    }
+}
@@ -11,7 +11,9 @@
 //! ## Contract
 //!
 //! - Key order: `text, alt, class, tooltip` (then `model, device, backend`
-//!   when extended).
+//!   when extended, followed by `engine, configured_engine` after a fallback,
+//!   `language` once one has been auto-detected and `provider` when an
+//!   ONNX engine has picked its execution provider).
 //! - Whitespace: a single space after each `:` between key and value.
 //! - The tooltip is a JSON string with `\n` (the two-byte escape) between
 //!   lines, not a real `0x0a` newline — Waybar renders these client-side.
@@ -33,6 +35,15 @@ pub struct ExtendedStatusInfo {
     pub model: String,
     pub device: String,
     pub backend: String,
+    /// `(configured, active)` engine names when the daemon fell back from
+    /// the configured engine at startup (`engine = ["a", "b"]`)
+    pub fallback: Option<(String, String)>,
+    /// Language auto-detected for the most recent transcription, when
+    /// `language` is "auto" or a list
+    pub language: Option<String>,
+    /// ONNX Runtime execution provider the daemon's engine runs on
+    /// ("CUDA", "MIGraphX", "CPU", ...), for ONNX engines
+    pub provider: Option<String>,
 }
 
 impl ExtendedStatusInfo {
@@ -63,12 +74,79 @@ impl ExtendedStatusInfo {
             "unknown".to_string()
         };
 
+        let fallback = std::fs::read_to_string(engine_fallback_file())
+            .ok()
+            .and_then(|s| {
+                let mut parts = s.split_whitespace();
+                Some((parts.next()?.to_string(), parts.next()?.to_string()))
+            });
+
+        // Report the model of the engine actually running
+        let model = match &fallback {
+            Some((_, active)) => match active.parse::<config::TranscriptionEngine>() {
+                Ok(engine) => config::Config {
+                    engine,
+                    ..config.clone()
+                }
+                .model_name()
+                .to_string(),
+                Err(_) => config.model_name().to_string(),
+            },
+            None => config.model_name().to_string(),
+        };
+
         Self {
-            model: config.model_name().to_string(),
+            model,
             device: config.audio.device.clone(),
             backend,
+            fallback,
+            language: read_detected_language(),
+            provider: read_execution_provider(),
         }
     }
+
+    /// Re-read the detected language after the daemon's state changes
+    pub fn refresh_language(&mut self) {
+        self.language = read_detected_language();
+    }
+
+    /// Re-read the execution provider, which on-demand loading only picks
+    /// on the first transcription
+    pub fn refresh_provider(&mut self) {
+        self.provider = read_execution_provider();
+    }
+}
+
+/// Runtime file where the daemon records an engine fallback as
+/// `"<configured> <active>"`. Absent when the configured engine loaded.
+pub fn engine_fallback_file() -> std::path::PathBuf {
+    config::Config::runtime_dir().join("engine_fallback")
+}
+
+/// Runtime file where the daemon records the language auto-detected for the
+/// most recent transcription. Absent when the language is fixed.
+pub fn detected_language_file() -> std::path::PathBuf {
+    config::Config::runtime_dir().join("detected_language")
+}
+
+/// Runtime file where the daemon records the ONNX Runtime execution
+/// provider its engine was built with. Absent for non-ONNX engines.
+pub fn execution_provider_file() -> std::path::PathBuf {
+    config::Config::runtime_dir().join("execution_provider")
+}
+
+fn read_execution_provider() -> Option<String> {
+    std::fs::read_to_string(execution_provider_file())
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn read_detected_language() -> Option<String> {
+    std::fs::read_to_string(detected_language_file())
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
 }
 
 /// User-facing backend label for an active variant. Combines engine family
@@ -127,12 +205,43 @@ pub fn format_state_json(
         Some(info) => {
             // Use real newlines in the tooltip — serde_json encodes each as
             // the two-byte `\n` escape, which is what waybar expects.
-            let tooltip = format!(
+            let mut tooltip = format!(
                 "{}\nModel: {}\nDevice: {}\nBackend: {}",
                 base_tooltip, info.model, info.device, info.backend
             );
+            // Fallback keys are appended only when a fallback happened, so
+            // the common shape stays byte-identical.
+            let fallback = match &info.fallback {
+                Some((configured, active)) => {
+                    tooltip.push_str(&format!(
+                        "\nFallback: {} (configured {})",
+                        active, configured
+                    ));
+                    format!(
+                        r#", "engine": {}, "configured_engine": {}"#,
+                        json_str(active),
+                        json_str(configured)
+                    )
+                }
+                None => String::new(),
+            };
+            // Likewise only present once a language has been detected
+            let language = match &info.language {
+                Some(lang) => {
+                    tooltip.push_str(&format!("\nLanguage: {}", lang));
+                    format!(r#", "language": {}"#, json_str(lang))
+                }
+                None => String::new(),
+            };
+            let provider = match &info.provider {
+                Some(provider) => {
+                    tooltip.push_str(&format!("\nProvider: {}", provider));
+                    format!(r#", "provider": {}"#, json_str(provider))
+                }
+                None => String::new(),
+            };
             format!(
-                r#"{{"text": {}, "alt": {}, "class": {}, "tooltip": {}, "model": {}, "device": {}, "backend": {}}}"#,
+                r#"{{"text": {}, "alt": {}, "class": {}, "tooltip": {}, "model": {}, "device": {}, "backend": {}{}{}{}}}"#,
                 json_str(text),
                 json_str(alt),
                 json_str(class),
@@ -140,6 +249,9 @@ pub fn format_state_json(
                 json_str(&info.model),
                 json_str(&info.device),
                 json_str(&info.backend),
+                fallback,
+                language,
+                provider,
             )
         }
         None => format!(
@@ -244,6 +356,9 @@ mod tests {
             model: "base.en".to_string(),
             device: "default".to_string(),
             backend: "CPU (AVX2)".to_string(),
+            fallback: None,
+            language: None,
+            provider: None,
         };
         assert_eq!(
             format_state_json("recording", &icons, Some(&ext)),
@@ -255,6 +370,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn format_state_json_reports_engine_fallback() {
+        let icons = config::StatusConfig::default().resolve_icons();
+        let ext = ExtendedStatusInfo {
+            model: "base.en".to_string(),
+            device: "default".to_string(),
+            backend: "CPU (AVX2)".to_string(),
+            fallback: Some(("parakeet".to_string(), "whisper".to_string())),
+            language: None,
+            provider: None,
+        };
+        let json = format_state_json("idle", &icons, Some(&ext));
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["engine"], "whisper");
+        assert_eq!(parsed["configured_engine"], "parakeet");
+        assert!(parsed["tooltip"]
+            .as_str()
+            .unwrap()
+            .ends_with("Fallback: whisper (configured parakeet)"));
+    }
+
+    #[test]
+    fn format_state_json_reports_detected_language() {
+        let icons = config::StatusConfig::default().resolve_icons();
+        let ext = ExtendedStatusInfo {
+            model: "large-v3-turbo".to_string(),
+            device: "default".to_string(),
+            backend: "GPU (Vulkan)".to_string(),
+            fallback: None,
+            language: Some("fr".to_string()),
+            provider: None,
+        };
+        let json = format_state_json("idle", &icons, Some(&ext));
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["language"], "fr");
+        assert!(parsed["tooltip"]
+            .as_str()
+            .unwrap()
+            .ends_with("Language: fr"));
+    }
+
+    #[test]
+    fn format_state_json_reports_execution_provider() {
+        let icons = config::StatusConfig::default().resolve_icons();
+        let ext = ExtendedStatusInfo {
+            model: "parakeet-tdt-0.6b-v3".to_string(),
+            device: "default".to_string(),
+            backend: "ONNX GPU (CUDA 12)".to_string(),
+            fallback: None,
+            language: None,
+            provider: Some("CPU".to_string()),
+        };
+        let json = format_state_json("idle", &icons, Some(&ext));
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["provider"], "CPU");
+        assert!(parsed["tooltip"]
+            .as_str()
+            .unwrap()
+            .ends_with("Provider: CPU"));
+    }
+
     /// The whole point of the serde_json switch in `format_state_json` is
     /// that a device name or model string containing `"` or `\` can't
     /// break the JSON output. Pin the escaping: round-trip the output
@@ -272,6 +448,9 @@ mod tests {
             model: r#"large-v3-"turbo""#.to_string(),
             device: r#"PulseAudio "Main" \ Loopback"#.to_string(),
             backend: r#"GPU \\ CUDA"#.to_string(),
+            fallback: None,
+            language: None,
+            provider: None,
         };
 
         let json = format_state_json("recording", &icons, Some(&ext));
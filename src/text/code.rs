@@ -0,0 +1,241 @@
+//! Code dictation modes
+//!
+//! A spoken mode phrase formats the words after it as a single identifier:
+//! "camel case user profile id" → "userProfileId", "snake case max retries"
+//! → "max_retries". The identifier runs until sentence punctuation, the next
+//! mode phrase, the words "end case", or the end of the text. Profiles can
+//! also format a whole dictation in one style via `identifier_style`.
+
+use crate::config::IdentifierStyle;
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Spoken mode phrases, longest first so "screaming snake case" wins over
+/// "snake case"
+const MODE_PHRASES: &[(&[&str], IdentifierStyle)] = &[
+    (&["screaming", "snake", "case"], IdentifierStyle::Constant),
+    (&["constant", "case"], IdentifierStyle::Constant),
+    (&["camel", "case"], IdentifierStyle::Camel),
+    (&["pascal", "case"], IdentifierStyle::Pascal),
+    (&["snake", "case"], IdentifierStyle::Snake),
+    (&["kebab", "case"], IdentifierStyle::Kebab),
+];
+
+/// Ends an identifier early: "camel case user id end case equals five"
+const END_PHRASE: &[&str] = &["end", "case"];
+
+/// Punctuation that ends an identifier and is kept after it
+const TRAILING_PUNCT: &[char] = &['.', ',', ';', ':', '!', '?', ')', ']', '}'];
+
+/// A whitespace-separated token and the whitespace before it
+pub(super) struct Token<'a> {
+    pub gap: &'a str,
+    pub text: &'a str,
+}
+
+/// Split text into tokens, returning the trailing whitespace separately.
+/// The original whitespace is kept so newlines and tabs from spoken
+/// punctuation survive rewriting.
+pub(super) fn tokenize(text: &str) -> (Vec<Token<'_>>, &str) {
+    static TOKEN_RE: OnceLock<Regex> = OnceLock::new();
+    let token_re = TOKEN_RE.get_or_init(|| Regex::new(r"\S+").expect("valid token regex"));
+
+    let mut tokens = Vec::new();
+    let mut last_end = 0;
+    for m in token_re.find_iter(text) {
+        tokens.push(Token {
+            gap: &text[last_end..m.start()],
+            text: m.as_str(),
+        });
+        last_end = m.end();
+    }
+    (tokens, &text[last_end..])
+}
+
+/// Replace each mode phrase and the words it covers with the identifier
+pub fn apply_mode_commands(text: &str) -> String {
+    let (tokens, tail) = tokenize(text);
+
+    let mut result = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < tokens.len() {
+        let Some((style, phrase_len)) = match_mode(&tokens[i..]) else {
+            result.push_str(tokens[i].gap);
+            result.push_str(tokens[i].text);
+            i += 1;
+            continue;
+        };
+
+        let mut j = i + phrase_len;
+        let mut words = Vec::new();
+        let mut trailing = "";
+        while j < tokens.len() {
+            if match_mode(&tokens[j..]).is_some() {
+                break;
+            }
+            if starts_with_phrase(&tokens[j..], END_PHRASE) {
+                // Keep punctuation said after "end case", drop the words
+                let last = tokens[j + END_PHRASE.len() - 1].text;
+                trailing = &last[last.trim_end_matches(TRAILING_PUNCT).len()..];
+                j += END_PHRASE.len();
+                break;
+            }
+            let word = tokens[j].text;
+            let core = word.trim_end_matches(TRAILING_PUNCT);
+            words.push(core);
+            j += 1;
+            if core.len() < word.len() {
+                trailing = &word[core.len()..];
+                break;
+            }
+        }
+
+        let identifier = format_identifier(&words.join(" "), style);
+        if identifier.is_empty() {
+            // A mode phrase on its own is just text
+            result.push_str(tokens[i].gap);
+            result.push_str(tokens[i].text);
+            i += 1;
+            continue;
+        }
+        result.push_str(tokens[i].gap);
+        result.push_str(&identifier);
+        result.push_str(trailing);
+        i = j;
+    }
+    result.push_str(tail);
+    result
+}
+
+/// Format text as one identifier. Words are split on anything that isn't a
+/// letter or digit; apostrophes are dropped so "user's" becomes "users".
+pub fn format_identifier(text: &str, style: IdentifierStyle) -> String {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .map(|w| w.replace('\'', "").to_lowercase())
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    match style {
+        IdentifierStyle::Camel => words
+            .iter()
+            .enumerate()
+            .map(|(i, w)| if i == 0 { w.clone() } else { capitalize(w) })
+            .collect(),
+        IdentifierStyle::Pascal => words.iter().map(|w| capitalize(w)).collect(),
+        IdentifierStyle::Snake => words.join("_"),
+        IdentifierStyle::Kebab => words.join("-"),
+        IdentifierStyle::Constant => words.join("_").to_uppercase(),
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Normalized form of a token for phrase matching
+fn key(token: &str) -> String {
+    token
+        .trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+fn starts_with_phrase(tokens: &[Token], phrase: &[&str]) -> bool {
+    tokens.len() >= phrase.len()
+        && phrase
+            .iter()
+            .zip(tokens)
+            .all(|(word, token)| key(token.text) == *word)
+}
+
+fn match_mode(tokens: &[Token]) -> Option<(IdentifierStyle, usize)> {
+    MODE_PHRASES
+        .iter()
+        .find(|(phrase, _)| starts_with_phrase(tokens, phrase))
+        .map(|(phrase, style)| (*style, phrase.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_phrases() {
+        assert_eq!(
+            apply_mode_commands("camel case user profile id"),
+            "userProfileId"
+        );
+        assert_eq!(apply_mode_commands("pascal case http client"), "HttpClient");
+        assert_eq!(
+            apply_mode_commands("set snake case max retries to 5"),
+            "set max_retries_to_5"
+        );
+        assert_eq!(
+            apply_mode_commands("kebab case main nav bar"),
+            "main-nav-bar"
+        );
+        assert_eq!(
+            apply_mode_commands("Screaming snake case default timeout"),
+            "DEFAULT_TIMEOUT"
+        );
+        assert_eq!(apply_mode_commands("constant case max size"), "MAX_SIZE");
+    }
+
+    #[test]
+    fn test_identifier_ends_at_punctuation_and_end_case() {
+        assert_eq!(
+            apply_mode_commands("call camel case get user. Then return"),
+            "call getUser. Then return"
+        );
+        assert_eq!(
+            apply_mode_commands("camel case user id end case equals 5"),
+            "userId equals 5"
+        );
+        assert_eq!(
+            apply_mode_commands("snake case first name end case, please"),
+            "first_name, please"
+        );
+        assert_eq!(
+            apply_mode_commands("camel case user id snake case user name"),
+            "userId user_name"
+        );
+    }
+
+    #[test]
+    fn test_preserves_whitespace_and_plain_text() {
+        assert_eq!(
+            apply_mode_commands("let\ncamel case item count\n"),
+            "let\nitemCount\n"
+        );
+        assert_eq!(
+            apply_mode_commands("a case of snake oil"),
+            "a case of snake oil"
+        );
+        // Mode phrase with nothing after it is left alone
+        assert_eq!(
+            apply_mode_commands("I like camel case"),
+            "I like camel case"
+        );
+    }
+
+    #[test]
+    fn test_format_identifier() {
+        assert_eq!(
+            format_identifier("User profile ID.", IdentifierStyle::Camel),
+            "userProfileId"
+        );
+        assert_eq!(
+            format_identifier("the user's name", IdentifierStyle::Snake),
+            "the_users_name"
+        );
+        assert_eq!(
+            format_identifier("fetch-all items", IdentifierStyle::Kebab),
+            "fetch-all-items"
+        );
+        assert_eq!(format_identifier("", IdentifierStyle::Pascal), "");
+    }
+}
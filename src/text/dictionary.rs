@@ -0,0 +1,174 @@
+//! External replacement dictionary (`[text] replacements_file`)
+//!
+//! A flat TOML table of `"spoken phrase" = "replacement"` entries kept
+//! outside the main config so it can be synced between machines. The file's
+//! modification time is checked before each use and the table re-read when
+//! it changes, so edits apply on the next dictation without a restart.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+/// Replacement entries loaded from a dictionary file, reloaded on change
+pub struct ReplacementsFile {
+    path: PathBuf,
+    state: Mutex<FileState>,
+}
+
+#[derive(Default)]
+struct FileState {
+    /// Modification time of the last read attempt. `None` until the file has
+    /// been seen, so a file created after startup is picked up.
+    modified: Option<SystemTime>,
+    /// Lowercased keys, matching how inline replacements are stored
+    entries: Arc<HashMap<String, String>>,
+}
+
+impl ReplacementsFile {
+    /// Create a dictionary for `path` and load it if it exists
+    pub fn new(path: PathBuf) -> Self {
+        let file = Self {
+            path,
+            state: Mutex::new(FileState::default()),
+        };
+        if !file.path.exists() {
+            tracing::warn!(
+                "Replacements file {:?} not found; it will be loaded once created",
+                file.path
+            );
+        }
+        file.entries();
+        file
+    }
+
+    /// Current entries, re-reading the file first if it changed on disk.
+    ///
+    /// A file that fails to parse keeps the previous entries so a half-saved
+    /// or half-synced edit doesn't drop the whole dictionary.
+    pub fn entries(&self) -> Arc<HashMap<String, String>> {
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        let modified = std::fs::metadata(&self.path)
+            .and_then(|m| m.modified())
+            .ok();
+        if modified == state.modified {
+            return Arc::clone(&state.entries);
+        }
+        state.modified = modified;
+
+        if modified.is_none() {
+            if !state.entries.is_empty() {
+                tracing::warn!("Replacements file {:?} was removed", self.path);
+                state.entries = Arc::default();
+            }
+            return Arc::clone(&state.entries);
+        }
+
+        match load(&self.path) {
+            Ok(entries) => {
+                tracing::info!(
+                    "Loaded {} word replacements from {:?}",
+                    entries.len(),
+                    self.path
+                );
+                state.entries = Arc::new(entries);
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to load replacements file {:?}: {}; keeping previous entries",
+                    self.path,
+                    e
+                );
+            }
+        }
+        Arc::clone(&state.entries)
+    }
+}
+
+/// Read and parse a dictionary file, lowercasing its keys
+fn load(path: &Path) -> Result<HashMap<String, String>, String> {
+    let contents = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let table: HashMap<String, String> = toml::from_str(&contents).map_err(|e| e.to_string())?;
+    Ok(table
+        .into_iter()
+        .map(|(k, v)| (k.to_lowercase(), v))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn write(path: &Path, contents: &str, age_secs: u64) {
+        std::fs::write(path, contents).unwrap();
+        // Set the mtime explicitly; back-to-back writes can land in the same
+        // filesystem timestamp tick.
+        let mtime = SystemTime::now() - Duration::from_secs(age_secs);
+        std::fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(mtime)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_loads_and_lowercases_keys() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("dictionary.toml");
+        write(&path, "\"Vox Type\" = \"voxtype\"\n", 60);
+
+        let file = ReplacementsFile::new(path);
+        let entries = file.entries();
+        assert_eq!(entries.get("vox type").map(String::as_str), Some("voxtype"));
+    }
+
+    #[test]
+    fn test_reloads_when_changed() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("dictionary.toml");
+        write(&path, "\"vox type\" = \"voxtype\"\n", 60);
+        let file = ReplacementsFile::new(path.clone());
+        assert_eq!(file.entries().len(), 1);
+
+        write(
+            &path,
+            "\"oh marky\" = \"Omarchy\"\n\"kube cuddle\" = \"kubectl\"\n",
+            30,
+        );
+        let entries = file.entries();
+        assert_eq!(entries.len(), 2);
+        assert!(!entries.contains_key("vox type"));
+    }
+
+    #[test]
+    fn test_parse_error_keeps_previous_entries() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("dictionary.toml");
+        write(&path, "\"vox type\" = \"voxtype\"\n", 60);
+        let file = ReplacementsFile::new(path.clone());
+
+        write(&path, "\"vox type\" = \n", 30);
+        assert_eq!(
+            file.entries().get("vox type").map(String::as_str),
+            Some("voxtype")
+        );
+    }
+
+    #[test]
+    fn test_missing_file_is_empty_until_created() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("dictionary.toml");
+        let file = ReplacementsFile::new(path.clone());
+        assert!(file.entries().is_empty());
+
+        write(&path, "\"vox type\" = \"voxtype\"\n", 30);
+        assert_eq!(file.entries().len(), 1);
+    }
+}
@@ -0,0 +1,246 @@
+//! Emoji and symbol dictation
+//!
+//! "thumbs up emoji" → 👍, "shrug emoji" → 🤷 and "degree symbol" → °.
+//! Names are matched case-insensitively on word boundaries and must be
+//! followed by the trigger word, so "thumbs up" on its own stays text.
+//! `[text] emoji_map` adds names to (or overrides) the built-in emoji table.
+
+use regex::{Captures, Regex};
+use std::collections::HashMap;
+
+/// Built-in names spoken before "emoji"
+const EMOJI: &[(&str, &str)] = &[
+    ("thumbs up", "👍"),
+    ("plus one", "👍"),
+    ("thumbs down", "👎"),
+    ("shrug", "🤷"),
+    ("facepalm", "🤦"),
+    ("smile", "😄"),
+    ("smiley", "😃"),
+    ("grin", "😁"),
+    ("laughing", "😂"),
+    ("crying laughing", "😂"),
+    ("joy", "😂"),
+    ("rofl", "🤣"),
+    ("wink", "😉"),
+    ("blush", "😊"),
+    ("sweat smile", "😅"),
+    ("upside down", "🙃"),
+    ("heart eyes", "😍"),
+    ("kiss", "😘"),
+    ("thinking", "🤔"),
+    ("eye roll", "🙄"),
+    ("neutral", "😐"),
+    ("grimace", "😬"),
+    ("sad", "😢"),
+    ("crying", "😭"),
+    ("angry", "😠"),
+    ("scream", "😱"),
+    ("cool", "😎"),
+    ("sunglasses", "😎"),
+    ("nerd", "🤓"),
+    ("sleeping", "😴"),
+    ("melting", "🫠"),
+    ("salute", "🫡"),
+    ("skull", "💀"),
+    ("ghost", "👻"),
+    ("robot", "🤖"),
+    ("poop", "💩"),
+    ("clap", "👏"),
+    ("pray", "🙏"),
+    ("thank you", "🙏"),
+    ("wave", "👋"),
+    ("ok hand", "👌"),
+    ("raised hands", "🙌"),
+    ("muscle", "💪"),
+    ("crossed fingers", "🤞"),
+    ("point up", "👆"),
+    ("point down", "👇"),
+    ("point left", "👈"),
+    ("point right", "👉"),
+    ("eyes", "👀"),
+    ("heart", "❤️"),
+    ("broken heart", "💔"),
+    ("fire", "🔥"),
+    ("hundred", "💯"),
+    ("one hundred", "💯"),
+    ("100", "💯"),
+    ("party", "🎉"),
+    ("tada", "🎉"),
+    ("confetti", "🎊"),
+    ("rocket", "🚀"),
+    ("star", "⭐"),
+    ("sparkles", "✨"),
+    ("rainbow", "🌈"),
+    ("sun", "☀️"),
+    ("check mark", "✅"),
+    ("check", "✅"),
+    ("cross mark", "❌"),
+    ("warning", "⚠️"),
+    ("question", "❓"),
+    ("exclamation", "❗"),
+    ("light bulb", "💡"),
+    ("lightbulb", "💡"),
+    ("bug", "🐛"),
+    ("coffee", "☕"),
+    ("beer", "🍺"),
+    ("cake", "🎂"),
+    ("gift", "🎁"),
+    ("trophy", "🏆"),
+    ("money", "💰"),
+    ("memo", "📝"),
+    ("pin", "📌"),
+    ("link", "🔗"),
+    ("lock", "🔒"),
+    ("key", "🔑"),
+    ("bell", "🔔"),
+    ("calendar", "📅"),
+];
+
+/// Built-in names spoken before "symbol"
+const SYMBOLS: &[(&str, &str)] = &[
+    ("degree", "°"),
+    ("copyright", "©"),
+    ("registered", "®"),
+    ("trademark", "™"),
+    ("euro", "€"),
+    ("pound", "£"),
+    ("yen", "¥"),
+    ("cent", "¢"),
+    ("section", "§"),
+    ("paragraph", "¶"),
+    ("bullet", "•"),
+    ("ellipsis", "…"),
+    ("em dash", "—"),
+    ("en dash", "–"),
+    ("arrow", "→"),
+    ("right arrow", "→"),
+    ("left arrow", "←"),
+    ("up arrow", "↑"),
+    ("down arrow", "↓"),
+    ("check mark", "✓"),
+    ("infinity", "∞"),
+    ("pi", "π"),
+    ("micro", "µ"),
+    ("plus minus", "±"),
+    ("times", "×"),
+    ("division", "÷"),
+    ("not equal", "≠"),
+    ("approximately", "≈"),
+    ("less than or equal", "≤"),
+    ("greater than or equal", "≥"),
+];
+
+/// Matches "<name> emoji" and "<name> symbol" phrases
+pub struct EmojiMatcher {
+    re: Regex,
+    emoji: HashMap<String, String>,
+    symbols: HashMap<String, String>,
+}
+
+impl EmojiMatcher {
+    /// Build a matcher from the built-in tables plus user emoji names
+    pub fn new(user_emoji: &HashMap<String, String>) -> Self {
+        let mut emoji: HashMap<String, String> = EMOJI
+            .iter()
+            .map(|(name, e)| (name.to_string(), e.to_string()))
+            .collect();
+        for (name, e) in user_emoji {
+            let name = normalize(name);
+            if !name.is_empty() {
+                emoji.insert(name, e.clone());
+            }
+        }
+        let symbols: HashMap<String, String> = SYMBOLS
+            .iter()
+            .map(|(name, s)| (name.to_string(), s.to_string()))
+            .collect();
+
+        let pattern = format!(
+            r"(?i)\b(?:({})\s+emoji|({})\s+symbol)\b",
+            alternation(emoji.keys()),
+            alternation(symbols.keys())
+        );
+        let re = Regex::new(&pattern).expect("emoji names are escaped");
+
+        Self { re, emoji, symbols }
+    }
+
+    /// Replace every emoji or symbol phrase in `text`
+    pub fn apply(&self, text: &str) -> String {
+        self.re
+            .replace_all(text, |caps: &Captures| {
+                let (table, name) = match (caps.get(1), caps.get(2)) {
+                    (Some(name), _) => (&self.emoji, name),
+                    (None, Some(name)) => (&self.symbols, name),
+                    (None, None) => return caps[0].to_string(),
+                };
+                table
+                    .get(&normalize(name.as_str()))
+                    .cloned()
+                    .unwrap_or_else(|| caps[0].to_string())
+            })
+            .into_owned()
+    }
+}
+
+/// Lowercase and collapse runs of whitespace so "Thumbs  Up" finds "thumbs up"
+fn normalize(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Regex alternation of names, longest first, allowing any whitespace
+/// between words
+fn alternation<'a>(names: impl Iterator<Item = &'a String>) -> String {
+    let mut names: Vec<&String> = names.collect();
+    names.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+    names
+        .iter()
+        .map(|name| {
+            name.split(' ')
+                .map(regex::escape)
+                .collect::<Vec<_>>()
+                .join(r"\s+")
+        })
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_emoji_and_symbols() {
+        let matcher = EmojiMatcher::new(&HashMap::new());
+        assert_eq!(matcher.apply("great job thumbs up emoji"), "great job 👍");
+        assert_eq!(matcher.apply("no idea Shrug Emoji"), "no idea 🤷");
+        assert_eq!(matcher.apply("copyright symbol 2026 Acme"), "© 2026 Acme");
+        assert_eq!(
+            matcher.apply("check mark emoji and check mark symbol"),
+            "✅ and ✓"
+        );
+    }
+
+    #[test]
+    fn test_requires_trigger_word() {
+        let matcher = EmojiMatcher::new(&HashMap::new());
+        assert_eq!(matcher.apply("thumbs up from me"), "thumbs up from me");
+        assert_eq!(matcher.apply("the emoji picker"), "the emoji picker");
+        assert_eq!(matcher.apply("fire emojis"), "fire emojis");
+    }
+
+    #[test]
+    fn test_user_emoji_map() {
+        let user = HashMap::from([
+            ("Ship It".to_string(), "🚢".to_string()),
+            ("fire".to_string(), "🧯".to_string()),
+        ]);
+        let matcher = EmojiMatcher::new(&user);
+        assert_eq!(matcher.apply("ship it emoji"), "🚢");
+        assert_eq!(matcher.apply("fire emoji"), "🧯");
+    }
+}
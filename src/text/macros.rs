@@ -0,0 +1,216 @@
+//! Spoken macros (`[text.macros]`)
+//!
+//! Maps a spoken phrase to a template that is expanded each time the phrase
+//! is dictated, for text a static replacement can't hold:
+//!
+//! ```toml
+//! [text.macros]
+//! "insert today's date" = "{date:%B %-d, %Y}"
+//! "insert time" = "{time}"
+//! "paste clipboard" = "{clipboard}"
+//! ```
+//!
+//! Placeholders: `{date}` and `{time}` (optionally `{date:FORMAT}` with a
+//! chrono/strftime format) and `{clipboard}`. Unknown placeholders are kept.
+
+use chrono::{Local, NaiveDateTime};
+use regex::{Captures, NoExpand, Regex, RegexBuilder};
+use std::cell::OnceCell;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::OnceLock;
+
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+const DEFAULT_TIME_FORMAT: &str = "%H:%M";
+
+/// Compiled macros, longest phrase first
+pub struct Macros {
+    entries: Vec<(Regex, String)>,
+}
+
+impl Macros {
+    /// Compile macro phrases. Matching is case-insensitive on word
+    /// boundaries, with any whitespace between words and optional
+    /// apostrophes ("today's" also matches "todays").
+    pub fn new(macros: &HashMap<String, String>) -> Self {
+        let mut phrases: Vec<(&String, &String)> = macros
+            .iter()
+            .filter(|(phrase, _)| !phrase.trim().is_empty())
+            .collect();
+        phrases.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then(a.0.cmp(b.0)));
+
+        let entries = phrases
+            .into_iter()
+            .filter_map(|(phrase, template)| {
+                let pattern = phrase_pattern(phrase);
+                match RegexBuilder::new(&pattern).case_insensitive(true).build() {
+                    Ok(re) => Some((re, template.clone())),
+                    Err(e) => {
+                        tracing::warn!("Ignoring [text.macros] phrase {:?}: {}", phrase, e);
+                        None
+                    }
+                }
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// Expand every macro phrase in `text` using the current time and
+    /// clipboard
+    pub fn apply(&self, text: &str) -> String {
+        self.apply_with(text, Local::now().naive_local(), &read_clipboard)
+    }
+
+    fn apply_with(&self, text: &str, now: NaiveDateTime, clipboard: &dyn Fn() -> String) -> String {
+        // Read the clipboard at most once per dictation
+        let clipboard_text = OnceCell::new();
+        let clipboard = || clipboard_text.get_or_init(clipboard).clone();
+
+        let mut result = text.to_string();
+        for (re, template) in &self.entries {
+            if re.is_match(&result) {
+                let expanded = expand_placeholders(template, now, &clipboard);
+                result = re.replace_all(&result, NoExpand(&expanded)).into_owned();
+            }
+        }
+        result
+    }
+}
+
+/// Case-insensitive word-boundary pattern for a spoken phrase
+pub(super) fn phrase_pattern(phrase: &str) -> String {
+    let words = phrase
+        .split_whitespace()
+        .map(|word| {
+            word.split(['\'', '’'])
+                .map(regex::escape)
+                .collect::<Vec<_>>()
+                .join("['’]?")
+        })
+        .collect::<Vec<_>>()
+        .join(r"\s+");
+    format!(r"\b{}\b", words)
+}
+
+/// Fill `{date}`, `{time}` and `{clipboard}` placeholders in `template`
+pub(super) fn expand_placeholders(
+    template: &str,
+    now: NaiveDateTime,
+    clipboard: &dyn Fn() -> String,
+) -> String {
+    static PLACEHOLDER_RE: OnceLock<Regex> = OnceLock::new();
+    let re = PLACEHOLDER_RE.get_or_init(|| {
+        Regex::new(r"\{(date|time|clipboard)(?::([^}]*))?\}").expect("valid placeholder regex")
+    });
+
+    re.replace_all(template, |caps: &Captures| match &caps[1] {
+        "date" => format_now(now, caps.get(2).map(|m| m.as_str()), DEFAULT_DATE_FORMAT),
+        "time" => format_now(now, caps.get(2).map(|m| m.as_str()), DEFAULT_TIME_FORMAT),
+        _ => clipboard(),
+    })
+    .into_owned()
+}
+
+/// Format `now`, falling back to `default` if the format is invalid
+fn format_now(now: NaiveDateTime, format: Option<&str>, default: &str) -> String {
+    let format = format.filter(|f| !f.is_empty()).unwrap_or(default);
+    let mut formatted = String::new();
+    if write!(formatted, "{}", now.format(format)).is_err() {
+        tracing::warn!("Invalid macro format {:?}, using {}", format, default);
+        return now.format(default).to_string();
+    }
+    formatted
+}
+
+/// Current clipboard text, or an empty string if it can't be read
+pub(super) fn read_clipboard() -> String {
+    let commands: &[(&str, &[&str])] = if cfg!(target_os = "macos") {
+        &[("pbpaste", &[])]
+    } else {
+        &[
+            ("wl-paste", &["--no-newline", "--type", "text"]),
+            ("xclip", &["-selection", "clipboard", "-o"]),
+        ]
+    };
+    for (command, args) in commands {
+        match std::process::Command::new(command).args(*args).output() {
+            Ok(output) if output.status.success() => {
+                return String::from_utf8_lossy(&output.stdout).into_owned();
+            }
+            Ok(output) => {
+                tracing::debug!(
+                    "{} failed: {}",
+                    command,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                );
+            }
+            Err(e) => tracing::debug!("{} unavailable: {}", command, e),
+        }
+    }
+    tracing::warn!("Could not read the clipboard for a {{clipboard}} placeholder");
+    String::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 3, 7)
+            .unwrap()
+            .and_hms_opt(14, 5, 0)
+            .unwrap()
+    }
+
+    fn macros(entries: &[(&str, &str)]) -> Macros {
+        Macros::new(
+            &entries
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_date_and_time_macros() {
+        let m = macros(&[
+            ("insert today's date", "{date}"),
+            ("insert long date", "{date:%B %-d, %Y}"),
+            ("insert time", "{time}"),
+        ]);
+        let clip = || String::new();
+        assert_eq!(
+            m.apply_with("due Insert todays date.", now(), &clip),
+            "due 2025-03-07."
+        );
+        assert_eq!(
+            m.apply_with("insert long date at insert time", now(), &clip),
+            "March 7, 2025 at 14:05"
+        );
+    }
+
+    #[test]
+    fn test_clipboard_macro_reads_once() {
+        let m = macros(&[("paste clipboard", "<{clipboard}>")]);
+        let reads = std::cell::Cell::new(0);
+        let clip = || {
+            reads.set(reads.get() + 1);
+            "copied $1 text".to_string()
+        };
+        assert_eq!(
+            m.apply_with("paste clipboard and paste clipboard", now(), &clip),
+            "<copied $1 text> and <copied $1 text>"
+        );
+        assert_eq!(reads.get(), 1);
+    }
+
+    #[test]
+    fn test_unknown_placeholder_and_bad_format() {
+        let clip = || String::new();
+        assert_eq!(
+            expand_placeholders("{name} {date:%Q}", now(), &clip),
+            "{name} 2025-03-07"
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Markdown dictation commands
+//!
+//! Spoken structure commands become markdown so notes and READMEs don't
+//! come out as one paragraph:
+//!
+//! | Say | Get |
+//! |-----|-----|
+//! | "bullet point" | `- ` on a new line |
+//! | "numbered item" | `1. ` on a new line |
+//! | "heading one" … "heading six" | `# ` … `###### ` after a blank line |
+//! | "block quote" | `> ` on a new line |
+//! | "code block" / "end code block" | a fenced code block |
+//!
+//! Punctuation the transcriber attaches to a command ("Bullet point, milk")
+//! is dropped along with the spaces around it.
+
+use regex::{Captures, Regex};
+use std::sync::OnceLock;
+
+/// Replace markdown commands in `text`
+pub fn apply_commands(text: &str) -> String {
+    static COMMAND_RE: OnceLock<Regex> = OnceLock::new();
+    let re = COMMAND_RE.get_or_init(|| {
+        Regex::new(
+            r"(?i)[ \t]*\b(end code block|code block|bullet point|numbered item|block quote|heading (one|two|three|four|five|six|[1-6]))\b[.,:;]?[ \t]*",
+        )
+        .expect("valid markdown command regex")
+    });
+
+    let result = re.replace_all(text, |caps: &Captures| {
+        if let Some(level) = caps.get(2) {
+            return format!("\n\n{} ", "#".repeat(heading_level(level.as_str())));
+        }
+        match caps[1].to_lowercase().as_str() {
+            "end code block" | "code block" => "\n```\n".to_string(),
+            "bullet point" => "\n- ".to_string(),
+            "numbered item" => "\n1. ".to_string(),
+            _ => "\n> ".to_string(),
+        }
+    });
+
+    // A command at the very start shouldn't begin with blank lines
+    result.trim_start_matches('\n').to_string()
+}
+
+fn heading_level(word: &str) -> usize {
+    match word.to_lowercase().as_str() {
+        "one" | "1" => 1,
+        "two" | "2" => 2,
+        "three" | "3" => 3,
+        "four" | "4" => 4,
+        "five" | "5" => 5,
+        _ => 6,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bullets_and_numbered_items() {
+        assert_eq!(
+            apply_commands("groceries bullet point milk bullet point eggs"),
+            "groceries\n- milk\n- eggs"
+        );
+        assert_eq!(
+            apply_commands("Numbered item, first. Numbered item second"),
+            "1. first.\n1. second"
+        );
+        // Only the whole command phrase
+        assert_eq!(
+            apply_commands("bullet points are nice"),
+            "bullet points are nice"
+        );
+    }
+
+    #[test]
+    fn test_headings_and_quotes() {
+        assert_eq!(
+            apply_commands("Heading two Install notes"),
+            "## Install notes"
+        );
+        assert_eq!(
+            apply_commands("Intro. Heading 3, setup block quote be careful"),
+            "Intro.\n\n### setup\n> be careful"
+        );
+    }
+
+    #[test]
+    fn test_code_block() {
+        assert_eq!(
+            apply_commands("run this code block cargo build end code block then test"),
+            "run this\n```\ncargo build\n```\nthen test"
+        );
+    }
+}
@@ -1,23 +1,194 @@
 //! Text processing module
 //!
 //! Provides post-transcription text transformations including:
-//! - Spoken punctuation conversion (e.g., "period" → ".")
-//! - Custom word replacements
-
-use crate::config::TextConfig;
-use regex::Regex;
+//! - Spoken punctuation conversion (e.g., "period" → "."), with phrases and
+//!   spacing for the configured locale
+//! - Sentence capitalization and spacing repair
+//! - Spoken numbers and dates to digits (e.g., "twenty three" → "23")
+//! - Profanity masking or removal
+//! - Custom word replacements, inline or from a hot-reloaded dictionary file
+//! - Emoji and symbol names (e.g., "thumbs up emoji" → "👍")
+//! - Regex rewrite rules with capture groups
+//! - Code dictation modes ("camel case user id" → "userId")
+//! - Markdown commands ("bullet point", "heading two", "code block")
+//! - Spoken macros for dates, times and the clipboard
+//! - Snippets typed in place of an exact spoken phrase
+//! - Spelling with the NATO alphabet ("spell alpha bravo seven" → "ab7")
+//! - Voice editing commands ("scratch that", "undo", "delete last word")
+//! - Case commands at the end of an utterance ("all caps", "lowercase that")
+
+pub mod code;
+pub mod dictionary;
+pub mod emoji;
+pub mod macros;
+pub mod markdown;
+pub mod numbers;
+pub mod profanity;
+pub mod punctuation;
+pub mod snippets;
+pub mod spelling;
+pub mod trailing;
+
+use crate::config::{ProfanityMode, TextConfig};
+use dictionary::ReplacementsFile;
+use emoji::EmojiMatcher;
+use macros::Macros;
+use profanity::ProfanityFilter;
+use regex::{Regex, RegexBuilder};
+use snippets::Snippets;
 use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Editing action for text typed by an earlier dictation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditCommand {
+    /// Remove the whole previous output ("scratch that", "undo")
+    DeleteLastOutput,
+    /// Remove the last word of the previous output ("delete last word")
+    DeleteLastWord,
+}
+
+impl EditCommand {
+    /// Number of backspaces needed to apply this command to `typed`, the
+    /// text the previous dictation left at the cursor
+    pub fn backspaces(self, typed: &str) -> usize {
+        match self {
+            EditCommand::DeleteLastOutput => typed.chars().count(),
+            // The word plus any whitespace typed after it (append_text)
+            EditCommand::DeleteLastWord => typed[last_word_start(typed)..].chars().count(),
+        }
+    }
+}
+
+/// Byte offset where the last whitespace-separated word of `text` starts
+fn last_word_start(text: &str) -> usize {
+    let trimmed = text.trim_end();
+    trimmed
+        .char_indices()
+        .rev()
+        .find(|(_, c)| c.is_whitespace())
+        .map_or(0, |(i, c)| i + c.len_utf8())
+}
+
+/// Abbreviations whose trailing period does not end a sentence
+const NON_TERMINAL_ABBREVIATIONS: &[&str] = &["e.g", "i.e", "vs", "etc", "approx", "cf"];
+
+/// Uppercase the first letter of the text and of every sentence after
+/// ".", "!", "?" or a line break. Quotes and brackets before the letter are
+/// skipped; a digit or symbol word ("3 apples", "$5") is left as is.
+fn capitalize_sentences(text: &str) -> String {
+    // Closing quotes and brackets that may follow a sentence terminator
+    const CLOSING: &str = "\"')]\u{201D}\u{2019}";
+    let mut result = String::with_capacity(text.len());
+    let mut capitalize_next = true;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if capitalize_next && c.is_alphabetic() {
+            result.extend(c.to_uppercase());
+            capitalize_next = false;
+            continue;
+        }
+        if capitalize_next && c.is_alphanumeric() {
+            capitalize_next = false;
+        }
+        result.push(c);
+
+        if c == '\n' {
+            capitalize_next = true;
+        } else if matches!(c, '.' | '!' | '?')
+            && chars
+                .peek()
+                .is_none_or(|next| next.is_whitespace() || CLOSING.contains(*next))
+        {
+            let word = result[..result.len() - 1]
+                .rsplit(char::is_whitespace)
+                .next()
+                .unwrap_or("")
+                .trim_start_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase();
+            capitalize_next = c != '.' || !NON_TERMINAL_ABBREVIATIONS.contains(&word.as_str());
+        }
+    }
+    result
+}
+
+/// Punctuation spacing conventions, selected by `[text] locale`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpacingRules {
+    /// No space before punctuation, straight quotes
+    English,
+    /// Narrow no-break space before "?", ";", ":" and "!", « » quotes
+    French,
+    /// German „…“ quotes
+    German,
+}
+
+impl SpacingRules {
+    /// Rules for a locale or language code ("fr", "fr-CA", "de_AT")
+    fn for_locale(locale: &str) -> Self {
+        match punctuation::language(locale).as_str() {
+            "fr" => SpacingRules::French,
+            "de" => SpacingRules::German,
+            _ => SpacingRules::English,
+        }
+    }
+}
 
 /// Text processor that applies transformations to transcribed text
 pub struct TextProcessor {
     /// Whether spoken punctuation is enabled
     spoken_punctuation: bool,
+    /// Spoken punctuation phrases for the locale, with `punctuation_map`
+    /// applied, longest first
+    punctuation: Vec<(String, String)>,
+    /// Spacing conventions for converted punctuation
+    spacing: SpacingRules,
+    /// Whether spoken number normalization is enabled
+    spoken_numbers: bool,
+    /// chrono format for dates found by spoken number normalization
+    date_format: String,
+    /// Profanity filter mode and matcher (`None` when the mode is off)
+    profanity: Option<(ProfanityMode, ProfanityFilter)>,
     /// Custom word replacements (lowercase key → replacement value)
     replacements: HashMap<String, String>,
+    /// Extra replacements from `replacements_file`, re-read when it changes.
+    /// Inline `replacements` take precedence on conflicting keys.
+    replacements_file: Option<ReplacementsFile>,
+    /// Emoji and symbol phrase matcher, `None` when `emoji` is off
+    emoji: Option<EmojiMatcher>,
+    /// Compiled `[[text.rules]]` in config order (pattern, replacement).
+    /// Rules whose pattern fails to compile are dropped with a warning.
+    rules: Vec<(Regex, String)>,
     /// Whether smart auto-submit is enabled
     smart_auto_submit: bool,
-    /// Pre-compiled regex for submit trigger detection
-    submit_re: Regex,
+    /// Whether sentence capitalization and spacing repair is enabled
+    auto_capitalize: bool,
+    /// Pre-compiled regex matching whitespace before a sentence terminator
+    space_before_term_re: Regex,
+    /// Pre-compiled regex matching a sentence terminator followed by
+    /// several spaces
+    space_after_term_re: Regex,
+    /// Pre-compiled regex matching "!" or "?" directly followed by a letter
+    missing_space_re: Regex,
+    /// Pre-compiled regex matching "word.Word" where a space was dropped
+    missing_space_period_re: Regex,
+    /// Pre-compiled regex matching the pronoun "i"
+    pronoun_i_re: Regex,
+    /// Whether code dictation modes ("camel case ...") are enabled
+    code_modes: bool,
+    /// Whether the "spell ..." command is enabled
+    spelling: bool,
+    /// Whether markdown commands ("bullet point", ...) are enabled
+    markdown_commands: bool,
+    /// `[text.macros]`, `None` when no macros are configured
+    macros: Option<Macros>,
+    /// `[text.snippets]`, `None` when no snippets are configured
+    snippets: Option<Snippets>,
+    /// Whether voice editing commands are enabled
+    editing_commands: bool,
+    /// Whether trailing case commands ("all caps") are enabled
+    case_commands: bool,
     /// Whether filler-word filtering is enabled
     filter_filler_words: bool,
     /// Pre-compiled regex matching any configured filler word.
@@ -43,6 +214,8 @@ pub struct TextProcessor {
 impl TextProcessor {
     /// Create a new text processor from configuration
     pub fn new(config: &TextConfig) -> Self {
+        let locale = config.locale.as_deref().unwrap_or("en");
+
         // Normalize replacement keys to lowercase for case-insensitive matching
         let replacements = config
             .replacements
@@ -50,10 +223,40 @@ impl TextProcessor {
             .map(|(k, v)| (k.to_lowercase(), v.clone()))
             .collect();
 
-        // Use (?:^|\s) instead of \b so that hyphenated forms like "pre-submit"
-        // do not trigger: a hyphen satisfies \b but not (?:^|\s).
-        let submit_re = Regex::new(r"(?i)(?:^|\s)submit[.!?,;]*\s*$")
-            .expect("BUG: submit regex is a compile-time constant and must be valid");
+        let replacements_file = config.replacements_file_path().map(ReplacementsFile::new);
+
+        let rules = config
+            .rules
+            .iter()
+            .filter_map(|rule| {
+                match RegexBuilder::new(&rule.pattern)
+                    .case_insensitive(!rule.case_sensitive)
+                    .build()
+                {
+                    Ok(re) => Some((re, rule.replacement.clone())),
+                    Err(e) => {
+                        tracing::warn!("Ignoring [[text.rules]] pattern {:?}: {}", rule.pattern, e);
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        let space_before_term_re = Regex::new(r"[ \t]+([.!?]+)(\s|$)").expect(
+            "BUG: space-before-terminator regex is a compile-time constant and must be valid",
+        );
+        let space_after_term_re = Regex::new(r"([.!?])[ \t]{2,}").expect(
+            "BUG: space-after-terminator regex is a compile-time constant and must be valid",
+        );
+        let missing_space_re = Regex::new(r"([!?])(\p{L})")
+            .expect("BUG: missing-space regex is a compile-time constant and must be valid");
+        // Only lowercase-word, period, capital: "done.Next" but not
+        // "example.com", "3.14" or "U.S.A"
+        let missing_space_period_re = Regex::new(r"(\p{Ll}{2})\.(\p{Lu})").expect(
+            "BUG: missing-space-after-period regex is a compile-time constant and must be valid",
+        );
+        let pronoun_i_re = Regex::new(r"\bi\b")
+            .expect("BUG: pronoun regex is a compile-time constant and must be valid");
 
         // Build a single alternation of all filler words. Word boundaries
         // (\b) ensure "um" is removed without touching "umbrella" or "summer".
@@ -88,9 +291,34 @@ impl TextProcessor {
 
         Self {
             spoken_punctuation: config.spoken_punctuation,
+            punctuation: punctuation::phrases(locale, &config.punctuation_map),
+            spacing: SpacingRules::for_locale(locale),
+            spoken_numbers: config.spoken_numbers,
+            date_format: config.date_format.clone(),
+            profanity: (config.profanity != ProfanityMode::Off).then(|| {
+                (
+                    config.profanity,
+                    ProfanityFilter::new(&config.profanity_words),
+                )
+            }),
             replacements,
+            replacements_file,
+            emoji: config.emoji.then(|| EmojiMatcher::new(&config.emoji_map)),
+            rules,
             smart_auto_submit: config.smart_auto_submit,
-            submit_re,
+            auto_capitalize: config.auto_capitalize,
+            space_before_term_re,
+            space_after_term_re,
+            missing_space_re,
+            missing_space_period_re,
+            pronoun_i_re,
+            code_modes: config.code_modes,
+            spelling: config.spelling,
+            markdown_commands: config.markdown_commands,
+            macros: (!config.macros.is_empty()).then(|| Macros::new(&config.macros)),
+            snippets: (!config.snippets.is_empty()).then(|| Snippets::new(&config.snippets)),
+            editing_commands: config.editing_commands,
+            case_commands: config.case_commands,
             filter_filler_words: config.filter_filler_words,
             filler_re,
             filler_space_re,
@@ -102,6 +330,24 @@ impl TextProcessor {
 
     /// Process text by applying all enabled transformations
     pub fn process(&self, text: &str) -> String {
+        // A trailing case command applies to everything said before it, so
+        // split it off the raw transcription and change the case last
+        if self.case_commands {
+            if let Some((before, command, submit)) = trailing::split_case_command(text) {
+                if !before.is_empty() {
+                    let mut result = command.apply(&self.transform(before));
+                    // Left for detect_submit
+                    if submit {
+                        result.push_str(" submit");
+                    }
+                    return result;
+                }
+            }
+        }
+        self.transform(text)
+    }
+
+    fn transform(&self, text: &str) -> String {
         let mut result = text.to_string();
 
         // Filter filler words first, on the raw transcription. Running before
@@ -111,21 +357,82 @@ impl TextProcessor {
             result = self.apply_filler_filter(&result);
         }
 
+        // A dictation that is exactly a snippet phrase ("um, my signature")
+        // becomes the snippet, typed verbatim
+        if let Some(expanded) = self.snippets.as_ref().and_then(|s| s.expand(&result)) {
+            return expanded;
+        }
+
+        // Also on the raw transcription, so replacements the user configured
+        // on purpose are never masked
+        if let Some((mode, filter)) = &self.profanity {
+            result = match mode {
+                ProfanityMode::Mask => filter.mask(&result),
+                ProfanityMode::Remove => self.clean_removed_words(&filter.blank(&result)),
+                ProfanityMode::Off => result,
+            };
+        }
+
         // Apply replacements first so phrases containing spoken punctuation words
         // (e.g. "slash pr" → "/pr") match before those words are converted to
         // punctuation characters.
-        if !self.replacements.is_empty() {
-            result = self.apply_replacements(&result);
+        let file_replacements = self.replacements_file.as_ref().map(|f| f.entries());
+        let has_replacements = !self.replacements.is_empty()
+            || file_replacements.as_ref().is_some_and(|f| !f.is_empty());
+        if has_replacements {
+            result = self.apply_replacements(&result, file_replacements.as_deref());
+        }
+
+        // Before spoken punctuation so names like "question emoji" or
+        // "plus one emoji" aren't converted to symbols first
+        if let Some(ref emoji) = self.emoji {
+            result = emoji.apply(&result);
         }
 
         if self.spoken_punctuation {
             result = self.apply_spoken_punctuation(&result);
         }
 
+        // After spoken punctuation, whose spacing cleanup would glue "## "
+        // to the heading, and before numbers so "heading two" is still words
+        if self.markdown_commands {
+            result = markdown::apply_commands(&result);
+        }
+
+        // After spoken punctuation so "twenty three period" ends up "23."
+        if self.spoken_numbers {
+            result = numbers::normalize(&result, &self.date_format);
+        }
+
         // Apply replacements again to catch patterns that only became matchable
         // after spoken punctuation conversion.
-        if !self.replacements.is_empty() {
-            result = self.apply_replacements(&result);
+        if has_replacements {
+            result = self.apply_replacements(&result, file_replacements.as_deref());
+        }
+
+        // Regex rules see the text after replacements, in config order
+        for (re, replacement) in &self.rules {
+            result = re.replace_all(&result, replacement.as_str()).into_owned();
+        }
+
+        // Last, so replacements and converted punctuation are in place
+        // before sentence boundaries are found
+        if self.auto_capitalize {
+            result = self.apply_auto_capitalize(&result);
+        }
+
+        // Spelling and code modes come after capitalization so their output
+        // keeps its exact case
+        if self.spelling {
+            result = spelling::apply_spell_command(&result);
+        }
+        if self.code_modes {
+            result = code::apply_mode_commands(&result);
+        }
+
+        // Very last, so dates and clipboard text are typed exactly as expanded
+        if let Some(ref macros) = self.macros {
+            result = macros.apply(&result);
         }
 
         result
@@ -144,21 +451,53 @@ impl TextProcessor {
             return (text.to_string(), false);
         }
 
-        // Match "submit" preceded by start-of-string or whitespace (not hyphens),
-        // optionally followed by punctuation. Leading whitespace in the match is
-        // consumed by replace(); trim_end() cleans any remaining trailing space.
-        if self.submit_re.is_match(text) {
-            // After stripping "submit", also remove trailing connector punctuation
-            // (commas, semicolons) that would otherwise dangle at end of text.
-            // Sentence-ending punctuation (. ! ?) is preserved.
-            let stripped = self
-                .submit_re
-                .replace(text, "")
-                .trim_end_matches(|c: char| c.is_whitespace() || c == ',' || c == ';')
-                .to_string();
-            (stripped, true)
+        // "submit" must follow start-of-string or whitespace, so hyphenated
+        // forms like "pre-submit" do not trigger. Connector punctuation
+        // (commas, semicolons) left dangling before it is removed, while
+        // sentence-ending punctuation (. ! ?) is preserved.
+        match trailing::split(text, trailing::SUBMIT) {
+            Some((stripped, _)) => (stripped.to_string(), true),
+            None => (text.to_string(), false),
+        }
+    }
+
+    /// Check if text ends with a voice editing command.
+    ///
+    /// Returns `(remaining_text, command)`. A command spoken after other
+    /// words edits those words: "hello world scratch that" yields no text
+    /// and "hello world delete last word" yields "hello". A command spoken
+    /// on its own yields empty text and the [`EditCommand`] for the caller
+    /// to apply to the previous output. Case-insensitive, and trailing
+    /// punctuation from spoken punctuation is ignored.
+    pub fn detect_edit_command(&self, text: &str) -> (String, Option<EditCommand>) {
+        if !self.editing_commands {
+            return (text.to_string(), None);
+        }
+        let Some((before, phrase)) = trailing::split(text, trailing::EDIT_COMMANDS) else {
+            return (text.to_string(), None);
+        };
+
+        let command = if phrase.starts_with("delete") {
+            EditCommand::DeleteLastWord
         } else {
-            (text.to_string(), false)
+            EditCommand::DeleteLastOutput
+        };
+
+        if before.is_empty() {
+            return (String::new(), Some(command));
+        }
+        // "undo" alone is too common at the end of a sentence to act on:
+        // "I want to undo" is dictation, not a command
+        if phrase == "undo" {
+            return (text.to_string(), None);
+        }
+        match command {
+            EditCommand::DeleteLastOutput => (String::new(), None),
+            EditCommand::DeleteLastWord => {
+                let kept = before[..last_word_start(before)]
+                    .trim_end_matches(|c: char| c.is_whitespace() || c == ',' || c == ';');
+                (kept.to_string(), None)
+            }
         }
     }
 
@@ -166,61 +505,14 @@ impl TextProcessor {
     fn apply_spoken_punctuation(&self, text: &str) -> String {
         let mut result = text.to_string();
 
-        // Order matters: longer phrases first to avoid partial matches
-        // Using word boundaries to avoid replacing parts of words
-        let punctuation_map: &[(&str, &str)] = &[
-            // Multi-word phrases first
-            ("question mark", "?"),
-            ("exclamation mark", "!"),
-            ("exclamation point", "!"),
-            ("open parenthesis", "("),
-            ("close parenthesis", ")"),
-            ("open paren", "("),
-            ("close paren", ")"),
-            ("open bracket", "["),
-            ("close bracket", "]"),
-            ("open brace", "{"),
-            ("close brace", "}"),
-            ("at sign", "@"),
-            ("at symbol", "@"),
-            ("dollar sign", "$"),
-            ("percent sign", "%"),
-            ("plus sign", "+"),
-            ("equals sign", "="),
-            ("forward slash", "/"),
-            ("single quote", "'"),
-            ("double quote", "\""),
-            ("new paragraph", "\n\n"),
-            ("new line", "\n"),
-            // Single words
-            ("period", "."),
-            ("comma", ","),
-            ("colon", ":"),
-            ("semicolon", ";"),
-            ("dash", "-"),
-            ("hyphen", "-"),
-            ("underscore", "_"),
-            ("hash", "#"),
-            ("hashtag", "#"),
-            ("percent", "%"),
-            ("ampersand", "&"),
-            ("asterisk", "*"),
-            ("plus", "+"),
-            ("equals", "="),
-            ("slash", "/"),
-            ("backslash", "\\"),
-            ("pipe", "|"),
-            ("tilde", "~"),
-            ("backtick", "`"),
-            ("tab", "\t"),
-        ];
-
-        for (phrase, symbol) in punctuation_map {
+        // Phrases are sorted longest first and matched on word boundaries,
+        // so "question mark" wins over a shorter phrase inside it
+        for (phrase, symbol) in &self.punctuation {
             result = replace_phrase_case_insensitive(&result, phrase, symbol);
         }
 
         // Clean up spacing around punctuation
-        result = clean_punctuation_spacing(&result);
+        result = clean_punctuation_spacing(&result, self.spacing);
 
         result
     }
@@ -238,7 +530,14 @@ impl TextProcessor {
 
         // Replace each filler with a single space so the input
         // "um, hello" becomes " , hello" and we can fold whitespace below.
-        let mut result = re.replace_all(text, " ").into_owned();
+        self.clean_removed_words(&re.replace_all(text, " "))
+    }
+
+    /// Tidy text after removed words were replaced with spaces: fold the
+    /// spaces and drop punctuation left dangling. Shared by the filler and
+    /// profanity filters.
+    fn clean_removed_words(&self, text: &str) -> String {
+        let mut result = text.to_string();
 
         // Collapse "<space><punct>" to "<punct>" so " , hello" -> ", hello".
         result = self.filler_punct_re.replace_all(&result, "$1").into_owned();
@@ -271,11 +570,52 @@ impl TextProcessor {
             .to_string()
     }
 
+    /// Repair spacing around sentence terminators, capitalize the pronoun
+    /// "i" and uppercase the first letter of each sentence
+    fn apply_auto_capitalize(&self, text: &str) -> String {
+        // "hello . world" -> "hello. world", "done.  Next" -> "done. Next"
+        let mut result = self
+            .space_before_term_re
+            .replace_all(text, "$1$2")
+            .into_owned();
+        result = self
+            .space_after_term_re
+            .replace_all(&result, "$1 ")
+            .into_owned();
+        result = self
+            .missing_space_re
+            .replace_all(&result, "$1 $2")
+            .into_owned();
+        result = self
+            .missing_space_period_re
+            .replace_all(&result, "$1. $2")
+            .into_owned();
+
+        // "i" -> "I", also in "i'm" and "i'll", but not in "i.e."
+        result = self
+            .pronoun_i_re
+            .replace_all(&result, |caps: &regex::Captures| {
+                let end = caps.get(0).map_or(0, |m| m.end());
+                if result[end..].starts_with('.') {
+                    "i".to_string()
+                } else {
+                    "I".to_string()
+                }
+            })
+            .into_owned();
+
+        capitalize_sentences(&result)
+    }
+
     /// Apply custom word replacements (case-insensitive)
-    fn apply_replacements(&self, text: &str) -> String {
+    fn apply_replacements(&self, text: &str, file: Option<&HashMap<String, String>>) -> String {
         let mut result = text.to_string();
 
-        for (word, replacement) in &self.replacements {
+        let file_entries = file
+            .into_iter()
+            .flatten()
+            .filter(|(word, _)| !self.replacements.contains_key(*word));
+        for (word, replacement) in self.replacements.iter().chain(file_entries) {
             result = replace_phrase_case_insensitive(&result, word, replacement);
         }
 
@@ -298,7 +638,7 @@ fn replace_phrase_case_insensitive(text: &str, from: &str, to: &str) -> String {
 }
 
 /// Clean up spacing around punctuation marks
-fn clean_punctuation_spacing(text: &str) -> String {
+fn clean_punctuation_spacing(text: &str, rules: SpacingRules) -> String {
     let mut result = text.to_string();
 
     // Remove space before punctuation that shouldn't have it
@@ -306,8 +646,8 @@ fn clean_punctuation_spacing(text: &str) -> String {
         result = result.replace(&format!(" {}", punct), &punct.to_string());
     }
 
-    // Remove space after opening brackets
-    for punct in ['(', '[', '{'] {
+    // Remove space after opening brackets and Spanish inverted marks
+    for punct in ['(', '[', '{', '¿', '¡'] {
         result = result.replace(&format!("{} ", punct), &punct.to_string());
     }
 
@@ -332,12 +672,44 @@ fn clean_punctuation_spacing(text: &str) -> String {
     result = result.replace(" \t", "\t");
     result = result.replace("\t ", "\t");
 
-    result
+    match rules {
+        SpacingRules::English => result,
+        SpacingRules::French => apply_french_spacing(&result),
+        SpacingRules::German => apply_german_quotes(&result),
+    }
+}
+
+/// Paired straight double quotes, with the spaces just inside them
+fn quote_pair_re() -> &'static Regex {
+    static QUOTE_PAIR_RE: OnceLock<Regex> = OnceLock::new();
+    QUOTE_PAIR_RE
+        .get_or_init(|| Regex::new(r#""[ \t]*([^"\n]*?)[ \t]*""#).expect("valid quote pair regex"))
+}
+
+/// "Quoi ?" and « citation »: a narrow no-break space before "?", ";", ":"
+/// and "!" and inside guillemets. A colon inside a word ("https://") is left
+/// alone.
+fn apply_french_spacing(text: &str) -> String {
+    static HIGH_PUNCT_RE: OnceLock<Regex> = OnceLock::new();
+    let high_punct_re = HIGH_PUNCT_RE.get_or_init(|| {
+        Regex::new(r"(\S)[ \t]*([?;:!]+)(\s|$)").expect("valid French punctuation regex")
+    });
+
+    let result = quote_pair_re().replace_all(text, "«\u{202F}$1\u{202F}»");
+    high_punct_re
+        .replace_all(&result, "$1\u{202F}$2$3")
+        .into_owned()
+}
+
+/// "Zitat" → „Zitat“
+fn apply_german_quotes(text: &str) -> String {
+    quote_pair_re().replace_all(text, "„$1“").into_owned()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::TextRule;
 
     fn make_config(spoken_punctuation: bool, replacements: &[(&str, &str)]) -> TextConfig {
         TextConfig {
@@ -388,6 +760,67 @@ mod tests {
         assert_eq!(processor.process("hello Period"), "hello.");
     }
 
+    #[test]
+    fn test_spoken_punctuation_locale_spacing() {
+        let mut config = make_config(true, &[]);
+        config.locale = Some("fr-CA".to_string());
+        let processor = TextProcessor::new(&config);
+        assert_eq!(
+            processor
+                .process("vraiment point d'interrogation il a dit deux points ouvrez les guillemets oui fermez les guillemets"),
+            "vraiment\u{202F}? il a dit\u{202F}: «\u{202F}oui\u{202F}»"
+        );
+        // Colons inside a word stay attached
+        assert_eq!(
+            processor.process("voir https://example.com point"),
+            "voir https://example.com."
+        );
+
+        config.locale = Some("de".to_string());
+        let processor = TextProcessor::new(&config);
+        assert_eq!(
+            processor.process("sie sagt Anführungszeichen ja Anführungszeichen Punkt"),
+            "sie sagt „ja“."
+        );
+
+        // Languages without built-ins use English phrases and spacing
+        config.locale = Some("it".to_string());
+        let processor = TextProcessor::new(&config);
+        assert_eq!(processor.process("what question mark"), "what?");
+    }
+
+    #[test]
+    fn test_spoken_punctuation_other_languages() {
+        let mut config = make_config(true, &[]);
+        config.locale = Some("de".to_string());
+        let processor = TextProcessor::new(&config);
+        assert_eq!(
+            processor.process("hallo Komma wie geht's Fragezeichen neue Zeile bis bald Punkt"),
+            "hallo, wie geht's?\nbis bald."
+        );
+
+        config.locale = Some("es".to_string());
+        let processor = TextProcessor::new(&config);
+        assert_eq!(
+            processor.process("abrir interrogación qué tal cerrar interrogación"),
+            "¿qué tal?"
+        );
+    }
+
+    #[test]
+    fn test_punctuation_map_overrides() {
+        let mut config = make_config(true, &[]);
+        config.punctuation_map = HashMap::from([
+            ("arrow".to_string(), "->".to_string()),
+            ("hash".to_string(), String::new()),
+        ]);
+        let processor = TextProcessor::new(&config);
+        assert_eq!(
+            processor.process("hash map arrow value period"),
+            "hash map -> value."
+        );
+    }
+
     #[test]
     fn test_word_replacements() {
         let config = make_config(false, &[("vox type", "voxtype")]);
@@ -514,6 +947,46 @@ mod tests {
         assert!(!submit);
     }
 
+    #[test]
+    fn test_case_commands() {
+        let config = TextConfig {
+            spoken_punctuation: true,
+            case_commands: true,
+            ..Default::default()
+        };
+        let processor = TextProcessor::new(&config);
+        assert_eq!(
+            processor.process("warning hot surface exclamation mark all caps."),
+            "WARNING HOT SURFACE!"
+        );
+        assert_eq!(
+            processor.process("Hello There, lowercase that"),
+            "hello there"
+        );
+        assert_eq!(
+            processor.process("the two towers title case that"),
+            "The Two Towers"
+        );
+        // The command alone is dictated as is
+        assert_eq!(processor.process("all caps"), "all caps");
+
+        // "submit" after the command is left for detect_submit
+        let processor = TextProcessor::new(&TextConfig {
+            smart_auto_submit: true,
+            ..config.clone()
+        });
+        let processed = processor.process("ship it all caps submit");
+        assert_eq!(processed, "SHIP IT submit");
+        assert_eq!(
+            processor.detect_submit(&processed, None),
+            ("SHIP IT".to_string(), true)
+        );
+
+        // Off by default
+        let processor = TextProcessor::new(&TextConfig::default());
+        assert_eq!(processor.process("ship it all caps"), "ship it all caps");
+    }
+
     #[test]
     fn test_pipeline_spoken_punctuation_then_detect_submit() {
         // Simulates the full daemon pipeline: user says "hello world comma submit"
@@ -807,4 +1280,369 @@ mod tests {
 
         assert_eq!(processor.process("well um I think period"), "well I think.");
     }
+
+    #[test]
+    fn test_pipeline_spoken_numbers_after_punctuation() {
+        let processor = TextProcessor::new(&TextConfig {
+            spoken_punctuation: true,
+            spoken_numbers: true,
+            date_format: "%d.%m.%Y".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(
+            processor.process("it costs twenty three point five period"),
+            "it costs 23.5."
+        );
+        assert_eq!(
+            processor.process("ship it march third twenty twenty five"),
+            "ship it 03.03.2025"
+        );
+        // Off by default
+        let processor = TextProcessor::new(&TextConfig::default());
+        assert_eq!(processor.process("twenty three"), "twenty three");
+    }
+
+    #[test]
+    fn test_replacements_file_merged_with_inline() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("dictionary.toml");
+        std::fs::write(
+            &path,
+            "\"vox type\" = \"VoxType\"\n\"oh marky\" = \"Omarchy\"\n",
+        )
+        .unwrap();
+
+        let mut config = make_config(false, &[("vox type", "voxtype")]);
+        config.replacements_file = Some(path.to_string_lossy().into_owned());
+        let processor = TextProcessor::new(&config);
+
+        // Inline entry wins over the file; file-only entries still apply
+        assert_eq!(
+            processor.process("vox type on oh marky"),
+            "voxtype on Omarchy"
+        );
+    }
+
+    fn rule(pattern: &str, replacement: &str, case_sensitive: bool) -> TextRule {
+        TextRule {
+            pattern: pattern.to_string(),
+            replacement: replacement.to_string(),
+            case_sensitive,
+        }
+    }
+
+    #[test]
+    fn test_rules_capture_groups() {
+        let processor = TextProcessor::new(&TextConfig {
+            rules: vec![
+                rule(r"\bticket (\d+)", "JIRA-$1", false),
+                rule(
+                    r"\b(?<area>\d{3})(?<exchange>\d{3})(?<line>\d{4})\b",
+                    "(${area}) ${exchange}-${line}",
+                    false,
+                ),
+            ],
+            ..Default::default()
+        });
+        assert_eq!(
+            processor.process("Ticket 4521 is about 5551234567"),
+            "JIRA-4521 is about (555) 123-4567"
+        );
+    }
+
+    #[test]
+    fn test_rules_case_sensitivity_and_order() {
+        let processor = TextProcessor::new(&TextConfig {
+            rules: vec![
+                rule("Rust", "rust-lang", true),
+                // Sees the output of the first rule
+                rule("rust-lang", "Rust (the language)", false),
+            ],
+            ..Default::default()
+        });
+        assert_eq!(
+            processor.process("Rust and rust"),
+            "Rust (the language) and rust"
+        );
+    }
+
+    #[test]
+    fn test_rules_run_after_replacements_and_skip_invalid() {
+        let processor = TextProcessor::new(&TextConfig {
+            replacements: HashMap::from([("pee are".to_string(), "PR".to_string())]),
+            rules: vec![
+                rule("(unclosed", "x", false),
+                rule(r"\bPR (\d+)", "PR #$1", true),
+            ],
+            ..Default::default()
+        });
+        assert_eq!(processor.process("see pee are 12"), "see PR #12");
+    }
+
+    #[test]
+    fn test_emoji_before_spoken_punctuation() {
+        let processor = TextProcessor::new(&TextConfig {
+            emoji: true,
+            spoken_punctuation: true,
+            emoji_map: HashMap::from([("ship it".to_string(), "🚢".to_string())]),
+            ..Default::default()
+        });
+        assert_eq!(
+            processor.process("plus one emoji comma ship it emoji"),
+            "👍, 🚢"
+        );
+
+        let disabled = make_config(true, &[]);
+        assert_eq!(
+            TextProcessor::new(&disabled).process("plus one emoji"),
+            "+ one emoji"
+        );
+    }
+
+    #[test]
+    fn test_spelling_after_other_processing() {
+        let processor = TextProcessor::new(&TextConfig {
+            spelling: true,
+            spoken_punctuation: true,
+            auto_capitalize: true,
+            ..Default::default()
+        });
+        assert_eq!(
+            processor.process("the code is spell alpha dash bravo seven period"),
+            "The code is a-b7."
+        );
+    }
+
+    #[test]
+    fn test_markdown_commands_with_punctuation() {
+        let processor = TextProcessor::new(&TextConfig {
+            markdown_commands: true,
+            spoken_punctuation: true,
+            auto_capitalize: true,
+            ..Default::default()
+        });
+        assert_eq!(
+            processor.process("heading two groceries bullet point milk bullet point eggs period"),
+            "## Groceries\n- Milk\n- Eggs."
+        );
+    }
+
+    #[test]
+    fn test_profanity_modes() {
+        let mask = TextProcessor::new(&TextConfig {
+            profanity: ProfanityMode::Mask,
+            ..Default::default()
+        });
+        assert_eq!(
+            mask.process("well shit, that broke"),
+            "well s***, that broke"
+        );
+
+        let remove = TextProcessor::new(&TextConfig {
+            profanity: ProfanityMode::Remove,
+            profanity_words: vec!["frak".to_string()],
+            ..Default::default()
+        });
+        assert_eq!(
+            remove.process("damn, that frak build failed"),
+            "that build failed"
+        );
+
+        let off = make_config(false, &[]);
+        assert_eq!(TextProcessor::new(&off).process("damn it"), "damn it");
+    }
+
+    #[test]
+    fn test_macros_expand_after_capitalization() {
+        let processor = TextProcessor::new(&TextConfig {
+            auto_capitalize: true,
+            macros: HashMap::from([("insert year".to_string(), "{date:year %Y}".to_string())]),
+            ..Default::default()
+        });
+        let year = chrono::Local::now().format("%Y").to_string();
+        assert_eq!(
+            processor.process("insert year. done"),
+            format!("year {}. Done", year)
+        );
+    }
+
+    #[test]
+    fn test_snippet_skips_other_processing() {
+        let processor = TextProcessor::new(&TextConfig {
+            auto_capitalize: true,
+            spoken_punctuation: true,
+            snippets: HashMap::from([(
+                "my signature".to_string(),
+                "best regards,\nPete".to_string(),
+            )]),
+            ..Default::default()
+        });
+        assert_eq!(
+            processor.process("Um, my signature."),
+            "best regards,\nPete"
+        );
+        // Only the whole dictation triggers a snippet
+        assert_eq!(
+            processor.process("add my signature period"),
+            "Add my signature."
+        );
+    }
+
+    #[test]
+    fn test_code_modes_after_auto_capitalize() {
+        let processor = TextProcessor::new(&TextConfig {
+            code_modes: true,
+            auto_capitalize: true,
+            ..Default::default()
+        });
+        assert_eq!(
+            processor.process("camel case user id. then snake case max retries"),
+            "userId. Then max_retries"
+        );
+
+        let disabled = make_config(false, &[]);
+        assert_eq!(
+            TextProcessor::new(&disabled).process("camel case user id"),
+            "camel case user id"
+        );
+    }
+
+    fn make_capitalize_processor() -> TextProcessor {
+        TextProcessor::new(&TextConfig {
+            auto_capitalize: true,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_auto_capitalize_sentences() {
+        let processor = make_capitalize_processor();
+        assert_eq!(
+            processor.process("hello world. this is a test! is it? yes"),
+            "Hello world. This is a test! Is it? Yes"
+        );
+        assert_eq!(
+            processor.process("first line\nsecond line"),
+            "First line\nSecond line"
+        );
+        assert_eq!(
+            processor.process("he said \"wow.\" \"really?\""),
+            "He said \"wow.\" \"Really?\""
+        );
+        // Numbers and abbreviations don't start a new sentence
+        assert_eq!(
+            processor.process("3 apples, e.g. red ones. pi is 3.14 etc. ok"),
+            "3 apples, e.g. red ones. Pi is 3.14 etc. ok"
+        );
+    }
+
+    #[test]
+    fn test_auto_capitalize_pronoun_i() {
+        let processor = make_capitalize_processor();
+        assert_eq!(
+            processor.process("so i think i'm right, i.e. correct"),
+            "So I think I'm right, i.e. correct"
+        );
+        // Only the standalone word
+        assert_eq!(processor.process("an idea in time"), "An idea in time");
+    }
+
+    #[test]
+    fn test_auto_capitalize_spacing() {
+        let processor = make_capitalize_processor();
+        assert_eq!(
+            processor.process("done .  next one!really?ok"),
+            "Done. Next one! Really? Ok"
+        );
+        assert_eq!(
+            processor.process("it was done.Next up"),
+            "It was done. Next up"
+        );
+        // Domains, decimals and initialisms keep their periods
+        assert_eq!(
+            processor.process("see example.com in the U.S.A now"),
+            "See example.com in the U.S.A now"
+        );
+    }
+
+    #[test]
+    fn test_auto_capitalize_off_by_default() {
+        let processor = TextProcessor::new(&TextConfig::default());
+        assert_eq!(processor.process("hello. i am here"), "hello. i am here");
+    }
+
+    fn make_edit_processor() -> TextProcessor {
+        TextProcessor::new(&TextConfig {
+            spoken_punctuation: true,
+            editing_commands: true,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_edit_commands_disabled_by_default() {
+        let processor = TextProcessor::new(&TextConfig::default());
+        assert_eq!(
+            processor.detect_edit_command("scratch that"),
+            ("scratch that".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn test_edit_command_alone_targets_previous_output() {
+        let processor = make_edit_processor();
+        for phrase in ["scratch that", "Undo.", "undo that", "Scratch that!"] {
+            assert_eq!(
+                processor.detect_edit_command(phrase),
+                (String::new(), Some(EditCommand::DeleteLastOutput)),
+                "{}",
+                phrase
+            );
+        }
+        assert_eq!(
+            processor.detect_edit_command("delete the last word"),
+            (String::new(), Some(EditCommand::DeleteLastWord))
+        );
+    }
+
+    #[test]
+    fn test_edit_command_after_text_edits_current_utterance() {
+        let processor = make_edit_processor();
+        assert_eq!(
+            processor.detect_edit_command("hello world, scratch that"),
+            (String::new(), None)
+        );
+        assert_eq!(
+            processor.detect_edit_command("send it to Bob, delete last word"),
+            ("send it to".to_string(), None)
+        );
+        // A bare "undo" only counts on its own
+        assert_eq!(
+            processor.detect_edit_command("I want to undo"),
+            ("I want to undo".to_string(), None)
+        );
+        // No match inside a longer word or mid-sentence
+        assert_eq!(
+            processor.detect_edit_command("scratch that itch"),
+            ("scratch that itch".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn test_pipeline_spoken_punctuation_then_edit_command() {
+        let processor = make_edit_processor();
+        let processed = processor.process("scratch that period");
+        assert_eq!(
+            processor.detect_edit_command(&processed),
+            (String::new(), Some(EditCommand::DeleteLastOutput))
+        );
+    }
+
+    #[test]
+    fn test_edit_command_backspaces() {
+        assert_eq!(EditCommand::DeleteLastOutput.backspaces("héllo world "), 12);
+        assert_eq!(EditCommand::DeleteLastWord.backspaces("héllo wörld "), 6);
+        assert_eq!(EditCommand::DeleteLastWord.backspaces("hello"), 5);
+        assert_eq!(EditCommand::DeleteLastWord.backspaces(""), 0);
+    }
 }
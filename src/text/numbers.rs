@@ -0,0 +1,501 @@
+//! Spoken number normalization
+//!
+//! Converts spelled-out numbers to digits: "twenty three point five" →
+//! "23.5", "nineteen eighty four" → "1984", and dates such as "march third
+//! twenty twenty five" → "2025-03-03" (format set by `[text] date_format`).
+//! Lone single-digit words ("one", "five") are left alone since they read
+//! better as words in prose; ordinals are only converted inside dates so
+//! "first of all" stays as it is.
+
+use chrono::NaiveDate;
+use regex::Regex;
+use std::fmt::Write;
+use std::sync::OnceLock;
+
+/// Date format used when `[text] date_format` is unset or invalid
+pub const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+const UNITS: &[&str] = &[
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: &[&str] = &[
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const ORDINAL_UNITS: &[&str] = &[
+    "",
+    "first",
+    "second",
+    "third",
+    "fourth",
+    "fifth",
+    "sixth",
+    "seventh",
+    "eighth",
+    "ninth",
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "thirteenth",
+    "fourteenth",
+    "fifteenth",
+    "sixteenth",
+    "seventeenth",
+    "eighteenth",
+    "nineteenth",
+];
+
+const MONTHS: &[&str] = &[
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// Month names that are also common verbs ("I may second that", "march
+/// first to the door"), taken as months only when capitalized or followed
+/// by a year
+const VERB_MONTHS: &[&str] = &["march", "may"];
+
+/// A whitespace-separated word of the input, split into surrounding
+/// punctuation and the lowercased word itself
+struct Token<'a> {
+    lead: &'a str,
+    word: String,
+    trail: &'a str,
+    /// Whether the word was written with a leading capital
+    capitalized: bool,
+}
+
+impl<'a> Token<'a> {
+    fn new(raw: &'a str) -> Self {
+        let start = raw.find(|c: char| c.is_alphanumeric()).unwrap_or(raw.len());
+        let end = raw.rfind(|c: char| c.is_alphanumeric()).map_or(start, |i| {
+            i + raw[i..].chars().next().map_or(1, char::len_utf8)
+        });
+        Self {
+            lead: &raw[..start],
+            word: raw[start..end].to_lowercase(),
+            trail: &raw[end..],
+            capitalized: raw[start..].starts_with(char::is_uppercase),
+        }
+    }
+}
+
+/// Convert spelled-out numbers and dates in `text` to digits
+pub fn normalize(text: &str, date_format: &str) -> String {
+    let text = split_hyphenated(text);
+    let spans: Vec<(usize, usize)> = word_re()
+        .find_iter(&text)
+        .map(|m| (m.start(), m.end()))
+        .collect();
+    let tokens: Vec<Token> = spans
+        .iter()
+        .map(|&(s, e)| Token::new(&text[s..e]))
+        .collect();
+
+    let mut result = String::with_capacity(text.len());
+    let mut copied = 0;
+    let mut i = 0;
+    while i < tokens.len() {
+        let parsed = parse_date(&tokens, i, date_format).or_else(|| parse_number(&tokens, i));
+        match parsed {
+            Some((end, replacement)) => {
+                result.push_str(&text[copied..spans[i].0]);
+                result.push_str(tokens[i].lead);
+                result.push_str(&replacement);
+                result.push_str(tokens[end - 1].trail);
+                copied = spans[end - 1].1;
+                i = end;
+            }
+            None => i += 1,
+        }
+    }
+    result.push_str(&text[copied..]);
+    result
+}
+
+fn word_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"\S+").expect("BUG: word regex is a compile-time constant and must be valid")
+    })
+}
+
+/// "twenty-three" → "twenty three", "thirty-first" → "thirty first"
+fn split_hyphenated(text: &str) -> String {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let re = RE.get_or_init(|| {
+        Regex::new(
+            r"(?i)\b(twenty|thirty|forty|fifty|sixty|seventy|eighty|ninety)-(one|two|three|four|five|six|seven|eight|nine|first|second|third|fourth|fifth|sixth|seventh|eighth|ninth)\b",
+        )
+        .expect("BUG: hyphenated number regex is a compile-time constant and must be valid")
+    });
+    re.replace_all(text, "$1 $2").into_owned()
+}
+
+/// Whether `tokens[i]` can continue a multi-word number: no punctuation
+/// may separate it from the previous word
+fn joins(tokens: &[Token], i: usize) -> bool {
+    i < tokens.len() && tokens[i].lead.is_empty() && tokens[i - 1].trail.is_empty()
+}
+
+fn unit_value(word: &str) -> Option<u64> {
+    UNITS.iter().position(|u| *u == word).map(|v| v as u64)
+}
+
+fn tens_value(word: &str) -> Option<u64> {
+    TENS.iter()
+        .position(|t| !t.is_empty() && *t == word)
+        .map(|v| v as u64 * 10)
+}
+
+fn scale_value(word: &str) -> Option<u64> {
+    match word {
+        "thousand" => Some(1_000),
+        "million" => Some(1_000_000),
+        "billion" => Some(1_000_000_000),
+        _ => None,
+    }
+}
+
+fn is_number_word(word: &str) -> bool {
+    unit_value(word).is_some()
+        || tens_value(word).is_some()
+        || scale_value(word).is_some()
+        || word == "hundred"
+}
+
+/// A cardinal number starting at `tokens[i]`: (value, index after it)
+fn parse_cardinal(tokens: &[Token], i: usize) -> Option<(u64, usize)> {
+    let mut total = 0u64;
+    let mut current = 0u64;
+    let mut last_scale = u64::MAX;
+    // What the previous word was, to reject sequences like "five six"
+    #[derive(PartialEq)]
+    enum Last {
+        None,
+        Unit,
+        Teen,
+        Tens,
+        Hundred,
+        Scale,
+    }
+    let mut last = Last::None;
+    let mut j = i;
+
+    while j < tokens.len() && (j == i || joins(tokens, j)) {
+        let word = tokens[j].word.as_str();
+        if let Some(v) = unit_value(word) {
+            let ok = match last {
+                Last::None | Last::Hundred | Last::Scale => true,
+                Last::Tens => v < 10 && v > 0,
+                _ => false,
+            };
+            // "zero" only stands on its own
+            if !ok || (v == 0 && last != Last::None) {
+                break;
+            }
+            current += v;
+            last = if v < 10 { Last::Unit } else { Last::Teen };
+        } else if let Some(v) = tens_value(word) {
+            if !matches!(last, Last::None | Last::Hundred | Last::Scale) {
+                break;
+            }
+            current += v;
+            last = Last::Tens;
+        } else if word == "hundred" {
+            if !matches!(last, Last::Unit | Last::Teen | Last::Tens) || current >= 100 {
+                break;
+            }
+            current *= 100;
+            last = Last::Hundred;
+        } else if let Some(scale) = scale_value(word) {
+            if last == Last::None || last == Last::Scale || scale >= last_scale || current == 0 {
+                break;
+            }
+            total += current * scale;
+            current = 0;
+            last_scale = scale;
+            last = Last::Scale;
+        } else if word == "and"
+            && matches!(last, Last::Hundred | Last::Scale)
+            && joins(tokens, j + 1)
+            && (unit_value(&tokens[j + 1].word).is_some_and(|v| v > 0)
+                || tens_value(&tokens[j + 1].word).is_some())
+        {
+            // "one hundred and five"
+        } else {
+            break;
+        }
+        j += 1;
+    }
+
+    (j > i).then_some((total + current, j))
+}
+
+/// A two-digit group of a spoken year: "eighty four", "twenty", "oh five"
+fn parse_year_half(tokens: &[Token], i: usize) -> Option<(u64, usize)> {
+    let word = tokens.get(i)?.word.as_str();
+    if word == "oh" && joins(tokens, i + 1) {
+        let v = unit_value(&tokens[i + 1].word).filter(|v| (1..10).contains(v))?;
+        return Some((v, i + 2));
+    }
+    let (value, end) = parse_cardinal(tokens, i)?;
+    let words = &tokens[i..end];
+    let plain = words
+        .iter()
+        .all(|t| scale_value(&t.word).is_none() && t.word != "hundred");
+    (plain && (10..100).contains(&value)).then_some((value, end))
+}
+
+/// A spoken year: "nineteen eighty four", "twenty oh five", "two thousand
+/// twenty five". Pairs starting with ten to twelve are clock times ("at
+/// twelve fifteen"), not years.
+fn parse_year(tokens: &[Token], i: usize) -> Option<(u64, usize)> {
+    if let Some((high, mid)) = parse_year_half(tokens, i) {
+        if high > 12 && joins(tokens, mid) {
+            if let Some((low, end)) = parse_year_half(tokens, mid) {
+                return Some((high * 100 + low, end));
+            }
+        }
+    }
+    parse_cardinal(tokens, i).filter(|(v, _)| (1000..10000).contains(v))
+}
+
+/// A number with an optional decimal part: "twenty three point five"
+fn parse_number(tokens: &[Token], i: usize) -> Option<(usize, String)> {
+    if !is_number_word(&tokens[i].word) {
+        return None;
+    }
+    let (value, end) = parse_year(tokens, i).or_else(|| parse_cardinal(tokens, i))?;
+
+    let mut digits = String::new();
+    let mut j = end;
+    if joins(tokens, j) && tokens[j].word == "point" {
+        let mut k = j + 1;
+        while joins(tokens, k) {
+            match tokens[k].word.as_str() {
+                "oh" => digits.push('0'),
+                word => match unit_value(word).filter(|v| *v < 10) {
+                    Some(v) => digits.push(char::from(b'0' + v as u8)),
+                    None => break,
+                },
+            }
+            k += 1;
+        }
+        if !digits.is_empty() {
+            j = k;
+        }
+    }
+
+    // A lone "one" or "five" reads better as a word
+    if j - i == 1 && value < 10 {
+        return None;
+    }
+    Some(if digits.is_empty() {
+        (j, value.to_string())
+    } else {
+        (j, format!("{}.{}", value, digits))
+    })
+}
+
+/// A day of the month spoken as an ordinal: "third", "twenty first"
+fn parse_day(tokens: &[Token], i: usize) -> Option<(u32, usize)> {
+    let word = tokens.get(i)?.word.as_str();
+    if let Some(v) = ORDINAL_UNITS
+        .iter()
+        .position(|o| !o.is_empty() && *o == word)
+    {
+        return Some((v as u32, i + 1));
+    }
+    match word {
+        "twentieth" => return Some((20, i + 1)),
+        "thirtieth" => return Some((30, i + 1)),
+        _ => {}
+    }
+    let tens = tens_value(word).filter(|v| *v == 20 || *v == 30)?;
+    if !joins(tokens, i + 1) {
+        return None;
+    }
+    let unit = ORDINAL_UNITS[1..10]
+        .iter()
+        .position(|o| *o == tokens[i + 1].word)?
+        + 1;
+    Some((tens as u32 + unit as u32, i + 2))
+}
+
+/// "march third" → "march 3", "march third twenty twenty five" → a date
+/// in `date_format`
+fn parse_date(tokens: &[Token], i: usize, date_format: &str) -> Option<(usize, String)> {
+    let month = MONTHS.iter().position(|m| *m == tokens[i].word)? as u32 + 1;
+    if !joins(tokens, i + 1) {
+        return None;
+    }
+    let (day, end) = parse_day(tokens, i + 1)?;
+
+    // The year may follow a comma: "march third, twenty twenty five"
+    let year_start = end < tokens.len()
+        && tokens[end].lead.is_empty()
+        && matches!(tokens[end - 1].trail, "" | ",");
+    if year_start {
+        if let Some((year, year_end)) = parse_year(tokens, end) {
+            if let Some(date) = NaiveDate::from_ymd_opt(year as i32, month, day) {
+                return Some((year_end, format_date(date, date_format)));
+            }
+        }
+    }
+
+    if VERB_MONTHS.contains(&tokens[i].word.as_str()) && !tokens[i].capitalized {
+        return None;
+    }
+    Some((end, format!("{} {}", capitalize(&tokens[i].word), day)))
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Format `date` with a chrono format string, falling back to ISO 8601 if
+/// the format is invalid
+fn format_date(date: NaiveDate, date_format: &str) -> String {
+    let mut formatted = String::new();
+    if write!(formatted, "{}", date.format(date_format)).is_err() {
+        tracing::warn!(
+            "Invalid [text] date_format {:?}, using {}",
+            date_format,
+            DEFAULT_DATE_FORMAT
+        );
+        return date.format(DEFAULT_DATE_FORMAT).to_string();
+    }
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn norm(text: &str) -> String {
+        normalize(text, DEFAULT_DATE_FORMAT)
+    }
+
+    #[test]
+    fn test_cardinals() {
+        assert_eq!(norm("twenty three apples"), "23 apples");
+        assert_eq!(norm("one hundred and five"), "105");
+        assert_eq!(norm("two thousand four hundred sixty one"), "2461");
+        assert_eq!(norm("three million two hundred thousand"), "3200000");
+        assert_eq!(norm("twenty-three"), "23");
+        assert_eq!(norm("fifteen"), "15");
+    }
+
+    #[test]
+    fn test_lone_small_numbers_stay_words() {
+        assert_eq!(norm("one of the five options"), "one of the five options");
+        assert_eq!(norm("first of all"), "first of all");
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_eq!(norm("twenty three point five"), "23.5");
+        assert_eq!(norm("three point one four"), "3.14");
+        assert_eq!(norm("zero point oh five"), "0.05");
+        // "point" not followed by digits is just a word
+        assert_eq!(norm("twenty point of view"), "20 point of view");
+    }
+
+    #[test]
+    fn test_years() {
+        assert_eq!(norm("in nineteen eighty four"), "in 1984");
+        assert_eq!(norm("twenty oh five"), "2005");
+        assert_eq!(norm("twenty twenty five"), "2025");
+        assert_eq!(norm("two thousand twenty five"), "2025");
+        // Clock times aren't years
+        assert_eq!(norm("at twelve fifteen"), "at 12 15");
+        assert_eq!(norm("ten thirty"), "10 30");
+    }
+
+    #[test]
+    fn test_punctuation_is_kept_and_splits_numbers() {
+        assert_eq!(norm("I counted twenty three."), "I counted 23.");
+        assert_eq!(norm("twenty, thirty"), "20, 30");
+        assert_eq!(norm("(forty two)"), "(42)");
+        assert_eq!(norm("Twenty three"), "23");
+    }
+
+    #[test]
+    fn test_dates() {
+        assert_eq!(norm("march third twenty twenty five"), "2025-03-03");
+        assert_eq!(
+            norm("due March thirty first, twenty twenty five."),
+            "due 2025-03-31."
+        );
+        assert_eq!(norm("on May fifth"), "on May 5");
+        assert_eq!(norm("june second"), "June 2");
+        // Lowercase "may" and "march" need a year to be a month
+        assert_eq!(norm("on may fifth twenty twenty five"), "on 2025-05-05");
+        // Not a real date: the numbers are still converted
+        assert_eq!(
+            norm("february thirtieth twenty twenty five"),
+            "February 30 2025"
+        );
+    }
+
+    #[test]
+    fn test_date_format() {
+        assert_eq!(
+            normalize("march third twenty twenty five", "%d/%m/%Y"),
+            "03/03/2025"
+        );
+        assert_eq!(
+            normalize("march third twenty twenty five", "%B %-d, %Y"),
+            "March 3, 2025"
+        );
+        // Invalid formats fall back to ISO 8601
+        assert_eq!(
+            normalize("march third twenty twenty five", "%Q"),
+            "2025-03-03"
+        );
+    }
+
+    #[test]
+    fn test_non_numbers_untouched() {
+        let text = "  Someone   said hello, world!  ";
+        assert_eq!(norm(text), text);
+        assert_eq!(norm("may I ask"), "may I ask");
+        assert_eq!(norm("I may second that"), "I may second that");
+        assert_eq!(norm("march first to the door"), "march first to the door");
+    }
+}
@@ -0,0 +1,149 @@
+//! Profanity filter (`[text] profanity`)
+//!
+//! Matches a bundled list of common English profanity, plus any words from
+//! `profanity_words`, case-insensitively on word boundaries. Matches are
+//! either masked ("shit" → "s***") or removed, with the same spacing and
+//! punctuation cleanup used for filler words.
+
+use regex::{Captures, Regex};
+
+/// Bundled wordlist. Inflected forms are listed explicitly so matching can
+/// stay on whole words and leave "class" or "scrapbook" alone.
+const WORDS: &[&str] = &[
+    "fuck",
+    "fucks",
+    "fucked",
+    "fucker",
+    "fuckers",
+    "fucking",
+    "fuckin",
+    "motherfucker",
+    "motherfuckers",
+    "motherfucking",
+    "shit",
+    "shits",
+    "shitty",
+    "shitting",
+    "bullshit",
+    "horseshit",
+    "ass",
+    "asses",
+    "asshole",
+    "assholes",
+    "arse",
+    "arsehole",
+    "jackass",
+    "bitch",
+    "bitches",
+    "bitching",
+    "bastard",
+    "bastards",
+    "damn",
+    "damned",
+    "dammit",
+    "goddamn",
+    "goddammit",
+    "crap",
+    "crappy",
+    "dick",
+    "dicks",
+    "dickhead",
+    "piss",
+    "pissed",
+    "pissing",
+    "cunt",
+    "cunts",
+    "cock",
+    "cocks",
+    "prick",
+    "pricks",
+    "twat",
+    "wanker",
+    "bollocks",
+];
+
+/// Compiled profanity matcher
+pub struct ProfanityFilter {
+    re: Regex,
+}
+
+impl ProfanityFilter {
+    /// Build a filter from the bundled list plus `extra` words
+    pub fn new(extra: &[String]) -> Self {
+        let mut words: Vec<String> = WORDS
+            .iter()
+            .map(|w| w.to_string())
+            .chain(
+                extra
+                    .iter()
+                    .map(|w| w.trim().to_lowercase())
+                    .filter(|w| !w.is_empty()),
+            )
+            .collect();
+        // Longest first so multi-word extensions win over their parts
+        words.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+        words.dedup();
+
+        let alternation = words
+            .iter()
+            .map(|w| regex::escape(w))
+            .collect::<Vec<_>>()
+            .join("|");
+        let re = Regex::new(&format!(r"(?i)\b(?:{})\b", alternation))
+            .expect("profanity words are escaped");
+        Self { re }
+    }
+
+    /// Keep the first letter of each match and star out the rest
+    pub fn mask(&self, text: &str) -> String {
+        self.re
+            .replace_all(text, |caps: &Captures| mask_word(&caps[0]))
+            .into_owned()
+    }
+
+    /// Replace each match with a single space, leaving cleanup to the caller
+    pub fn blank(&self, text: &str) -> String {
+        self.re.replace_all(text, " ").into_owned()
+    }
+}
+
+fn mask_word(word: &str) -> String {
+    let mut seen_first = false;
+    word.chars()
+        .map(|c| {
+            if !c.is_alphanumeric() {
+                c
+            } else if seen_first {
+                '*'
+            } else {
+                seen_first = true;
+                c
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask() {
+        let filter = ProfanityFilter::new(&[]);
+        assert_eq!(filter.mask("this is Shit, really"), "this is S***, really");
+        assert_eq!(
+            filter.mask("what the fucking hell"),
+            "what the f****** hell"
+        );
+        // Whole words only
+        assert_eq!(filter.mask("a class assessment"), "a class assessment");
+    }
+
+    #[test]
+    fn test_user_words() {
+        let filter = ProfanityFilter::new(&["Frak".to_string(), "holy cow".to_string()]);
+        assert_eq!(filter.mask("frak me"), "f*** me");
+        assert_eq!(filter.mask("holy cow"), "h*** ***");
+        assert_eq!(filter.blank("oh frak"), "oh  ");
+    }
+}
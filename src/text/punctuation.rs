@@ -0,0 +1,246 @@
+//! Spoken punctuation phrase tables
+//!
+//! Built-in phrases exist for English, German, French and Spanish; the
+//! table is picked by the language part of `[text] locale` and falls back
+//! to English. `[text.punctuation_map]` adds or overrides entries, and an
+//! empty value removes a built-in phrase:
+//!
+//! ```toml
+//! [text.punctuation_map]
+//! "arrow" = "->"
+//! "hash" = ""       # keep "hash" as a word
+//! ```
+
+use std::collections::HashMap;
+
+const ENGLISH: &[(&str, &str)] = &[
+    ("question mark", "?"),
+    ("exclamation mark", "!"),
+    ("exclamation point", "!"),
+    ("open parenthesis", "("),
+    ("close parenthesis", ")"),
+    ("open paren", "("),
+    ("close paren", ")"),
+    ("open bracket", "["),
+    ("close bracket", "]"),
+    ("open brace", "{"),
+    ("close brace", "}"),
+    ("at sign", "@"),
+    ("at symbol", "@"),
+    ("dollar sign", "$"),
+    ("percent sign", "%"),
+    ("plus sign", "+"),
+    ("equals sign", "="),
+    ("forward slash", "/"),
+    ("single quote", "'"),
+    ("double quote", "\""),
+    ("new paragraph", "\n\n"),
+    ("new line", "\n"),
+    ("period", "."),
+    ("comma", ","),
+    ("colon", ":"),
+    ("semicolon", ";"),
+    ("dash", "-"),
+    ("hyphen", "-"),
+    ("underscore", "_"),
+    ("hash", "#"),
+    ("hashtag", "#"),
+    ("percent", "%"),
+    ("ampersand", "&"),
+    ("asterisk", "*"),
+    ("plus", "+"),
+    ("equals", "="),
+    ("slash", "/"),
+    ("backslash", "\\"),
+    ("pipe", "|"),
+    ("tilde", "~"),
+    ("backtick", "`"),
+    ("tab", "\t"),
+];
+
+const GERMAN: &[(&str, &str)] = &[
+    ("Fragezeichen", "?"),
+    ("Ausrufezeichen", "!"),
+    ("Punkt", "."),
+    ("Komma", ","),
+    ("Doppelpunkt", ":"),
+    ("Semikolon", ";"),
+    ("Strichpunkt", ";"),
+    ("Klammer auf", "("),
+    ("Klammer zu", ")"),
+    ("eckige Klammer auf", "["),
+    ("eckige Klammer zu", "]"),
+    ("geschweifte Klammer auf", "{"),
+    ("geschweifte Klammer zu", "}"),
+    ("Anführungszeichen", "\""),
+    ("Apostroph", "'"),
+    ("Bindestrich", "-"),
+    ("Unterstrich", "_"),
+    ("Schrägstrich", "/"),
+    ("Backslash", "\\"),
+    ("At-Zeichen", "@"),
+    ("Dollarzeichen", "$"),
+    ("Prozentzeichen", "%"),
+    ("Rautezeichen", "#"),
+    ("Sternchen", "*"),
+    ("Pluszeichen", "+"),
+    ("Gleichheitszeichen", "="),
+    ("neue Zeile", "\n"),
+    ("neuer Absatz", "\n\n"),
+    ("Tabulator", "\t"),
+];
+
+const FRENCH: &[(&str, &str)] = &[
+    ("point d'interrogation", "?"),
+    ("point d'exclamation", "!"),
+    ("points de suspension", "..."),
+    ("point-virgule", ";"),
+    ("point virgule", ";"),
+    ("deux points", ":"),
+    ("point", "."),
+    ("virgule", ","),
+    ("ouvrez la parenthèse", "("),
+    ("fermez la parenthèse", ")"),
+    ("ouvrir la parenthèse", "("),
+    ("fermer la parenthèse", ")"),
+    ("ouvrez les guillemets", "\""),
+    ("fermez les guillemets", "\""),
+    ("ouvrir les guillemets", "\""),
+    ("fermer les guillemets", "\""),
+    ("apostrophe", "'"),
+    ("trait d'union", "-"),
+    ("tiret bas", "_"),
+    ("tiret", "-"),
+    ("barre oblique", "/"),
+    ("arobase", "@"),
+    ("dièse", "#"),
+    ("astérisque", "*"),
+    ("signe plus", "+"),
+    ("signe égal", "="),
+    ("à la ligne", "\n"),
+    ("nouvelle ligne", "\n"),
+    ("nouveau paragraphe", "\n\n"),
+    ("tabulation", "\t"),
+];
+
+const SPANISH: &[(&str, &str)] = &[
+    ("abrir interrogación", "¿"),
+    ("cerrar interrogación", "?"),
+    ("signo de interrogación", "?"),
+    ("abrir exclamación", "¡"),
+    ("cerrar exclamación", "!"),
+    ("signo de exclamación", "!"),
+    ("punto y coma", ";"),
+    ("punto y aparte", ".\n\n"),
+    ("punto y seguido", "."),
+    ("punto", "."),
+    ("coma", ","),
+    ("dos puntos", ":"),
+    ("abrir paréntesis", "("),
+    ("cerrar paréntesis", ")"),
+    ("comillas", "\""),
+    ("guion bajo", "_"),
+    ("guion", "-"),
+    ("barra", "/"),
+    ("arroba", "@"),
+    ("almohadilla", "#"),
+    ("asterisco", "*"),
+    ("nueva línea", "\n"),
+    ("nuevo párrafo", "\n\n"),
+];
+
+/// Language part of a locale: "fr-CA" and "fr_CA" → "fr"
+pub(super) fn language(locale: &str) -> String {
+    locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+/// Built-in phrases for the language part of `locale` ("de-AT" → German)
+fn builtin(locale: &str) -> &'static [(&'static str, &'static str)] {
+    match language(locale).as_str() {
+        "de" => GERMAN,
+        "fr" => FRENCH,
+        "es" => SPANISH,
+        _ => ENGLISH,
+    }
+}
+
+/// Phrase table for `locale` with `overrides` applied, longest phrase first
+/// so "question mark" is replaced before a shorter phrase could match part
+/// of it
+pub fn phrases(locale: &str, overrides: &HashMap<String, String>) -> Vec<(String, String)> {
+    let mut table: Vec<(String, String)> = builtin(locale)
+        .iter()
+        .filter(|(phrase, _)| {
+            let phrase = phrase.to_lowercase();
+            !overrides.keys().any(|k| k.trim().to_lowercase() == phrase)
+        })
+        .map(|(phrase, symbol)| (phrase.to_string(), symbol.to_string()))
+        .collect();
+    table.extend(
+        overrides
+            .iter()
+            .filter(|(phrase, symbol)| !phrase.trim().is_empty() && !symbol.is_empty())
+            .map(|(phrase, symbol)| (phrase.trim().to_string(), symbol.clone())),
+    );
+    table.sort_by(|a, b| {
+        b.0.chars()
+            .count()
+            .cmp(&a.0.chars().count())
+            .then_with(|| a.0.cmp(&b.0))
+    });
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol_for<'a>(table: &'a [(String, String)], phrase: &str) -> Option<&'a str> {
+        table
+            .iter()
+            .find(|(p, _)| p == phrase)
+            .map(|(_, s)| s.as_str())
+    }
+
+    #[test]
+    fn test_builtin_by_language() {
+        let none = HashMap::new();
+        assert_eq!(symbol_for(&phrases("en", &none), "period"), Some("."));
+        assert_eq!(symbol_for(&phrases("de-AT", &none), "Punkt"), Some("."));
+        assert_eq!(symbol_for(&phrases("fr_CA", &none), "virgule"), Some(","));
+        assert_eq!(
+            symbol_for(&phrases("es", &none), "abrir interrogación"),
+            Some("¿")
+        );
+        // Unknown languages and "auto" fall back to English
+        assert_eq!(symbol_for(&phrases("auto", &none), "comma"), Some(","));
+        assert_eq!(symbol_for(&phrases("de", &none), "comma"), None);
+    }
+
+    #[test]
+    fn test_overrides_add_replace_and_remove() {
+        let overrides = HashMap::from([
+            ("arrow".to_string(), "->".to_string()),
+            ("Hash".to_string(), String::new()),
+            ("dash".to_string(), "–".to_string()),
+        ]);
+        let table = phrases("en", &overrides);
+        assert_eq!(symbol_for(&table, "arrow"), Some("->"));
+        assert_eq!(symbol_for(&table, "dash"), Some("–"));
+        assert_eq!(symbol_for(&table, "hash"), None);
+        assert_eq!(symbol_for(&table, "Hash"), None);
+        assert_eq!(symbol_for(&table, "hashtag"), Some("#"));
+    }
+
+    #[test]
+    fn test_longest_phrase_first() {
+        let table = phrases("fr", &HashMap::new());
+        let position = |phrase: &str| table.iter().position(|(p, _)| p == phrase).unwrap();
+        assert!(position("point d'interrogation") < position("point"));
+        assert!(position("tiret bas") < position("tiret"));
+    }
+}
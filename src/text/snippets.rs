@@ -0,0 +1,103 @@
+//! Snippet expansion (`[text.snippets]`)
+//!
+//! When a whole dictation is exactly a snippet's phrase, the snippet's text
+//! is typed instead, verbatim and with no other text processing:
+//!
+//! ```toml
+//! [text.snippets]
+//! "my signature" = "Best regards,\nPete"
+//! ```
+//!
+//! Matching ignores case, punctuation and extra whitespace, so "My
+//! signature." triggers it. Values may span lines and use the same
+//! `{date}`, `{time}` and `{clipboard}` placeholders as `[text.macros]`.
+
+use super::macros::{expand_placeholders, read_clipboard};
+use chrono::Local;
+use std::collections::HashMap;
+
+/// Snippets keyed by normalized phrase
+pub struct Snippets {
+    entries: HashMap<String, String>,
+}
+
+impl Snippets {
+    /// Index snippets by normalized phrase
+    pub fn new(snippets: &HashMap<String, String>) -> Self {
+        let entries = snippets
+            .iter()
+            .map(|(phrase, text)| (normalize(phrase), text.clone()))
+            .filter(|(phrase, _)| !phrase.is_empty())
+            .collect();
+        Self { entries }
+    }
+
+    /// The expanded snippet if `text` is exactly one of the phrases
+    pub fn expand(&self, text: &str) -> Option<String> {
+        let template = self.entries.get(&normalize(text))?;
+        Some(expand_placeholders(
+            template,
+            Local::now().naive_local(),
+            &read_clipboard,
+        ))
+    }
+}
+
+/// Lowercase words with punctuation and apostrophes removed
+fn normalize(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snippets() -> Snippets {
+        Snippets::new(&HashMap::from([
+            (
+                "my signature".to_string(),
+                "Best regards,\nPete".to_string(),
+            ),
+            (
+                "today's standup".to_string(),
+                "Standup {date}:\n- ".to_string(),
+            ),
+        ]))
+    }
+
+    #[test]
+    fn test_exact_phrase_expands() {
+        let s = snippets();
+        assert_eq!(
+            s.expand("My signature."),
+            Some("Best regards,\nPete".to_string())
+        );
+        assert_eq!(
+            s.expand("  my   SIGNATURE "),
+            Some("Best regards,\nPete".to_string())
+        );
+    }
+
+    #[test]
+    fn test_only_whole_dictation_matches() {
+        let s = snippets();
+        assert_eq!(s.expand("add my signature"), None);
+        assert_eq!(s.expand("my signature please"), None);
+    }
+
+    #[test]
+    fn test_placeholders() {
+        let expanded = snippets().expand("Todays standup").unwrap();
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        assert_eq!(expanded, format!("Standup {}:\n- ", today));
+    }
+}
@@ -0,0 +1,192 @@
+//! Spelling mode with the NATO alphabet
+//!
+//! Letter sequences are where speech recognition does worst, so serial
+//! numbers, usernames and passwords can be spelled instead: "spell alpha
+//! bravo seven charlie" → "ab7c". "capital" (or "uppercase") before a letter
+//! word uppercases it, and "dash", "underscore" and "dot" insert symbols.
+//! The spelled run ends at the first word that isn't part of the alphabet.
+//! Profiles can spell a whole dictation via `spelling = true`.
+
+use super::code::{tokenize, Token};
+
+/// Trigger word for spelling inside a dictation
+const SPELL_KEYWORD: &str = "spell";
+
+/// Words that uppercase the following letter word
+const CAPITAL_WORDS: &[&str] = &["capital", "uppercase", "cap"];
+
+/// Punctuation Whisper tends to put between spelled words, dropped inside a
+/// spelled run
+const ATTACHED_PUNCT: &[char] = &['.', ',', ';', ':', '!', '?'];
+
+/// Output for a single spelling word, or `None` if it isn't one
+fn spell_word(word: &str) -> Option<&'static str> {
+    Some(match word {
+        "alpha" | "alfa" => "a",
+        "bravo" => "b",
+        "charlie" => "c",
+        "delta" => "d",
+        "echo" => "e",
+        "foxtrot" => "f",
+        "golf" => "g",
+        "hotel" => "h",
+        "india" => "i",
+        "juliet" | "juliett" => "j",
+        "kilo" => "k",
+        "lima" => "l",
+        "mike" => "m",
+        "november" => "n",
+        "oscar" => "o",
+        "papa" => "p",
+        "quebec" => "q",
+        "romeo" => "r",
+        "sierra" => "s",
+        "tango" => "t",
+        "uniform" => "u",
+        "victor" => "v",
+        "whiskey" | "whisky" => "w",
+        "x-ray" | "xray" => "x",
+        "yankee" => "y",
+        "zulu" => "z",
+        "zero" => "0",
+        "one" => "1",
+        "two" => "2",
+        "three" => "3",
+        "four" => "4",
+        "five" => "5",
+        "six" => "6",
+        "seven" => "7",
+        "eight" => "8",
+        "nine" | "niner" => "9",
+        "dash" | "hyphen" | "-" => "-",
+        "underscore" | "_" => "_",
+        "dot" => ".",
+        _ => return None,
+    })
+}
+
+/// Spell the token at `tokens[0]`, possibly with a preceding "capital".
+/// Returns the output and the number of tokens consumed.
+fn spell_at(tokens: &[Token]) -> Option<(String, usize)> {
+    let first = key(tokens.first()?.text);
+    if CAPITAL_WORDS.contains(&first.as_str()) {
+        let letter = spell_word(&key(tokens.get(1)?.text))?;
+        if letter.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Some((letter.to_uppercase(), 2));
+        }
+        return None;
+    }
+    if !first.is_empty() && first.chars().all(|c| c.is_ascii_digit()) {
+        return Some((first, 1));
+    }
+    spell_word(&first).map(|s| (s.to_string(), 1))
+}
+
+/// Lowercased token without punctuation attached by the transcriber
+fn key(token: &str) -> String {
+    token.trim_end_matches(ATTACHED_PUNCT).to_lowercase()
+}
+
+/// Replace "spell" followed by spelling words with the spelled text.
+/// Punctuation after the last spelled word is kept.
+pub fn apply_spell_command(text: &str) -> String {
+    let (tokens, tail) = tokenize(text);
+
+    let mut result = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < tokens.len() {
+        if key(tokens[i].text) != SPELL_KEYWORD {
+            result.push_str(tokens[i].gap);
+            result.push_str(tokens[i].text);
+            i += 1;
+            continue;
+        }
+
+        let mut j = i + 1;
+        let mut spelled = String::new();
+        while let Some((s, consumed)) = spell_at(&tokens[j..]) {
+            spelled.push_str(&s);
+            j += consumed;
+        }
+        if spelled.is_empty() {
+            // "I can't spell that" is just text
+            result.push_str(tokens[i].gap);
+            result.push_str(tokens[i].text);
+            i += 1;
+            continue;
+        }
+
+        let last = tokens[j - 1].text;
+        result.push_str(tokens[i].gap);
+        result.push_str(&spelled);
+        result.push_str(&last[last.trim_end_matches(ATTACHED_PUNCT).len()..]);
+        i = j;
+    }
+    result.push_str(tail);
+    result
+}
+
+/// Spell a whole dictation, for profiles with `spelling = true`. Words that
+/// aren't spelling words are kept as said; spaces and attached punctuation
+/// are dropped.
+pub fn spell_all(text: &str) -> String {
+    let (tokens, _) = tokenize(text);
+
+    let mut result = String::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        match spell_at(&tokens[i..]) {
+            Some((s, consumed)) => {
+                result.push_str(&s);
+                i += consumed;
+            }
+            None => {
+                result.push_str(tokens[i].text.trim_end_matches(ATTACHED_PUNCT));
+                i += 1;
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spell_command() {
+        assert_eq!(
+            apply_spell_command("spell alpha bravo seven charlie"),
+            "ab7c"
+        );
+        assert_eq!(
+            apply_spell_command("my username is spell capital juliet delta underscore 42 thanks"),
+            "my username is Jd_42 thanks"
+        );
+        assert_eq!(
+            apply_spell_command("Spell Alpha, Bravo, X-ray. Then send it"),
+            "abx. Then send it"
+        );
+    }
+
+    #[test]
+    fn test_spell_without_letters_is_text() {
+        assert_eq!(
+            apply_spell_command("I can't spell that word"),
+            "I can't spell that word"
+        );
+        assert_eq!(apply_spell_command("spell"), "spell");
+        // "capital" needs a letter after it
+        assert_eq!(
+            apply_spell_command("spell alpha capital city"),
+            "a capital city"
+        );
+    }
+
+    #[test]
+    fn test_spell_all() {
+        assert_eq!(spell_all("Alpha, Bravo, 7, Charlie."), "ab7c");
+        assert_eq!(spell_all("capital sierra nine niner dash kilo"), "S99-k");
+        assert_eq!(spell_all("delta hello"), "dhello");
+    }
+}
@@ -0,0 +1,170 @@
+//! Commands spoken at the end of an utterance
+//!
+//! "submit", the voice editing commands and the case commands all work the
+//! same way: a fixed phrase at the very end of the dictation, spoken as
+//! whole words, possibly followed by punctuation from spoken punctuation or
+//! the transcriber ("hello world, submit."). [`split`] finds such a phrase
+//! and returns the text before it.
+//!
+//! Case commands change the case of everything said before them:
+//!
+//! | Say | Get |
+//! |-----|-----|
+//! | "... all caps" / "... uppercase that" | `UPPERCASE` |
+//! | "... lowercase that" / "... no caps" | `lowercase` |
+//! | "... title case that" / "... capitalize that" | `Title Case` |
+
+/// Phrase that triggers smart auto-submit
+pub const SUBMIT: &[&str] = &["submit"];
+
+/// Voice editing phrases. A bare "undo" only counts as the whole utterance
+/// (see `TextProcessor::detect_edit_command`).
+pub const EDIT_COMMANDS: &[&str] = &[
+    "scratch that",
+    "undo that",
+    "undo",
+    "delete the last word",
+    "delete last word",
+];
+
+const CASE_COMMANDS: &[(&str, CaseCommand)] = &[
+    ("all caps that", CaseCommand::Upper),
+    ("all caps", CaseCommand::Upper),
+    ("caps that", CaseCommand::Upper),
+    ("uppercase that", CaseCommand::Upper),
+    ("upper case that", CaseCommand::Upper),
+    ("lowercase that", CaseCommand::Lower),
+    ("lower case that", CaseCommand::Lower),
+    ("no caps that", CaseCommand::Lower),
+    ("no caps", CaseCommand::Lower),
+    ("title case that", CaseCommand::Title),
+    ("capitalize that", CaseCommand::Title),
+];
+
+/// Case change requested by a trailing case command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseCommand {
+    /// Every letter uppercase
+    Upper,
+    /// Every letter lowercase
+    Lower,
+    /// First letter of every word uppercase, the rest unchanged
+    Title,
+}
+
+impl CaseCommand {
+    /// Apply the case change to `text`
+    pub fn apply(self, text: &str) -> String {
+        match self {
+            CaseCommand::Upper => text.to_uppercase(),
+            CaseCommand::Lower => text.to_lowercase(),
+            CaseCommand::Title => {
+                let mut result = String::with_capacity(text.len());
+                let mut word_start = true;
+                for c in text.chars() {
+                    if word_start && c.is_alphabetic() {
+                        result.extend(c.to_uppercase());
+                    } else {
+                        result.push(c);
+                    }
+                    word_start = c.is_whitespace() || c == '-' || c == '(' || c == '"';
+                }
+                result
+            }
+        }
+    }
+}
+
+/// Split a command phrase off the end of `text`
+///
+/// `phrases` are lowercase ASCII and match case-insensitively as whole
+/// words: the phrase must start the text or follow whitespace, so
+/// "pre-submit" is not "submit". Punctuation after the phrase is ignored.
+/// Returns the text before the phrase, without trailing whitespace, commas
+/// or semicolons (sentence-ending punctuation is kept), and the phrase.
+pub fn split<'a>(text: &'a str, phrases: &[&'static str]) -> Option<(&'a str, &'static str)> {
+    let head = text
+        .trim_end_matches(|c: char| c.is_whitespace() || matches!(c, '.' | '!' | '?' | ',' | ';'));
+
+    // The longest matching phrase starts earliest: "undo that" over "that"
+    let (start, phrase) = phrases
+        .iter()
+        .filter_map(|phrase| {
+            let start = head.len().checked_sub(phrase.len())?;
+            if !head.is_char_boundary(start) || !head[start..].eq_ignore_ascii_case(phrase) {
+                return None;
+            }
+            let whole_word = head[..start]
+                .chars()
+                .next_back()
+                .is_none_or(char::is_whitespace);
+            whole_word.then_some((start, *phrase))
+        })
+        .min_by_key(|(start, _)| *start)?;
+
+    let before =
+        head[..start].trim_end_matches(|c: char| c.is_whitespace() || c == ',' || c == ';');
+    Some((before, phrase))
+}
+
+/// Split a trailing case command off `text`
+///
+/// A "submit" after the case command ("... all caps submit") is kept, so
+/// smart auto-submit still sees it: the result is the text before the case
+/// command, the command, and whether "submit" followed.
+pub fn split_case_command(text: &str) -> Option<(&str, CaseCommand, bool)> {
+    let (text, submit) = match split(text, SUBMIT) {
+        Some((before, _)) => (before, true),
+        None => (text, false),
+    };
+    let phrases: Vec<&'static str> = CASE_COMMANDS.iter().map(|(phrase, _)| *phrase).collect();
+    let (before, phrase) = split(text, &phrases)?;
+    let command = CASE_COMMANDS
+        .iter()
+        .find(|(p, _)| *p == phrase)
+        .map(|(_, command)| *command)?;
+    Some((before, command, submit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_whole_words_and_punctuation() {
+        assert_eq!(
+            split("hello world, Submit.", SUBMIT),
+            Some(("hello world", "submit"))
+        );
+        assert_eq!(split("Done. submit", SUBMIT), Some(("Done.", "submit")));
+        assert_eq!(split("submit", SUBMIT), Some(("", "submit")));
+        assert_eq!(split("pre-submit", SUBMIT), None);
+        assert_eq!(split("resubmit", SUBMIT), None);
+        assert_eq!(split("submit this", SUBMIT), None);
+        assert_eq!(
+            split("fix it undo that", EDIT_COMMANDS),
+            Some(("fix it", "undo that"))
+        );
+    }
+
+    #[test]
+    fn test_case_commands() {
+        assert_eq!(
+            split_case_command("warning, all caps."),
+            Some(("warning", CaseCommand::Upper, false))
+        );
+        assert_eq!(
+            split_case_command("Hello There lowercase that submit"),
+            Some(("Hello There", CaseCommand::Lower, true))
+        );
+        assert_eq!(split_case_command("hello submit"), None);
+        assert_eq!(split_case_command("they wrote it in all caps now"), None);
+
+        assert_eq!(CaseCommand::Upper.apply("warning: hot"), "WARNING: HOT");
+        assert_eq!(CaseCommand::Lower.apply("Hello There"), "hello there");
+        assert_eq!(
+            CaseCommand::Title.apply("the lord of the rings (extended) - part two"),
+            "The Lord Of The Rings (Extended) - Part Two"
+        );
+    }
+}
@@ -6,12 +6,14 @@
 //!
 //! The whisper-cli binary must be installed separately or built from whisper.cpp.
 
+use super::whisper::{resolve_model_path, strip_suppressed_phrases};
 use super::Transcriber;
-use crate::config::{Config, WhisperConfig};
+use crate::config::{WhisperConfig, WhisperDecodingConfig};
 use crate::error::TranscribeError;
 use serde::Deserialize;
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
+use std::sync::Mutex;
 
 /// CLI-based transcriber using whisper-cli subprocess
 pub struct CliTranscriber {
@@ -27,6 +29,12 @@ pub struct CliTranscriber {
     threads: usize,
     /// Initial prompt for context
     initial_prompt: Option<String>,
+    /// Hallucinated phrases dropped from the output
+    suppress_phrases: Vec<String>,
+    /// Sampling strategy and fallback thresholds
+    decoding: WhisperDecodingConfig,
+    /// GBNF grammar from the active profile, if any
+    grammar: Mutex<Option<String>>,
 }
 
 /// JSON output structure from whisper-cli
@@ -44,7 +52,7 @@ impl CliTranscriber {
     /// Create a new CLI-based transcriber
     pub fn new(config: &WhisperConfig) -> Result<Self, TranscribeError> {
         let cli_path = resolve_cli_path(config.whisper_cli_path.as_deref())?;
-        let model_path = resolve_model_path(&config.model)?;
+        let model_path = resolve_model_path(&config.model, &config.custom_models)?;
 
         tracing::info!(
             "Using whisper-cli backend: {:?} with model {:?}",
@@ -76,6 +84,9 @@ impl CliTranscriber {
             translate: config.translate,
             threads,
             initial_prompt: config.initial_prompt.clone(),
+            suppress_phrases: config.suppress_phrases.clone(),
+            decoding: config.decoding.clone(),
+            grammar: Mutex::new(None),
         })
     }
 
@@ -179,6 +190,35 @@ impl Transcriber for CliTranscriber {
             cmd.arg("--prompt").arg(prompt);
         }
 
+        // Decoding parameters, left at whisper-cli's defaults when unset
+        let decoding = &self.decoding;
+        if let Some(beam_size) = decoding.beam_size {
+            cmd.arg("--beam-size").arg(beam_size.max(1).to_string());
+        }
+        if let Some(best_of) = decoding.best_of {
+            cmd.arg("--best-of").arg(best_of.max(1).to_string());
+        }
+        if let Some(temperature) = decoding.temperature {
+            cmd.arg("--temperature").arg(temperature.to_string());
+        }
+        if let Some(increment) = decoding.temperature_increment {
+            cmd.arg("--temperature-inc").arg(increment.to_string());
+        }
+        if let Some(threshold) = decoding.entropy_threshold {
+            cmd.arg("--entropy-thold").arg(threshold.to_string());
+        }
+        if let Some(threshold) = decoding.logprob_threshold {
+            cmd.arg("--logprob-thold").arg(threshold.to_string());
+        }
+
+        // Grammar-constrained decoding, starting from the `root` rule
+        if let Some(grammar) = self.grammar.lock().ok().and_then(|g| g.clone()) {
+            cmd.arg("--grammar")
+                .arg(grammar)
+                .arg("--grammar-rule")
+                .arg("root");
+        }
+
         tracing::debug!("Running whisper-cli: {:?}", cmd);
 
         // Run whisper-cli
@@ -216,11 +256,10 @@ impl Transcriber for CliTranscriber {
         let text: String = result
             .transcription
             .iter()
-            .map(|s| s.text.trim())
+            .map(|s| strip_suppressed_phrases(&s.text, &self.suppress_phrases))
+            .filter(|s| !s.is_empty())
             .collect::<Vec<_>>()
-            .join(" ")
-            .trim()
-            .to_string();
+            .join(" ");
 
         tracing::info!(
             "Transcription completed in {:.2}s: {:?}",
@@ -234,6 +273,13 @@ impl Transcriber for CliTranscriber {
 
         Ok(text)
     }
+
+    fn set_grammar(&self, grammar: Option<String>) -> bool {
+        if let Ok(mut guard) = self.grammar.lock() {
+            *guard = grammar;
+        }
+        true
+    }
 }
 
 /// Resolve whisper-cli path
@@ -276,66 +322,6 @@ fn resolve_cli_path(configured_path: Option<&str>) -> Result<PathBuf, Transcribe
     ))
 }
 
-/// Resolve model name to file path
-fn resolve_model_path(model: &str) -> Result<PathBuf, TranscribeError> {
-    // If it's already an absolute path, use it directly
-    let path = PathBuf::from(model);
-    if path.is_absolute() && path.exists() {
-        return Ok(path);
-    }
-
-    // Map model names to file names
-    let model_filename = match model {
-        "tiny" => "ggml-tiny.bin",
-        "tiny.en" => "ggml-tiny.en.bin",
-        "base" => "ggml-base.bin",
-        "base.en" => "ggml-base.en.bin",
-        "small" => "ggml-small.bin",
-        "small.en" => "ggml-small.en.bin",
-        "medium" => "ggml-medium.bin",
-        "medium.en" => "ggml-medium.en.bin",
-        "large" | "large-v1" => "ggml-large-v1.bin",
-        "large-v2" => "ggml-large-v2.bin",
-        "large-v3" => "ggml-large-v3.bin",
-        "large-v3-turbo" => "ggml-large-v3-turbo.bin",
-        other if other.ends_with(".bin") => other,
-        other => {
-            return Err(TranscribeError::ModelNotFound(format!(
-                "Unknown model: '{}'. Valid models: tiny, base, small, medium, large-v3, large-v3-turbo",
-                other
-            )));
-        }
-    };
-
-    // Look in the data directory
-    let models_dir = Config::models_dir();
-    let model_path = models_dir.join(model_filename);
-
-    if model_path.exists() {
-        return Ok(model_path);
-    }
-
-    // Also check current directory
-    let cwd_path = PathBuf::from(model_filename);
-    if cwd_path.exists() {
-        return Ok(cwd_path);
-    }
-
-    // Also check ./models/
-    let local_models_path = PathBuf::from("models").join(model_filename);
-    if local_models_path.exists() {
-        return Ok(local_models_path);
-    }
-
-    Err(TranscribeError::ModelNotFound(format!(
-        "Model '{}' not found. Looked in:\n  - {}\n  - {}\n  - {}",
-        model,
-        model_path.display(),
-        cwd_path.display(),
-        local_models_path.display()
-    )))
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -349,7 +335,7 @@ mod tests {
 
     #[test]
     fn test_resolve_model_path_unknown() {
-        let result = resolve_model_path("nonexistent-model");
+        let result = resolve_model_path("nonexistent-model", &Default::default());
         assert!(result.is_err());
     }
 }
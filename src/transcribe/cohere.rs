@@ -139,21 +139,22 @@ impl CohereTranscriber {
     pub fn new(config: &CohereConfig) -> Result<Self, TranscribeError> {
         let model_dir = resolve_model_path(&config.model)?;
         let threads = config.threads.unwrap_or_else(|| num_cpus::get().min(4));
-        Self::with_threads_and_lang(&model_dir, threads, &config.language)
+        Self::with_threads_and_lang(&model_dir, threads, &config.language, config.cuda_device)
     }
 
     pub fn from_dir(model_dir: &Path) -> Result<Self, TranscribeError> {
-        Self::with_threads_and_lang(model_dir, num_cpus::get().min(4), "en")
+        Self::with_threads_and_lang(model_dir, num_cpus::get().min(4), "en", None)
     }
 
     pub fn with_threads(model_dir: &Path, threads: usize) -> Result<Self, TranscribeError> {
-        Self::with_threads_and_lang(model_dir, threads, "en")
+        Self::with_threads_and_lang(model_dir, threads, "en", None)
     }
 
     pub fn with_threads_and_lang(
         model_dir: &Path,
         threads: usize,
         language: &str,
+        cuda_device: Option<u32>,
     ) -> Result<Self, TranscribeError> {
         tracing::info!("Loading Cohere Transcribe model from {:?}", model_dir);
         let start = std::time::Instant::now();
@@ -184,14 +185,14 @@ impl CohereTranscriber {
             ))
         })?;
 
-        let encoder = build_session(&encoder_file, threads, "encoder", true)?;
+        let encoder = build_session(&encoder_file, threads, "encoder", true, cuda_device)?;
         // Decoder pinned to CPU: ORT's CUDA GroupQueryAttention kernel rejects
         // the `attention_bias` input that the HF Optimum decoder export uses
         // (validated on GTX 1660 Ti, ORT 1.20 via pyke ort 2.0.0-rc.12). The
         // encoder still runs on GPU, where the 1.4GB-weight matmuls dominate
         // wall time; the smaller decoder runs CPU-side until ORT lands the
         // attention_bias kernel.
-        let decoder = build_session(&decoder_file, threads, "decoder", false)?;
+        let decoder = build_session(&decoder_file, threads, "decoder", false, None)?;
 
         // The HF Optimum exports use mixed precision: `encoder_hidden_states`
         // stays Float32 across every variant (q4/int8/FP32 keep encoder
@@ -555,20 +556,21 @@ fn build_session(
     threads: usize,
     label: &str,
     use_gpu: bool,
+    cuda_device: Option<u32>,
 ) -> Result<Session, TranscribeError> {
     let builder = Session::builder()
         .map_err(|e| TranscribeError::InitFailed(format!("{label} builder: {e}")))?
         .with_intra_threads(threads)
         .map_err(|e| TranscribeError::InitFailed(format!("{label} threads: {e}")))?;
 
-    let mut builder = if use_gpu {
-        super::onnx_ep::register_gpu_eps(builder, "Cohere", label)
+    let builder = if use_gpu {
+        super::onnx_ep::register_gpu_eps(builder, "Cohere", label, cuda_device)
             .map_err(|e| TranscribeError::InitFailed(format!("{label} EPs: {e}")))?
     } else {
         builder
     };
 
-    builder.commit_from_file(path).map_err(|e| {
+    super::model_file::commit_session(builder, path).map_err(|e| {
         TranscribeError::InitFailed(format!(
             "Failed to load Cohere {label} from {:?}: {e}",
             path
@@ -79,6 +79,39 @@ pub fn ctc_greedy_decode(
     tokens_to_string(&token_ids, tokens, config)
 }
 
+/// Utterance confidence from CTC output: the mean softmax probability of
+/// the winning token over all non-blank frames. Works on raw logits and on
+/// log-probabilities alike, since softmax is shift-invariant. Returns `None`
+/// when every frame decoded to blank.
+pub fn ctc_confidence(
+    logits: &[f32],
+    time_steps: usize,
+    vocab_size: usize,
+    blank_id: u32,
+) -> Option<f32> {
+    let mut total = 0.0f32;
+    let mut frames = 0usize;
+
+    for t in 0..time_steps {
+        let offset = t * vocab_size;
+        let frame_logits = &logits[offset..offset + vocab_size];
+
+        let (best_id, &best) = frame_logits
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))?;
+        if best_id as u32 == blank_id {
+            continue;
+        }
+
+        let denom: f32 = frame_logits.iter().map(|&l| (l - best).exp()).sum();
+        total += 1.0 / denom;
+        frames += 1;
+    }
+
+    (frames > 0).then(|| total / frames as f32)
+}
+
 /// Decode pre-argmaxed output where values are already token IDs (as f32)
 ///
 /// Some ONNX models output 2D logits where each value is already the best
@@ -268,6 +301,19 @@ mod tests {
         assert_eq!(result, "hello world");
     }
 
+    #[test]
+    fn test_ctc_confidence_skips_blank_frames() {
+        // Frame 0: blank wins. Frame 1: token 1 certain. Frame 2: tokens 1/2 tied.
+        let logits = vec![
+            10.0, 0.0, 0.0, //
+            0.0, 100.0, 0.0, //
+            0.0, 5.0, 5.0,
+        ];
+        let confidence = ctc_confidence(&logits, 3, 3, 0).unwrap();
+        assert!((confidence - 0.75).abs() < 0.01, "got {}", confidence);
+        assert_eq!(ctc_confidence(&logits[..3], 1, 3, 0), None);
+    }
+
     #[test]
     fn test_decode_pre_argmax() {
         let mut tokens = HashMap::new();
@@ -33,6 +33,8 @@ pub struct DolphinTranscriber {
     neg_mean: Vec<f32>,
     inv_stddev: Vec<f32>,
     fbank_extractor: FbankExtractor,
+    /// Confidence of the most recent transcription, see [`ctc::ctc_confidence`]
+    last_confidence: std::sync::Mutex<Option<f32>>,
 }
 
 impl DolphinTranscriber {
@@ -79,19 +81,23 @@ impl DolphinTranscriber {
         // No GPU EP registration: Dolphin runs on the CPU EP only.
         // MIGraphX 7.2 rejects this encoder's Slice op shape, so we
         // keep the engine on CPU on the AMD-targeted binary.
-        let session = Session::builder()
-            .map_err(|e| {
-                TranscribeError::InitFailed(format!("ONNX session builder failed: {}", e))
-            })?
-            .with_intra_threads(threads)
-            .map_err(|e| TranscribeError::InitFailed(format!("Failed to set threads: {}", e)))?
-            .commit_from_file(&model_file)
-            .map_err(|e| {
-                TranscribeError::InitFailed(format!(
-                    "Failed to load Dolphin model from {:?}: {}",
-                    model_file, e
-                ))
-            })?;
+        let session = super::model_file::commit_session(
+            Session::builder()
+                .map_err(|e| {
+                    TranscribeError::InitFailed(format!("ONNX session builder failed: {}", e))
+                })?
+                .with_intra_threads(threads)
+                .map_err(|e| {
+                    TranscribeError::InitFailed(format!("Failed to set threads: {}", e))
+                })?,
+            &model_file,
+        )
+        .map_err(|e| {
+            TranscribeError::InitFailed(format!(
+                "Failed to load Dolphin model from {:?}: {}",
+                model_file, e
+            ))
+        })?;
 
         // Read CMVN stats from model metadata
         // Dolphin uses "mean"/"invstd" naming (mean is positive, needs negation)
@@ -110,6 +116,7 @@ impl DolphinTranscriber {
             neg_mean,
             inv_stddev,
             fbank_extractor,
+            last_confidence: std::sync::Mutex::new(None),
         })
     }
 }
@@ -207,9 +214,11 @@ impl Transcriber for DolphinTranscriber {
         tracing::debug!("Dolphin output shape: {:?}", shape_dims);
 
         // Dolphin CTC output: [batch, time_steps, vocab_size]
+        let mut confidence = None;
         let raw_text = if shape_dims.len() == 3 {
             let time_steps = shape_dims[1] as usize;
             let vocab_size = shape_dims[2] as usize;
+            confidence = ctc::ctc_confidence(logits_data, time_steps, vocab_size, 0);
             let config = ctc::CtcConfig {
                 blank_id: 0,
                 num_metadata_tokens: 0,
@@ -235,6 +244,10 @@ impl Transcriber for DolphinTranscriber {
         // Filter language/region tokens from output (e.g., <zh>, <CN>, <ja>, <JP>)
         let result = filter_language_tokens(&raw_text);
 
+        if let Ok(mut guard) = self.last_confidence.lock() {
+            *guard = confidence;
+        }
+
         tracing::info!(
             "Dolphin transcription completed in {:.2}s: {:?}",
             start.elapsed().as_secs_f32(),
@@ -247,6 +260,10 @@ impl Transcriber for DolphinTranscriber {
 
         Ok(result)
     }
+
+    fn last_confidence(&self) -> Option<f32> {
+        self.last_confidence.lock().ok().and_then(|g| *g)
+    }
 }
 
 /// Remove language and region tokens from CTC output
@@ -0,0 +1,234 @@
+//! Hotword biasing for transducer greedy search
+//!
+//! Each `[transcribe.hotwords]` entry is split into vocabulary tokens and
+//! stored in a token trie. While decoding, every token that would start or
+//! continue a hotword has its boost added to the joiner logits before the
+//! argmax, so project names and jargon win over acoustically similar
+//! common words (shallow fusion, as in sherpa-onnx's context graph).
+//!
+//! Greedy search cannot take back tokens, so a partial match that fails is
+//! simply dropped rather than penalized.
+
+use std::collections::HashMap;
+
+/// SentencePiece word-start marker
+const WORD_START: char = '\u{2581}';
+
+/// Token trie over the configured hotwords
+pub struct HotwordBias {
+    nodes: Vec<Node>,
+}
+
+#[derive(Default)]
+struct Node {
+    /// Child node per token ID
+    children: HashMap<i64, usize>,
+    /// Highest boost of any hotword passing through this node
+    boost: f32,
+}
+
+/// Trie nodes of the partial matches in progress (the root is implicit)
+pub type HotwordState = Vec<usize>;
+
+impl HotwordBias {
+    /// Build the trie from `word -> boost` entries. Words that cannot be
+    /// spelled with the model's vocabulary are skipped with a warning.
+    pub fn new(hotwords: &HashMap<String, f32>, tokens: &HashMap<u32, String>) -> Self {
+        let vocab: HashMap<&str, i64> = tokens
+            .iter()
+            .filter(|(_, piece)| !(piece.starts_with('<') && piece.ends_with('>')))
+            .map(|(&id, piece)| (piece.as_str(), id as i64))
+            .collect();
+        let max_piece = vocab.keys().map(|p| p.chars().count()).max().unwrap_or(1);
+
+        let mut bias = Self {
+            nodes: vec![Node::default()],
+        };
+        for (word, &boost) in hotwords {
+            match encode(word, &vocab, max_piece) {
+                Some(ids) => bias.insert(&ids, boost),
+                None => tracing::warn!(
+                    "Hotword {:?} cannot be spelled with this model's vocabulary, ignoring",
+                    word
+                ),
+            }
+        }
+        bias
+    }
+
+    /// True if no hotword made it into the trie
+    pub fn is_empty(&self) -> bool {
+        self.nodes[0].children.is_empty()
+    }
+
+    fn insert(&mut self, ids: &[i64], boost: f32) {
+        let mut node = 0;
+        for &id in ids {
+            node = match self.nodes[node].children.get(&id) {
+                Some(&child) => child,
+                None => {
+                    self.nodes.push(Node::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(id, child);
+                    child
+                }
+            };
+            self.nodes[node].boost = self.nodes[node].boost.max(boost);
+        }
+    }
+
+    /// Add the boost of every token that starts or continues a hotword
+    pub fn apply(&self, state: &HotwordState, logits: &mut [f32]) {
+        let mut bonus: HashMap<i64, f32> = HashMap::new();
+        for &node in std::iter::once(&0).chain(state.iter()) {
+            for (&id, &child) in &self.nodes[node].children {
+                let boost = self.nodes[child].boost;
+                bonus
+                    .entry(id)
+                    .and_modify(|b| *b = b.max(boost))
+                    .or_insert(boost);
+            }
+        }
+        for (id, boost) in bonus {
+            if let Some(logit) = logits.get_mut(id as usize) {
+                *logit += boost;
+            }
+        }
+    }
+
+    /// Move the partial matches past an emitted (non-blank) token
+    pub fn advance(&self, state: &mut HotwordState, token: i64) {
+        let next: HotwordState = std::iter::once(&0)
+            .chain(state.iter())
+            .filter_map(|&node| self.nodes[node].children.get(&token).copied())
+            // Completed hotwords have nothing left to continue
+            .filter(|&child| !self.nodes[child].children.is_empty())
+            .collect();
+        *state = next;
+    }
+}
+
+/// Spell `phrase` as token IDs by greedy longest match, trying the phrase
+/// as written, then upper- and lowercase (icefall English vocabularies are
+/// often all caps). CJK vocabularies without word-start markers are tried
+/// without them.
+fn encode(phrase: &str, vocab: &HashMap<&str, i64>, max_piece: usize) -> Option<Vec<i64>> {
+    let variants = [
+        phrase.to_string(),
+        phrase.to_uppercase(),
+        phrase.to_lowercase(),
+    ];
+    for marked in [true, false] {
+        for variant in &variants {
+            let mut ids = Vec::new();
+            let spelled = variant.split_whitespace().all(|word| {
+                let piece = if marked {
+                    format!("{}{}", WORD_START, word)
+                } else {
+                    word.to_string()
+                };
+                match longest_match(&piece, vocab, max_piece) {
+                    Some(word_ids) => {
+                        ids.extend(word_ids);
+                        true
+                    }
+                    None => false,
+                }
+            });
+            if spelled && !ids.is_empty() {
+                return Some(ids);
+            }
+        }
+    }
+    None
+}
+
+fn longest_match(text: &str, vocab: &HashMap<&str, i64>, max_piece: usize) -> Option<Vec<i64>> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut ids = Vec::new();
+    let mut start = 0;
+    while start < chars.len() {
+        let longest = (start + 1..=chars.len().min(start + max_piece))
+            .rev()
+            .find_map(|end| {
+                let piece: String = chars[start..end].iter().collect();
+                vocab.get(piece.as_str()).map(|&id| (id, end))
+            });
+        let (id, end) = longest?;
+        ids.push(id);
+        start = end;
+    }
+    Some(ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vocab() -> HashMap<u32, String> {
+        [
+            (0, "<blk>"),
+            (1, "▁THE"),
+            (2, "▁VOX"),
+            (3, "TYPE"),
+            (4, "▁V"),
+            (5, "OX"),
+            (6, "▁KU"),
+            (7, "BER"),
+        ]
+        .into_iter()
+        .map(|(id, s)| (id, s.to_string()))
+        .collect()
+    }
+
+    fn bias(words: &[(&str, f32)]) -> HotwordBias {
+        let hotwords = words.iter().map(|&(w, b)| (w.to_string(), b)).collect();
+        HotwordBias::new(&hotwords, &vocab())
+    }
+
+    #[test]
+    fn test_encode_prefers_longest_pieces_and_uppercases() {
+        let tokens = vocab();
+        let vocab: HashMap<&str, i64> = tokens
+            .iter()
+            .map(|(&id, s)| (s.as_str(), id as i64))
+            .collect();
+        assert_eq!(encode("voxtype", &vocab, 4), Some(vec![2, 3]));
+        assert_eq!(encode("zzz", &vocab, 4), None);
+    }
+
+    #[test]
+    fn test_apply_boosts_hotword_start_and_continuation() {
+        let bias = bias(&[("voxtype", 2.0)]);
+        let mut state = HotwordState::new();
+
+        let mut logits = vec![0.0; 8];
+        bias.apply(&state, &mut logits);
+        assert_eq!(logits[2], 2.0);
+        assert_eq!(logits[3], 0.0);
+
+        bias.advance(&mut state, 2);
+        let mut logits = vec![0.0; 8];
+        bias.apply(&state, &mut logits);
+        assert_eq!(logits[3], 2.0);
+
+        // The hotword is complete, so nothing is left to continue
+        bias.advance(&mut state, 3);
+        assert!(state.is_empty());
+    }
+
+    #[test]
+    fn test_unrelated_token_drops_partial_match() {
+        let bias = bias(&[("voxtype", 2.0)]);
+        let mut state = HotwordState::new();
+        bias.advance(&mut state, 2);
+        bias.advance(&mut state, 1);
+        assert!(state.is_empty());
+    }
+
+    #[test]
+    fn test_unspellable_hotwords_are_skipped() {
+        assert!(bias(&[("zzz", 1.0)]).is_empty());
+        assert!(!bias(&[("zzz", 1.0), ("the", 1.0)]).is_empty());
+    }
+}
@@ -0,0 +1,466 @@
+//! Incremental streaming for batch transcription engines
+//!
+//! Gives engines without a native streaming pipeline (local Whisper and the
+//! batch ONNX engines) a streaming mode by re-decoding the growing recording
+//! at a fixed cadence. Enabled with `[transcribe] streaming = true`.
+//!
+//! # Stabilization
+//!
+//! Re-decoding the same audio twice rarely produces identical text: the tail
+//! of each hypothesis is unstable because the last word is usually cut off
+//! mid-syllable. Only the words that two consecutive hypotheses agree on
+//! (local agreement) are emitted while the user is still speaking. On stream
+//! close the full final decode is emitted, so the typed text always ends up
+//! matching a batch transcription of the same audio.
+//!
+//! Each emission is a cumulative [`StreamingEvent::Hypothesis`]; the output
+//! session diffs it against what is already typed and corrects with
+//! backspaces when an earlier word changes.
+//!
+//! # Windowing
+//!
+//! Whisper decodes at most 30 seconds at a time, and re-decoding minutes of
+//! audio every second would be wasteful anyway. Once the current window
+//! exceeds `streaming_max_window_secs`, the window is cut at the quietest
+//! point of its last two seconds (so a word is not split), that stretch is
+//! decoded one last time and committed, and decoding continues from the cut.
+
+use super::streaming::{SegmentId, StreamHandle, StreamingEvent, StreamingTranscriber};
+use super::{TimedSegment, TimedWord, Transcriber};
+use crate::config::TranscribeConfig;
+use crate::error::TranscribeError;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::{JoinError, JoinHandle};
+
+/// Sample rate of audio delivered by the capture pipeline
+const SAMPLE_RATE: usize = 16000;
+
+/// Minimum new audio before a re-decode is worth running (250 ms)
+const MIN_NEW_SAMPLES: usize = SAMPLE_RATE / 4;
+
+/// Region at the end of a full window searched for a quiet cut point (2 s)
+const CUT_SEARCH_SAMPLES: usize = SAMPLE_RATE * 2;
+
+/// Frame size used when looking for the quietest cut point (20 ms)
+const CUT_FRAME_SAMPLES: usize = SAMPLE_RATE / 50;
+
+/// Wraps a batch [`Transcriber`] and adds [`StreamingTranscriber`] support
+/// through periodic re-decoding. Batch calls pass straight through.
+pub struct IncrementalTranscriber {
+    inner: Arc<dyn Transcriber>,
+    interval: Duration,
+    max_window_samples: usize,
+}
+
+impl IncrementalTranscriber {
+    pub fn new(inner: Arc<dyn Transcriber>, config: &TranscribeConfig) -> Self {
+        let max_window_secs = config.streaming_max_window_secs.clamp(5.0, 28.0);
+        Self {
+            inner,
+            interval: Duration::from_millis(config.streaming_interval_ms.max(200)),
+            max_window_samples: (max_window_secs * SAMPLE_RATE as f32) as usize,
+        }
+    }
+}
+
+impl Transcriber for IncrementalTranscriber {
+    fn transcribe(&self, samples: &[f32]) -> Result<String, TranscribeError> {
+        self.inner.transcribe(samples)
+    }
+
+    fn transcribe_timed(&self, samples: &[f32]) -> Result<Vec<TimedSegment>, TranscribeError> {
+        self.inner.transcribe_timed(samples)
+    }
+
+    fn transcribe_words(&self, samples: &[f32]) -> Result<Vec<TimedWord>, TranscribeError> {
+        self.inner.transcribe_words(samples)
+    }
+
+    fn prepare(&self) {
+        self.inner.prepare();
+    }
+
+    fn as_streaming(&self) -> Option<&dyn StreamingTranscriber> {
+        Some(self)
+    }
+
+    fn last_confidence(&self) -> Option<f32> {
+        self.inner.last_confidence()
+    }
+
+    fn last_detected_language(&self) -> Option<String> {
+        self.inner.last_detected_language()
+    }
+
+    fn set_translate(&self, translate: bool) -> bool {
+        self.inner.set_translate(translate)
+    }
+}
+
+impl StreamingTranscriber for IncrementalTranscriber {
+    fn start_stream(
+        &self,
+        samples_rx: mpsc::Receiver<Vec<f32>>,
+    ) -> Result<StreamHandle, TranscribeError> {
+        let (events_tx, events_rx) = mpsc::channel(32);
+        let (cancel_tx, cancel_rx) = oneshot::channel();
+        let task = tokio::spawn(drive_stream(
+            Arc::clone(&self.inner),
+            self.interval,
+            self.max_window_samples,
+            samples_rx,
+            events_tx,
+            cancel_rx,
+        ));
+        Ok(StreamHandle {
+            events: events_rx,
+            cancel: cancel_tx,
+            task,
+        })
+    }
+}
+
+/// Text committed from finished windows plus the hypothesis of the current one
+#[derive(Default)]
+struct Transcript {
+    committed: String,
+    previous_hypothesis: String,
+}
+
+impl Transcript {
+    /// Full text with `window_text` appended to the committed windows
+    fn with_window(&self, window_text: &str) -> String {
+        join_words(&self.committed, window_text)
+    }
+
+    /// Record a new hypothesis for the current window and return the part
+    /// both it and the previous hypothesis agree on
+    fn stabilize(&mut self, hypothesis: String) -> String {
+        let stable = agreed_prefix(&self.previous_hypothesis, &hypothesis);
+        self.previous_hypothesis = hypothesis;
+        self.with_window(&stable)
+    }
+
+    /// Commit the final text of a finished window
+    fn commit_window(&mut self, window_text: &str) {
+        self.committed = self.with_window(window_text);
+        self.previous_hypothesis.clear();
+    }
+}
+
+/// A decode running on a blocking thread while the stream keeps reading audio
+struct PendingDecode {
+    /// The decode finishes a full window instead of re-decoding the current one
+    commits_window: bool,
+    task: JoinHandle<Result<String, TranscribeError>>,
+}
+
+async fn drive_stream(
+    inner: Arc<dyn Transcriber>,
+    interval: Duration,
+    max_window_samples: usize,
+    mut samples_rx: mpsc::Receiver<Vec<f32>>,
+    events_tx: mpsc::Sender<StreamingEvent>,
+    mut cancel_rx: oneshot::Receiver<()>,
+) -> Result<(), TranscribeError> {
+    let mut window: Vec<f32> = Vec::new();
+    let mut decoded_len = 0usize;
+    let mut transcript = Transcript::default();
+    let mut last_emitted = String::new();
+    let mut pending: Option<PendingDecode> = None;
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    // The decode runs as its own select arm so the capture channel is still
+    // drained while a slow model works; producers use try_send and would
+    // drop audio if the loop stopped reading.
+    loop {
+        tokio::select! {
+            _ = &mut cancel_rx => {
+                let _ = events_tx.send(StreamingEvent::Ended).await;
+                return Ok(());
+            }
+            chunk = samples_rx.recv() => match chunk {
+                Some(samples) => window.extend_from_slice(&samples),
+                None => break,
+            },
+            result = async { (&mut pending.as_mut().unwrap().task).await }, if pending.is_some() => {
+                let commits_window = pending.take().is_some_and(|p| p.commits_window);
+                let text = match join_decode(result) {
+                    Ok(text) if commits_window => {
+                        transcript.commit_window(&text);
+                        transcript.with_window("")
+                    }
+                    Ok(hypothesis) => transcript.stabilize(hypothesis),
+                    Err(e) => {
+                        tracing::warn!("Incremental decode failed: {}", e);
+                        continue;
+                    }
+                };
+                emit_hypothesis(&events_tx, &mut last_emitted, text).await;
+            }
+            _ = ticker.tick(), if pending.is_none() => {
+                if window.len() < decoded_len + MIN_NEW_SAMPLES {
+                    continue;
+                }
+
+                if window.len() >= max_window_samples {
+                    let cut = quiet_cut_point(&window);
+                    let head: Vec<f32> = window.drain(..cut).collect();
+                    decoded_len = 0;
+                    pending = Some(PendingDecode {
+                        commits_window: true,
+                        task: spawn_decode(&inner, head),
+                    });
+                } else {
+                    decoded_len = window.len();
+                    pending = Some(PendingDecode {
+                        commits_window: false,
+                        task: spawn_decode(&inner, window.clone()),
+                    });
+                }
+            }
+        }
+    }
+
+    // Samples sender dropped. A window still being committed must land
+    // first; an in-flight hypothesis is superseded by the final decode.
+    if let Some(p) = pending.take() {
+        let result = join_decode(p.task.await);
+        if p.commits_window {
+            match result {
+                Ok(text) => transcript.commit_window(&text),
+                Err(e) => tracing::warn!("Incremental decode failed: {}", e),
+            }
+        }
+    }
+
+    // Decode whatever remains and commit it whole.
+    if window.len() >= MIN_NEW_SAMPLES {
+        match join_decode(spawn_decode(&inner, window).await) {
+            Ok(text) => transcript.commit_window(&text),
+            Err(e) => {
+                let _ = events_tx.send(StreamingEvent::Error(e)).await;
+                let _ = events_tx.send(StreamingEvent::Ended).await;
+                return Ok(());
+            }
+        }
+    }
+    let text = transcript.with_window("");
+    emit_hypothesis(&events_tx, &mut last_emitted, text).await;
+    let _ = events_tx.send(StreamingEvent::Ended).await;
+    Ok(())
+}
+
+/// Send `text` as the cumulative hypothesis unless it was already sent
+async fn emit_hypothesis(
+    events_tx: &mpsc::Sender<StreamingEvent>,
+    last_emitted: &mut String,
+    text: String,
+) {
+    const SEGMENT: SegmentId = 0;
+
+    if text == *last_emitted {
+        return;
+    }
+    last_emitted.clone_from(&text);
+    let _ = events_tx
+        .send(StreamingEvent::Hypothesis {
+            text,
+            segment_id: SEGMENT,
+        })
+        .await;
+}
+
+/// Start a batch decode on a blocking thread
+fn spawn_decode(
+    inner: &Arc<dyn Transcriber>,
+    samples: Vec<f32>,
+) -> JoinHandle<Result<String, TranscribeError>> {
+    let inner = Arc::clone(inner);
+    tokio::task::spawn_blocking(move || inner.transcribe(&samples))
+}
+
+/// Flatten a finished decode task into its trimmed text
+fn join_decode(
+    result: Result<Result<String, TranscribeError>, JoinError>,
+) -> Result<String, TranscribeError> {
+    result
+        .map_err(|e| TranscribeError::InferenceFailed(format!("Decode task failed: {}", e)))?
+        .map(|text| text.trim().to_string())
+}
+
+/// Longest run of leading words two hypotheses agree on. Words are compared
+/// case-insensitively without trailing punctuation, since whisper often
+/// flips "hello," to "hello." as more context arrives; the newer spelling
+/// is kept.
+fn agreed_prefix(previous: &str, current: &str) -> String {
+    fn normalize(word: &str) -> String {
+        word.trim_end_matches(|c: char| c.is_ascii_punctuation())
+            .to_lowercase()
+    }
+
+    let agreed = previous
+        .split_whitespace()
+        .zip(current.split_whitespace())
+        .take_while(|(a, b)| normalize(a) == normalize(b))
+        .count();
+
+    current
+        .split_whitespace()
+        .take(agreed)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Join two text fragments with a single space
+fn join_words(head: &str, tail: &str) -> String {
+    match (head.is_empty(), tail.is_empty()) {
+        (true, _) => tail.to_string(),
+        (_, true) => head.to_string(),
+        _ => format!("{} {}", head, tail),
+    }
+}
+
+/// Sample index of the quietest frame in the last two seconds of `samples`
+fn quiet_cut_point(samples: &[f32]) -> usize {
+    let search_start = samples.len().saturating_sub(CUT_SEARCH_SAMPLES);
+    let mut best = (f32::MAX, samples.len());
+    let mut frame_start = search_start;
+    while frame_start + CUT_FRAME_SAMPLES <= samples.len() {
+        let frame = &samples[frame_start..frame_start + CUT_FRAME_SAMPLES];
+        let energy = frame.iter().map(|s| s * s).sum::<f32>();
+        if energy < best.0 {
+            best = (energy, frame_start + CUT_FRAME_SAMPLES / 2);
+        }
+        frame_start += CUT_FRAME_SAMPLES;
+    }
+    best.1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn agreed_prefix_stops_at_first_disagreement() {
+        assert_eq!(
+            agreed_prefix("the quick brown fax", "the quick brown fox jumps"),
+            "the quick brown"
+        );
+        assert_eq!(agreed_prefix("", "hello world"), "");
+    }
+
+    #[test]
+    fn agreed_prefix_ignores_case_and_trailing_punctuation() {
+        assert_eq!(
+            agreed_prefix("Hello, world", "hello. World is big"),
+            "hello. World"
+        );
+    }
+
+    #[test]
+    fn transcript_stabilizes_then_commits() {
+        let mut t = Transcript::default();
+        assert_eq!(t.stabilize("one two thr".into()), "");
+        assert_eq!(t.stabilize("one two three fo".into()), "one two");
+        t.commit_window("one two three four");
+        assert_eq!(t.stabilize("five si".into()), "one two three four");
+        assert_eq!(t.stabilize("five six".into()), "one two three four five");
+    }
+
+    #[test]
+    fn quiet_cut_point_finds_silent_frame() {
+        let mut samples = vec![0.5f32; SAMPLE_RATE * 3];
+        let silent_start = SAMPLE_RATE * 2 + SAMPLE_RATE / 2;
+        for s in &mut samples[silent_start..silent_start + CUT_FRAME_SAMPLES * 2] {
+            *s = 0.0;
+        }
+        let cut = quiet_cut_point(&samples);
+        assert!(cut >= silent_start && cut < silent_start + CUT_FRAME_SAMPLES * 2);
+    }
+
+    struct CountingTranscriber;
+
+    impl Transcriber for CountingTranscriber {
+        fn transcribe(&self, samples: &[f32]) -> Result<String, TranscribeError> {
+            let words = ["alpha", "beta", "gamma", "delta", "epsilon"];
+            let n = (samples.len() / (SAMPLE_RATE / 2)).min(words.len());
+            Ok(words[..n].join(" "))
+        }
+    }
+
+    #[tokio::test]
+    async fn stream_ends_with_full_final_decode() {
+        let config = TranscribeConfig {
+            streaming: true,
+            streaming_interval_ms: 200,
+            ..TranscribeConfig::default()
+        };
+        let transcriber = IncrementalTranscriber::new(Arc::new(CountingTranscriber), &config);
+        let (tx, rx) = mpsc::channel(8);
+        let mut handle = transcriber.start_stream(rx).unwrap();
+
+        tx.send(vec![0.1; SAMPLE_RATE * 2]).await.unwrap();
+        drop(tx);
+
+        let mut last = String::new();
+        while let Some(event) = handle.events.recv().await {
+            match event {
+                StreamingEvent::Hypothesis { text, .. } => last = text,
+                StreamingEvent::Ended => break,
+                other => panic!("unexpected event: {:?}", other),
+            }
+        }
+        assert_eq!(last, "alpha beta gamma delta");
+        handle.task.await.unwrap().unwrap();
+    }
+
+    /// Reports how many samples it was given, slowly
+    struct SlowLengthTranscriber;
+
+    impl Transcriber for SlowLengthTranscriber {
+        fn transcribe(&self, samples: &[f32]) -> Result<String, TranscribeError> {
+            std::thread::sleep(Duration::from_millis(300));
+            Ok(samples.len().to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn samples_sent_during_slow_decode_are_not_dropped() {
+        let config = TranscribeConfig {
+            streaming: true,
+            streaming_interval_ms: 200,
+            ..TranscribeConfig::default()
+        };
+        let transcriber = IncrementalTranscriber::new(Arc::new(SlowLengthTranscriber), &config);
+        // Small channel fed with try_send, like the capture producers
+        let (tx, rx) = mpsc::channel(4);
+        let mut handle = transcriber.start_stream(rx).unwrap();
+
+        let chunk = SAMPLE_RATE / 10;
+        let chunks = 40;
+        let mut dropped = 0;
+        for _ in 0..chunks {
+            if tx.try_send(vec![0.1; chunk]).is_err() {
+                dropped += 1;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        drop(tx);
+
+        let mut last = String::new();
+        while let Some(event) = handle.events.recv().await {
+            match event {
+                StreamingEvent::Hypothesis { text, .. } => last = text,
+                StreamingEvent::Ended => break,
+                other => panic!("unexpected event: {:?}", other),
+            }
+        }
+        assert_eq!(dropped, 0);
+        assert_eq!(last, (chunk * chunks).to_string());
+        handle.task.await.unwrap().unwrap();
+    }
+}
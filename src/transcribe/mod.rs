@@ -11,15 +11,23 @@
 //! - Optionally Paraformer via ONNX Runtime (when `paraformer` feature is enabled)
 //! - Optionally Dolphin via ONNX Runtime (when `dolphin` feature is enabled)
 //! - Optionally Omnilingual via ONNX Runtime (when `omnilingual` feature is enabled)
+//! - Optionally Vosk/Kaldi via a runtime-loaded libvosk (when `vosk` feature is enabled)
+//! - Optionally streaming Zipformer via ONNX Runtime (when `zipformer` feature is enabled)
 
 pub mod cli;
+pub mod incremental;
+pub mod model_file;
 #[cfg(feature = "parakeet")]
 pub mod parakeet_streaming;
 pub mod remote;
+#[cfg(feature = "remote-ws")]
+pub mod remote_ws;
+pub mod server;
 #[cfg(feature = "soniox")]
 pub mod soniox;
 pub mod streaming;
 pub mod subprocess;
+pub mod two_pass;
 pub mod whisper;
 pub mod worker;
 
@@ -32,11 +40,12 @@ pub use streaming::{SegmentId, StreamHandle, StreamingEvent, StreamingTranscribe
     feature = "dolphin",
     feature = "omnilingual",
     feature = "cohere",
+    feature = "zipformer",
 ))]
 pub mod fbank;
 
 /// Shared GPU execution-provider registration for ONNX-based engines.
-#[cfg(feature = "onnx-common")]
+/// Always built so status reporting can ask which provider is active.
 pub mod onnx_ep;
 
 /// Shared CTC greedy decoder for CTC-based ASR engines
@@ -46,12 +55,18 @@ pub mod onnx_ep;
     feature = "dolphin",
     feature = "omnilingual",
     feature = "cohere",
+    feature = "zipformer",
+    feature = "parakeet-hotwords",
 ))]
 pub mod ctc;
 
 #[cfg(feature = "parakeet")]
 pub mod parakeet;
 
+/// Parakeet TDT search run by voxtype, used when hotwords are configured
+#[cfg(feature = "parakeet-hotwords")]
+pub mod parakeet_tdt;
+
 #[cfg(feature = "moonshine")]
 pub mod moonshine;
 
@@ -67,13 +82,24 @@ pub mod dolphin;
 #[cfg(feature = "omnilingual")]
 pub mod omnilingual;
 
+#[cfg(feature = "vosk")]
+pub mod vosk;
+
+#[cfg(feature = "zipformer")]
+pub mod zipformer;
+
+/// Hotword biasing for the Zipformer and Parakeet TDT transducer searches
+#[cfg(any(feature = "zipformer", feature = "parakeet-hotwords"))]
+pub mod hotwords;
+
 /// Cohere Transcribe backend (proof-of-concept, not wired into factory/CLI/config).
 /// See `src/transcribe/cohere.rs` for usage.
 #[cfg(feature = "cohere")]
 pub mod cohere;
 
 /// Cohere-specific log-mel feature extractor (NeMo conventions, 128 mels).
-#[cfg(feature = "cohere")]
+/// Parakeet shares the NeMo preprocessor, so its TDT decoder uses it too.
+#[cfg(any(feature = "cohere", feature = "parakeet-hotwords"))]
 pub mod cohere_fbank;
 
 use crate::config::{Config, TranscriptionEngine, WhisperConfig, WhisperMode};
@@ -90,6 +116,49 @@ pub struct TimedSegment {
     pub end_secs: f32,
 }
 
+/// A single recognized word with timing and, where the engine reports it,
+/// a confidence score
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimedWord {
+    pub text: String,
+    /// Start time in milliseconds relative to the audio input
+    pub start_ms: u64,
+    /// End time in milliseconds relative to the audio input
+    pub end_ms: u64,
+    /// Engine confidence in 0.0-1.0, or `None` if the engine has no
+    /// per-word score
+    pub confidence: Option<f32>,
+}
+
+/// Split segments into words, spreading each segment's time span across
+/// its words in proportion to their length. Used by engines that only
+/// report segment-level timing.
+pub fn words_from_segments(segments: &[TimedSegment]) -> Vec<TimedWord> {
+    let mut words = Vec::new();
+    for segment in segments {
+        let parts: Vec<&str> = segment.text.split_whitespace().collect();
+        let total_chars: usize = parts.iter().map(|w| w.chars().count()).sum();
+        if total_chars == 0 {
+            continue;
+        }
+        let start_ms = (segment.start_secs * 1000.0).max(0.0);
+        let span_ms = (segment.end_secs * 1000.0 - start_ms).max(0.0);
+        let mut consumed = 0usize;
+        for part in parts {
+            let word_start = start_ms + span_ms * consumed as f32 / total_chars as f32;
+            consumed += part.chars().count();
+            let word_end = start_ms + span_ms * consumed as f32 / total_chars as f32;
+            words.push(TimedWord {
+                text: part.to_string(),
+                start_ms: word_start.round() as u64,
+                end_ms: word_end.round() as u64,
+                confidence: None,
+            });
+        }
+    }
+    words
+}
+
 /// Trait for speech-to-text implementations
 pub trait Transcriber: Send + Sync {
     /// Transcribe audio samples to text
@@ -112,6 +181,13 @@ pub trait Transcriber: Send + Sync {
         }
     }
 
+    /// Transcribe with per-word timestamps and confidence.
+    /// Default implementation splits the segments from `transcribe_timed()`
+    /// into words (see [`words_from_segments`]) with no confidence.
+    fn transcribe_words(&self, samples: &[f32]) -> Result<Vec<TimedWord>, TranscribeError> {
+        Ok(words_from_segments(&self.transcribe_timed(samples)?))
+    }
+
     /// Prepare for transcription (optional, called when recording starts)
     ///
     /// For subprocess-based transcribers, this spawns the worker process
@@ -134,6 +210,18 @@ pub trait Transcriber: Send + Sync {
         None
     }
 
+    /// Confidence (0.0-1.0) of the most recent [`Self::transcribe`] call, if
+    /// the backend can estimate one.
+    ///
+    /// Whisper derives it from token probabilities and the no-speech
+    /// probability; CTC engines from the per-frame output distribution. The
+    /// daemon compares it against `[transcribe] min_confidence` to decide
+    /// whether to type the text. The default implementation returns `None`,
+    /// which is never treated as low confidence.
+    fn last_confidence(&self) -> Option<f32> {
+        None
+    }
+
     /// Two-letter language code detected (or selected) for the most recent
     /// transcription, if the backend tracks it.
     ///
@@ -149,10 +237,43 @@ pub trait Transcriber: Send + Sync {
     fn last_detected_language(&self) -> Option<String> {
         None
     }
+
+    /// Constrain subsequent transcriptions to a GBNF grammar, or lift the
+    /// constraint with `None`.
+    ///
+    /// The daemon calls this before each transcription with the active
+    /// profile's `grammar_file` contents. Returns `false` if the backend
+    /// cannot apply grammars, in which case output is unconstrained. Only
+    /// the whisper-cli backend supports them: whisper-rs does not pass
+    /// grammar rules to whisper.cpp in the layout it expects.
+    fn set_grammar(&self, _grammar: Option<String>) -> bool {
+        false
+    }
+
+    /// Switch translate-to-English on or off for subsequent transcriptions.
+    ///
+    /// Returns `false` if the backend fixed the setting when it was created
+    /// (e.g. a spawned server or worker process), in which case the caller
+    /// must build a new transcriber to apply it.
+    fn set_translate(&self, _translate: bool) -> bool {
+        false
+    }
 }
 
 /// Factory function to create transcriber based on configured engine
 pub fn create_transcriber(config: &Config) -> Result<Box<dyn Transcriber>, TranscribeError> {
+    if !config.transcribe.hotwords.is_empty()
+        && !matches!(
+            config.engine,
+            TranscriptionEngine::Zipformer | TranscriptionEngine::Parakeet
+        )
+    {
+        tracing::warn!(
+            "[transcribe.hotwords] is only applied by the Zipformer and Parakeet engines, ignoring for {:?}",
+            config.engine
+        );
+    }
+
     match config.engine {
         TranscriptionEngine::Whisper => create_whisper_transcriber(&config.whisper),
         #[cfg(feature = "parakeet")]
@@ -163,12 +284,18 @@ pub fn create_transcriber(config: &Config) -> Result<Box<dyn Transcriber>, Trans
                 )
             })?;
             if parakeet_config.streaming {
+                if !config.transcribe.hotwords.is_empty() {
+                    tracing::warn!(
+                        "[transcribe.hotwords] is not applied by Parakeet streaming, ignoring"
+                    );
+                }
                 Ok(Box::new(
                     parakeet_streaming::ParakeetStreamingTranscriber::new(parakeet_config)?,
                 ))
             } else {
                 Ok(Box::new(parakeet::ParakeetTranscriber::new(
                     parakeet_config,
+                    &config.transcribe.hotwords,
                 )?))
             }
         }
@@ -283,6 +410,37 @@ pub fn create_transcriber(config: &Config) -> Result<Box<dyn Transcriber>, Trans
             "Soniox engine requested but voxtype was not compiled with --features soniox"
                 .to_string(),
         )),
+        #[cfg(feature = "vosk")]
+        TranscriptionEngine::Vosk => {
+            let cfg = config.vosk.as_ref().ok_or_else(|| {
+                TranscribeError::InitFailed(
+                    "Vosk engine selected but [vosk] config section is missing".to_string(),
+                )
+            })?;
+            Ok(Box::new(vosk::VoskTranscriber::new(cfg)?))
+        }
+        #[cfg(not(feature = "vosk"))]
+        TranscriptionEngine::Vosk => Err(TranscribeError::InitFailed(
+            "Vosk engine requested but voxtype was not compiled with --features vosk".to_string(),
+        )),
+        #[cfg(feature = "zipformer")]
+        TranscriptionEngine::Zipformer => {
+            let cfg = config.zipformer.as_ref().ok_or_else(|| {
+                TranscribeError::InitFailed(
+                    "Zipformer engine selected but [zipformer] config section is missing"
+                        .to_string(),
+                )
+            })?;
+            Ok(Box::new(zipformer::ZipformerTranscriber::new(
+                cfg,
+                &config.transcribe.hotwords,
+            )?))
+        }
+        #[cfg(not(feature = "zipformer"))]
+        TranscriptionEngine::Zipformer => Err(TranscribeError::InitFailed(
+            "Zipformer engine requested but voxtype was not compiled with --features zipformer"
+                .to_string(),
+        )),
     }
 }
 
@@ -331,5 +489,39 @@ pub fn create_transcriber_with_config_path(
             tracing::info!("Using whisper-cli subprocess backend");
             Ok(Box::new(cli::CliTranscriber::new(config)?))
         }
+        WhisperMode::Server => {
+            tracing::info!("Using managed whisper-server backend");
+            Ok(Box::new(server::ServerTranscriber::new(config)?))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_from_segments_spreads_span_by_length() {
+        let segments = vec![TimedSegment {
+            text: "hi there".to_string(),
+            start_secs: 1.0,
+            end_secs: 1.7,
+        }];
+        let words = words_from_segments(&segments);
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].text, "hi");
+        assert_eq!((words[0].start_ms, words[0].end_ms), (1000, 1200));
+        assert_eq!((words[1].start_ms, words[1].end_ms), (1200, 1700));
+        assert_eq!(words[1].confidence, None);
+    }
+
+    #[test]
+    fn words_from_segments_skips_blank_segments() {
+        let segments = vec![TimedSegment {
+            text: "   ".to_string(),
+            start_secs: 0.0,
+            end_secs: 1.0,
+        }];
+        assert!(words_from_segments(&segments).is_empty());
     }
 }
@@ -0,0 +1,92 @@
+//! Model file loading shared by local Whisper and the ONNX engines.
+//!
+//! With `[transcribe] mmap_models = true` the model file is memory-mapped
+//! and handed to the runtime as a byte slice instead of being read through
+//! a file handle. The setting is process-wide: it is applied once in
+//! `app::run` after the config and CLI overrides are resolved, so the
+//! daemon, `voxtype transcribe` and the gpu_isolation worker (which loads
+//! the same config file) all agree without threading a flag through every
+//! engine constructor.
+
+use crate::error::TranscribeError;
+use memmap2::Mmap;
+use std::fs::File;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static MMAP_MODELS: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable memory-mapped model loading for this process
+pub fn set_mmap_models(enabled: bool) {
+    MMAP_MODELS.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether model files should be memory-mapped
+pub fn mmap_models() -> bool {
+    MMAP_MODELS.load(Ordering::Relaxed)
+}
+
+/// Map a model file read-only into memory
+pub fn map(path: &Path) -> Result<Mmap, TranscribeError> {
+    let file = File::open(path)
+        .map_err(|e| TranscribeError::ModelNotFound(format!("Failed to open {:?}: {}", path, e)))?;
+    // SAFETY: the mapping is read-only and only borrowed while the runtime
+    // builds its model. Model files are written to a temporary name and
+    // renamed into place on download, so they are not truncated underneath
+    // a live mapping.
+    unsafe { Mmap::map(&file) }
+        .map_err(|e| TranscribeError::InitFailed(format!("Failed to mmap {:?}: {}", path, e)))
+}
+
+/// Build an ONNX Runtime session from `path`, memory-mapping the file when
+/// `mmap_models` is enabled. Falls back to a regular file load if the file
+/// cannot be mapped.
+#[cfg(feature = "onnx-common")]
+pub fn commit_session(
+    mut builder: ort::session::builder::SessionBuilder,
+    path: &Path,
+) -> ort::Result<ort::session::Session> {
+    if !mmap_models() {
+        return builder.commit_from_file(path);
+    }
+
+    let mapped = match map(path) {
+        Ok(mapped) => mapped,
+        Err(e) => {
+            tracing::warn!("{}, loading normally", e);
+            return builder.commit_from_file(path);
+        }
+    };
+
+    // Models loaded from memory have no path of their own, so point ONNX
+    // Runtime at the model's directory to resolve external weight files.
+    let mut builder = match path.parent() {
+        Some(dir) => builder.with_config_entry(
+            "session.model_external_initializers_file_folder_path",
+            dir.to_string_lossy(),
+        )?,
+        None => builder,
+    };
+    tracing::debug!("Memory-mapped {:?} ({} bytes)", path, mapped.len());
+    builder.commit_from_memory(&mapped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_map_reads_file_contents() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(b"ggml model bytes").unwrap();
+        let mapped = map(file.path()).unwrap();
+        assert_eq!(&mapped[..], b"ggml model bytes");
+    }
+
+    #[test]
+    fn test_map_missing_file() {
+        let err = map(Path::new("/nonexistent/model.bin")).unwrap_err();
+        assert!(matches!(err, TranscribeError::ModelNotFound(_)));
+    }
+}
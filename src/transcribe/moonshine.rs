@@ -118,41 +118,59 @@ impl MoonshineTranscriber {
             .map_err(|e| TranscribeError::InitFailed(format!("Failed to load tokenizer: {}", e)))?;
 
         // Create ONNX sessions.
-        // No GPU EP registration: Moonshine runs on the CPU EP only.
-        // MIGraphX 7.2 can't compile the encoder-decoder `If` op (then/else
-        // sub-graphs have different output shapes), so we keep the engine
-        // on CPU on the AMD-targeted binary.
-        let encoder = Session::builder()
-            .map_err(|e| {
-                TranscribeError::InitFailed(format!("ONNX encoder session builder failed: {}", e))
-            })?
-            .with_intra_threads(threads)
-            .map_err(|e| {
-                TranscribeError::InitFailed(format!("Failed to set encoder threads: {}", e))
-            })?
-            .commit_from_file(&encoder_file)
-            .map_err(|e| {
-                TranscribeError::InitFailed(format!(
-                    "Failed to load Moonshine encoder from {:?}: {}",
-                    encoder_file, e
-                ))
-            })?;
+        // No MIGraphX: 7.2 can't compile the encoder-decoder `If` op
+        // (then/else sub-graphs have different output shapes). AMD GPUs are
+        // used through the ROCm EP in `moonshine-rocm` builds; everything
+        // else runs on the CPU EP.
+        let encoder = super::model_file::commit_session(
+            Session::builder()
+                .map_err(|e| {
+                    TranscribeError::InitFailed(format!(
+                        "ONNX encoder session builder failed: {}",
+                        e
+                    ))
+                })?
+                .with_intra_threads(threads)
+                .map_err(|e| {
+                    TranscribeError::InitFailed(format!("Failed to set encoder threads: {}", e))
+                })
+                .and_then(|builder| {
+                    super::onnx_ep::register_rocm_ep(builder, "Moonshine", "encoder")
+                        .map_err(|e| TranscribeError::InitFailed(format!("encoder EPs: {}", e)))
+                })?,
+            &encoder_file,
+        )
+        .map_err(|e| {
+            TranscribeError::InitFailed(format!(
+                "Failed to load Moonshine encoder from {:?}: {}",
+                encoder_file, e
+            ))
+        })?;
 
-        let decoder = Session::builder()
-            .map_err(|e| {
-                TranscribeError::InitFailed(format!("ONNX decoder session builder failed: {}", e))
-            })?
-            .with_intra_threads(threads)
-            .map_err(|e| {
-                TranscribeError::InitFailed(format!("Failed to set decoder threads: {}", e))
-            })?
-            .commit_from_file(&decoder_file)
-            .map_err(|e| {
-                TranscribeError::InitFailed(format!(
-                    "Failed to load Moonshine decoder from {:?}: {}",
-                    decoder_file, e
-                ))
-            })?;
+        let decoder = super::model_file::commit_session(
+            Session::builder()
+                .map_err(|e| {
+                    TranscribeError::InitFailed(format!(
+                        "ONNX decoder session builder failed: {}",
+                        e
+                    ))
+                })?
+                .with_intra_threads(threads)
+                .map_err(|e| {
+                    TranscribeError::InitFailed(format!("Failed to set decoder threads: {}", e))
+                })
+                .and_then(|builder| {
+                    super::onnx_ep::register_rocm_ep(builder, "Moonshine", "decoder")
+                        .map_err(|e| TranscribeError::InitFailed(format!("decoder EPs: {}", e)))
+                })?,
+            &decoder_file,
+        )
+        .map_err(|e| {
+            TranscribeError::InitFailed(format!(
+                "Failed to load Moonshine decoder from {:?}: {}",
+                decoder_file, e
+            ))
+        })?;
 
         // Cache input/output names from model metadata
         let decoder_input_names: Vec<String> = decoder
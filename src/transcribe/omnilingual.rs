@@ -28,6 +28,8 @@ const SAMPLE_RATE: usize = 16000;
 pub struct OmnilingualTranscriber {
     session: std::sync::Mutex<Session>,
     tokens: HashMap<u32, String>,
+    /// Confidence of the most recent transcription, see [`ctc::ctc_confidence`]
+    last_confidence: std::sync::Mutex<Option<f32>>,
 }
 
 impl OmnilingualTranscriber {
@@ -75,19 +77,23 @@ impl OmnilingualTranscriber {
         // MIGraphX 7.2 produces garbled output and pays a 65s+ first-call
         // compile penalty on this graph, so we keep the engine on CPU on
         // the AMD-targeted binary.
-        let session = Session::builder()
-            .map_err(|e| {
-                TranscribeError::InitFailed(format!("ONNX session builder failed: {}", e))
-            })?
-            .with_intra_threads(threads)
-            .map_err(|e| TranscribeError::InitFailed(format!("Failed to set threads: {}", e)))?
-            .commit_from_file(&model_file)
-            .map_err(|e| {
-                TranscribeError::InitFailed(format!(
-                    "Failed to load Omnilingual model from {:?}: {}",
-                    model_file, e
-                ))
-            })?;
+        let session = super::model_file::commit_session(
+            Session::builder()
+                .map_err(|e| {
+                    TranscribeError::InitFailed(format!("ONNX session builder failed: {}", e))
+                })?
+                .with_intra_threads(threads)
+                .map_err(|e| {
+                    TranscribeError::InitFailed(format!("Failed to set threads: {}", e))
+                })?,
+            &model_file,
+        )
+        .map_err(|e| {
+            TranscribeError::InitFailed(format!(
+                "Failed to load Omnilingual model from {:?}: {}",
+                model_file, e
+            ))
+        })?;
 
         tracing::info!(
             "Omnilingual model loaded in {:.2}s",
@@ -97,6 +103,7 @@ impl OmnilingualTranscriber {
         Ok(Self {
             session: std::sync::Mutex::new(session),
             tokens,
+            last_confidence: std::sync::Mutex::new(None),
         })
     }
 }
@@ -165,9 +172,11 @@ impl Transcriber for OmnilingualTranscriber {
         tracing::debug!("Omnilingual output shape: {:?}", shape_dims);
 
         // CTC output: [batch, time_steps, vocab_size]
+        let mut confidence = None;
         let result = if shape_dims.len() == 3 {
             let time_steps = shape_dims[1] as usize;
             let vocab_size = shape_dims[2] as usize;
+            confidence = ctc::ctc_confidence(logits_data, time_steps, vocab_size, 0);
             let config = ctc::CtcConfig {
                 blank_id: 0,
                 num_metadata_tokens: 0,
@@ -190,6 +199,10 @@ impl Transcriber for OmnilingualTranscriber {
             )));
         };
 
+        if let Ok(mut guard) = self.last_confidence.lock() {
+            *guard = confidence;
+        }
+
         tracing::info!(
             "Omnilingual transcription completed in {:.2}s: {:?}",
             start.elapsed().as_secs_f32(),
@@ -202,6 +215,10 @@ impl Transcriber for OmnilingualTranscriber {
 
         Ok(result)
     }
+
+    fn last_confidence(&self) -> Option<f32> {
+        self.last_confidence.lock().ok().and_then(|g| *g)
+    }
 }
 
 /// Apply mean-variance normalization (instance normalization) to audio samples
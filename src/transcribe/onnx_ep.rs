@@ -7,6 +7,8 @@
 //!
 //! - `onnx-cuda-enabled`     — CUDA EP (NVIDIA)
 //! - `onnx-migraphx-enabled` — MIGraphX EP (AMD)
+//! - `onnx-rocm-enabled`     — ROCm EP (AMD, for engines MIGraphX can't
+//!   compile; see [`register_rocm_ep`])
 //! - `onnx-tensorrt-enabled` — TensorRT EP (NVIDIA, optimized)
 //!
 //! Enabling any per-engine feature like `cohere-migraphx` or
@@ -20,54 +22,523 @@
 //! bottom of the chain — even if every GPU EP fails to register at
 //! runtime (no GPU, missing driver, missing companion .so files), ort
 //! still runs the model on CPU.
+//!
+//! Every GPU EP is additionally gated on a pre-flight check of its
+//! runtime: [`cuda_preflight`] for the NVIDIA EPs (CUDA runtime version
+//! and the configured `cuda_device`) and [`hip_preflight`] for MIGraphX
+//! and ROCm (HIP runtime and a visible AMD GPU). Some failures (CUDA major version
+//! mismatch, an out-of-range device index, no ROCm device) crash ONNX
+//! Runtime instead of returning an error, so they are caught here and the
+//! engine runs on CPU with a logged reason.
+//!
+//! The provider picked for the last session is recorded with
+//! [`record_provider`]; the daemon publishes it for `voxtype status`, and
+//! `voxtype setup gpu --status` shows [`preflight_report`] for this binary.
 
 #[cfg(feature = "onnx-common")]
 use ort::execution_providers::ExecutionProviderDispatch;
 #[cfg(feature = "onnx-common")]
 use ort::session::builder::{BuilderResult, SessionBuilder};
+use std::sync::Mutex;
+
+static ACTIVE_PROVIDER: Mutex<Option<&'static str>> = Mutex::new(None);
+
+/// Remember the execution provider the last ONNX session was built with
+/// ("CUDA", "TensorRT", "MIGraphX", "ROCm" or "CPU")
+pub fn record_provider(provider: &'static str) {
+    if let Ok(mut active) = ACTIVE_PROVIDER.lock() {
+        *active = Some(provider);
+    }
+}
+
+/// Execution provider of the last ONNX session built in this process, if any
+pub fn active_provider() -> Option<&'static str> {
+    ACTIVE_PROVIDER.lock().ok().and_then(|active| *active)
+}
+
+/// Result of one GPU provider's pre-flight check
+#[derive(Debug, Clone)]
+pub struct ProviderCheck {
+    pub provider: &'static str,
+    /// Runtime details when usable, the reason it will be skipped otherwise
+    pub result: Result<String, String>,
+}
+
+/// Pre-flight every GPU execution provider compiled into this binary.
+/// Empty for CPU-only builds.
+#[allow(unused_variables, clippy::vec_init_then_push)]
+pub fn preflight_report(cuda_device: Option<u32>) -> Vec<ProviderCheck> {
+    #[allow(unused_mut)]
+    let mut checks = Vec::new();
+
+    #[cfg(any(feature = "onnx-tensorrt-enabled", feature = "parakeet-tensorrt"))]
+    checks.push(ProviderCheck {
+        provider: "TensorRT",
+        result: cuda_preflight(cuda_device),
+    });
+    #[cfg(any(feature = "onnx-cuda-enabled", feature = "parakeet-cuda"))]
+    checks.push(ProviderCheck {
+        provider: "CUDA",
+        result: cuda_preflight(cuda_device),
+    });
+    #[cfg(any(feature = "onnx-migraphx-enabled", feature = "parakeet-migraphx"))]
+    checks.push(ProviderCheck {
+        provider: "MIGraphX",
+        result: hip_preflight(),
+    });
+    #[cfg(feature = "onnx-rocm-enabled")]
+    checks.push(ProviderCheck {
+        provider: "ROCm",
+        result: hip_preflight(),
+    });
+
+    checks
+}
 
 /// Register GPU EPs onto a session builder.
 ///
 /// `engine_label` and `session_label` are used only for logging
-/// (`"Cohere encoder: registering execution providers [...]"`). Returns
-/// the modified builder; if no EPs are compiled in or registration
-/// fails, falls through unchanged and ort uses the CPU EP.
+/// (`"Cohere encoder: registering execution providers [...]"`).
+/// `cuda_device` selects the GPU for the CUDA and TensorRT EPs (default:
+/// device 0). Returns the modified builder; if no EPs are compiled in or
+/// registration fails, falls through unchanged and ort uses the CPU EP.
 #[cfg(feature = "onnx-common")]
 pub fn register_gpu_eps(
     builder: SessionBuilder,
     engine_label: &str,
     session_label: &str,
+    cuda_device: Option<u32>,
 ) -> BuilderResult {
-    let providers = compiled_providers();
+    let providers = compiled_providers(engine_label, cuda_device);
     if providers.is_empty() {
+        record_provider("CPU");
         return Ok(builder);
     }
     let names: Vec<&'static str> = providers.iter().map(|(n, _)| *n).collect();
     tracing::info!("{engine_label} {session_label}: registering execution providers {names:?}");
+    record_provider(names[0]);
     let dispatches: Vec<_> = providers.into_iter().map(|(_, ep)| ep).collect();
     builder.with_execution_providers(dispatches)
 }
 
+/// Register the ROCm EP onto a session builder, for engines whose graphs
+/// MIGraphX can't compile (Moonshine's `If` op, SenseVoice's Where-op
+/// broadcast). ROCm runs the graph op by op through MIOpen and hipBLAS, so
+/// it has no such restrictions. Without `onnx-rocm-enabled`, or when the
+/// HIP pre-flight fails, the builder is returned unchanged and ort uses
+/// the CPU EP.
+#[cfg(feature = "onnx-common")]
+#[allow(unused_variables)]
+pub fn register_rocm_ep(
+    builder: SessionBuilder,
+    engine_label: &str,
+    session_label: &str,
+) -> BuilderResult {
+    #[cfg(feature = "onnx-rocm-enabled")]
+    if rocm_preflight(engine_label) {
+        use ort::execution_providers::ROCmExecutionProvider;
+        tracing::info!(
+            "{engine_label} {session_label}: registering execution providers [\"ROCm\"]"
+        );
+        record_provider("ROCm");
+        return builder.with_execution_providers([ROCmExecutionProvider::default().build()]);
+    }
+    record_provider("CPU");
+    Ok(builder)
+}
+
 #[cfg(feature = "onnx-common")]
-fn compiled_providers() -> Vec<(&'static str, ExecutionProviderDispatch)> {
+#[allow(unused_variables)]
+fn compiled_providers(
+    engine_label: &str,
+    cuda_device: Option<u32>,
+) -> Vec<(&'static str, ExecutionProviderDispatch)> {
     #[allow(unused_mut)]
     let mut providers: Vec<(&'static str, ExecutionProviderDispatch)> = Vec::new();
 
+    #[cfg(any(feature = "onnx-cuda-enabled", feature = "onnx-tensorrt-enabled"))]
+    let nvidia_ok = nvidia_preflight(engine_label, cuda_device);
+
     #[cfg(feature = "onnx-tensorrt-enabled")]
-    {
-        use ort::execution_providers::{ExecutionProvider, TensorRTExecutionProvider};
-        providers.push(("TensorRT", TensorRTExecutionProvider::default().build()));
+    if nvidia_ok {
+        use ort::execution_providers::TensorRTExecutionProvider;
+        let mut ep = TensorRTExecutionProvider::default();
+        if let Some(device) = cuda_device {
+            ep = ep.with_device_id(device as i32);
+        }
+        providers.push(("TensorRT", ep.build()));
     }
     #[cfg(feature = "onnx-cuda-enabled")]
-    {
-        use ort::execution_providers::{CUDAExecutionProvider, ExecutionProvider};
-        providers.push(("CUDA", CUDAExecutionProvider::default().build()));
+    if nvidia_ok {
+        use ort::execution_providers::CUDAExecutionProvider;
+        let mut ep = CUDAExecutionProvider::default();
+        if let Some(device) = cuda_device {
+            ep = ep.with_device_id(device as i32);
+        }
+        providers.push(("CUDA", ep.build()));
     }
     #[cfg(feature = "onnx-migraphx-enabled")]
-    {
-        use ort::execution_providers::{ExecutionProvider, MIGraphXExecutionProvider};
+    if amd_preflight(engine_label) {
+        use ort::execution_providers::MIGraphXExecutionProvider;
         providers.push(("MIGraphX", MIGraphXExecutionProvider::default().build()));
     }
 
     providers
 }
+
+/// Pre-flight for the NVIDIA EPs: ONNX Runtime must have been built with
+/// CUDA support and the CUDA runtime must pass [`probe_cuda_runtime`].
+#[cfg(all(
+    feature = "onnx-common",
+    any(feature = "onnx-cuda-enabled", feature = "onnx-tensorrt-enabled")
+))]
+fn nvidia_preflight(engine_label: &str, cuda_device: Option<u32>) -> bool {
+    use ort::execution_providers::{CUDAExecutionProvider, ExecutionProvider};
+
+    if !CUDAExecutionProvider::default()
+        .is_available()
+        .unwrap_or(false)
+    {
+        tracing::warn!(
+            "{engine_label}: ONNX Runtime has no CUDA execution provider; running on CPU"
+        );
+        return false;
+    }
+    if !probe_cuda_runtime(cuda_device) {
+        tracing::warn!("{engine_label}: CUDA pre-flight check failed; running on CPU");
+        return false;
+    }
+    true
+}
+
+/// Pre-flight for the MIGraphX EP: ONNX Runtime must have been built with
+/// MIGraphX support and the HIP runtime must pass [`hip_preflight`].
+#[cfg(all(feature = "onnx-common", feature = "onnx-migraphx-enabled"))]
+fn amd_preflight(engine_label: &str) -> bool {
+    use ort::execution_providers::{ExecutionProvider, MIGraphXExecutionProvider};
+
+    if !MIGraphXExecutionProvider::default()
+        .is_available()
+        .unwrap_or(false)
+    {
+        tracing::warn!(
+            "{engine_label}: ONNX Runtime has no MIGraphX execution provider; running on CPU"
+        );
+        return false;
+    }
+    if !probe_hip_runtime() {
+        tracing::warn!("{engine_label}: ROCm pre-flight check failed; running on CPU");
+        return false;
+    }
+    true
+}
+
+/// Pre-flight for the ROCm EP: ONNX Runtime must have been built with
+/// ROCm support and the HIP runtime must pass [`hip_preflight`].
+#[cfg(all(feature = "onnx-common", feature = "onnx-rocm-enabled"))]
+fn rocm_preflight(engine_label: &str) -> bool {
+    use ort::execution_providers::{ExecutionProvider, ROCmExecutionProvider};
+
+    if !ROCmExecutionProvider::default()
+        .is_available()
+        .unwrap_or(false)
+    {
+        tracing::warn!(
+            "{engine_label}: ONNX Runtime has no ROCm execution provider; running on CPU"
+        );
+        return false;
+    }
+    if !probe_hip_runtime() {
+        tracing::warn!("{engine_label}: ROCm pre-flight check failed; running on CPU");
+        return false;
+    }
+    true
+}
+
+/// Probe CUDA runtime availability, version compatibility and the
+/// configured device index, logging the reason when CUDA can't be used.
+///
+/// Returns true if CUDA looks usable, false if it should be skipped.
+#[cfg(any(
+    feature = "onnx-cuda-enabled",
+    feature = "onnx-tensorrt-enabled",
+    feature = "parakeet-cuda",
+    feature = "parakeet-tensorrt"
+))]
+pub fn probe_cuda_runtime(device: Option<u32>) -> bool {
+    match cuda_preflight(device) {
+        Ok(details) => {
+            tracing::info!("Using {details}");
+            true
+        }
+        Err(reason) => {
+            tracing::error!("{reason}");
+            false
+        }
+    }
+}
+
+/// Check the CUDA runtime, its version and the configured device index.
+///
+/// The bundled ONNX Runtime (from the `ort` crate) is built against CUDA 12.x.
+/// If the system has a different major CUDA version, ONNX Runtime will segfault
+/// during EP initialization rather than returning an error. A `cuda_device`
+/// index past the last GPU fails the same way, so it is checked against
+/// `cudaGetDeviceCount` here (on hybrid laptops the NVIDIA dGPU is usually
+/// the only CUDA device, at index 0, whatever the iGPU enumerates as).
+///
+/// Returns a summary such as "CUDA 12.4, device 0 of 1", or why CUDA
+/// must not be initialized.
+#[cfg(any(
+    feature = "onnx-cuda-enabled",
+    feature = "onnx-tensorrt-enabled",
+    feature = "parakeet-cuda",
+    feature = "parakeet-tensorrt"
+))]
+pub fn cuda_preflight(device: Option<u32>) -> Result<String, String> {
+    let handle = dlopen_first(&[c"libcudart.so", c"libcudart.so.12", c"libcudart.so.13"])
+        .ok_or_else(|| {
+            "CUDA runtime library (libcudart.so) not found. \
+         Cannot initialize CUDA execution provider.\n  \
+         Install the CUDA toolkit, or use a CPU backend instead."
+                .to_string()
+        })?;
+
+    // The handle is deliberately not closed once the device count has been
+    // queried: that initializes the CUDA runtime, and unloading it again
+    // before ONNX Runtime opens it would tear down that state mid-flight.
+    let version = check_cuda_version(handle)?;
+    let device = check_cuda_device(handle, device.unwrap_or(0))?;
+    Ok(match version {
+        Some(version) => format!("CUDA {version}, {device}"),
+        None => format!("CUDA (unknown version), {device}"),
+    })
+}
+
+/// Compare the runtime's major version against the bundled ONNX Runtime's.
+/// Returns the version as "major.minor", None if it can't be determined.
+#[cfg(any(
+    feature = "onnx-cuda-enabled",
+    feature = "onnx-tensorrt-enabled",
+    feature = "parakeet-cuda",
+    feature = "parakeet-tensorrt"
+))]
+fn check_cuda_version(handle: *mut libc::c_void) -> Result<Option<String>, String> {
+    let sym = unsafe { libc::dlsym(handle, c"cudaRuntimeGetVersion".as_ptr()) };
+
+    if sym.is_null() {
+        tracing::warn!("Could not find cudaRuntimeGetVersion in CUDA runtime library");
+        // Can't determine version, proceed and hope for the best
+        return Ok(None);
+    }
+
+    // cudaRuntimeGetVersion signature: cudaError_t cudaRuntimeGetVersion(int *runtimeVersion)
+    // Version is encoded as (major * 1000 + minor * 10)
+    type CudaRuntimeGetVersion = unsafe extern "C" fn(*mut i32) -> i32;
+    let get_version: CudaRuntimeGetVersion = unsafe { std::mem::transmute(sym) };
+
+    let mut version: i32 = 0;
+    let result = unsafe { get_version(&mut version) };
+
+    if result != 0 {
+        tracing::warn!("cudaRuntimeGetVersion failed (error code {})", result);
+        return Ok(None);
+    }
+
+    let major = version / 1000;
+    let minor = (version % 1000) / 10;
+    tracing::info!("Detected CUDA runtime version: {}.{}", major, minor);
+
+    // ort 2.0.0-rc.12 picks the cu12 or cu13 prebuilt at compile time from
+    // ORT_CUDA_VERSION (see ort-sys/build/download/resolve.rs). build.rs
+    // mirrors that selection into VOXTYPE_BUILD_CUDA_MAJOR so this probe
+    // accepts only the runtime version the bundled EP can actually talk to.
+    // A mismatched major would crash ort's CUDA EP during initialization.
+    //
+    // Voxtype ships separate voxtype-onnx-cuda-12 and voxtype-onnx-cuda-13
+    // binaries. `voxtype setup gpu --enable` symlinks voxtype-onnx-cuda to
+    // whichever variant matches the host's CUDA runtime.
+    //
+    // Load-dynamic builds skip this check: there is no bundled ORT to
+    // mismatch — the binary dlopens whatever libonnxruntime the system
+    // provides, and ORT does its own kernel-image lookup against the host
+    // CUDA at session-create time. v0.7.3 cuda-13 forgot to set
+    // ORT_CUDA_VERSION=13 in its Dockerfile so VOXTYPE_BUILD_CUDA_MAJOR
+    // baked in the default ("12"), and this probe falsely rejected
+    // Blackwell hosts with "this binary's bundled ONNX Runtime requires
+    // CUDA 12.x" (#386). The Dockerfile fix sets the env var properly,
+    // and gating the check on `not(feature = "onnx-load-dynamic")`
+    // closes the design hole so future load-dynamic builds don't depend
+    // on remembering to set it.
+    #[cfg(not(feature = "onnx-load-dynamic"))]
+    {
+        const EXPECTED_CUDA_MAJOR: i32 = match env!("VOXTYPE_BUILD_CUDA_MAJOR").as_bytes() {
+            b"13" => 13,
+            _ => 12,
+        };
+
+        if major != EXPECTED_CUDA_MAJOR {
+            return Err(format!(
+                "CUDA version mismatch: found CUDA {major}.{minor}, but this binary's \
+                 bundled ONNX Runtime requires CUDA {EXPECTED_CUDA_MAJOR}.x. \
+                 Continuing would crash the process.\n  \
+                 Options:\n  \
+                 1. Install the matching voxtype-onnx-cuda-{EXPECTED_CUDA_MAJOR} package\n  \
+                 2. Switch to voxtype-onnx-cuda-{major} for your CUDA version (`voxtype setup gpu --enable` \
+                 auto-detects and points the symlink at the right one)\n  \
+                 3. Build from source with --features parakeet-load-dynamic to link \
+                 against your system's ONNX Runtime instead",
+            ));
+        }
+    }
+
+    Ok(Some(format!("{major}.{minor}")))
+}
+
+/// Check that `device` is a valid CUDA device index.
+#[cfg(any(
+    feature = "onnx-cuda-enabled",
+    feature = "onnx-tensorrt-enabled",
+    feature = "parakeet-cuda",
+    feature = "parakeet-tensorrt"
+))]
+fn check_cuda_device(handle: *mut libc::c_void, device: u32) -> Result<String, String> {
+    let sym = unsafe { libc::dlsym(handle, c"cudaGetDeviceCount".as_ptr()) };
+
+    if sym.is_null() {
+        tracing::warn!("Could not find cudaGetDeviceCount; cannot verify cuda_device = {device}");
+        return Ok(format!("device {device}"));
+    }
+
+    // cudaGetDeviceCount signature: cudaError_t cudaGetDeviceCount(int *count)
+    type CudaGetDeviceCount = unsafe extern "C" fn(*mut i32) -> i32;
+    let get_count: CudaGetDeviceCount = unsafe { std::mem::transmute(sym) };
+
+    let mut count: i32 = 0;
+    let result = unsafe { get_count(&mut count) };
+
+    if result != 0 || count <= 0 {
+        return Err(format!(
+            "No usable CUDA device found (cudaGetDeviceCount error code {result}, {count} devices). \
+             Check that the NVIDIA driver is loaded (`nvidia-smi`)."
+        ));
+    }
+
+    if device >= count as u32 {
+        return Err(format!(
+            "cuda_device = {device}, but only {count} CUDA device(s) are visible \
+             (valid: 0-{}). List them with `nvidia-smi -L`.",
+            count - 1
+        ));
+    }
+
+    Ok(format!("device {device} of {count}"))
+}
+
+/// Probe the HIP runtime the MIGraphX EP runs on, logging the reason when
+/// it can't be used.
+///
+/// Returns true if ROCm looks usable, false if it should be skipped.
+#[cfg(any(
+    feature = "onnx-migraphx-enabled",
+    feature = "onnx-rocm-enabled",
+    feature = "parakeet-migraphx"
+))]
+pub fn probe_hip_runtime() -> bool {
+    match hip_preflight() {
+        Ok(details) => {
+            tracing::info!("Using {details}");
+            true
+        }
+        Err(reason) => {
+            tracing::error!("{reason}");
+            false
+        }
+    }
+}
+
+/// Check the HIP runtime and that it can see an AMD GPU.
+///
+/// Without a usable ROCm device (amdgpu driver not loaded, user not in the
+/// `render` group, ROCm missing) the MIGraphX and ROCm EPs can crash
+/// during session creation instead of failing to register, so it is
+/// checked here.
+///
+/// Returns a summary such as "ROCm HIP 6.3, 1 device(s)", or why the AMD
+/// EPs must not be initialized.
+#[cfg(any(
+    feature = "onnx-migraphx-enabled",
+    feature = "onnx-rocm-enabled",
+    feature = "parakeet-migraphx"
+))]
+pub fn hip_preflight() -> Result<String, String> {
+    let handle = dlopen_first(&[c"libamdhip64.so", c"libamdhip64.so.6", c"libamdhip64.so.7"])
+        .ok_or_else(|| {
+            "HIP runtime library (libamdhip64.so) not found. \
+             Cannot initialize the AMD GPU execution provider.\n  \
+             Install ROCm, or use a CPU backend instead."
+                .to_string()
+        })?;
+
+    // hipRuntimeGetVersion: hipError_t hipRuntimeGetVersion(int *runtimeVersion)
+    // Version is encoded as (major * 10000000 + minor * 100000 + patch)
+    type HipGetInt = unsafe extern "C" fn(*mut i32) -> i32;
+    let call = |name: &std::ffi::CStr| -> Option<(i32, i32)> {
+        let sym = unsafe { libc::dlsym(handle, name.as_ptr()) };
+        if sym.is_null() {
+            return None;
+        }
+        let f: HipGetInt = unsafe { std::mem::transmute(sym) };
+        let mut value: i32 = 0;
+        let result = unsafe { f(&mut value) };
+        Some((result, value))
+    };
+
+    let version = match call(c"hipRuntimeGetVersion") {
+        Some((0, v)) => format!("{}.{}", v / 10_000_000, (v / 100_000) % 100),
+        _ => "unknown version".to_string(),
+    };
+
+    match call(c"hipGetDeviceCount") {
+        Some((0, count)) if count > 0 => Ok(format!("ROCm HIP {version}, {count} device(s)")),
+        Some((result, count)) => Err(format!(
+            "No usable ROCm device found (hipGetDeviceCount error code {result}, {count} devices). \
+             Check that the amdgpu driver is loaded and your user is in the `render` and \
+             `video` groups (`rocminfo` should list your GPU)."
+        )),
+        None => {
+            tracing::warn!("Could not find hipGetDeviceCount; cannot verify a ROCm device");
+            Ok(format!("ROCm HIP {version}"))
+        }
+    }
+}
+
+/// dlopen the first library in `names` that loads
+#[cfg(any(
+    feature = "onnx-cuda-enabled",
+    feature = "onnx-tensorrt-enabled",
+    feature = "onnx-migraphx-enabled",
+    feature = "onnx-rocm-enabled",
+    feature = "parakeet-cuda",
+    feature = "parakeet-tensorrt",
+    feature = "parakeet-migraphx"
+))]
+fn dlopen_first(names: &[&std::ffi::CStr]) -> Option<*mut libc::c_void> {
+    names.iter().find_map(|name| {
+        let handle = unsafe { libc::dlopen(name.as_ptr(), libc::RTLD_LAZY) };
+        (!handle.is_null()).then_some(handle)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_provider() {
+        record_provider("CUDA");
+        assert_eq!(active_provider(), Some("CUDA"));
+        record_provider("CPU");
+        assert_eq!(active_provider(), Some("CPU"));
+    }
+}
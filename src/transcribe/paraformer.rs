@@ -80,19 +80,23 @@ impl ParaformerTranscriber {
         // No GPU EP registration: Paraformer runs on the CPU EP only.
         // MIGraphX 7.2 segfaults during model load on this graph; we
         // keep the engine on CPU on the AMD-targeted binary.
-        let session = Session::builder()
-            .map_err(|e| {
-                TranscribeError::InitFailed(format!("ONNX session builder failed: {}", e))
-            })?
-            .with_intra_threads(threads)
-            .map_err(|e| TranscribeError::InitFailed(format!("Failed to set threads: {}", e)))?
-            .commit_from_file(&model_file)
-            .map_err(|e| {
-                TranscribeError::InitFailed(format!(
-                    "Failed to load Paraformer model from {:?}: {}",
-                    model_file, e
-                ))
-            })?;
+        let session = super::model_file::commit_session(
+            Session::builder()
+                .map_err(|e| {
+                    TranscribeError::InitFailed(format!("ONNX session builder failed: {}", e))
+                })?
+                .with_intra_threads(threads)
+                .map_err(|e| {
+                    TranscribeError::InitFailed(format!("Failed to set threads: {}", e))
+                })?,
+            &model_file,
+        )
+        .map_err(|e| {
+            TranscribeError::InitFailed(format!(
+                "Failed to load Paraformer model from {:?}: {}",
+                model_file, e
+            ))
+        })?;
 
         // Read CMVN stats from am.mvn (Kaldi binary matrix)
         let mvn_path = model_dir.join("am.mvn");
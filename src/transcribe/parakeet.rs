@@ -6,7 +6,13 @@
 //! Supports two model architectures:
 //! - CTC (Connectionist Temporal Classification): faster, character-level output
 //! - TDT (Token-Duration-Transducer): recommended, proper punctuation and word boundaries
+//!
+//! With `[transcribe.hotwords]` set and the `parakeet-hotwords` feature
+//! built in, TDT models are decoded by [`super::parakeet_tdt`] instead, since
+//! parakeet-rs has no way to bias its search.
 
+#[cfg(feature = "parakeet-hotwords")]
+use super::parakeet_tdt::TdtDecoder;
 use super::{TimedSegment, Transcriber};
 use crate::config::{ParakeetConfig, ParakeetModelType};
 use crate::error::TranscribeError;
@@ -19,6 +25,7 @@ use parakeet_rs::ExecutionProvider;
 use parakeet_rs::{
     ExecutionConfig, Parakeet, ParakeetTDT, Transcriber as ParakeetTranscriberTrait,
 };
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Mutex;
 
@@ -28,6 +35,9 @@ enum ParakeetModel {
     Ctc(Mutex<Parakeet>),
     /// TDT model (token-level, better quality output)
     Tdt(Mutex<ParakeetTDT>),
+    /// TDT model decoded by voxtype with hotword boosts
+    #[cfg(feature = "parakeet-hotwords")]
+    TdtHotwords(TdtDecoder),
 }
 
 /// Parakeet-based transcriber using ONNX Runtime
@@ -40,7 +50,10 @@ pub struct ParakeetTranscriber {
 
 impl ParakeetTranscriber {
     /// Create a new Parakeet transcriber
-    pub fn new(config: &ParakeetConfig) -> Result<Self, TranscribeError> {
+    pub fn new(
+        config: &ParakeetConfig,
+        hotwords: &HashMap<String, f32>,
+    ) -> Result<Self, TranscribeError> {
         let model_path = resolve_model_path(&config.model)?;
 
         // Determine model type: use config override or auto-detect from directory
@@ -56,17 +69,23 @@ impl ParakeetTranscriber {
         let start = std::time::Instant::now();
 
         // Configure execution provider based on feature flags
-        let exec_config = build_execution_config();
+        let exec_config = build_execution_config(config.cuda_device);
+
+        let hotword_model = load_hotword_model(model_type, &model_path, hotwords, config)?;
 
-        let model = match model_type {
-            ParakeetModelType::Ctc => {
+        let model = match (model_type, hotword_model) {
+            (_, Some(model)) => {
+                tracing::info!("Hotword biasing enabled");
+                model
+            }
+            (ParakeetModelType::Ctc, None) => {
                 let parakeet =
                     Parakeet::from_pretrained(&model_path, exec_config).map_err(|e| {
                         TranscribeError::InitFailed(format!("Parakeet CTC init failed: {}", e))
                     })?;
                 ParakeetModel::Ctc(Mutex::new(parakeet))
             }
-            ParakeetModelType::Tdt => {
+            (ParakeetModelType::Tdt, None) => {
                 let parakeet =
                     ParakeetTDT::from_pretrained(&model_path, exec_config).map_err(|e| {
                         TranscribeError::InitFailed(format!("Parakeet TDT init failed: {}", e))
@@ -85,6 +104,44 @@ impl ParakeetTranscriber {
     }
 }
 
+/// voxtype's own TDT decoder when hotwords are set and the model is TDT
+#[cfg(feature = "parakeet-hotwords")]
+fn load_hotword_model(
+    model_type: ParakeetModelType,
+    model_path: &std::path::Path,
+    hotwords: &HashMap<String, f32>,
+    config: &ParakeetConfig,
+) -> Result<Option<ParakeetModel>, TranscribeError> {
+    match model_type {
+        ParakeetModelType::Tdt if !hotwords.is_empty() => {
+            Ok(TdtDecoder::new(model_path, hotwords, config.cuda_device)?
+                .map(ParakeetModel::TdtHotwords))
+        }
+        ParakeetModelType::Ctc if !hotwords.is_empty() => {
+            tracing::warn!(
+                "[transcribe.hotwords] is only applied to Parakeet TDT models, ignoring"
+            );
+            Ok(None)
+        }
+        _ => Ok(None),
+    }
+}
+
+#[cfg(not(feature = "parakeet-hotwords"))]
+fn load_hotword_model(
+    _model_type: ParakeetModelType,
+    _model_path: &std::path::Path,
+    hotwords: &HashMap<String, f32>,
+    _config: &ParakeetConfig,
+) -> Result<Option<ParakeetModel>, TranscribeError> {
+    if !hotwords.is_empty() {
+        tracing::warn!(
+            "[transcribe.hotwords] for Parakeet needs a build with the parakeet-hotwords feature, ignoring"
+        );
+    }
+    Ok(None)
+}
+
 impl Transcriber for ParakeetTranscriber {
     fn transcribe(&self, samples: &[f32]) -> Result<String, TranscribeError> {
         if samples.is_empty() {
@@ -152,6 +209,8 @@ impl Transcriber for ParakeetTranscriber {
 
                 result.text.trim().to_string()
             }
+            #[cfg(feature = "parakeet-hotwords")]
+            ParakeetModel::TdtHotwords(decoder) => decoder.transcribe(samples)?.text,
         };
 
         tracing::info!(
@@ -171,7 +230,7 @@ impl Transcriber for ParakeetTranscriber {
     fn transcribe_timed(&self, samples: &[f32]) -> Result<Vec<TimedSegment>, TranscribeError> {
         let start = std::time::Instant::now();
 
-        let result = match &self.model {
+        let (text, token_times) = match &self.model {
             ParakeetModel::Ctc(parakeet) => {
                 let mut parakeet = parakeet.lock().map_err(|e| {
                     TranscribeError::InferenceFailed(format!(
@@ -179,14 +238,17 @@ impl Transcriber for ParakeetTranscriber {
                         e
                     ))
                 })?;
-                parakeet
+                let result = parakeet
                     .transcribe_samples(samples.to_vec(), 16000, 1, None)
                     .map_err(|e| {
                         TranscribeError::InferenceFailed(format!(
                             "Parakeet CTC inference failed: {}",
                             e
                         ))
-                    })?
+                    })?;
+                let times: Vec<(f32, f32)> =
+                    result.tokens.iter().map(|t| (t.start, t.end)).collect();
+                (result.text, times)
             }
             ParakeetModel::Tdt(parakeet) => {
                 let mut parakeet = parakeet.lock().map_err(|e| {
@@ -195,250 +257,214 @@ impl Transcriber for ParakeetTranscriber {
                         e
                     ))
                 })?;
-                parakeet
+                let result = parakeet
                     .transcribe_samples(samples.to_vec(), 16000, 1, None)
                     .map_err(|e| {
                         TranscribeError::InferenceFailed(format!(
                             "Parakeet TDT inference failed: {}",
                             e
                         ))
-                    })?
+                    })?;
+                let times: Vec<(f32, f32)> =
+                    result.tokens.iter().map(|t| (t.start, t.end)).collect();
+                (result.text, times)
+            }
+            #[cfg(feature = "parakeet-hotwords")]
+            ParakeetModel::TdtHotwords(decoder) => {
+                let output = decoder.transcribe(samples)?;
+                (output.text, output.token_times)
             }
         };
 
-        // Split the full text on sentence boundaries (.!?) and map each sentence
-        // back to token timestamps. result.text is properly assembled by parakeet-rs;
-        // individual tokens are subword pieces that can't be naively joined.
-        let full_text = result.text.trim();
-        let tokens = &result.tokens;
+        let segments = sentence_segments(&text, &token_times);
 
-        if full_text.is_empty() || tokens.is_empty() {
-            return Ok(vec![]);
-        }
+        tracing::info!(
+            "Parakeet {:?} timed transcription completed in {:.2}s: {} segments",
+            self.model_type,
+            start.elapsed().as_secs_f32(),
+            segments.len()
+        );
 
-        // Split text into sentences on .!? boundaries
-        let mut sentences: Vec<String> = Vec::new();
-        let mut current = String::new();
-        for ch in full_text.chars() {
-            current.push(ch);
-            if ch == '.' || ch == '!' || ch == '?' {
-                let trimmed = current.trim().to_string();
-                if !trimmed.is_empty() {
-                    sentences.push(trimmed);
-                }
-                current = String::new();
-            }
-        }
-        if !current.trim().is_empty() {
-            sentences.push(current.trim().to_string());
-        }
+        Ok(segments)
+    }
+}
 
-        // Map sentences to timestamps by distributing tokens proportionally.
-        // Each sentence gets the time span of its corresponding token range.
-        let total_sentences = sentences.len();
-        let total_tokens = tokens.len();
-        let tokens_per_sentence = if total_sentences > 0 {
-            total_tokens / total_sentences
-        } else {
-            total_tokens
-        };
+/// Split `text` into sentences on `.!?` boundaries and give each the time
+/// span of its share of the tokens. The text is assembled by the decoder;
+/// individual tokens are subword pieces that can't be naively joined.
+fn sentence_segments(text: &str, token_times: &[(f32, f32)]) -> Vec<TimedSegment> {
+    let full_text = text.trim();
+    if full_text.is_empty() || token_times.is_empty() {
+        return vec![];
+    }
 
-        let mut segments = Vec::new();
-        let mut token_idx = 0;
+    // Split text into sentences on .!? boundaries
+    let mut sentences: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for ch in full_text.chars() {
+        current.push(ch);
+        if ch == '.' || ch == '!' || ch == '?' {
+            let trimmed = current.trim().to_string();
+            if !trimmed.is_empty() {
+                sentences.push(trimmed);
+            }
+            current = String::new();
+        }
+    }
+    if !current.trim().is_empty() {
+        sentences.push(current.trim().to_string());
+    }
 
-        for (i, sentence) in sentences.iter().enumerate() {
-            let start_secs = if token_idx < tokens.len() {
-                tokens[token_idx].start
-            } else {
-                tokens.last().map(|t| t.end).unwrap_or(0.0)
-            };
+    // Map sentences to timestamps by distributing tokens proportionally.
+    // Each sentence gets the time span of its corresponding token range.
+    let total_sentences = sentences.len();
+    let total_tokens = token_times.len();
+    let tokens_per_sentence = if total_sentences > 0 {
+        total_tokens / total_sentences
+    } else {
+        total_tokens
+    };
 
-            // Last sentence gets all remaining tokens
-            let end_token_idx = if i == total_sentences - 1 {
-                total_tokens
-            } else {
-                (token_idx + tokens_per_sentence).min(total_tokens)
-            };
+    let mut segments = Vec::new();
+    let mut token_idx = 0;
 
-            let end_secs = if end_token_idx > 0 && end_token_idx <= tokens.len() {
-                tokens[end_token_idx - 1].end
-            } else {
-                start_secs
-            };
+    for (i, sentence) in sentences.iter().enumerate() {
+        let start_secs = if token_idx < token_times.len() {
+            token_times[token_idx].0
+        } else {
+            token_times.last().map(|t| t.1).unwrap_or(0.0)
+        };
 
-            segments.push(TimedSegment {
-                text: sentence.clone(),
-                start_secs,
-                end_secs,
-            });
+        // Last sentence gets all remaining tokens
+        let end_token_idx = if i == total_sentences - 1 {
+            total_tokens
+        } else {
+            (token_idx + tokens_per_sentence).min(total_tokens)
+        };
 
-            token_idx = end_token_idx;
-        }
+        let end_secs = if end_token_idx > 0 && end_token_idx <= token_times.len() {
+            token_times[end_token_idx - 1].1
+        } else {
+            start_secs
+        };
 
-        tracing::info!(
-            "Parakeet {:?} timed transcription completed in {:.2}s: {} segments",
-            self.model_type,
-            start.elapsed().as_secs_f32(),
-            segments.len()
-        );
+        segments.push(TimedSegment {
+            text: sentence.clone(),
+            start_secs,
+            end_secs,
+        });
 
-        Ok(segments)
+        token_idx = end_token_idx;
     }
+
+    segments
 }
 
-/// Build execution config based on compile-time feature flags
-pub(super) fn build_execution_config() -> Option<ExecutionConfig> {
+/// Build execution config based on compile-time feature flags.
+/// `cuda_device` selects the GPU for the CUDA and TensorRT providers.
+#[allow(unused_variables)]
+pub(super) fn build_execution_config(cuda_device: Option<u32>) -> Option<ExecutionConfig> {
     #[cfg(feature = "parakeet-cuda")]
     {
-        if probe_cuda_runtime() {
+        if super::onnx_ep::probe_cuda_runtime(cuda_device) {
             tracing::info!("Configuring CUDA execution provider for NVIDIA GPU acceleration");
-            return Some(ExecutionConfig::new().with_execution_provider(ExecutionProvider::Cuda));
+            super::onnx_ep::record_provider("CUDA");
+            let Some(device) = cuda_device else {
+                return Some(
+                    ExecutionConfig::new().with_execution_provider(ExecutionProvider::Cuda),
+                );
+            };
+            // parakeet-rs has no device option, so register the EP chain
+            // ourselves with the same CPU fallback it would use.
+            return Some(
+                ExecutionConfig::new().with_custom_configure(move |builder| {
+                    Ok(builder.with_execution_providers([
+                        ort::ep::CUDA::default()
+                            .with_device_id(device as i32)
+                            .build(),
+                        ort::ep::CPU::default().build().error_on_failure(),
+                    ])?)
+                }),
+            );
         }
         tracing::warn!("CUDA not available or incompatible, falling back to CPU inference");
+        super::onnx_ep::record_provider("CPU");
         return None;
     }
 
     #[cfg(feature = "parakeet-tensorrt")]
     {
-        if probe_cuda_runtime() {
+        if super::onnx_ep::probe_cuda_runtime(cuda_device) {
             tracing::info!("Configuring TensorRT execution provider for NVIDIA GPU acceleration");
+            super::onnx_ep::record_provider("TensorRT");
+            let Some(device) = cuda_device else {
+                return Some(
+                    ExecutionConfig::new().with_execution_provider(ExecutionProvider::TensorRT),
+                );
+            };
             return Some(
-                ExecutionConfig::new().with_execution_provider(ExecutionProvider::TensorRT),
+                ExecutionConfig::new().with_custom_configure(move |builder| {
+                    Ok(builder.with_execution_providers([
+                        ort::ep::TensorRT::default()
+                            .with_device_id(device as i32)
+                            .build(),
+                        ort::ep::CPU::default().build().error_on_failure(),
+                    ])?)
+                }),
             );
         }
         tracing::warn!("CUDA not available or incompatible, falling back to CPU inference");
+        super::onnx_ep::record_provider("CPU");
         return None;
     }
 
     #[cfg(feature = "parakeet-migraphx")]
     {
-        tracing::info!("Configuring MIGraphX execution provider for AMD GPU acceleration");
-        return Some(ExecutionConfig::new().with_execution_provider(ExecutionProvider::MIGraphX));
+        if super::onnx_ep::probe_hip_runtime() {
+            tracing::info!("Configuring MIGraphX execution provider for AMD GPU acceleration");
+            super::onnx_ep::record_provider("MIGraphX");
+            return Some(
+                ExecutionConfig::new().with_execution_provider(ExecutionProvider::MIGraphX),
+            );
+        }
+        tracing::warn!("ROCm not available or incompatible, falling back to CPU inference");
+        super::onnx_ep::record_provider("CPU");
+        return None;
+    }
+
+    // The ROCm EP is only reached through `onnx-rocm`; parakeet-rs has no
+    // option for it, so the chain is registered here like a CUDA device.
+    #[cfg(feature = "onnx-rocm-enabled")]
+    {
+        if super::onnx_ep::probe_hip_runtime() {
+            tracing::info!("Configuring ROCm execution provider for AMD GPU acceleration");
+            super::onnx_ep::record_provider("ROCm");
+            return Some(
+                ExecutionConfig::new().with_custom_configure(move |builder| {
+                    Ok(builder.with_execution_providers([
+                        ort::ep::ROCm::default().build(),
+                        ort::ep::CPU::default().build().error_on_failure(),
+                    ])?)
+                }),
+            );
+        }
+        tracing::warn!("ROCm not available or incompatible, falling back to CPU inference");
+        super::onnx_ep::record_provider("CPU");
+        return None;
     }
 
     #[cfg(not(any(
         feature = "parakeet-cuda",
         feature = "parakeet-tensorrt",
-        feature = "parakeet-migraphx"
+        feature = "parakeet-migraphx",
+        feature = "onnx-rocm-enabled"
     )))]
     {
+        super::onnx_ep::record_provider("CPU");
         None
     }
 }
 
-/// Probe CUDA runtime availability and version compatibility.
-///
-/// The bundled ONNX Runtime (from the `ort` crate) is built against CUDA 12.x.
-/// If the system has a different major CUDA version, ONNX Runtime will segfault
-/// during EP initialization rather than returning an error.
-///
-/// Returns true if CUDA looks compatible, false if it should be skipped.
-#[cfg(any(feature = "parakeet-cuda", feature = "parakeet-tensorrt"))]
-fn probe_cuda_runtime() -> bool {
-    // Null-terminated library names to try, in order of preference
-    let lib_names: &[&[u8]] = &[
-        b"libcudart.so\0",
-        b"libcudart.so.12\0",
-        b"libcudart.so.13\0",
-    ];
-
-    let mut handle = std::ptr::null_mut();
-    for name in lib_names {
-        handle = unsafe { libc::dlopen(name.as_ptr() as *const libc::c_char, libc::RTLD_LAZY) };
-        if !handle.is_null() {
-            break;
-        }
-    }
-
-    if handle.is_null() {
-        tracing::error!(
-            "CUDA runtime library (libcudart.so) not found. \
-             Cannot initialize CUDA execution provider.\n  \
-             Install the CUDA toolkit, or use a CPU backend instead."
-        );
-        return false;
-    }
-
-    let sym = unsafe {
-        libc::dlsym(
-            handle,
-            b"cudaRuntimeGetVersion\0".as_ptr() as *const libc::c_char,
-        )
-    };
-
-    if sym.is_null() {
-        tracing::warn!("Could not find cudaRuntimeGetVersion in CUDA runtime library");
-        unsafe { libc::dlclose(handle) };
-        // Can't determine version, proceed and hope for the best
-        return true;
-    }
-
-    // cudaRuntimeGetVersion signature: cudaError_t cudaRuntimeGetVersion(int *runtimeVersion)
-    // Version is encoded as (major * 1000 + minor * 10)
-    type CudaRuntimeGetVersion = unsafe extern "C" fn(*mut i32) -> i32;
-    let get_version: CudaRuntimeGetVersion = unsafe { std::mem::transmute(sym) };
-
-    let mut version: i32 = 0;
-    let result = unsafe { get_version(&mut version) };
-    unsafe { libc::dlclose(handle) };
-
-    if result != 0 {
-        tracing::warn!("cudaRuntimeGetVersion failed (error code {})", result);
-        return true;
-    }
-
-    let major = version / 1000;
-    let minor = (version % 1000) / 10;
-    tracing::info!("Detected CUDA runtime version: {}.{}", major, minor);
-
-    // ort 2.0.0-rc.12 picks the cu12 or cu13 prebuilt at compile time from
-    // ORT_CUDA_VERSION (see ort-sys/build/download/resolve.rs). build.rs
-    // mirrors that selection into VOXTYPE_BUILD_CUDA_MAJOR so this probe
-    // accepts only the runtime version the bundled EP can actually talk to.
-    // A mismatched major would crash ort's CUDA EP during initialization.
-    //
-    // Voxtype ships separate voxtype-onnx-cuda-12 and voxtype-onnx-cuda-13
-    // binaries. `voxtype setup gpu --enable` symlinks voxtype-onnx-cuda to
-    // whichever variant matches the host's CUDA runtime.
-    //
-    // Load-dynamic builds skip this check: there is no bundled ORT to
-    // mismatch — the binary dlopens whatever libonnxruntime the system
-    // provides, and ORT does its own kernel-image lookup against the host
-    // CUDA at session-create time. v0.7.3 cuda-13 forgot to set
-    // ORT_CUDA_VERSION=13 in its Dockerfile so VOXTYPE_BUILD_CUDA_MAJOR
-    // baked in the default ("12"), and this probe falsely rejected
-    // Blackwell hosts with "this binary's bundled ONNX Runtime requires
-    // CUDA 12.x" (#386). The Dockerfile fix sets the env var properly,
-    // and gating the check on `not(feature = "onnx-load-dynamic")`
-    // closes the design hole so future load-dynamic builds don't depend
-    // on remembering to set it.
-    #[cfg(not(feature = "onnx-load-dynamic"))]
-    {
-        const EXPECTED_CUDA_MAJOR: i32 = match env!("VOXTYPE_BUILD_CUDA_MAJOR").as_bytes() {
-            b"13" => 13,
-            _ => 12,
-        };
-
-        if major != EXPECTED_CUDA_MAJOR {
-            tracing::error!(
-                "CUDA version mismatch: found CUDA {major}.{minor}, but this binary's \
-                 bundled ONNX Runtime requires CUDA {EXPECTED_CUDA_MAJOR}.x. \
-                 Continuing would crash the process.\n  \
-                 Options:\n  \
-                 1. Install the matching voxtype-onnx-cuda-{EXPECTED_CUDA_MAJOR} package\n  \
-                 2. Switch to voxtype-onnx-cuda-{} for your CUDA version (`voxtype setup gpu --enable` \
-                 auto-detects and points the symlink at the right one)\n  \
-                 3. Build from source with --features parakeet-load-dynamic to link \
-                 against your system's ONNX Runtime instead",
-                major,
-            );
-            return false;
-        }
-    }
-
-    true
-}
-
 /// Auto-detect model type from directory structure
 ///
 /// TDT models have: encoder-model.onnx, decoder_joint-model.onnx, vocab.txt
@@ -618,6 +644,18 @@ mod tests {
         assert_eq!(resolved.unwrap(), model_path);
     }
 
+    #[test]
+    fn test_sentence_segments_split_token_spans() {
+        let times = [(0.0, 0.4), (0.4, 0.8), (1.0, 1.4), (1.4, 2.0)];
+        let segments = sentence_segments(" Hello there. Bye now ", &times);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].text, "Hello there.");
+        assert_eq!((segments[0].start_secs, segments[0].end_secs), (0.0, 0.8));
+        assert_eq!(segments[1].text, "Bye now");
+        assert_eq!((segments[1].start_secs, segments[1].end_secs), (1.0, 2.0));
+        assert!(sentence_segments("", &times).is_empty());
+    }
+
     #[test]
     fn test_resolve_model_path_not_found() {
         let result = resolve_model_path("/nonexistent/path/to/model");
@@ -99,7 +99,7 @@ impl ParakeetStreamingTranscriber {
         );
         let start = std::time::Instant::now();
 
-        let exec_config = build_execution_config();
+        let exec_config = build_execution_config(config.cuda_device);
         let handle = ParakeetUnifiedHandle::load(&model_path, exec_config).map_err(|e| {
             TranscribeError::InitFailed(format!(
                 "Parakeet streaming (ParakeetUnified) init failed: {}\n\n\
@@ -0,0 +1,555 @@
+//! Parakeet TDT decoding with hotword boosts
+//!
+//! parakeet-rs runs its transducer search internally and never exposes the
+//! joint network's logits, so `[transcribe.hotwords]` cannot be applied
+//! through it. When hotwords are configured for a TDT model, the same ONNX
+//! export is run here directly on ONNX Runtime and the greedy search is
+//! done in voxtype, adding the boosts to the token logits before each
+//! argmax (see [`super::hotwords`]).
+//!
+//! Pipeline: Audio (f32, 16kHz) -> NeMo log-mel (128-dim, per-feature
+//! normalized) -> encoder -> TDT greedy search (decoder + joint) -> tokens
+//!
+//! The joint output holds the token logits followed by one logit per entry
+//! in [`DURATIONS`]. After each step the search skips ahead by the predicted
+//! number of encoder frames instead of always advancing by one.
+//!
+//! Model files: encoder-model.onnx, decoder_joint-model.onnx (int8 variants
+//! accepted), vocab.txt
+
+use super::cohere_fbank::CohereFbank;
+use super::ctc;
+use super::hotwords::{HotwordBias, HotwordState};
+use crate::error::TranscribeError;
+use ort::session::{Session, SessionInputValue};
+use ort::value::{DynValue, Tensor, ValueType};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Frame durations the TDT joint predicts, in encoder frames
+const DURATIONS: [usize; 5] = [0, 1, 2, 3, 4];
+
+/// Tokens emitted on one encoder frame before the search is forced ahead
+const MAX_TOKENS_PER_STEP: usize = 10;
+
+/// Seconds per encoder frame (10ms feature hop, 8x subsampling)
+const FRAME_SECS: f32 = 0.08;
+
+/// Parakeet TDT model run directly on ONNX Runtime
+pub struct TdtDecoder {
+    encoder: Mutex<Session>,
+    decoder_joint: Mutex<Session>,
+    tokens: HashMap<u32, String>,
+    hotwords: HotwordBias,
+    fbank: CohereFbank,
+    blank_id: i64,
+    /// Token logits at the start of the joint output (blank included)
+    vocab_size: usize,
+    /// Prediction network states, paired with the output that returns their update
+    states: Vec<StateSpec>,
+}
+
+/// One recurrent state of the prediction network
+struct StateSpec {
+    input: String,
+    output: String,
+    shape: Vec<i64>,
+}
+
+/// A token emitted by the search, with the encoder frames it covers
+#[derive(Debug, PartialEq)]
+struct Emission {
+    token: i64,
+    frame: usize,
+    frames: usize,
+}
+
+/// Decoded text with the start and end time of each emitted token
+pub struct TdtOutput {
+    pub text: String,
+    pub token_times: Vec<(f32, f32)>,
+}
+
+impl TdtDecoder {
+    /// Load the TDT model in `model_dir` for decoding with `hotwords`.
+    /// Returns `Ok(None)` when none of the hotwords can be spelled with the
+    /// model's vocabulary, so the caller can keep using parakeet-rs.
+    pub fn new(
+        model_dir: &Path,
+        hotwords: &HashMap<String, f32>,
+        cuda_device: Option<u32>,
+    ) -> Result<Option<Self>, TranscribeError> {
+        let vocab_path = model_dir.join("vocab.txt");
+        if !vocab_path.exists() {
+            return Err(TranscribeError::ModelNotFound(format!(
+                "Parakeet vocab.txt not found: {}",
+                vocab_path.display()
+            )));
+        }
+        let tokens = ctc::load_tokens(&vocab_path)?;
+
+        let hotwords = HotwordBias::new(hotwords, &tokens);
+        if hotwords.is_empty() {
+            return Ok(None);
+        }
+
+        let vocab_size = tokens.keys().max().map_or(0, |&id| id as usize + 1);
+        let blank_id = tokens
+            .iter()
+            .find(|(_, piece)| piece.as_str() == "<blk>")
+            .map_or(vocab_size as i64 - 1, |(&id, _)| id as i64);
+
+        let threads = num_cpus::get().min(4);
+        let encoder_file = find_model_file(model_dir, "encoder-model")?;
+        let decoder_file = find_model_file(model_dir, "decoder_joint-model")?;
+
+        // The decoder/joint runs once per step on [1, D, 1] tensors, where
+        // host/device copies cost more than the compute, so it stays on CPU.
+        let encoder = build_session(&encoder_file, threads, "encoder", true, cuda_device)?;
+        let decoder_joint = build_session(&decoder_file, 1, "decoder_joint", false, None)?;
+
+        let states = decoder_joint
+            .inputs()
+            .iter()
+            .filter(|input| input.name().starts_with("input_states"))
+            .map(|input| match input.dtype() {
+                ValueType::Tensor { shape, .. } => Ok(StateSpec {
+                    input: input.name().to_string(),
+                    output: input.name().replacen("input_", "output_", 1),
+                    // Dynamic (batch) dimensions become 1
+                    shape: shape.iter().map(|&d| d.max(1)).collect(),
+                }),
+                other => Err(TranscribeError::InitFailed(format!(
+                    "Parakeet decoder input '{}' is not a tensor: {:?}",
+                    input.name(),
+                    other
+                ))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Some(Self {
+            encoder: Mutex::new(encoder),
+            decoder_joint: Mutex::new(decoder_joint),
+            tokens,
+            hotwords,
+            fbank: CohereFbank::new(),
+            blank_id,
+            vocab_size,
+            states,
+        }))
+    }
+
+    /// Transcribe a complete utterance
+    pub fn transcribe(&self, samples: &[f32]) -> Result<TdtOutput, TranscribeError> {
+        let frames = self.encode(samples)?;
+
+        let initial = self
+            .states
+            .iter()
+            .map(zero_state)
+            .collect::<Result<Vec<_>, _>>()?;
+        let emissions = greedy_search(
+            frames.len(),
+            self.vocab_size,
+            self.blank_id,
+            &self.hotwords,
+            initial,
+            |t, token, states| self.run_decoder_joint(&frames[t], token, states),
+        )?;
+
+        let ids: Vec<i64> = emissions.iter().map(|e| e.token).collect();
+        let token_times = emissions
+            .iter()
+            .map(|e| {
+                let start = e.frame as f32 * FRAME_SECS;
+                (start, start + e.frames.max(1) as f32 * FRAME_SECS)
+            })
+            .collect();
+
+        Ok(TdtOutput {
+            text: tokens_to_text(&ids, &self.tokens),
+            token_times,
+        })
+    }
+
+    /// Run the encoder and return its output frames
+    fn encode(&self, samples: &[f32]) -> Result<Vec<Vec<f32>>, TranscribeError> {
+        let features = self.fbank.extract(samples);
+        let num_frames = features.nrows();
+        if num_frames == 0 {
+            return Ok(Vec::new());
+        }
+
+        // The encoder takes [1, mels, frames]
+        let x: Vec<f32> = features.t().iter().copied().collect();
+        let x = Tensor::<f32>::from_array(([1usize, self.fbank.num_mels(), num_frames], x))
+            .map_err(|e| {
+                TranscribeError::InferenceFailed(format!("Failed to create feature tensor: {}", e))
+            })?;
+        let length =
+            Tensor::<i64>::from_array(([1usize], vec![num_frames as i64])).map_err(|e| {
+                TranscribeError::InferenceFailed(format!("Failed to create length tensor: {}", e))
+            })?;
+
+        let mut encoder = self.encoder.lock().map_err(|e| {
+            TranscribeError::InferenceFailed(format!("Failed to lock encoder: {}", e))
+        })?;
+        let x_name = encoder.inputs()[0].name().to_string();
+        let length_name = encoder.inputs()[1].name().to_string();
+        let outputs = encoder
+            .run(vec![
+                (Cow::Owned(x_name), SessionInputValue::from(x)),
+                (Cow::Owned(length_name), SessionInputValue::from(length)),
+            ])
+            .map_err(|e| {
+                TranscribeError::InferenceFailed(format!("Parakeet encoder failed: {}", e))
+            })?;
+
+        // Output is [1, D, T]; regroup it frame by frame
+        let (shape, data) = outputs[0].try_extract_tensor::<f32>().map_err(|e| {
+            TranscribeError::InferenceFailed(format!("Failed to extract encoder output: {}", e))
+        })?;
+        if shape.len() != 3 {
+            return Err(TranscribeError::InferenceFailed(format!(
+                "Parakeet encoder output has shape {:?}, expected [1, D, T]",
+                shape
+            )));
+        }
+        let dim = shape[1] as usize;
+        let total = shape[2] as usize;
+        let valid = outputs[1]
+            .try_extract_tensor::<i64>()
+            .ok()
+            .and_then(|(_, lengths)| lengths.first().copied())
+            .map_or(total, |n| (n as usize).min(total));
+
+        Ok((0..valid)
+            .map(|t| (0..dim).map(|d| data[d * total + t]).collect())
+            .collect())
+    }
+
+    /// Run the prediction network on the last emitted token and join it
+    /// with one encoder frame. Returns the joint output and the updated
+    /// prediction network states.
+    fn run_decoder_joint(
+        &self,
+        frame: &[f32],
+        token: i64,
+        states: &[DynValue],
+    ) -> Result<(Vec<f32>, Vec<DynValue>), TranscribeError> {
+        let enc =
+            Tensor::<f32>::from_array(([1usize, frame.len(), 1], frame.to_vec())).map_err(|e| {
+                TranscribeError::InferenceFailed(format!("Failed to create joint input: {}", e))
+            })?;
+        let targets =
+            Tensor::<i32>::from_array(([1usize, 1], vec![token as i32])).map_err(|e| {
+                TranscribeError::InferenceFailed(format!("Failed to create decoder input: {}", e))
+            })?;
+        let target_length = Tensor::<i32>::from_array(([1usize], vec![1])).map_err(|e| {
+            TranscribeError::InferenceFailed(format!("Failed to create decoder input: {}", e))
+        })?;
+
+        let mut inputs: Vec<(Cow<str>, SessionInputValue)> =
+            Vec::with_capacity(self.states.len() + 3);
+        inputs.push((Cow::Borrowed("encoder_outputs"), enc.into()));
+        inputs.push((Cow::Borrowed("targets"), targets.into()));
+        inputs.push((Cow::Borrowed("target_length"), target_length.into()));
+        for (spec, value) in self.states.iter().zip(states.iter()) {
+            inputs.push((
+                Cow::Borrowed(spec.input.as_str()),
+                SessionInputValue::from(value),
+            ));
+        }
+
+        let mut decoder_joint = self.decoder_joint.lock().map_err(|e| {
+            TranscribeError::InferenceFailed(format!("Failed to lock decoder_joint: {}", e))
+        })?;
+        let mut outputs = decoder_joint.run(inputs).map_err(|e| {
+            TranscribeError::InferenceFailed(format!("Parakeet decoder_joint failed: {}", e))
+        })?;
+
+        let (_, data) = outputs[0].try_extract_tensor::<f32>().map_err(|e| {
+            TranscribeError::InferenceFailed(format!("Failed to extract joint output: {}", e))
+        })?;
+        let logits = data.to_vec();
+
+        let new_states = self
+            .states
+            .iter()
+            .map(|spec| {
+                outputs.remove(&spec.output).ok_or_else(|| {
+                    TranscribeError::InferenceFailed(format!(
+                        "Parakeet decoder_joint output '{}' missing",
+                        spec.output
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((logits, new_states))
+    }
+}
+
+/// TDT greedy search over `num_frames` encoder frames.
+///
+/// `joint(t, token, state)` runs the prediction network on the last emitted
+/// token (blank at the start) and joins it with frame `t`, returning the
+/// joint output and the prediction network's next state. That state is only
+/// kept when a token is emitted.
+fn greedy_search<S>(
+    num_frames: usize,
+    vocab_size: usize,
+    blank_id: i64,
+    hotwords: &HotwordBias,
+    mut state: S,
+    mut joint: impl FnMut(usize, i64, &S) -> Result<(Vec<f32>, S), TranscribeError>,
+) -> Result<Vec<Emission>, TranscribeError> {
+    let mut emissions = Vec::new();
+    let mut hotword_state = HotwordState::new();
+    let mut last = blank_id;
+    let mut t = 0;
+    let mut emitted_here = 0;
+
+    while t < num_frames {
+        let (mut output, next_state) = joint(t, last, &state)?;
+        if output.len() < vocab_size + DURATIONS.len() {
+            return Err(TranscribeError::InferenceFailed(format!(
+                "Parakeet joint returned {} logits, expected {} tokens and {} durations",
+                output.len(),
+                vocab_size,
+                DURATIONS.len()
+            )));
+        }
+        let (logits, durations) = output.split_at_mut(vocab_size);
+        hotwords.apply(&hotword_state, logits);
+        let token = argmax(logits) as i64;
+        let skip = DURATIONS[argmax(&durations[..DURATIONS.len()])];
+
+        if token != blank_id {
+            hotwords.advance(&mut hotword_state, token);
+            state = next_state;
+            last = token;
+            emitted_here += 1;
+            emissions.push(Emission {
+                token,
+                frame: t,
+                frames: skip,
+            });
+        }
+
+        if skip > 0 {
+            t += skip;
+            emitted_here = 0;
+        } else if token == blank_id || emitted_here == MAX_TOKENS_PER_STEP {
+            t += 1;
+            emitted_here = 0;
+        }
+    }
+
+    Ok(emissions)
+}
+
+fn argmax(logits: &[f32]) -> usize {
+    logits
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(idx, _)| idx)
+        .unwrap_or(0)
+}
+
+/// Convert token IDs to text. Parakeet vocabularies are SentencePiece:
+/// `▁` marks a word start.
+fn tokens_to_text(token_ids: &[i64], tokens: &HashMap<u32, String>) -> String {
+    let mut result = String::new();
+    for &id in token_ids {
+        if let Some(piece) = tokens.get(&(id as u32)) {
+            if piece.starts_with('<') && piece.ends_with('>') {
+                continue;
+            }
+            result.push_str(&piece.replace('\u{2581}', " "));
+        }
+    }
+    result.trim().to_string()
+}
+
+/// Zero-filled initial prediction network state
+fn zero_state(spec: &StateSpec) -> Result<DynValue, TranscribeError> {
+    let len = spec.shape.iter().product::<i64>() as usize;
+    Tensor::<f32>::from_array((spec.shape.clone(), vec![0.0f32; len]))
+        .map(|t| t.into_dyn())
+        .map_err(|e| {
+            TranscribeError::InferenceFailed(format!(
+                "Failed to create state '{}': {}",
+                spec.input, e
+            ))
+        })
+}
+
+/// Find `<stem>.onnx`, else the `<stem>.int8.onnx` quantized export
+fn find_model_file(dir: &Path, stem: &str) -> Result<PathBuf, TranscribeError> {
+    [format!("{stem}.onnx"), format!("{stem}.int8.onnx")]
+        .into_iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.exists())
+        .ok_or_else(|| {
+            TranscribeError::ModelNotFound(format!(
+                "Parakeet {stem} not found in {:?}\n  \
+                 Expected {stem}.onnx or {stem}.int8.onnx",
+                dir
+            ))
+        })
+}
+
+fn build_session(
+    path: &Path,
+    threads: usize,
+    label: &str,
+    use_gpu: bool,
+    cuda_device: Option<u32>,
+) -> Result<Session, TranscribeError> {
+    let builder = Session::builder()
+        .map_err(|e| TranscribeError::InitFailed(format!("ONNX session builder failed: {}", e)))?
+        .with_intra_threads(threads)
+        .map_err(|e| TranscribeError::InitFailed(format!("Failed to set threads: {}", e)))?;
+
+    let builder = if use_gpu {
+        super::onnx_ep::register_gpu_eps(builder, "Parakeet", label, cuda_device)
+            .map_err(|e| TranscribeError::InitFailed(format!("{label} EPs: {e}")))?
+    } else {
+        builder
+    };
+
+    super::model_file::commit_session(builder, path).map_err(|e| {
+        TranscribeError::InitFailed(format!(
+            "Failed to load Parakeet {label} from {:?}: {e}",
+            path
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const VOCAB: usize = 4;
+    const BLANK: i64 = 3;
+
+    fn vocab() -> HashMap<u32, String> {
+        ["\u{2581}cube", "\u{2581}ctl", "\u{2581}cubectl", "<blk>"]
+            .into_iter()
+            .enumerate()
+            .map(|(id, piece)| (id as u32, piece.to_string()))
+            .collect()
+    }
+
+    /// Joint output favoring `token` with a skip of `skip` frames
+    fn output(token: usize, skip: usize) -> Vec<f32> {
+        let mut out = vec![0.0; VOCAB + DURATIONS.len()];
+        out[token] = 1.0;
+        out[VOCAB + skip] = 1.0;
+        out
+    }
+
+    #[test]
+    fn test_greedy_search_follows_predicted_durations() {
+        let no_hotwords = HotwordBias::new(&HashMap::new(), &vocab());
+        let mut visited = Vec::new();
+        let emissions = greedy_search(6, VOCAB, BLANK, &no_hotwords, 0, |t, _, &calls| {
+            visited.push(t);
+            let out = match t {
+                0 => output(0, 2),
+                2 => output(BLANK as usize, 1),
+                _ => output(1, 4),
+            };
+            Ok((out, calls + 1))
+        })
+        .unwrap();
+
+        assert_eq!(visited, vec![0, 2, 3]);
+        assert_eq!(
+            emissions,
+            vec![
+                Emission {
+                    token: 0,
+                    frame: 0,
+                    frames: 2
+                },
+                Emission {
+                    token: 1,
+                    frame: 3,
+                    frames: 4
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_greedy_search_keeps_state_only_for_emitted_tokens() {
+        let no_hotwords = HotwordBias::new(&HashMap::new(), &vocab());
+        let mut seen = Vec::new();
+        greedy_search(3, VOCAB, BLANK, &no_hotwords, 0, |t, last, &state| {
+            seen.push((last, state));
+            let token = if t == 1 { 2 } else { BLANK as usize };
+            Ok((output(token, 1), state + 1))
+        })
+        .unwrap();
+
+        // Blank at frame 0 leaves the state alone; the token at frame 1
+        // becomes the context for frame 2
+        assert_eq!(seen, vec![(BLANK, 0), (BLANK, 0), (2, 1)]);
+    }
+
+    #[test]
+    fn test_greedy_search_forces_progress_after_max_tokens() {
+        let no_hotwords = HotwordBias::new(&HashMap::new(), &vocab());
+        let emissions = greedy_search(2, VOCAB, BLANK, &no_hotwords, (), |_, _, _| {
+            Ok((output(0, 0), ()))
+        })
+        .unwrap();
+
+        assert_eq!(emissions.len(), 2 * MAX_TOKENS_PER_STEP);
+    }
+
+    #[test]
+    fn test_hotword_boost_wins_the_argmax() {
+        let hotwords = HotwordBias::new(&HashMap::from([("cubectl".to_string(), 3.0)]), &vocab());
+        let emissions = greedy_search(1, VOCAB, BLANK, &hotwords, (), |_, _, _| {
+            let mut out = output(0, 1);
+            out[2] = 0.5;
+            Ok((out, ()))
+        })
+        .unwrap();
+
+        assert_eq!(emissions[0].token, 2);
+    }
+
+    #[test]
+    fn test_greedy_search_rejects_short_joint_output() {
+        let no_hotwords = HotwordBias::new(&HashMap::new(), &vocab());
+        let result = greedy_search(1, VOCAB, BLANK, &no_hotwords, (), |_, _, _| {
+            Ok((vec![0.0; VOCAB], ()))
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_tokens_to_text_drops_blank() {
+        assert_eq!(tokens_to_text(&[0, BLANK, 1], &vocab()), "cube ctl");
+    }
+
+    #[test]
+    fn test_find_model_file_falls_back_to_int8() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        fs::write(dir.join("encoder-model.int8.onnx"), b"dummy").unwrap();
+
+        let found = find_model_file(dir, "encoder-model").unwrap();
+        assert_eq!(found, dir.join("encoder-model.int8.onnx"));
+        assert!(find_model_file(dir, "decoder_joint-model").is_err());
+    }
+}
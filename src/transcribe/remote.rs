@@ -5,66 +5,158 @@
 //!
 //! Note: Remote APIs don't support language arrays. When a language array is
 //! configured, the first/primary language is used.
+//!
+//! When `remote_endpoint` is a list, a request that times out or gets a 5xx
+//! is retried on the next endpoint, and the endpoint that answered is tried
+//! first from then on. After a minute on a fallback the first endpoint is
+//! tried again. Each endpoint is sent its own API key and model.
+//!
+//! A `ws://` or `wss://` endpoint switches to WebSocket streaming (see
+//! [`super::remote_ws`], requires the `remote-ws` feature).
 
 use super::Transcriber;
 use crate::config::{LanguageConfig, WhisperConfig};
 use crate::error::TranscribeError;
 use std::io::Cursor;
-use std::time::Duration;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use ureq::serde_json;
 
+/// How long a fallback endpoint stays first before the first endpoint is
+/// tried again
+const PRIMARY_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// A remote endpoint with its API key and model resolved
+#[derive(Debug)]
+struct Endpoint {
+    /// Base URL (e.g., "http://192.168.1.100:8080")
+    url: String,
+    /// Model name to send to this endpoint
+    model: String,
+    /// Optional API key for authentication
+    api_key: Option<String>,
+}
+
+/// Which endpoint of a failover list answered last. Shared between
+/// transcribers for the same list so a failover outlives a rebuild.
+#[derive(Debug, Default)]
+pub struct EndpointHealth {
+    /// Index of the endpoint that last answered
+    active: AtomicUsize,
+    /// When the active endpoint moved off the first one
+    failed_over_at: Mutex<Option<Instant>>,
+}
+
+impl EndpointHealth {
+    /// Index of the endpoint that last answered
+    fn active(&self, count: usize) -> usize {
+        self.active.load(Ordering::Relaxed) % count
+    }
+
+    /// Endpoint to try first: the active one, or the first endpoint again
+    /// once a fallback has been active for `retry_after`
+    fn first(&self, count: usize, retry_after: Duration) -> usize {
+        let active = self.active(count);
+        let retry_primary = active != 0
+            && self
+                .failed_over_at
+                .lock()
+                .unwrap()
+                .is_some_and(|at| at.elapsed() >= retry_after);
+        if retry_primary {
+            0
+        } else {
+            active
+        }
+    }
+
+    /// Record the endpoint that answered
+    fn set_active(&self, index: usize) {
+        self.active.store(index, Ordering::Relaxed);
+        *self.failed_over_at.lock().unwrap() = (index != 0).then(Instant::now);
+    }
+}
+
 /// Remote transcriber using OpenAI-compatible Whisper API
 #[derive(Debug)]
 pub struct RemoteTranscriber {
-    /// Base endpoint URL (e.g., "http://192.168.1.100:8080")
-    endpoint: String,
-    /// Model name to send to server
-    model: String,
+    /// Endpoints in failover order
+    endpoints: Vec<Endpoint>,
+    /// Endpoint that last answered, tried first
+    health: Arc<EndpointHealth>,
+    /// How long to stay on a fallback before retrying the first endpoint
+    primary_retry_after: Duration,
     /// Language configuration
     language: LanguageConfig,
     /// Whether to translate to English
     translate: bool,
-    /// Optional API key for authentication
-    api_key: Option<String>,
     /// Optional initial prompt for transcription context
     initial_prompt: Option<String>,
+    /// Hallucinated phrases dropped from the output
+    suppress_phrases: Vec<String>,
     /// Request timeout
     timeout: Duration,
+    /// WebSocket session settings, set when the endpoint is ws:// or wss://
+    #[cfg(feature = "remote-ws")]
+    ws: Option<super::remote_ws::WsSettings>,
 }
 
 impl RemoteTranscriber {
     /// Create a new remote transcriber from config
     pub fn new(config: &WhisperConfig) -> Result<Self, TranscribeError> {
-        let endpoint = config
+        let configured = config
             .remote_endpoint
             .as_ref()
+            .map(|e| e.as_vec())
+            .filter(|v| !v.is_empty())
             .ok_or_else(|| {
                 TranscribeError::ConfigError(
                     "remote_endpoint is required when mode = 'remote'".into(),
                 )
-            })?
-            .clone();
+            })?;
 
         // Validate endpoint URL format
-        if !endpoint.starts_with("http://") && !endpoint.starts_with("https://") {
-            return Err(TranscribeError::ConfigError(format!(
-                "remote_endpoint must start with http:// or https://, got: {}",
-                endpoint
-            )));
+        let is_websocket = |e: &str| e.starts_with("ws://") || e.starts_with("wss://");
+        for endpoint in configured.iter().map(|e| &e.url) {
+            if !endpoint.starts_with("http://")
+                && !endpoint.starts_with("https://")
+                && !is_websocket(endpoint)
+            {
+                return Err(TranscribeError::ConfigError(format!(
+                    "remote_endpoint must start with http://, https://, ws:// or wss://, got: {}",
+                    endpoint
+                )));
+            }
+        }
+        let websocket = is_websocket(&configured[0].url);
+        if websocket && !cfg!(feature = "remote-ws") {
+            return Err(TranscribeError::ConfigError(
+                "WebSocket remote_endpoint requires voxtype built with --features remote-ws".into(),
+            ));
+        }
+        if configured.len() > 1 && configured.iter().any(|e| is_websocket(&e.url)) {
+            return Err(TranscribeError::ConfigError(
+                "remote_endpoint failover lists only support http:// and https:// URLs".into(),
+            ));
         }
 
-        // Warn about non-HTTPS for non-localhost endpoints
-        if endpoint.starts_with("http://")
-            && !endpoint.contains("localhost")
-            && !endpoint.contains("127.0.0.1")
-            && !endpoint.contains("[::1]")
-        {
-            tracing::warn!(
-                "Remote endpoint uses HTTP without TLS. Audio data will be transmitted unencrypted!"
-            );
+        // Warn about non-TLS for non-localhost endpoints
+        for endpoint in configured.iter().map(|e| &e.url) {
+            if (endpoint.starts_with("http://") || endpoint.starts_with("ws://"))
+                && !endpoint.contains("localhost")
+                && !endpoint.contains("127.0.0.1")
+                && !endpoint.contains("[::1]")
+            {
+                tracing::warn!(
+                    "Remote endpoint {} uses HTTP without TLS. Audio data will be transmitted unencrypted!",
+                    endpoint
+                );
+            }
         }
 
-        // Check for API key in config or environment
+        // Check for API key in config or environment. Endpoints given as
+        // tables override the key and model for themselves only.
         let api_key = config
             .remote_api_key
             .clone()
@@ -75,6 +167,15 @@ impl RemoteTranscriber {
             .clone()
             .unwrap_or_else(|| "whisper-1".to_string());
 
+        let endpoints: Vec<Endpoint> = configured
+            .into_iter()
+            .map(|e| Endpoint {
+                url: e.url,
+                model: e.model.unwrap_or_else(|| model.clone()),
+                api_key: e.api_key.or_else(|| api_key.clone()),
+            })
+            .collect();
+
         let timeout = Duration::from_secs(config.remote_timeout_secs.unwrap_or(30));
 
         // Warn if language array is configured (remote APIs don't support arrays)
@@ -87,9 +188,12 @@ impl RemoteTranscriber {
         }
 
         tracing::info!(
-            "Configured remote transcriber: endpoint={}, model={}, timeout={}s",
-            endpoint,
-            model,
+            "Configured remote transcriber: endpoint={}, timeout={}s",
+            endpoints
+                .iter()
+                .map(|e| format!("{} (model={})", e.url, e.model))
+                .collect::<Vec<_>>()
+                .join(", "),
             timeout.as_secs()
         );
 
@@ -99,17 +203,41 @@ impl RemoteTranscriber {
             .filter(|s| !s.is_empty())
             .cloned();
 
+        #[cfg(feature = "remote-ws")]
+        let ws = websocket.then(|| super::remote_ws::WsSettings {
+            endpoint: endpoints[0].url.clone(),
+            api_key: endpoints[0].api_key.clone(),
+            timeout,
+            suppress_phrases: config.suppress_phrases.clone(),
+            start: super::remote_ws::StartFrame::new(
+                (!config.language.is_auto()).then(|| config.language.primary().to_string()),
+                config.translate,
+                endpoints[0].model.clone(),
+                initial_prompt.clone(),
+            ),
+        });
+
         Ok(Self {
-            endpoint,
-            model,
+            endpoints,
+            health: Arc::new(EndpointHealth::default()),
+            primary_retry_after: PRIMARY_RETRY_AFTER,
             language: config.language.clone(),
             translate: config.translate,
-            api_key,
             initial_prompt,
+            suppress_phrases: config.suppress_phrases.clone(),
             timeout,
+            #[cfg(feature = "remote-ws")]
+            ws,
         })
     }
 
+    /// Share the endpoint health with other transcribers for the same
+    /// endpoints, so a failover is remembered when the transcriber is rebuilt
+    pub fn with_shared_health(mut self, health: Arc<EndpointHealth>) -> Self {
+        self.health = health;
+        self
+    }
+
     /// Encode f32 samples to WAV format
     fn encode_wav(&self, samples: &[f32]) -> Result<Vec<u8>, TranscribeError> {
         let spec = hound::WavSpec {
@@ -141,7 +269,7 @@ impl RemoteTranscriber {
     }
 
     /// Build the multipart form body for the API request
-    fn build_multipart_body(&self, wav_data: &[u8]) -> (String, Vec<u8>) {
+    fn build_multipart_body(&self, model: &str, wav_data: &[u8]) -> (String, Vec<u8>) {
         let boundary = format!(
             "----VoxtypeBoundary{}",
             std::time::SystemTime::now()
@@ -164,7 +292,7 @@ impl RemoteTranscriber {
         // Add model field
         body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
         body.extend_from_slice(b"Content-Disposition: form-data; name=\"model\"\r\n\r\n");
-        body.extend_from_slice(self.model.as_bytes());
+        body.extend_from_slice(model.as_bytes());
         body.extend_from_slice(b"\r\n");
 
         // Add language field (if not auto-detect mode)
@@ -194,29 +322,14 @@ impl RemoteTranscriber {
 
         (boundary, body)
     }
-}
 
-impl Transcriber for RemoteTranscriber {
-    fn transcribe(&self, samples: &[f32]) -> Result<String, TranscribeError> {
-        if samples.is_empty() {
-            return Err(TranscribeError::AudioFormat("Empty audio buffer".into()));
-        }
-
-        let duration_secs = samples.len() as f32 / 16000.0;
-        tracing::debug!(
-            "Sending {:.2}s of audio to remote server ({} samples)",
-            duration_secs,
-            samples.len()
-        );
-
-        let start = std::time::Instant::now();
-
-        // Encode audio to WAV
-        let wav_data = self.encode_wav(samples)?;
-        tracing::debug!("Encoded WAV: {} bytes", wav_data.len());
-
-        // Build multipart form
-        let (boundary, body) = self.build_multipart_body(&wav_data);
+    /// Post the audio to one endpoint and parse the JSON response
+    fn post(
+        &self,
+        endpoint: &Endpoint,
+        wav_data: &[u8],
+    ) -> Result<serde_json::Value, RequestFailure> {
+        let (boundary, body) = self.build_multipart_body(&endpoint.model, wav_data);
 
         // Determine the API path based on whether we're doing transcription or translation
         let path = if self.translate {
@@ -225,44 +338,146 @@ impl Transcriber for RemoteTranscriber {
             "/v1/audio/transcriptions"
         };
 
-        let url = format!("{}{}", self.endpoint.trim_end_matches('/'), path);
+        let url = format!("{}{}", endpoint.url.trim_end_matches('/'), path);
 
         // Build request
-        let mut request = ureq::post(&url).timeout(self.timeout).set(
-            "Content-Type",
-            &format!("multipart/form-data; boundary={}", boundary),
-        );
+        let mut request = crate::network::agent_builder(&url)
+            .build()
+            .post(&url)
+            .timeout(self.timeout)
+            .set(
+                "Content-Type",
+                &format!("multipart/form-data; boundary={}", boundary),
+            );
 
         // Add authorization if API key is configured
-        if let Some(ref key) = self.api_key {
+        if let Some(ref key) = endpoint.api_key {
             request = request.set("Authorization", &format!("Bearer {}", key));
         }
 
-        // Send request
+        // Send request. Timeouts, unreachable hosts and server errors are
+        // worth retrying elsewhere; client errors would fail everywhere.
         let response = request.send_bytes(&body).map_err(|e| match e {
             ureq::Error::Status(code, resp) => {
                 let body = resp.into_string().unwrap_or_default();
-                TranscribeError::RemoteError(format!("Server returned {}: {}", code, body))
-            }
-            ureq::Error::Transport(t) => {
-                TranscribeError::NetworkError(format!("Request failed: {}", t))
+                let error =
+                    TranscribeError::RemoteError(format!("Server returned {}: {}", code, body));
+                if code >= 500 {
+                    RequestFailure::FailOver(error)
+                } else {
+                    RequestFailure::Fatal(error)
+                }
             }
+            ureq::Error::Transport(t) => RequestFailure::FailOver(TranscribeError::NetworkError(
+                format!("Request failed: {}", t),
+            )),
         })?;
 
         // Parse JSON response
-        let json: serde_json::Value = response.into_json().map_err(|e| {
-            TranscribeError::RemoteError(format!("Failed to parse response: {}", e))
-        })?;
+        response.into_json().map_err(|e| {
+            RequestFailure::Fatal(TranscribeError::RemoteError(format!(
+                "Failed to parse response: {}",
+                e
+            )))
+        })
+    }
+
+    /// Send the request, starting with the last healthy endpoint and moving
+    /// down the list on timeouts and server errors
+    fn post_with_failover(&self, wav_data: &[u8]) -> Result<serde_json::Value, TranscribeError> {
+        let count = self.endpoints.len();
+        let active = self.health.active(count);
+        let first = self.health.first(count, self.primary_retry_after);
+        let mut last_error = None;
+
+        for offset in 0..count {
+            let index = (first + offset) % count;
+            let endpoint = &self.endpoints[index];
+            match self.post(endpoint, wav_data) {
+                Ok(json) => {
+                    if index != active {
+                        tracing::info!(
+                            "Remote endpoint {} is now the active endpoint",
+                            endpoint.url
+                        );
+                    }
+                    // Also restarts the retry timer when a retried first
+                    // endpoint is still down
+                    if index != active || index != first {
+                        self.health.set_active(index);
+                    }
+                    return Ok(json);
+                }
+                Err(RequestFailure::FailOver(e)) => {
+                    if count > 1 {
+                        tracing::warn!("Remote endpoint {} failed: {}", endpoint.url, e);
+                    }
+                    last_error = Some(e);
+                }
+                Err(RequestFailure::Fatal(e)) => return Err(e),
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| {
+            TranscribeError::ConfigError("remote_endpoint is required when mode = 'remote'".into())
+        }))
+    }
+}
+
+/// Why a request to one endpoint failed
+enum RequestFailure {
+    /// Timeout, connection failure or 5xx: try the next endpoint
+    FailOver(TranscribeError),
+    /// Client error or bad response: give up
+    Fatal(TranscribeError),
+}
+
+impl Transcriber for RemoteTranscriber {
+    fn transcribe(&self, samples: &[f32]) -> Result<String, TranscribeError> {
+        if samples.is_empty() {
+            return Err(TranscribeError::AudioFormat("Empty audio buffer".into()));
+        }
+
+        let duration_secs = samples.len() as f32 / 16000.0;
+        tracing::debug!(
+            "Sending {:.2}s of audio to remote server ({} samples)",
+            duration_secs,
+            samples.len()
+        );
+
+        let start = std::time::Instant::now();
+
+        #[cfg(feature = "remote-ws")]
+        if let Some(ws) = &self.ws {
+            let run = super::remote_ws::transcribe(ws, samples);
+            let text = match tokio::runtime::Handle::try_current() {
+                Ok(handle) => tokio::task::block_in_place(|| handle.block_on(run)),
+                Err(_) => tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .map_err(|e| {
+                        TranscribeError::InferenceFailed(format!("Failed to create runtime: {}", e))
+                    })?
+                    .block_on(run),
+            }?;
+            tracing::info!(
+                "Remote WebSocket transcription completed in {:.2}s",
+                start.elapsed().as_secs_f32()
+            );
+            return Ok(text);
+        }
+
+        // Encode audio to WAV
+        let wav_data = self.encode_wav(samples)?;
+        tracing::debug!("Encoded WAV: {} bytes", wav_data.len());
+
+        let json = self.post_with_failover(&wav_data)?;
 
         // Extract text from response
-        let text = json
-            .get("text")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                TranscribeError::RemoteError(format!("Response missing 'text' field: {}", json))
-            })?
-            .trim()
-            .to_string();
+        let text = json.get("text").and_then(|v| v.as_str()).ok_or_else(|| {
+            TranscribeError::RemoteError(format!("Response missing 'text' field: {}", json))
+        })?;
+        let text = super::whisper::strip_suppressed_phrases(text, &self.suppress_phrases);
 
         tracing::info!(
             "Remote transcription completed in {:.2}s: {:?}",
@@ -276,6 +491,24 @@ impl Transcriber for RemoteTranscriber {
 
         Ok(text)
     }
+
+    #[cfg(feature = "remote-ws")]
+    fn as_streaming(&self) -> Option<&dyn super::StreamingTranscriber> {
+        self.ws.is_some().then_some(self as _)
+    }
+}
+
+#[cfg(feature = "remote-ws")]
+impl super::StreamingTranscriber for RemoteTranscriber {
+    fn start_stream(
+        &self,
+        samples_rx: tokio::sync::mpsc::Receiver<Vec<f32>>,
+    ) -> Result<super::StreamHandle, TranscribeError> {
+        let ws = self.ws.clone().ok_or_else(|| {
+            TranscribeError::ConfigError("remote_endpoint is not a WebSocket URL".into())
+        })?;
+        Ok(super::remote_ws::start_stream(ws, samples_rx))
+    }
 }
 
 #[cfg(test)]
@@ -286,7 +519,7 @@ mod tests {
     fn test_encode_wav_basic() {
         let config = WhisperConfig {
             mode: Some(crate::config::WhisperMode::Remote),
-            remote_endpoint: Some("http://localhost:8080".to_string()),
+            remote_endpoint: Some("http://localhost:8080".into()),
             ..Default::default()
         };
 
@@ -324,7 +557,7 @@ mod tests {
     fn test_config_validation_invalid_url() {
         let config = WhisperConfig {
             mode: Some(crate::config::WhisperMode::Remote),
-            remote_endpoint: Some("not-a-url".to_string()),
+            remote_endpoint: Some("not-a-url".into()),
             ..Default::default()
         };
 
@@ -333,11 +566,28 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("http://"));
     }
 
+    #[test]
+    fn test_websocket_endpoint_streams() {
+        let config = WhisperConfig {
+            mode: Some(crate::config::WhisperMode::Remote),
+            remote_endpoint: Some("ws://localhost:8080/stream".into()),
+            ..Default::default()
+        };
+        assert!(config.remote_uses_websocket());
+
+        let result = RemoteTranscriber::new(&config);
+        if cfg!(feature = "remote-ws") {
+            assert!(result.unwrap().as_streaming().is_some());
+        } else {
+            assert!(result.unwrap_err().to_string().contains("remote-ws"));
+        }
+    }
+
     #[test]
     fn test_multipart_body_structure() {
         let config = WhisperConfig {
             mode: Some(crate::config::WhisperMode::Remote),
-            remote_endpoint: Some("http://localhost:8080".to_string()),
+            remote_endpoint: Some("http://localhost:8080".into()),
             remote_model: Some("large-v3".to_string()),
             ..Default::default()
         };
@@ -345,7 +595,8 @@ mod tests {
         let transcriber = RemoteTranscriber::new(&config).unwrap();
         let wav_data = vec![0u8; 100]; // Dummy data
 
-        let (boundary, body) = transcriber.build_multipart_body(&wav_data);
+        let (boundary, body) =
+            transcriber.build_multipart_body(&transcriber.endpoints[0].model, &wav_data);
 
         let body_str = String::from_utf8_lossy(&body);
 
@@ -366,7 +617,7 @@ mod tests {
     fn test_multipart_body_includes_prompt() {
         let config = WhisperConfig {
             mode: Some(crate::config::WhisperMode::Remote),
-            remote_endpoint: Some("http://localhost:8080".to_string()),
+            remote_endpoint: Some("http://localhost:8080".into()),
             initial_prompt: Some("Technical discussion about Rust and Kubernetes.".to_string()),
             ..Default::default()
         };
@@ -374,7 +625,8 @@ mod tests {
         let transcriber = RemoteTranscriber::new(&config).unwrap();
         let wav_data = vec![0u8; 100];
 
-        let (_boundary, body) = transcriber.build_multipart_body(&wav_data);
+        let (_boundary, body) =
+            transcriber.build_multipart_body(&transcriber.endpoints[0].model, &wav_data);
         let body_str = String::from_utf8_lossy(&body);
 
         assert!(body_str.contains("name=\"prompt\""));
@@ -385,7 +637,7 @@ mod tests {
     fn test_multipart_body_excludes_empty_prompt() {
         let config = WhisperConfig {
             mode: Some(crate::config::WhisperMode::Remote),
-            remote_endpoint: Some("http://localhost:8080".to_string()),
+            remote_endpoint: Some("http://localhost:8080".into()),
             initial_prompt: Some("".to_string()),
             ..Default::default()
         };
@@ -393,7 +645,8 @@ mod tests {
         let transcriber = RemoteTranscriber::new(&config).unwrap();
         let wav_data = vec![0u8; 100];
 
-        let (_boundary, body) = transcriber.build_multipart_body(&wav_data);
+        let (_boundary, body) =
+            transcriber.build_multipart_body(&transcriber.endpoints[0].model, &wav_data);
         let body_str = String::from_utf8_lossy(&body);
 
         assert!(!body_str.contains("name=\"prompt\""));
@@ -403,7 +656,7 @@ mod tests {
     fn test_multipart_body_excludes_prompt_when_none() {
         let config = WhisperConfig {
             mode: Some(crate::config::WhisperMode::Remote),
-            remote_endpoint: Some("http://localhost:8080".to_string()),
+            remote_endpoint: Some("http://localhost:8080".into()),
             initial_prompt: None,
             ..Default::default()
         };
@@ -411,7 +664,8 @@ mod tests {
         let transcriber = RemoteTranscriber::new(&config).unwrap();
         let wav_data = vec![0u8; 100];
 
-        let (_boundary, body) = transcriber.build_multipart_body(&wav_data);
+        let (_boundary, body) =
+            transcriber.build_multipart_body(&transcriber.endpoints[0].model, &wav_data);
         let body_str = String::from_utf8_lossy(&body);
 
         assert!(!body_str.contains("name=\"prompt\""));
@@ -422,7 +676,7 @@ mod tests {
         let config = WhisperConfig {
             mode: Some(crate::config::WhisperMode::Remote),
             translate: false,
-            remote_endpoint: Some("http://localhost:8080".to_string()),
+            remote_endpoint: Some("http://localhost:8080".into()),
             ..Default::default()
         };
 
@@ -445,7 +699,7 @@ mod tests {
         let config = WhisperConfig {
             mode: Some(crate::config::WhisperMode::Remote),
             translate: true,
-            remote_endpoint: Some("http://localhost:8080".to_string()),
+            remote_endpoint: Some("http://localhost:8080".into()),
             ..Default::default()
         };
 
@@ -467,20 +721,23 @@ mod tests {
     fn test_api_key_from_config() {
         let config = WhisperConfig {
             mode: Some(crate::config::WhisperMode::Remote),
-            remote_endpoint: Some("http://localhost:8080".to_string()),
+            remote_endpoint: Some("http://localhost:8080".into()),
             remote_api_key: Some("sk-test-key-123".to_string()),
             ..Default::default()
         };
 
         let transcriber = RemoteTranscriber::new(&config).unwrap();
-        assert_eq!(transcriber.api_key, Some("sk-test-key-123".to_string()));
+        assert_eq!(
+            transcriber.endpoints[0].api_key,
+            Some("sk-test-key-123".to_string())
+        );
     }
 
     #[test]
     fn test_custom_timeout() {
         let config = WhisperConfig {
             mode: Some(crate::config::WhisperMode::Remote),
-            remote_endpoint: Some("http://localhost:8080".to_string()),
+            remote_endpoint: Some("http://localhost:8080".into()),
             remote_timeout_secs: Some(60),
             ..Default::default()
         };
@@ -493,11 +750,177 @@ mod tests {
     fn test_default_timeout() {
         let config = WhisperConfig {
             mode: Some(crate::config::WhisperMode::Remote),
-            remote_endpoint: Some("http://localhost:8080".to_string()),
+            remote_endpoint: Some("http://localhost:8080".into()),
             ..Default::default()
         };
 
         let transcriber = RemoteTranscriber::new(&config).unwrap();
         assert_eq!(transcriber.timeout, Duration::from_secs(30));
     }
+
+    /// Serve every request with a fixed status and body, counting requests
+    fn mock_server(status: &'static str, body: &'static str) -> (String, Arc<AtomicUsize>) {
+        let (url, hits, _) = recording_server(status, body);
+        (url, hits)
+    }
+
+    /// Like [`mock_server`], also keeping the text of the last request
+    fn recording_server(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, Arc<AtomicUsize>, Arc<Mutex<String>>) {
+        use std::io::{BufRead, BufReader, Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let hits = Arc::new(AtomicUsize::new(0));
+        let last_request = Arc::new(Mutex::new(String::new()));
+        let counter = Arc::clone(&hits);
+        let recorded = Arc::clone(&last_request);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut length = 0;
+                let mut request = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                    if let Some(v) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        length = v.trim().parse().unwrap();
+                    }
+                }
+                let mut request_body = vec![0; length];
+                reader.read_exact(&mut request_body).unwrap();
+                request.push_str(&String::from_utf8_lossy(&request_body));
+                *recorded.lock().unwrap() = request;
+                counter.fetch_add(1, Ordering::SeqCst);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+            }
+        });
+        (format!("http://{}", addr), hits, last_request)
+    }
+
+    fn failover_transcriber(endpoints: Vec<String>) -> RemoteTranscriber {
+        let config = WhisperConfig {
+            mode: Some(crate::config::WhisperMode::Remote),
+            remote_endpoint: Some(crate::config::RemoteEndpointConfig::Multiple(
+                endpoints.into_iter().map(Into::into).collect(),
+            )),
+            remote_timeout_secs: Some(5),
+            ..Default::default()
+        };
+        RemoteTranscriber::new(&config).unwrap()
+    }
+
+    #[test]
+    fn test_failover_to_next_endpoint_and_remember_it() {
+        let (failing, failing_hits) = mock_server("503 Service Unavailable", "{}");
+        let (healthy, healthy_hits) = mock_server("200 OK", r#"{"text":"hello"}"#);
+        let transcriber = failover_transcriber(vec![failing, healthy]);
+
+        assert_eq!(transcriber.transcribe(&[0.0; 1600]).unwrap(), "hello");
+        assert_eq!(transcriber.health.active(2), 1);
+
+        // The healthy endpoint is tried first from now on
+        assert_eq!(transcriber.transcribe(&[0.0; 1600]).unwrap(), "hello");
+        assert_eq!(failing_hits.load(Ordering::SeqCst), 1);
+        assert_eq!(healthy_hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_primary_is_retried_after_cooldown() {
+        let (failing, failing_hits) = mock_server("503 Service Unavailable", "{}");
+        let (healthy, healthy_hits) = mock_server("200 OK", r#"{"text":"hello"}"#);
+        let mut transcriber = failover_transcriber(vec![failing, healthy]);
+        transcriber.primary_retry_after = Duration::ZERO;
+
+        assert_eq!(transcriber.transcribe(&[0.0; 1600]).unwrap(), "hello");
+        assert_eq!(transcriber.transcribe(&[0.0; 1600]).unwrap(), "hello");
+        assert_eq!(failing_hits.load(Ordering::SeqCst), 2);
+        assert_eq!(healthy_hits.load(Ordering::SeqCst), 2);
+        assert_eq!(transcriber.health.active(2), 1);
+    }
+
+    #[test]
+    fn test_recovered_primary_becomes_active_again() {
+        let health = EndpointHealth::default();
+        health.set_active(1);
+        assert_eq!(health.first(2, Duration::from_secs(60)), 1);
+        assert_eq!(health.first(2, Duration::ZERO), 0);
+
+        health.set_active(0);
+        assert_eq!(health.first(2, Duration::ZERO), 0);
+        assert!(health.failed_over_at.lock().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_each_endpoint_gets_its_own_key_and_model() {
+        use crate::config::{RemoteEndpoint, RemoteEndpointConfig, RemoteEndpointEntry};
+
+        let (lan, _, lan_request) = recording_server("503 Service Unavailable", "{}");
+        let (cloud, _, cloud_request) = recording_server("200 OK", r#"{"text":"hello"}"#);
+        let config = WhisperConfig {
+            mode: Some(crate::config::WhisperMode::Remote),
+            remote_endpoint: Some(RemoteEndpointConfig::Multiple(vec![
+                RemoteEndpointEntry::Table(RemoteEndpoint {
+                    url: lan,
+                    api_key: None,
+                    model: Some("large-v3-turbo".into()),
+                }),
+                RemoteEndpointEntry::Table(RemoteEndpoint {
+                    url: cloud,
+                    api_key: Some("sk-cloud".into()),
+                    model: Some("whisper-1".into()),
+                }),
+            ])),
+            remote_timeout_secs: Some(5),
+            ..Default::default()
+        };
+        let transcriber = RemoteTranscriber::new(&config).unwrap();
+
+        assert_eq!(transcriber.transcribe(&[0.0; 1600]).unwrap(), "hello");
+        let lan_request = lan_request.lock().unwrap();
+        assert!(!lan_request.contains("sk-cloud"));
+        assert!(lan_request.contains("large-v3-turbo"));
+        let cloud_request = cloud_request.lock().unwrap();
+        assert!(cloud_request.contains("Bearer sk-cloud"));
+        assert!(cloud_request.contains("whisper-1"));
+        assert!(!cloud_request.contains("large-v3-turbo"));
+    }
+
+    #[test]
+    fn test_client_error_does_not_fail_over() {
+        let (rejecting, _) = mock_server("401 Unauthorized", r#"{"error":"bad key"}"#);
+        let (healthy, healthy_hits) = mock_server("200 OK", r#"{"text":"hello"}"#);
+        let transcriber = failover_transcriber(vec![rejecting, healthy]);
+
+        let err = transcriber.transcribe(&[0.0; 1600]).unwrap_err();
+        assert!(err.to_string().contains("401"));
+        assert_eq!(healthy_hits.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_failover_list_rejects_websocket() {
+        let config = WhisperConfig {
+            mode: Some(crate::config::WhisperMode::Remote),
+            remote_endpoint: Some(crate::config::RemoteEndpointConfig::Multiple(vec![
+                "http://localhost:8080".to_string().into(),
+                "ws://localhost:8081".to_string().into(),
+            ])),
+            ..Default::default()
+        };
+        let err = RemoteTranscriber::new(&config).unwrap_err();
+        assert!(err.to_string().contains("failover"));
+    }
 }
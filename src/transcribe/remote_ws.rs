@@ -0,0 +1,384 @@
+//! WebSocket streaming for the remote Whisper backend
+//!
+//! Used when `remote_endpoint` is a `ws://` or `wss://` URL. Instead of
+//! uploading one WAV after the hotkey is released, audio is streamed while
+//! the user is still speaking and the server sends transcripts back as it
+//! decodes, so long dictations finish almost as soon as speech stops.
+//!
+//! ## Protocol
+//!
+//! Client to server:
+//!
+//! - one text frame with the session settings:
+//!   `{"type":"start","sample_rate":16000,"format":"pcm_s16le",
+//!   "language":"en","translate":false,"model":"...","prompt":"..."}`
+//!   (`language` and `prompt` are omitted when unset)
+//! - binary frames of 16 kHz mono `pcm_s16le` audio
+//! - `{"type":"stop"}` once recording ends
+//!
+//! Server to client (text frames):
+//!
+//! - `{"type":"partial","text":"..."}`: current hypothesis for the
+//!   utterance in progress; may be revised
+//! - `{"type":"final","text":"..."}`: committed text for an utterance
+//! - `{"type":"error","message":"..."}`
+//! - `{"type":"done"}` (or closing the socket) after the last final
+//!
+//! Partials and finals are folded into one cumulative
+//! [`StreamingEvent::Hypothesis`], like incremental re-decoding, so the
+//! output session can correct a revised partial with backspaces.
+
+use super::streaming::{SegmentId, StreamHandle, StreamingEvent};
+use crate::error::TranscribeError;
+use futures_util::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::Message;
+
+/// The whole session is reported as one segment
+const SEGMENT: SegmentId = 0;
+
+/// Samples per binary frame in one-shot mode (~1 s of audio)
+const BATCH_FRAME_SAMPLES: usize = 16000;
+
+/// Connection settings shared by streaming and one-shot sessions
+#[derive(Debug, Clone)]
+pub struct WsSettings {
+    pub endpoint: String,
+    pub api_key: Option<String>,
+    /// Bounds the connect and the wait for the last transcript after stop
+    pub timeout: Duration,
+    pub suppress_phrases: Vec<String>,
+    pub start: StartFrame,
+}
+
+/// First frame of a session
+#[derive(Debug, Clone, Serialize)]
+pub struct StartFrame {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    sample_rate: u32,
+    format: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+    translate: bool,
+    model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prompt: Option<String>,
+}
+
+impl StartFrame {
+    pub fn new(
+        language: Option<String>,
+        translate: bool,
+        model: String,
+        prompt: Option<String>,
+    ) -> Self {
+        Self {
+            kind: "start",
+            sample_rate: 16000,
+            format: "pcm_s16le",
+            language,
+            translate,
+            model,
+            prompt,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ServerMessage {
+    Partial { text: String },
+    Final { text: String },
+    Error { message: String },
+    Done,
+}
+
+/// Running transcript: committed finals plus the current partial
+#[derive(Debug, Default)]
+struct Transcript {
+    committed: String,
+    partial: String,
+}
+
+impl Transcript {
+    fn text(&self) -> String {
+        join(&self.committed, &self.partial)
+    }
+
+    fn set_partial(&mut self, text: &str) {
+        self.partial = text.trim().to_string();
+    }
+
+    fn commit(&mut self, text: &str) {
+        self.committed = join(&self.committed, text.trim());
+        self.partial.clear();
+    }
+}
+
+fn join(a: &str, b: &str) -> String {
+    match (a.is_empty(), b.is_empty()) {
+        (_, true) => a.to_string(),
+        (true, false) => b.to_string(),
+        (false, false) => format!("{} {}", a, b),
+    }
+}
+
+fn f32_to_s16le_bytes(samples: &[f32]) -> Vec<u8> {
+    samples
+        .iter()
+        .flat_map(|&s| ((s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16).to_le_bytes())
+        .collect()
+}
+
+/// Start a streaming session on the current tokio runtime
+pub fn start_stream(settings: WsSettings, samples_rx: mpsc::Receiver<Vec<f32>>) -> StreamHandle {
+    let (events_tx, events_rx) = mpsc::channel::<StreamingEvent>(64);
+    let (cancel_tx, cancel_rx) = oneshot::channel::<()>();
+
+    let task = tokio::spawn(async move {
+        if let Err(e) = run_session(&settings, samples_rx, &events_tx, cancel_rx).await {
+            let _ = events_tx.send(StreamingEvent::Error(e)).await;
+        }
+        let _ = events_tx.send(StreamingEvent::Ended).await;
+        Ok(())
+    });
+
+    StreamHandle {
+        events: events_rx,
+        cancel: cancel_tx,
+        task,
+    }
+}
+
+/// Stream a finished recording through one session and return the final text
+pub async fn transcribe(settings: &WsSettings, samples: &[f32]) -> Result<String, TranscribeError> {
+    let (samples_tx, samples_rx) = mpsc::channel(samples.len() / BATCH_FRAME_SAMPLES + 1);
+    for chunk in samples.chunks(BATCH_FRAME_SAMPLES) {
+        let _ = samples_tx.try_send(chunk.to_vec());
+    }
+    drop(samples_tx);
+
+    let (events_tx, mut events_rx) = mpsc::channel(64);
+    let (_cancel_tx, cancel_rx) = oneshot::channel();
+    // Moving the sender in closes the event channel when the session ends
+    let session = async move { run_session(settings, samples_rx, &events_tx, cancel_rx).await };
+
+    let mut text = String::new();
+    let drain = async {
+        while let Some(event) = events_rx.recv().await {
+            if let StreamingEvent::Hypothesis { text: t, .. } = event {
+                text = t;
+            }
+        }
+    };
+    let (result, ()) = tokio::join!(session, drain);
+    result.map(|()| text)
+}
+
+async fn run_session(
+    settings: &WsSettings,
+    mut samples_rx: mpsc::Receiver<Vec<f32>>,
+    events_tx: &mpsc::Sender<StreamingEvent>,
+    mut cancel_rx: oneshot::Receiver<()>,
+) -> Result<(), TranscribeError> {
+    let mut request = settings
+        .endpoint
+        .as_str()
+        .into_client_request()
+        .map_err(|e| TranscribeError::ConfigError(format!("Invalid remote_endpoint: {}", e)))?;
+    if let Some(key) = &settings.api_key {
+        let value = HeaderValue::from_str(&format!("Bearer {}", key))
+            .map_err(|e| TranscribeError::ConfigError(format!("Invalid API key: {}", e)))?;
+        request.headers_mut().insert("Authorization", value);
+    }
+
+    let (ws_stream, _) =
+        tokio::time::timeout(settings.timeout, tokio_tungstenite::connect_async(request))
+            .await
+            .map_err(|_| TranscribeError::NetworkError("WebSocket connect timed out".into()))?
+            .map_err(|e| {
+                TranscribeError::NetworkError(format!("WebSocket connect failed: {}", e))
+            })?;
+    let (mut write, mut read) = ws_stream.split();
+
+    let start = serde_json::to_string(&settings.start).map_err(|e| {
+        TranscribeError::RemoteError(format!("Failed to encode start frame: {}", e))
+    })?;
+    write
+        .send(Message::Text(start))
+        .await
+        .map_err(|e| TranscribeError::NetworkError(format!("Failed to send start frame: {}", e)))?;
+
+    let mut transcript = Transcript::default();
+    let mut samples_closed = false;
+    let mut drain_deadline: Option<tokio::time::Instant> = None;
+
+    loop {
+        let drain_timer = async {
+            match drain_deadline {
+                Some(d) => tokio::time::sleep_until(d).await,
+                None => std::future::pending::<()>().await,
+            }
+        };
+
+        tokio::select! {
+            biased;
+
+            _ = &mut cancel_rx => {
+                tracing::debug!("Remote WebSocket session cancelled");
+                break;
+            }
+
+            _ = drain_timer, if drain_deadline.is_some() => {
+                tracing::warn!(
+                    "Remote server sent no final transcript within {}s of the end of audio",
+                    settings.timeout.as_secs()
+                );
+                break;
+            }
+
+            chunk = samples_rx.recv(), if !samples_closed => match chunk {
+                Some(c) if !c.is_empty() => {
+                    write.send(Message::Binary(f32_to_s16le_bytes(&c))).await.map_err(|e| {
+                        TranscribeError::NetworkError(format!("Failed to send audio: {}", e))
+                    })?;
+                }
+                Some(_) => {}
+                None => {
+                    samples_closed = true;
+                    write
+                        .send(Message::Text(r#"{"type":"stop"}"#.into()))
+                        .await
+                        .map_err(|e| {
+                            TranscribeError::NetworkError(format!("Failed to send stop: {}", e))
+                        })?;
+                    drain_deadline = Some(tokio::time::Instant::now() + settings.timeout);
+                }
+            },
+
+            msg = read.next() => {
+                let text = match msg {
+                    Some(Ok(Message::Text(t))) => t,
+                    Some(Ok(Message::Close(_))) | None => break,
+                    Some(Ok(_)) => continue,
+                    Some(Err(e)) => {
+                        return Err(TranscribeError::NetworkError(format!(
+                            "WebSocket error: {}",
+                            e
+                        )))
+                    }
+                };
+                let parsed: ServerMessage = match serde_json::from_str(&text) {
+                    Ok(m) => m,
+                    Err(e) => {
+                        tracing::debug!("Ignoring unrecognized server message ({}): {}", e, text);
+                        continue;
+                    }
+                };
+                match parsed {
+                    ServerMessage::Partial { text } => transcript.set_partial(&text),
+                    ServerMessage::Final { text } => transcript.commit(
+                        &super::whisper::strip_suppressed_phrases(&text, &settings.suppress_phrases),
+                    ),
+                    ServerMessage::Error { message } => {
+                        return Err(TranscribeError::RemoteError(message));
+                    }
+                    ServerMessage::Done => break,
+                }
+                let event = StreamingEvent::Hypothesis {
+                    text: transcript.text(),
+                    segment_id: SEGMENT,
+                };
+                if events_tx.send(event).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+
+    let _ = write.send(Message::Close(None)).await;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_frame_omits_unset_fields() {
+        let frame = StartFrame::new(None, false, "whisper-1".into(), None);
+        let json = serde_json::to_string(&frame).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"start","sample_rate":16000,"format":"pcm_s16le","translate":false,"model":"whisper-1"}"#
+        );
+    }
+
+    #[test]
+    fn test_server_messages_parse() {
+        let msg: ServerMessage =
+            serde_json::from_str(r#"{"type":"partial","text":"hel"}"#).unwrap();
+        assert!(matches!(msg, ServerMessage::Partial { text } if text == "hel"));
+        let msg: ServerMessage = serde_json::from_str(r#"{"type":"done"}"#).unwrap();
+        assert!(matches!(msg, ServerMessage::Done));
+    }
+
+    #[test]
+    fn test_transcript_replaces_partial_and_keeps_finals() {
+        let mut t = Transcript::default();
+        t.set_partial("hello wor");
+        assert_eq!(t.text(), "hello wor");
+        t.commit(" hello world. ");
+        t.set_partial("how are");
+        assert_eq!(t.text(), "hello world. how are");
+        t.commit("How are you?");
+        assert_eq!(t.text(), "hello world. How are you?");
+    }
+
+    #[tokio::test]
+    async fn test_transcribe_against_mock_server() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            let mut audio_bytes = 0;
+            while let Some(Ok(msg)) = ws.next().await {
+                match msg {
+                    Message::Binary(b) => audio_bytes += b.len(),
+                    Message::Text(t) if t.contains("\"stop\"") => break,
+                    _ => {}
+                }
+            }
+            for frame in [
+                r#"{"type":"partial","text":"hello wor"}"#,
+                r#"{"type":"final","text":"Hello world."}"#,
+                r#"{"type":"done"}"#,
+            ] {
+                ws.send(Message::Text(frame.into())).await.unwrap();
+            }
+            audio_bytes
+        });
+
+        let settings = WsSettings {
+            endpoint: format!("ws://{}", addr),
+            api_key: None,
+            timeout: Duration::from_secs(5),
+            suppress_phrases: vec![],
+            start: StartFrame::new(None, false, "whisper-1".into(), None),
+        };
+        let text = transcribe(&settings, &vec![0.0; 24000]).await.unwrap();
+        assert_eq!(text, "Hello world.");
+        assert_eq!(server.await.unwrap(), 48000);
+    }
+
+    #[test]
+    fn test_pcm_encoding_is_little_endian_s16() {
+        assert_eq!(f32_to_s16le_bytes(&[0.0, 1.0]), vec![0, 0, 0xff, 0x7f]);
+    }
+}
@@ -33,6 +33,8 @@ pub struct SenseVoiceTranscriber {
     text_norm_id: i32,
     fbank_extractor: FbankExtractor,
     ctc_config: CtcConfig,
+    /// Confidence of the most recent transcription, see [`ctc::ctc_confidence`]
+    last_confidence: std::sync::Mutex<Option<f32>>,
 }
 
 impl SenseVoiceTranscriber {
@@ -76,22 +78,28 @@ impl SenseVoiceTranscriber {
         tracing::debug!("Loaded {} tokens", tokens.len());
 
         // Create ONNX session.
-        // No GPU EP registration: SenseVoice runs on the CPU EP only.
-        // MIGraphX 7.2 rejects this encoder's Where-op broadcast pattern,
-        // so we keep the engine on CPU on the AMD-targeted binary.
-        let session = Session::builder()
-            .map_err(|e| {
-                TranscribeError::InitFailed(format!("ONNX session builder failed: {}", e))
-            })?
-            .with_intra_threads(threads)
-            .map_err(|e| TranscribeError::InitFailed(format!("Failed to set threads: {}", e)))?
-            .commit_from_file(&model_file)
-            .map_err(|e| {
-                TranscribeError::InitFailed(format!(
-                    "Failed to load SenseVoice model from {:?}: {}",
-                    model_file, e
-                ))
-            })?;
+        // No MIGraphX: 7.2 rejects this encoder's Where-op broadcast
+        // pattern. AMD GPUs are used through the ROCm EP in
+        // `sensevoice-rocm` builds; everything else runs on the CPU EP.
+        let session = super::model_file::commit_session(
+            Session::builder()
+                .map_err(|e| {
+                    TranscribeError::InitFailed(format!("ONNX session builder failed: {}", e))
+                })?
+                .with_intra_threads(threads)
+                .map_err(|e| TranscribeError::InitFailed(format!("Failed to set threads: {}", e)))
+                .and_then(|builder| {
+                    super::onnx_ep::register_rocm_ep(builder, "SenseVoice", "model")
+                        .map_err(|e| TranscribeError::InitFailed(format!("EPs: {}", e)))
+                })?,
+            &model_file,
+        )
+        .map_err(|e| {
+            TranscribeError::InitFailed(format!(
+                "Failed to load SenseVoice model from {:?}: {}",
+                model_file, e
+            ))
+        })?;
 
         // Read CMVN stats from model metadata
         let (neg_mean, inv_stddev) = read_cmvn_from_metadata(&session)?;
@@ -119,6 +127,7 @@ impl SenseVoiceTranscriber {
             text_norm_id,
             fbank_extractor,
             ctc_config: CtcConfig::sensevoice(),
+            last_confidence: std::sync::Mutex::new(None),
         })
     }
 }
@@ -237,9 +246,16 @@ impl Transcriber for SenseVoiceTranscriber {
         tracing::debug!("Logits shape: {:?}", shape_dims);
 
         // logits shape: [batch=1, time_steps] or [batch=1, time_steps, vocab_size]
+        let mut confidence = None;
         let result = if shape_dims.len() == 3 {
             let time_steps = shape_dims[1] as usize;
             let vocab_size = shape_dims[2] as usize;
+            confidence = ctc::ctc_confidence(
+                logits_data,
+                time_steps,
+                vocab_size,
+                self.ctc_config.blank_id,
+            );
             ctc::ctc_greedy_decode(
                 logits_data,
                 time_steps,
@@ -258,6 +274,10 @@ impl Transcriber for SenseVoiceTranscriber {
             )));
         };
 
+        if let Ok(mut guard) = self.last_confidence.lock() {
+            *guard = confidence;
+        }
+
         tracing::info!(
             "SenseVoice transcription completed in {:.2}s: {:?}",
             start.elapsed().as_secs_f32(),
@@ -270,6 +290,10 @@ impl Transcriber for SenseVoiceTranscriber {
 
         Ok(result)
     }
+
+    fn last_confidence(&self) -> Option<f32> {
+        self.last_confidence.lock().ok().and_then(|g| *g)
+    }
 }
 
 /// Map language string to SenseVoice language ID
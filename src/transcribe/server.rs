@@ -0,0 +1,337 @@
+//! Managed whisper-server transcription
+//!
+//! Spawns whisper.cpp's `whisper-server` as a child process bound to
+//! localhost and sends audio to its OpenAI-compatible endpoint through
+//! [`RemoteTranscriber`]. The model stays resident in the server (on the
+//! GPU if the server was built with one) without linking whisper-rs,
+//! which helps systems where the FFI bindings crash.
+//!
+//! The server is health-checked before first use and restarted if it has
+//! exited by the next transcription. It is killed when the transcriber is
+//! dropped, e.g. on daemon shutdown or model eviction.
+//!
+//! voxtype does not download whisper-server: whisper.cpp publishes no Linux
+//! release binaries, so it must be built and installed (or pointed to with
+//! `whisper_server_path`) by the user.
+//!
+//! Without `whisper_server_port`, a free port is picked before the server
+//! starts. Another process can take it in between, so a server that exits
+//! during startup is retried on a fresh port.
+
+use super::remote::RemoteTranscriber;
+use super::whisper::resolve_model_path;
+use super::Transcriber;
+use crate::config::{WhisperConfig, WhisperMode};
+use crate::error::TranscribeError;
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// How long the server may take to load its model and accept connections
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Interval between readiness probes while the server starts
+const PROBE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Path the server serves transcriptions on, matching what
+/// `RemoteTranscriber` posts to
+const INFERENCE_PATH: &str = "/v1/audio/transcriptions";
+
+/// Startup attempts on fresh ports when an auto-picked port was taken
+const PORT_ATTEMPTS: usize = 3;
+
+/// Transcriber backed by a supervised local whisper-server process
+pub struct ServerTranscriber {
+    /// Command line used to (re)start the server, without host and port
+    server_path: PathBuf,
+    args: Vec<String>,
+    /// `whisper_server_port`, or `None` to pick a free port on each start
+    port: Option<u16>,
+    /// Config the HTTP client is built from
+    config: WhisperConfig,
+    server: Mutex<RunningServer>,
+}
+
+/// A started server and the HTTP client for its address
+struct RunningServer {
+    child: Child,
+    addr: SocketAddr,
+    remote: Arc<RemoteTranscriber>,
+}
+
+impl ServerTranscriber {
+    /// Start whisper-server for the configured model and wait until it is ready
+    pub fn new(config: &WhisperConfig) -> Result<Self, TranscribeError> {
+        let server_path = resolve_server_path(config.whisper_server_path.as_deref())?;
+        let model_path = resolve_model_path(&config.model, &config.custom_models)?;
+
+        let threads = config.threads.unwrap_or_else(|| num_cpus::get().min(4));
+        let language = if config.language.is_auto() {
+            "auto".to_string()
+        } else {
+            config.language.primary().to_string()
+        };
+        let mut args = vec![
+            "--model".to_string(),
+            model_path.to_string_lossy().into_owned(),
+            "--threads".to_string(),
+            threads.to_string(),
+            "--language".to_string(),
+            language,
+            "--inference-path".to_string(),
+            INFERENCE_PATH.to_string(),
+        ];
+        if config.translate {
+            args.push("--translate".to_string());
+        }
+        if config.flash_attention {
+            args.push("--flash-attn".to_string());
+        }
+
+        tracing::info!(
+            "Starting whisper-server {:?} with model {:?}",
+            server_path,
+            model_path
+        );
+        let server = start(&server_path, &args, config.whisper_server_port, config)?;
+
+        Ok(Self {
+            server_path,
+            args,
+            port: config.whisper_server_port,
+            config: config.clone(),
+            server: Mutex::new(server),
+        })
+    }
+
+    /// Restart the server if it has exited since the last request and
+    /// return the client for the running one
+    fn ensure_running(&self) -> Result<Arc<RemoteTranscriber>, TranscribeError> {
+        let mut server = self
+            .server
+            .lock()
+            .map_err(|e| TranscribeError::InferenceFailed(format!("Server lock: {}", e)))?;
+        if let Ok(Some(status)) = server.child.try_wait() {
+            tracing::warn!("whisper-server exited ({}), restarting", status);
+            *server = start(&self.server_path, &self.args, self.port, &self.config)?;
+        }
+        Ok(Arc::clone(&server.remote))
+    }
+}
+
+impl Transcriber for ServerTranscriber {
+    fn transcribe(&self, samples: &[f32]) -> Result<String, TranscribeError> {
+        self.ensure_running()?.transcribe(samples)
+    }
+}
+
+impl Drop for ServerTranscriber {
+    fn drop(&mut self) {
+        if let Ok(server) = self.server.get_mut() {
+            let _ = server.child.kill();
+            let _ = server.child.wait();
+            tracing::debug!("Stopped whisper-server on {}", server.addr);
+        }
+    }
+}
+
+/// Start the server and build the HTTP client for its address
+fn start(
+    server_path: &PathBuf,
+    args: &[String],
+    port: Option<u16>,
+    config: &WhisperConfig,
+) -> Result<RunningServer, TranscribeError> {
+    let (child, addr) = start_on_port(port, |addr| {
+        let mut args = args.to_vec();
+        args.extend([
+            "--host".to_string(),
+            addr.ip().to_string(),
+            "--port".to_string(),
+            addr.port().to_string(),
+        ]);
+        spawn_and_wait(server_path, &args, addr)
+    })?;
+
+    // Translation is a server flag, so requests always use the
+    // transcriptions path
+    let remote = RemoteTranscriber::new(&WhisperConfig {
+        mode: Some(WhisperMode::Remote),
+        remote_endpoint: Some(format!("http://{}", addr).into()),
+        translate: false,
+        ..config.clone()
+    })?;
+
+    Ok(RunningServer {
+        child,
+        addr,
+        remote: Arc::new(remote),
+    })
+}
+
+/// Run `spawn` on the configured port, or on a free port. A free port can
+/// be taken before the server binds it, so an auto-picked port is replaced
+/// and the spawn retried when the server fails to start.
+fn start_on_port(
+    port: Option<u16>,
+    mut spawn: impl FnMut(SocketAddr) -> Result<Child, TranscribeError>,
+) -> Result<(Child, SocketAddr), TranscribeError> {
+    if let Some(port) = port {
+        let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+        return spawn(addr).map(|child| (child, addr));
+    }
+
+    let mut attempt = 1;
+    loop {
+        let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, free_port()?));
+        match spawn(addr) {
+            Ok(child) => return Ok((child, addr)),
+            Err(e) if attempt < PORT_ATTEMPTS => {
+                tracing::warn!(
+                    "whisper-server failed to start on {}: {}. Retrying on another port",
+                    addr,
+                    e
+                );
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Spawn the server and block until it accepts connections
+fn spawn_and_wait(
+    server_path: &PathBuf,
+    args: &[String],
+    addr: SocketAddr,
+) -> Result<Child, TranscribeError> {
+    let mut child = Command::new(server_path)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| {
+            TranscribeError::InitFailed(format!("Failed to start whisper-server: {}", e))
+        })?;
+
+    let start = Instant::now();
+    loop {
+        if let Ok(Some(status)) = child.try_wait() {
+            return Err(TranscribeError::InitFailed(format!(
+                "whisper-server exited during startup ({}). Run {:?} by hand to see its output.",
+                status, server_path
+            )));
+        }
+        if TcpStream::connect_timeout(&addr, PROBE_INTERVAL).is_ok() {
+            tracing::info!(
+                "whisper-server ready in {:.2}s",
+                start.elapsed().as_secs_f32()
+            );
+            return Ok(child);
+        }
+        if start.elapsed() > STARTUP_TIMEOUT {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(TranscribeError::InitFailed(format!(
+                "whisper-server did not start listening on {} within {}s",
+                addr,
+                STARTUP_TIMEOUT.as_secs()
+            )));
+        }
+        std::thread::sleep(PROBE_INTERVAL);
+    }
+}
+
+/// Ask the OS for a free localhost port
+fn free_port() -> Result<u16, TranscribeError> {
+    TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+        .and_then(|l| l.local_addr())
+        .map(|a| a.port())
+        .map_err(|e| TranscribeError::InitFailed(format!("No free port for whisper-server: {}", e)))
+}
+
+/// Resolve the whisper-server binary
+fn resolve_server_path(configured_path: Option<&str>) -> Result<PathBuf, TranscribeError> {
+    if let Some(path) = configured_path {
+        let p = PathBuf::from(path);
+        if p.exists() {
+            return Ok(p);
+        }
+        return Err(TranscribeError::InitFailed(format!(
+            "Configured whisper-server path not found: {}",
+            path
+        )));
+    }
+
+    let candidates = [
+        which::which("whisper-server").ok(),
+        Some(PathBuf::from("/usr/local/bin/whisper-server")),
+        Some(PathBuf::from("/usr/bin/whisper-server")),
+        directories::BaseDirs::new().map(|d| d.home_dir().join(".local/bin/whisper-server")),
+    ];
+
+    for candidate in candidates.into_iter().flatten() {
+        if candidate.exists() {
+            return Ok(candidate);
+        }
+    }
+
+    Err(TranscribeError::InitFailed(
+        "whisper-server not found. voxtype does not download it: build it from https://github.com/ggerganov/whisper.cpp and install it in PATH, or set whisper_server_path in config.".to_string()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_server_not_found() {
+        let result = resolve_server_path(Some("/nonexistent/whisper-server"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_free_port_is_nonzero() {
+        assert_ne!(free_port().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_auto_port_retries_on_startup_failure() {
+        let mut ports = Vec::new();
+        let (mut child, addr) = start_on_port(None, |addr| {
+            ports.push(addr.port());
+            if ports.len() == 1 {
+                // Port taken between free_port() and the server binding it
+                Err(TranscribeError::InitFailed("exited during startup".into()))
+            } else {
+                Ok(Command::new("true").spawn().unwrap())
+            }
+        })
+        .unwrap();
+        let _ = child.wait();
+        assert_eq!(ports.len(), 2);
+        assert_eq!(addr.port(), ports[1]);
+    }
+
+    #[test]
+    fn test_fixed_port_is_not_retried() {
+        let mut attempts = 0;
+        let result = start_on_port(Some(8178), |_| {
+            attempts += 1;
+            Err(TranscribeError::InitFailed("exited during startup".into()))
+        });
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn test_spawn_fails_fast_when_server_exits() {
+        let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, free_port().unwrap()));
+        let err = spawn_and_wait(&PathBuf::from("false"), &[], addr).unwrap_err();
+        assert!(err.to_string().contains("exited during startup"));
+    }
+}
@@ -190,12 +190,13 @@ impl SonioxTranscriber {
         if let Some(c) = self.async_client.get() {
             return Ok(c);
         }
-        let client = reqwest::Client::builder()
-            .timeout(SONIOX_TIMEOUT)
-            .build()
-            .map_err(|e| {
-                TranscribeError::InferenceFailed(format!("reqwest client build failed: {}", e))
-            })?;
+        let mut builder = reqwest::Client::builder().timeout(SONIOX_TIMEOUT);
+        if let Some(proxy) = crate::network::reqwest_proxy() {
+            builder = builder.proxy(proxy);
+        }
+        let client = builder.build().map_err(|e| {
+            TranscribeError::InferenceFailed(format!("reqwest client build failed: {}", e))
+        })?;
         // get_or_init can't return Result, so first build then OnceLock::set;
         // race with another thread is fine — we'd just drop one extra client.
         let _ = self.async_client.set(client);
@@ -1434,6 +1435,7 @@ mod tests {
                 StreamingEvent::Replace {
                     backspace, text, ..
                 } => ("Replace", format!("-{}+{}", backspace, text)),
+                StreamingEvent::Hypothesis { text, .. } => ("Hypothesis", text.clone()),
                 StreamingEvent::Ended => ("Ended", String::new()),
                 StreamingEvent::Error(e) => ("Error", e.to_string()),
             })
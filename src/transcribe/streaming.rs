@@ -84,6 +84,13 @@ pub enum StreamingEvent {
         segment_id: SegmentId,
     },
 
+    /// Full transcript so far, replacing whatever earlier hypotheses
+    /// produced. Emitted by re-decoding backends (see
+    /// [`super::incremental`]) whose text may change anywhere, not just in
+    /// the tail. The daemon diffs it against what it has already typed and
+    /// backspaces only the part that changed.
+    Hypothesis { text: String, segment_id: SegmentId },
+
     /// The backend has finished processing all audio sent so far and is
     /// closing the stream gracefully (e.g., the daemon dropped the
     /// samples sender and the backend has flushed).
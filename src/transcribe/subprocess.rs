@@ -15,6 +15,14 @@
 //! - Worker loads model while user is speaking
 //! - `transcribe()` sends audio to already-ready worker
 //! - Perceived latency is just transcription time, not model load + transcription
+//!
+//! Warm worker pool (`worker_idle_ttl_secs > 0`):
+//! - After each job a replacement worker is spawned in the background, so the
+//!   next recording finds a model already loaded
+//! - Each worker still serves exactly one job and exits, so memory from the
+//!   finished transcription is always released
+//! - A warm worker that sits idle longer than the TTL is retired, releasing
+//!   the GPU until the next recording starts
 
 use super::worker::READY_SIGNAL;
 use super::Transcriber;
@@ -22,7 +30,9 @@ use crate::config::WhisperConfig;
 use crate::error::TranscribeError;
 use std::io::{BufRead, BufReader, Read, Write};
 use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use ureq::serde_json;
 
 /// Response from the transcription worker process
@@ -45,6 +55,42 @@ struct PreparedWorker {
     child: Child,
     stdin: ChildStdin,
     stdout: BufReader<ChildStdout>,
+    /// Pool generation this worker was spawned for. The idle reaper only
+    /// retires the worker if the slot still holds the same generation.
+    generation: u64,
+}
+
+impl PreparedWorker {
+    /// Whether the worker process is still running (not crashed or exited)
+    fn is_alive(&mut self) -> bool {
+        matches!(self.child.try_wait(), Ok(None))
+    }
+
+    /// Kill the worker and reap it so no zombie is left behind
+    fn retire(mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// State shared between the transcriber and its background pool threads
+struct WorkerSlot {
+    /// Config to pass to the worker
+    config: WhisperConfig,
+    /// Path to the config file (if any)
+    config_path: Option<std::path::PathBuf>,
+    /// Pre-spawned worker (from prepare() or a pool refill)
+    worker: Mutex<Option<PreparedWorker>>,
+    /// Bumped on every spawn and every claim so stale reapers leave newer
+    /// (or claimed) workers alone
+    generation: AtomicU64,
+}
+
+impl WorkerSlot {
+    /// Allocate a generation no armed reaper is waiting on
+    fn next_generation(&self) -> u64 {
+        self.generation.fetch_add(1, Ordering::SeqCst) + 1
+    }
 }
 
 /// Subprocess-based transcriber for GPU isolation
@@ -54,14 +100,14 @@ struct PreparedWorker {
 /// This ensures all GPU resources are released after transcription.
 ///
 /// With eager spawning (`prepare()` called when recording starts), the worker
-/// loads the model while the user is speaking, hiding load latency.
+/// loads the model while the user is speaking, hiding load latency. With a
+/// non-zero `worker_idle_ttl_secs`, a replacement worker is kept warm after
+/// each job and retired once it has been idle for the TTL.
 pub struct SubprocessTranscriber {
-    /// Config to pass to the worker
-    config: WhisperConfig,
-    /// Path to the config file (if any)
-    config_path: Option<std::path::PathBuf>,
-    /// Pre-spawned worker (from prepare())
-    prepared_worker: Mutex<Option<PreparedWorker>>,
+    slot: Arc<WorkerSlot>,
+    /// How long a pre-spawned worker may sit idle before it is retired.
+    /// `None` disables the warm pool (one worker per recording).
+    idle_ttl: Option<Duration>,
     /// Last language reported by the worker, if any. Mirrors
     /// `WhisperTranscriber::last_language` so the daemon can derive a layout
     /// hint after transcription. See [`Transcriber::last_detected_language`].
@@ -74,14 +120,27 @@ impl SubprocessTranscriber {
         config: &WhisperConfig,
         config_path: Option<std::path::PathBuf>,
     ) -> Result<Self, TranscribeError> {
+        let idle_ttl = match config.worker_idle_ttl_secs {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        };
         Ok(Self {
-            config: config.clone(),
-            config_path,
-            prepared_worker: Mutex::new(None),
+            slot: Arc::new(WorkerSlot {
+                config: config.clone(),
+                config_path,
+                worker: Mutex::new(None),
+                generation: AtomicU64::new(0),
+            }),
+            idle_ttl,
             last_language: Mutex::new(None),
         })
     }
 
+    /// Whether this transcriber keeps a warm worker between recordings
+    pub fn is_pooled(&self) -> bool {
+        self.idle_ttl.is_some()
+    }
+
     /// Get the path to the voxtype executable
     fn get_executable_path() -> Result<std::path::PathBuf, TranscribeError> {
         std::env::current_exe().map_err(|e| {
@@ -90,14 +149,14 @@ impl SubprocessTranscriber {
     }
 
     /// Build the command to spawn a worker
-    fn build_worker_command(&self) -> Result<Command, TranscribeError> {
+    fn build_worker_command(slot: &WorkerSlot) -> Result<Command, TranscribeError> {
         let exe_path = Self::get_executable_path()?;
 
         let mut cmd = Command::new(&exe_path);
 
         // Pass config path BEFORE the subcommand — --config is a parent-level
         // arg in clap, so it must appear before "transcribe-worker"
-        if let Some(ref config_path) = self.config_path {
+        if let Some(ref config_path) = slot.config_path {
             cmd.arg("--config").arg(config_path);
         }
 
@@ -107,15 +166,15 @@ impl SubprocessTranscriber {
             .stderr(Stdio::piped());
 
         // Pass essential config via command-line arguments
-        cmd.arg("--model").arg(&self.config.model);
+        cmd.arg("--model").arg(&slot.config.model);
         // Serialize language config as comma-separated string for CLI
         // Single: "en", Auto: "auto", Multiple: "en,fr,de"
-        let language_str = self.config.language.as_vec().join(",");
+        let language_str = slot.config.language.as_vec().join(",");
         cmd.arg("--language").arg(&language_str);
-        if self.config.translate {
+        if slot.config.translate {
             cmd.arg("--translate");
         }
-        if let Some(threads) = self.config.threads {
+        if let Some(threads) = slot.config.threads {
             cmd.arg("--threads").arg(threads.to_string());
         }
 
@@ -123,8 +182,8 @@ impl SubprocessTranscriber {
     }
 
     /// Spawn a worker process and wait for it to be ready
-    fn spawn_and_wait_ready(&self) -> Result<PreparedWorker, TranscribeError> {
-        let mut cmd = self.build_worker_command()?;
+    fn spawn_and_wait_ready(slot: &WorkerSlot) -> Result<PreparedWorker, TranscribeError> {
+        let mut cmd = Self::build_worker_command(slot)?;
 
         let mut child = cmd.spawn().map_err(|e| {
             TranscribeError::InitFailed(format!("Failed to spawn transcribe-worker: {}", e))
@@ -167,9 +226,75 @@ impl SubprocessTranscriber {
             child,
             stdin,
             stdout,
+            generation: slot.next_generation(),
         })
     }
 
+    /// Take the pre-spawned worker out of the slot, discarding it if the
+    /// process has died since it signaled READY.
+    fn take_live_worker(slot: &WorkerSlot) -> Option<PreparedWorker> {
+        let mut worker = slot.worker.lock().unwrap().take()?;
+        if worker.is_alive() {
+            Some(worker)
+        } else {
+            tracing::debug!("Discarding pre-spawned worker that exited while idle");
+            worker.retire();
+            None
+        }
+    }
+
+    /// Store a ready worker in the slot, retiring whatever was there before
+    fn store_worker(slot: &WorkerSlot, worker: PreparedWorker) {
+        let previous = slot.worker.lock().unwrap().replace(worker);
+        if let Some(previous) = previous {
+            previous.retire();
+        }
+    }
+
+    /// Spawn a replacement worker in the background and arm its idle reaper
+    fn refill_pool(&self) {
+        let Some(ttl) = self.idle_ttl else {
+            return;
+        };
+        let slot = Arc::clone(&self.slot);
+        std::thread::spawn(move || {
+            let start = Instant::now();
+            let worker = match Self::spawn_and_wait_ready(&slot) {
+                Ok(worker) => worker,
+                Err(e) => {
+                    tracing::warn!("Failed to refill worker pool: {}", e);
+                    return;
+                }
+            };
+            let generation = worker.generation;
+            Self::store_worker(&slot, worker);
+            tracing::debug!(
+                "Warm worker ready in {:.2}s (retired after {}s idle)",
+                start.elapsed().as_secs_f32(),
+                ttl.as_secs()
+            );
+
+            Self::retire_after_idle(&slot, generation, ttl);
+        });
+    }
+
+    /// Sleep for the idle TTL, then retire the slot's worker if it is still
+    /// the one of `generation` (not replaced, and not claimed by `prepare()`)
+    fn retire_after_idle(slot: &WorkerSlot, generation: u64, ttl: Duration) {
+        std::thread::sleep(ttl);
+        let mut guard = slot.worker.lock().unwrap();
+        if guard.as_ref().map(|w| w.generation) == Some(generation) {
+            if let Some(worker) = guard.take() {
+                drop(guard);
+                tracing::info!(
+                    "Retiring warm worker after {}s idle (releasing GPU memory)",
+                    ttl.as_secs()
+                );
+                worker.retire();
+            }
+        }
+    }
+
     /// Write audio samples to the worker's stdin
     fn write_audio_to_worker(
         stdin: &mut ChildStdin,
@@ -219,13 +344,21 @@ impl SubprocessTranscriber {
 
 impl Transcriber for SubprocessTranscriber {
     fn prepare(&self) {
+        // A warm worker from the pool is already loaded; keep it. A recording
+        // now owns it, so move it to a new generation to disarm its reaper.
+        if let Some(mut worker) = Self::take_live_worker(&self.slot) {
+            tracing::debug!("Warm worker already available, skipping spawn");
+            worker.generation = self.slot.next_generation();
+            Self::store_worker(&self.slot, worker);
+            return;
+        }
+
         tracing::debug!("Preparing subprocess transcriber (spawning worker)...");
         let start = std::time::Instant::now();
 
-        match self.spawn_and_wait_ready() {
+        match Self::spawn_and_wait_ready(&self.slot) {
             Ok(worker) => {
-                let mut guard = self.prepared_worker.lock().unwrap();
-                *guard = Some(worker);
+                Self::store_worker(&self.slot, worker);
                 tracing::info!(
                     "Worker prepared in {:.2}s (model loaded while recording)",
                     start.elapsed().as_secs_f32()
@@ -247,8 +380,7 @@ impl Transcriber for SubprocessTranscriber {
         let duration_secs = samples.len() as f32 / 16000.0;
 
         // Try to use prepared worker, or spawn a new one
-        let mut prepared = self.prepared_worker.lock().unwrap();
-        let mut worker = match prepared.take() {
+        let mut worker = match Self::take_live_worker(&self.slot) {
             Some(w) => {
                 tracing::debug!(
                     "Using pre-spawned worker for {:.2}s of audio",
@@ -261,10 +393,9 @@ impl Transcriber for SubprocessTranscriber {
                     "No prepared worker, spawning new one for {:.2}s of audio",
                     duration_secs
                 );
-                self.spawn_and_wait_ready()?
+                Self::spawn_and_wait_ready(&self.slot)?
             }
         };
-        drop(prepared); // Release lock
 
         let start = std::time::Instant::now();
 
@@ -296,6 +427,9 @@ impl Transcriber for SubprocessTranscriber {
             start.elapsed().as_secs_f32()
         );
 
+        // The worker that served this job has exited; keep the next one warm.
+        self.refill_pool();
+
         // Record reported language for layout-aware output methods. Missing
         // (older worker) leaves the previous value untouched-by-success
         // semantics: clear it on every successful call so stale language
@@ -324,6 +458,14 @@ impl Transcriber for SubprocessTranscriber {
     }
 }
 
+impl Drop for SubprocessTranscriber {
+    fn drop(&mut self) {
+        if let Some(worker) = self.slot.worker.lock().ok().and_then(|mut g| g.take()) {
+            worker.retire();
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -350,4 +492,58 @@ mod tests {
         assert!(success.ok);
         assert_eq!(success.language, Some("ru".to_string()));
     }
+
+    /// A stand-in worker process that stays alive until killed
+    fn idle_worker(slot: &WorkerSlot) -> PreparedWorker {
+        let mut child = Command::new("cat")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        PreparedWorker {
+            stdin: child.stdin.take().unwrap(),
+            stdout: BufReader::new(child.stdout.take().unwrap()),
+            child,
+            generation: slot.next_generation(),
+        }
+    }
+
+    #[test]
+    fn idle_reaper_retires_unclaimed_worker() {
+        let transcriber = SubprocessTranscriber::new(&WhisperConfig::default(), None).unwrap();
+        let slot = Arc::clone(&transcriber.slot);
+        let worker = idle_worker(&slot);
+        let generation = worker.generation;
+        SubprocessTranscriber::store_worker(&slot, worker);
+
+        SubprocessTranscriber::retire_after_idle(&slot, generation, Duration::from_millis(50));
+        assert!(slot.worker.lock().unwrap().is_none());
+    }
+
+    #[test]
+    fn idle_reaper_leaves_worker_claimed_by_prepare() {
+        let transcriber = SubprocessTranscriber::new(&WhisperConfig::default(), None).unwrap();
+        let slot = Arc::clone(&transcriber.slot);
+        let worker = idle_worker(&slot);
+        let generation = worker.generation;
+        SubprocessTranscriber::store_worker(&slot, worker);
+
+        let reaper = {
+            let slot = Arc::clone(&slot);
+            std::thread::spawn(move || {
+                SubprocessTranscriber::retire_after_idle(
+                    &slot,
+                    generation,
+                    Duration::from_millis(200),
+                )
+            })
+        };
+        // Recording starts before the TTL runs out
+        transcriber.prepare();
+        reaper.join().unwrap();
+
+        let mut guard = slot.worker.lock().unwrap();
+        let worker = guard.as_mut().expect("claimed worker was retired");
+        assert!(worker.is_alive());
+    }
 }
@@ -0,0 +1,127 @@
+//! Two-pass "fast draft, accurate redo" transcription
+//!
+//! With `[whisper] redo_model` set, the primary (small, fast) model's draft
+//! is output immediately as usual, then the same audio is re-transcribed by
+//! the larger redo model on a background thread. If the two disagree by
+//! more than `redo_min_change`, the daemon offers the correction according
+//! to `redo_action`.
+//!
+//! The redo transcriber is loaded on first use inside the background task
+//! and handed back with the result, so the daemon's event loop never blocks
+//! on loading a large model.
+
+use super::Transcriber;
+use crate::config::WhisperConfig;
+use crate::error::TranscribeError;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Outcome of a background redo pass
+pub struct RedoOutcome {
+    /// The redo transcriber, to be cached for the next recording
+    pub transcriber: Option<Arc<dyn Transcriber>>,
+    /// Text that was output for the draft (after text and post-processing),
+    /// which the processed redo is compared against
+    pub output_text: String,
+    /// Whether the draft was typed and can be backspaced over
+    pub replaceable: bool,
+    /// Raw redo transcription
+    pub result: Result<String, TranscribeError>,
+}
+
+/// Re-transcribe `samples` with `config.redo_model` on a blocking thread.
+/// `cached` is reused when present; otherwise the model is loaded first.
+pub fn spawn_redo(
+    config: &WhisperConfig,
+    config_path: Option<PathBuf>,
+    cached: Option<Arc<dyn Transcriber>>,
+    samples: Vec<f32>,
+    output_text: String,
+    replaceable: bool,
+) -> tokio::task::JoinHandle<RedoOutcome> {
+    let mut redo_config = config.clone();
+    if let Some(model) = &config.redo_model {
+        redo_config.model = model.clone();
+    }
+
+    tokio::task::spawn_blocking(move || {
+        let transcriber = match cached {
+            Some(t) => t,
+            None => {
+                tracing::info!("Loading redo model: {}", redo_config.model);
+                match super::create_transcriber_with_config_path(&redo_config, config_path) {
+                    Ok(t) => Arc::from(t),
+                    Err(e) => {
+                        return RedoOutcome {
+                            transcriber: None,
+                            output_text,
+                            replaceable,
+                            result: Err(e),
+                        }
+                    }
+                }
+            }
+        };
+        let result = transcriber.transcribe(&samples);
+        RedoOutcome {
+            transcriber: Some(transcriber),
+            output_text,
+            replaceable,
+            result,
+        }
+    })
+}
+
+/// Fraction of words that differ between `draft` and `redo`: word-level
+/// edit distance divided by the longer word count. Case and trailing
+/// punctuation are ignored so "Hello," vs "hello." counts as unchanged.
+pub fn word_change_ratio(draft: &str, redo: &str) -> f32 {
+    fn words(text: &str) -> Vec<String> {
+        text.split_whitespace()
+            .map(|w| {
+                w.trim_matches(|c: char| c.is_ascii_punctuation())
+                    .to_lowercase()
+            })
+            .filter(|w| !w.is_empty())
+            .collect()
+    }
+
+    let a = words(draft);
+    let b = words(redo);
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 0.0;
+    }
+
+    // Single-row Levenshtein over words
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, wa) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, wb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(wa != wb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()] as f32 / longest as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_text_has_no_change() {
+        assert_eq!(word_change_ratio("hello world", "hello world"), 0.0);
+        assert_eq!(word_change_ratio("Hello, world.", "hello world"), 0.0);
+        assert_eq!(word_change_ratio("", ""), 0.0);
+    }
+
+    #[test]
+    fn change_ratio_counts_word_edits() {
+        assert_eq!(word_change_ratio("the cat sat", "the bat sat"), 1.0 / 3.0);
+        assert_eq!(word_change_ratio("one two", "one two three four"), 0.5);
+        assert_eq!(word_change_ratio("", "anything"), 1.0);
+    }
+}
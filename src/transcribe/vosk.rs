@@ -0,0 +1,361 @@
+//! Vosk speech-to-text transcription (Kaldi via libvosk)
+//!
+//! Vosk's small models are Kaldi TDNN acoustic models with a compact
+//! n-gram graph. They decode in a fraction of real time on CPUs that are
+//! too old for Whisper tiny, at the cost of accuracy.
+//!
+//! libvosk is opened at runtime with `libloading` instead of being linked,
+//! so voxtype builds without the Vosk SDK installed and reports a clear
+//! error when the library is missing.
+//!
+//! Pipeline: Audio (f32, 16kHz) -> scale to 16-bit range -> Kaldi recognizer -> JSON result
+//!
+//! Languages: one per model (20+ published at alphacephei.com/vosk/models)
+//! Model files: standard Vosk model directory (am/, conf/, graph/)
+
+use super::{TimedWord, Transcriber};
+use crate::config::VoskConfig;
+use crate::error::TranscribeError;
+use libloading::Library;
+use std::ffi::{c_char, c_float, c_int, c_void, CStr, CString};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Sample rate expected by voxtype's audio pipeline and passed to Vosk
+const SAMPLE_RATE: f32 = 16000.0;
+
+#[cfg(target_os = "macos")]
+const DEFAULT_LIBRARY: &str = "libvosk.dylib";
+#[cfg(not(target_os = "macos"))]
+const DEFAULT_LIBRARY: &str = "libvosk.so";
+
+/// Function pointers resolved from libvosk. `_lib` keeps the library
+/// mapped for as long as the pointers are in use.
+struct VoskApi {
+    _lib: Library,
+    model_new: unsafe extern "C" fn(*const c_char) -> *mut c_void,
+    model_free: unsafe extern "C" fn(*mut c_void),
+    recognizer_new: unsafe extern "C" fn(*mut c_void, c_float) -> *mut c_void,
+    recognizer_set_words: unsafe extern "C" fn(*mut c_void, c_int),
+    recognizer_accept_waveform_f: unsafe extern "C" fn(*mut c_void, *const c_float, c_int) -> c_int,
+    recognizer_final_result: unsafe extern "C" fn(*mut c_void) -> *const c_char,
+    recognizer_free: unsafe extern "C" fn(*mut c_void),
+}
+
+impl VoskApi {
+    fn load(path: &Path) -> Result<Self, TranscribeError> {
+        // SAFETY: loading libvosk runs its (Kaldi/OpenFST) static
+        // initializers, which have no preconditions on our side.
+        let lib = unsafe { Library::new(path) }.map_err(|e| {
+            TranscribeError::InitFailed(format!(
+                "Failed to load {}: {}\n  \
+                 Install libvosk from https://github.com/alphacep/vosk-api/releases\n  \
+                 or set [vosk] library to its full path.",
+                path.display(),
+                e
+            ))
+        })?;
+
+        macro_rules! symbol {
+            ($name:literal) => {
+                // SAFETY: the signature matches the declaration in vosk_api.h
+                *unsafe { lib.get(concat!($name, "\0").as_bytes()) }.map_err(|e| {
+                    TranscribeError::InitFailed(format!(
+                        "{} is missing symbol {}: {}",
+                        path.display(),
+                        $name,
+                        e
+                    ))
+                })?
+            };
+        }
+
+        let set_log_level: unsafe extern "C" fn(c_int) = symbol!("vosk_set_log_level");
+        // Kaldi logs every model component at level 0; keep stderr quiet
+        // unless voxtype itself is debugging.
+        let level = if tracing::enabled!(tracing::Level::DEBUG) {
+            0
+        } else {
+            -1
+        };
+        // SAFETY: plain setter on a global
+        unsafe { set_log_level(level) };
+
+        Ok(Self {
+            model_new: symbol!("vosk_model_new"),
+            model_free: symbol!("vosk_model_free"),
+            recognizer_new: symbol!("vosk_recognizer_new"),
+            recognizer_set_words: symbol!("vosk_recognizer_set_words"),
+            recognizer_accept_waveform_f: symbol!("vosk_recognizer_accept_waveform_f"),
+            recognizer_final_result: symbol!("vosk_recognizer_final_result"),
+            recognizer_free: symbol!("vosk_recognizer_free"),
+            _lib: lib,
+        })
+    }
+}
+
+/// Vosk transcriber backed by a shared libvosk model
+pub struct VoskTranscriber {
+    api: VoskApi,
+    /// `VoskModel*`; immutable after load and shared by recognizers
+    model: *mut c_void,
+    /// Confidence of the most recent transcription (mean word confidence)
+    last_confidence: Mutex<Option<f32>>,
+}
+
+// SAFETY: a VoskModel is read-only after vosk_model_new and documented as
+// safe to share between threads; each transcription creates its own
+// recognizer, which never leaves the calling thread.
+unsafe impl Send for VoskTranscriber {}
+unsafe impl Sync for VoskTranscriber {}
+
+impl VoskTranscriber {
+    pub fn new(config: &VoskConfig) -> Result<Self, TranscribeError> {
+        let model_dir = resolve_model_path(&config.model)?;
+        let library = config
+            .library
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_LIBRARY));
+
+        tracing::info!("Loading Vosk model from {:?}", model_dir);
+        let start = std::time::Instant::now();
+
+        let api = VoskApi::load(&library)?;
+        let c_path = CString::new(model_dir.to_string_lossy().as_bytes()).map_err(|_| {
+            TranscribeError::InitFailed(format!("Invalid Vosk model path: {:?}", model_dir))
+        })?;
+        // SAFETY: c_path is a valid NUL-terminated string for the call
+        let model = unsafe { (api.model_new)(c_path.as_ptr()) };
+        if model.is_null() {
+            return Err(TranscribeError::InitFailed(format!(
+                "libvosk could not load the model in {:?}. \
+                 Is it an unpacked Vosk model directory?",
+                model_dir
+            )));
+        }
+
+        tracing::info!("Vosk model loaded in {:.2}s", start.elapsed().as_secs_f32());
+
+        Ok(Self {
+            api,
+            model,
+            last_confidence: Mutex::new(None),
+        })
+    }
+
+    /// Run a fresh recognizer over `samples` and parse its final result
+    fn recognize(&self, samples: &[f32]) -> Result<VoskResult, TranscribeError> {
+        // SAFETY: self.model stays valid until drop
+        let recognizer = unsafe { (self.api.recognizer_new)(self.model, SAMPLE_RATE) };
+        if recognizer.is_null() {
+            return Err(TranscribeError::InferenceFailed(
+                "vosk_recognizer_new failed".to_string(),
+            ));
+        }
+
+        // accept_waveform_f expects float samples in 16-bit PCM range
+        let scaled: Vec<f32> = samples.iter().map(|s| s * 32768.0).collect();
+        // SAFETY: recognizer is non-null and owned by this call; the
+        // result pointer is valid until the recognizer is freed, so it is
+        // copied out before vosk_recognizer_free.
+        let json = unsafe {
+            (self.api.recognizer_set_words)(recognizer, 1);
+            for chunk in scaled.chunks(SAMPLE_RATE as usize) {
+                (self.api.recognizer_accept_waveform_f)(
+                    recognizer,
+                    chunk.as_ptr(),
+                    chunk.len() as c_int,
+                );
+            }
+            let raw = (self.api.recognizer_final_result)(recognizer);
+            let json = if raw.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(raw).to_string_lossy().into_owned()
+            };
+            (self.api.recognizer_free)(recognizer);
+            json
+        };
+
+        let result = parse_result(&json)?;
+        *self.last_confidence.lock().unwrap() = result.confidence();
+        Ok(result)
+    }
+}
+
+impl Drop for VoskTranscriber {
+    fn drop(&mut self) {
+        // SAFETY: model came from vosk_model_new and no recognizer outlives
+        // a recognize() call
+        unsafe { (self.api.model_free)(self.model) };
+    }
+}
+
+impl Transcriber for VoskTranscriber {
+    fn transcribe(&self, samples: &[f32]) -> Result<String, TranscribeError> {
+        if samples.is_empty() {
+            return Err(TranscribeError::AudioFormat(
+                "Empty audio buffer".to_string(),
+            ));
+        }
+
+        let duration_secs = samples.len() as f32 / SAMPLE_RATE;
+        tracing::debug!(
+            "Transcribing {:.2}s of audio ({} samples) with Vosk",
+            duration_secs,
+            samples.len()
+        );
+        let start = std::time::Instant::now();
+        let result = self.recognize(samples)?;
+        tracing::info!(
+            "Vosk transcription completed in {:.2}s: {:?}",
+            start.elapsed().as_secs_f32(),
+            if result.text.chars().count() > 50 {
+                format!("{}...", result.text.chars().take(50).collect::<String>())
+            } else {
+                result.text.clone()
+            }
+        );
+        Ok(result.text)
+    }
+
+    fn transcribe_words(&self, samples: &[f32]) -> Result<Vec<TimedWord>, TranscribeError> {
+        if samples.is_empty() {
+            return Err(TranscribeError::AudioFormat(
+                "Empty audio buffer".to_string(),
+            ));
+        }
+        Ok(self.recognize(samples)?.words())
+    }
+
+    fn last_confidence(&self) -> Option<f32> {
+        *self.last_confidence.lock().unwrap()
+    }
+}
+
+/// Final result JSON from `vosk_recognizer_final_result` with words enabled
+#[derive(Debug, serde::Deserialize)]
+struct VoskResult {
+    #[serde(default)]
+    text: String,
+    #[serde(default)]
+    result: Vec<VoskWord>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct VoskWord {
+    word: String,
+    start: f64,
+    end: f64,
+    conf: f32,
+}
+
+impl VoskResult {
+    fn confidence(&self) -> Option<f32> {
+        if self.result.is_empty() {
+            return None;
+        }
+        Some(self.result.iter().map(|w| w.conf).sum::<f32>() / self.result.len() as f32)
+    }
+
+    fn words(&self) -> Vec<TimedWord> {
+        self.result
+            .iter()
+            .map(|w| TimedWord {
+                text: w.word.clone(),
+                start_ms: (w.start * 1000.0).round() as u64,
+                end_ms: (w.end * 1000.0).round() as u64,
+                confidence: Some(w.conf),
+            })
+            .collect()
+    }
+}
+
+fn parse_result(json: &str) -> Result<VoskResult, TranscribeError> {
+    if json.trim().is_empty() {
+        return Ok(VoskResult {
+            text: String::new(),
+            result: Vec::new(),
+        });
+    }
+    serde_json::from_str(json).map_err(|e| {
+        TranscribeError::InferenceFailed(format!("Unexpected Vosk result {:?}: {}", json, e))
+    })
+}
+
+/// Resolve model name to directory path
+fn resolve_model_path(model: &str) -> Result<PathBuf, TranscribeError> {
+    let path = PathBuf::from(model);
+    if path.is_absolute() && path.exists() {
+        return Ok(path);
+    }
+
+    let models_dir = crate::config::Config::models_dir();
+    let model_dir_name = if model.starts_with("vosk-model-") {
+        model.to_string()
+    } else {
+        format!("vosk-model-{}", model)
+    };
+    for candidate in [models_dir.join(&model_dir_name), models_dir.join(model)] {
+        if candidate.is_dir() {
+            return Ok(candidate);
+        }
+    }
+
+    Err(TranscribeError::ModelNotFound(format!(
+        "Vosk model '{}' not found. Looked in:\n  \
+         - {}\n  \
+         - {}\n\n  \
+         Download a model from https://alphacephei.com/vosk/models and unpack it\n  \
+         into {}",
+        model,
+        models_dir.join(&model_dir_name).display(),
+        models_dir.join(model).display(),
+        models_dir.display()
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_final_result_with_words() {
+        let json = r#"{
+            "result" : [{
+                "conf" : 1.000000,
+                "end" : 0.720000,
+                "start" : 0.300000,
+                "word" : "hello"
+            }, {
+                "conf" : 0.500000,
+                "end" : 1.200000,
+                "start" : 0.720000,
+                "word" : "world"
+            }],
+            "text" : "hello world"
+        }"#;
+        let result = parse_result(json).unwrap();
+        assert_eq!(result.text, "hello world");
+        assert_eq!(result.confidence(), Some(0.75));
+        let words = result.words();
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].start_ms, 300);
+        assert_eq!(words[1].end_ms, 1200);
+        assert_eq!(words[1].confidence, Some(0.5));
+    }
+
+    #[test]
+    fn parses_silence_as_empty() {
+        let result = parse_result(r#"{"text" : ""}"#).unwrap();
+        assert!(result.text.is_empty());
+        assert_eq!(result.confidence(), None);
+        assert!(parse_result("").unwrap().words().is_empty());
+    }
+
+    #[test]
+    fn test_resolve_model_path_not_found() {
+        let result = resolve_model_path("/nonexistent/path/to/model");
+        assert!(result.is_err());
+    }
+}
@@ -7,12 +7,17 @@
 //! - Auto-detect: Let Whisper detect from all ~99 supported languages
 //! - Constrained auto-detect: Detect from a user-specified subset of languages
 
-use super::Transcriber;
-use crate::config::{Config, LanguageConfig, WhisperConfig};
+use super::{TimedSegment, TimedWord, Transcriber};
+use crate::config::{Config, LanguageConfig, WhisperConfig, WhisperDecodingConfig};
 use crate::error::TranscribeError;
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
-use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters};
+use std::time::{Duration, Instant};
+use whisper_rs::{
+    FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters, WhisperState,
+};
 
 /// Whisper-based transcriber
 pub struct WhisperTranscriber {
@@ -20,8 +25,8 @@ pub struct WhisperTranscriber {
     ctx: WhisperContext,
     /// Language configuration (single, auto, or array)
     language: LanguageConfig,
-    /// Whether to translate to English
-    translate: bool,
+    /// Whether to translate to English (switchable at runtime)
+    translate: AtomicBool,
     /// Number of threads to use
     threads: usize,
     /// Whether to optimize context window for short clips
@@ -29,20 +34,38 @@ pub struct WhisperTranscriber {
     /// Initial prompt to provide context for transcription
     initial_prompt: Option<String>,
     /// Two-letter code for the language used during the most recent
-    /// `transcribe()` call. Populated for single-language and constrained
-    /// auto-detection modes; left empty for unconstrained auto-detection
-    /// (since whisper-rs does not currently expose the chosen language
-    /// from the full() pipeline). Read via [`Transcriber::last_detected_language`].
+    /// `transcribe()` call: the configured language, the one picked from
+    /// the allowed set, or the one Whisper detected inside `full()` for
+    /// unconstrained auto-detection. Read via [`Transcriber::last_detected_language`].
     last_language: Mutex<Option<String>>,
+    /// Confidence of the most recent `transcribe()` call, see
+    /// [`utterance_confidence`]
+    last_confidence: Mutex<Option<f32>>,
+    /// Hallucinated phrases dropped from the output
+    suppress_phrases: Vec<String>,
+    /// Discard output whose no-speech probability reaches this value
+    no_speech_threshold: Option<f32>,
+    /// Sampling strategy and fallback thresholds
+    decoding: WhisperDecodingConfig,
+    /// Decoding state kept between calls (`keep_context_warm`). None when
+    /// every call allocates its own.
+    warm_state: Option<Mutex<Option<WhisperState>>>,
+}
+
+/// How the decoding state for one call was obtained, for the timing
+/// breakdown in the log
+struct StateSetup {
+    elapsed: Duration,
+    reused: bool,
 }
 
 impl WhisperTranscriber {
     /// Create a new whisper transcriber
     pub fn new(config: &WhisperConfig) -> Result<Self, TranscribeError> {
-        let model_path = resolve_model_path(&config.model)?;
+        let model_path = resolve_model_path(&config.model, &config.custom_models)?;
 
         tracing::info!("Loading whisper model from {:?}", model_path);
-        let start = std::time::Instant::now();
+        let start = Instant::now();
 
         let mut ctx_params = WhisperContextParameters::default();
         if let Some(device) = config.gpu_device {
@@ -53,13 +76,36 @@ impl WhisperTranscriber {
         if config.flash_attention {
             tracing::info!("Flash attention enabled");
         }
+        #[allow(unused_mut)]
+        let mut use_mmap = super::model_file::mmap_models();
+        #[cfg(feature = "gpu-metal")]
+        {
+            let coreml = coreml_encoder_path(&model_path);
+            if coreml.exists() {
+                tracing::info!("Using CoreML encoder {:?}", coreml);
+                // whisper.cpp finds the CoreML encoder next to the model
+                // path, which a memory-mapped buffer does not have.
+                use_mmap = false;
+            } else {
+                tracing::info!(
+                    "No CoreML encoder at {:?}, running the encoder on Metal",
+                    coreml
+                );
+            }
+        }
 
-        let ctx = WhisperContext::new_with_params(
-            model_path
-                .to_str()
-                .ok_or_else(|| TranscribeError::ModelNotFound("Invalid path".to_string()))?,
-            ctx_params,
-        )
+        let ctx = if use_mmap {
+            let mapped = super::model_file::map(&model_path)?;
+            tracing::debug!("Memory-mapped {:?} ({} bytes)", model_path, mapped.len());
+            WhisperContext::new_from_buffer_with_params(&mapped, ctx_params)
+        } else {
+            WhisperContext::new_with_params(
+                model_path
+                    .to_str()
+                    .ok_or_else(|| TranscribeError::ModelNotFound("Invalid path".to_string()))?,
+                ctx_params,
+            )
+        }
         .map_err(|e| TranscribeError::InitFailed(e.to_string()))?;
 
         tracing::info!("Model loaded in {:.2}s", start.elapsed().as_secs_f32());
@@ -69,14 +115,55 @@ impl WhisperTranscriber {
         Ok(Self {
             ctx,
             language: config.language.clone(),
-            translate: config.translate,
+            translate: AtomicBool::new(config.translate),
             threads,
             context_window_optimization: config.context_window_optimization,
             initial_prompt: config.initial_prompt.clone(),
             last_language: Mutex::new(None),
+            last_confidence: Mutex::new(None),
+            suppress_phrases: config.suppress_phrases.clone(),
+            no_speech_threshold: config.no_speech_threshold,
+            decoding: config.decoding.clone(),
+            warm_state: config.keep_context_warm.then(|| Mutex::new(None)),
         })
     }
 
+    /// A decoding state for one call: the warm one if it is being kept,
+    /// otherwise a freshly allocated one
+    fn acquire_state(&self) -> Result<(WhisperState, StateSetup), TranscribeError> {
+        let start = Instant::now();
+        let warm = self
+            .warm_state
+            .as_ref()
+            .and_then(|slot| slot.lock().ok()?.take());
+        let reused = warm.is_some();
+        let state = match warm {
+            Some(state) => state,
+            None => self
+                .ctx
+                .create_state()
+                .map_err(|e| TranscribeError::InferenceFailed(e.to_string()))?,
+        };
+        Ok((
+            state,
+            StateSetup {
+                elapsed: start.elapsed(),
+                reused,
+            },
+        ))
+    }
+
+    /// Hand a state back after a call. Kept for the next call with
+    /// `keep_context_warm`, freed otherwise.
+    fn release_state(&self, state: WhisperState) {
+        if let Some(Ok(mut slot)) = self.warm_state.as_ref().map(|slot| slot.lock()) {
+            // A concurrent call (eager chunks) may already have put one back
+            if slot.is_none() {
+                *slot = Some(state);
+            }
+        }
+    }
+
     /// Select the best language from allowed languages using Whisper's language detection.
     ///
     /// This runs the mel spectrogram computation and language detection head to get
@@ -84,7 +171,7 @@ impl WhisperTranscriber {
     /// from the user's allowed set.
     fn select_language_from_allowed(
         &self,
-        state: &mut whisper_rs::WhisperState,
+        state: &mut WhisperState,
         samples: &[f32],
         allowed: &[String],
     ) -> Result<String, TranscribeError> {
@@ -136,10 +223,16 @@ impl WhisperTranscriber {
 
         Ok(selected)
     }
-}
 
-impl Transcriber for WhisperTranscriber {
-    fn transcribe(&self, samples: &[f32]) -> Result<String, TranscribeError> {
+    /// Run the full whisper pipeline over `samples` and return the state
+    /// holding the decoded segments. `token_timestamps` enables per-token
+    /// timing, which costs a little extra work and is only needed for
+    /// word-level output. Pass the state to `release_state` when done.
+    fn run_full(
+        &self,
+        samples: &[f32],
+        token_timestamps: bool,
+    ) -> Result<(WhisperState, StateSetup), TranscribeError> {
         if samples.is_empty() {
             return Err(TranscribeError::AudioFormat(
                 "Empty audio buffer".to_string(),
@@ -153,13 +246,8 @@ impl Transcriber for WhisperTranscriber {
             samples.len()
         );
 
-        let start = std::time::Instant::now();
-
-        // Create state for this transcription
-        let mut state = self
-            .ctx
-            .create_state()
-            .map_err(|e| TranscribeError::InferenceFailed(e.to_string()))?;
+        // Get the decoding state for this transcription
+        let (mut state, setup) = self.acquire_state()?;
 
         // Determine language based on configuration mode
         let selected_language: Option<String> = if self.language.is_auto() {
@@ -181,14 +269,35 @@ impl Transcriber for WhisperTranscriber {
         // Record the language for output methods that benefit from a layout
         // hint (e.g. eitype --layout, dotool DOTOOL_XKB_LAYOUT). See
         // `Transcriber::last_detected_language`. Unconstrained auto-detect
-        // leaves this as `None`; whisper-rs does not expose the language
-        // chosen inside `full()` so we cannot recover it after the fact.
+        // is filled in after `full()` has picked a language.
         if let Ok(mut guard) = self.last_language.lock() {
             *guard = selected_language.clone();
         }
 
         // Configure parameters
-        let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+        let decoding = &self.decoding;
+        let strategy = match decoding.beam_size {
+            Some(beam_size) => SamplingStrategy::BeamSearch {
+                beam_size: beam_size.max(1) as i32,
+                patience: -1.0,
+            },
+            None => SamplingStrategy::Greedy {
+                best_of: decoding.best_of.unwrap_or(1).max(1) as i32,
+            },
+        };
+        let mut params = FullParams::new(strategy);
+        if let Some(temperature) = decoding.temperature {
+            params.set_temperature(temperature);
+        }
+        if let Some(increment) = decoding.temperature_increment {
+            params.set_temperature_inc(increment);
+        }
+        if let Some(threshold) = decoding.entropy_threshold {
+            params.set_entropy_thold(threshold);
+        }
+        if let Some(threshold) = decoding.logprob_threshold {
+            params.set_logprob_thold(threshold);
+        }
 
         // Set language
         match &selected_language {
@@ -196,7 +305,7 @@ impl Transcriber for WhisperTranscriber {
             None => params.set_language(None),
         }
 
-        params.set_translate(self.translate);
+        params.set_translate(self.translate.load(Ordering::Relaxed));
         params.set_n_threads(self.threads as i32);
 
         // Disable output we don't need
@@ -240,26 +349,114 @@ impl Transcriber for WhisperTranscriber {
             }
         }
 
+        params.set_token_timestamps(token_timestamps);
+
         // Run inference
         state
             .full(params, samples)
             .map_err(|e| TranscribeError::InferenceFailed(e.to_string()))?;
 
+        if selected_language.is_none() {
+            let detected = whisper_rs::get_lang_str(state.full_lang_id_from_state());
+            tracing::debug!("Whisper auto-detected language: {:?}", detected);
+            if let Ok(mut guard) = self.last_language.lock() {
+                *guard = detected.map(str::to_string);
+            }
+        }
+
+        Ok((state, setup))
+    }
+
+    /// Group the tokens of every segment into words. Special and timestamp
+    /// tokens (ids at or above end-of-text) are dropped.
+    fn collect_words(&self, state: &WhisperState) -> Vec<TimedWord> {
+        let eot = self.ctx.token_eot();
+        let mut tokens = Vec::new();
+        for segment in state.as_iter() {
+            for i in 0..segment.n_tokens() {
+                let Some(token) = segment.get_token(i) else {
+                    continue;
+                };
+                if token.token_id() >= eot {
+                    continue;
+                }
+                let Ok(bytes) = token.to_bytes() else {
+                    continue;
+                };
+                let data = token.token_data();
+                tokens.push(WordToken {
+                    bytes: bytes.to_vec(),
+                    t0_cs: data.t0,
+                    t1_cs: data.t1,
+                    p: data.p,
+                });
+            }
+        }
+        merge_tokens(&tokens)
+    }
+}
+
+impl Transcriber for WhisperTranscriber {
+    fn transcribe(&self, samples: &[f32]) -> Result<String, TranscribeError> {
+        let start = Instant::now();
+        let (state, setup) = self.run_full(samples, false)?;
+
         // Collect all segments using iterator API
+        let eot = self.ctx.token_eot();
+        let mut token_probs = Vec::new();
+        let mut no_speech_prob = 0.0f32;
         let mut text = String::new();
         for segment in state.as_iter() {
-            text.push_str(
-                segment
-                    .to_str()
-                    .map_err(|e| TranscribeError::InferenceFailed(e.to_string()))?,
-            );
+            no_speech_prob = no_speech_prob.max(segment.no_speech_probability());
+            for i in 0..segment.n_tokens() {
+                if let Some(token) = segment.get_token(i) {
+                    if token.token_id() < eot {
+                        token_probs.push(token.token_probability());
+                    }
+                }
+            }
+            let segment_text = segment
+                .to_str()
+                .map_err(|e| TranscribeError::InferenceFailed(e.to_string()))?;
+            let kept = strip_suppressed_phrases(segment_text, &self.suppress_phrases);
+            if !kept.is_empty() {
+                text.push(' ');
+                text.push_str(&kept);
+            }
         }
 
-        let result = text.trim().to_string();
+        let mut result = text.trim().to_string();
+        if let Some(threshold) = self.no_speech_threshold {
+            if no_speech_prob >= threshold && !result.is_empty() {
+                tracing::info!(
+                    "Discarding transcription: no-speech probability {:.2} >= {:.2}",
+                    no_speech_prob,
+                    threshold
+                );
+                result.clear();
+            }
+        }
 
+        let confidence = utterance_confidence(&token_probs, no_speech_prob);
+        if let Some(c) = confidence {
+            tracing::debug!(
+                "Transcription confidence: {:.2} (no_speech_prob={:.2})",
+                c,
+                no_speech_prob
+            );
+        }
+        if let Ok(mut guard) = self.last_confidence.lock() {
+            *guard = confidence;
+        }
+        self.release_state(state);
+
+        let total = start.elapsed();
         tracing::info!(
-            "Transcription completed in {:.2}s: {:?}",
-            start.elapsed().as_secs_f32(),
+            "Transcription completed in {:.2}s (state {} in {:.3}s, inference {:.2}s): {:?}",
+            total.as_secs_f32(),
+            if setup.reused { "reused" } else { "allocated" },
+            setup.elapsed.as_secs_f32(),
+            total.saturating_sub(setup.elapsed).as_secs_f32(),
             if result.chars().count() > 50 {
                 format!("{}...", result.chars().take(50).collect::<String>())
             } else {
@@ -270,73 +467,238 @@ impl Transcriber for WhisperTranscriber {
         Ok(result)
     }
 
+    fn transcribe_timed(&self, samples: &[f32]) -> Result<Vec<TimedSegment>, TranscribeError> {
+        let (state, _) = self.run_full(samples, false)?;
+        let mut segments = Vec::new();
+        for segment in state.as_iter() {
+            let text = strip_suppressed_phrases(
+                &segment
+                    .to_str_lossy()
+                    .map_err(|e| TranscribeError::InferenceFailed(e.to_string()))?,
+                &self.suppress_phrases,
+            );
+            if text.is_empty() {
+                continue;
+            }
+            // Segment timestamps are in centiseconds
+            segments.push(TimedSegment {
+                text,
+                start_secs: segment.start_timestamp() as f32 / 100.0,
+                end_secs: segment.end_timestamp() as f32 / 100.0,
+            });
+        }
+        self.release_state(state);
+        Ok(segments)
+    }
+
+    fn transcribe_words(&self, samples: &[f32]) -> Result<Vec<TimedWord>, TranscribeError> {
+        let (state, _) = self.run_full(samples, true)?;
+        let words = self.collect_words(&state);
+        self.release_state(state);
+        Ok(words)
+    }
+
+    fn last_confidence(&self) -> Option<f32> {
+        self.last_confidence.lock().ok().and_then(|g| *g)
+    }
+
     fn last_detected_language(&self) -> Option<String> {
         self.last_language.lock().ok().and_then(|g| g.clone())
     }
+
+    fn set_translate(&self, translate: bool) -> bool {
+        self.translate.store(translate, Ordering::Relaxed);
+        true
+    }
+}
+
+/// Remove sentences that match one of `phrases`, ignoring case,
+/// punctuation and spacing, and trim the result. Whisper tends to append
+/// stock phrases ("Thanks for watching!") to real speech, so matching is
+/// done per sentence rather than on the whole text.
+pub(crate) fn strip_suppressed_phrases(text: &str, phrases: &[String]) -> String {
+    fn normalize(s: &str) -> String {
+        s.split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    let phrases: Vec<String> = phrases
+        .iter()
+        .map(|p| normalize(p))
+        .filter(|p| !p.is_empty())
+        .collect();
+    if phrases.is_empty() {
+        return text.trim().to_string();
+    }
+
+    let mut kept = String::new();
+    for sentence in text.split_inclusive(['.', '!', '?', '。', '！', '？']) {
+        let normalized = normalize(sentence);
+        if !normalized.is_empty() && phrases.contains(&normalized) {
+            tracing::debug!("Suppressed hallucinated phrase: {:?}", sentence.trim());
+            continue;
+        }
+        kept.push_str(sentence);
+    }
+    kept.trim().to_string()
 }
 
-/// Resolve model name to file path
-fn resolve_model_path(model: &str) -> Result<PathBuf, TranscribeError> {
+/// Utterance confidence: the geometric mean of the text token probabilities
+/// (i.e. exp of the average log-probability), scaled down by the highest
+/// segment no-speech probability so hallucinations over silence score low.
+fn utterance_confidence(token_probs: &[f32], no_speech_prob: f32) -> Option<f32> {
+    if token_probs.is_empty() {
+        return None;
+    }
+    let avg_logprob = token_probs
+        .iter()
+        .map(|p| p.max(f32::MIN_POSITIVE).ln())
+        .sum::<f32>()
+        / token_probs.len() as f32;
+    Some(avg_logprob.exp() * (1.0 - no_speech_prob.clamp(0.0, 1.0)))
+}
+
+/// A decoded whisper token with its timing (centiseconds) and probability
+struct WordToken {
+    bytes: Vec<u8>,
+    t0_cs: i64,
+    t1_cs: i64,
+    p: f32,
+}
+
+/// Merge BPE tokens into words. A token starting with a space begins a new
+/// word; anything else (word pieces, trailing punctuation) extends the
+/// current one. Bytes are joined before decoding because multi-byte
+/// characters can be split across tokens. Word confidence is the mean
+/// token probability.
+fn merge_tokens(tokens: &[WordToken]) -> Vec<TimedWord> {
+    fn flush(words: &mut Vec<TimedWord>, bytes: &[u8], t0: i64, t1: i64, probs: &[f32]) {
+        let text = String::from_utf8_lossy(bytes).trim().to_string();
+        if text.is_empty() || probs.is_empty() {
+            return;
+        }
+        words.push(TimedWord {
+            text,
+            start_ms: t0.max(0) as u64 * 10,
+            end_ms: t1.max(t0).max(0) as u64 * 10,
+            confidence: Some(probs.iter().sum::<f32>() / probs.len() as f32),
+        });
+    }
+
+    let mut words = Vec::new();
+    let mut bytes: Vec<u8> = Vec::new();
+    let mut probs: Vec<f32> = Vec::new();
+    let (mut t0, mut t1) = (0i64, 0i64);
+
+    for token in tokens {
+        if token.bytes.first() == Some(&b' ') && !bytes.is_empty() {
+            flush(&mut words, &bytes, t0, t1, &probs);
+            bytes.clear();
+            probs.clear();
+        }
+        if bytes.is_empty() {
+            t0 = token.t0_cs;
+        }
+        bytes.extend_from_slice(&token.bytes);
+        probs.push(token.p);
+        t1 = token.t1_cs;
+    }
+    flush(&mut words, &bytes, t0, t1, &probs);
+    words
+}
+
+/// Resolve model name to file path.
+///
+/// Accepts built-in names, names registered under `[whisper] custom_models`,
+/// `.bin` filenames and absolute paths.
+pub(crate) fn resolve_model_path(
+    model: &str,
+    custom_models: &HashMap<String, String>,
+) -> Result<PathBuf, TranscribeError> {
     // If it's already an absolute path, use it directly
     let path = PathBuf::from(model);
     if path.is_absolute() && path.exists() {
         return Ok(path);
     }
 
-    // Map model names to file names
-    let model_filename = match model {
-        "tiny" => "ggml-tiny.bin",
-        "tiny.en" => "ggml-tiny.en.bin",
-        "base" => "ggml-base.bin",
-        "base.en" => "ggml-base.en.bin",
-        "small" => "ggml-small.bin",
-        "small.en" => "ggml-small.en.bin",
-        "medium" => "ggml-medium.bin",
-        "medium.en" => "ggml-medium.en.bin",
-        "large" | "large-v1" => "ggml-large-v1.bin",
-        "large-v2" => "ggml-large-v2.bin",
-        "large-v3" => "ggml-large-v3.bin",
-        "large-v3-turbo" => "ggml-large-v3-turbo.bin",
-        // If it looks like a filename, use it as-is
-        other if other.ends_with(".bin") => other,
-        // Otherwise, assume it's a model name and add prefix/suffix
-        other => {
+    // Custom models may point straight at a local file
+    if let Some(source) = custom_models.get(model) {
+        if !is_url(source) {
+            let path = PathBuf::from(source);
+            if path.exists() {
+                return Ok(path);
+            }
             return Err(TranscribeError::ModelNotFound(format!(
-                "Unknown model: '{}'. Valid models: tiny, base, small, medium, large-v3, large-v3-turbo",
-                other
+                "Custom model '{}' points to {}, which does not exist",
+                model, source
             )));
         }
-    };
+    }
+
+    // Map model names to file names
+    if known_model(model).is_none()
+        && !custom_models.contains_key(model)
+        && !model.ends_with(".bin")
+    {
+        return Err(TranscribeError::ModelNotFound(format!(
+            "Unknown model: '{}'. Valid models: tiny, base, small, medium, large-v3, \
+             large-v3-turbo, distil-large-v3 (see `voxtype setup model`), or register \
+             it under [whisper.custom_models]",
+            model
+        )));
+    }
+    let model_filename = get_model_filename(model);
 
     // Look in the data directory
     let models_dir = Config::models_dir();
-    let model_path = models_dir.join(model_filename);
+    let model_path = models_dir.join(&model_filename);
 
     if model_path.exists() {
         return Ok(model_path);
     }
 
     // Also check current directory
-    let cwd_path = PathBuf::from(model_filename);
+    let cwd_path = PathBuf::from(&model_filename);
     if cwd_path.exists() {
         return Ok(cwd_path);
     }
 
     // Also check ./models/
-    let local_models_path = PathBuf::from("models").join(model_filename);
+    let local_models_path = PathBuf::from("models").join(&model_filename);
     if local_models_path.exists() {
         return Ok(local_models_path);
     }
 
     Err(TranscribeError::ModelNotFound(format!(
-        "Model '{}' not found. Looked in:\n  - {}\n  - {}\n  - {}\n\nDownload from: https://huggingface.co/ggerganov/whisper.cpp/tree/main",
+        "Model '{}' not found. Looked in:\n  - {}\n  - {}\n  - {}\n\nDownload with: voxtype setup --download --model {}",
         model,
         model_path.display(),
         cwd_path.display(),
-        local_models_path.display()
+        local_models_path.display(),
+        model
     )))
 }
 
+/// Path where whisper.cpp looks for the CoreML encoder of a ggml model:
+/// `ggml-base.en.bin` -> `ggml-base.en-encoder.mlmodelc`. A quantization
+/// suffix such as `-q5_0` is dropped, since the encoder is shared.
+///
+/// Used by `gpu-metal` builds, where a present encoder runs on the Apple
+/// Neural Engine and a missing one falls back to Metal.
+pub fn coreml_encoder_path(model_path: &Path) -> PathBuf {
+    let mut stem = model_path.with_extension("").to_string_lossy().into_owned();
+    if let Some(pos) = stem.rfind('-') {
+        let suffix = &stem.as_bytes()[pos..];
+        if suffix.len() == 5 && suffix[1] == b'q' && suffix[3] == b'_' {
+            stem.truncate(pos);
+        }
+    }
+    PathBuf::from(format!("{stem}-encoder.mlmodelc"))
+}
+
 /// Calculate audio_ctx parameter for short clips (≤22.5s).
 /// Formula: max(duration_seconds * 50 + 128, 384), rounded up to multiple of 8
 ///
@@ -362,38 +724,175 @@ fn calculate_audio_ctx(duration_secs: f32) -> Option<i32> {
     }
 }
 
-/// Get the filename for a model
+/// Repository hosting the official whisper.cpp ggml models
+const WHISPER_CPP_REPO: &str = "https://huggingface.co/ggerganov/whisper.cpp/resolve/main";
+
+/// Built-in models as (name, filename, download URL). An empty URL means the
+/// file is hosted in [`WHISPER_CPP_REPO`] under the same filename.
+const KNOWN_MODELS: &[(&str, &str, &str)] = &[
+    ("tiny", "ggml-tiny.bin", ""),
+    ("tiny.en", "ggml-tiny.en.bin", ""),
+    ("base", "ggml-base.bin", ""),
+    ("base.en", "ggml-base.en.bin", ""),
+    ("small", "ggml-small.bin", ""),
+    ("small.en", "ggml-small.en.bin", ""),
+    ("medium", "ggml-medium.bin", ""),
+    ("medium.en", "ggml-medium.en.bin", ""),
+    ("large", "ggml-large-v1.bin", ""),
+    ("large-v1", "ggml-large-v1.bin", ""),
+    ("large-v2", "ggml-large-v2.bin", ""),
+    ("large-v3", "ggml-large-v3.bin", ""),
+    ("large-v3-turbo", "ggml-large-v3-turbo.bin", ""),
+    // Quantized variants of the official models
+    ("large-v3-q5_0", "ggml-large-v3-q5_0.bin", ""),
+    ("large-v3-turbo-q5_0", "ggml-large-v3-turbo-q5_0.bin", ""),
+    ("large-v3-turbo-q8_0", "ggml-large-v3-turbo-q8_0.bin", ""),
+    // Distil-Whisper (English-only, hosted by the distil-whisper project)
+    (
+        "distil-large-v3",
+        "ggml-distil-large-v3.bin",
+        "https://huggingface.co/distil-whisper/distil-large-v3-ggml/resolve/main/ggml-distil-large-v3.bin",
+    ),
+    (
+        "distil-large-v3.5",
+        "ggml-distil-large-v3.5.bin",
+        "https://huggingface.co/distil-whisper/distil-large-v3.5-ggml/resolve/main/ggml-model.bin",
+    ),
+];
+
+fn known_model(model: &str) -> Option<&'static (&'static str, &'static str, &'static str)> {
+    KNOWN_MODELS.iter().find(|(name, _, _)| *name == model)
+}
+
+fn is_url(source: &str) -> bool {
+    source.starts_with("https://") || source.starts_with("http://")
+}
+
+/// Get the filename for a model.
+///
+/// Names without a built-in entry (such as `[whisper] custom_models` keys)
+/// map to `ggml-<name>.bin`; `.bin` filenames are returned unchanged.
 pub fn get_model_filename(model: &str) -> String {
-    match model {
-        "tiny" => "ggml-tiny.bin",
-        "tiny.en" => "ggml-tiny.en.bin",
-        "base" => "ggml-base.bin",
-        "base.en" => "ggml-base.en.bin",
-        "small" => "ggml-small.bin",
-        "small.en" => "ggml-small.en.bin",
-        "medium" => "ggml-medium.bin",
-        "medium.en" => "ggml-medium.en.bin",
-        "large-v3" => "ggml-large-v3.bin",
-        "large-v3-turbo" => "ggml-large-v3-turbo.bin",
-        other => other,
-    }
-    .to_string()
+    match known_model(model) {
+        Some((_, filename, _)) => filename.to_string(),
+        None if model.ends_with(".bin") => model.to_string(),
+        None => format!("ggml-{}.bin", model),
+    }
 }
 
 /// Get the download URL for a model
 pub fn get_model_url(model: &str) -> String {
-    let filename = get_model_filename(model);
+    match known_model(model) {
+        Some((_, _, url)) if !url.is_empty() => url.to_string(),
+        _ => format!("{}/{}", WHISPER_CPP_REPO, get_model_filename(model)),
+    }
+}
 
-    format!(
-        "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/{}",
-        filename
-    )
+/// Download URL of a model registered under `[whisper] custom_models`, or
+/// None if it isn't registered or points to a local file
+pub fn custom_model_url<'a>(
+    custom_models: &'a HashMap<String, String>,
+    model: &str,
+) -> Option<&'a str> {
+    custom_models
+        .get(model)
+        .map(String::as_str)
+        .filter(|source| is_url(source))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn token(text: &str, t0_cs: i64, t1_cs: i64, p: f32) -> WordToken {
+        WordToken {
+            bytes: text.as_bytes().to_vec(),
+            t0_cs,
+            t1_cs,
+            p,
+        }
+    }
+
+    #[test]
+    fn test_merge_tokens_joins_word_pieces_and_punctuation() {
+        let words = merge_tokens(&[
+            token(" Hel", 0, 20, 0.9),
+            token("lo", 20, 35, 0.7),
+            token(",", 35, 36, 1.0),
+            token(" world", 40, 80, 0.5),
+        ]);
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].text, "Hello,");
+        assert_eq!((words[0].start_ms, words[0].end_ms), (0, 360));
+        assert!((words[0].confidence.unwrap() - 0.8667).abs() < 0.001);
+        assert_eq!(words[1].text, "world");
+        assert_eq!((words[1].start_ms, words[1].end_ms), (400, 800));
+    }
+
+    #[test]
+    fn test_merge_tokens_reassembles_split_utf8() {
+        let bytes = " é".as_bytes();
+        let words = merge_tokens(&[
+            WordToken {
+                bytes: bytes[..2].to_vec(),
+                t0_cs: 0,
+                t1_cs: 10,
+                p: 1.0,
+            },
+            WordToken {
+                bytes: bytes[2..].to_vec(),
+                t0_cs: 10,
+                t1_cs: 20,
+                p: 1.0,
+            },
+        ]);
+        assert_eq!(words.len(), 1);
+        assert_eq!(words[0].text, "é");
+    }
+
+    #[test]
+    fn test_utterance_confidence() {
+        assert_eq!(utterance_confidence(&[], 0.0), None);
+        let c = utterance_confidence(&[0.5, 0.5], 0.0).unwrap();
+        assert!((c - 0.5).abs() < 1e-6);
+        let c = utterance_confidence(&[0.9, 0.1], 0.0).unwrap();
+        assert!((c - 0.3).abs() < 1e-6);
+        let c = utterance_confidence(&[1.0], 0.8).unwrap();
+        assert!((c - 0.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_strip_suppressed_phrases() {
+        let phrases = vec!["Thanks for watching!".to_string(), "Subscribe".to_string()];
+        assert_eq!(
+            strip_suppressed_phrases(" Hello there. Thanks for watching!", &phrases),
+            "Hello there."
+        );
+        assert_eq!(
+            strip_suppressed_phrases(" thanks for watching", &phrases),
+            ""
+        );
+        assert_eq!(strip_suppressed_phrases("SUBSCRIBE!", &phrases), "");
+        // Phrases only match whole sentences
+        assert_eq!(
+            strip_suppressed_phrases("Please subscribe to the list.", &phrases),
+            "Please subscribe to the list."
+        );
+        assert_eq!(strip_suppressed_phrases(" Hi. ", &[]), "Hi.");
+    }
+
+    #[test]
+    fn test_coreml_encoder_path() {
+        assert_eq!(
+            coreml_encoder_path(Path::new("/m/ggml-base.en.bin")),
+            PathBuf::from("/m/ggml-base.en-encoder.mlmodelc")
+        );
+        assert_eq!(
+            coreml_encoder_path(Path::new("/m/ggml-large-v3-turbo-q5_0.bin")),
+            PathBuf::from("/m/ggml-large-v3-turbo-encoder.mlmodelc")
+        );
+    }
+
     #[test]
     fn test_model_url() {
         let url = get_model_url("base.en");
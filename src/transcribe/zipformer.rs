@@ -0,0 +1,829 @@
+//! Zipformer-based streaming speech-to-text transcription
+//!
+//! Runs sherpa-onnx streaming Zipformer transducer exports (icefall
+//! `zipformer2` recipes) via ONNX Runtime. Unlike the batch ONNX engines,
+//! the encoder carries cached attention/convolution state between chunks,
+//! so audio is decoded as it arrives rather than after hotkey release.
+//!
+//! Pipeline: Audio (f32, 16kHz) -> Fbank (80-dim) -> chunked encoder (with
+//! cached state) -> greedy transducer search (decoder + joiner) -> tokens
+//!
+//! The encoder's chunk geometry comes from its ONNX metadata: `T` is the
+//! number of feature frames fed per call (chunk plus right-context padding)
+//! and `decode_chunk_len` is how far the window advances. Every encoder
+//! input after the feature tensor is a cached state whose updated value is
+//! returned at the same output position.
+//!
+//! Greedy search emits at most one token per encoder frame, so text only
+//! ever grows: each chunk yields an append-only delta the daemon can type
+//! directly. `[transcribe.hotwords]` boosts are added to the joiner logits
+//! before each argmax (see [`super::hotwords`]).
+//!
+//! Model files: encoder.onnx, decoder.onnx, joiner.onnx (int8 variants
+//! preferred, sherpa-onnx `encoder-epoch-*.onnx` names also accepted),
+//! tokens.txt
+
+use super::ctc;
+use super::fbank::FbankExtractor;
+use super::hotwords::{HotwordBias, HotwordState};
+use super::streaming::{StreamHandle, StreamingEvent, StreamingTranscriber};
+use super::Transcriber;
+use crate::config::ZipformerConfig;
+use crate::error::TranscribeError;
+use ort::session::{Session, SessionInputValue};
+use ort::value::{DynValue, Tensor, TensorElementType, ValueType};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{mpsc, oneshot};
+
+/// Sample rate expected by Zipformer
+const SAMPLE_RATE: usize = 16000;
+
+/// Fbank frame length in samples (25ms at 16kHz, matches FbankConfig default)
+const FRAME_LENGTH: usize = 400;
+
+/// Fbank frame shift in samples (10ms at 16kHz, matches FbankConfig default)
+const FRAME_SHIFT: usize = 160;
+
+/// Mel channels the encoder expects
+const NUM_MELS: usize = 80;
+
+/// Silence appended at end of input so the last words clear the encoder's
+/// right context (same 0.66s sherpa-onnx uses)
+const TAIL_PADDING_SAMPLES: usize = SAMPLE_RATE * 66 / 100;
+
+/// Transducer blank token
+const BLANK_ID: i64 = 0;
+
+/// FbankExtractor works on int16-scaled samples (Kaldi convention), while
+/// icefall models are trained on features from [-1, 1] samples. Power
+/// scales by 32768^2, so the log-mel features differ by this constant.
+const INT16_LOG_POWER_OFFSET: f32 = 20.794_415; // ln(32768^2)
+
+/// Log-energy floor applied after the offset (ln of f32::EPSILON, as
+/// kaldi-native-fbank does)
+const LOG_FLOOR: f32 = -15.942_385;
+
+/// Zipformer transcriber using ONNX Runtime
+pub struct ZipformerTranscriber {
+    model: Arc<ZipformerModel>,
+    streaming: bool,
+}
+
+/// Loaded sessions and model geometry, shared with streaming tasks
+struct ZipformerModel {
+    encoder: Mutex<Session>,
+    decoder: Mutex<Session>,
+    joiner: Mutex<Session>,
+    tokens: HashMap<u32, String>,
+    /// Hotword boosts, `None` when no hotwords are configured
+    hotwords: Option<HotwordBias>,
+    fbank_extractor: FbankExtractor,
+    /// Feature frames per encoder call (metadata `T`)
+    chunk_frames: usize,
+    /// Feature frames the window advances per call (metadata `decode_chunk_len`)
+    chunk_shift: usize,
+    /// Decoder context size (metadata `context_size`)
+    context_size: usize,
+    /// Encoder feature input name
+    feature_input: String,
+    /// Cached-state inputs, paired with the output that returns their update
+    states: Vec<StateSpec>,
+}
+
+/// One cached encoder state tensor
+struct StateSpec {
+    input: String,
+    output: String,
+    ty: TensorElementType,
+    shape: Vec<i64>,
+}
+
+/// Per-utterance decoding state
+struct DecodeStream {
+    /// Samples not yet turned into feature frames
+    samples: Vec<f32>,
+    /// Feature frames (flattened, NUM_MELS wide) not yet consumed
+    features: Vec<f32>,
+    /// Current encoder cache, in `ZipformerModel::states` order
+    states: Vec<DynValue>,
+    /// Emitted token IDs, prefixed with `context_size` context tokens
+    hyp: Vec<i64>,
+    /// Decoder output for the current context
+    decoder_out: Vec<f32>,
+    /// Hotword matches in progress
+    hotword_state: HotwordState,
+}
+
+impl ZipformerTranscriber {
+    pub fn new(
+        config: &ZipformerConfig,
+        hotwords: &HashMap<String, f32>,
+    ) -> Result<Self, TranscribeError> {
+        let model_dir = resolve_model_path(&config.model)?;
+
+        tracing::info!("Loading Zipformer model from {:?}", model_dir);
+        let start = std::time::Instant::now();
+
+        let threads = config.threads.unwrap_or_else(|| num_cpus::get().min(4));
+
+        let encoder_file = find_model_file(&model_dir, "encoder")?;
+        let decoder_file = find_model_file(&model_dir, "decoder")?;
+        let joiner_file = find_model_file(&model_dir, "joiner")?;
+
+        let tokens_path = model_dir.join("tokens.txt");
+        if !tokens_path.exists() {
+            return Err(TranscribeError::ModelNotFound(format!(
+                "Zipformer tokens.txt not found: {}\n  \
+                 Ensure tokens.txt is in the model directory.",
+                tokens_path.display()
+            )));
+        }
+        let tokens = ctc::load_tokens(&tokens_path)?;
+        tracing::debug!("Loaded {} tokens", tokens.len());
+
+        let hotwords = Some(HotwordBias::new(hotwords, &tokens)).filter(|h| !h.is_empty());
+        if hotwords.is_some() {
+            tracing::info!("Hotword biasing enabled");
+        }
+
+        // Only the encoder gets GPU EPs: the decoder and joiner run once per
+        // frame on [1, C] tensors, where host/device copies cost more than
+        // the compute.
+        let encoder = build_session(&encoder_file, threads, "encoder", true, config.cuda_device)?;
+        let decoder = build_session(&decoder_file, 1, "decoder", false, None)?;
+        let joiner = build_session(&joiner_file, 1, "joiner", false, None)?;
+
+        let metadata = encoder.metadata().map_err(|e| {
+            TranscribeError::InitFailed(format!("Failed to read encoder metadata: {}", e))
+        })?;
+        let chunk_frames = metadata_usize(&metadata, "T")?;
+        let chunk_shift = metadata_usize(&metadata, "decode_chunk_len")?;
+        drop(metadata);
+
+        let context_size = decoder
+            .metadata()
+            .ok()
+            .and_then(|m| m.custom("context_size"))
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap_or(2);
+
+        let inputs = encoder.inputs();
+        let outputs = encoder.outputs();
+        if inputs.len() != outputs.len() {
+            return Err(TranscribeError::InitFailed(format!(
+                "Zipformer encoder has {} inputs but {} outputs; expected a \
+                 streaming export whose outputs mirror its cached-state inputs",
+                inputs.len(),
+                outputs.len()
+            )));
+        }
+        let feature_input = inputs[0].name().to_string();
+        let states = inputs
+            .iter()
+            .zip(outputs.iter())
+            .skip(1)
+            .map(|(input, output)| match input.dtype() {
+                ValueType::Tensor { ty, shape, .. } => Ok(StateSpec {
+                    input: input.name().to_string(),
+                    output: output.name().to_string(),
+                    ty: *ty,
+                    // Dynamic (batch) dimensions become 1
+                    shape: shape.iter().map(|&d| d.max(1)).collect(),
+                }),
+                other => Err(TranscribeError::InitFailed(format!(
+                    "Zipformer encoder input '{}' is not a tensor: {:?}",
+                    input.name(),
+                    other
+                ))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        tracing::info!(
+            "Zipformer model loaded in {:.2}s (T={}, decode_chunk_len={}, {} cached states)",
+            start.elapsed().as_secs_f32(),
+            chunk_frames,
+            chunk_shift,
+            states.len(),
+        );
+
+        Ok(Self {
+            model: Arc::new(ZipformerModel {
+                encoder: Mutex::new(encoder),
+                decoder: Mutex::new(decoder),
+                joiner: Mutex::new(joiner),
+                tokens,
+                hotwords,
+                fbank_extractor: FbankExtractor::new_default(),
+                chunk_frames,
+                chunk_shift,
+                context_size,
+                feature_input,
+                states,
+            }),
+            streaming: config.streaming,
+        })
+    }
+}
+
+impl Transcriber for ZipformerTranscriber {
+    fn transcribe(&self, samples: &[f32]) -> Result<String, TranscribeError> {
+        if samples.is_empty() {
+            return Err(TranscribeError::AudioFormat(
+                "Empty audio buffer".to_string(),
+            ));
+        }
+
+        let duration_secs = samples.len() as f32 / SAMPLE_RATE as f32;
+        tracing::debug!(
+            "Transcribing {:.2}s of audio ({} samples) with Zipformer",
+            duration_secs,
+            samples.len(),
+        );
+
+        let start = std::time::Instant::now();
+
+        let mut stream = self.model.new_stream()?;
+        self.model.accept(&mut stream, samples)?;
+        self.model.finish(&mut stream)?;
+        let result = self.model.text(&stream);
+
+        tracing::info!(
+            "Zipformer transcription completed in {:.2}s: {:?}",
+            start.elapsed().as_secs_f32(),
+            if result.chars().count() > 50 {
+                format!("{}...", result.chars().take(50).collect::<String>())
+            } else {
+                result.clone()
+            }
+        );
+
+        Ok(result)
+    }
+
+    fn as_streaming(&self) -> Option<&dyn StreamingTranscriber> {
+        if self.streaming {
+            Some(self)
+        } else {
+            None
+        }
+    }
+}
+
+impl StreamingTranscriber for ZipformerTranscriber {
+    fn start_stream(
+        &self,
+        mut samples_rx: mpsc::Receiver<Vec<f32>>,
+    ) -> Result<StreamHandle, TranscribeError> {
+        let model = Arc::clone(&self.model);
+        let mut stream = model.new_stream()?;
+
+        let (events_tx, events_rx) = mpsc::channel::<StreamingEvent>(64);
+        let (cancel_tx, mut cancel_rx) = oneshot::channel::<()>();
+
+        let task = tokio::task::spawn_blocking(move || -> Result<(), TranscribeError> {
+            let runtime = tokio::runtime::Handle::current();
+            let segment_id: u64 = 0;
+            let mut emitted = String::new();
+
+            loop {
+                match cancel_rx.try_recv() {
+                    Ok(()) => {
+                        tracing::debug!("Zipformer streaming session cancelled");
+                        break;
+                    }
+                    Err(oneshot::error::TryRecvError::Closed) => break,
+                    Err(oneshot::error::TryRecvError::Empty) => {}
+                }
+
+                let chunk = match runtime.block_on(samples_rx.recv()) {
+                    Some(c) => c,
+                    None => break, // graceful EOF
+                };
+
+                if chunk.is_empty() {
+                    continue;
+                }
+
+                if let Err(e) = model.accept(&mut stream, &chunk) {
+                    let _ = runtime.block_on(events_tx.send(StreamingEvent::Error(e)));
+                    let _ = runtime.block_on(events_tx.send(StreamingEvent::Ended));
+                    return Ok(());
+                }
+
+                if let Some(delta) = take_delta(&mut emitted, &model.text(&stream)) {
+                    let _ = runtime.block_on(events_tx.send(StreamingEvent::Partial {
+                        text: delta,
+                        segment_id,
+                    }));
+                }
+            }
+
+            // Push the tail padding through so the last words are decoded.
+            if let Err(e) = model.finish(&mut stream) {
+                tracing::warn!("Zipformer final chunk failed: {}", e);
+            }
+            if let Some(delta) = take_delta(&mut emitted, &model.text(&stream)) {
+                let _ = runtime.block_on(events_tx.send(StreamingEvent::Final {
+                    text: delta,
+                    segment_id,
+                }));
+            }
+            let _ = runtime.block_on(events_tx.send(StreamingEvent::Ended));
+            Ok(())
+        });
+
+        let task = tokio::spawn(async move {
+            match task.await {
+                Ok(r) => r,
+                Err(join_err) => Err(TranscribeError::InferenceFailed(format!(
+                    "Zipformer streaming task panicked: {}",
+                    join_err
+                ))),
+            }
+        });
+
+        Ok(StreamHandle {
+            events: events_rx,
+            cancel: cancel_tx,
+            task,
+        })
+    }
+}
+
+impl ZipformerModel {
+    /// Fresh decoding state: zeroed encoder cache and blank decoder context
+    fn new_stream(&self) -> Result<DecodeStream, TranscribeError> {
+        let states = self
+            .states
+            .iter()
+            .map(zero_state)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // sherpa-onnx seeds the context with -1 padding and a final blank
+        let mut hyp = vec![-1; self.context_size];
+        if let Some(last) = hyp.last_mut() {
+            *last = BLANK_ID;
+        }
+        let decoder_out = self.run_decoder(&hyp)?;
+
+        Ok(DecodeStream {
+            samples: Vec::new(),
+            features: Vec::new(),
+            states,
+            hyp,
+            decoder_out,
+            hotword_state: HotwordState::new(),
+        })
+    }
+
+    /// Feed audio and decode every complete encoder chunk
+    fn accept(&self, stream: &mut DecodeStream, samples: &[f32]) -> Result<(), TranscribeError> {
+        stream.samples.extend_from_slice(samples);
+
+        if stream.samples.len() >= FRAME_LENGTH {
+            let num_frames = (stream.samples.len() - FRAME_LENGTH) / FRAME_SHIFT + 1;
+            let used = (num_frames - 1) * FRAME_SHIFT + FRAME_LENGTH;
+            // Each call pre-emphasizes its first sample against zero rather
+            // than the previous sample; one sample per chunk is inaudible to
+            // the model.
+            let fbank = self.fbank_extractor.extract(&stream.samples[..used]);
+            stream.features.extend(
+                fbank
+                    .iter()
+                    .map(|&v| (v - INT16_LOG_POWER_OFFSET).max(LOG_FLOOR)),
+            );
+            stream.samples.drain(..num_frames * FRAME_SHIFT);
+        }
+
+        while stream.features.len() >= self.chunk_frames * NUM_MELS {
+            let x = stream.features[..self.chunk_frames * NUM_MELS].to_vec();
+            self.run_chunk(stream, x)?;
+            stream.features.drain(..self.chunk_shift * NUM_MELS);
+        }
+
+        Ok(())
+    }
+
+    /// Flush the end of the utterance. Frames short of a full chunk after
+    /// the tail padding are dropped, as in sherpa-onnx.
+    fn finish(&self, stream: &mut DecodeStream) -> Result<(), TranscribeError> {
+        self.accept(stream, &vec![0.0; TAIL_PADDING_SAMPLES])
+    }
+
+    /// Text decoded so far
+    fn text(&self, stream: &DecodeStream) -> String {
+        tokens_to_text(&stream.hyp[self.context_size..], &self.tokens)
+    }
+
+    /// Run one encoder chunk and greedy-search its output frames
+    fn run_chunk(&self, stream: &mut DecodeStream, x: Vec<f32>) -> Result<(), TranscribeError> {
+        let x_tensor = Tensor::<f32>::from_array(([1usize, self.chunk_frames, NUM_MELS], x))
+            .map_err(|e| {
+                TranscribeError::InferenceFailed(format!("Failed to create feature tensor: {}", e))
+            })?;
+
+        let mut inputs: Vec<(Cow<str>, SessionInputValue)> =
+            Vec::with_capacity(self.states.len() + 1);
+        inputs.push((Cow::Borrowed(self.feature_input.as_str()), x_tensor.into()));
+        for (spec, value) in self.states.iter().zip(stream.states.iter()) {
+            inputs.push((
+                Cow::Borrowed(spec.input.as_str()),
+                SessionInputValue::from(value),
+            ));
+        }
+
+        let (encoder_out, dim, new_states) = {
+            let mut encoder = self.encoder.lock().map_err(|e| {
+                TranscribeError::InferenceFailed(format!("Failed to lock encoder: {}", e))
+            })?;
+            let output_name = encoder.outputs()[0].name().to_string();
+            let mut outputs = encoder.run(inputs).map_err(|e| {
+                TranscribeError::InferenceFailed(format!("Zipformer encoder failed: {}", e))
+            })?;
+
+            let (shape, data) = outputs[output_name.as_str()]
+                .try_extract_tensor::<f32>()
+                .map_err(|e| {
+                    TranscribeError::InferenceFailed(format!(
+                        "Failed to extract encoder output: {}",
+                        e
+                    ))
+                })?;
+            let dim = shape.last().copied().unwrap_or(0) as usize;
+            let encoder_out = data.to_vec();
+
+            let new_states = self
+                .states
+                .iter()
+                .map(|spec| {
+                    outputs.remove(&spec.output).ok_or_else(|| {
+                        TranscribeError::InferenceFailed(format!(
+                            "Zipformer encoder output '{}' missing",
+                            spec.output
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            (encoder_out, dim, new_states)
+        };
+        stream.states = new_states;
+
+        if dim == 0 {
+            return Err(TranscribeError::InferenceFailed(
+                "Zipformer encoder returned an empty output".to_string(),
+            ));
+        }
+
+        for frame in encoder_out.chunks_exact(dim) {
+            let mut logits = self.run_joiner(frame, &stream.decoder_out)?;
+            if let Some(hotwords) = &self.hotwords {
+                hotwords.apply(&stream.hotword_state, &mut logits);
+            }
+            let best = argmax(&logits);
+            if best != BLANK_ID {
+                if let Some(hotwords) = &self.hotwords {
+                    hotwords.advance(&mut stream.hotword_state, best);
+                }
+                stream.hyp.push(best);
+                let context = &stream.hyp[stream.hyp.len() - self.context_size..];
+                stream.decoder_out = self.run_decoder(context)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn run_decoder(&self, context: &[i64]) -> Result<Vec<f32>, TranscribeError> {
+        let y = Tensor::<i64>::from_array(([1usize, context.len()], context.to_vec())).map_err(
+            |e| TranscribeError::InferenceFailed(format!("Failed to create decoder input: {}", e)),
+        )?;
+
+        let mut decoder = self.decoder.lock().map_err(|e| {
+            TranscribeError::InferenceFailed(format!("Failed to lock decoder: {}", e))
+        })?;
+        let input_name = decoder.inputs()[0].name().to_string();
+        let outputs = decoder
+            .run(vec![(Cow::Owned(input_name), SessionInputValue::from(y))])
+            .map_err(|e| {
+                TranscribeError::InferenceFailed(format!("Zipformer decoder failed: {}", e))
+            })?;
+        let (_, data) = outputs[0].try_extract_tensor::<f32>().map_err(|e| {
+            TranscribeError::InferenceFailed(format!("Failed to extract decoder output: {}", e))
+        })?;
+        Ok(data.to_vec())
+    }
+
+    fn run_joiner(
+        &self,
+        encoder_frame: &[f32],
+        decoder_out: &[f32],
+    ) -> Result<Vec<f32>, TranscribeError> {
+        let enc =
+            Tensor::<f32>::from_array(([1usize, encoder_frame.len()], encoder_frame.to_vec()))
+                .map_err(|e| {
+                    TranscribeError::InferenceFailed(format!(
+                        "Failed to create joiner input: {}",
+                        e
+                    ))
+                })?;
+        let dec = Tensor::<f32>::from_array(([1usize, decoder_out.len()], decoder_out.to_vec()))
+            .map_err(|e| {
+                TranscribeError::InferenceFailed(format!("Failed to create joiner input: {}", e))
+            })?;
+
+        let mut joiner = self.joiner.lock().map_err(|e| {
+            TranscribeError::InferenceFailed(format!("Failed to lock joiner: {}", e))
+        })?;
+        let enc_name = joiner.inputs()[0].name().to_string();
+        let dec_name = joiner.inputs()[1].name().to_string();
+        let outputs = joiner
+            .run(vec![
+                (Cow::Owned(enc_name), SessionInputValue::from(enc)),
+                (Cow::Owned(dec_name), SessionInputValue::from(dec)),
+            ])
+            .map_err(|e| {
+                TranscribeError::InferenceFailed(format!("Zipformer joiner failed: {}", e))
+            })?;
+        let (_, data) = outputs[0].try_extract_tensor::<f32>().map_err(|e| {
+            TranscribeError::InferenceFailed(format!("Failed to extract joiner output: {}", e))
+        })?;
+        Ok(data.to_vec())
+    }
+}
+
+fn build_session(
+    path: &Path,
+    threads: usize,
+    label: &str,
+    use_gpu: bool,
+    cuda_device: Option<u32>,
+) -> Result<Session, TranscribeError> {
+    let builder = Session::builder()
+        .map_err(|e| TranscribeError::InitFailed(format!("ONNX session builder failed: {}", e)))?
+        .with_intra_threads(threads)
+        .map_err(|e| TranscribeError::InitFailed(format!("Failed to set threads: {}", e)))?;
+
+    let builder = if use_gpu {
+        super::onnx_ep::register_gpu_eps(builder, "Zipformer", label, cuda_device)
+            .map_err(|e| TranscribeError::InitFailed(format!("{label} EPs: {e}")))?
+    } else {
+        builder
+    };
+
+    super::model_file::commit_session(builder, path).map_err(|e| {
+        TranscribeError::InitFailed(format!(
+            "Failed to load Zipformer {label} from {:?}: {e}",
+            path
+        ))
+    })
+}
+
+/// Required integer from the encoder's custom metadata
+fn metadata_usize(
+    metadata: &ort::session::ModelMetadata,
+    key: &str,
+) -> Result<usize, TranscribeError> {
+    metadata
+        .custom(key)
+        .and_then(|v| v.trim().parse().ok())
+        .filter(|&v: &usize| v > 0)
+        .ok_or_else(|| {
+            TranscribeError::InitFailed(format!(
+                "Zipformer encoder metadata is missing '{}'. \
+                 Only sherpa-onnx streaming Zipformer exports are supported.",
+                key
+            ))
+        })
+}
+
+/// Zero-filled initial value for a cached encoder state
+fn zero_state(spec: &StateSpec) -> Result<DynValue, TranscribeError> {
+    let len = spec.shape.iter().product::<i64>() as usize;
+    let value = match spec.ty {
+        TensorElementType::Float32 => {
+            Tensor::<f32>::from_array((spec.shape.clone(), vec![0.0f32; len])).map(|t| t.into_dyn())
+        }
+        TensorElementType::Int64 => {
+            Tensor::<i64>::from_array((spec.shape.clone(), vec![0i64; len])).map(|t| t.into_dyn())
+        }
+        other => {
+            return Err(TranscribeError::InitFailed(format!(
+                "Zipformer encoder state '{}' has unsupported type {:?}",
+                spec.input, other
+            )))
+        }
+    };
+    value.map_err(|e| {
+        TranscribeError::InferenceFailed(format!("Failed to create state '{}': {}", spec.input, e))
+    })
+}
+
+fn argmax(logits: &[f32]) -> i64 {
+    logits
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(idx, _)| idx as i64)
+        .unwrap_or(BLANK_ID)
+}
+
+/// Advance `emitted` to `text` and return the newly appended tail, if any.
+/// Greedy transducer output only grows, so `text` extends `emitted`.
+fn take_delta(emitted: &mut String, text: &str) -> Option<String> {
+    let delta = text.strip_prefix(emitted.as_str())?;
+    if delta.is_empty() {
+        return None;
+    }
+    let delta = delta.to_string();
+    emitted.push_str(&delta);
+    Some(delta)
+}
+
+/// Convert token IDs to text
+///
+/// Zipformer vocabularies are SentencePiece BPE: `▁` marks a word start.
+/// CJK models emit one character per token with no marker. Special tokens
+/// (<blk>, <sos/eos>, <unk>) are filtered out.
+fn tokens_to_text(token_ids: &[i64], tokens: &HashMap<u32, String>) -> String {
+    let mut result = String::new();
+
+    for &id in token_ids {
+        if let Some(token_str) = tokens.get(&(id as u32)) {
+            if token_str.starts_with('<') && token_str.ends_with('>') {
+                continue;
+            }
+            result.push_str(&token_str.replace('\u{2581}', " "));
+        }
+    }
+
+    result.trim().to_string()
+}
+
+/// Find `<stem>.int8.onnx` / `<stem>.onnx`, falling back to sherpa-onnx
+/// release names like `encoder-epoch-99-avg-1-chunk-16-left-128.int8.onnx`
+fn find_model_file(dir: &Path, stem: &str) -> Result<PathBuf, TranscribeError> {
+    for name in [format!("{stem}.int8.onnx"), format!("{stem}.onnx")] {
+        let path = dir.join(name);
+        if path.exists() {
+            return Ok(path);
+        }
+    }
+
+    let mut candidates: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok().map(|e| e.path()))
+                .filter(|p| {
+                    p.file_name()
+                        .and_then(|n| n.to_str())
+                        .is_some_and(|n| n.starts_with(&format!("{stem}-")) && n.ends_with(".onnx"))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    // int8 first, then alphabetical for a stable pick
+    candidates.sort_by_key(|p| {
+        let name = p
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("")
+            .to_string();
+        (!name.ends_with(".int8.onnx"), name)
+    });
+
+    candidates.into_iter().next().ok_or_else(|| {
+        TranscribeError::ModelNotFound(format!(
+            "Zipformer {stem} not found in {:?}\n  \
+             Expected {stem}.int8.onnx or {stem}.onnx\n  \
+             Run: voxtype setup model",
+            dir
+        ))
+    })
+}
+
+fn resolve_model_path(model: &str) -> Result<PathBuf, TranscribeError> {
+    let path = PathBuf::from(model);
+    if path.is_absolute() && path.exists() {
+        return Ok(path);
+    }
+
+    // Map short names to directory names
+    let model_dir_name = if model.starts_with("zipformer-") {
+        model.to_string()
+    } else {
+        format!("zipformer-{}", model)
+    };
+
+    let models_dir = crate::config::Config::models_dir();
+    let model_path = models_dir.join(&model_dir_name);
+
+    if model_path.exists() {
+        return Ok(model_path);
+    }
+
+    // Check without prefix (e.g. an unpacked sherpa-onnx release archive)
+    let alt_path = models_dir.join(model);
+    if alt_path.exists() {
+        return Ok(alt_path);
+    }
+
+    Err(TranscribeError::ModelNotFound(format!(
+        "Zipformer model '{}' not found. Looked in:\n  \
+         - {}\n  \
+         - {}\n\n\
+         Run: voxtype setup model",
+        model,
+        model_path.display(),
+        alt_path.display(),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_resolve_model_path_not_found() {
+        let result = resolve_model_path("/nonexistent/path/to/model");
+        assert!(matches!(
+            result.unwrap_err(),
+            TranscribeError::ModelNotFound(_)
+        ));
+    }
+
+    #[test]
+    fn test_find_model_file_prefers_plain_int8() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        fs::write(dir.join("encoder.onnx"), b"dummy").unwrap();
+        fs::write(dir.join("encoder.int8.onnx"), b"dummy").unwrap();
+
+        let found = find_model_file(dir, "encoder").unwrap();
+        assert_eq!(found, dir.join("encoder.int8.onnx"));
+    }
+
+    #[test]
+    fn test_find_model_file_accepts_sherpa_names() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        fs::write(
+            dir.join("joiner-epoch-99-avg-1-chunk-16-left-128.onnx"),
+            b"dummy",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("joiner-epoch-99-avg-1-chunk-16-left-128.int8.onnx"),
+            b"dummy",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("decoder-epoch-99-avg-1-chunk-16-left-128.onnx"),
+            b"dummy",
+        )
+        .unwrap();
+
+        let found = find_model_file(dir, "joiner").unwrap();
+        assert_eq!(
+            found,
+            dir.join("joiner-epoch-99-avg-1-chunk-16-left-128.int8.onnx")
+        );
+        assert!(find_model_file(dir, "encoder").is_err());
+    }
+
+    #[test]
+    fn test_tokens_to_text_sentencepiece() {
+        let mut tokens = HashMap::new();
+        tokens.insert(0, "<blk>".to_string());
+        tokens.insert(1, "<sos/eos>".to_string());
+        tokens.insert(10, "\u{2581}HELLO".to_string());
+        tokens.insert(11, "\u{2581}WOR".to_string());
+        tokens.insert(12, "LD".to_string());
+
+        let result = tokens_to_text(&[0, 10, 11, 12, 1], &tokens);
+        assert_eq!(result, "HELLO WORLD");
+    }
+
+    #[test]
+    fn test_take_delta_returns_appended_tail() {
+        let mut emitted = String::new();
+        assert_eq!(take_delta(&mut emitted, "").as_deref(), None);
+        assert_eq!(take_delta(&mut emitted, "hello").as_deref(), Some("hello"));
+        assert_eq!(take_delta(&mut emitted, "hello").as_deref(), None);
+        assert_eq!(
+            take_delta(&mut emitted, "hello world").as_deref(),
+            Some(" world")
+        );
+        assert_eq!(emitted, "hello world");
+    }
+
+    #[test]
+    fn test_log_constants() {
+        assert!((INT16_LOG_POWER_OFFSET - (32768f32 * 32768.0).ln()).abs() < 1e-4);
+        assert!((LOG_FLOOR - f32::EPSILON.ln()).abs() < 1e-4);
+    }
+}
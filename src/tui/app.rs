@@ -515,6 +515,17 @@ fn detect_missing_model() -> Option<MissingModel> {
         config::TranscriptionEngine::Cohere => return None,
         // Soniox is cloud-only, no local model to probe.
         config::TranscriptionEngine::Soniox => return None,
+        // Vosk models are downloaded by hand; the transcriber reports a
+        // missing model directory with a download hint at startup.
+        config::TranscriptionEngine::Vosk => return None,
+        config::TranscriptionEngine::Zipformer => (
+            "zipformer",
+            cfg.zipformer
+                .as_ref()
+                .map(|c| c.model.clone())
+                .unwrap_or_default(),
+            "voxtype setup model",
+        ),
     };
 
     if model.is_empty() {
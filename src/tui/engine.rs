@@ -542,7 +542,11 @@ impl EngineState {
         ed.set_bool("whisper", "gpu_isolation", f.w_gpu_isolation);
         match &f.w_remote_endpoint {
             Some(v) if !v.is_empty() => ed.set_string("whisper", "remote_endpoint", v),
-            _ => ed.unset("whisper", "remote_endpoint"),
+            // A failover list is not editable here; leave it alone
+            _ if ed.get_string("whisper", "remote_endpoint").is_some() => {
+                ed.unset("whisper", "remote_endpoint")
+            }
+            _ => {}
         }
         match &f.w_remote_api_key {
             Some(v) if !v.is_empty() => ed.set_string("whisper", "remote_api_key", v),
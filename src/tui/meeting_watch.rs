@@ -0,0 +1,781 @@
+//! Live transcript view for `voxtype meeting watch`.
+//!
+//! Reads the daemon's meeting events (see `crate::meeting::live`) on a
+//! background thread and renders the rolling transcript with speaker labels,
+//! the elapsed meeting time and what the chunk processor is doing. The view
+//! follows new segments until the user scrolls up; `End` resumes following.
+//! With rolling summaries on, the latest summary is shown above the
+//! transcript (`s` hides it).
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers, MouseEventKind};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use std::path::Path;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use crate::meeting::data::{
+    format_offset, AudioSource, Bookmark, MeetingSummary, TranscriptSegment,
+};
+use crate::meeting::live::{LiveClient, LiveEvent};
+
+/// Colors assigned to speakers in order of first appearance
+const SPEAKER_COLORS: &[Color] = &[
+    Color::Cyan,
+    Color::Magenta,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Red,
+];
+
+/// Where the meeting stands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    /// Connected, but no meeting has started since the daemon did
+    Waiting,
+    Recording,
+    Paused,
+    Stopped,
+}
+
+/// What the chunk processor last reported
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ChunkStatus {
+    Idle,
+    Transcribing {
+        source: AudioSource,
+        audio_ms: u64,
+    },
+    Done {
+        source: AudioSource,
+        segments: usize,
+    },
+}
+
+/// Everything the view shows, built from the event stream
+#[derive(Debug)]
+struct WatchState {
+    phase: Phase,
+    meeting_id: Option<String>,
+    title: Option<String>,
+    started_at: Option<DateTime<Utc>>,
+    /// Time spent paused before the current pause (or now)
+    paused_total: chrono::Duration,
+    paused_since: Option<DateTime<Utc>>,
+    stopped_at: Option<DateTime<Utc>>,
+    chunks: u32,
+    chunk: ChunkStatus,
+    segments: Vec<TranscriptSegment>,
+    bookmarks: Vec<Bookmark>,
+    speakers: Vec<String>,
+    last_error: Option<String>,
+    /// Latest rolling summary and the meeting time it covers
+    summary: Option<(MeetingSummary, u64)>,
+    show_summary: bool,
+    disconnected: bool,
+    /// Lines scrolled up from the bottom; `None` follows new segments
+    scroll_back: Option<usize>,
+}
+
+impl WatchState {
+    fn new() -> Self {
+        Self {
+            phase: Phase::Waiting,
+            meeting_id: None,
+            title: None,
+            started_at: None,
+            paused_total: chrono::Duration::zero(),
+            paused_since: None,
+            stopped_at: None,
+            chunks: 0,
+            chunk: ChunkStatus::Idle,
+            segments: Vec::new(),
+            bookmarks: Vec::new(),
+            speakers: Vec::new(),
+            last_error: None,
+            summary: None,
+            show_summary: true,
+            disconnected: false,
+            scroll_back: None,
+        }
+    }
+
+    fn apply(&mut self, event: LiveEvent) {
+        match event {
+            LiveEvent::Started {
+                meeting_id,
+                title,
+                started_at,
+            } => {
+                *self = Self {
+                    phase: Phase::Recording,
+                    meeting_id: Some(meeting_id),
+                    title,
+                    started_at: Some(started_at),
+                    show_summary: self.show_summary,
+                    ..Self::new()
+                };
+            }
+            LiveEvent::ChunkStarted { source, audio_ms } => {
+                self.chunk = ChunkStatus::Transcribing { source, audio_ms };
+            }
+            LiveEvent::ChunkProcessed { source, segments } => {
+                self.chunks += 1;
+                self.chunk = ChunkStatus::Done {
+                    source,
+                    segments: segments.len(),
+                };
+                for segment in segments.into_iter().filter(|s| !s.text.trim().is_empty()) {
+                    let speaker = segment.speaker_display();
+                    if !self.speakers.contains(&speaker) {
+                        self.speakers.push(speaker);
+                    }
+                    self.segments.push(segment);
+                }
+                // Sources are transcribed one after the other; keep the
+                // transcript in meeting time order
+                self.segments.sort_by_key(|s| s.start_ms);
+            }
+            LiveEvent::Summary {
+                summary,
+                covered_ms,
+            } => {
+                self.summary = Some((summary, covered_ms));
+            }
+            LiveEvent::Marked { bookmark } => {
+                let index = self
+                    .bookmarks
+                    .partition_point(|b| b.offset_ms <= bookmark.offset_ms);
+                self.bookmarks.insert(index, bookmark);
+            }
+            LiveEvent::Paused { at } => {
+                self.phase = Phase::Paused;
+                self.paused_since = Some(at);
+            }
+            LiveEvent::Resumed { at } => {
+                self.phase = Phase::Recording;
+                if let Some(since) = self.paused_since.take() {
+                    self.paused_total += at - since;
+                }
+            }
+            LiveEvent::Stopped { at } => {
+                self.phase = Phase::Stopped;
+                self.stopped_at = Some(at);
+                self.chunk = ChunkStatus::Idle;
+            }
+            LiveEvent::Error { message } => {
+                self.last_error = Some(message);
+            }
+        }
+    }
+
+    /// Recording time so far, not counting pauses
+    fn elapsed(&self, now: DateTime<Utc>) -> chrono::Duration {
+        let Some(started_at) = self.started_at else {
+            return chrono::Duration::zero();
+        };
+        let end = self.paused_since.or(self.stopped_at).unwrap_or(now);
+        (end - started_at - self.paused_total).max(chrono::Duration::zero())
+    }
+
+    fn speaker_color(&self, speaker: &str) -> Color {
+        let index = self.speakers.iter().position(|s| s == speaker).unwrap_or(0);
+        SPEAKER_COLORS[index % SPEAKER_COLORS.len()]
+    }
+
+    /// Transcript lines wrapped to `width` columns, with bookmarks before
+    /// the first segment starting after them
+    fn transcript_lines(&self, width: usize) -> Vec<Line<'static>> {
+        let mut lines = Vec::new();
+        let mut bookmarks = self.bookmarks.iter().peekable();
+        let bookmark_lines = |lines: &mut Vec<Line<'static>>, bookmark: &Bookmark| {
+            let text = format!("[{}] ★ {}", bookmark.format_timestamp(), bookmark.label());
+            let style = Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD);
+            for text in wrap(&text, width.max(1)) {
+                lines.push(Line::styled(text, style));
+            }
+        };
+        for segment in &self.segments {
+            while let Some(bookmark) = bookmarks.next_if(|b| b.offset_ms <= segment.start_ms) {
+                bookmark_lines(&mut lines, bookmark);
+            }
+            let speaker = segment.speaker_display();
+            let prefix = format!("[{}] {}: ", segment.format_timestamp(), speaker);
+            let indent = prefix.chars().count().min(width / 2);
+            let wrapped = wrap(&segment.text, width.saturating_sub(indent).max(1));
+            for (i, text) in wrapped.into_iter().enumerate() {
+                let lead = if i == 0 {
+                    Span::styled(
+                        prefix.clone(),
+                        Style::default()
+                            .fg(self.speaker_color(&speaker))
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    Span::raw(" ".repeat(indent))
+                };
+                lines.push(Line::from(vec![lead, Span::raw(text)]));
+            }
+            if let Some(ref translation) = segment.translation {
+                let dim = Style::default().fg(Color::DarkGray);
+                for text in wrap(translation, width.saturating_sub(indent).max(1)) {
+                    lines.push(Line::from(vec![
+                        Span::raw(" ".repeat(indent)),
+                        Span::styled(text, dim),
+                    ]));
+                }
+            }
+        }
+        for bookmark in bookmarks {
+            bookmark_lines(&mut lines, bookmark);
+        }
+        lines
+    }
+
+    /// Summary pane lines wrapped to `width` columns
+    fn summary_lines(&self, width: usize) -> Vec<Line<'static>> {
+        let Some((ref summary, _)) = self.summary else {
+            return Vec::new();
+        };
+        let mut lines: Vec<Line> = wrap(&summary.summary, width.max(1))
+            .into_iter()
+            .map(Line::raw)
+            .collect();
+        let items = summary
+            .key_points
+            .iter()
+            .map(|p| ("•", p.clone(), Color::Gray))
+            .chain(summary.action_items.iter().map(|a| {
+                let text = match a.assignee {
+                    Some(ref who) => format!("{} ({})", a.description, who),
+                    None => a.description.clone(),
+                };
+                ("☐", text, Color::Yellow)
+            }));
+        for (bullet, text, color) in items {
+            for (i, text) in wrap(&text, width.saturating_sub(2).max(1))
+                .into_iter()
+                .enumerate()
+            {
+                let lead = if i == 0 { bullet } else { " " };
+                lines.push(Line::styled(
+                    format!("{} {}", lead, text),
+                    Style::default().fg(color),
+                ));
+            }
+        }
+        lines
+    }
+}
+
+/// Word-wrap `text` to lines of at most `width` characters, splitting words
+/// longer than a line
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        while word.len() > width {
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            lines.push(word.drain(..width).collect());
+        }
+        let word: String = word.into_iter().collect();
+        let needed = if current.is_empty() { 0 } else { 1 } + word.chars().count();
+        if !current.is_empty() && current.chars().count() + needed > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(&word);
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Format a duration as HH:MM:SS
+fn format_elapsed(elapsed: chrono::Duration) -> String {
+    let secs = elapsed.num_seconds().max(0);
+    format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        (secs % 3600) / 60,
+        secs % 60
+    )
+}
+
+/// Run the viewer until the user quits
+pub fn run(socket_path: &Path) -> anyhow::Result<()> {
+    // Connect before taking over the terminal so errors print normally
+    let mut client = LiveClient::connect(socket_path).map_err(|e| {
+        anyhow::anyhow!(
+            "Cannot connect to the meeting socket at {}: {}\n\
+             Is the daemon running with [meeting] enabled = true?",
+            socket_path.display(),
+            e
+        )
+    })?;
+
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || loop {
+        match client.next_event() {
+            Ok(Some(event)) => {
+                if tx.send(Some(event)).is_err() {
+                    return;
+                }
+            }
+            Ok(None) | Err(_) => {
+                let _ = tx.send(None);
+                return;
+            }
+        }
+    });
+
+    let mut terminal = super::enter_terminal()?;
+    let result = event_loop(&mut terminal, &rx);
+    super::leave_terminal(&mut terminal)?;
+    result
+}
+
+fn event_loop(
+    terminal: &mut super::Tui,
+    rx: &mpsc::Receiver<Option<LiveEvent>>,
+) -> anyhow::Result<()> {
+    let mut state = WatchState::new();
+    loop {
+        for event in rx.try_iter() {
+            match event {
+                Some(event) => state.apply(event),
+                None => state.disconnected = true,
+            }
+        }
+
+        terminal.draw(|f| draw(f, &mut state))?;
+
+        // Redraw at least once a second for the clock
+        if !event::poll(Duration::from_millis(250))? {
+            continue;
+        }
+        match event::read()? {
+            Event::Key(key) => {
+                if !matches!(
+                    key.kind,
+                    event::KeyEventKind::Press | event::KeyEventKind::Repeat
+                ) {
+                    continue;
+                }
+                if handle_key(&mut state, key) {
+                    return Ok(());
+                }
+            }
+            Event::Mouse(mouse) => match mouse.kind {
+                MouseEventKind::ScrollUp => scroll_up(&mut state, 3),
+                MouseEventKind::ScrollDown => scroll_down(&mut state, 3),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+/// Handle a key press; returns true to quit
+fn handle_key(state: &mut WatchState, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => return true,
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return true,
+        KeyCode::Up | KeyCode::Char('k') => scroll_up(state, 1),
+        KeyCode::Down | KeyCode::Char('j') => scroll_down(state, 1),
+        KeyCode::PageUp => scroll_up(state, 10),
+        KeyCode::PageDown => scroll_down(state, 10),
+        KeyCode::End | KeyCode::Char('f') => state.scroll_back = None,
+        KeyCode::Char('s') => state.show_summary = !state.show_summary,
+        _ => {}
+    }
+    false
+}
+
+fn scroll_up(state: &mut WatchState, lines: usize) {
+    // Clamped against the transcript length when drawing
+    state.scroll_back = Some(state.scroll_back.unwrap_or(0).saturating_add(lines));
+}
+
+fn scroll_down(state: &mut WatchState, lines: usize) {
+    state.scroll_back = match state.scroll_back {
+        Some(back) if back > lines => Some(back - lines),
+        _ => None,
+    };
+}
+
+fn draw(f: &mut Frame, state: &mut WatchState) {
+    // The summary takes at most a third of the screen
+    let summary_height = if state.show_summary && state.summary.is_some() {
+        let lines = state.summary_lines(f.area().width as usize).len() as u16;
+        (lines + 1).min(f.area().height / 3)
+    } else {
+        0
+    };
+    let area = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),              // meeting header
+            Constraint::Length(1),              // chunk status
+            Constraint::Length(summary_height), // rolling summary
+            Constraint::Min(0),                 // transcript
+            Constraint::Length(1),              // footer / help
+        ])
+        .split(f.area());
+
+    render_header(f, area[0], state);
+    render_chunk_status(f, area[1], state);
+    render_summary(f, area[2], state);
+    render_transcript(f, area[3], state);
+    render_footer(f, area[4], state);
+}
+
+fn render_header(f: &mut Frame, area: Rect, state: &WatchState) {
+    let (badge, color) = match state.phase {
+        Phase::Waiting => ("○ WAITING", Color::DarkGray),
+        Phase::Recording => ("● REC", Color::Red),
+        Phase::Paused => ("❚❚ PAUSED", Color::Yellow),
+        Phase::Stopped => ("■ ENDED", Color::DarkGray),
+    };
+    let mut spans = vec![
+        Span::styled(
+            format!(" {} ", badge),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+    ];
+    if state.phase == Phase::Waiting {
+        spans.push(Span::raw(
+            "Waiting for a meeting to start (voxtype meeting start)",
+        ));
+    } else {
+        spans.push(Span::styled(
+            state.title.clone().unwrap_or_else(|| "Meeting".to_string()),
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::styled(
+            format!("  {}", format_elapsed(state.elapsed(Utc::now()))),
+            Style::default().fg(Color::White),
+        ));
+        if let Some(ref id) = state.meeting_id {
+            spans.push(Span::styled(
+                format!("  {}", id),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+    }
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+fn render_chunk_status(f: &mut Frame, area: Rect, state: &WatchState) {
+    let status = match state.chunk {
+        ChunkStatus::Idle if state.phase == Phase::Recording => {
+            "Listening, first chunk not transcribed yet".to_string()
+        }
+        ChunkStatus::Idle => String::new(),
+        ChunkStatus::Transcribing { source, audio_ms } => format!(
+            "Transcribing {}s of {} audio…",
+            (audio_ms + 500) / 1000,
+            source
+        ),
+        ChunkStatus::Done { source, segments } => {
+            format!("Last chunk ({}): {} segment(s)", source, segments)
+        }
+    };
+    let line = Line::from(vec![
+        Span::styled(
+            format!(" Chunks: {}  ", state.chunks),
+            Style::default().fg(Color::DarkGray),
+        ),
+        Span::styled(status, Style::default().fg(Color::Gray)),
+    ]);
+    f.render_widget(Paragraph::new(line), area);
+}
+
+fn render_summary(f: &mut Frame, area: Rect, state: &WatchState) {
+    let Some((_, covered_ms)) = state.summary else {
+        return;
+    };
+    if area.height == 0 {
+        return;
+    }
+    let block = Block::default().borders(Borders::TOP).title(Span::styled(
+        format!(" Summary up to {} ", format_offset(covered_ms)),
+        Style::default().fg(Color::DarkGray),
+    ));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let lines: Vec<Line> = state
+        .summary_lines(inner.width as usize)
+        .into_iter()
+        .take(inner.height as usize)
+        .collect();
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+fn render_transcript(f: &mut Frame, area: Rect, state: &mut WatchState) {
+    let block = Block::default().borders(Borders::TOP | Borders::BOTTOM);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let lines = state.transcript_lines(inner.width as usize);
+    let height = inner.height as usize;
+    let max_back = lines.len().saturating_sub(height);
+    if let Some(back) = state.scroll_back {
+        state.scroll_back = Some(back.min(max_back));
+    }
+    let back = state.scroll_back.unwrap_or(0);
+    let top = lines.len().saturating_sub(height + back);
+
+    if lines.is_empty() && state.phase != Phase::Waiting {
+        let hint = Paragraph::new(Line::styled(
+            "No speech transcribed yet.",
+            Style::default().fg(Color::DarkGray),
+        ));
+        f.render_widget(hint, inner);
+        return;
+    }
+    let visible: Vec<Line> = lines.into_iter().skip(top).take(height).collect();
+    f.render_widget(Paragraph::new(visible), inner);
+}
+
+fn render_footer(f: &mut Frame, area: Rect, state: &WatchState) {
+    let line = if state.disconnected {
+        Line::styled(
+            " Daemon closed the connection. q: quit",
+            Style::default().fg(Color::Red),
+        )
+    } else if let Some(ref error) = state.last_error {
+        Line::styled(format!(" {}", error), Style::default().fg(Color::Red))
+    } else {
+        let follow = if state.scroll_back.is_some() {
+            "End: follow"
+        } else {
+            "following"
+        };
+        let summary = if state.summary.is_some() {
+            "s: summary  "
+        } else {
+            ""
+        };
+        Line::styled(
+            format!(" ↑/↓ PgUp/PgDn: scroll  {}  {}q: quit", follow, summary),
+            Style::default().fg(Color::DarkGray),
+        )
+    };
+    f.render_widget(Paragraph::new(line), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start_ms: u64, text: &str, source: AudioSource) -> TranscriptSegment {
+        let mut segment = TranscriptSegment::new(0, start_ms, start_ms + 1000, text.to_string(), 0);
+        segment.source = source;
+        segment
+    }
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    #[test]
+    fn test_apply_builds_transcript() {
+        let mut state = WatchState::new();
+        state.apply(LiveEvent::Started {
+            meeting_id: "m1".to_string(),
+            title: Some("Standup".to_string()),
+            started_at: at(0),
+        });
+        state.apply(LiveEvent::ChunkStarted {
+            source: AudioSource::Microphone,
+            audio_ms: 30_000,
+        });
+        assert!(matches!(state.chunk, ChunkStatus::Transcribing { .. }));
+
+        state.apply(LiveEvent::ChunkProcessed {
+            source: AudioSource::Microphone,
+            segments: vec![
+                segment(5_000, "second", AudioSource::Microphone),
+                segment(9_000, "  ", AudioSource::Microphone),
+            ],
+        });
+        state.apply(LiveEvent::ChunkProcessed {
+            source: AudioSource::Loopback,
+            segments: vec![segment(1_000, "first", AudioSource::Loopback)],
+        });
+
+        let texts: Vec<&str> = state.segments.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, vec!["first", "second"]);
+        assert_eq!(state.chunks, 2);
+        assert_eq!(state.speakers, vec!["You", "Remote"]);
+
+        // A new meeting starts from scratch
+        state.apply(LiveEvent::Started {
+            meeting_id: "m2".to_string(),
+            title: None,
+            started_at: at(100),
+        });
+        assert!(state.segments.is_empty());
+        assert_eq!(state.chunks, 0);
+    }
+
+    #[test]
+    fn test_translation_lines() {
+        let mut state = WatchState::new();
+        let mut translated = segment(1_000, "Hallo zusammen", AudioSource::Loopback);
+        translated.translation = Some("Hello everyone".to_string());
+        state.apply(LiveEvent::ChunkProcessed {
+            source: AudioSource::Loopback,
+            segments: vec![translated, segment(4_000, "ok", AudioSource::Loopback)],
+        });
+
+        let lines: Vec<String> = state
+            .transcript_lines(80)
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].ends_with("Hallo zusammen"));
+        assert_eq!(lines[1].trim(), "Hello everyone");
+        assert!(lines[2].ends_with("ok"));
+    }
+
+    #[test]
+    fn test_bookmark_lines() {
+        let mut state = WatchState::new();
+        state.apply(LiveEvent::ChunkProcessed {
+            source: AudioSource::Microphone,
+            segments: vec![
+                segment(1_000, "first", AudioSource::Microphone),
+                segment(8_000, "second", AudioSource::Microphone),
+            ],
+        });
+        let bookmark = |offset_ms, note: Option<&str>| LiveEvent::Marked {
+            bookmark: Bookmark {
+                offset_ms,
+                note: note.map(String::from),
+                created_at: at(0),
+            },
+        };
+        state.apply(bookmark(12_000, None));
+        state.apply(bookmark(5_000, Some("Decision")));
+
+        let lines: Vec<String> = state
+            .transcript_lines(80)
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].ends_with("first"));
+        assert_eq!(lines[1], "[00:05] ★ Decision");
+        assert!(lines[2].ends_with("second"));
+        assert_eq!(lines[3], "[00:12] ★ Bookmark");
+    }
+
+    #[test]
+    fn test_elapsed_skips_pauses() {
+        let mut state = WatchState::new();
+        state.apply(LiveEvent::Started {
+            meeting_id: "m1".to_string(),
+            title: None,
+            started_at: at(0),
+        });
+        state.apply(LiveEvent::Paused { at: at(60) });
+        assert_eq!(state.elapsed(at(500)).num_seconds(), 60);
+        state.apply(LiveEvent::Resumed { at: at(90) });
+        assert_eq!(state.elapsed(at(100)).num_seconds(), 70);
+        state.apply(LiveEvent::Stopped { at: at(120) });
+        assert_eq!(state.elapsed(at(999)).num_seconds(), 90);
+        assert_eq!(format_elapsed(state.elapsed(at(999))), "00:01:30");
+    }
+
+    #[test]
+    fn test_summary_pane() {
+        let mut state = WatchState::new();
+        state.apply(LiveEvent::Started {
+            meeting_id: "m1".to_string(),
+            title: None,
+            started_at: at(0),
+        });
+        assert!(state.summary_lines(40).is_empty());
+
+        state.apply(LiveEvent::Summary {
+            summary: MeetingSummary {
+                summary: "We planned the release.".to_string(),
+                key_points: vec!["Ship Friday".to_string()],
+                action_items: vec![crate::meeting::data::ActionItem {
+                    description: "Write notes".to_string(),
+                    assignee: Some("Sam".to_string()),
+                    due_date: None,
+                    completed: false,
+                }],
+                decisions: Vec::new(),
+                generated_at: at(600),
+                model: None,
+                chapters: Vec::new(),
+            },
+            covered_ms: 600_000,
+        });
+        let lines: Vec<String> = state
+            .summary_lines(40)
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                "We planned the release.",
+                "• Ship Friday",
+                "☐ Write notes (Sam)"
+            ]
+        );
+
+        // Hiding the pane survives the next meeting
+        handle_key(&mut state, KeyEvent::from(KeyCode::Char('s')));
+        state.apply(LiveEvent::Started {
+            meeting_id: "m2".to_string(),
+            title: None,
+            started_at: at(700),
+        });
+        assert!(state.summary.is_none());
+        assert!(!state.show_summary);
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(wrap("one two three", 7), vec!["one two", "three"]);
+        assert_eq!(wrap("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert!(wrap("   ", 4).is_empty());
+    }
+
+    #[test]
+    fn test_scroll() {
+        let mut state = WatchState::new();
+        scroll_down(&mut state, 1);
+        assert_eq!(state.scroll_back, None);
+        scroll_up(&mut state, 3);
+        scroll_down(&mut state, 1);
+        assert_eq!(state.scroll_back, Some(2));
+        scroll_down(&mut state, 2);
+        assert_eq!(state.scroll_back, None);
+    }
+}
@@ -3,6 +3,9 @@
 //! Renders an interactive view over voxtype settings. The General section
 //! (variant picker + daemon status) is functional today; remaining sections
 //! ship as placeholders and will be filled in over subsequent PRs.
+//!
+//! Also hosts the live transcript view for `voxtype meeting watch`
+//! (`meeting_watch.rs`), which shares the terminal setup.
 
 mod advanced_section;
 mod app;
@@ -14,6 +17,7 @@ mod engine;
 mod general;
 mod hotkey;
 mod meeting_section;
+mod meeting_watch;
 mod notifications_section;
 mod osd_section;
 mod output_section;
@@ -25,6 +29,7 @@ mod waybar_section;
 
 #[allow(unused_imports)]
 pub(crate) use config_editor::{ConfigEditor, EditorError};
+pub use meeting_watch::run as run_meeting_watch;
 
 use crossterm::{
     event::{
@@ -68,7 +68,15 @@ impl Field {
     ];
 }
 
-const MODE_CHOICES: &[&str] = &["type", "clipboard", "paste", "file"];
+const MODE_CHOICES: &[&str] = &[
+    "type",
+    "clipboard",
+    "paste",
+    "primary",
+    "tmux",
+    "webhook",
+    "file",
+];
 const APPEND_CHOICES: &[Option<&str>] = &[None, Some(" "), Some("\n"), Some(". ")];
 const POST_PROCESS_PRESETS: &[Option<&str>] = &[
     None,
@@ -396,6 +404,24 @@ fn guidance_for_field(state: &OutputState) -> Vec<Line<'_>> {
             )),
             Line::from("Clipboard + Ctrl+V. Faster than typing for long transcripts."),
             Line::from(""),
+            Line::from(Span::styled(
+                "primary: ",
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Line::from("Puts text on the primary selection — paste it with middle-click."),
+            Line::from(""),
+            Line::from(Span::styled(
+                "tmux: ",
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Line::from("Loads a tmux paste buffer, and pastes it with tmux_paste = true."),
+            Line::from(""),
+            Line::from(Span::styled(
+                "webhook: ",
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Line::from("Posts the text as JSON to the [output.webhook] url."),
+            Line::from(""),
             Line::from(Span::styled(
                 "file: ",
                 Style::default().add_modifier(Modifier::BOLD),
@@ -32,7 +32,7 @@ pub enum Field {
 impl Field {
     const ALL: &'static [Field] = &[Field::Enabled, Field::Backend, Field::Threshold];
 }
-const BACKEND_CHOICES: &[&str] = &["auto", "energy", "whisper"];
+const BACKEND_CHOICES: &[&str] = &["auto", "energy", "whisper", "silero"];
 
 impl VadState {
     pub fn load() -> Result<Self, EditorError> {
@@ -202,8 +202,9 @@ fn guidance_for_field(state: &VadState) -> Vec<Line<'_>> {
                 Style::default().add_modifier(Modifier::BOLD),
             )),
             Line::from(
-                "Whisper VAD for the Whisper engine; Energy VAD for ONNX. \
-                 Pick this unless you want to override.",
+                "Whisper VAD for the Whisper engine; Silero VAD for the \
+                 others once downloaded, Energy VAD until then. Pick this \
+                 unless you want to override.",
             ),
             Line::from(""),
             Line::from(Span::styled(
@@ -223,6 +224,16 @@ fn guidance_for_field(state: &VadState) -> Vec<Line<'_>> {
                 "Silero VAD via whisper-rs. Most accurate. Requires \
                  ggml-silero-vad.bin (run `voxtype setup vad` to fetch).",
             ),
+            Line::from(""),
+            Line::from(Span::styled(
+                "silero: ",
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Line::from(
+                "The same Silero model run with ONNX Runtime, for any \
+                 engine. Requires an ONNX build and silero_vad.onnx (run \
+                 `voxtype setup vad --backend silero` to fetch).",
+            ),
         ],
         Field::Threshold => vec![
             heading("Speech threshold"),
@@ -0,0 +1,205 @@
+//! Trailing-silence endpointing for hands-free recordings
+//!
+//! [`EndpointDetector`] watches audio while it is captured and reports when
+//! the speaker has stopped: at least `min_speech_duration_ms` of speech
+//! followed by `auto_stop_silence_ms` of silence in a row. Frames are
+//! classified with Silero VAD when that is the configured backend, and by
+//! RMS energy otherwise.
+
+use super::energy::{map_threshold_to_energy, EnergyVad};
+#[cfg(feature = "silero-vad")]
+use super::{SileroStream, SileroVad};
+use crate::config::VadConfig;
+#[cfg(feature = "silero-vad")]
+use std::sync::Arc;
+
+const SAMPLE_RATE: usize = 16000;
+/// Samples per frame, the frame size Silero scores (32ms)
+const FRAME_SIZE: usize = 512;
+const FRAME_MS: u32 = (FRAME_SIZE * 1000 / SAMPLE_RATE) as u32;
+
+/// Decides whether a frame is speech
+#[derive(Clone)]
+enum FrameClassifier {
+    /// RMS above the threshold `[vad] threshold` maps to
+    Energy { threshold: f32 },
+    #[cfg(feature = "silero-vad")]
+    Silero {
+        vad: Arc<SileroVad>,
+        stream: SileroStream,
+        /// Whether the previous frame was speech, for the hysteresis
+        speaking: bool,
+    },
+}
+
+impl FrameClassifier {
+    /// Whether `frame` is speech
+    fn is_speech(&mut self, frame: &[f32]) -> bool {
+        match self {
+            FrameClassifier::Energy { threshold } => EnergyVad::calculate_rms(frame) >= *threshold,
+            #[cfg(feature = "silero-vad")]
+            FrameClassifier::Silero {
+                vad,
+                stream,
+                speaking,
+            } => match vad.process(stream, frame) {
+                Ok(probability) => {
+                    let threshold = if *speaking {
+                        (vad.threshold() - super::silero::HYSTERESIS).max(0.0)
+                    } else {
+                        vad.threshold()
+                    };
+                    *speaking = probability >= threshold;
+                    *speaking
+                }
+                Err(e) => {
+                    // Count it as speech so a failing model never cuts the
+                    // speaker off; the recording can still be stopped by hand
+                    tracing::warn!("Auto-stop VAD failed: {}", e);
+                    true
+                }
+            },
+        }
+    }
+}
+
+/// Detects the end of speech in a stream of 16kHz audio
+///
+/// Create one per recording; clones of an unused detector start fresh.
+#[derive(Clone)]
+pub struct EndpointDetector {
+    classifier: FrameClassifier,
+    /// Samples not yet making up a whole frame
+    pending: Vec<f32>,
+    min_speech_frames: usize,
+    silence_frames_limit: usize,
+    speech_frames: usize,
+    silence_frames: usize,
+}
+
+impl EndpointDetector {
+    /// Detector classifying frames by RMS energy
+    pub fn energy(config: &VadConfig) -> Self {
+        Self::new(
+            FrameClassifier::Energy {
+                threshold: map_threshold_to_energy(config.threshold),
+            },
+            config,
+        )
+    }
+
+    /// Detector classifying frames with Silero VAD
+    #[cfg(feature = "silero-vad")]
+    pub fn silero(vad: Arc<SileroVad>, config: &VadConfig) -> Self {
+        Self::new(
+            FrameClassifier::Silero {
+                vad,
+                stream: SileroStream::default(),
+                speaking: false,
+            },
+            config,
+        )
+    }
+
+    fn new(classifier: FrameClassifier, config: &VadConfig) -> Self {
+        Self {
+            classifier,
+            pending: Vec::with_capacity(FRAME_SIZE),
+            min_speech_frames: frames_for(config.min_speech_duration_ms).max(1),
+            silence_frames_limit: frames_for(config.auto_stop_silence_ms).max(1),
+            speech_frames: 0,
+            silence_frames: 0,
+        }
+    }
+
+    /// Add captured samples. Returns true once the speaker has stopped.
+    pub fn push(&mut self, samples: &[f32]) -> bool {
+        self.pending.extend_from_slice(samples);
+
+        let mut ended = false;
+        let mut offset = 0;
+        while self.pending.len() - offset >= FRAME_SIZE {
+            let frame = &self.pending[offset..offset + FRAME_SIZE];
+            offset += FRAME_SIZE;
+
+            if self.classifier.is_speech(frame) {
+                self.speech_frames += 1;
+                self.silence_frames = 0;
+            } else {
+                self.silence_frames += 1;
+            }
+            ended |= self.speech_frames >= self.min_speech_frames
+                && self.silence_frames >= self.silence_frames_limit;
+        }
+        self.pending.drain(..offset);
+        ended
+    }
+}
+
+/// Whole frames covering `ms` milliseconds, rounded up
+fn frames_for(ms: u32) -> usize {
+    ms.div_ceil(FRAME_MS) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone(ms: usize) -> Vec<f32> {
+        (0..SAMPLE_RATE * ms / 1000)
+            .map(|i| (i as f32 * 440.0 * 2.0 * std::f32::consts::PI / 16000.0).sin() * 0.5)
+            .collect()
+    }
+
+    fn silence(ms: usize) -> Vec<f32> {
+        vec![0.0; SAMPLE_RATE * ms / 1000]
+    }
+
+    fn config(auto_stop_silence_ms: u32) -> VadConfig {
+        VadConfig {
+            auto_stop_silence_ms,
+            ..VadConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_stops_after_trailing_silence() {
+        let mut detector = EndpointDetector::energy(&config(1000));
+        assert!(!detector.push(&tone(500)));
+        assert!(!detector.push(&silence(800)));
+        // Speech resets the silence count
+        assert!(!detector.push(&tone(200)));
+        assert!(!detector.push(&silence(800)));
+        assert!(detector.push(&silence(300)));
+    }
+
+    #[test]
+    fn test_needs_speech_first() {
+        let mut detector = EndpointDetector::energy(&config(500));
+        // Silence before the speaker starts never ends the recording
+        assert!(!detector.push(&silence(3000)));
+        // Nor does a click shorter than min_speech_duration_ms
+        assert!(!detector.push(&tone(32)));
+        assert!(!detector.push(&silence(1000)));
+        assert!(!detector.push(&tone(200)));
+        assert!(detector.push(&silence(600)));
+    }
+
+    #[test]
+    fn test_small_chunks() {
+        let mut detector = EndpointDetector::energy(&config(300));
+        let mut audio = tone(300);
+        audio.extend(silence(400));
+        // Capture delivers chunks that don't line up with frames
+        let stopped = audio.chunks(160).any(|chunk| detector.push(chunk));
+        assert!(stopped);
+    }
+
+    #[test]
+    fn test_frames_for() {
+        assert_eq!(frames_for(0), 0);
+        assert_eq!(frames_for(32), 1);
+        assert_eq!(frames_for(33), 2);
+        assert_eq!(frames_for(1500), 47);
+    }
+}
@@ -37,7 +37,7 @@ impl EnergyVad {
     }
 
     /// Calculate RMS energy of a sample slice
-    fn calculate_rms(samples: &[f32]) -> f32 {
+    pub(super) fn calculate_rms(samples: &[f32]) -> f32 {
         if samples.is_empty() {
             return 0.0;
         }
@@ -51,7 +51,7 @@ impl EnergyVad {
 /// - 0.0 = very sensitive (energy threshold ~0.001, detects quiet whispers)
 /// - 0.5 = balanced (energy threshold ~0.01, filters silence)
 /// - 1.0 = aggressive (energy threshold ~0.1, requires louder speech)
-fn map_threshold_to_energy(config_threshold: f32) -> f32 {
+pub(super) fn map_threshold_to_energy(config_threshold: f32) -> f32 {
     // Exponential mapping: lower config values = lower energy threshold
     // Range: 0.001 to 0.1
     let t = config_threshold.clamp(0.0, 1.0);
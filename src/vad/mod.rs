@@ -3,18 +3,29 @@
 //! Provides VAD to filter silence-only recordings before transcription,
 //! preventing Whisper hallucinations when processing silence.
 //!
-//! Two backends are available:
+//! Three backends are available:
 //! - **Energy VAD**: Simple RMS-based detection, no model needed, fast
 //! - **Whisper VAD**: Silero model via whisper-rs, more accurate, requires model download
+//! - **Silero VAD**: Silero model via ONNX Runtime, works with every engine,
+//!   requires the `silero-vad` feature and a model download
+//!
+//! [`EndpointDetector`] reuses them to end hands-free recordings after
+//! trailing silence (`auto_stop_silence_ms`).
 
+mod endpoint;
 mod energy;
+#[cfg(feature = "silero-vad")]
+mod silero;
 mod whisper_vad;
 
-use crate::config::{Config, TranscriptionEngine, VadBackend};
+use crate::config::{Activation, Config, TranscriptionEngine, VadBackend};
 use crate::error::VadError;
 use std::path::PathBuf;
 
+pub use endpoint::EndpointDetector;
 pub use energy::EnergyVad;
+#[cfg(feature = "silero-vad")]
+pub use silero::{SileroStream, SileroVad};
 pub use whisper_vad::WhisperVad;
 
 /// Result of voice activity detection
@@ -51,10 +62,76 @@ pub fn create_vad(config: &Config) -> Result<Option<Box<dyn VoiceActivityDetecto
         return Ok(None);
     }
 
-    // Determine which backend to use
-    let backend = match config.vad.backend {
+    let vad: Box<dyn VoiceActivityDetector> = match resolve_backend(config) {
+        VadBackend::Energy | VadBackend::Auto => {
+            tracing::info!("Using Energy VAD backend");
+            Box::new(EnergyVad::new(&config.vad))
+        }
+        VadBackend::Whisper => {
+            let model_path = resolve_vad_model_path(&config.vad, get_whisper_vad_model_filename())?;
+            tracing::info!("Using Whisper VAD backend with model {:?}", model_path);
+            Box::new(WhisperVad::new(&model_path, &config.vad)?)
+        }
+        #[cfg(feature = "silero-vad")]
+        VadBackend::Silero => {
+            let model_path = resolve_vad_model_path(&config.vad, get_silero_vad_model_filename())?;
+            tracing::info!("Using Silero VAD backend with model {:?}", model_path);
+            Box::new(SileroVad::new(&model_path, &config.vad)?)
+        }
+        #[cfg(not(feature = "silero-vad"))]
+        VadBackend::Silero => return Err(silero_unavailable()),
+    };
+
+    Ok(Some(vad))
+}
+
+/// Whether `create_vad` builds the same detector for `a` and `b`, so a
+/// profile can share the default one instead of loading its own
+pub fn same_vad(a: &Config, b: &Config) -> bool {
+    if !a.vad.enabled || !b.vad.enabled {
+        return a.vad.enabled == b.vad.enabled;
+    }
+    a.vad == b.vad && resolve_backend(a) == resolve_backend(b)
+}
+
+/// Create the trailing-silence detector for auto-stop
+///
+/// Returns None if `auto_stop_silence_ms` is 0 (outside wake word
+/// activation, which falls back to `[wake_word] silence_ms`), or Err if the
+/// Silero model fails to load. Whisper VAD only scores whole recordings, so
+/// that backend endpoints with Energy VAD.
+pub fn create_endpoint_detector(config: &Config) -> Result<Option<EndpointDetector>, VadError> {
+    let mut vad_config = config.vad.clone();
+    if vad_config.auto_stop_silence_ms == 0 && config.activation == Activation::WakeWord {
+        vad_config.auto_stop_silence_ms = config.wake_word.silence_ms;
+    }
+    if vad_config.auto_stop_silence_ms == 0 {
+        return Ok(None);
+    }
+
+    let detector = match resolve_backend(config) {
+        VadBackend::Energy | VadBackend::Auto | VadBackend::Whisper => {
+            EndpointDetector::energy(&vad_config)
+        }
+        #[cfg(feature = "silero-vad")]
+        VadBackend::Silero => {
+            let model_path = resolve_vad_model_path(&vad_config, get_silero_vad_model_filename())?;
+            let vad = SileroVad::new(&model_path, &vad_config)?;
+            EndpointDetector::silero(std::sync::Arc::new(vad), &vad_config)
+        }
+        #[cfg(not(feature = "silero-vad"))]
+        VadBackend::Silero => return Err(silero_unavailable()),
+    };
+
+    Ok(Some(detector))
+}
+
+/// The backend `[vad] backend` selects for the configured engine
+fn resolve_backend(config: &Config) -> VadBackend {
+    match config.vad.backend {
         VadBackend::Auto => {
-            // Auto-select: Whisper VAD for Whisper engine, Energy for Parakeet
+            // Auto-select: Whisper VAD for Whisper engine; Silero for the
+            // others when it's built in and downloaded, Energy otherwise
             match config.engine {
                 TranscriptionEngine::Whisper => VadBackend::Whisper,
                 TranscriptionEngine::Parakeet
@@ -64,29 +141,39 @@ pub fn create_vad(config: &Config) -> Result<Option<Box<dyn VoiceActivityDetecto
                 | TranscriptionEngine::Dolphin
                 | TranscriptionEngine::Omnilingual
                 | TranscriptionEngine::Cohere
-                | TranscriptionEngine::Soniox => VadBackend::Energy,
+                | TranscriptionEngine::Soniox
+                | TranscriptionEngine::Vosk
+                | TranscriptionEngine::Zipformer => {
+                    if cfg!(feature = "silero-vad")
+                        && resolve_vad_model_path(&config.vad, get_silero_vad_model_filename())
+                            .is_ok()
+                    {
+                        VadBackend::Silero
+                    } else {
+                        VadBackend::Energy
+                    }
+                }
             }
         }
         explicit => explicit,
-    };
-
-    let vad: Box<dyn VoiceActivityDetector> = match backend {
-        VadBackend::Energy | VadBackend::Auto => {
-            tracing::info!("Using Energy VAD backend");
-            Box::new(EnergyVad::new(&config.vad))
-        }
-        VadBackend::Whisper => {
-            let model_path = resolve_whisper_vad_model_path(&config.vad)?;
-            tracing::info!("Using Whisper VAD backend with model {:?}", model_path);
-            Box::new(WhisperVad::new(&model_path, &config.vad)?)
-        }
-    };
+    }
+}
 
-    Ok(Some(vad))
+#[cfg(not(feature = "silero-vad"))]
+fn silero_unavailable() -> VadError {
+    VadError::InitFailed(
+        "Silero VAD requires a build with the `silero-vad` feature (included with \
+         every ONNX engine). Use backend = \"energy\" or \"whisper\" instead."
+            .to_string(),
+    )
 }
 
-/// Resolve the path to the Whisper VAD model
-fn resolve_whisper_vad_model_path(config: &crate::config::VadConfig) -> Result<PathBuf, VadError> {
+/// Resolve the path to a VAD model: the configured one, or `filename` in
+/// the models directory
+fn resolve_vad_model_path(
+    config: &crate::config::VadConfig,
+    filename: &str,
+) -> Result<PathBuf, VadError> {
     // If model path is explicitly configured, use it
     if let Some(ref model) = config.model {
         let path = PathBuf::from(model);
@@ -98,7 +185,7 @@ fn resolve_whisper_vad_model_path(config: &crate::config::VadConfig) -> Result<P
 
     // Use default model location
     let models_dir = Config::models_dir();
-    let model_path = models_dir.join("ggml-silero-vad.bin");
+    let model_path = models_dir.join(filename);
 
     if model_path.exists() {
         Ok(model_path)
@@ -120,6 +207,16 @@ pub fn get_whisper_vad_model_filename() -> &'static str {
     "ggml-silero-vad.bin"
 }
 
+/// Get the download URL for the Silero VAD ONNX model
+pub fn get_silero_vad_model_url() -> &'static str {
+    "https://raw.githubusercontent.com/snakers4/silero-vad/v5.1.2/src/silero_vad/data/silero_vad.onnx"
+}
+
+/// Get the Silero VAD ONNX model filename
+pub fn get_silero_vad_model_filename() -> &'static str {
+    "silero_vad.onnx"
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,6 +263,65 @@ mod tests {
         assert!(vad.is_some());
     }
 
+    #[test]
+    fn test_same_vad() {
+        let mut global = Config::default();
+        global.vad.enabled = true;
+        global.vad.backend = VadBackend::Energy;
+
+        let mut profile = global.clone();
+        assert!(same_vad(&global, &profile));
+        // An explicit backend doesn't depend on the engine
+        profile.engine = TranscriptionEngine::Parakeet;
+        assert!(same_vad(&global, &profile));
+        profile.vad.threshold = 0.7;
+        assert!(!same_vad(&global, &profile));
+
+        // With "auto" the engine picks the backend
+        global.vad.backend = VadBackend::Auto;
+        let mut profile = global.clone();
+        profile.engine = TranscriptionEngine::Parakeet;
+        assert!(!same_vad(&global, &profile));
+
+        // Disabled is disabled, whatever the other settings
+        let mut profile = Config::default();
+        profile.vad.threshold = 0.9;
+        assert!(same_vad(&Config::default(), &profile));
+        assert!(!same_vad(&global, &Config::default()));
+    }
+
+    #[test]
+    fn test_parse_silero_backend() {
+        let config: crate::config::VadConfig =
+            toml::from_str("enabled = true\nbackend = \"silero\"").unwrap();
+        assert_eq!(config.backend, VadBackend::Silero);
+    }
+
+    #[cfg(not(feature = "silero-vad"))]
+    #[test]
+    fn test_create_vad_silero_needs_feature() {
+        let mut config = Config::default();
+        config.vad.enabled = true;
+        config.vad.backend = VadBackend::Silero;
+        assert!(matches!(create_vad(&config), Err(VadError::InitFailed(_))));
+    }
+
+    #[test]
+    fn test_create_endpoint_detector() {
+        let mut config = Config::default();
+        // Auto-stop is off by default and doesn't depend on [vad] enabled
+        assert!(create_endpoint_detector(&config).unwrap().is_none());
+        config.vad.auto_stop_silence_ms = 1500;
+        config.vad.backend = VadBackend::Whisper;
+        // Whisper VAD can't stream, so this endpoints on energy without a model
+        assert!(create_endpoint_detector(&config).unwrap().is_some());
+
+        // Wake word recordings always stop on silence
+        config.vad.auto_stop_silence_ms = 0;
+        config.activation = Activation::WakeWord;
+        assert!(create_endpoint_detector(&config).unwrap().is_some());
+    }
+
     #[test]
     fn test_whisper_vad_model_url() {
         let url = get_whisper_vad_model_url();
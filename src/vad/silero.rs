@@ -0,0 +1,204 @@
+//! Silero VAD run directly with ONNX Runtime
+//!
+//! The same Silero model Whisper VAD wraps, but loaded from its ONNX
+//! export (silero_vad.onnx, v5) through ort, so it works with every engine
+//! and doesn't need a whisper-rs build with VAD support.
+//!
+//! The model scores 32ms frames (512 samples at 16kHz), carrying a
+//! recurrent state and the last 64 samples of the previous frame from one
+//! frame to the next.
+
+use super::{VadResult, VoiceActivityDetector};
+use crate::config::VadConfig;
+use crate::error::VadError;
+use ort::session::{Session, SessionInputValue};
+use ort::value::Tensor;
+use std::borrow::Cow;
+use std::path::Path;
+use std::sync::Mutex;
+
+const SAMPLE_RATE: usize = 16000;
+/// Samples per frame the model scores
+pub const FRAME_SIZE: usize = 512;
+/// Samples of the previous frame prepended to each frame
+const CONTEXT_SIZE: usize = 64;
+/// Size of the recurrent state, [2, 1, 128]
+const STATE_SIZE: usize = 2 * 128;
+/// Speech ends once the probability drops this far below the threshold,
+/// as in the reference implementation
+pub(super) const HYSTERESIS: f32 = 0.15;
+
+/// Silero VAD model loaded with ONNX Runtime
+pub struct SileroVad {
+    session: Mutex<Session>,
+    /// Speech probability threshold (0.0 - 1.0)
+    threshold: f32,
+    /// Minimum speech duration in milliseconds
+    min_speech_duration_ms: u32,
+}
+
+impl SileroVad {
+    /// Load the model
+    ///
+    /// # Arguments
+    /// * `model_path` - Path to the ONNX model file (silero_vad.onnx)
+    /// * `config` - VAD configuration
+    pub fn new(model_path: &Path, config: &VadConfig) -> Result<Self, VadError> {
+        tracing::debug!("Loading Silero VAD model from {:?}", model_path);
+
+        let session = Session::builder()
+            .map_err(|e| VadError::InitFailed(format!("ONNX session builder failed: {}", e)))?
+            .with_intra_threads(1)
+            .map_err(|e| VadError::InitFailed(format!("Failed to set threads: {}", e)))?
+            .commit_from_file(model_path)
+            .map_err(|e| VadError::InitFailed(format!("Failed to load VAD model: {}", e)))?;
+
+        tracing::info!("Silero VAD model loaded successfully");
+
+        Ok(Self {
+            session: Mutex::new(session),
+            threshold: config.threshold.clamp(0.0, 1.0),
+            min_speech_duration_ms: config.min_speech_duration_ms,
+        })
+    }
+
+    /// Speech probability threshold
+    pub fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    /// Speech probability of the next frame of the stream `stream` tracks.
+    /// Frames shorter than [`FRAME_SIZE`] are padded with silence.
+    pub fn process(&self, stream: &mut SileroStream, frame: &[f32]) -> Result<f32, VadError> {
+        let mut input = Vec::with_capacity(CONTEXT_SIZE + FRAME_SIZE);
+        input.extend_from_slice(&stream.context);
+        input.extend(frame.iter().take(FRAME_SIZE));
+        input.resize(CONTEXT_SIZE + FRAME_SIZE, 0.0);
+        let context = input[FRAME_SIZE..].to_vec();
+
+        let input_tensor =
+            Tensor::<f32>::from_array(([1usize, input.len()], input)).map_err(|e| {
+                VadError::DetectionFailed(format!("Failed to create input tensor: {}", e))
+            })?;
+        let state_tensor = Tensor::<f32>::from_array(([2usize, 1, 128], stream.state.clone()))
+            .map_err(|e| {
+                VadError::DetectionFailed(format!("Failed to create state tensor: {}", e))
+            })?;
+        let sr_tensor = Tensor::<i64>::from_array(([] as [usize; 0], vec![SAMPLE_RATE as i64]))
+            .map_err(|e| VadError::DetectionFailed(format!("Failed to create sr tensor: {}", e)))?;
+
+        let mut session = self
+            .session
+            .lock()
+            .map_err(|e| VadError::DetectionFailed(format!("Failed to acquire VAD lock: {}", e)))?;
+        let inputs: Vec<(Cow<str>, SessionInputValue)> = vec![
+            (Cow::Borrowed("input"), input_tensor.into()),
+            (Cow::Borrowed("state"), state_tensor.into()),
+            (Cow::Borrowed("sr"), sr_tensor.into()),
+        ];
+        let outputs = session
+            .run(inputs)
+            .map_err(|e| VadError::DetectionFailed(format!("VAD inference failed: {}", e)))?;
+
+        let (_, probability) = outputs["output"]
+            .try_extract_tensor::<f32>()
+            .map_err(|e| VadError::DetectionFailed(format!("Failed to read output: {}", e)))?;
+        let probability = probability.first().copied().unwrap_or(0.0);
+        let (_, state) = outputs["stateN"]
+            .try_extract_tensor::<f32>()
+            .map_err(|e| VadError::DetectionFailed(format!("Failed to read state: {}", e)))?;
+        stream.state = state.to_vec();
+        stream.context = context;
+
+        Ok(probability)
+    }
+}
+
+/// Model state for one stream of audio, carried from frame to frame
+#[derive(Debug, Clone)]
+pub struct SileroStream {
+    state: Vec<f32>,
+    context: Vec<f32>,
+}
+
+impl Default for SileroStream {
+    fn default() -> Self {
+        Self {
+            state: vec![0.0; STATE_SIZE],
+            context: vec![0.0; CONTEXT_SIZE],
+        }
+    }
+}
+
+/// Count speech frames from per-frame probabilities. A frame at or above
+/// `threshold` starts speech, which lasts until a frame drops below
+/// `threshold - HYSTERESIS`. Returns the number of speech frames.
+fn speech_frames(probabilities: &[f32], threshold: f32) -> usize {
+    let end_threshold = (threshold - HYSTERESIS).max(0.0);
+    let mut speaking = false;
+    probabilities
+        .iter()
+        .filter(|&&p| {
+            speaking = if speaking {
+                p >= end_threshold
+            } else {
+                p >= threshold
+            };
+            speaking
+        })
+        .count()
+}
+
+impl VoiceActivityDetector for SileroVad {
+    fn detect(&self, samples: &[f32]) -> Result<VadResult, VadError> {
+        let mut stream = SileroStream::default();
+        let probabilities = samples
+            .chunks(FRAME_SIZE)
+            .map(|frame| self.process(&mut stream, frame))
+            .collect::<Result<Vec<f32>, VadError>>()?;
+
+        let frames = speech_frames(&probabilities, self.threshold);
+        let speech_duration_secs =
+            (frames * FRAME_SIZE).min(samples.len()) as f32 / SAMPLE_RATE as f32;
+        let total_duration_secs = samples.len() as f32 / SAMPLE_RATE as f32;
+        let speech_ratio = if total_duration_secs > 0.0 {
+            (speech_duration_secs / total_duration_secs).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        let min_speech_secs = self.min_speech_duration_ms as f32 / 1000.0;
+        let has_speech = frames > 0 && speech_duration_secs >= min_speech_secs;
+
+        tracing::debug!(
+            "VAD result: {} speech frames, {:.2}s speech ({:.1}% of {:.2}s total)",
+            frames,
+            speech_duration_secs,
+            speech_ratio * 100.0,
+            total_duration_secs
+        );
+
+        Ok(VadResult {
+            has_speech,
+            speech_duration_secs,
+            speech_ratio,
+            rms_energy: 0.0, // Not available from Silero VAD
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_speech_frames_hysteresis() {
+        // Below the threshold never starts speech
+        assert_eq!(speech_frames(&[0.1, 0.4, 0.45], 0.5), 0);
+        // Once started, speech continues down to threshold - 0.15
+        assert_eq!(speech_frames(&[0.2, 0.6, 0.4, 0.36, 0.3, 0.4], 0.5), 3);
+        assert_eq!(speech_frames(&[], 0.5), 0);
+        // A threshold of 0 counts every frame
+        assert_eq!(speech_frames(&[0.0, 0.0], 0.0), 2);
+    }
+}
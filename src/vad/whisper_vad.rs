@@ -132,6 +132,7 @@ mod tests {
             backend: VadBackend::Whisper,
             threshold: 1.5, // Above max
             min_speech_duration_ms: 100,
+            auto_stop_silence_ms: 0,
             model: None,
         };
 
@@ -145,6 +146,7 @@ mod tests {
             backend: VadBackend::Whisper,
             threshold: -0.5, // Below min
             min_speech_duration_ms: 100,
+            auto_stop_silence_ms: 0,
             model: None,
         };
         let clamped2 = config2.threshold.clamp(0.0, 1.0);
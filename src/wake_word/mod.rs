@@ -0,0 +1,242 @@
+//! Wake word activation
+//!
+//! With `activation = "wake_word"` the daemon keeps a microphone capture
+//! open and runs an openWakeWord model over it. Hearing the wake word starts
+//! a hands-free recording, which the trailing-silence endpoint detector
+//! ([`crate::vad::EndpointDetector`]) stops.
+//!
+//! openWakeWord needs three ONNX files in `<models>/wake-word/`: the shared
+//! melspectrogram and embedding models, plus a classifier for the wake word
+//! itself. `voxtype setup wake-word` downloads them. Detection requires the
+//! `wake-word` feature, included with every ONNX engine.
+
+#[cfg(feature = "wake-word")]
+mod openwakeword;
+
+use crate::config::{Config, WakeWordConfig};
+use crate::error::WakeWordError;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+#[cfg(feature = "wake-word")]
+pub use openwakeword::OpenWakeWord;
+
+/// Pretrained openWakeWord models `[wake_word] model` can name
+pub const PRETRAINED_MODELS: &[&str] = &["alexa", "hey_jarvis", "hey_mycroft", "hey_rhasspy"];
+
+/// Directory under the models directory holding the wake word models
+pub const MODELS_DIRNAME: &str = "wake-word";
+
+/// Melspectrogram model shared by every wake word
+pub const MELSPECTROGRAM_FILENAME: &str = "melspectrogram.onnx";
+
+/// Speech embedding model shared by every wake word
+pub const EMBEDDING_FILENAME: &str = "embedding_model.onnx";
+
+/// Wake word activations within this long of the last one are ignored, so
+/// one utterance starts one recording
+const COOLDOWN: Duration = Duration::from_secs(2);
+
+/// Paths to the three models a detector loads
+#[derive(Debug, Clone)]
+pub struct ModelPaths {
+    pub melspectrogram: PathBuf,
+    pub embedding: PathBuf,
+    pub classifier: PathBuf,
+}
+
+/// Directory the wake word models are downloaded to
+pub fn models_dir() -> PathBuf {
+    Config::models_dir().join(MODELS_DIRNAME)
+}
+
+/// File name of a pretrained model, or None for a custom model path
+pub fn pretrained_filename(model: &str) -> Option<String> {
+    PRETRAINED_MODELS
+        .contains(&model)
+        .then(|| format!("{}_v0.1.onnx", model))
+}
+
+/// Download URL of an openWakeWord release file
+pub fn model_url(filename: &str) -> String {
+    format!(
+        "https://github.com/dscripka/openWakeWord/releases/download/v0.5.1/{}",
+        filename
+    )
+}
+
+/// Resolve the model files for `config`, checking they exist
+pub fn resolve_model_paths(config: &WakeWordConfig) -> Result<ModelPaths, WakeWordError> {
+    let dir = models_dir();
+    let classifier = match pretrained_filename(&config.model) {
+        Some(filename) => dir.join(filename),
+        None => PathBuf::from(&config.model),
+    };
+    let paths = ModelPaths {
+        melspectrogram: dir.join(MELSPECTROGRAM_FILENAME),
+        embedding: dir.join(EMBEDDING_FILENAME),
+        classifier,
+    };
+
+    for path in [&paths.melspectrogram, &paths.embedding, &paths.classifier] {
+        if !path.exists() {
+            return Err(WakeWordError::ModelNotFound(path.display().to_string()));
+        }
+    }
+    Ok(paths)
+}
+
+/// Turns detection scores into activations: a score at or above the
+/// threshold activates, unless the last activation was within the cooldown
+#[derive(Debug, Clone)]
+pub struct Trigger {
+    threshold: f32,
+    last: Option<Instant>,
+}
+
+impl Trigger {
+    pub fn new(threshold: f32) -> Self {
+        Self {
+            threshold: threshold.clamp(0.0, 1.0),
+            last: None,
+        }
+    }
+
+    /// Whether `score`, seen at `now`, activates
+    pub fn fire(&mut self, score: f32, now: Instant) -> bool {
+        if score < self.threshold {
+            return false;
+        }
+        if self
+            .last
+            .is_some_and(|last| now.saturating_duration_since(last) < COOLDOWN)
+        {
+            return false;
+        }
+        self.last = Some(now);
+        true
+    }
+}
+
+/// Start listening for the configured wake word
+///
+/// Opens its own microphone capture and returns a receiver that gets the
+/// detection score each time the wake word is heard, along with the
+/// listening task (aborting it closes the capture).
+#[cfg(feature = "wake-word")]
+pub async fn spawn_listener(
+    config: &Config,
+) -> Result<(mpsc::Receiver<f32>, tokio::task::JoinHandle<()>), WakeWordError> {
+    /// How often the listener drains the captured audio
+    const POLL_INTERVAL: Duration = Duration::from_millis(80);
+
+    let paths = resolve_model_paths(&config.wake_word)?;
+    tracing::info!("Loading wake word model {:?}", paths.classifier);
+    let mut detector = tokio::task::spawn_blocking(move || OpenWakeWord::new(&paths))
+        .await
+        .map_err(|e| WakeWordError::InitFailed(e.to_string()))??;
+
+    let mut capture = crate::audio::create_capture(&config.audio)
+        .map_err(|e| WakeWordError::InitFailed(format!("Failed to open audio: {}", e)))?;
+    // The capture also buffers its audio for get_samples(), which the
+    // listener drains, so the chunk channel isn't needed
+    let _ = capture
+        .start()
+        .await
+        .map_err(|e| WakeWordError::InitFailed(format!("Failed to start audio: {}", e)))?;
+
+    let (tx, rx) = mpsc::channel(4);
+    let mut trigger = Trigger::new(config.wake_word.threshold);
+    let task = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(POLL_INTERVAL);
+        loop {
+            interval.tick().await;
+            let samples = capture.get_samples().await;
+            // Inference runs off the async runtime; the detector moves to
+            // the blocking thread and back
+            let (returned, result) = match tokio::task::spawn_blocking(move || {
+                let result = detector.process(&samples);
+                (detector, result)
+            })
+            .await
+            {
+                Ok(output) => output,
+                Err(e) => {
+                    tracing::error!("Wake word detector stopped: {}", e);
+                    break;
+                }
+            };
+            detector = returned;
+
+            match result {
+                Ok(Some(score)) if trigger.fire(score, Instant::now()) => {
+                    tracing::info!("Wake word detected (score {:.2})", score);
+                    if tx.send(score).await.is_err() {
+                        break;
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!("{}", e),
+            }
+        }
+        let _ = capture.stop().await;
+    });
+
+    Ok((rx, task))
+}
+
+/// Start listening for the configured wake word (unavailable in this build)
+#[cfg(not(feature = "wake-word"))]
+pub async fn spawn_listener(
+    _config: &Config,
+) -> Result<(mpsc::Receiver<f32>, tokio::task::JoinHandle<()>), WakeWordError> {
+    Err(WakeWordError::InitFailed(
+        "Wake word activation requires a build with the `wake-word` feature (included with \
+         every ONNX engine)"
+            .to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trigger_threshold_and_cooldown() {
+        let start = Instant::now();
+        let mut trigger = Trigger::new(0.5);
+        assert!(!trigger.fire(0.4, start));
+        assert!(trigger.fire(0.9, start));
+        // The same utterance keeps scoring high for a few chunks
+        assert!(!trigger.fire(0.9, start + Duration::from_millis(500)));
+        assert!(trigger.fire(0.6, start + COOLDOWN));
+    }
+
+    #[test]
+    fn test_pretrained_filename() {
+        assert_eq!(
+            pretrained_filename("hey_jarvis").as_deref(),
+            Some("hey_jarvis_v0.1.onnx")
+        );
+        assert_eq!(pretrained_filename("/models/hey_voxtype.onnx"), None);
+        assert!(model_url("melspectrogram.onnx").ends_with("/v0.5.1/melspectrogram.onnx"));
+    }
+
+    #[test]
+    fn test_cli_wake_words_match() {
+        assert_eq!(crate::cli::WAKE_WORD_MODELS, PRETRAINED_MODELS);
+    }
+
+    #[test]
+    fn test_resolve_custom_model_missing() {
+        let config = WakeWordConfig {
+            model: "/nonexistent/hey_voxtype.onnx".to_string(),
+            ..WakeWordConfig::default()
+        };
+        assert!(matches!(
+            resolve_model_paths(&config),
+            Err(WakeWordError::ModelNotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,177 @@
+//! openWakeWord models run with ONNX Runtime
+//!
+//! Detection is split across three models. Audio goes in 80ms chunks
+//! (1280 samples at 16kHz): the melspectrogram model turns each chunk into
+//! mel frames, the embedding model turns the last 76 frames into a speech
+//! embedding, and the wake word classifier scores the last 16 embeddings.
+//! Scores start once the windows have filled, about two seconds in.
+
+use super::ModelPaths;
+use crate::error::WakeWordError;
+use ort::session::{Session, SessionInputValue};
+use ort::value::Tensor;
+use std::borrow::Cow;
+use std::path::Path;
+
+/// Samples per chunk (80ms at 16kHz)
+const CHUNK_SIZE: usize = 1280;
+/// Samples of the previous chunk prepended for the melspectrogram window
+const MEL_CONTEXT: usize = 480;
+const MEL_BINS: usize = 32;
+/// Mel frames per embedding
+const EMBEDDING_WINDOW: usize = 76;
+const EMBEDDING_SIZE: usize = 96;
+/// Embeddings per classifier input
+const FEATURE_WINDOW: usize = 16;
+
+/// One ONNX model with a single input and output
+struct Model {
+    session: Session,
+    input: String,
+    output: String,
+}
+
+impl Model {
+    fn load(path: &Path) -> Result<Self, WakeWordError> {
+        let session = Session::builder()
+            .map_err(|e| WakeWordError::InitFailed(format!("ONNX builder failed: {}", e)))?
+            .with_intra_threads(1)
+            .map_err(|e| WakeWordError::InitFailed(format!("Failed to set threads: {}", e)))?
+            .commit_from_file(path)
+            .map_err(|e| WakeWordError::InitFailed(format!("Failed to load {:?}: {}", path, e)))?;
+
+        let input = session.inputs().first().map(|i| i.name().to_string());
+        let output = session.outputs().first().map(|o| o.name().to_string());
+        match (input, output) {
+            (Some(input), Some(output)) => Ok(Self {
+                session,
+                input,
+                output,
+            }),
+            _ => Err(WakeWordError::InitFailed(format!(
+                "{:?} has no inputs or outputs",
+                path
+            ))),
+        }
+    }
+
+    fn run<const N: usize>(
+        &mut self,
+        shape: [usize; N],
+        data: Vec<f32>,
+    ) -> Result<Vec<f32>, WakeWordError> {
+        let tensor = Tensor::<f32>::from_array((shape, data)).map_err(|e| {
+            WakeWordError::DetectionFailed(format!("Failed to create input tensor: {}", e))
+        })?;
+        let inputs: Vec<(Cow<str>, SessionInputValue)> =
+            vec![(Cow::Borrowed(self.input.as_str()), tensor.into())];
+        let outputs = self
+            .session
+            .run(inputs)
+            .map_err(|e| WakeWordError::DetectionFailed(format!("Inference failed: {}", e)))?;
+        let (_, values) = outputs[self.output.as_str()]
+            .try_extract_tensor::<f32>()
+            .map_err(|e| WakeWordError::DetectionFailed(format!("Failed to read output: {}", e)))?;
+        Ok(values.to_vec())
+    }
+}
+
+/// openWakeWord detector for one wake word
+pub struct OpenWakeWord {
+    melspectrogram: Model,
+    embedding: Model,
+    classifier: Model,
+    /// Samples not yet making up a chunk, at int16 scale
+    pending: Vec<f32>,
+    /// Last samples of the previous chunk
+    context: Vec<f32>,
+    /// Most recent mel frames, flattened
+    mel: Vec<f32>,
+    /// Most recent embeddings, flattened
+    features: Vec<f32>,
+}
+
+impl OpenWakeWord {
+    /// Load the models
+    pub fn new(paths: &ModelPaths) -> Result<Self, WakeWordError> {
+        let detector = Self {
+            melspectrogram: Model::load(&paths.melspectrogram)?,
+            embedding: Model::load(&paths.embedding)?,
+            classifier: Model::load(&paths.classifier)?,
+            pending: Vec::with_capacity(CHUNK_SIZE),
+            context: vec![0.0; MEL_CONTEXT],
+            mel: Vec::new(),
+            features: Vec::new(),
+        };
+        tracing::info!("Wake word models loaded successfully");
+        Ok(detector)
+    }
+
+    /// Add 16kHz samples. Returns the highest wake word score (0.0 - 1.0)
+    /// among the chunks they complete, or None if no chunk was scored.
+    pub fn process(&mut self, samples: &[f32]) -> Result<Option<f32>, WakeWordError> {
+        // openWakeWord expects int16-scaled audio
+        self.pending
+            .extend(samples.iter().map(|s| s * i16::MAX as f32));
+
+        let mut best: Option<f32> = None;
+        while self.pending.len() >= CHUNK_SIZE {
+            let chunk: Vec<f32> = self.pending.drain(..CHUNK_SIZE).collect();
+            if let Some(score) = self.process_chunk(chunk)? {
+                best = Some(best.map_or(score, |b| b.max(score)));
+            }
+        }
+        Ok(best)
+    }
+
+    fn process_chunk(&mut self, chunk: Vec<f32>) -> Result<Option<f32>, WakeWordError> {
+        let mut input = std::mem::take(&mut self.context);
+        input.extend(chunk);
+        self.context = input[input.len() - MEL_CONTEXT..].to_vec();
+
+        let samples = input.len();
+        let mel = self.melspectrogram.run([1, samples], input)?;
+        // The transform openWakeWord applies to match its training data
+        self.mel.extend(mel.into_iter().map(|v| v / 10.0 + 2.0));
+        if !keep_last(&mut self.mel, EMBEDDING_WINDOW * MEL_BINS) {
+            return Ok(None);
+        }
+
+        let embedding = self
+            .embedding
+            .run([1, EMBEDDING_WINDOW, MEL_BINS, 1], self.mel.clone())?;
+        self.features.extend(embedding);
+        if !keep_last(&mut self.features, FEATURE_WINDOW * EMBEDDING_SIZE) {
+            return Ok(None);
+        }
+
+        let score = self
+            .classifier
+            .run([1, FEATURE_WINDOW, EMBEDDING_SIZE], self.features.clone())?;
+        Ok(score.first().copied())
+    }
+}
+
+/// Trim `values` to its last `len` entries. Returns false while it is
+/// shorter than that.
+fn keep_last(values: &mut Vec<f32>, len: usize) -> bool {
+    if values.len() < len {
+        return false;
+    }
+    values.drain(..values.len() - len);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keep_last() {
+        let mut values = vec![1.0, 2.0];
+        assert!(!keep_last(&mut values, 3));
+        values.extend([3.0, 4.0]);
+        assert!(keep_last(&mut values, 3));
+        assert_eq!(values, vec![2.0, 3.0, 4.0]);
+    }
+}
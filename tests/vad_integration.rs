@@ -43,6 +43,7 @@ fn energy_vad_with_threshold(threshold: f32) -> EnergyVad {
         backend: VadBackend::Energy,
         threshold,
         min_speech_duration_ms: 100,
+        auto_stop_silence_ms: 0,
         model: None,
     };
     EnergyVad::new(&config)
@@ -307,6 +308,7 @@ fn energy_vad_min_speech_duration_filtering() {
         backend: VadBackend::Energy,
         threshold: 0.5,
         min_speech_duration_ms: 10000, // 10 seconds - longer than the clip
+        auto_stop_silence_ms: 0,
         model: None,
     };
     let vad = EnergyVad::new(&config);
@@ -348,6 +350,7 @@ fn try_create_whisper_vad() -> Option<voxtype::vad::WhisperVad> {
         backend: VadBackend::Whisper,
         threshold: 0.5,
         min_speech_duration_ms: 100,
+        auto_stop_silence_ms: 0,
         model: None,
     };
 